@@ -3,6 +3,7 @@
 #![warn(missing_docs)]
 
 use std::ops::Deref;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use bevy_app::{App, CoreSet, Plugin};
@@ -20,7 +21,8 @@ use bevy_utils::{tracing::warn, HashMap};
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        AnimationClip, AnimationPlayer, AnimationPlugin, EntityPath, Keyframes, VariableCurve,
+        AnimationClip, AnimationEvent, AnimationLayer, AnimationMask, AnimationPlayer,
+        AnimationPlugin, EntityPath, Keyframes, VariableCurve,
     };
 }
 
@@ -53,6 +55,74 @@ pub struct EntityPath {
     pub parts: Vec<Name>,
 }
 
+impl EntityPath {
+    /// Returns `true` if this path is `other` or one of its descendants.
+    pub fn starts_with(&self, other: &EntityPath) -> bool {
+        self.parts.starts_with(&other.parts)
+    }
+}
+
+/// A subset of the bones of a skeleton, used to restrict an [`AnimationLayer`] to e.g. the upper
+/// body.
+///
+/// A bone is in the mask if it is in one of the included subtrees, but not in any of the excluded
+/// ones. The lower body can thus be masked by including the root and excluding the spine.
+#[derive(Reflect, FromReflect, Clone, Debug, Default)]
+pub struct AnimationMask {
+    /// Roots of the subtrees that are in the mask.
+    pub included: Vec<EntityPath>,
+    /// Roots of the subtrees that are not in the mask, even if they are in an included subtree.
+    pub excluded: Vec<EntityPath>,
+}
+
+impl AnimationMask {
+    /// Creates an empty mask.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the bone at `path` and all of its descendants to the mask.
+    pub fn with_subtree(mut self, path: EntityPath) -> Self {
+        self.included.push(path);
+        self
+    }
+
+    /// Removes the bone at `path` and all of its descendants from the mask.
+    pub fn without_subtree(mut self, path: EntityPath) -> Self {
+        self.excluded.push(path);
+        self
+    }
+
+    /// Returns `true` if the bone at `path` is in the mask.
+    pub fn contains(&self, path: &EntityPath) -> bool {
+        self.included.iter().any(|root| path.starts_with(root))
+            && !self.excluded.iter().any(|root| path.starts_with(root))
+    }
+}
+
+/// An event embedded in an [`AnimationClip`] with [`AnimationClip::add_event`], e.g. a footstep.
+///
+/// It is sent when the main animation or a layer of an [`AnimationPlayer`] crosses the time of the
+/// event, with [`AnimationEvent::player`] set to the entity of the player.
+#[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+pub struct AnimationEvent {
+    /// The name of the event.
+    pub name: String,
+    /// The entity of the [`AnimationPlayer`] that sent the event, [`Entity::PLACEHOLDER`] in the
+    /// [`AnimationClip`].
+    pub player: Entity,
+}
+
+impl AnimationEvent {
+    /// Creates an event with the given name, to be added to an [`AnimationClip`].
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            player: Entity::PLACEHOLDER,
+        }
+    }
+}
+
 /// A list of [`VariableCurve`], and the [`EntityPath`] to which they apply.
 #[derive(Reflect, FromReflect, Clone, TypeUuid, Debug, Default)]
 #[uuid = "d81b7179-0448-4eb0-89fe-c067222725bf"]
@@ -60,6 +130,7 @@ pub struct AnimationClip {
     curves: Vec<Vec<VariableCurve>>,
     paths: HashMap<EntityPath, usize>,
     duration: f32,
+    events: Vec<(f32, AnimationEvent)>,
 }
 
 impl AnimationClip {
@@ -105,6 +176,48 @@ impl AnimationClip {
             self.paths.insert(path, idx);
         }
     }
+
+    /// Events of the clip, with the time in seconds at which they are sent.
+    #[inline]
+    pub fn events(&self) -> &[(f32, AnimationEvent)] {
+        &self.events
+    }
+
+    /// Add an [`AnimationEvent`] to be sent when an [`AnimationPlayer`] crosses `time`, in seconds.
+    ///
+    /// Events at the start of the clip are sent when it starts playing. Events are not sent by
+    /// animations which are fading out.
+    pub fn add_event(&mut self, time: f32, event: AnimationEvent) {
+        self.events.push((time, event));
+    }
+
+    /// Calls `send` for each event crossed by advancing a playing animation from `from` to `to`,
+    /// counting an event at `from` but not at `to`, as seen in the direction of playback.
+    fn crossed_events(
+        &self,
+        from: f32,
+        to: f32,
+        repeat: bool,
+        mut send: impl FnMut(&AnimationEvent),
+    ) {
+        for (time, event) in &self.events {
+            let count = if repeat && self.duration > 0.0 {
+                // The number of repetitions of the event that were crossed
+                if from <= to {
+                    ((to - time) / self.duration).ceil() - ((from - time) / self.duration).ceil()
+                } else {
+                    ((from - time) / self.duration).floor() - ((to - time) / self.duration).floor()
+                }
+            } else if (from <= *time && *time < to) || (to < *time && *time <= from) {
+                1.0
+            } else {
+                0.0
+            };
+            for _ in 0..count as usize {
+                send(event);
+            }
+        }
+    }
 }
 
 #[derive(Reflect)]
@@ -138,6 +251,148 @@ struct AnimationTransition {
     animation: PlayingAnimation,
 }
 
+/// An animation blended on top of the main animation of an [`AnimationPlayer`], e.g. to wave with
+/// the upper body while walking.
+///
+/// Layers are applied in the order they were added with [`AnimationPlayer::add_layer`]. Each layer
+/// blends its pose into the result of the previous layers with its weight, limited to the bones in
+/// its [`AnimationMask`], if any.
+pub struct AnimationLayer {
+    weight: f32,
+    target_weight: f32,
+    weight_change_per_sec: f32,
+    mask: Option<AnimationMask>,
+    animation: PlayingAnimation,
+    transitions: Vec<AnimationTransition>,
+}
+
+impl AnimationLayer {
+    /// Creates a layer playing the animation with a weight of 1.0, affecting all bones.
+    pub fn new(handle: Handle<AnimationClip>) -> Self {
+        Self {
+            weight: 1.0,
+            target_weight: 1.0,
+            weight_change_per_sec: 0.0,
+            mask: None,
+            animation: PlayingAnimation {
+                animation_clip: handle,
+                ..Default::default()
+            },
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Sets the weight of the layer.
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.set_weight(weight);
+        self
+    }
+
+    /// Restricts the layer to the bones in `mask`.
+    pub fn with_mask(mut self, mask: AnimationMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Start playing an animation in this layer, resetting its state.
+    pub fn start(&mut self, handle: Handle<AnimationClip>) -> &mut Self {
+        self.animation = PlayingAnimation {
+            animation_clip: handle,
+            ..Default::default()
+        };
+        self.transitions.clear();
+        self
+    }
+
+    /// Start playing an animation in this layer, resetting its state.
+    /// This will use a linear blending between the previous and the new animation to make a smooth transition
+    pub fn start_with_transition(
+        &mut self,
+        handle: Handle<AnimationClip>,
+        transition_duration: Duration,
+    ) -> &mut Self {
+        let mut animation = PlayingAnimation {
+            animation_clip: handle,
+            ..Default::default()
+        };
+        std::mem::swap(&mut animation, &mut self.animation);
+        self.transitions.push(AnimationTransition {
+            current_weight: 1.0,
+            weight_decline_per_sec: 1.0 / transition_duration.as_secs_f32(),
+            animation,
+        });
+        self
+    }
+
+    /// Set the animation of this layer to repeat
+    pub fn repeat(&mut self) -> &mut Self {
+        self.animation.repeat = true;
+        self
+    }
+
+    /// Stop the animation of this layer from repeating
+    pub fn stop_repeating(&mut self) -> &mut Self {
+        self.animation.repeat = false;
+        self
+    }
+
+    /// Set the speed of the animation playback of this layer
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.animation.speed = speed;
+        self
+    }
+
+    /// Time elapsed playing the animation of this layer
+    pub fn elapsed(&self) -> f32 {
+        self.animation.elapsed
+    }
+
+    /// The current weight of the layer
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Set the weight of the layer, stopping any ongoing fade
+    pub fn set_weight(&mut self, weight: f32) -> &mut Self {
+        self.weight = weight;
+        self.target_weight = weight;
+        self.weight_change_per_sec = 0.0;
+        self
+    }
+
+    /// Linearly change the weight of the layer to `weight` over `duration`, e.g. to fade the layer
+    /// in or out
+    pub fn fade_to(&mut self, weight: f32, duration: Duration) -> &mut Self {
+        if duration.is_zero() {
+            return self.set_weight(weight);
+        }
+        self.target_weight = weight;
+        self.weight_change_per_sec = (weight - self.weight).abs() / duration.as_secs_f32();
+        self
+    }
+
+    /// The mask of the layer
+    pub fn mask(&self) -> Option<&AnimationMask> {
+        self.mask.as_ref()
+    }
+
+    /// Set the mask of the layer, or remove it to affect all bones
+    pub fn set_mask(&mut self, mask: Option<AnimationMask>) -> &mut Self {
+        self.mask = mask;
+        self
+    }
+
+    fn update(&mut self, time: &Time) {
+        let step = self.weight_change_per_sec * time.delta_seconds();
+        self.weight = if self.weight < self.target_weight {
+            (self.weight + step).min(self.target_weight)
+        } else {
+            (self.weight - step).max(self.target_weight)
+        };
+        update_transitions(&mut self.transitions, time);
+    }
+}
+
 /// Animation controls
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
@@ -153,6 +408,10 @@ pub struct AnimationPlayer {
     // Once a transition is finished, it will be automatically removed from the list
     #[reflect(ignore)]
     transitions: Vec<AnimationTransition>,
+
+    // Animations blended on top of the main animation
+    #[reflect(ignore)]
+    layers: Vec<AnimationLayer>,
 }
 
 impl AnimationPlayer {
@@ -267,6 +526,33 @@ impl AnimationPlayer {
         self.animation.elapsed = elapsed;
         self
     }
+
+    /// Add a layer blended on top of the main animation and the previously added layers,
+    /// returning its index
+    pub fn add_layer(&mut self, layer: AnimationLayer) -> usize {
+        self.layers.push(layer);
+        self.layers.len() - 1
+    }
+
+    /// The layer with the given index
+    pub fn layer(&self, index: usize) -> Option<&AnimationLayer> {
+        self.layers.get(index)
+    }
+
+    /// The layer with the given index
+    pub fn layer_mut(&mut self, index: usize) -> Option<&mut AnimationLayer> {
+        self.layers.get_mut(index)
+    }
+
+    /// All layers, in the order they are applied
+    pub fn layers(&self) -> &[AnimationLayer] {
+        &self.layers
+    }
+
+    /// Remove all layers
+    pub fn clear_layers(&mut self) {
+        self.layers.clear();
+    }
 }
 
 fn find_bone(
@@ -335,6 +621,7 @@ fn verify_no_ancestor_player(
 
 /// System that will play all animations, using any entity with a [`AnimationPlayer`]
 /// and a [`Handle<AnimationClip>`] as an animation root
+#[allow(clippy::too_many_arguments)]
 pub fn animation_player(
     time: Res<Time>,
     animations: Res<Assets<AnimationClip>>,
@@ -343,11 +630,17 @@ pub fn animation_player(
     transforms: Query<&mut Transform>,
     parents: Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
     mut animation_players: Query<(Entity, Option<&Parent>, &mut AnimationPlayer)>,
+    mut animation_events: EventWriter<AnimationEvent>,
 ) {
+    let events = Mutex::new(Vec::new());
     animation_players
         .par_iter_mut()
         .for_each_mut(|(root, maybe_parent, mut player)| {
-            update_transitions(&mut player, &time);
+            update_transitions(&mut player.transitions, &time);
+            for layer in &mut player.layers {
+                layer.update(&time);
+            }
+            let mut player_events = Vec::new();
             run_animation_player(
                 root,
                 player,
@@ -358,8 +651,17 @@ pub fn animation_player(
                 maybe_parent,
                 &parents,
                 &children,
+                &mut player_events,
             );
+            if !player_events.is_empty() {
+                events.lock().unwrap().push((root, player_events));
+            }
         });
+    // The players are updated in parallel, so their events are sorted to be sent in a deterministic
+    // order
+    let mut events = events.into_inner().unwrap();
+    events.sort_unstable_by_key(|(player, _)| *player);
+    animation_events.send_batch(events.into_iter().flat_map(|(_, events)| events));
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -373,6 +675,7 @@ fn run_animation_player(
     maybe_parent: Option<&Parent>,
     parents: &Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
     children: &Query<&Children>,
+    events: &mut Vec<AnimationEvent>,
 ) {
     let paused = player.paused;
     // Continue if paused unless the `AnimationPlayer` was changed
@@ -381,10 +684,14 @@ fn run_animation_player(
         return;
     }
 
+    let player = player.as_mut();
+
     // Apply the main animation
     apply_animation(
         1.0,
+        None,
         &mut player.animation,
+        Some(&mut *events),
         paused,
         root,
         time,
@@ -405,7 +712,29 @@ fn run_animation_player(
     {
         apply_animation(
             *current_weight,
+            None,
             animation,
+            None,
+            paused,
+            root,
+            time,
+            animations,
+            names,
+            transforms,
+            maybe_parent,
+            parents,
+            children,
+        );
+    }
+
+    // Apply the layers with their own fade-out transitions
+    for layer in &mut player.layers {
+        let mask = layer.mask.as_ref();
+        apply_animation(
+            layer.weight,
+            mask,
+            &mut layer.animation,
+            Some(&mut *events),
             paused,
             root,
             time,
@@ -416,13 +745,37 @@ fn run_animation_player(
             parents,
             children,
         );
+        for AnimationTransition {
+            current_weight,
+            animation,
+            ..
+        } in &mut layer.transitions
+        {
+            apply_animation(
+                layer.weight * *current_weight,
+                mask,
+                animation,
+                None,
+                paused,
+                root,
+                time,
+                animations,
+                names,
+                transforms,
+                maybe_parent,
+                parents,
+                children,
+            );
+        }
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn apply_animation(
     weight: f32,
+    mask: Option<&AnimationMask>,
     animation: &mut PlayingAnimation,
+    events: Option<&mut Vec<AnimationEvent>>,
     paused: bool,
     root: Entity,
     time: &Time,
@@ -435,7 +788,21 @@ fn apply_animation(
 ) {
     if let Some(animation_clip) = animations.get(&animation.animation_clip) {
         if !paused {
+            let previous_elapsed = animation.elapsed;
             animation.elapsed += time.delta_seconds() * animation.speed;
+            if let Some(events) = events {
+                animation_clip.crossed_events(
+                    previous_elapsed,
+                    animation.elapsed,
+                    animation.repeat,
+                    |event| {
+                        events.push(AnimationEvent {
+                            player: root,
+                            ..event.clone()
+                        });
+                    },
+                );
+            }
         }
         let mut elapsed = animation.elapsed;
         if animation.repeat {
@@ -453,6 +820,9 @@ fn apply_animation(
         }
 
         for (path, bone_id) in &animation_clip.paths {
+            if mask.map_or(false, |mask| !mask.contains(path)) {
+                continue;
+            }
             let cached_path = &mut animation.path_cache[*bone_id];
             let curves = animation_clip.get_curves(*bone_id).unwrap();
             let Some(target) = find_bone(root, path, children, names, cached_path) else { continue };
@@ -534,8 +904,8 @@ fn apply_animation(
     }
 }
 
-fn update_transitions(player: &mut AnimationPlayer, time: &Time) {
-    player.transitions.retain_mut(|animation| {
+fn update_transitions(transitions: &mut Vec<AnimationTransition>, time: &Time) {
+    transitions.retain_mut(|animation| {
         animation.current_weight -= animation.weight_decline_per_sec * time.delta_seconds();
         animation.current_weight > 0.0
     });
@@ -548,6 +918,7 @@ pub struct AnimationPlugin {}
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.add_asset::<AnimationClip>()
+            .add_event::<AnimationEvent>()
             .register_asset_reflect::<AnimationClip>()
             .register_type::<AnimationPlayer>()
             .add_system(
@@ -557,3 +928,208 @@ impl Plugin for AnimationPlugin {
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::AssetPlugin;
+    use bevy_core::TaskPoolPlugin;
+    use bevy_hierarchy::BuildWorldChildren;
+
+    fn path(parts: &[&'static str]) -> EntityPath {
+        EntityPath {
+            parts: parts.iter().map(|part| Name::new(*part)).collect(),
+        }
+    }
+
+    fn clip_with_event(duration: f32, time: f32) -> AnimationClip {
+        let mut clip = AnimationClip {
+            duration,
+            ..Default::default()
+        };
+        clip.add_event(
+            time,
+            AnimationEvent {
+                name: "footstep".into(),
+                player: Entity::PLACEHOLDER,
+            },
+        );
+        clip
+    }
+
+    fn count_crossed_events(clip: &AnimationClip, from: f32, to: f32, repeat: bool) -> usize {
+        let mut count = 0;
+        clip.crossed_events(from, to, repeat, |_| count += 1);
+        count
+    }
+
+    #[test]
+    fn crossed_events_without_repeat() {
+        let clip = clip_with_event(1.0, 0.5);
+        assert_eq!(count_crossed_events(&clip, 0.0, 0.4, false), 0);
+        assert_eq!(count_crossed_events(&clip, 0.4, 0.6, false), 1);
+        // an event at `from` is counted, an event at `to` is not
+        assert_eq!(count_crossed_events(&clip, 0.5, 0.6, false), 1);
+        assert_eq!(count_crossed_events(&clip, 0.4, 0.5, false), 0);
+        // backwards playback
+        assert_eq!(count_crossed_events(&clip, 0.6, 0.4, false), 1);
+        assert_eq!(count_crossed_events(&clip, 0.6, 0.5, false), 0);
+        // the animation doesn't wrap around
+        assert_eq!(count_crossed_events(&clip, 0.9, 1.6, false), 0);
+    }
+
+    #[test]
+    fn crossed_events_wrap_around() {
+        let clip = clip_with_event(1.0, 0.5);
+        assert_eq!(count_crossed_events(&clip, 0.9, 1.4, true), 0);
+        assert_eq!(count_crossed_events(&clip, 0.9, 1.6, true), 1);
+        assert_eq!(count_crossed_events(&clip, 1.6, 0.9, true), 1);
+        assert_eq!(count_crossed_events(&clip, 1.5, 1.6, true), 1);
+
+        let clip = clip_with_event(1.0, 0.0);
+        assert_eq!(count_crossed_events(&clip, 0.0, 0.1, true), 1);
+        assert_eq!(count_crossed_events(&clip, 0.9, 1.0, true), 0);
+        assert_eq!(count_crossed_events(&clip, 0.9, 1.1, true), 1);
+    }
+
+    #[test]
+    fn crossed_events_multiple_repeats() {
+        let clip = clip_with_event(1.0, 0.5);
+        assert_eq!(count_crossed_events(&clip, 0.0, 3.2, true), 3);
+        assert_eq!(count_crossed_events(&clip, 0.6, 3.6, true), 3);
+        assert_eq!(count_crossed_events(&clip, 3.6, 0.6, true), 3);
+        assert_eq!(count_crossed_events(&clip, 0.0, 3.2, false), 1);
+    }
+
+    #[test]
+    fn animation_mask_contains() {
+        let mask = AnimationMask::new()
+            .with_subtree(path(&["root"]))
+            .without_subtree(path(&["root", "hips"]));
+
+        assert!(mask.contains(&path(&["root"])));
+        assert!(mask.contains(&path(&["root", "spine", "head"])));
+        assert!(!mask.contains(&path(&["root", "hips"])));
+        assert!(!mask.contains(&path(&["root", "hips", "left_leg"])));
+        assert!(!mask.contains(&path(&["other_root"])));
+        // only whole names are matched
+        assert!(mask.contains(&path(&["root", "hips_armor"])));
+        assert!(!AnimationMask::new().contains(&path(&["root"])));
+    }
+
+    /// A clip moving the bones at the `paths` to `translation`.
+    fn clip_with_translation(paths: &[EntityPath], translation: Vec3) -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        for path in paths {
+            clip.add_curve_to_path(
+                path.clone(),
+                VariableCurve {
+                    keyframe_timestamps: vec![0.0],
+                    keyframes: Keyframes::Translation(vec![translation]),
+                },
+            );
+        }
+        clip
+    }
+
+    fn advance_time(app: &mut App, seconds: f32) {
+        let mut time = app.world.resource_mut::<Time>();
+        let last_update = time.last_update().unwrap();
+        time.update_with_instant(last_update + Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    #[test]
+    fn masked_layers_blend_and_fade_over_time() {
+        let mut app = App::new();
+        app.add_plugin(TaskPoolPlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(AnimationPlugin::default());
+        let mut time = Time::default();
+        time.update();
+        app.insert_resource(time);
+
+        let upper = path(&["root", "upper"]);
+        let lower = path(&["root", "lower"]);
+        let mut clips = app.world.resource_mut::<Assets<AnimationClip>>();
+        let idle = clips.add(clip_with_translation(
+            &[upper.clone(), lower.clone()],
+            Vec3::ZERO,
+        ));
+        let wave = clips.add(clip_with_translation(
+            &[upper.clone(), lower.clone()],
+            Vec3::X * 10.0,
+        ));
+
+        let mut player = AnimationPlayer::default();
+        player.start(idle);
+        player.add_layer(
+            AnimationLayer::new(wave)
+                .with_weight(0.0)
+                .with_mask(AnimationMask::new().with_subtree(upper)),
+        );
+        player
+            .layer_mut(0)
+            .unwrap()
+            .fade_to(1.0, Duration::from_secs(1));
+        let upper = app
+            .world
+            .spawn((Name::new("upper"), Transform::default()))
+            .id();
+        let lower = app
+            .world
+            .spawn((Name::new("lower"), Transform::default()))
+            .id();
+        let root = app
+            .world
+            .spawn((Name::new("root"), Transform::default(), player))
+            .push_children(&[upper, lower])
+            .id();
+
+        let state = |app: &App| {
+            let layer_weight = app
+                .world
+                .get::<AnimationPlayer>(root)
+                .unwrap()
+                .layer(0)
+                .unwrap()
+                .weight();
+            let upper = app.world.get::<Transform>(upper).unwrap().translation.x;
+            let lower = app.world.get::<Transform>(lower).unwrap().translation.x;
+            (layer_weight, upper, lower)
+        };
+
+        // The layer fades in, only moving the masked bone with its weight.
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (0.5, 5.0, 0.0));
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (1.0, 10.0, 0.0));
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (1.0, 10.0, 0.0));
+
+        // And fades out again.
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .layer_mut(0)
+            .unwrap()
+            .fade_to(0.0, Duration::from_secs(2));
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (0.75, 7.5, 0.0));
+        advance_time(&mut app, 1.5);
+        assert_eq!(state(&app), (0.0, 0.0, 0.0));
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (0.0, 0.0, 0.0));
+
+        // Setting the weight stops the fade.
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .layer_mut(0)
+            .unwrap()
+            .fade_to(1.0, Duration::from_secs(1))
+            .set_weight(0.25);
+        advance_time(&mut app, 0.5);
+        assert_eq!(state(&app), (0.25, 2.5, 0.0));
+    }
+}
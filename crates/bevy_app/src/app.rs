@@ -1,17 +1,19 @@
 use crate::{
-    CoreSchedule, CoreSet, IntoSystemAppConfig, IntoSystemAppConfigs, Plugin, PluginGroup,
-    StartupSet, SystemAppConfig,
+    send_queued_events, AppEventSender, AppWaker, CoreSchedule, CoreSet, IntoSystemAppConfig,
+    IntoSystemAppConfigs, Plugin, PluginGroup, PluginTimings, StartupSchedules, StartupSet,
+    SystemAppConfig,
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
+    event::{EventCapacityPolicy, ManualEventReader},
     prelude::*,
     schedule::{
         apply_state_transition, common_conditions::run_once as run_once_condition,
         run_enter_schedule, BoxedScheduleLabel, IntoSystemConfig, IntoSystemSetConfigs,
-        ScheduleLabel,
+        ScheduleLabel, SystemPanicked,
     },
 };
-use bevy_utils::{tracing::debug, HashMap, HashSet};
+use bevy_utils::{tracing::debug, Duration, HashMap, HashSet, Instant};
 use std::{
     fmt::Debug,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
@@ -26,10 +28,21 @@ bevy_utils::define_label!(
     AppLabelId,
 );
 
-/// The [`Resource`] that stores the [`App`]'s [`TypeRegistry`](bevy_reflect::TypeRegistry).
 #[cfg(feature = "bevy_reflect")]
-#[derive(Resource, Clone, bevy_derive::Deref, bevy_derive::DerefMut, Default)]
-pub struct AppTypeRegistry(pub bevy_reflect::TypeRegistryArc);
+pub use bevy_ecs::reflect::AppTypeRegistry;
+
+/// An entry of [`App::plugin_build_order`], describing a [`Plugin`] that was built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginBuildEntry {
+    /// The [name](Plugin::name) of the plugin.
+    pub name: String,
+    /// The name of the [`PluginGroup`] the plugin was added from, if any.
+    pub group: Option<String>,
+    /// The number of plugins that were being built when this plugin was added, i.e. `0` for
+    /// plugins added directly to the [`App`] or through a [`PluginGroup`], and `1` for plugins
+    /// added by those plugins.
+    pub depth: usize,
+}
 
 pub(crate) enum AppError {
     DuplicatePlugin { plugin_name: String },
@@ -85,6 +98,12 @@ pub struct App {
     plugin_name_added: HashSet<String>,
     /// A private counter to prevent incorrect calls to `App::run()` from `Plugin::build()`
     building_plugin_depth: usize,
+    /// The time spent building the plugins added by the plugin that is currently being built,
+    /// which is excluded from its own [`PluginTiming`](crate::PluginTiming).
+    nested_plugin_build_time: Duration,
+    plugin_build_order: Vec<PluginBuildEntry>,
+    /// The name of the [`PluginGroup`] whose plugins are currently being added.
+    pub(crate) adding_plugin_group: Option<String>,
 }
 
 impl Debug for App {
@@ -198,6 +217,7 @@ impl Default for App {
         app.add_default_schedules();
 
         app.add_event::<AppExit>();
+        app.add_event::<SystemPanicked>();
 
         #[cfg(feature = "bevy_ci_testing")]
         {
@@ -232,6 +252,9 @@ impl App {
             default_schedule_label: Box::new(CoreSchedule::Main),
             outer_schedule_label: Box::new(CoreSchedule::Outer),
             building_plugin_depth: 0,
+            nested_plugin_build_time: Duration::ZERO,
+            plugin_build_order: Vec::new(),
+            adding_plugin_group: None,
         }
     }
 
@@ -260,6 +283,7 @@ impl App {
             sub_app.run();
         }
 
+        self.world.despawn_released_entities();
         self.world.clear_trackers();
     }
 
@@ -304,15 +328,75 @@ impl App {
         (runner)(app);
     }
 
+    /// Runs the exit sequence if an [`AppExit`] was requested, returning the [`AppExit`] once the
+    /// app should stop running.
+    ///
+    /// This is meant to be called by [runners](Self::set_runner) after each [`App::update`]:
+    /// new [`AppExit`] events read by `reader` insert the [`AppExitRequested`] resource, after
+    /// which [`CoreSchedule::ExitRequested`] runs so that systems can veto or delay the exit.
+    /// If neither happened, [`App::shutdown`] is called and the [`AppExit`] is returned.
+    pub fn poll_exit(&mut self, reader: &mut ManualEventReader<AppExit>) -> Option<AppExit> {
+        if let Some(exit) = self
+            .world
+            .get_resource::<Events<AppExit>>()
+            .and_then(|events| reader.iter(events).last().cloned())
+        {
+            if !self.world.contains_resource::<AppExitRequested>() {
+                self.world.insert_resource(AppExitRequested {
+                    exit,
+                    vetoed: false,
+                    delayed: false,
+                });
+            }
+        }
+
+        let mut request = self.world.get_resource_mut::<AppExitRequested>()?;
+        request.vetoed = false;
+        request.delayed = false;
+        let _ = self.world.try_run_schedule(CoreSchedule::ExitRequested);
+
+        let request = self.world.resource::<AppExitRequested>();
+        if request.delayed && !request.vetoed {
+            return None;
+        }
+        let request = self.world.remove_resource::<AppExitRequested>().unwrap();
+        if request.vetoed {
+            return None;
+        }
+        self.shutdown();
+        Some(request.exit)
+    }
+
+    /// Runs [`CoreSchedule::Shutdown`] a single time, if it exists.
+    ///
+    /// [Runners](Self::set_runner) call this once before returning, giving teardown logic such
+    /// as flushing saves or closing network connections a guaranteed point of execution.
+    pub fn shutdown(&mut self) {
+        #[cfg(feature = "trace")]
+        let _bevy_shutdown_span = info_span!("shutdown").entered();
+        let _ = self.world.try_run_schedule(CoreSchedule::Shutdown);
+    }
+
     /// Run [`Plugin::setup`] for each plugin. This is usually called by [`App::run`], but can
     /// be useful for situations where you want to use [`App::update`].
+    ///
+    /// The time spent in each plugin is recorded in the [`PluginTimings`] resource, and a summary
+    /// of it is logged at the `DEBUG` level afterwards.
     pub fn setup(&mut self) {
         // temporarily remove the plugin registry to run each plugin's setup function on app.
         let plugin_registry = std::mem::take(&mut self.plugin_registry);
-        for plugin in &plugin_registry {
+        for (index, plugin) in plugin_registry.iter().enumerate() {
+            let start = Instant::now();
             plugin.setup(self);
+            let elapsed = start.elapsed();
+            if let Some(mut timings) = self.world.get_resource_mut::<PluginTimings>() {
+                timings.record_setup(index, elapsed);
+            }
         }
         self.plugin_registry = plugin_registry;
+        if let Some(timings) = self.world.get_resource::<PluginTimings>() {
+            debug!("{timings}");
+        }
     }
 
     /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
@@ -492,6 +576,53 @@ impl App {
         self.add_systems(systems.into_configs().in_schedule(CoreSchedule::Startup))
     }
 
+    /// Adds the schedule with the given `label` to the end of the [`StartupSchedules`],
+    /// creating it if it does not exist yet.
+    ///
+    /// Startup schedules run once, in order, at the start of the [`App`]'s lifecycle,
+    /// beginning with [`CoreSchedule::Startup`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+    /// #
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct LoadLevel;
+    ///
+    /// fn spawn_level(_commands: Commands) {}
+    ///
+    /// App::new()
+    ///     .add_startup_schedule(LoadLevel)
+    ///     .add_system(spawn_level.in_schedule(LoadLevel));
+    /// ```
+    pub fn add_startup_schedule(&mut self, label: impl ScheduleLabel) -> &mut Self {
+        self.init_schedule(label.dyn_clone());
+        self.world
+            .get_resource_or_insert_with(StartupSchedules::default)
+            .push(label);
+        self
+    }
+
+    /// Adds the schedule with the given `label` to the [`StartupSchedules`] so that it runs
+    /// right after the `after` schedule, creating it if it does not exist yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `after` is not a startup schedule.
+    pub fn add_startup_schedule_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        label: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.init_schedule(label.dyn_clone());
+        self.world
+            .get_resource_or_insert_with(StartupSchedules::default)
+            .insert_after(after, label);
+        self
+    }
+
     /// Configures a system set in the default schedule, adding the set if it does not exist.
     pub fn configure_set(&mut self, set: impl IntoSystemSetConfig) -> &mut Self {
         self.world
@@ -525,6 +656,8 @@ impl App {
     /// - [`CoreSchedule::Startup`]: uses [`StartupSet::base_schedule`]
     /// - [`CoreSchedule::Main`]: uses [`CoreSet::base_schedule`]
     /// - [`CoreSchedule::FixedUpdate`]: no starting configuration
+    /// - [`CoreSchedule::ExitRequested`]: no starting configuration
+    /// - [`CoreSchedule::Shutdown`]: no starting configuration
     ///
     /// # Examples
     ///
@@ -542,6 +675,9 @@ impl App {
         self.add_schedule(CoreSchedule::Startup, StartupSet::base_schedule());
         self.add_schedule(CoreSchedule::Main, CoreSet::base_schedule());
         self.init_schedule(CoreSchedule::FixedUpdate);
+        self.init_schedule(CoreSchedule::ExitRequested);
+        self.init_schedule(CoreSchedule::Shutdown);
+        self.init_resource::<StartupSchedules>();
 
         self
     }
@@ -583,12 +719,84 @@ impl App {
         T: Event,
     {
         if !self.world.contains_resource::<Events<T>>() {
+            self.world.track_event_memory_usage::<T>();
             self.init_resource::<Events<T>>()
                 .add_system(Events::<T>::update_system.in_base_set(CoreSet::First));
         }
         self
     }
 
+    /// Setup the application to manage events of type `T` and adds an [`AppEventSender<T>`]
+    /// resource, which can be cloned into async tasks and OS callbacks to send events from other
+    /// threads and wake the app.
+    ///
+    /// The queued events are sent in [`CoreSet::First`], after the events are updated.
+    pub fn add_event_sender<T>(&mut self) -> &mut Self
+    where
+        T: Event,
+    {
+        if !self.world.contains_resource::<AppEventSender<T>>() {
+            let waker = self
+                .world
+                .get_resource_or_insert_with(AppWaker::default)
+                .clone();
+            self.add_event::<T>()
+                .insert_resource(AppEventSender::<T>::new(waker))
+                .add_system(
+                    send_queued_events::<T>
+                        .in_base_set(CoreSet::First)
+                        .after(Events::<T>::update_system),
+                );
+        }
+        self
+    }
+
+    /// Sets the default [`EventCapacityPolicy`] of all [`Events`] added with [`App::add_event`],
+    /// controlling whether their buffers release memory after a burst of events.
+    ///
+    /// Individual [`Events`] can override it with [`Events::set_capacity_policy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::event::EventCapacityPolicy;
+    /// #
+    /// App::new().set_event_capacity_policy(EventCapacityPolicy::Shrink { min_capacity: 64 });
+    /// ```
+    pub fn set_event_capacity_policy(&mut self, policy: EventCapacityPolicy) -> &mut Self {
+        self.insert_resource(policy)
+    }
+
+    /// Setup the application to manage events of type `T` that are addressed to specific entities.
+    ///
+    /// This is done by adding a [`Resource`] of type [`EntityEvents::<T>`],
+    /// and inserting an [`update_system`](EntityEvents::update_system) into [`CoreSet::First`].
+    ///
+    /// See [`EntityEvents`] for defining events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # struct Damage(f32);
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_entity_event::<Damage>();
+    /// ```
+    pub fn add_entity_event<T>(&mut self) -> &mut Self
+    where
+        T: Event,
+    {
+        if !self.world.contains_resource::<EntityEvents<T>>() {
+            self.init_resource::<EntityEvents<T>>()
+                .add_system(EntityEvents::<T>::update_system.in_base_set(CoreSet::First));
+        }
+        self
+    }
+
     /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
     ///
     /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
@@ -763,16 +971,84 @@ impl App {
                 plugin_name: plugin.name().to_string(),
             })?;
         }
+        // Plugins added while building a plugin of a group don't belong to the group.
+        let group = self.adding_plugin_group.take();
+        self.plugin_build_order.push(PluginBuildEntry {
+            name: plugin.name().to_string(),
+            group: group.clone(),
+            depth: self.building_plugin_depth,
+        });
         self.building_plugin_depth += 1;
+        let outer_nested_build_time = std::mem::take(&mut self.nested_plugin_build_time);
+        let start = Instant::now();
         let result = catch_unwind(AssertUnwindSafe(|| plugin.build(self)));
+        let elapsed = start.elapsed();
+        let nested_build_time = std::mem::replace(
+            &mut self.nested_plugin_build_time,
+            outer_nested_build_time + elapsed,
+        );
         self.building_plugin_depth -= 1;
+        self.adding_plugin_group = group;
         if let Err(payload) = result {
             resume_unwind(payload);
         }
+        self.world
+            .get_resource_or_insert_with(PluginTimings::default)
+            .record_build(plugin.name(), elapsed.saturating_sub(nested_build_time));
         self.plugin_registry.push(plugin);
         Ok(self)
     }
 
+    /// Returns the [`Plugin`]s in the order they were built, after expanding [`PluginGroup`]s
+    /// and applying their ordering.
+    ///
+    /// A plugin is listed before the plugins it adds while being built.
+    pub fn plugin_build_order(&self) -> &[PluginBuildEntry] {
+        &self.plugin_build_order
+    }
+
+    /// Asserts that the [`Plugin`]s with the given names were built in the given order.
+    ///
+    /// The names may be the full [name](Plugin::name) of a plugin or its short name without the
+    /// module path. Other plugins may be built in between.
+    ///
+    /// ```rust
+    /// # use bevy_app::prelude::*;
+    /// # struct FirstPlugin;
+    /// # impl Plugin for FirstPlugin {
+    /// #    fn build(&self, app: &mut App) {}
+    /// # }
+    /// # struct SecondPlugin;
+    /// # impl Plugin for SecondPlugin {
+    /// #    fn build(&self, app: &mut App) {}
+    /// # }
+    /// let mut app = App::new();
+    /// app.add_plugin(FirstPlugin).add_plugin(SecondPlugin);
+    /// app.assert_plugin_build_order(&["FirstPlugin", "SecondPlugin"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the plugins wasn't built, or not in the given order.
+    pub fn assert_plugin_build_order(&self, expected: &[&str]) {
+        let mut built = self.plugin_build_order.iter();
+        for name in expected {
+            if !built.any(|entry| {
+                entry.name == *name || bevy_utils::get_short_name(&entry.name) == *name
+            }) {
+                let order: Vec<_> = self
+                    .plugin_build_order
+                    .iter()
+                    .map(|entry| entry.name.as_str())
+                    .collect();
+                panic!(
+                    "Expected plugins to be built in the order {expected:?}, but {name} was missing \
+                    or out of order. Actual order: {order:?}"
+                );
+            }
+        }
+    }
+
     /// Checks if a [`Plugin`] has already been added.
     ///
     /// This can be used by plugins to check if a plugin they depend upon has already been
@@ -889,6 +1165,26 @@ impl App {
         self
     }
 
+    /// Registers the runtime-defined struct type of `descriptor` in the
+    /// [`TypeRegistry`](bevy_reflect::TypeRegistry) resource, and inserts the
+    /// [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent) that allows using its values as
+    /// components, e.g. when spawning scenes.
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_dynamic`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_dynamic_component(
+        &mut self,
+        descriptor: &bevy_reflect::DynamicTypeDescriptor,
+    ) -> Result<&mut Self, bevy_reflect::DynamicTypeError> {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        let mut registry = registry.write();
+        let registration = registry.register_dynamic(descriptor)?;
+        let slot = registration.dynamic_slot().unwrap();
+        registration.insert(bevy_ecs::reflect::ReflectComponent::dynamic(slot).unwrap());
+        drop(registry);
+        Ok(self)
+    }
+
     /// Retrieves a `SubApp` stored inside this [`App`].
     ///
     /// # Panics
@@ -999,14 +1295,43 @@ impl App {
 
         self
     }
+
+    /// Merges the systems and system sets of a plugin-provided `schedule` into the [`Schedule`]
+    /// associated with `label`, nesting them in the `namespace` set.
+    ///
+    /// See [`Schedule::merge`] for details.
+    ///
+    /// **Note:** This will create the schedule if it does not already exist.
+    pub fn merge_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+        namespace: impl SystemSet,
+        schedule: Schedule,
+    ) -> &mut Self {
+        let mut schedules = self.world.resource_mut::<Schedules>();
+
+        if schedules.get(&label).is_none() {
+            schedules.insert(label.dyn_clone(), Schedule::new());
+        }
+
+        schedules
+            .get_mut(&label)
+            .unwrap()
+            .merge(namespace, schedule);
+
+        self
+    }
 }
 
 fn run_once(mut app: App) {
     app.update();
+    app.shutdown();
 }
 
 /// An event that indicates the [`App`] should exit. This will fully exit the app process at the
-/// start of the next tick of the schedule.
+/// end of the current tick of the schedule, unless a system in [`CoreSchedule::ExitRequested`]
+/// vetoes or delays the exit, see [`AppExitRequested`]. [`CoreSchedule::Shutdown`] runs once
+/// before the runner returns.
 ///
 /// You can also use this event to detect that an exit was requested. In order to receive it, systems
 /// subscribing to this event should run after it was emitted and before the schedule of the same
@@ -1018,6 +1343,41 @@ fn run_once(mut app: App) {
 #[derive(Debug, Clone, Default)]
 pub struct AppExit;
 
+/// A [`Resource`] that is present while an [`AppExit`] is pending.
+///
+/// When an [`AppExit`] event is sent, the runner inserts this resource and runs
+/// [`CoreSchedule::ExitRequested`]. Systems in that schedule can [`veto`](Self::veto) the exit,
+/// which cancels it, or [`delay`](Self::delay) it, e.g. until a save has finished. A delayed exit
+/// is re-evaluated after each subsequent update, and systems in [`CoreSchedule::Main`] can check
+/// for the presence of this resource to react to the pending exit.
+///
+/// Once no system vetoes or delays the exit, [`CoreSchedule::Shutdown`] runs a single time and
+/// the runner returns.
+#[derive(Resource, Debug, Clone)]
+pub struct AppExitRequested {
+    exit: AppExit,
+    vetoed: bool,
+    delayed: bool,
+}
+
+impl AppExitRequested {
+    /// Returns the [`AppExit`] event that requested the exit.
+    pub fn exit(&self) -> &AppExit {
+        &self.exit
+    }
+
+    /// Cancels the pending exit. The app keeps running until another [`AppExit`] is sent.
+    pub fn veto(&mut self) {
+        self.vetoed = true;
+    }
+
+    /// Postpones the pending exit until after the next update, at which point
+    /// [`CoreSchedule::ExitRequested`] runs again.
+    pub fn delay(&mut self) {
+        self.delayed = true;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
@@ -1025,7 +1385,9 @@ mod tests {
         system::Commands,
     };
 
-    use crate::{App, IntoSystemAppConfig, IntoSystemAppConfigs, Plugin};
+    use crate::{
+        App, CoreSchedule, IntoSystemAppConfig, IntoSystemAppConfigs, Plugin, RerunStartup,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1068,6 +1430,108 @@ mod tests {
         App::new().add_plugin(PluginD).add_plugin(PluginD);
     }
 
+    #[test]
+    fn plugin_timings_exclude_nested_plugins() {
+        use crate::PluginTimings;
+        use std::time::Duration;
+
+        struct SlowPlugin;
+        impl Plugin for SlowPlugin {
+            fn build(&self, _: &mut crate::App) {
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            fn setup(&self, _: &mut crate::App) {
+                std::thread::sleep(Duration::from_millis(20));
+            }
+        }
+        struct ParentPlugin;
+        impl Plugin for ParentPlugin {
+            fn build(&self, app: &mut crate::App) {
+                app.add_plugin(SlowPlugin);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugin(ParentPlugin);
+        app.setup();
+
+        let timings = app.world.resource::<PluginTimings>();
+        let names: Vec<_> = timings.iter().map(|timing| timing.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                std::any::type_name::<SlowPlugin>(),
+                std::any::type_name::<ParentPlugin>()
+            ]
+        );
+
+        let slow = timings.get(std::any::type_name::<SlowPlugin>()).unwrap();
+        let parent = timings.get(std::any::type_name::<ParentPlugin>()).unwrap();
+        assert!(slow.build >= Duration::from_millis(20));
+        assert!(slow.setup >= Duration::from_millis(20));
+        assert!(parent.build < slow.build);
+        assert_eq!(timings.slowest().next(), Some(slow));
+    }
+
+    #[test]
+    fn plugin_build_order_includes_groups_and_nested_plugins() {
+        use crate::{PluginBuildEntry, PluginGroup, PluginGroupBuilder};
+
+        struct ParentPlugin;
+        impl Plugin for ParentPlugin {
+            fn build(&self, app: &mut crate::App) {
+                app.add_plugin(PluginD);
+            }
+        }
+        struct TestGroup;
+        impl PluginGroup for TestGroup {
+            fn build(self) -> PluginGroupBuilder {
+                PluginGroupBuilder::start::<Self>()
+                    .add(PluginB)
+                    .add_before::<PluginB, _>(ParentPlugin)
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugin(PluginA).add_plugins(TestGroup);
+
+        let group = Some(TestGroup::name());
+        assert_eq!(
+            app.plugin_build_order(),
+            [
+                PluginBuildEntry {
+                    name: std::any::type_name::<PluginA>().to_string(),
+                    group: None,
+                    depth: 0,
+                },
+                PluginBuildEntry {
+                    name: std::any::type_name::<ParentPlugin>().to_string(),
+                    group: group.clone(),
+                    depth: 0,
+                },
+                PluginBuildEntry {
+                    name: std::any::type_name::<PluginD>().to_string(),
+                    group: None,
+                    depth: 1,
+                },
+                PluginBuildEntry {
+                    name: std::any::type_name::<PluginB>().to_string(),
+                    group,
+                    depth: 0,
+                },
+            ]
+        );
+        app.assert_plugin_build_order(&["PluginA", "PluginD", "PluginB"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn plugin_build_order_assertion_fails_on_wrong_order() {
+        let mut app = App::new();
+        app.add_plugin(PluginA).add_plugin(PluginB);
+        app.assert_plugin_build_order(&["PluginB", "PluginA"]);
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -1136,4 +1600,136 @@ mod tests {
         app.world.run_schedule(OnEnter(AppState::MainMenu));
         assert_eq!(app.world.entities().len(), 2);
     }
+
+    #[test]
+    fn rerun_startup() {
+        use bevy_ecs::{
+            schedule::{common_conditions::run_once, IntoSystemConfig},
+            system::{ResMut, Resource},
+        };
+
+        #[derive(Resource, Default)]
+        struct Counts {
+            startup: usize,
+            once: usize,
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counts>()
+            .add_startup_system(|mut counts: ResMut<Counts>| counts.startup += 1)
+            .add_startup_system((|mut counts: ResMut<Counts>| counts.once += 1).run_if(run_once()));
+
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Counts>().startup, 1);
+
+        app.insert_resource(RerunStartup);
+        app.update();
+        app.update();
+        let counts = app.world.resource::<Counts>();
+        assert_eq!(counts.startup, 2);
+        assert_eq!(counts.once, 1);
+        assert!(!app.world.contains_resource::<RerunStartup>());
+    }
+
+    #[test]
+    fn ordered_startup_schedules() {
+        use bevy_ecs::{
+            schedule::ScheduleLabel,
+            system::{ResMut, Resource},
+        };
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Late;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Early;
+
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        let mut app = App::new();
+        app.init_resource::<Order>()
+            .add_startup_schedule(Late)
+            .add_startup_schedule_after(CoreSchedule::Startup, Early)
+            .add_system((|mut order: ResMut<Order>| order.0.push("late")).in_schedule(Late))
+            .add_system((|mut order: ResMut<Order>| order.0.push("early")).in_schedule(Early))
+            .add_startup_system(|mut order: ResMut<Order>| order.0.push("startup"))
+            .add_system(|mut order: ResMut<Order>| order.0.push("main"));
+
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world.resource::<Order>().0,
+            ["startup", "early", "late", "main", "main"]
+        );
+
+        app.world.resource_mut::<Order>().0.clear();
+        app.insert_resource(RerunStartup);
+        app.update();
+        assert_eq!(
+            app.world.resource::<Order>().0,
+            ["startup", "early", "late", "main"]
+        );
+    }
+
+    #[test]
+    fn exit_sequence() {
+        use crate::{AppExit, AppExitRequested, CoreSchedule};
+        use bevy_ecs::{
+            event::{EventWriter, ManualEventReader},
+            system::{Local, ResMut, Resource},
+        };
+
+        #[derive(Resource, Default)]
+        struct Counts {
+            requested: usize,
+            shutdown: usize,
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counts>()
+            .add_system(
+                (|mut exit: EventWriter<AppExit>, mut frame: Local<usize>| {
+                    *frame += 1;
+                    if *frame == 1 || *frame == 2 {
+                        exit.send(AppExit);
+                    }
+                })
+                .in_schedule(CoreSchedule::Main),
+            )
+            .add_system(
+                (|mut request: ResMut<AppExitRequested>, mut counts: ResMut<Counts>| {
+                    counts.requested += 1;
+                    match counts.requested {
+                        1 => request.veto(),
+                        2 => request.delay(),
+                        _ => {}
+                    }
+                })
+                .in_schedule(CoreSchedule::ExitRequested),
+            )
+            .add_system(
+                (|mut counts: ResMut<Counts>| counts.shutdown += 1)
+                    .in_schedule(CoreSchedule::Shutdown),
+            );
+        let mut reader = ManualEventReader::<AppExit>::default();
+
+        // The first exit is vetoed.
+        app.update();
+        assert!(app.poll_exit(&mut reader).is_none());
+        assert!(!app.world.contains_resource::<AppExitRequested>());
+
+        // The second exit is delayed for one update.
+        app.update();
+        assert!(app.poll_exit(&mut reader).is_none());
+        assert!(app.world.contains_resource::<AppExitRequested>());
+
+        app.update();
+        assert!(app.poll_exit(&mut reader).is_some());
+        assert!(!app.world.contains_resource::<AppExitRequested>());
+        let counts = app.world.resource::<Counts>();
+        assert_eq!(counts.requested, 3);
+        assert_eq!(counts.shutdown, 1);
+    }
 }
@@ -0,0 +1,192 @@
+use crate::{App, CoreSet, Plugin};
+use bevy_ecs::prelude::*;
+use std::{fmt, marker::PhantomData};
+
+/// How the component of a [`ComponentEvent`] changed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ComponentEventKind {
+    /// The component was added to the entity.
+    Added,
+    /// The component of the entity was changed, without being added since the last events were
+    /// sent.
+    Changed,
+    /// The component was removed from the entity, or the entity was despawned.
+    Removed,
+}
+
+/// An event sent by the [`ChangeEventsPlugin<T>`] when a `T` component was added to, changed on,
+/// or removed from an entity.
+pub struct ComponentEvent<T: Component> {
+    /// The entity whose component changed.
+    pub entity: Entity,
+    /// How the component changed.
+    pub kind: ComponentEventKind,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component> ComponentEvent<T> {
+    /// Creates a new [`ComponentEvent`] for the `entity`.
+    pub fn new(entity: Entity, kind: ComponentEventKind) -> Self {
+        Self {
+            entity,
+            kind,
+            marker: PhantomData,
+        }
+    }
+}
+
+// Implemented manually to not require `T` to implement these traits.
+impl<T: Component> Clone for ComponentEvent<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Component> Copy for ComponentEvent<T> {}
+
+impl<T: Component> PartialEq for ComponentEvent<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity && self.kind == other.kind
+    }
+}
+
+impl<T: Component> Eq for ComponentEvent<T> {}
+
+impl<T: Component> fmt::Debug for ComponentEvent<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentEvent")
+            .field("component", &std::any::type_name::<T>())
+            .field("entity", &self.entity)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+/// Sends a [`ComponentEvent<T>`] for each `T` component that was added, changed or removed since
+/// the system last ran.
+///
+/// A component that was added and changed again is only reported as added. Removals are sent
+/// first, so a component that was removed and inserted again is reported as removed and then as
+/// added, matching the fact that it is present.
+pub fn send_component_events<T: Component>(
+    changed: Query<(Entity, Ref<T>), Changed<T>>,
+    mut removed: RemovedComponents<T>,
+    mut events: EventWriter<ComponentEvent<T>>,
+) {
+    for entity in &mut removed {
+        events.send(ComponentEvent::new(entity, ComponentEventKind::Removed));
+    }
+    for (entity, component) in &changed {
+        let kind = if component.is_added() {
+            ComponentEventKind::Added
+        } else {
+            ComponentEventKind::Changed
+        };
+        events.send(ComponentEvent::new(entity, kind));
+    }
+}
+
+/// Converts the change detection of the `T` component into [`ComponentEvent<T>`] events, so that
+/// other crates can react to the lifecycle of `T` with an ordinary [`EventReader`].
+///
+/// The events are sent in [`CoreSet::Last`], so they cover all changes of the frame and can be
+/// read from the next frame on.
+///
+/// ```
+/// # use bevy_app::{prelude::*, ChangeEventsPlugin, ComponentEvent, ComponentEventKind};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn log_deaths(mut events: EventReader<ComponentEvent<Health>>) {
+///     for event in events.iter() {
+///         if event.kind == ComponentEventKind::Removed {
+///             println!("{:?} has no health anymore", event.entity);
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(ChangeEventsPlugin::<Health>::default())
+///     .add_system(log_deaths);
+/// ```
+pub struct ChangeEventsPlugin<T: Component>(PhantomData<fn() -> T>);
+
+impl<T: Component> Default for ChangeEventsPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component> Plugin for ChangeEventsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ComponentEvent<T>>()
+            .add_system(send_component_events::<T>.in_base_set(CoreSet::Last));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    fn events(app: &mut App) -> Vec<ComponentEvent<Health>> {
+        app.world
+            .resource_mut::<Events<ComponentEvent<Health>>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn component_events() {
+        let mut app = App::new();
+        app.add_plugin(ChangeEventsPlugin::<Health>::default());
+
+        let entity = app.world.spawn(Health(10)).id();
+        app.update();
+        assert_eq!(
+            events(&mut app),
+            [ComponentEvent::new(entity, ComponentEventKind::Added)]
+        );
+
+        app.update();
+        assert!(events(&mut app).is_empty());
+
+        app.world.get_mut::<Health>(entity).unwrap().0 = 5;
+        app.update();
+        assert_eq!(
+            events(&mut app),
+            [ComponentEvent::new(entity, ComponentEventKind::Changed)]
+        );
+
+        app.world.despawn(entity);
+        app.update();
+        assert_eq!(
+            events(&mut app),
+            [ComponentEvent::new(entity, ComponentEventKind::Removed)]
+        );
+    }
+
+    #[test]
+    fn reinserted_component_events() {
+        let mut app = App::new();
+        app.add_plugin(ChangeEventsPlugin::<Health>::default());
+
+        let entity = app.world.spawn(Health(10)).id();
+        app.update();
+        events(&mut app);
+
+        app.world.entity_mut(entity).remove::<Health>();
+        app.world.entity_mut(entity).insert(Health(20));
+        app.update();
+        assert_eq!(
+            events(&mut app),
+            [
+                ComponentEvent::new(entity, ComponentEventKind::Removed),
+                ComponentEvent::new(entity, ComponentEventKind::Added)
+            ]
+        );
+    }
+}
@@ -2,11 +2,12 @@ use bevy_ecs::{
     all_tuples,
     schedule::{
         BaseSystemSet, BoxedScheduleLabel, Condition, FreeSystemSet, IntoSystemConfig,
-        IntoSystemSet, ScheduleLabel, SystemConfig, SystemConfigs,
+        IntoSystemSet, ScheduleLabel, SystemConfig, SystemConfigs, ThreadAffinity,
     },
 };
 
 use crate::CoreSchedule;
+use std::borrow::Cow;
 
 /// A [`System`] with [`App`]-aware scheduling metadata.
 ///
@@ -125,6 +126,22 @@ impl IntoSystemConfig<(), Self> for SystemAppConfig {
         }
     }
 
+    fn before_and_flush<M>(self, set: impl IntoSystemSet<M>) -> Self {
+        let Self { system, schedule } = self;
+        Self {
+            system: system.before_and_flush(set),
+            schedule,
+        }
+    }
+
+    fn after_and_flush<M>(self, set: impl IntoSystemSet<M>) -> Self {
+        let Self { system, schedule } = self;
+        Self {
+            system: system.after_and_flush(set),
+            schedule,
+        }
+    }
+
     fn run_if<P>(self, condition: impl Condition<P>) -> Self {
         let Self { system, schedule } = self;
         Self {
@@ -148,6 +165,31 @@ impl IntoSystemConfig<(), Self> for SystemAppConfig {
             schedule,
         }
     }
+
+    fn with_local<T: Send + 'static>(self, value: T) -> Self {
+        let Self { system, schedule } = self;
+        Self {
+            system: system.with_local(value),
+            schedule,
+        }
+    }
+
+    fn on_thread(self, affinity: ThreadAffinity) -> Self {
+        let Self { system, schedule } = self;
+        Self {
+            system: system.on_thread(affinity),
+            schedule,
+        }
+    }
+
+    #[track_caller]
+    fn instance(self, label: impl Into<Cow<'static, str>>) -> Self {
+        let Self { system, schedule } = self;
+        Self {
+            system: system.instance(label),
+            schedule,
+        }
+    }
 }
 
 impl IntoSystemAppConfig<()> for SystemAppConfig {
@@ -0,0 +1,135 @@
+use bevy_ecs::prelude::*;
+use std::sync::{Arc, Mutex};
+
+type WakeFn = Arc<dyn Fn() + Send + Sync>;
+
+/// Wakes the runner of the [`App`](crate::App) if it is waiting for events, e.g. in the
+/// reactive update modes of `bevy_winit`.
+///
+/// The runner installs its wake function with [`AppWaker::set`], all clones of the waker share it.
+/// Waking does nothing if the runner doesn't support it.
+#[derive(Resource, Clone, Default)]
+pub struct AppWaker(Arc<Mutex<Option<WakeFn>>>);
+
+impl AppWaker {
+    /// Sets the function called by [`AppWaker::wake`].
+    pub fn set(&self, wake: impl Fn() + Send + Sync + 'static) {
+        *self.0.lock().unwrap() = Some(Arc::new(wake));
+    }
+
+    /// Wakes the runner, so the app is updated as soon as possible.
+    pub fn wake(&self) {
+        // don't hold the lock while waking
+        let wake = self.0.lock().unwrap().clone();
+        if let Some(wake) = wake {
+            wake();
+        }
+    }
+}
+
+/// A thread-safe handle to send `T` events to the [`App`](crate::App) from async tasks and OS
+/// callbacks, which wakes the app if it is sleeping.
+///
+/// The events are queued and sent in [`CoreSet::First`](crate::CoreSet::First) of the next update.
+/// Add the sender with [`App::add_event_sender`](crate::App::add_event_sender), and clone the
+/// resource into the tasks.
+///
+/// ```
+/// # use bevy_app::{prelude::*, AppEventSender};
+/// # use bevy_ecs::prelude::*;
+/// struct PacketReceived(Vec<u8>);
+///
+/// fn spawn_network_task(sender: Res<AppEventSender<PacketReceived>>) {
+///     let sender = sender.clone();
+///     std::thread::spawn(move || {
+///         // ... wait for a packet
+///         sender.send(PacketReceived(vec![42]));
+///     });
+/// }
+///
+/// App::new()
+///     .add_event_sender::<PacketReceived>()
+///     .add_startup_system(spawn_network_task);
+/// ```
+#[derive(Resource)]
+pub struct AppEventSender<T: Event> {
+    queue: Arc<Mutex<Vec<T>>>,
+    waker: AppWaker,
+}
+
+impl<T: Event> AppEventSender<T> {
+    pub(crate) fn new(waker: AppWaker) -> Self {
+        Self {
+            queue: Default::default(),
+            waker,
+        }
+    }
+
+    /// Queues the `event` and wakes the app.
+    pub fn send(&self, event: T) {
+        self.queue.lock().unwrap().push(event);
+        self.waker.wake();
+    }
+
+    /// Queues the `events` and wakes the app once.
+    pub fn send_batch(&self, events: impl IntoIterator<Item = T>) {
+        self.queue.lock().unwrap().extend(events);
+        self.waker.wake();
+    }
+}
+
+// Implemented manually to not require `T: Clone`.
+impl<T: Event> Clone for AppEventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            waker: self.waker.clone(),
+        }
+    }
+}
+
+/// Sends the events queued by the [`AppEventSender<T>`].
+pub fn send_queued_events<T: Event>(sender: Res<AppEventSender<T>>, mut events: EventWriter<T>) {
+    let queued = std::mem::take(&mut *sender.queue.lock().unwrap());
+    events.send_batch(queued);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, AppEventSender, AppWaker};
+    use bevy_ecs::event::{Events, ManualEventReader};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Packet(u32);
+
+    #[test]
+    fn events_are_sent_from_other_threads() {
+        let mut app = App::new();
+        app.add_event_sender::<Packet>();
+
+        let wakes = Arc::new(AtomicUsize::new(0));
+        let wake_count = wakes.clone();
+        app.world.resource::<AppWaker>().set(move || {
+            wake_count.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let sender = app.world.resource::<AppEventSender<Packet>>().clone();
+        std::thread::spawn(move || {
+            sender.send(Packet(1));
+            sender.send_batch([Packet(2), Packet(3)]);
+        })
+        .join()
+        .unwrap();
+        assert_eq!(wakes.load(Ordering::Relaxed), 2);
+
+        app.update();
+        let events = app.world.resource::<Events<Packet>>();
+        let mut reader = ManualEventReader::default();
+        let received: Vec<_> = reader.iter(events).collect();
+        assert_eq!(received, [&Packet(1), &Packet(2), &Packet(3)]);
+    }
+}
@@ -3,20 +3,28 @@
 #![warn(missing_docs)]
 
 mod app;
+mod change_events;
 mod config;
+mod event_sender;
 mod plugin;
 mod plugin_group;
+mod plugin_timings;
 mod schedule_runner;
+mod stable_abi;
 
 #[cfg(feature = "bevy_ci_testing")]
 mod ci_testing;
 
 pub use app::*;
 pub use bevy_derive::DynamicPlugin;
+pub use change_events::*;
 pub use config::*;
+pub use event_sender::*;
 pub use plugin::*;
 pub use plugin_group::*;
+pub use plugin_timings::*;
 pub use schedule_runner::*;
+pub use stable_abi::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
@@ -27,16 +35,17 @@ pub mod prelude {
     pub use crate::{
         app::App,
         config::{IntoSystemAppConfig, IntoSystemAppConfigs},
-        CoreSchedule, CoreSet, DynamicPlugin, Plugin, PluginGroup, StartupSet,
+        CoreSchedule, CoreSet, DynamicPlugin, Plugin, PluginGroup, RerunStartup, StartupSchedules,
+        StartupSet,
     };
 }
 
 use bevy_ecs::{
     schedule::{
-        apply_system_buffers, IntoSystemConfig, IntoSystemSetConfigs, Schedule, ScheduleLabel,
-        SystemSet,
+        apply_system_buffers, BoxedScheduleLabel, IntoSystemConfig, IntoSystemSetConfigs, Schedule,
+        ScheduleLabel, SystemSet,
     },
-    system::Local,
+    system::{Local, Resource},
     world::World,
 };
 
@@ -58,6 +67,16 @@ pub enum CoreSchedule {
     ///
     /// The exclusive `run_fixed_update_schedule` system runs this schedule during the [`CoreSet::FixedUpdate`] system set.
     FixedUpdate,
+    /// The schedule that runs after an [`AppExit`](crate::AppExit) was sent, while the
+    /// [`AppExitRequested`](crate::AppExitRequested) resource is present.
+    ///
+    /// Systems in this schedule can veto or delay the exit.
+    ExitRequested,
+    /// The schedule that runs once before the runner returns, after an exit was requested and
+    /// not vetoed or delayed.
+    ///
+    /// Use this for teardown logic, such as flushing saves or disconnecting from the network.
+    Shutdown,
 }
 
 impl CoreSchedule {
@@ -65,10 +84,24 @@ impl CoreSchedule {
     ///
     /// [`CoreSchedule::Main`] is always run.
     ///
-    /// If this is the first time this system has been run, [`CoreSchedule::Startup`] will run before [`CoreSchedule::Main`].
+    /// If this is the first time this system has been run, or if the [`RerunStartup`] resource is present,
+    /// the schedules listed in [`StartupSchedules`] will run in order before [`CoreSchedule::Main`].
+    /// Without that resource, only [`CoreSchedule::Startup`] runs.
     pub fn outer_loop(world: &mut World, mut run_at_least_once: Local<bool>) {
-        if !*run_at_least_once {
-            world.run_schedule(CoreSchedule::Startup);
+        let rerun_startup = world.remove_resource::<RerunStartup>().is_some();
+        if !*run_at_least_once || rerun_startup {
+            match world.get_resource::<StartupSchedules>() {
+                Some(startup_schedules) => {
+                    let labels: Vec<BoxedScheduleLabel> = startup_schedules
+                        .iter()
+                        .map(|label| label.dyn_clone())
+                        .collect();
+                    for label in &labels {
+                        world.run_schedule_ref(&**label);
+                    }
+                }
+                None => world.run_schedule(CoreSchedule::Startup),
+            }
             *run_at_least_once = true;
         }
 
@@ -84,6 +117,78 @@ impl CoreSchedule {
     }
 }
 
+/// Requests that [`CoreSchedule::Startup`] runs again before the next run of [`CoreSchedule::Main`].
+///
+/// Insert this resource (e.g. with `commands.insert_resource(RerunStartup)`) to set up
+/// the world from scratch again, for example after clearing all entities.
+/// It is removed as soon as [`CoreSchedule::outer_loop`] picks it up.
+///
+/// Run conditions on startup systems are evaluated again on every run of the startup schedule,
+/// so systems that should only ever run once can use [`run_once`](bevy_ecs::schedule::common_conditions::run_once).
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct RerunStartup;
+
+/// The startup schedules run by [`CoreSchedule::outer_loop`], in the order they run.
+///
+/// This starts out containing only [`CoreSchedule::Startup`]. Additional schedules are usually
+/// added with [`App::add_startup_schedule`] or [`App::add_startup_schedule_after`],
+/// which also create the schedule if it does not exist yet.
+///
+/// All startup schedules run again when [`RerunStartup`] is inserted.
+#[derive(Resource, Debug)]
+pub struct StartupSchedules {
+    labels: Vec<BoxedScheduleLabel>,
+}
+
+impl Default for StartupSchedules {
+    fn default() -> Self {
+        Self {
+            labels: vec![Box::new(CoreSchedule::Startup)],
+        }
+    }
+}
+
+impl StartupSchedules {
+    /// Adds the schedule with the given `label` to the end of the startup schedules.
+    ///
+    /// Does nothing if the schedule is already a startup schedule.
+    pub fn push(&mut self, label: impl ScheduleLabel) {
+        if !self.contains(&label) {
+            self.labels.push(Box::new(label));
+        }
+    }
+
+    /// Adds the schedule with the given `label` right after the `after` schedule.
+    ///
+    /// Does nothing if the schedule is already a startup schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `after` is not a startup schedule.
+    pub fn insert_after(&mut self, after: impl ScheduleLabel, label: impl ScheduleLabel) {
+        if self.contains(&label) {
+            return;
+        }
+        let after: &dyn ScheduleLabel = &after;
+        let index = self
+            .labels
+            .iter()
+            .position(|current| **current == *after)
+            .unwrap_or_else(|| panic!("{after:?} is not a startup schedule"));
+        self.labels.insert(index + 1, Box::new(label));
+    }
+
+    /// Returns `true` if the schedule with the given `label` is a startup schedule.
+    pub fn contains(&self, label: &dyn ScheduleLabel) -> bool {
+        self.labels.iter().any(|current| &**current == label)
+    }
+
+    /// Returns an iterator over the labels of the startup schedules, in the order they run.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn ScheduleLabel> {
+        self.labels.iter().map(|label| &**label)
+    }
+}
+
 /// The names of the default [`App`] system sets.
 ///
 /// These are ordered in the same order they are listed.
@@ -173,6 +173,7 @@ impl PluginGroupBuilder {
     ///
     /// Panics if one of the plugin in the group was already added to the application.
     pub fn finish(mut self, app: &mut App) {
+        let outer_group = app.adding_plugin_group.replace(self.group_name.clone());
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty) {
                 if entry.enabled {
@@ -189,6 +190,7 @@ impl PluginGroupBuilder {
                 }
             }
         }
+        app.adding_plugin_group = outer_group;
     }
 }
 
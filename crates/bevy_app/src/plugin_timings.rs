@@ -0,0 +1,113 @@
+use bevy_ecs::system::Resource;
+use bevy_utils::Duration;
+use std::{cmp::Reverse, fmt};
+
+/// The time spent in [`Plugin::build`](crate::Plugin::build) and
+/// [`Plugin::setup`](crate::Plugin::setup) of a single plugin.
+///
+/// The durations only include the time spent in the plugin itself: the time spent building
+/// plugins that it adds in its own [`Plugin::build`](crate::Plugin::build) is attributed to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginTiming {
+    /// The [name](crate::Plugin::name) of the plugin.
+    pub name: String,
+    /// The time spent in [`Plugin::build`](crate::Plugin::build).
+    pub build: Duration,
+    /// The time spent in [`Plugin::setup`](crate::Plugin::setup).
+    pub setup: Duration,
+}
+
+impl PluginTiming {
+    /// Returns the total time spent building and setting up the plugin.
+    pub fn total(&self) -> Duration {
+        self.build + self.setup
+    }
+}
+
+/// A [`Resource`] that records how long each plugin of an [`App`](crate::App) took to build and
+/// set up, so slow startup can be attributed to specific plugins.
+///
+/// It is updated whenever a plugin is added and when [`App::setup`](crate::App::setup) runs.
+/// Once the plugins are set up, a summary is logged at the `DEBUG` level.
+///
+/// ```
+/// # use bevy_app::{prelude::*, PluginTimings};
+/// struct SlowPlugin;
+///
+/// impl Plugin for SlowPlugin {
+///     fn build(&self, _app: &mut App) {
+///         std::thread::sleep(std::time::Duration::from_millis(10));
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugin(SlowPlugin);
+///
+/// let timings = app.world.resource::<PluginTimings>();
+/// let slowest = timings.slowest().next().unwrap();
+/// assert_eq!(slowest.name, std::any::type_name::<SlowPlugin>());
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PluginTimings {
+    timings: Vec<PluginTiming>,
+}
+
+impl PluginTimings {
+    /// Returns the timings of all plugins, in the order they were added.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &PluginTiming> {
+        self.timings.iter()
+    }
+
+    /// Returns the timings of all plugins, from slowest to fastest.
+    pub fn slowest(&self) -> impl Iterator<Item = &PluginTiming> {
+        let mut timings: Vec<_> = self.timings.iter().collect();
+        timings.sort_by_key(|timing| Reverse(timing.total()));
+        timings.into_iter()
+    }
+
+    /// Returns the timing of the first plugin with the given [name](crate::Plugin::name).
+    pub fn get(&self, name: &str) -> Option<&PluginTiming> {
+        self.timings.iter().find(|timing| timing.name == name)
+    }
+
+    /// Returns the total time spent building and setting up plugins.
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(PluginTiming::total).sum()
+    }
+
+    pub(crate) fn record_build(&mut self, name: &str, build: Duration) {
+        self.timings.push(PluginTiming {
+            name: name.to_string(),
+            build,
+            setup: Duration::ZERO,
+        });
+    }
+
+    pub(crate) fn record_setup(&mut self, index: usize, setup: Duration) {
+        if let Some(timing) = self.timings.get_mut(index) {
+            timing.setup = setup;
+        }
+    }
+}
+
+impl fmt::Display for PluginTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} plugins built and set up in {:?}",
+            self.timings.len(),
+            self.total()
+        )?;
+        for timing in self.slowest() {
+            write!(
+                f,
+                "\n{:>12.3?} (build {:.3?}, setup {:.3?}) {}",
+                timing.total(),
+                timing.build,
+                timing.setup,
+                timing.name
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     app::{App, AppExit},
     plugin::Plugin,
 };
-use bevy_ecs::event::{Events, ManualEventReader};
+use bevy_ecs::event::ManualEventReader;
 use bevy_ecs::prelude::Resource;
 use bevy_utils::{Duration, Instant};
 
@@ -85,6 +85,7 @@ impl Plugin for ScheduleRunnerPlugin {
             match settings.run_mode {
                 RunMode::Once => {
                     app.update();
+                    app.shutdown();
                 }
                 RunMode::Loop { wait } => {
                     let mut tick = move |app: &mut App,
@@ -92,24 +93,10 @@ impl Plugin for ScheduleRunnerPlugin {
                           -> Result<Option<Duration>, AppExit> {
                         let start_time = Instant::now();
 
-                        if let Some(app_exit_events) =
-                            app.world.get_resource_mut::<Events<AppExit>>()
-                        {
-                            if let Some(exit) = app_exit_event_reader.iter(&app_exit_events).last()
-                            {
-                                return Err(exit.clone());
-                            }
-                        }
-
                         app.update();
 
-                        if let Some(app_exit_events) =
-                            app.world.get_resource_mut::<Events<AppExit>>()
-                        {
-                            if let Some(exit) = app_exit_event_reader.iter(&app_exit_events).last()
-                            {
-                                return Err(exit.clone());
-                            }
+                        if let Some(exit) = app.poll_exit(&mut app_exit_event_reader) {
+                            return Err(exit);
                         }
 
                         let end_time = Instant::now();
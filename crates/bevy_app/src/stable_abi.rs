@@ -0,0 +1,438 @@
+use std::{ffi::c_void, marker::PhantomData};
+
+/// The version of the [`AppVTable`] layout, plugins are only loaded if it matches the host.
+///
+/// Incremented whenever the [`AppVTable`] changes.
+pub const PLUGIN_ABI_VERSION: u32 = 2;
+
+/// The version of the engine, plugins are only loaded if it matches the host.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Declares the types crossing the library boundary, and derives [`ABI_HASH`] from their
+/// declarations without their doc comments.
+macro_rules! abi_types {
+    ($($item:item)*) => {
+        $($item)*
+
+        /// A hash of the declarations of all types crossing the library boundary.
+        ///
+        /// Unlike [`PLUGIN_ABI_VERSION`] and [`ENGINE_VERSION`], it changes with every edit of
+        /// the declarations, so plugins built from another revision of the engine with a
+        /// different ABI are rejected even if the versions match. Doc comments are ignored, as
+        /// they don't affect the ABI.
+        pub const ABI_HASH: u64 = abi_hash(stringify!($($item)*).as_bytes());
+    };
+}
+
+/// Hashes the stringified `declarations` with FNV-1a, skipping the `#[doc = ...]` attributes
+/// doc comments are stringified to.
+///
+/// Runs of whitespace are hashed as a single space, as `stringify!` breaks lines depending on
+/// the length of the declarations, and therefore on their docs.
+const fn abi_hash(declarations: &[u8]) -> u64 {
+    const DOC_ATTRIBUTE: &[u8] = b"#[doc = ";
+
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < declarations.len() {
+        if starts_with_at(declarations, i, DOC_ATTRIBUTE) {
+            i = skip_doc_attribute(declarations, i + DOC_ATTRIBUTE.len());
+            continue;
+        }
+        let byte = if declarations[i].is_ascii_whitespace() {
+            while i + 1 < declarations.len() && declarations[i + 1].is_ascii_whitespace() {
+                i += 1;
+            }
+            b' '
+        } else {
+            declarations[i]
+        };
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+const fn starts_with_at(bytes: &[u8], start: usize, prefix: &[u8]) -> bool {
+    if start + prefix.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[start + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the index after the string literal starting at `i`, the closing `]` of the attribute
+/// and the following whitespace. The literal is either a raw string, as doc comments are
+/// stringified to, or a string with escapes.
+const fn skip_doc_attribute(bytes: &[u8], mut i: usize) -> usize {
+    if bytes[i] == b'r' {
+        i += 1;
+        let mut hashes = 0;
+        while bytes[i] == b'#' {
+            hashes += 1;
+            i += 1;
+        }
+        // the opening quote
+        i += 1;
+        loop {
+            if bytes[i] == b'"' && i + hashes < bytes.len() {
+                let mut closing = 0;
+                while closing < hashes && bytes[i + 1 + closing] == b'#' {
+                    closing += 1;
+                }
+                if closing == hashes {
+                    i += 1 + hashes;
+                    break;
+                }
+            }
+            i += 1;
+        }
+    } else {
+        i += 1;
+        while bytes[i] != b'"' {
+            if bytes[i] == b'\\' {
+                i += 1;
+            }
+            i += 1;
+        }
+        i += 1;
+    }
+    // the closing bracket
+    i += 1;
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+abi_types! {
+    /// A borrowed UTF-8 string passed across the library boundary.
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct AbiStr<'a> {
+        ptr: *const u8,
+        len: usize,
+        marker: PhantomData<&'a str>,
+    }
+
+    /// A field of a component type registered with [`StableApp::register_component`].
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct AbiField<'a> {
+        /// The name of the field.
+        pub name: AbiStr<'a>,
+        /// The registered name of the field's type.
+        pub type_name: AbiStr<'a>,
+    }
+
+    /// The information about a [`StablePlugin`] checked by the host before building it.
+    ///
+    /// It is written through a pointer by `_bevy_stable_plugin_info`, which is only called once
+    /// the [`PLUGIN_ABI_VERSION`] and the [`ABI_HASH`] of the plugin are known to match.
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct StablePluginInfo {
+        /// The [`ENGINE_VERSION`] the plugin was compiled with.
+        pub engine_version: AbiStr<'static>,
+        /// The name of the plugin.
+        pub name: AbiStr<'static>,
+    }
+
+    /// The functions a [`StablePlugin`] uses to interact with the host [`App`](crate::App).
+    ///
+    /// Fields are only ever appended, together with an increment of [`PLUGIN_ABI_VERSION`].
+    #[repr(C)]
+    pub struct AppVTable {
+        /// The [`PLUGIN_ABI_VERSION`] of the host.
+        pub abi_version: u32,
+        /// The host app, only passed back to the functions of the table.
+        pub app: *mut c_void,
+        /// Returns `true` if a type with the name is registered in the `AppTypeRegistry`.
+        pub is_type_registered: unsafe extern "C" fn(app: *mut c_void, type_name: AbiStr) -> bool,
+        /// Deserializes the RON `value` as the registered type with the name, and inserts it as a
+        /// resource. Returns `false` if the type isn't a registered reflected resource, or the
+        /// value can't be deserialized.
+        pub insert_resource:
+            unsafe extern "C" fn(app: *mut c_void, type_name: AbiStr, value: AbiStr) -> bool,
+        /// Logs the message with the host's logger. The `level` is a [`StableLogLevel`], which
+        /// is validated by the host.
+        pub log: unsafe extern "C" fn(app: *mut c_void, level: u8, message: AbiStr),
+        /// Registers a component type with the name and the `field_count` fields pointed to by
+        /// `fields`. Returns `false` if the type can't be registered.
+        pub register_component: unsafe extern "C" fn(
+            app: *mut c_void,
+            type_name: AbiStr,
+            fields: *const AbiField,
+            field_count: usize,
+        ) -> bool,
+    }
+}
+
+impl<'a> AbiStr<'a> {
+    /// Borrows the `string`.
+    pub const fn new(string: &'a str) -> Self {
+        Self {
+            ptr: string.as_ptr(),
+            len: string.len(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the string.
+    ///
+    /// # Safety
+    ///
+    /// The string must have been created with [`AbiStr::new`], or point to `len` bytes of valid
+    /// UTF-8 which live for `'a`.
+    pub unsafe fn as_str(&self) -> &'a str {
+        std::str::from_utf8_unchecked(std::slice::from_raw_parts(self.ptr, self.len))
+    }
+}
+
+impl StablePluginInfo {
+    /// Creates the info of the plugin `P`.
+    pub fn new<P: StablePlugin>() -> Self {
+        Self {
+            engine_version: AbiStr::new(ENGINE_VERSION),
+            name: AbiStr::new(P::NAME),
+        }
+    }
+}
+
+/// The severity of a message logged with [`StableApp::log`].
+///
+/// It crosses the library boundary as a `u8`, see [`StableLogLevel::from_u8`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StableLogLevel {
+    /// Logged with `error!`.
+    Error,
+    /// Logged with `warn!`.
+    Warn,
+    /// Logged with `info!`.
+    Info,
+    /// Logged with `debug!`.
+    Debug,
+}
+
+impl StableLogLevel {
+    /// Returns the level with the discriminant `level`, or `None` if it is out of range.
+    pub fn from_u8(level: u8) -> Option<Self> {
+        match level {
+            0 => Some(Self::Error),
+            1 => Some(Self::Warn),
+            2 => Some(Self::Info),
+            3 => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
+
+/// A safe wrapper of the [`AppVTable`] passed to [`StablePlugin::build`].
+pub struct StableApp<'a> {
+    vtable: &'a AppVTable,
+}
+
+impl<'a> StableApp<'a> {
+    /// Wraps the `vtable`.
+    ///
+    /// # Safety
+    ///
+    /// The functions of the `vtable` must be safe to call with its `app`, which is the case for
+    /// the tables created by the host when building the plugin.
+    pub unsafe fn from_vtable(vtable: &'a AppVTable) -> Self {
+        Self { vtable }
+    }
+
+    /// Returns `true` if a type with the `type_name` is registered in the host.
+    pub fn is_type_registered(&self, type_name: &str) -> bool {
+        // SAFETY: guaranteed by `from_vtable`
+        unsafe { (self.vtable.is_type_registered)(self.vtable.app, AbiStr::new(type_name)) }
+    }
+
+    /// Inserts the resource with the registered `type_name`, deserialized from the RON `value`.
+    ///
+    /// Returns `false` if the type isn't a registered reflected resource, or the value can't be
+    /// deserialized.
+    pub fn insert_resource(&self, type_name: &str, value: &str) -> bool {
+        // SAFETY: guaranteed by `from_vtable`
+        unsafe {
+            (self.vtable.insert_resource)(
+                self.vtable.app,
+                AbiStr::new(type_name),
+                AbiStr::new(value),
+            )
+        }
+    }
+
+    /// Logs the `message` with the host's logger.
+    pub fn log(&self, level: StableLogLevel, message: &str) {
+        // SAFETY: guaranteed by `from_vtable`
+        unsafe { (self.vtable.log)(self.vtable.app, level as u8, AbiStr::new(message)) }
+    }
+
+    /// Registers a component type with the `type_name` and the `fields`, given as pairs of field
+    /// names and registered type names, so its values can be inserted by name, e.g. by scenes.
+    ///
+    /// Returns `false` if the type can't be registered, e.g. because the name is taken or a
+    /// field type isn't registered.
+    pub fn register_component(&self, type_name: &str, fields: &[(&str, &str)]) -> bool {
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|(name, type_name)| AbiField {
+                name: AbiStr::new(name),
+                type_name: AbiStr::new(type_name),
+            })
+            .collect();
+        // SAFETY: guaranteed by `from_vtable`
+        unsafe {
+            (self.vtable.register_component)(
+                self.vtable.app,
+                AbiStr::new(type_name),
+                fields.as_ptr(),
+                fields.len(),
+            )
+        }
+    }
+}
+
+/// An opt-in dynamic plugin which only interacts with the host through a stable ABI.
+///
+/// Plugins derived with `DynamicPlugin` exchange Rust trait objects with the host, which is
+/// undefined behavior unless both are built by the same compiler from the same engine sources.
+/// A [`StablePlugin`] instead only crosses the library boundary through `#[repr(C)]` types and
+/// `extern "C"` functions:
+///
+/// - `_bevy_stable_plugin_abi_version` and `_bevy_stable_plugin_abi_hash` return the
+///   [`PLUGIN_ABI_VERSION`] and the [`ABI_HASH`] of the plugin. They are called before anything
+///   else, as their signatures never change, so mismatched plugins are rejected with a meaningful
+///   error instead of undefined behavior.
+/// - `_bevy_stable_plugin_info` writes the [`StablePluginInfo`] of the plugin through a pointer,
+///   and the host checks its [`ENGINE_VERSION`].
+/// - `_bevy_stable_plugin_build` builds the plugin with an [`AppVTable`], a versioned table of
+///   functions to interact with the host [`App`](crate::App).
+///
+/// Types are referred to by their registered type name instead of their `TypeId`, which isn't
+/// stable across compilations. All functions are exported by [`export_stable_plugin!`], and
+/// loaded by `bevy_dynamic_plugin::dynamically_load_stable_plugin`.
+///
+/// ```
+/// # use bevy_app::{export_stable_plugin, StableApp, StableLogLevel, StablePlugin};
+/// #[derive(Default)]
+/// struct DifficultyMod;
+///
+/// impl StablePlugin for DifficultyMod {
+///     const NAME: &'static str = "difficulty_mod";
+///
+///     fn build(&self, app: &StableApp) {
+///         app.register_component("difficulty_mod::Elite", &[("health_factor", "f32")]);
+///         if !app.insert_resource("my_game::Difficulty", "(enemy_health: 2.0)") {
+///             app.log(StableLogLevel::Error, "the game doesn't support difficulty settings");
+///         }
+///     }
+/// }
+///
+/// export_stable_plugin!(DifficultyMod);
+/// ```
+pub trait StablePlugin: Default {
+    /// The name of the plugin, reported to the host.
+    const NAME: &'static str;
+
+    /// Configures the host app.
+    fn build(&self, app: &StableApp);
+}
+
+/// Exports the `extern "C"` entry points of a [`StablePlugin`], which has to implement
+/// [`Default`]. Only one plugin can be exported per library.
+#[macro_export]
+macro_rules! export_stable_plugin {
+    ($plugin:ty) => {
+        #[no_mangle]
+        pub extern "C" fn _bevy_stable_plugin_abi_version() -> u32 {
+            $crate::PLUGIN_ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn _bevy_stable_plugin_abi_hash() -> u64 {
+            $crate::ABI_HASH
+        }
+
+        /// # Safety
+        ///
+        /// `info` must be valid for writes of a [`StablePluginInfo`]($crate::StablePluginInfo).
+        #[no_mangle]
+        pub unsafe extern "C" fn _bevy_stable_plugin_info(info: *mut $crate::StablePluginInfo) {
+            info.write($crate::StablePluginInfo::new::<$plugin>());
+        }
+
+        /// # Safety
+        ///
+        /// `vtable` must point to a valid [`AppVTable`]($crate::AppVTable) created by the host.
+        #[no_mangle]
+        pub unsafe extern "C" fn _bevy_stable_plugin_build(vtable: *const $crate::AppVTable) {
+            let app = $crate::StableApp::from_vtable(&*vtable);
+            <$plugin as $crate::StablePlugin>::build(&<$plugin as Default>::default(), &app);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    mod documented {
+        use crate::stable_abi::*;
+
+        abi_types! {
+            /// A "documented" type, with a ] and a \ in its docs.
+            #[repr(C)]
+            pub struct Foo {
+                /// The first field.
+                pub a: u32,
+                #[doc = "The \"second\" field ]"]
+                pub b: u8,
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    mod undocumented {
+        use crate::stable_abi::*;
+
+        abi_types! {
+            #[repr(C)]
+            pub struct Foo {
+                pub a: u32,
+                pub b: u8,
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    mod changed {
+        use crate::stable_abi::*;
+
+        abi_types! {
+            /// A "documented" type, with a ] and a \ in its docs.
+            #[repr(C)]
+            pub struct Foo {
+                /// The first field.
+                pub a: u64,
+                #[doc = "The \"second\" field ]"]
+                pub b: u8,
+            }
+        }
+    }
+
+    #[test]
+    fn abi_hash_ignores_doc_comments() {
+        assert_eq!(documented::ABI_HASH, undocumented::ABI_HASH);
+        assert_ne!(documented::ABI_HASH, changed::ABI_HASH);
+    }
+}
@@ -2,16 +2,19 @@ use crate::{
     path::{AssetPath, AssetPathId, SourcePathId},
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
     AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    PendingUnload, RefChange, RefChangeChannel, SourceInfo, SourceMeta, UnloadPolicy,
 };
 use anyhow::Result;
 use bevy_ecs::system::{Res, ResMut, Resource};
 use bevy_log::warn;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
+use bevy_utils::{Entry, HashMap, HashSet, Instant, Uuid};
 use crossbeam_channel::TryRecvError;
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`.
@@ -38,6 +41,10 @@ pub enum AssetServerError {
     #[error("encountered an error while loading an asset: {0}")]
     AssetLoaderError(anyhow::Error),
 
+    /// Encountered an error while processing an asset with an [`AssetProcessor`](crate::AssetProcessor).
+    #[error("encountered an error while processing an asset: {0}")]
+    AssetProcessorError(anyhow::Error),
+
     /// Encountered an error while reading an asset from disk.
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
@@ -60,6 +67,7 @@ pub(crate) struct AssetRefCounter {
     pub(crate) channel: Arc<RefChangeChannel>,
     pub(crate) ref_counts: Arc<RwLock<HashMap<HandleId, usize>>>,
     pub(crate) mark_unused_assets: Arc<Mutex<Vec<HandleId>>>,
+    pub(crate) pending_unloads: Arc<Mutex<HashMap<HandleId, PendingUnload>>>,
 }
 
 /// Internal data for the asset server.
@@ -73,6 +81,10 @@ pub struct AssetServerInternal {
     loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    /// Maps the source of each dependency to the sources of the assets that depend on it.
+    dependents: RwLock<HashMap<SourcePathId, HashSet<AssetPath<'static>>>>,
+    pub(crate) processed_asset_folder: RwLock<Option<PathBuf>>,
+    pub(crate) unload_policies: RwLock<HashMap<Uuid, UnloadPolicy>>,
 }
 
 /// Loads assets from the filesystem in the background.
@@ -132,6 +144,9 @@ impl AssetServer {
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
                 asset_lifecycles: Default::default(),
+                dependents: Default::default(),
+                processed_asset_folder: Default::default(),
+                unload_policies: Default::default(),
                 asset_io,
             }),
         }
@@ -308,6 +323,7 @@ impl AssetServer {
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
+        dependency_modified: bool,
     ) -> Result<AssetPathId, AssetServerError> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
@@ -324,6 +340,8 @@ impl AssetServer {
                     meta: None,
                     path: asset_path.path().to_owned(),
                     version: 0,
+                    dependency_modified: false,
+                    bytes: None,
                 }),
             };
 
@@ -341,6 +359,7 @@ impl AssetServer {
             source_info.committed_assets.clear();
             source_info.version += 1;
             source_info.meta = None;
+            source_info.dependency_modified = dependency_modified;
             source_info.version
         };
 
@@ -370,6 +389,33 @@ impl AssetServer {
             }
         };
 
+        let source_len = bytes.len();
+
+        // process the asset source if the loader declares a processing step, and load the
+        // processed artifact with the loader of its extension instead
+        let (asset_loader, bytes) = match asset_loader.processor() {
+            Some(processor) => {
+                let processed = match self
+                    .process_asset(processor, asset_path.path(), &bytes)
+                    .await
+                {
+                    Ok(processed) => processed,
+                    Err(err) => {
+                        set_asset_failed();
+                        return Err(err);
+                    }
+                };
+                match self.get_asset_loader(processor.processed_extension()) {
+                    Ok(loader) => (loader, processed),
+                    Err(err) => {
+                        set_asset_failed();
+                        return Err(err);
+                    }
+                }
+            }
+            None => (asset_loader, bytes),
+        };
+
         // load the asset source using the corresponding AssetLoader
         let mut load_context = LoadContext::new(
             asset_path.path(),
@@ -397,6 +443,8 @@ impl AssetServer {
             return Ok(asset_path_id);
         }
 
+        source_info.bytes = Some(source_len);
+
         // if all assets have been committed already (aka there were 0), set state to "Loaded"
         if source_info.is_loaded() {
             source_info.load_state = LoadState::Loaded;
@@ -412,22 +460,54 @@ impl AssetServer {
         });
 
         // load asset dependencies and prepare asset type hashmap
+        let source_path = AssetPath::new(asset_path.path().to_owned(), None);
+        let mut dependents = self.server.dependents.write();
+        for sources in dependents.values_mut() {
+            sources.remove(&source_path);
+        }
         for (label, loaded_asset) in &mut load_context.labeled_assets {
             let label_id = LabelId::from(label.as_ref().map(|label| label.as_str()));
             let type_uuid = loaded_asset.value.as_ref().unwrap().type_uuid();
             source_info.asset_types.insert(label_id, type_uuid);
             for dependency in &loaded_asset.dependencies {
+                dependents
+                    .entry(dependency.get_id().source_path_id())
+                    .or_default()
+                    .insert(source_path.clone());
                 self.load_untracked(dependency.clone(), false);
             }
         }
+        drop(dependents);
 
         self.asset_io()
             .watch_path_for_changes(asset_path.path(), None)
             .unwrap();
         self.create_assets_in_load_context(&mut load_context);
+
+        // a reload of this source should also reload everything that depends on it
+        if force && !dependency_modified {
+            self.reload_dependents(asset_path_id.source_path_id());
+        }
         Ok(asset_path_id)
     }
 
+    /// Reloads all sources that directly or indirectly depend on the given source.
+    fn reload_dependents(&self, source_path_id: SourcePathId) {
+        let dependents = self.server.dependents.read();
+        let mut visited = HashSet::default();
+        visited.insert(source_path_id);
+        let mut queue = vec![source_path_id];
+        while let Some(source_path_id) = queue.pop() {
+            for dependent in dependents.get(&source_path_id).into_iter().flatten() {
+                let dependent_id = dependent.get_id().source_path_id();
+                if visited.insert(dependent_id) {
+                    queue.push(dependent_id);
+                    self.spawn_load(dependent.clone(), true, true);
+                }
+            }
+        }
+    }
+
     /// Queues the [`Asset`] at the provided path for loading and returns an untyped handle.
     ///
     /// See [`load`](AssetServer::load).
@@ -446,15 +526,7 @@ impl AssetServer {
     }
 
     pub(crate) fn load_untracked(&self, asset_path: AssetPath<'_>, force: bool) -> HandleId {
-        let server = self.clone();
-        let owned_path = asset_path.to_owned();
-        IoTaskPool::get()
-            .spawn(async move {
-                if let Err(err) = server.load_async(owned_path, force).await {
-                    warn!("{}", err);
-                }
-            })
-            .detach();
+        self.spawn_load(asset_path.to_owned(), force, false);
 
         let handle_id = asset_path.get_id().into();
         self.server
@@ -466,6 +538,20 @@ impl AssetServer {
         asset_path.into()
     }
 
+    fn spawn_load(&self, asset_path: AssetPath<'static>, force: bool, dependency_modified: bool) {
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                if let Err(err) = server
+                    .load_async(asset_path, force, dependency_modified)
+                    .await
+                {
+                    warn!("{}", err);
+                }
+            })
+            .detach();
+    }
+
     /// Loads assets from the specified folder recursively.
     ///
     /// # Errors
@@ -503,14 +589,17 @@ impl AssetServer {
         Ok(handles)
     }
 
-    /// Frees unused assets, unloading them from memory.
+    /// Frees unused assets, unloading them from memory as decided by their [`UnloadPolicy`].
     pub fn free_unused_assets(&self) {
+        let now = Instant::now();
         let mut potential_frees = self.server.asset_ref_counter.mark_unused_assets.lock();
 
         if !potential_frees.is_empty() {
             let ref_counts = self.server.asset_ref_counter.ref_counts.read();
             let asset_sources = self.server.asset_sources.read();
             let asset_lifecycles = self.server.asset_lifecycles.read();
+            let unload_policies = self.server.unload_policies.read();
+            let mut pending_unloads = self.server.asset_ref_counter.pending_unloads.lock();
             for potential_free in potential_frees.drain(..) {
                 if let Some(&0) = ref_counts.get(&potential_free) {
                     let type_uuid = match potential_free {
@@ -520,14 +609,32 @@ impl AssetServer {
                             .and_then(|source_info| source_info.get_asset_type(id.label_id())),
                     };
 
-                    if let Some(type_uuid) = type_uuid {
-                        if let Some(asset_lifecycle) = asset_lifecycles.get(&type_uuid) {
-                            asset_lifecycle.free_asset(potential_free);
+                    let Some(type_uuid) = type_uuid else {
+                        continue;
+                    };
+                    let deadline = match unload_policies.get(&type_uuid) {
+                        None | Some(UnloadPolicy::WhenUnused) => {
+                            if let Some(asset_lifecycle) = asset_lifecycles.get(&type_uuid) {
+                                asset_lifecycle.free_asset(potential_free);
+                            }
+                            continue;
                         }
-                    }
+                        Some(UnloadPolicy::KeepAlive(duration)) => Some(now + *duration),
+                        Some(UnloadPolicy::Manual) => None,
+                    };
+                    pending_unloads.insert(
+                        potential_free,
+                        PendingUnload {
+                            type_uuid,
+                            deadline,
+                        },
+                    );
                 }
             }
         }
+        drop(potential_frees);
+
+        self.unload_pending_assets(Some(now));
     }
 
     /// Iterates through asset references and marks assets with no active handles as unused.
@@ -594,6 +701,7 @@ impl AssetServer {
         loop {
             match channel.receiver.try_recv() {
                 Ok(AssetLifecycleEvent::Create(result)) => {
+                    let mut dependency_modified = false;
                     // update SourceInfo if this asset was loaded from an AssetPath
                     if let HandleId::AssetPathId(id) = result.id {
                         let asset_sources = asset_sources_guard
@@ -604,11 +712,15 @@ impl AssetServer {
                                 if source_info.is_loaded() {
                                     source_info.load_state = LoadState::Loaded;
                                 }
+                                dependency_modified = source_info.dependency_modified;
                             }
                         }
                     }
 
                     assets.set_untracked(result.id, *result.asset);
+                    if dependency_modified {
+                        assets.send_dependency_modified(result.id);
+                    }
                 }
                 Ok(AssetLifecycleEvent::Free(handle_id)) => {
                     if let HandleId::AssetPathId(id) = handle_id {
@@ -619,7 +731,9 @@ impl AssetServer {
                             source_info.load_state = LoadState::Unloaded;
                         }
                     }
-                    assets.remove(handle_id);
+                    if assets.remove(handle_id).is_some() {
+                        assets.send_evicted(handle_id);
+                    }
                 }
                 Err(TryRecvError::Empty) => {
                     break;
@@ -643,7 +757,7 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{loader::LoadedAsset, update_asset_storage_system};
+    use crate::{loader::LoadedAsset, update_asset_storage_system, AssetEvent};
     use bevy_app::App;
     use bevy_ecs::prelude::*;
     use bevy_reflect::TypeUuid;
@@ -669,6 +783,22 @@ mod test {
         }
     }
 
+    struct FakeDependentLoader;
+    impl AssetLoader for FakeDependentLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            ctx.set_default_asset(LoadedAsset::new(PngAsset).with_dependency("fake.png".into()));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["dep"]
+        }
+    }
+
     struct FailingLoader;
     impl AssetLoader for FailingLoader {
         fn load<'a>(
@@ -797,8 +927,9 @@ mod test {
         let path: AssetPath = "file.not-a-real-extension".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-            .unwrap_err();
+        let err =
+            futures_lite::future::block_on(asset_server.load_async(path.clone(), true, false))
+                .unwrap_err();
         assert!(match err {
             AssetServerError::MissingAssetLoader { extensions } => {
                 extensions == ["not-a-real-extension"]
@@ -817,8 +948,9 @@ mod test {
         let path: AssetPath = "an/invalid/path.png".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-            .unwrap_err();
+        let err =
+            futures_lite::future::block_on(asset_server.load_async(path.clone(), true, false))
+                .unwrap_err();
         assert!(matches!(err, AssetServerError::AssetIoError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
@@ -833,8 +965,9 @@ mod test {
         let path: AssetPath = "fake.fail".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-            .unwrap_err();
+        let err =
+            futures_lite::future::block_on(asset_server.load_async(path.clone(), true, false))
+                .unwrap_err();
         assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
@@ -859,8 +992,9 @@ mod test {
 
         fn load_asset(path: AssetPath, world: &World) -> HandleUntyped {
             let asset_server = world.resource::<AssetServer>();
-            let id = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-                .unwrap();
+            let id =
+                futures_lite::future::block_on(asset_server.load_async(path.clone(), true, false))
+                    .unwrap();
             asset_server.get_handle_untyped(id)
         }
 
@@ -948,4 +1082,63 @@ mod test {
         let invalid_path = AssetPath::new("some/path.ext".into(), None);
         assert!(server.get_handle_path(invalid_path).is_none());
     }
+
+    #[test]
+    fn test_reload_dependents() {
+        let dir = create_dir_and_file("fake.png");
+        std::fs::write(dir.path().join("fake.dep"), []).unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        asset_server.add_loader(FakeDependentLoader);
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets)
+            .insert_resource(asset_server.clone())
+            .add_event::<AssetEvent<PngAsset>>()
+            .add_systems(
+                (
+                    update_asset_storage_system::<PngAsset>,
+                    Assets::<PngAsset>::asset_event_system,
+                )
+                    .chain(),
+            );
+        let mut reader = app
+            .world
+            .resource::<Events<AssetEvent<PngAsset>>>()
+            .get_reader();
+
+        // Runs the app until `condition` holds for an event, returning whether it did.
+        let mut wait_for = |app: &mut App, condition: &dyn Fn(&AssetEvent<PngAsset>) -> bool| {
+            for _ in 0..200 {
+                app.update();
+                let events = app.world.resource::<Events<AssetEvent<PngAsset>>>();
+                if reader.iter(events).any(condition) {
+                    return true;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+            false
+        };
+
+        let parent: Handle<PngAsset> = asset_server.load("fake.dep");
+        let child: Handle<PngAsset> = asset_server.get_handle("fake.png");
+        assert!(wait_for(&mut app, &|_| asset_server.get_load_state(&child)
+            == LoadState::Loaded
+            && asset_server.get_load_state(&parent) == LoadState::Loaded));
+
+        // reloading the dependency reloads the dependent asset
+        asset_server.reload_asset("fake.png");
+        assert!(wait_for(&mut app, &|event| matches!(
+            event,
+            AssetEvent::DependencyModified { handle } if *handle == parent
+        )));
+
+        // reloading the dependent asset doesn't reload its dependency
+        asset_server.reload_asset("fake.dep");
+        assert!(!wait_for(&mut app, &|event| matches!(
+            event,
+            AssetEvent::DependencyModified { .. }
+        )));
+    }
 }
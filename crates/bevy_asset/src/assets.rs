@@ -1,6 +1,6 @@
 use crate::{
     update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetSet, Handle, HandleId,
-    RefChange, ReflectAsset, ReflectHandle,
+    RefChange, ReflectAsset, ReflectHandle, UnloadPolicy,
 };
 use bevy_app::{App, AppTypeRegistry};
 use bevy_ecs::prelude::*;
@@ -20,6 +20,19 @@ pub enum AssetEvent<T: Asset> {
     Modified { handle: Handle<T> },
     #[allow(missing_docs)]
     Removed { handle: Handle<T> },
+    /// Sent in addition to [`AssetEvent::Removed`] when the asset was unloaded because it had no
+    /// strong handles left, see [`UnloadPolicy`](crate::UnloadPolicy), or was removed by
+    /// [`Assets::retain`].
+    Evicted {
+        /// The handle of the evicted asset.
+        handle: Handle<T>,
+    },
+    /// Sent in addition to [`AssetEvent::Modified`] when the asset was reloaded because one of
+    /// its dependencies changed, see [`LoadedAsset::add_dependency`](crate::LoadedAsset::add_dependency).
+    DependencyModified {
+        /// The handle of the reloaded asset.
+        handle: Handle<T>,
+    },
 }
 
 impl<T: Asset> Debug for AssetEvent<T> {
@@ -46,6 +59,20 @@ impl<T: Asset> Debug for AssetEvent<T> {
                 ))
                 .field("handle", &handle.id())
                 .finish(),
+            AssetEvent::Evicted { handle } => f
+                .debug_struct(&format!(
+                    "AssetEvent<{}>::Evicted",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .finish(),
+            AssetEvent::DependencyModified { handle } => f
+                .debug_struct(&format!(
+                    "AssetEvent<{}>::DependencyModified",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .finish(),
         }
     }
 }
@@ -128,6 +155,18 @@ impl<T: Asset> Assets<T> {
         }
     }
 
+    pub(crate) fn send_evicted(&mut self, id: HandleId) {
+        self.events.send(AssetEvent::Evicted {
+            handle: Handle::weak(id),
+        });
+    }
+
+    pub(crate) fn send_dependency_modified(&mut self, id: HandleId) {
+        self.events.send(AssetEvent::DependencyModified {
+            handle: Handle::weak(id),
+        });
+    }
+
     /// Gets the asset for the given handle.
     ///
     /// This is the main method for accessing asset data from an [Assets] collection. If you need
@@ -221,6 +260,28 @@ impl<T: Asset> Assets<T> {
         asset
     }
 
+    /// Removes all assets for which `f` returns `false`, e.g. to garbage collect assets that are
+    /// no longer needed.
+    ///
+    /// # Events
+    ///
+    /// * [`AssetEvent::Removed`] and [`AssetEvent::Evicted`] for each removed asset
+    pub fn retain(&mut self, mut f: impl FnMut(HandleId, &mut T) -> bool) {
+        let events = &mut self.events;
+        self.assets.retain(|&id, asset| {
+            let retain = f(id, asset);
+            if !retain {
+                events.send(AssetEvent::Removed {
+                    handle: Handle::weak(id),
+                });
+                events.send(AssetEvent::Evicted {
+                    handle: Handle::weak(id),
+                });
+            }
+            retain
+        });
+    }
+
     /// Clears the inner asset map, removing all key-value pairs.
     ///
     /// Keeps the allocated memory for reuse.
@@ -315,6 +376,11 @@ pub trait AddAsset {
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
         T: AssetLoader;
+
+    /// Sets the [`UnloadPolicy`] of the asset type `T`, see [`AssetServer::set_unload_policy`].
+    fn set_unload_policy<T>(&mut self, policy: UnloadPolicy) -> &mut Self
+    where
+        T: Asset;
 }
 
 impl AddAsset for App {
@@ -404,6 +470,16 @@ impl AddAsset for App {
         self.world.resource_mut::<AssetServer>().add_loader(loader);
         self
     }
+
+    fn set_unload_policy<T>(&mut self, policy: UnloadPolicy) -> &mut Self
+    where
+        T: Asset,
+    {
+        self.world
+            .resource::<AssetServer>()
+            .set_unload_policy::<T>(policy);
+        self
+    }
 }
 
 /// Loads an internal asset.
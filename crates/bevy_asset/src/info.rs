@@ -36,6 +36,10 @@ pub struct SourceInfo {
     pub committed_assets: HashSet<LabelId>,
     /// Current version of the source.
     pub version: usize,
+    /// Whether the current version was loaded because one of the source's dependencies changed.
+    pub dependency_modified: bool,
+    /// The size of the source in bytes, once it was read.
+    pub bytes: Option<usize>,
 }
 
 impl SourceInfo {
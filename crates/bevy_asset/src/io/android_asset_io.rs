@@ -1,4 +1,4 @@
-use crate::{AssetIo, AssetIoError, Metadata};
+use crate::{AssetIo, AssetIoError, BlockingReader, Metadata, Reader};
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
 use std::{
@@ -12,6 +12,7 @@ use std::{
 /// Implementation details:
 ///
 /// - `load_path` uses the [AssetManager] to load files.
+/// - `read_path` streams files from the [AssetManager].
 /// - `read_directory` always returns an empty iterator.
 /// - `get_metadata` will probably return an error.
 /// - Watching for changes is not supported. The watcher methods will do nothing.
@@ -44,6 +45,23 @@ impl AssetIo for AndroidAssetIo {
         })
     }
 
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let asset_manager = bevy_winit::ANDROID_APP
+                .get()
+                .expect("Bevy must be setup with the #[bevy_main] macro on Android")
+                .asset_manager();
+            let opened_asset = asset_manager
+                .open(&CString::new(path.to_str().unwrap()).unwrap())
+                .ok_or(AssetIoError::NotFound(path.to_path_buf()))?;
+            let reader: Box<Reader<'a>> = Box::new(BlockingReader::new(opened_asset));
+            Ok(reader)
+        })
+    }
+
     fn read_directory(
         &self,
         _path: &Path,
@@ -1,6 +1,6 @@
 #[cfg(feature = "filesystem_watcher")]
-use crate::{filesystem_watcher::FilesystemWatcher, AssetServer};
-use crate::{AssetIo, AssetIoError, Metadata};
+use crate::{filesystem_watcher::FilesystemWatcher, AssetServer, SchemeAssetIo};
+use crate::{AssetIo, AssetIoError, BlockingReader, Metadata, Reader};
 use anyhow::Result;
 #[cfg(feature = "filesystem_watcher")]
 use bevy_ecs::system::Res;
@@ -109,6 +109,25 @@ impl AssetIo for FileAssetIo {
         })
     }
 
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            match File::open(&full_path) {
+                Ok(file) => {
+                    let reader: Box<Reader<'a>> = Box::new(BlockingReader::new(file));
+                    Ok(reader)
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    Err(AssetIoError::NotFound(full_path))
+                }
+                Err(e) => Err(e.into()),
+            }
+        })
+    }
+
     fn read_directory(
         &self,
         path: &Path,
@@ -175,12 +194,17 @@ impl AssetIo for FileAssetIo {
     all(not(target_arch = "wasm32"), not(target_os = "android"))
 ))]
 pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
-    let asset_io =
-        if let Some(asset_io) = asset_server.server.asset_io.downcast_ref::<FileAssetIo>() {
-            asset_io
-        } else {
-            return;
-        };
+    let asset_io = &*asset_server.server.asset_io;
+    let asset_io = if let Some(asset_io) = asset_io.downcast_ref::<FileAssetIo>() {
+        asset_io
+    } else if let Some(asset_io) = asset_io
+        .downcast_ref::<SchemeAssetIo>()
+        .and_then(|asset_io| asset_io.default_io().downcast_ref::<FileAssetIo>())
+    {
+        asset_io
+    } else {
+        return;
+    };
     let watcher = asset_io.filesystem_watcher.read();
     if let Some(ref watcher) = *watcher {
         let mut changed = HashSet::<&PathBuf>::default();
@@ -209,3 +233,38 @@ pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future, AsyncReadExt};
+
+    #[test]
+    fn read_path_in_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("data.bin"), b"streamed asset data").unwrap();
+        let asset_io = FileAssetIo::new(dir.path(), false);
+
+        let chunks = future::block_on(async {
+            let mut reader = asset_io.read_path(Path::new("data.bin")).await?;
+            let mut chunks = Vec::new();
+            let mut buffer = [0; 8];
+            loop {
+                let read = reader.read(&mut buffer).await?;
+                if read == 0 {
+                    break;
+                }
+                chunks.push(buffer[..read].to_vec());
+            }
+            Ok::<_, AssetIoError>(chunks)
+        })
+        .unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), b"streamed asset data");
+
+        assert!(matches!(
+            future::block_on(asset_io.read_path(Path::new("missing.bin"))),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+}
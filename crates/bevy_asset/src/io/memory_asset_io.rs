@@ -0,0 +1,101 @@
+use crate::{AssetIo, AssetIoError, FileType, Metadata, Reader};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap, HashSet};
+use parking_lot::RwLock;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// I/O implementation serving assets from memory.
+///
+/// Useful for assets that are embedded in the executable, generated at runtime, or downloaded, e.g.
+/// registered for the `memory` scheme of a [`SchemeAssetIo`](crate::SchemeAssetIo).
+#[derive(Default)]
+pub struct MemoryAssetIo {
+    files: RwLock<HashMap<PathBuf, Arc<[u8]>>>,
+}
+
+impl MemoryAssetIo {
+    /// Inserts a file with the given `bytes` at `path`, replacing any previous file at that path.
+    ///
+    /// Directories are implied by the paths of their files.
+    pub fn insert(&self, path: impl Into<PathBuf>, bytes: impl Into<Arc<[u8]>>) {
+        self.files.write().insert(path.into(), bytes.into());
+    }
+
+    /// Removes the file at `path`, returning `true` if it existed.
+    pub fn remove(&self, path: &Path) -> bool {
+        self.files.write().remove(path).is_some()
+    }
+
+    fn get(&self, path: &Path) -> Result<Arc<[u8]>, AssetIoError> {
+        self.files
+            .read()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+    }
+}
+
+impl AssetIo for MemoryAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move { Ok(self.get(path)?.to_vec()) })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let reader: Box<Reader<'a>> = Box::new(futures_lite::io::Cursor::new(self.get(path)?));
+            Ok(reader)
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        if !self.is_dir(path) {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        let mut entries = HashSet::default();
+        for file_path in self.files.read().keys() {
+            if let Some(child) = file_path
+                .strip_prefix(path)
+                .ok()
+                .and_then(|rest| rest.components().next())
+            {
+                entries.insert(path.join(child));
+            }
+        }
+        Ok(Box::new(entries.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let files = self.files.read();
+        if files.contains_key(path) {
+            Ok(Metadata::new(FileType::File))
+        } else if files
+            .keys()
+            .any(|file_path| file_path != path && file_path.starts_with(path))
+        {
+            Ok(Metadata::new(FileType::Directory))
+        } else {
+            Err(AssetIoError::NotFound(path.to_owned()))
+        }
+    }
+
+    fn watch_path_for_changes(
+        &self,
+        _to_watch: &Path,
+        _to_reload: Option<PathBuf>,
+    ) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+}
@@ -5,7 +5,9 @@ mod file_asset_io;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
 
+mod memory_asset_io;
 mod metadata;
+mod scheme_asset_io;
 
 #[cfg(target_os = "android")]
 pub use android_asset_io::*;
@@ -14,11 +16,20 @@ pub use file_asset_io::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::*;
 
+pub use memory_asset_io::*;
 pub use metadata::*;
+pub use scheme_asset_io::*;
 
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
 use downcast_rs::{impl_downcast, Downcast};
+use futures_lite::AsyncRead;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    pin::Pin,
+    sync::{Mutex, PoisonError},
+    task::{Context, Poll},
+};
 use std::{
     io,
     path::{Path, PathBuf},
@@ -39,6 +50,37 @@ pub enum AssetIoError {
     /// Failed to watch path.
     #[error("failed to watch path: {0}")]
     PathWatchError(PathBuf),
+
+    /// No asset I/O is registered for the scheme of the path.
+    #[error("no asset io registered for scheme: {0}")]
+    UnknownScheme(String),
+}
+
+/// An asynchronous reader of the data of an asset, see [`AssetIo::read_path`].
+pub type Reader<'a> = dyn AsyncRead + Unpin + Send + Sync + 'a;
+
+/// A [`Reader`] over a blocking [`io::Read`] source, which reads at most one buffer of data each
+/// time it is polled.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct BlockingReader<R>(Mutex<R>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> BlockingReader<R> {
+    pub(crate) fn new(source: R) -> Self {
+        Self(Mutex::new(source))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: io::Read + Unpin> AsyncRead for BlockingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let source = self.get_mut().0.get_mut().unwrap_or_else(PoisonError::into_inner);
+        Poll::Ready(source.read(buf))
+    }
 }
 
 /// A storage provider for an [`AssetServer`].
@@ -56,6 +98,21 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
     /// Returns a future to load the full file data at the provided path.
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>>;
 
+    /// Returns a future to open a [`Reader`] streaming the file data at the provided path.
+    ///
+    /// The default implementation loads the full file data with [`AssetIo::load_path`], so
+    /// implementations that can read the data incrementally should override it.
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let bytes = self.load_path(path).await?;
+            let reader: Box<Reader<'a>> = Box::new(futures_lite::io::Cursor::new(bytes));
+            Ok(reader)
+        })
+    }
+
     /// Returns an iterator of directory entry names at the provided path.
     fn read_directory(
         &self,
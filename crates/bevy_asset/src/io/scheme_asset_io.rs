@@ -0,0 +1,204 @@
+use crate::{AssetIo, AssetIoError, Metadata, Reader};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use std::path::{Path, PathBuf};
+
+/// I/O implementation dispatching paths with a `scheme://` prefix to the asset I/O registered for
+/// that scheme.
+///
+/// Paths without a scheme are handled by the default asset I/O. This allows loading assets from,
+/// for example, pack files or downloadable content next to the regular asset folder:
+///
+/// ```
+/// # use bevy_asset::{AssetPlugin, AssetServer, MemoryAssetIo, SchemeAssetIo};
+/// let memory = MemoryAssetIo::default();
+/// memory.insert("config.ron", b"()".to_vec());
+///
+/// let default_io = AssetPlugin::default().create_platform_default_asset_io();
+/// let asset_io = SchemeAssetIo::with_boxed_io(default_io).with_scheme("memory", memory);
+/// let asset_server = AssetServer::new(asset_io);
+/// // `asset_server.load("memory://config.ron")` now loads from the `MemoryAssetIo`.
+/// ```
+pub struct SchemeAssetIo {
+    default_io: Box<dyn AssetIo>,
+    schemes: HashMap<String, Box<dyn AssetIo>>,
+}
+
+impl SchemeAssetIo {
+    /// Creates a new `SchemeAssetIo` handling paths without a scheme with `default_io`.
+    pub fn new<T: AssetIo>(default_io: T) -> Self {
+        Self::with_boxed_io(Box::new(default_io))
+    }
+
+    /// Creates a new `SchemeAssetIo` handling paths without a scheme with the boxed `default_io`.
+    pub fn with_boxed_io(default_io: Box<dyn AssetIo>) -> Self {
+        Self {
+            default_io,
+            schemes: HashMap::default(),
+        }
+    }
+
+    /// Registers `asset_io` to handle paths starting with `<scheme>://`, replacing the asset I/O
+    /// previously registered for the scheme.
+    pub fn add_scheme<T: AssetIo>(&mut self, scheme: impl Into<String>, asset_io: T) -> &mut Self {
+        self.add_boxed_scheme(scheme, Box::new(asset_io))
+    }
+
+    /// Registers the boxed `asset_io` to handle paths starting with `<scheme>://`, replacing the
+    /// asset I/O previously registered for the scheme.
+    pub fn add_boxed_scheme(
+        &mut self,
+        scheme: impl Into<String>,
+        asset_io: Box<dyn AssetIo>,
+    ) -> &mut Self {
+        self.schemes.insert(scheme.into(), asset_io);
+        self
+    }
+
+    /// Registers `asset_io` to handle paths starting with `<scheme>://`, see
+    /// [`SchemeAssetIo::add_scheme`].
+    #[must_use]
+    pub fn with_scheme<T: AssetIo>(mut self, scheme: impl Into<String>, asset_io: T) -> Self {
+        self.add_scheme(scheme, asset_io);
+        self
+    }
+
+    /// Returns the asset I/O handling paths without a scheme.
+    pub fn default_io(&self) -> &dyn AssetIo {
+        &*self.default_io
+    }
+
+    /// Returns the asset I/O registered for the `scheme`.
+    pub fn scheme_io(&self, scheme: &str) -> Option<&dyn AssetIo> {
+        self.schemes.get(scheme).map(|asset_io| &**asset_io)
+    }
+
+    /// Splits `path` into its scheme and the remaining path, and returns the asset I/O handling it.
+    fn resolve<'a>(
+        &self,
+        path: &'a Path,
+    ) -> Result<(Option<&'a str>, &dyn AssetIo, &'a Path), AssetIoError> {
+        match path.to_str().and_then(|path| path.split_once("://")) {
+            Some((scheme, rest)) => {
+                let asset_io = self
+                    .scheme_io(scheme)
+                    .ok_or_else(|| AssetIoError::UnknownScheme(scheme.to_string()))?;
+                Ok((Some(scheme), asset_io, Path::new(rest)))
+            }
+            None => Ok((None, self.default_io(), path)),
+        }
+    }
+}
+
+impl AssetIo for SchemeAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let (_, asset_io, path) = self.resolve(path)?;
+            asset_io.load_path(path).await
+        })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let (_, asset_io, path) = self.resolve(path)?;
+            asset_io.read_path(path).await
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let (scheme, asset_io, path) = self.resolve(path)?;
+        let entries = asset_io.read_directory(path)?;
+        match scheme {
+            Some(scheme) => {
+                let scheme = scheme.to_string();
+                Ok(Box::new(entries.map(move |entry| {
+                    PathBuf::from(format!("{scheme}://{}", entry.to_string_lossy()))
+                })))
+            }
+            None => Ok(entries),
+        }
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let (_, asset_io, path) = self.resolve(path)?;
+        asset_io.get_metadata(path)
+    }
+
+    fn watch_path_for_changes(
+        &self,
+        to_watch: &Path,
+        to_reload: Option<PathBuf>,
+    ) -> Result<(), AssetIoError> {
+        let (scheme, asset_io, path) = self.resolve(to_watch)?;
+        // the asset to reload has to be identified by its full path, including the scheme
+        let to_reload = match scheme {
+            Some(_) => Some(to_reload.unwrap_or_else(|| to_watch.to_owned())),
+            None => to_reload,
+        };
+        asset_io.watch_path_for_changes(path, to_reload)
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        self.default_io.watch_for_changes()?;
+        for asset_io in self.schemes.values() {
+            asset_io.watch_for_changes()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryAssetIo;
+    use futures_lite::{future, AsyncReadExt};
+
+    #[test]
+    fn dispatch_by_scheme() {
+        let default_io = MemoryAssetIo::default();
+        default_io.insert("a.txt", b"default".to_vec());
+        let pak = MemoryAssetIo::default();
+        pak.insert("dir/a.txt", b"pak".to_vec());
+        let asset_io = SchemeAssetIo::new(default_io).with_scheme("pak", pak);
+
+        assert_eq!(
+            future::block_on(asset_io.load_path(Path::new("a.txt"))).unwrap(),
+            b"default"
+        );
+        assert_eq!(
+            future::block_on(asset_io.load_path(Path::new("pak://dir/a.txt"))).unwrap(),
+            b"pak"
+        );
+        assert!(matches!(
+            future::block_on(asset_io.load_path(Path::new("pak://a.txt"))),
+            Err(AssetIoError::NotFound(_))
+        ));
+        assert!(matches!(
+            future::block_on(asset_io.load_path(Path::new("http://a.txt"))),
+            Err(AssetIoError::UnknownScheme(scheme)) if scheme == "http"
+        ));
+
+        let mut bytes = Vec::new();
+        future::block_on(async {
+            let mut reader = asset_io.read_path(Path::new("pak://dir/a.txt")).await?;
+            reader.read_to_end(&mut bytes).await?;
+            Ok::<_, AssetIoError>(())
+        })
+        .unwrap();
+        assert_eq!(bytes, b"pak");
+
+        assert!(asset_io.is_dir(Path::new("pak://dir")));
+        assert!(asset_io.is_file(Path::new("pak://dir/a.txt")));
+        let entries: Vec<_> = asset_io
+            .read_directory(Path::new("pak://dir"))
+            .unwrap()
+            .collect();
+        assert_eq!(entries, [PathBuf::from("pak://dir/a.txt")]);
+    }
+}
@@ -15,6 +15,8 @@ use web_sys::Response;
 /// Implementation details:
 ///
 /// - `load_path` makes [fetch()] requests.
+/// - `read_path` uses the default implementation, which reads the whole response before
+///   streaming it.
 /// - `read_directory` always returns an empty iterator.
 /// - `get_metadata` will always return an error.
 /// - Watching for changes is not supported. The watcher methods will do nothing.
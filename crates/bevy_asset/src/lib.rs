@@ -24,8 +24,12 @@ mod handle;
 mod info;
 mod io;
 mod loader;
+mod loading_group;
 mod path;
+mod processor;
 mod reflect;
+mod settings;
+mod unload;
 
 /// The `bevy_asset` prelude.
 pub mod prelude {
@@ -43,8 +47,12 @@ pub use handle::*;
 pub use info::*;
 pub use io::*;
 pub use loader::*;
+pub use loading_group::*;
 pub use path::*;
+pub use processor::*;
 pub use reflect::*;
+pub use settings::*;
+pub use unload::*;
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
@@ -70,6 +78,11 @@ pub struct AssetPlugin {
     /// Whether to watch for changes in asset files. Requires the `filesystem_watcher` feature,
     /// and cannot be supported on the wasm32 arch nor android os.
     pub watch_for_changes: bool,
+    /// The folder where processed assets are cached, relative to the base path of the
+    /// [`FileAssetIo`]. If `None`, processed assets are not cached.
+    ///
+    /// See [`AssetProcessor`].
+    pub processed_asset_folder: Option<String>,
 }
 
 impl Default for AssetPlugin {
@@ -77,6 +90,7 @@ impl Default for AssetPlugin {
         Self {
             asset_folder: "assets".to_string(),
             watch_for_changes: false,
+            processed_asset_folder: Some(".imported_assets".to_string()),
         }
     }
 }
@@ -103,6 +117,12 @@ impl Plugin for AssetPlugin {
         if !app.world.contains_resource::<AssetServer>() {
             let source = self.create_platform_default_asset_io();
             let asset_server = AssetServer::with_boxed_io(source);
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            asset_server.set_processed_asset_folder(
+                self.processed_asset_folder
+                    .as_ref()
+                    .map(|folder| FileAssetIo::get_base_path().join(folder)),
+            );
             app.insert_resource(asset_server);
         }
 
@@ -1,6 +1,6 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetProcessor, AssetServer, Assets, Handle,
+    HandleId, RefChangeChannel,
 };
 use anyhow::Error;
 use anyhow::Result;
@@ -25,6 +25,14 @@ pub trait AssetLoader: Send + Sync + 'static {
 
     /// Returns a list of extensions supported by this asset loader, without the preceding dot.
     fn extensions(&self) -> &[&str];
+
+    /// Returns the processing step of the assets supported by this loader, if any.
+    ///
+    /// If a processor is returned, sources are not loaded by this loader, but processed with the
+    /// [`AssetProcessor`] first, see its documentation for more details.
+    fn processor(&self) -> Option<&dyn AssetProcessor> {
+        None
+    }
 }
 
 /// An essential piece of data of an application.
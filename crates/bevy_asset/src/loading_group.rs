@@ -0,0 +1,247 @@
+use crate::{Asset, AssetServer, Handle, HandleId, HandleUntyped, LoadState};
+use bevy_ecs::system::Res;
+use bevy_utils::HashSet;
+
+/// A group of asset handles whose loading progress is tracked together, e.g. for a loading screen.
+///
+/// The group holds strong handles, so the assets stay loaded for as long as the group exists.
+/// Use [`AssetServer::get_group_progress`] to query the aggregate progress of the group, or the
+/// [`assets_loaded`] run condition to run systems once all of its assets are loaded.
+///
+/// Only handles of assets loaded through the [`AssetServer`] are tracked, see
+/// [`AssetServer::get_load_state`].
+///
+/// ```
+/// # use bevy_asset::{AssetServer, Handle, LoadingGroup};
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(Debug, bevy_reflect::TypeUuid)]
+/// # #[uuid = "00000000-0000-0000-0000-000000000000"]
+/// # struct Image;
+/// #[derive(Resource)]
+/// struct LevelAssets(LoadingGroup);
+///
+/// fn start_loading(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let mut group = LoadingGroup::default();
+///     let _: Handle<Image> = group.load(&asset_server, "level/background.png");
+///     let _: Handle<Image> = group.load(&asset_server, "level/tiles.png");
+///     commands.insert_resource(LevelAssets(group));
+/// }
+///
+/// fn show_progress(asset_server: Res<AssetServer>, level: Res<LevelAssets>) {
+///     let progress = asset_server.get_group_progress(&level.0);
+///     println!("{:.0}% loaded", progress.fraction() * 100.0);
+/// }
+/// # bevy_ecs::system::assert_is_system(start_loading);
+/// # bevy_ecs::system::assert_is_system(show_progress);
+/// ```
+#[derive(Debug, Default)]
+pub struct LoadingGroup {
+    handles: Vec<HandleUntyped>,
+}
+
+impl LoadingGroup {
+    /// Loads the asset at `path` with the `asset_server` and adds it to the group.
+    ///
+    /// See [`AssetServer::load`].
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset once the group is dropped"]
+    pub fn load<'a, T: Asset, P: Into<crate::AssetPath<'a>>>(
+        &mut self,
+        asset_server: &AssetServer,
+        path: P,
+    ) -> Handle<T> {
+        let handle: Handle<T> = asset_server.load(path);
+        self.add(&handle);
+        handle
+    }
+
+    /// Adds the asset of the `handle` to the group.
+    pub fn add<T: Asset>(&mut self, handle: &Handle<T>) {
+        self.handles.push(handle.clone_untyped());
+    }
+
+    /// Adds the asset of the untyped `handle` to the group.
+    pub fn add_untyped(&mut self, handle: &HandleUntyped) {
+        self.handles.push(handle.clone());
+    }
+
+    /// Returns an iterator over the ids of the assets in the group.
+    pub fn ids(&self) -> impl Iterator<Item = HandleId> + '_ {
+        self.handles.iter().map(|handle| handle.id())
+    }
+
+    /// Returns the number of assets in the group.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the group contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+/// The aggregate loading progress of a [`LoadingGroup`], see [`AssetServer::get_group_progress`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadingProgress {
+    /// The number of assets that are loaded.
+    pub loaded: usize,
+    /// The number of assets that failed to load.
+    pub failed: usize,
+    /// The total number of assets in the group.
+    pub total: usize,
+    /// The number of bytes read from the sources of the assets so far.
+    pub bytes: usize,
+}
+
+impl LoadingProgress {
+    /// Returns the fraction of loaded assets, between `0.0` and `1.0`.
+    ///
+    /// An empty group is considered fully loaded.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+
+    /// Returns `true` if every asset either loaded or failed to load.
+    pub fn is_done(&self) -> bool {
+        self.loaded + self.failed == self.total
+    }
+}
+
+impl AssetServer {
+    /// Gets the aggregate loading progress of the assets in the `group`.
+    pub fn get_group_progress(&self, group: &LoadingGroup) -> LoadingProgress {
+        let mut progress = LoadingProgress {
+            total: group.len(),
+            ..Default::default()
+        };
+        let mut sources = HashSet::default();
+        for id in group.ids() {
+            match self.get_load_state(id) {
+                LoadState::Loaded => progress.loaded += 1,
+                LoadState::Failed => progress.failed += 1,
+                _ => {}
+            }
+            if let HandleId::AssetPathId(id) = id {
+                if sources.insert(id.source_path_id()) {
+                    progress.bytes += self
+                        .server
+                        .asset_sources
+                        .read()
+                        .get(&id.source_path_id())
+                        .and_then(|source_info| source_info.bytes)
+                        .unwrap_or(0);
+                }
+            }
+        }
+        progress
+    }
+}
+
+/// Generates a [`Condition`](bevy_ecs::schedule::Condition)-satisfying closure that returns
+/// `true` once all assets of the `group` are [loaded](LoadState::Loaded).
+///
+/// The condition only stores the ids of the assets, so it doesn't keep them loaded.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::{assets_loaded, LoadingGroup};
+/// # use bevy_ecs::prelude::*;
+/// # let mut app = App::new();
+/// # let group = LoadingGroup::default();
+/// # fn spawn_level() {}
+/// app.add_system(spawn_level.run_if(assets_loaded(&group)));
+/// ```
+pub fn assets_loaded(group: &LoadingGroup) -> impl FnMut(Res<AssetServer>) -> bool + Clone {
+    let ids: Vec<HandleId> = group.ids().collect();
+    move |asset_server: Res<AssetServer>| {
+        ids.iter()
+            .all(|&id| asset_server.get_load_state(id) == LoadState::Loaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{update_asset_storage_system, AssetLoader, FileAssetIo, LoadContext, LoadedAsset};
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::TypeUuid;
+    use bevy_tasks::IoTaskPool;
+    use bevy_utils::BoxedFuture;
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "6b1a4f3e-4d0e-4bb4-a0b0-8c0a3b6c1f27"]
+    struct TextAsset;
+
+    struct TextLoader;
+    impl AssetLoader for TextLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            ctx.set_default_asset(LoadedAsset::new(TextAsset));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[test]
+    fn loading_group_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "hello").unwrap();
+        std::fs::write(dir.path().join("b.txt"), "world!").unwrap();
+        IoTaskPool::init(Default::default);
+        let asset_server = AssetServer::new(FileAssetIo::new(dir.path(), false));
+        asset_server.add_loader(TextLoader);
+
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<TextAsset>())
+            .insert_resource(asset_server.clone())
+            .add_system(update_asset_storage_system::<TextAsset>);
+
+        let mut group = LoadingGroup::default();
+        assert_eq!(asset_server.get_group_progress(&group).fraction(), 1.0);
+        let a: Handle<TextAsset> = group.load(&asset_server, "a.txt");
+        let b: Handle<TextAsset> = group.load(&asset_server, "b.txt");
+        let _missing: Handle<TextAsset> = group.load(&asset_server, "missing.txt");
+        assert_eq!(group.len(), 3);
+
+        let mut condition = IntoSystem::into_system(assets_loaded(&group));
+        condition.initialize(&mut app.world);
+
+        let mut progress = LoadingProgress::default();
+        for _ in 0..200 {
+            app.update();
+            progress = asset_server.get_group_progress(&group);
+            if progress.is_done() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(
+            progress,
+            LoadingProgress {
+                loaded: 2,
+                failed: 1,
+                total: 3,
+                bytes: 11,
+            }
+        );
+        assert!(!condition.run((), &mut app.world));
+
+        let mut group = LoadingGroup::default();
+        group.add(&a);
+        group.add(&b);
+        let mut condition = IntoSystem::into_system(assets_loaded(&group));
+        condition.initialize(&mut app.world);
+        assert!(condition.run((), &mut app.world));
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{AssetServer, AssetServerError};
+use anyhow::{Error, Result};
+use bevy_log::warn;
+use bevy_utils::BoxedFuture;
+use siphasher::sip128::{Hasher128, SipHasher24};
+use std::{
+    hash::Hasher,
+    path::{Path, PathBuf},
+};
+
+/// A processing step turning the source of an asset into an artifact that is cheaper to load, e.g.
+/// by compressing textures or baking mipmaps ahead of time.
+///
+/// An [`AssetLoader`](crate::AssetLoader) declares its processing step by returning it from
+/// [`AssetLoader::processor`](crate::AssetLoader::processor). The asset server then loads the
+/// processed artifact with the loader registered for the
+/// [`processed_extension`](Self::processed_extension) instead of loading the source directly.
+///
+/// If a processed asset folder is set on the [`AssetServer`], the artifacts are cached there, keyed
+/// by a hash of the source and the processor, so that unchanged sources are only processed once.
+pub trait AssetProcessor: Send + Sync + 'static {
+    /// Processes the `bytes` of the asset source at `path` into the bytes of the artifact.
+    fn process<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, Error>>;
+
+    /// Returns the extension of the processed artifacts, without the preceding dot.
+    ///
+    /// The artifacts are loaded with the loader registered for this extension. Note that the
+    /// [`LoadContext`](crate::LoadContext) passed to that loader still uses the path of the source.
+    fn processed_extension(&self) -> &str;
+
+    /// Returns the version of this processor.
+    ///
+    /// Changing the version invalidates all artifacts cached by previous versions.
+    fn version(&self) -> u32 {
+        0
+    }
+
+    /// Returns the settings of this processor, serialized in a format that is stable across runs.
+    ///
+    /// Changing the settings invalidates all artifacts cached with other settings.
+    fn serialized_settings(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    /// Returns the name of the processor type, which is part of the key of cached artifacts.
+    ///
+    /// Defaults to [`std::any::type_name`], which may change with the compiler version, so
+    /// processors whose artifacts are shipped should return a fixed name.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+impl AssetServer {
+    /// Sets the folder processed assets are cached in, or disables the cache if `None`.
+    ///
+    /// The [`AssetPlugin`](crate::AssetPlugin) sets this to
+    /// [`processed_asset_folder`](crate::AssetPlugin::processed_asset_folder).
+    pub fn set_processed_asset_folder(&self, folder: Option<PathBuf>) {
+        *self.server.processed_asset_folder.write() = folder;
+    }
+
+    /// Returns the folder processed assets are cached in, if any.
+    pub fn processed_asset_folder(&self) -> Option<PathBuf> {
+        self.server.processed_asset_folder.read().clone()
+    }
+
+    /// Processes the source `bytes` of the asset at `path`, reusing a cached artifact if one exists.
+    pub(crate) async fn process_asset(
+        &self,
+        processor: &dyn AssetProcessor,
+        path: &Path,
+        bytes: &[u8],
+    ) -> Result<Vec<u8>, AssetServerError> {
+        let cache_path = self
+            .processed_asset_folder()
+            .map(|folder| folder.join(processed_file_name(processor, path, bytes)));
+        if let Some(processed) = cache_path
+            .as_ref()
+            .and_then(|cache_path| std::fs::read(cache_path).ok())
+        {
+            return Ok(processed);
+        }
+
+        let processed = processor
+            .process(bytes, path)
+            .await
+            .map_err(AssetServerError::AssetProcessorError)?;
+
+        if let Some(cache_path) = cache_path {
+            let result = cache_path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|_| std::fs::write(&cache_path, &processed));
+            if let Err(err) = result {
+                warn!(
+                    "could not cache processed asset {:?} at {:?}: {}",
+                    path, cache_path, err
+                );
+            }
+        }
+        Ok(processed)
+    }
+}
+
+/// Returns the file name of the artifact of the source `bytes` at `path`, which changes whenever
+/// the source or the processor changes.
+///
+/// The name is a SipHash-2-4 hash with zero keys, whose output is fixed by its specification, so
+/// cached artifacts stay valid across builds and Rust versions.
+fn processed_file_name(processor: &dyn AssetProcessor, path: &Path, bytes: &[u8]) -> String {
+    let mut hasher = SipHasher24::new();
+    let mut write = |field: &[u8]| {
+        hasher.write(&(field.len() as u64).to_le_bytes());
+        hasher.write(field);
+    };
+    write(processor.type_name().as_bytes());
+    write(&processor.version().to_le_bytes());
+    write(&processor.serialized_settings());
+    write(processor.processed_extension().as_bytes());
+    write(path.to_string_lossy().as_bytes());
+    write(bytes);
+    format!(
+        "{:032x}.{}",
+        hasher.finish128().as_u128(),
+        processor.processed_extension()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        update_asset_storage_system, AssetLoader, Assets, FileAssetIo, Handle, LoadContext,
+        LoadState, LoadedAsset,
+    };
+    use bevy_app::App;
+    use bevy_reflect::TypeUuid;
+    use bevy_tasks::IoTaskPool;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "4f0b8f61-4b0a-4f37-9d1c-61e6a5b4c2d9"]
+    struct Text(String);
+
+    struct UppercaseProcessor(Arc<AtomicUsize>);
+    impl AssetProcessor for UppercaseProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _: &'a Path,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, Error>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(bytes.to_ascii_uppercase()) })
+        }
+
+        fn processed_extension(&self) -> &str {
+            "upper"
+        }
+    }
+
+    struct SourceLoader(UppercaseProcessor);
+    impl AssetLoader for SourceLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            _: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), Error>> {
+            Box::pin(async move { Err(anyhow::anyhow!("source should be processed")) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+
+        fn processor(&self) -> Option<&dyn AssetProcessor> {
+            Some(&self.0)
+        }
+    }
+
+    struct ProcessedLoader;
+    impl AssetLoader for ProcessedLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), Error>> {
+            let text = String::from_utf8(bytes.to_vec()).unwrap();
+            ctx.set_default_asset(LoadedAsset::new(Text(text)));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["upper"]
+        }
+    }
+
+    fn load_text(dir: &Path, cache: &Path, processed: &Arc<AtomicUsize>) -> String {
+        let asset_server = AssetServer::new(FileAssetIo::new(dir, false));
+        asset_server.set_processed_asset_folder(Some(cache.to_owned()));
+        asset_server.add_loader(SourceLoader(UppercaseProcessor(processed.clone())));
+        asset_server.add_loader(ProcessedLoader);
+
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<Text>())
+            .insert_resource(asset_server.clone())
+            .add_system(update_asset_storage_system::<Text>);
+
+        let handle: Handle<Text> = asset_server.load("a.txt");
+        for _ in 0..200 {
+            app.update();
+            if asset_server.get_load_state(&handle) == LoadState::Loaded {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        app.update();
+        let text = app.world.resource::<Assets<Text>>().get(&handle).unwrap();
+        text.0.clone()
+    }
+
+    struct ConfigurableProcessor {
+        version: u32,
+        settings: Vec<u8>,
+    }
+    impl AssetProcessor for ConfigurableProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _: &'a Path,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, Error>> {
+            Box::pin(async move { Ok(bytes.to_vec()) })
+        }
+
+        fn processed_extension(&self) -> &str {
+            "out"
+        }
+
+        fn version(&self) -> u32 {
+            self.version
+        }
+
+        fn serialized_settings(&self) -> Vec<u8> {
+            self.settings.clone()
+        }
+
+        fn type_name(&self) -> &'static str {
+            "ConfigurableProcessor"
+        }
+    }
+
+    #[test]
+    fn processed_file_name_changes_with_processor() {
+        let path = Path::new("a.txt");
+        let processor = ConfigurableProcessor {
+            version: 0,
+            settings: Vec::new(),
+        };
+        let name = processed_file_name(&processor, path, b"hello");
+        // the name must be stable across builds, or caches are invalidated on every update
+        assert_eq!(name, "e3132e9a5a888a4a1de9337a3a2cb2b6.out");
+
+        let other_version = ConfigurableProcessor {
+            version: 1,
+            settings: Vec::new(),
+        };
+        let other_settings = ConfigurableProcessor {
+            version: 0,
+            settings: vec![1],
+        };
+        let other_type = UppercaseProcessor(Default::default());
+        for other in [
+            processed_file_name(&other_version, path, b"hello"),
+            processed_file_name(&other_settings, path, b"hello"),
+            processed_file_name(&other_type, path, b"hello"),
+            processed_file_name(&processor, Path::new("b.txt"), b"hello"),
+            processed_file_name(&processor, path, b"world"),
+        ] {
+            assert_ne!(other.split('.').next(), name.split('.').next());
+        }
+    }
+
+    #[test]
+    fn processed_assets_are_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "hello").unwrap();
+        let cache = dir.path().join(".imported_assets");
+        let processed = Arc::new(AtomicUsize::new(0));
+        IoTaskPool::init(Default::default);
+
+        assert_eq!(load_text(dir.path(), &cache, &processed), "HELLO");
+        assert_eq!(processed.load(Ordering::SeqCst), 1);
+        assert_eq!(std::fs::read_dir(&cache).unwrap().count(), 1);
+
+        // a new asset server reuses the cached artifact
+        assert_eq!(load_text(dir.path(), &cache, &processed), "HELLO");
+        assert_eq!(processed.load(Ordering::SeqCst), 1);
+
+        // changing the source invalidates the artifact
+        std::fs::write(dir.path().join("a.txt"), "world").unwrap();
+        assert_eq!(load_text(dir.path(), &cache, &processed), "WORLD");
+        assert_eq!(processed.load(Ordering::SeqCst), 2);
+    }
+}
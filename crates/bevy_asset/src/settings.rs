@@ -0,0 +1,285 @@
+use crate::{
+    AddAsset, AssetEvent, AssetLoader, AssetServer, Assets, Handle, LoadContext, LoadedAsset,
+};
+use anyhow::Result;
+use bevy_app::{App, AppTypeRegistry, CoreSet, Plugin};
+use bevy_ecs::{prelude::*, schedule::FreeSystemSet};
+use bevy_reflect::{
+    serde::UntypedReflectDeserializer, FromReflect, Reflect, TypeRegistryArc, TypeUuid,
+};
+use bevy_utils::{tracing::warn, BoxedFuture};
+use serde::de::DeserializeSeed;
+use std::{any::type_name, marker::PhantomData};
+
+/// An asset holding the reflected value of a settings resource.
+///
+/// Settings assets are RON files with the `settings.ron` extension. They contain a map with a
+/// single entry from the full type name of the resource to its value, in the same format as the
+/// resources of a scene:
+///
+/// ```ron
+/// {
+///     "my_game::settings::MouseSettings": (
+///         sensitivity: 0.5,
+///     ),
+/// }
+/// ```
+///
+/// The resource type has to be registered in the [`AppTypeRegistry`]. Use the
+/// [`SettingsAssetPlugin`] to load a settings asset and keep the resource in sync with it.
+#[derive(Debug, TypeUuid)]
+#[uuid = "23d6e8e3-956d-40fb-9d4d-f2ab82f5e9c7"]
+pub struct SettingsAsset {
+    /// The reflected value of the settings resource.
+    pub value: Box<dyn Reflect>,
+}
+
+/// Loads [`SettingsAsset`]s from `settings.ron` files.
+#[derive(Debug)]
+pub struct SettingsAssetLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for SettingsAssetLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        SettingsAssetLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for SettingsAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let type_registry = self.type_registry.read();
+            let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
+            let value =
+                UntypedReflectDeserializer::new(&type_registry).deserialize(&mut deserializer)?;
+            load_context.set_default_asset(LoadedAsset::new(SettingsAsset { value }));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["settings.ron"]
+    }
+}
+
+/// The default [`SystemSet`] of the systems added by [`SettingsAssetPlugin`]s, which is part of
+/// [`CoreSet::PreUpdate`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct SettingsAssetSystem;
+
+/// A resource holding the handle of the [`SettingsAsset`] the `T` resource is synced with.
+#[derive(Resource, Debug)]
+pub struct SettingsHandle<T> {
+    /// The handle of the settings asset.
+    pub handle: Handle<SettingsAsset>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> SettingsHandle<T> {
+    /// Creates a new [`SettingsHandle`] for the settings asset with the given `handle`.
+    pub fn new(handle: Handle<SettingsAsset>) -> Self {
+        Self {
+            handle,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Loads the `T` resource from a [`SettingsAsset`] and applies the changes to the asset to the
+/// resource, which allows tweaking settings without recompiling when the
+/// [`AssetPlugin`](crate::AssetPlugin) watches for changes.
+///
+/// The resource is inserted or replaced by the [`sync_settings_asset`] system once the asset is
+/// loaded and whenever it is modified. The system runs in the [`SettingsAssetSystem`] by default,
+/// use [`SettingsAssetPlugin::in_set`] to apply the settings before the systems using them, e.g.
+/// in the set of the plugin owning the resource.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::SettingsAssetPlugin;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::{FromReflect, Reflect};
+/// #[derive(Resource, Reflect, FromReflect)]
+/// struct MouseSettings {
+///     sensitivity: f32,
+/// }
+///
+/// # fn build(app: &mut App) {
+/// app.register_type::<MouseSettings>()
+///     .add_plugin(SettingsAssetPlugin::<MouseSettings>::new("mouse.settings.ron"));
+/// # }
+/// ```
+pub struct SettingsAssetPlugin<T, S = SettingsAssetSystem> {
+    path: String,
+    set: S,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> SettingsAssetPlugin<T> {
+    /// Creates a plugin loading the `T` resource from the settings asset at `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            set: SettingsAssetSystem,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S> SettingsAssetPlugin<T, S> {
+    /// Runs the [`sync_settings_asset`] system of the plugin in the given `set` instead of the
+    /// [`SettingsAssetSystem`].
+    pub fn in_set<S2: FreeSystemSet + Clone>(self, set: S2) -> SettingsAssetPlugin<T, S2> {
+        SettingsAssetPlugin {
+            path: self.path,
+            set,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S> Plugin for SettingsAssetPlugin<T, S>
+where
+    T: Resource + FromReflect,
+    S: FreeSystemSet + Clone,
+{
+    fn build(&self, app: &mut App) {
+        if !app.world.contains_resource::<Assets<SettingsAsset>>() {
+            app.add_asset::<SettingsAsset>()
+                .init_asset_loader::<SettingsAssetLoader>()
+                .configure_set(SettingsAssetSystem.in_base_set(CoreSet::PreUpdate));
+        }
+
+        let handle = app.world.resource::<AssetServer>().load(self.path.as_str());
+        app.insert_resource(SettingsHandle::<T>::new(handle))
+            .add_system(sync_settings_asset::<T>.in_set(self.set.clone()));
+    }
+}
+
+/// Inserts the `T` resource from its [`SettingsAsset`] when the asset was loaded or modified.
+///
+/// The asset is looked up with the [`SettingsHandle<T>`] resource.
+pub fn sync_settings_asset<T: Resource + FromReflect>(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<SettingsAsset>>,
+    settings_handle: Res<SettingsHandle<T>>,
+    assets: Res<Assets<SettingsAsset>>,
+    resource: Option<ResMut<T>>,
+) {
+    let changed = events.iter().any(|event| match event {
+        AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+            *handle == settings_handle.handle
+        }
+        _ => false,
+    });
+    if !changed {
+        return;
+    }
+    let Some(asset) = assets.get(&settings_handle.handle) else {
+        return;
+    };
+
+    let settings = if asset.value.type_name() == type_name::<T>() {
+        T::from_reflect(&*asset.value)
+    } else {
+        None
+    };
+    match (settings, resource) {
+        (Some(settings), Some(mut resource)) => *resource = settings,
+        (Some(settings), None) => commands.insert_resource(settings),
+        (None, _) => warn!(
+            "The settings asset {:?} does not contain a valid `{}`, found a `{}`",
+            settings_handle.handle,
+            type_name::<T>(),
+            asset.value.type_name()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetServer, FileAssetIo};
+    use bevy_tasks::IoTaskPool;
+
+    #[derive(Resource, Reflect, FromReflect, Debug, PartialEq)]
+    struct TestSettings {
+        deadzone: f32,
+        bindings: Vec<String>,
+    }
+
+    #[test]
+    fn settings_asset_is_synced() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("test.settings.ron"),
+            r#"{
+                "bevy_asset::settings::tests::TestSettings": (
+                    deadzone: 0.1,
+                    bindings: ["Space"],
+                ),
+            }"#,
+        )
+        .unwrap();
+        IoTaskPool::init(Default::default);
+
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(FileAssetIo::new(dir.path(), false)))
+            .init_resource::<AppTypeRegistry>()
+            .register_type::<TestSettings>()
+            .register_type::<Vec<String>>()
+            .add_plugin(SettingsAssetPlugin::<TestSettings>::new(
+                "test.settings.ron",
+            ));
+
+        for _ in 0..200 {
+            app.update();
+            if app.world.contains_resource::<TestSettings>() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(
+            app.world.get_resource::<TestSettings>(),
+            Some(&TestSettings {
+                deadzone: 0.1,
+                bindings: vec!["Space".to_string()],
+            })
+        );
+
+        let handle = app
+            .world
+            .resource::<SettingsHandle<TestSettings>>()
+            .handle
+            .clone();
+        app.world
+            .resource_mut::<Assets<SettingsAsset>>()
+            .set_untracked(
+                handle,
+                SettingsAsset {
+                    value: Box::new(TestSettings {
+                        deadzone: 0.2,
+                        bindings: Vec::new(),
+                    }),
+                },
+            );
+        // The asset event is sent at the end of the frame and applied in the next one.
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world.resource::<TestSettings>(),
+            &TestSettings {
+                deadzone: 0.2,
+                bindings: Vec::new(),
+            }
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{Asset, AssetServer, HandleId};
+use bevy_utils::{Duration, Instant, Uuid};
+
+/// Decides when an asset without strong handles is unloaded from memory.
+///
+/// Set per asset type with [`AssetServer::set_unload_policy`] or
+/// [`AddAsset::set_unload_policy`](crate::AddAsset::set_unload_policy). When an asset is unloaded
+/// by its policy, an [`AssetEvent::Evicted`](crate::AssetEvent::Evicted) is sent in addition to the
+/// [`AssetEvent::Removed`](crate::AssetEvent::Removed).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum UnloadPolicy {
+    /// Unloads the asset as soon as its last strong handle is dropped.
+    #[default]
+    WhenUnused,
+    /// Keeps the asset loaded for the given duration after its last strong handle is dropped, so
+    /// that it can be reused without reloading it if it is requested again in the meantime.
+    KeepAlive(Duration),
+    /// Keeps the asset loaded until [`AssetServer::unload_unused_assets`] is called.
+    Manual,
+}
+
+/// An asset without strong handles, waiting to be unloaded by its [`UnloadPolicy`].
+pub(crate) struct PendingUnload {
+    pub(crate) type_uuid: Uuid,
+    /// `None` for assets that are only unloaded manually.
+    pub(crate) deadline: Option<Instant>,
+}
+
+impl AssetServer {
+    /// Sets the [`UnloadPolicy`] of the asset type `T`.
+    ///
+    /// Assets that are already waiting to be unloaded keep their previous policy.
+    pub fn set_unload_policy<T: Asset>(&self, policy: UnloadPolicy) {
+        self.server
+            .unload_policies
+            .write()
+            .insert(T::TYPE_UUID, policy);
+    }
+
+    /// Returns the [`UnloadPolicy`] of the asset type `T`.
+    pub fn unload_policy<T: Asset>(&self) -> UnloadPolicy {
+        self.server
+            .unload_policies
+            .read()
+            .get(&T::TYPE_UUID)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of strong handles to the asset with the given `id`.
+    ///
+    /// The count is updated by the [`free_unused_assets_system`](crate::free_unused_assets_system)
+    /// once per frame, so it may lag behind handles that were cloned or dropped since.
+    pub fn strong_count<H: Into<HandleId>>(&self, id: H) -> usize {
+        self.server
+            .asset_ref_counter
+            .ref_counts
+            .read()
+            .get(&id.into())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Unloads all assets without strong handles that are kept loaded by their [`UnloadPolicy`],
+    /// i.e. assets with the [`UnloadPolicy::Manual`] and [`UnloadPolicy::KeepAlive`] policies.
+    pub fn unload_unused_assets(&self) {
+        self.unload_pending_assets(None);
+    }
+
+    /// Unloads the pending assets whose deadline has passed at `now`, or all of them if `now` is
+    /// `None`. Assets that got a strong handle again are no longer pending.
+    pub(crate) fn unload_pending_assets(&self, now: Option<Instant>) {
+        let mut pending_unloads = self.server.asset_ref_counter.pending_unloads.lock();
+        if pending_unloads.is_empty() {
+            return;
+        }
+        let ref_counts = self.server.asset_ref_counter.ref_counts.read();
+        let asset_lifecycles = self.server.asset_lifecycles.read();
+        pending_unloads.retain(|&id, pending| {
+            if ref_counts.get(&id) != Some(&0) {
+                return false;
+            }
+            let expired = match (now, pending.deadline) {
+                (None, _) => true,
+                (Some(now), Some(deadline)) => deadline <= now,
+                (Some(_), None) => false,
+            };
+            if expired {
+                if let Some(asset_lifecycle) = asset_lifecycles.get(&pending.type_uuid) {
+                    asset_lifecycle.free_asset(id);
+                }
+            }
+            !expired
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        free_unused_assets_system, update_asset_storage_system, AssetEvent, Assets, Handle,
+        MemoryAssetIo,
+    };
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::TypeUuid;
+
+    #[derive(Debug, TypeUuid)]
+    #[uuid = "0f6f0fd5-6f35-4b8a-a1c7-7c0a4b1f5e22"]
+    struct Texture;
+
+    #[derive(Resource, Default)]
+    struct Evicted(usize);
+
+    fn count_evicted(mut events: EventReader<AssetEvent<Texture>>, mut evicted: ResMut<Evicted>) {
+        evicted.0 += events
+            .iter()
+            .filter(|event| matches!(event, AssetEvent::Evicted { .. }))
+            .count();
+    }
+
+    fn setup(policy: UnloadPolicy) -> App {
+        let asset_server = AssetServer::new(MemoryAssetIo::default());
+        asset_server.set_unload_policy::<Texture>(policy);
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<Texture>())
+            .insert_resource(asset_server)
+            .add_event::<AssetEvent<Texture>>()
+            .init_resource::<Evicted>()
+            .add_systems((
+                free_unused_assets_system,
+                update_asset_storage_system::<Texture>.after(free_unused_assets_system),
+                Assets::<Texture>::asset_event_system.after(update_asset_storage_system::<Texture>),
+                count_evicted.after(Assets::<Texture>::asset_event_system),
+            ));
+        app
+    }
+
+    fn add_and_drop(app: &mut App) -> HandleId {
+        let handle: Handle<Texture> = app.world.resource_mut::<Assets<Texture>>().add(Texture);
+        let id = handle.id();
+        app.update();
+        assert_eq!(app.world.resource::<AssetServer>().strong_count(id), 1);
+        drop(handle);
+        // the first update marks the asset as unused, the second one applies the policy
+        app.update();
+        app.update();
+        id
+    }
+
+    #[test]
+    fn unload_when_unused() {
+        let mut app = setup(UnloadPolicy::WhenUnused);
+        let id = add_and_drop(&mut app);
+        app.update();
+        assert!(app
+            .world
+            .resource::<Assets<Texture>>()
+            .get(&Handle::weak(id))
+            .is_none());
+        assert_eq!(app.world.resource::<Evicted>().0, 1);
+    }
+
+    #[test]
+    fn keep_alive() {
+        let mut app = setup(UnloadPolicy::KeepAlive(Duration::from_millis(50)));
+        let id = add_and_drop(&mut app);
+        app.update();
+        assert!(app
+            .world
+            .resource::<Assets<Texture>>()
+            .get(&Handle::weak(id))
+            .is_some());
+
+        std::thread::sleep(Duration::from_millis(60));
+        app.update();
+        app.update();
+        assert!(app
+            .world
+            .resource::<Assets<Texture>>()
+            .get(&Handle::weak(id))
+            .is_none());
+        assert_eq!(app.world.resource::<Evicted>().0, 1);
+    }
+
+    #[test]
+    fn manual_unload() {
+        let mut app = setup(UnloadPolicy::Manual);
+        let id = add_and_drop(&mut app);
+        app.update();
+        assert!(app
+            .world
+            .resource::<Assets<Texture>>()
+            .get(&Handle::weak(id))
+            .is_some());
+
+        app.world.resource::<AssetServer>().unload_unused_assets();
+        app.update();
+        assert!(app
+            .world
+            .resource::<Assets<Texture>>()
+            .get(&Handle::weak(id))
+            .is_none());
+        assert_eq!(app.world.resource::<Evicted>().0, 1);
+    }
+
+    #[test]
+    fn reacquired_assets_stay_loaded() {
+        let mut app = setup(UnloadPolicy::Manual);
+        let id = add_and_drop(&mut app);
+        let handle = app.world.resource_mut::<Assets<Texture>>().get_handle(id);
+        app.update();
+
+        app.world.resource::<AssetServer>().unload_unused_assets();
+        app.update();
+        assert!(app.world.resource::<Assets<Texture>>().contains(&handle));
+        assert_eq!(app.world.resource::<Evicted>().0, 0);
+    }
+}
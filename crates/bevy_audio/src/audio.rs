@@ -1,5 +1,5 @@
-use crate::{AudioSink, AudioSource, Decodable, SpatialAudioSink};
-use bevy_asset::{Asset, Handle, HandleId};
+use crate::{AudioBus, AudioListener, AudioSink, AudioSource, Decodable, SpatialAudioSink};
+use bevy_asset::{Asset, AssetServer, Handle, HandleId};
 use bevy_ecs::system::Resource;
 use bevy_math::Vec3;
 use bevy_transform::prelude::Transform;
@@ -23,6 +23,11 @@ where
 {
     /// Queue for playing audio from asset handles
     pub(crate) queue: RwLock<VecDeque<AudioToPlay<Source>>>,
+    /// Used to create strong handles to sinks before they are added to their
+    /// [`Assets`](bevy_asset::Assets)
+    ///
+    /// Set by the [`AudioPlugin`](crate::AudioPlugin) once an [`AssetServer`] is available.
+    pub(crate) asset_server: Option<AssetServer>,
 }
 
 impl<Source: Asset> fmt::Debug for Audio<Source>
@@ -41,6 +46,7 @@ where
     fn default() -> Self {
         Self {
             queue: Default::default(),
+            asset_server: None,
         }
     }
 }
@@ -60,23 +66,32 @@ where
     /// }
     /// ```
     ///
-    /// Returns a weak [`Handle`] to the [`AudioSink`]. If this handle isn't changed to a
-    /// strong one, the sink will be detached and the sound will continue playing. Changing it
-    /// to a strong handle allows you to control the playback through the [`AudioSink`] asset.
+    /// Returns a strong [`Handle`] to the [`AudioSink`], which can be used to control the playback
+    /// once the audio source is loaded and the sound has started. If all the handles to the sink
+    /// are dropped, the sink is detached and the sound continues playing. Without an
+    /// [`AssetServer`], the handle is weak and the sink is detached right away.
     ///
     /// ```
-    /// # use bevy_ecs::system::Res;
-    /// # use bevy_asset::{AssetServer, Assets};
-    /// # use bevy_audio::{Audio, AudioSink};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_asset::{AssetServer, Assets, Handle};
+    /// # use bevy_audio::{Audio, AudioSink, AudioSinkPlayback};
+    /// # use std::time::Duration;
+    /// #[derive(Resource)]
+    /// struct Music(Handle<AudioSink>);
+    ///
     /// fn play_audio_system(
+    ///     mut commands: Commands,
     ///     asset_server: Res<AssetServer>,
     ///     audio: Res<Audio>,
-    ///     audio_sinks: Res<Assets<AudioSink>>,
     /// ) {
-    ///     // This is a weak handle, and can't be used to control playback.
-    ///     let weak_handle = audio.play(asset_server.load("my_sound.ogg"));
-    ///     // This is now a strong handle, and can be used to control playback.
-    ///     let strong_handle = audio_sinks.get_handle(weak_handle);
+    ///     let handle = audio.play(asset_server.load("my_sound.ogg"));
+    ///     commands.insert_resource(Music(handle));
+    /// }
+    ///
+    /// fn restart_audio_system(music: Res<Music>, audio_sinks: Res<Assets<AudioSink>>) {
+    ///     if let Some(sink) = audio_sinks.get(&music.0) {
+    ///         sink.seek(Duration::ZERO);
+    ///     }
     /// }
     /// ```
     pub fn play(&self, audio_source: Handle<Source>) -> Handle<AudioSink> {
@@ -88,7 +103,7 @@ where
             spatial: None,
         };
         self.queue.write().push_back(config);
-        Handle::<AudioSink>::weak(id)
+        self.sink_handle(id)
     }
 
     /// Play audio from a [`Handle`] to the audio source with [`PlaybackSettings`] that
@@ -121,7 +136,7 @@ where
             spatial: None,
         };
         self.queue.write().push_back(config);
-        Handle::<AudioSink>::weak(id)
+        self.sink_handle(id)
     }
 
     /// Play audio from a [`Handle`] to the audio source, placing the listener at the given
@@ -149,33 +164,11 @@ where
     /// }
     /// ```
     ///
-    /// Returns a weak [`Handle`] to the [`SpatialAudioSink`]. If this handle isn't changed to a
-    /// strong one, the sink will be detached and the sound will continue playing. Changing it
-    /// to a strong handle allows you to control the playback, or move the listener and emitter
-    /// through the [`SpatialAudioSink`] asset.
-    ///
-    /// ```
-    /// # use bevy_ecs::system::Res;
-    /// # use bevy_asset::{AssetServer, Assets};
-    /// # use bevy_audio::{Audio, SpatialAudioSink};
-    /// # use bevy_math::Vec3;
-    /// # use bevy_transform::prelude::Transform;
-    /// fn play_spatial_audio_system(
-    ///     asset_server: Res<AssetServer>,
-    ///     audio: Res<Audio>,
-    ///     spatial_audio_sinks: Res<Assets<SpatialAudioSink>>,
-    /// ) {
-    ///     // This is a weak handle, and can't be used to control playback.
-    ///     let weak_handle = audio.play_spatial(
-    ///         asset_server.load("my_sound.ogg"),
-    ///         Transform::IDENTITY,
-    ///         1.0,
-    ///         Vec3::new(-2.0, 0.0, 1.0),
-    ///     );
-    ///     // This is now a strong handle, and can be used to control playback, or move the emitter.
-    ///     let strong_handle = spatial_audio_sinks.get_handle(weak_handle);
-    /// }
-    /// ```
+    /// Returns a strong [`Handle`] to the [`SpatialAudioSink`], which can be used to control the
+    /// playback, or move the listener and emitter, once the audio source is loaded and the sound
+    /// has started. If all the handles to the sink are dropped, the sink is detached and the sound
+    /// continues playing. Without an [`AssetServer`], the handle is weak and the sink is detached
+    /// right away.
     pub fn play_spatial(
         &self,
         audio_source: Handle<Source>,
@@ -195,7 +188,7 @@ where
             }),
         };
         self.queue.write().push_back(config);
-        Handle::<SpatialAudioSink>::weak(id)
+        self.sink_handle(id)
     }
 
     /// Play spatial audio from a [`Handle`] to the audio source with [`PlaybackSettings`] that
@@ -247,7 +240,58 @@ where
             }),
         };
         self.queue.write().push_back(config);
-        Handle::<SpatialAudioSink>::weak(id)
+        self.sink_handle(id)
+    }
+
+    /// Play spatial audio from a [`Handle`] to the audio source, with the listener and the emitter
+    /// placed by a [`SpatialAudioEmitter`](crate::SpatialAudioEmitter).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_asset::AssetServer;
+    /// # use bevy_audio::{Audio, SpatialAudioEmitter};
+    /// # use bevy_transform::prelude::{Transform, TransformBundle};
+    /// fn spawn_emitter(mut commands: Commands, asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let handle = audio.play_emitted(asset_server.load("my_sound.ogg"));
+    ///     commands.spawn((
+    ///         SpatialAudioEmitter::new(handle),
+    ///         TransformBundle::from_transform(Transform::from_xyz(5.0, 0.0, 0.0)),
+    ///     ));
+    /// }
+    /// ```
+    ///
+    /// The sound starts at the position of the listener, and is moved by
+    /// [`update_spatial_audio`](crate::update_spatial_audio) in the frame it starts.
+    pub fn play_emitted(&self, audio_source: Handle<Source>) -> Handle<SpatialAudioSink> {
+        self.play_emitted_with_settings(audio_source, PlaybackSettings::ONCE)
+    }
+
+    /// Play spatial audio from a [`Handle`] to the audio source with [`PlaybackSettings`] that
+    /// allows looping or changing volume from the start, with the listener and the emitter placed
+    /// by a [`SpatialAudioEmitter`](crate::SpatialAudioEmitter).
+    ///
+    /// See [`Self::play_emitted`] for an example.
+    pub fn play_emitted_with_settings(
+        &self,
+        audio_source: Handle<Source>,
+        settings: PlaybackSettings,
+    ) -> Handle<SpatialAudioSink> {
+        self.play_spatial_with_settings(
+            audio_source,
+            settings,
+            Transform::IDENTITY,
+            AudioListener::default().gap,
+            Vec3::ZERO,
+        )
+    }
+
+    /// Returns a strong handle to the sink with the given id, or a weak one without an
+    /// [`AssetServer`].
+    fn sink_handle<T: Asset>(&self, id: HandleId) -> Handle<T> {
+        match &self.asset_server {
+            Some(asset_server) => asset_server.get_handle(id),
+            None => Handle::weak(id),
+        }
     }
 }
 
@@ -260,6 +304,8 @@ pub struct PlaybackSettings {
     pub volume: f32,
     /// Speed to play at.
     pub speed: f32,
+    /// The [`AudioBus`] to play on, whose volume and effects are applied to the sound.
+    pub bus: AudioBus,
 }
 
 impl Default for PlaybackSettings {
@@ -274,6 +320,7 @@ impl PlaybackSettings {
         repeat: false,
         volume: 1.0,
         speed: 1.0,
+        bus: AudioBus::Master,
     };
 
     /// Will play the associate audio source in a loop.
@@ -281,6 +328,7 @@ impl PlaybackSettings {
         repeat: true,
         volume: 1.0,
         speed: 1.0,
+        bus: AudioBus::Master,
     };
 
     /// Helper to set the volume from start of playback.
@@ -294,9 +342,15 @@ impl PlaybackSettings {
         self.speed = speed;
         self
     }
+
+    /// Helper to route the sound to an [`AudioBus`].
+    pub const fn with_bus(mut self, bus: AudioBus) -> Self {
+        self.bus = bus;
+        self
+    }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub(crate) struct SpatialSettings {
     pub(crate) left_ear: [f32; 3],
     pub(crate) right_ear: [f32; 3],
@@ -326,3 +380,28 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AudioPlugin;
+    use bevy_app::App;
+    use bevy_asset::AssetPlugin;
+
+    #[test]
+    fn audio_without_asset_server_returns_weak_handles() {
+        let audio = Audio::<AudioSource>::default();
+        let handle = audio.play(Handle::default());
+        assert!(handle.is_weak());
+        assert_eq!(audio.queue.read().len(), 1);
+    }
+
+    #[test]
+    fn audio_returns_strong_handles() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_plugin(AudioPlugin);
+        let handle = app.world.resource::<Audio>().play(Handle::default());
+        assert!(handle.is_strong());
+    }
+}
@@ -1,9 +1,16 @@
-use crate::{Audio, AudioSource, Decodable, SpatialAudioSink, SpatialSettings};
-use bevy_asset::{Asset, Assets};
-use bevy_ecs::system::{Res, ResMut, Resource};
+use crate::{
+    Audio, AudioBus, AudioBuses, AudioSource, BusSource, Decodable, PlaybackState,
+    SpatialAudioSink, SpatialSettings,
+};
+use bevy_asset::{Asset, AssetServer, Assets, Handle, HandleId};
+use bevy_ecs::{
+    event::EventWriter,
+    system::{Res, ResMut, Resource},
+};
 use bevy_utils::tracing::warn;
+use parking_lot::Mutex;
 use rodio::{OutputStream, OutputStreamHandle, Sink, Source, SpatialSink};
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::atomic::Ordering, time::Duration};
 
 use crate::AudioSink;
 
@@ -55,15 +62,23 @@ where
     Source: Asset + Decodable,
     f32: rodio::cpal::FromSample<Source::DecoderItem>,
 {
-    fn play_source(&self, audio_source: &Source, repeat: bool) -> Option<Sink> {
+    fn play_source(
+        &self,
+        audio_source: &Source,
+        playback: &PlaybackState,
+        start: Duration,
+        audio_buses: &AudioBuses,
+    ) -> Option<Sink> {
         self.stream_handle
             .as_ref()
             .and_then(|stream_handle| match Sink::try_new(stream_handle) {
                 Ok(sink) => {
-                    if repeat {
-                        sink.append(audio_source.decoder().repeat_infinite());
-                    } else {
-                        sink.append(audio_source.decoder());
+                    let bus = playback.bus;
+                    let source = audio_source.decoder().skip_duration(start);
+                    append(&sink, bus_source(audio_buses, bus, source));
+                    if playback.repeat {
+                        let source = audio_source.decoder().repeat_infinite();
+                        append(&sink, bus_source(audio_buses, bus, source));
                     }
                     Some(sink)
                 }
@@ -77,8 +92,10 @@ where
     fn play_spatial_source(
         &self,
         audio_source: &Source,
-        repeat: bool,
+        playback: &PlaybackState,
+        start: Duration,
         spatial: SpatialSettings,
+        audio_buses: &AudioBuses,
     ) -> Option<SpatialSink> {
         self.stream_handle.as_ref().and_then(|stream_handle| {
             match SpatialSink::try_new(
@@ -88,10 +105,12 @@ where
                 spatial.right_ear,
             ) {
                 Ok(sink) => {
-                    if repeat {
-                        sink.append(audio_source.decoder().repeat_infinite());
-                    } else {
-                        sink.append(audio_source.decoder());
+                    let bus = playback.bus;
+                    let source = audio_source.decoder().skip_duration(start);
+                    append_spatial(&sink, bus_source(audio_buses, bus, source));
+                    if playback.repeat {
+                        let source = audio_source.decoder().repeat_infinite();
+                        append_spatial(&sink, bus_source(audio_buses, bus, source));
                     }
                     Some(sink)
                 }
@@ -109,6 +128,7 @@ where
         audio: &mut Audio<Source>,
         sinks: &mut Assets<AudioSink>,
         spatial_sinks: &mut Assets<SpatialAudioSink>,
+        audio_buses: &AudioBuses,
     ) {
         let mut queue = audio.queue.write();
         let len = queue.len();
@@ -116,23 +136,43 @@ where
         while i < len {
             let config = queue.pop_front().unwrap();
             if let Some(audio_source) = audio_sources.get(&config.source_handle) {
+                let playback =
+                    PlaybackState::new(config.source_handle.clone_untyped(), &config.settings);
                 if let Some(spatial) = config.spatial {
-                    if let Some(sink) =
-                        self.play_spatial_source(audio_source, config.settings.repeat, spatial)
-                    {
+                    if let Some(sink) = self.play_spatial_source(
+                        audio_source,
+                        &playback,
+                        Duration::ZERO,
+                        spatial,
+                        audio_buses,
+                    ) {
                         sink.set_speed(config.settings.speed);
                         sink.set_volume(config.settings.volume);
 
-                        // don't keep the strong handle. there is no way to return it to the user here as it is async
-                        let _ = spatial_sinks
-                            .set(config.sink_handle, SpatialAudioSink { sink: Some(sink) });
+                        // the user keeps the handle returned by `Audio`, this one can be dropped
+                        let _ = spatial_sinks.set(
+                            config.sink_handle,
+                            SpatialAudioSink {
+                                sink: Some(sink),
+                                playback,
+                                spatial: Mutex::new(spatial),
+                            },
+                        );
                     }
-                } else if let Some(sink) = self.play_source(audio_source, config.settings.repeat) {
+                } else if let Some(sink) =
+                    self.play_source(audio_source, &playback, Duration::ZERO, audio_buses)
+                {
                     sink.set_speed(config.settings.speed);
                     sink.set_volume(config.settings.volume);
 
-                    // don't keep the strong handle. there is no way to return it to the user here as it is async
-                    let _ = sinks.set(config.sink_handle, AudioSink { sink: Some(sink) });
+                    // the user keeps the handle returned by `Audio`, this one can be dropped
+                    let _ = sinks.set(
+                        config.sink_handle,
+                        AudioSink {
+                            sink: Some(sink),
+                            playback,
+                        },
+                    );
                 }
             } else {
                 // audio source hasn't loaded yet. add it back to the queue
@@ -141,19 +181,260 @@ where
             i += 1;
         }
     }
+
+    /// Restarts the sinks playing a `Source` that have been
+    /// [seeked](crate::AudioSinkPlayback::seek).
+    ///
+    /// rodio can't seek a playing sound, so the sink is replaced by a new one playing the audio
+    /// source from the requested position.
+    fn seek_sinks(
+        &self,
+        audio_sources: &Assets<Source>,
+        sinks: &mut Assets<AudioSink>,
+        spatial_sinks: &mut Assets<SpatialAudioSink>,
+        audio_buses: &AudioBuses,
+    ) {
+        let seeked = |playback: &PlaybackState| {
+            playback.seek.lock().is_some() && audio_sources.contains(&playback.source.typed_weak())
+        };
+        let seeked_sinks = sinks
+            .iter()
+            .filter(|(_, sink)| seeked(&sink.playback))
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        for id in seeked_sinks {
+            let sink = sinks.get_mut(&Handle::weak(id)).unwrap();
+            let start = sink.playback.seek.lock().take().unwrap();
+            let audio_source = audio_sources
+                .get(&sink.playback.source.typed_weak())
+                .unwrap();
+            if let Some(new_sink) =
+                self.play_source(audio_source, &sink.playback, start, audio_buses)
+            {
+                sink.restart(new_sink);
+            }
+        }
+
+        let seeked_sinks = spatial_sinks
+            .iter()
+            .filter(|(_, sink)| seeked(&sink.playback))
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        for id in seeked_sinks {
+            let sink = spatial_sinks.get_mut(&Handle::weak(id)).unwrap();
+            let start = sink.playback.seek.lock().take().unwrap();
+            let audio_source = audio_sources
+                .get(&sink.playback.source.typed_weak())
+                .unwrap();
+            let spatial = *sink.spatial.lock();
+            if let Some(new_sink) =
+                self.play_spatial_source(audio_source, &sink.playback, start, spatial, audio_buses)
+            {
+                sink.restart(new_sink);
+            }
+        }
+    }
 }
 
-/// Plays audio currently queued in the [`Audio`] resource through the [`AudioOutput`] resource
+/// Plays audio currently queued in the [`Audio`] resource through the [`AudioOutput`] resource,
+/// and restarts the sinks that have been [seeked](crate::AudioSinkPlayback::seek)
 pub fn play_queued_audio_system<Source: Asset + Decodable>(
     audio_output: Res<AudioOutput<Source>>,
     audio_sources: Option<Res<Assets<Source>>>,
     mut audio: ResMut<Audio<Source>>,
     mut sinks: ResMut<Assets<AudioSink>>,
     mut spatial_sinks: ResMut<Assets<SpatialAudioSink>>,
+    audio_buses: Res<AudioBuses>,
+    asset_server: Option<Res<AssetServer>>,
 ) where
     f32: rodio::cpal::FromSample<Source::DecoderItem>,
 {
+    // `Audio` may have been created before the asset server
+    if audio.asset_server.is_none() {
+        audio.asset_server = asset_server.map(|asset_server| asset_server.clone());
+    }
     if let Some(audio_sources) = audio_sources {
-        audio_output.try_play_queued(&*audio_sources, &mut *audio, &mut sinks, &mut spatial_sinks);
+        audio_output.try_play_queued(
+            &*audio_sources,
+            &mut *audio,
+            &mut sinks,
+            &mut spatial_sinks,
+            &audio_buses,
+        );
+        audio_output.seek_sinks(
+            &*audio_sources,
+            &mut sinks,
+            &mut spatial_sinks,
+            &audio_buses,
+        );
     };
 }
+
+/// Converts `source` to the samples passed to the effects of `bus`, and applies them.
+fn bus_source<S>(audio_buses: &AudioBuses, bus: AudioBus, source: S) -> BusSource
+where
+    S: Source + Send + 'static,
+    S::Item: rodio::Sample,
+    f32: rodio::cpal::FromSample<S::Item>,
+{
+    audio_buses.apply(bus, Box::new(source.convert_samples()))
+}
+
+// `Sink::append` is called outside of `AudioOutput`, where the bound on `Source::DecoderItem`
+// prevents the compiler from finding that `f32` can be converted to itself.
+fn append(sink: &Sink, source: BusSource) {
+    sink.append(source);
+}
+
+fn append_spatial(sink: &SpatialSink, source: BusSource) {
+    sink.append(source);
+}
+
+/// An event sent when an [`AudioSink`] or a [`SpatialAudioSink`] has played its whole sound, or
+/// has been stopped.
+///
+/// Looping sounds only complete when they are stopped. Sinks are only checked while a [`Handle`]
+/// to them is kept, such as the one returned by [`Audio::play`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::Handle;
+/// # use bevy_audio::{AudioSink, PlaybackCompleted};
+/// #[derive(Resource)]
+/// struct Music(Handle<AudioSink>);
+///
+/// fn music_completed(music: Res<Music>, mut completed: EventReader<PlaybackCompleted>) {
+///     for event in completed.iter() {
+///         if event.id == music.0.id() {
+///             println!("The music is over");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaybackCompleted {
+    /// The id of the [`AudioSink`] or [`SpatialAudioSink`] that completed.
+    pub id: HandleId,
+}
+
+/// Sends a [`PlaybackCompleted`] event for each sink that finished playing since the last frame.
+pub fn playback_completed_system(
+    sinks: Res<Assets<AudioSink>>,
+    spatial_sinks: Res<Assets<SpatialAudioSink>>,
+    mut playback_completed: EventWriter<PlaybackCompleted>,
+) {
+    for (id, sink) in sinks.iter() {
+        if sink.sink.as_ref().unwrap().empty()
+            && !sink.playback.completed.swap(true, Ordering::Relaxed)
+        {
+            playback_completed.send(PlaybackCompleted { id });
+        }
+    }
+    for (id, sink) in spatial_sinks.iter() {
+        if sink.sink.as_ref().unwrap().empty()
+            && !sink.playback.completed.swap(true, Ordering::Relaxed)
+        {
+            playback_completed.send(PlaybackCompleted { id });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AudioPlugin, AudioSinkPlayback, PlaybackSettings};
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, HandleUntyped};
+    use bevy_ecs::event::Events;
+
+    /// Creates an [`AudioSink`] that isn't connected to an audio device, and is playing as long as
+    /// `playing` is true.
+    fn idle_sink(playing: bool) -> AudioSink {
+        AudioSink {
+            sink: Some(idle_rodio_sink(playing)),
+            playback: PlaybackState::new(
+                HandleUntyped::weak(HandleId::default::<AudioSource>()),
+                &PlaybackSettings::ONCE,
+            ),
+        }
+    }
+
+    fn idle_rodio_sink(playing: bool) -> Sink {
+        let (sink, _) = Sink::new_idle();
+        if playing {
+            // Nothing consumes the output of an idle sink, so the sound never ends
+            sink.append(rodio::source::Zero::<f32>::new(1, 44100));
+        }
+        sink
+    }
+
+    fn completed(app: &mut App) -> Vec<HandleId> {
+        app.world
+            .resource_mut::<Events<PlaybackCompleted>>()
+            .drain()
+            .map(|event| event.id)
+            .collect()
+    }
+
+    #[test]
+    fn playback_completed_is_sent_once_per_playback() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_plugin(AudioPlugin);
+        let mut sinks = app.world.resource_mut::<Assets<AudioSink>>();
+        let playing = sinks.add(idle_sink(true));
+        let finished = sinks.add(idle_sink(false));
+
+        app.update();
+        assert_eq!(completed(&mut app), vec![finished.id()]);
+        app.update();
+        assert!(completed(&mut app).is_empty());
+
+        // Seeking restarts the sound, so it can complete again
+        let mut sinks = app.world.resource_mut::<Assets<AudioSink>>();
+        sinks
+            .get_mut(&finished)
+            .unwrap()
+            .restart(idle_rodio_sink(false));
+        app.update();
+        assert_eq!(completed(&mut app), vec![finished.id()]);
+        assert!(app.world.resource::<Assets<AudioSink>>().contains(&playing));
+    }
+
+    #[test]
+    fn seeking_keeps_the_playback_settings() {
+        let mut sink = idle_sink(true);
+        sink.set_volume(0.5);
+        sink.set_speed(2.0);
+        sink.pause();
+        sink.seek(Duration::from_secs(3));
+        assert_eq!(*sink.playback.seek.lock(), Some(Duration::from_secs(3)));
+
+        sink.restart(idle_rodio_sink(true));
+        assert_eq!(sink.volume(), 0.5);
+        assert_eq!(sink.sink.as_ref().unwrap().volume(), 0.5);
+        assert_eq!(sink.speed(), 2.0);
+        assert!(sink.is_paused());
+    }
+
+    #[test]
+    fn seeking_waits_for_the_audio_source() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_plugin(AudioPlugin);
+        let handle = app
+            .world
+            .resource_mut::<Assets<AudioSink>>()
+            .add(idle_sink(true));
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks.get(&handle).unwrap().seek(Duration::from_secs(1));
+
+        // The audio source of the sink is not loaded
+        app.update();
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        assert_eq!(
+            *sinks.get(&handle).unwrap().playback.seek.lock(),
+            Some(Duration::from_secs(1))
+        );
+    }
+}
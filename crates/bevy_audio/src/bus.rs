@@ -0,0 +1,298 @@
+use bevy_ecs::system::Resource;
+use bevy_utils::HashMap;
+use parking_lot::Mutex;
+use rodio::Source;
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// A group of sounds whose volume can be controlled together through [`AudioBuses`].
+///
+/// Sounds are routed to a bus when they are played, with
+/// [`PlaybackSettings::with_bus`](crate::PlaybackSettings::with_bus). Every bus is itself routed
+/// to [`AudioBus::Master`], so the volume of a sound is multiplied by the volume of its bus and by
+/// the master volume.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+    /// The bus controlling all sounds, used by sounds that aren't routed to another bus.
+    #[default]
+    Master,
+    /// The bus for background music.
+    Music,
+    /// The bus for sound effects.
+    Sfx,
+    /// The bus for dialogue.
+    Voice,
+    /// A bus defined by the application.
+    Custom(&'static str),
+}
+
+/// The samples of a sound played on an [`AudioBus`], as passed to the effects of the bus.
+pub type BusSource = Box<dyn Source<Item = f32> + Send>;
+
+/// The state of an [`AudioBus`].
+struct BusState {
+    volume: f32,
+    muted: bool,
+    effect: Option<Box<dyn Fn(BusSource) -> BusSource + Send + Sync>>,
+}
+
+impl Default for BusState {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            muted: false,
+            effect: None,
+        }
+    }
+}
+
+/// Controls the volume and the effects of each [`AudioBus`].
+///
+/// Changes to the volume apply to all the sounds routed to the bus, including the ones that are
+/// already playing and the ones whose sink handles have been dropped.
+///
+/// ```
+/// # use bevy_ecs::system::{In, ResMut};
+/// # use bevy_audio::{AudioBus, AudioBuses};
+/// fn music_volume_slider(In(volume): In<f32>, mut audio_buses: ResMut<AudioBuses>) {
+///     audio_buses.set_volume(AudioBus::Music, volume);
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct AudioBuses {
+    buses: HashMap<AudioBus, BusState>,
+    /// The gain of each bus sounds have been played on, shared with the [`BusGain`] of the sounds.
+    gains: Mutex<HashMap<AudioBus, Arc<AtomicU32>>>,
+}
+
+impl fmt::Debug for AudioBuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for (bus, state) in &self.buses {
+            map.entry(bus, &(state.volume, state.muted));
+        }
+        map.finish()
+    }
+}
+
+impl AudioBuses {
+    /// Gets the volume of `bus`, without the master volume.
+    ///
+    /// The default volume of all buses is `1.0`.
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        self.buses.get(&bus).map_or(1.0, |state| state.volume)
+    }
+
+    /// Changes the volume of `bus`.
+    ///
+    /// The volume of the sounds routed to this bus is multiplied by this value.
+    pub fn set_volume(&mut self, bus: AudioBus, volume: f32) {
+        self.buses.entry(bus).or_default().volume = volume;
+        self.update_gains();
+    }
+
+    /// Is `bus` muted?
+    ///
+    /// This doesn't take into account whether [`AudioBus::Master`] is muted.
+    pub fn is_muted(&self, bus: AudioBus) -> bool {
+        matches!(self.buses.get(&bus), Some(state) if state.muted)
+    }
+
+    /// Mutes or unmutes `bus`, keeping its volume.
+    pub fn set_muted(&mut self, bus: AudioBus, muted: bool) {
+        self.buses.entry(bus).or_default().muted = muted;
+        self.update_gains();
+    }
+
+    /// Mutes `bus` if it isn't muted, and unmutes it otherwise.
+    pub fn toggle_mute(&mut self, bus: AudioBus) {
+        let state = self.buses.entry(bus).or_default();
+        state.muted = !state.muted;
+        self.update_gains();
+    }
+
+    /// Gets the factor applied to the volume of the sounds routed to `bus`, taking into account
+    /// the master volume and whether the buses are muted.
+    pub fn gain(&self, bus: AudioBus) -> f32 {
+        let gain = |bus| {
+            if self.is_muted(bus) {
+                0.0
+            } else {
+                self.volume(bus)
+            }
+        };
+        if bus == AudioBus::Master {
+            gain(bus)
+        } else {
+            gain(bus) * gain(AudioBus::Master)
+        }
+    }
+
+    /// Sets an effect applied to the sounds routed to `bus`, such as a filter or a reverb.
+    ///
+    /// The effect applies to the sounds started after this call, including sounds that are
+    /// [seeked](crate::AudioSinkPlayback::seek). The effect of a bus is applied before the
+    /// effect of [`AudioBus::Master`].
+    ///
+    /// ```
+    /// # use bevy_ecs::system::ResMut;
+    /// # use bevy_audio::{AudioBus, AudioBuses, BusSource, Source};
+    /// fn muffle_sound_effects(mut audio_buses: ResMut<AudioBuses>) {
+    ///     audio_buses.set_effect(AudioBus::Sfx, |source| -> BusSource {
+    ///         Box::new(source.low_pass(1000))
+    ///     });
+    /// }
+    /// ```
+    pub fn set_effect(
+        &mut self,
+        bus: AudioBus,
+        effect: impl Fn(BusSource) -> BusSource + Send + Sync + 'static,
+    ) {
+        self.buses.entry(bus).or_default().effect = Some(Box::new(effect));
+    }
+
+    /// Removes the effect of `bus`.
+    ///
+    /// Sounds already playing keep the effect.
+    pub fn clear_effect(&mut self, bus: AudioBus) {
+        if let Some(state) = self.buses.get_mut(&bus) {
+            state.effect = None;
+        }
+    }
+
+    /// Applies the effects of `bus` and of [`AudioBus::Master`] to `source`, followed by the gain
+    /// of `bus`.
+    pub(crate) fn apply(&self, bus: AudioBus, source: BusSource) -> BusSource {
+        let gain = self
+            .gains
+            .lock()
+            .entry(bus)
+            .or_insert_with(|| Arc::new(AtomicU32::new(self.gain(bus).to_bits())))
+            .clone();
+        Box::new(BusGain {
+            source: self.apply_effects(bus, source),
+            gain,
+        })
+    }
+
+    /// Applies the effects of `bus` and of [`AudioBus::Master`] to `source`.
+    fn apply_effects(&self, bus: AudioBus, mut source: BusSource) -> BusSource {
+        if let Some(effect) = self.buses.get(&bus).and_then(|state| state.effect.as_ref()) {
+            source = effect(source);
+        }
+        if bus != AudioBus::Master {
+            source = self.apply_effects(AudioBus::Master, source);
+        }
+        source
+    }
+
+    /// Passes the gain of each bus to the sounds playing on it.
+    fn update_gains(&self) {
+        for (bus, gain) in self.gains.lock().iter() {
+            gain.store(self.gain(*bus).to_bits(), Ordering::Relaxed);
+        }
+    }
+}
+
+/// Multiplies the samples of a sound by the gain of its [`AudioBus`].
+///
+/// The gain is shared with [`AudioBuses`], so that the sound follows the volume of its bus while
+/// it plays, without its sink being updated.
+struct BusGain {
+    source: BusSource,
+    gain: Arc<AtomicU32>,
+}
+
+impl Iterator for BusGain {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let gain = f32::from_bits(self.gain.load(Ordering::Relaxed));
+        self.source.next().map(|sample| sample * gain)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl Source for BusGain {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.source.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.source.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.source.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.source.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    #[test]
+    fn gain_of_buses() {
+        let mut audio_buses = AudioBuses::default();
+        assert_eq!(audio_buses.gain(AudioBus::Music), 1.0);
+
+        audio_buses.set_volume(AudioBus::Music, 0.5);
+        audio_buses.set_volume(AudioBus::Master, 0.5);
+        assert_eq!(audio_buses.gain(AudioBus::Music), 0.25);
+        assert_eq!(audio_buses.gain(AudioBus::Sfx), 0.5);
+        assert_eq!(audio_buses.gain(AudioBus::Master), 0.5);
+    }
+
+    #[test]
+    fn gain_of_muted_buses() {
+        let mut audio_buses = AudioBuses::default();
+        audio_buses.set_volume(AudioBus::Music, 0.5);
+
+        audio_buses.set_muted(AudioBus::Music, true);
+        assert_eq!(audio_buses.gain(AudioBus::Music), 0.0);
+        assert_eq!(audio_buses.gain(AudioBus::Sfx), 1.0);
+        assert_eq!(audio_buses.gain(AudioBus::Master), 1.0);
+
+        audio_buses.toggle_mute(AudioBus::Music);
+        assert_eq!(audio_buses.gain(AudioBus::Music), 0.5);
+
+        // Muting the master bus mutes all buses, but keeps their volume
+        audio_buses.set_muted(AudioBus::Master, true);
+        assert_eq!(audio_buses.gain(AudioBus::Music), 0.0);
+        assert_eq!(audio_buses.gain(AudioBus::Master), 0.0);
+        assert_eq!(audio_buses.volume(AudioBus::Music), 0.5);
+        audio_buses.set_muted(AudioBus::Master, false);
+        assert_eq!(audio_buses.gain(AudioBus::Music), 0.5);
+    }
+
+    #[test]
+    fn playing_sounds_follow_the_gain() {
+        let mut audio_buses = AudioBuses::default();
+        audio_buses.set_volume(AudioBus::Music, 0.5);
+        let mut source = audio_buses.apply(
+            AudioBus::Music,
+            Box::new(SamplesBuffer::new(1, 44100, vec![1.0; 3])),
+        );
+        assert_eq!(source.next(), Some(0.5));
+
+        audio_buses.set_volume(AudioBus::Master, 0.5);
+        assert_eq!(source.next(), Some(0.25));
+        audio_buses.set_muted(AudioBus::Music, true);
+        assert_eq!(source.next(), Some(0.0));
+        assert_eq!(source.next(), None);
+    }
+}
@@ -25,13 +25,16 @@
 mod audio;
 mod audio_output;
 mod audio_source;
+mod bus;
 mod sinks;
+mod spatial;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        Audio, AudioOutput, AudioSink, AudioSinkPlayback, AudioSource, Decodable, PlaybackSettings,
+        Audio, AudioBus, AudioBuses, AudioListener, AudioOutput, AudioSink, AudioSinkPlayback,
+        AudioSource, Decodable, PlaybackCompleted, PlaybackSettings, SpatialAudioEmitter,
         SpatialAudioSink,
     };
 }
@@ -39,15 +42,18 @@ pub mod prelude {
 pub use audio::*;
 pub use audio_output::*;
 pub use audio_source::*;
+pub use bus::*;
 
 pub use rodio::cpal::Sample as CpalSample;
 pub use rodio::source::Source;
 pub use rodio::Sample;
 pub use sinks::*;
+pub use spatial::*;
 
 use bevy_app::prelude::*;
-use bevy_asset::{AddAsset, Asset};
+use bevy_asset::{AddAsset, Asset, AssetServer};
 use bevy_ecs::prelude::*;
+use bevy_transform::TransformSystem;
 
 /// Adds support for audio playback to a Bevy Application
 ///
@@ -62,7 +68,25 @@ impl Plugin for AudioPlugin {
             .add_asset::<AudioSink>()
             .add_asset::<SpatialAudioSink>()
             .init_resource::<Audio<AudioSource>>()
-            .add_system(play_queued_audio_system::<AudioSource>.in_base_set(CoreSet::PostUpdate));
+            .init_resource::<AudioBuses>()
+            .register_type::<AudioListener>()
+            .register_type::<SpatialAudioEmitter>()
+            .add_event::<PlaybackCompleted>()
+            .add_system(play_queued_audio_system::<AudioSource>.in_base_set(CoreSet::PostUpdate))
+            .add_system(
+                update_spatial_audio
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate)
+                    // Newly played sounds are positioned in the frame they start
+                    .after(play_queued_audio_system::<AudioSource>),
+            )
+            .add_system(
+                playback_completed_system
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(play_queued_audio_system::<AudioSource>),
+            );
+
+        set_asset_server::<AudioSource>(app);
 
         #[cfg(any(feature = "mp3", feature = "flac", feature = "wav", feature = "vorbis"))]
         app.init_asset_loader::<AudioLoader>();
@@ -78,6 +102,20 @@ impl AddAudioSource for App {
         self.add_asset::<T>()
             .init_resource::<Audio<T>>()
             .init_resource::<AudioOutput<T>>()
-            .add_system(play_queued_audio_system::<T>.in_base_set(CoreSet::PostUpdate))
+            .add_system(play_queued_audio_system::<T>.in_base_set(CoreSet::PostUpdate));
+        set_asset_server::<T>(self);
+        self
+    }
+}
+
+/// Lets [`Audio`] hand out strong handles to sinks from the first frame on, instead of once
+/// [`play_queued_audio_system`] has found the [`AssetServer`].
+fn set_asset_server<Source: Asset + Decodable>(app: &mut App) {
+    let Some(asset_server) = app.world.get_resource::<AssetServer>().cloned() else {
+        return;
+    };
+    let mut audio = app.world.resource_mut::<Audio<Source>>();
+    if audio.asset_server.is_none() {
+        audio.asset_server = Some(asset_server);
     }
 }
@@ -1,7 +1,14 @@
+use crate::{AudioBus, PlaybackSettings, SpatialSettings};
+use bevy_asset::HandleUntyped;
 use bevy_math::Vec3;
 use bevy_reflect::TypeUuid;
 use bevy_transform::prelude::Transform;
+use parking_lot::Mutex;
 use rodio::{Sink, SpatialSink};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
 /// Common interactions with an audio sink.
 pub trait AudioSinkPlayback {
@@ -14,7 +21,8 @@ pub trait AudioSinkPlayback {
     /// Changes the volume of the sound.
     ///
     /// The value `1.0` is the "normal" volume (unfiltered input). Any value other than `1.0`
-    /// will multiply each sample by this value.
+    /// will multiply each sample by this value. The volume of the [`AudioBus`] the sound is
+    /// routed to is applied on top of it.
     fn set_volume(&self, volume: f32);
 
     /// Gets the speed of the sound.
@@ -58,8 +66,39 @@ pub trait AudioSinkPlayback {
 
     /// Stops the sink.
     ///
-    /// It won't be possible to restart it afterwards.
+    /// It won't be possible to restart it afterwards, except by [seeking](Self::seek).
     fn stop(&self);
+
+    /// Restarts the sound from `position`, measured from the start of the audio source.
+    ///
+    /// The sink keeps its volume, speed and paused state. A stopped or finished sound is played
+    /// again. The seek happens when the audio is next updated, at the end of the frame.
+    fn seek(&self, position: Duration);
+}
+
+/// State shared by [`AudioSink`] and [`SpatialAudioSink`] to restart the sound and to report its
+/// completion.
+pub(crate) struct PlaybackState {
+    /// Keeps the audio source loaded, so the sound can be restarted at another position.
+    pub(crate) source: HandleUntyped,
+    pub(crate) repeat: bool,
+    pub(crate) bus: AudioBus,
+    pub(crate) seek: Mutex<Option<Duration>>,
+    /// Whether a [`PlaybackCompleted`](crate::PlaybackCompleted) event has been sent since the
+    /// sound was last started.
+    pub(crate) completed: AtomicBool,
+}
+
+impl PlaybackState {
+    pub(crate) fn new(source: HandleUntyped, settings: &PlaybackSettings) -> Self {
+        Self {
+            source,
+            repeat: settings.repeat,
+            bus: settings.bus,
+            seek: Mutex::new(None),
+            completed: AtomicBool::new(false),
+        }
+    }
 }
 
 /// Asset controlling the playback of a sound
@@ -90,6 +129,7 @@ pub struct AudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
     // It will never be None during its life
     pub(crate) sink: Option<Sink>,
+    pub(crate) playback: PlaybackState,
 }
 
 impl Drop for AudioSink {
@@ -98,6 +138,21 @@ impl Drop for AudioSink {
     }
 }
 
+impl AudioSink {
+    /// Replaces the rodio sink after a [seek](AudioSinkPlayback::seek), keeping its volume, speed
+    /// and paused state.
+    pub(crate) fn restart(&mut self, new_sink: Sink) {
+        let old_sink = self.sink.replace(new_sink).unwrap();
+        let new_sink = self.sink.as_ref().unwrap();
+        new_sink.set_speed(old_sink.speed());
+        new_sink.set_volume(old_sink.volume());
+        if old_sink.is_paused() {
+            new_sink.pause();
+        }
+        self.playback.completed.store(false, Ordering::Relaxed);
+    }
+}
+
 impl AudioSinkPlayback for AudioSink {
     fn volume(&self) -> f32 {
         self.sink.as_ref().unwrap().volume()
@@ -130,6 +185,10 @@ impl AudioSinkPlayback for AudioSink {
     fn stop(&self) {
         self.sink.as_ref().unwrap().stop();
     }
+
+    fn seek(&self, position: Duration) {
+        *self.playback.seek.lock() = Some(position);
+    }
 }
 
 /// Asset controlling the playback of a sound, or the locations of its listener and emitter.
@@ -157,6 +216,9 @@ pub struct SpatialAudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
     // It will never be None during its life
     pub(crate) sink: Option<SpatialSink>,
+    pub(crate) playback: PlaybackState,
+    /// The positions of the ears and the emitter, as rodio doesn't expose them.
+    pub(crate) spatial: Mutex<SpatialSettings>,
 }
 
 impl Drop for SpatialAudioSink {
@@ -197,14 +259,34 @@ impl AudioSinkPlayback for SpatialAudioSink {
     fn stop(&self) {
         self.sink.as_ref().unwrap().stop();
     }
+
+    fn seek(&self, position: Duration) {
+        *self.playback.seek.lock() = Some(position);
+    }
 }
 
 impl SpatialAudioSink {
+    /// Replaces the rodio sink after a [seek](AudioSinkPlayback::seek), keeping its volume, speed
+    /// and paused state.
+    pub(crate) fn restart(&mut self, new_sink: SpatialSink) {
+        let old_sink = self.sink.replace(new_sink).unwrap();
+        let new_sink = self.sink.as_ref().unwrap();
+        new_sink.set_speed(old_sink.speed());
+        new_sink.set_volume(old_sink.volume());
+        if old_sink.is_paused() {
+            new_sink.pause();
+        }
+        self.playback.completed.store(false, Ordering::Relaxed);
+    }
+
     /// Set the two ears position.
     pub fn set_ears_position(&self, left_position: Vec3, right_position: Vec3) {
         let sink = self.sink.as_ref().unwrap();
-        sink.set_left_ear_position(left_position.to_array());
-        sink.set_right_ear_position(right_position.to_array());
+        let mut spatial = self.spatial.lock();
+        spatial.left_ear = left_position.to_array();
+        spatial.right_ear = right_position.to_array();
+        sink.set_left_ear_position(spatial.left_ear);
+        sink.set_right_ear_position(spatial.right_ear);
     }
 
     /// Set the listener position, with an ear on each side separated by `gap`.
@@ -217,9 +299,11 @@ impl SpatialAudioSink {
 
     /// Set the emitter position.
     pub fn set_emitter_position(&self, position: Vec3) {
+        let mut spatial = self.spatial.lock();
+        spatial.emitter = position.to_array();
         self.sink
             .as_ref()
             .unwrap()
-            .set_emitter_position(position.to_array());
+            .set_emitter_position(spatial.emitter);
     }
 }
@@ -0,0 +1,119 @@
+use crate::SpatialAudioSink;
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::prelude::GlobalTransform;
+use bevy_utils::{default, tracing::warn};
+
+/// Places the ears of the listener of all [`SpatialAudioEmitter`]s at the [`GlobalTransform`] of
+/// this entity, usually the camera or the player.
+///
+/// Only one entity should have this component. The ears are on the left and right side of the
+/// entity, separated by [`gap`](Self::gap).
+///
+/// Like [`Audio::play_spatial`](crate::Audio::play_spatial), this doesn't use HRTF: the sound is
+/// panned by changing the level of each stereo channel according to the distance between the
+/// emitter and each ear.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct AudioListener {
+    /// The distance between the left and the right ear.
+    pub gap: f32,
+    /// The factor all positions are multiplied by before they are passed to the audio sinks.
+    ///
+    /// The volume drops with the distance between the emitter and the ears, so a scale below
+    /// `1.0` is useful when the world is measured in small units, like pixels in 2D games.
+    pub scale: f32,
+}
+
+impl Default for AudioListener {
+    fn default() -> Self {
+        Self {
+            gap: 4.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl AudioListener {
+    /// Creates an [`AudioListener`] with the ears separated by `gap`.
+    pub fn new(gap: f32) -> Self {
+        Self { gap, ..default() }
+    }
+
+    /// Returns this [`AudioListener`] with a new [`scale`](Self::scale).
+    #[must_use]
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+/// Moves the emitter of a [`SpatialAudioSink`] to the [`GlobalTransform`] of this entity, and its
+/// ears to the [`AudioListener`], every frame.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::AssetServer;
+/// # use bevy_audio::{Audio, SpatialAudioEmitter};
+/// # use bevy_transform::prelude::{Transform, TransformBundle};
+/// fn spawn_emitter(mut commands: Commands, asset_server: Res<AssetServer>, audio: Res<Audio>) {
+///     let handle = audio.play_emitted(asset_server.load("my_sound.ogg"));
+///     commands.spawn((
+///         SpatialAudioEmitter::new(handle),
+///         TransformBundle::from_transform(Transform::from_xyz(5.0, 0.0, 0.0)),
+///     ));
+/// }
+/// ```
+///
+/// The emitter keeps the sink alive, so the sound stops when the entity is despawned. Sinks played
+/// with [`Audio::play_spatial`](crate::Audio::play_spatial) can be emitted as well, their positions
+/// are replaced once the emitter is spawned.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct SpatialAudioEmitter {
+    /// The sink of the sound emitted by this entity.
+    pub sink: Handle<SpatialAudioSink>,
+}
+
+impl SpatialAudioEmitter {
+    /// Creates a [`SpatialAudioEmitter`] for the given sink.
+    pub fn new(sink: Handle<SpatialAudioSink>) -> Self {
+        Self { sink }
+    }
+}
+
+/// Updates the positions of the emitter and the ears of the sinks of all [`SpatialAudioEmitter`]s
+/// from their [`GlobalTransform`] and the [`GlobalTransform`] of the [`AudioListener`].
+pub fn update_spatial_audio(
+    mut warned: Local<bool>,
+    spatial_audio_sinks: Res<Assets<SpatialAudioSink>>,
+    listeners: Query<(&AudioListener, &GlobalTransform)>,
+    emitters: Query<(&SpatialAudioEmitter, &GlobalTransform)>,
+) {
+    let mut listeners = listeners.iter();
+    let Some((listener, listener_transform)) = listeners.next() else {
+        return;
+    };
+    if listeners.next().is_some() && !*warned {
+        warn!("Multiple AudioListeners found, using the first one.");
+        *warned = true;
+    }
+
+    let listener_transform = listener_transform.compute_transform();
+    let left_ear = (listener_transform.translation
+        + listener_transform.left() * listener.gap / 2.0)
+        * listener.scale;
+    let right_ear = (listener_transform.translation
+        + listener_transform.right() * listener.gap / 2.0)
+        * listener.scale;
+
+    for (emitter, transform) in &emitters {
+        // The sink is created once the audio source is loaded
+        let Some(sink) = spatial_audio_sinks.get(&emitter.sink) else {
+            continue;
+        };
+        sink.set_ears_position(left_ear, right_ear);
+        sink.set_emitter_position(transform.translation() * listener.scale);
+    }
+}
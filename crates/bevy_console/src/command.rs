@@ -0,0 +1,258 @@
+use bevy_ecs::{
+    prelude::*,
+    system::{BoxedSystem, IntoSystem},
+};
+use bevy_reflect::{FromReflect, TypeInfo, Typed};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+use crate::parse::{parse_args, tokenize, ConsoleParseError};
+
+/// An error that occurs when running a console command.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleCommandError {
+    #[error("unknown command `{0}`")]
+    UnknownCommand(String),
+    #[error("invalid arguments for `{name}`: {error}\nusage: {usage}")]
+    InvalidArguments {
+        name: String,
+        usage: String,
+        error: ConsoleParseError,
+    },
+    #[error(transparent)]
+    Parse(#[from] ConsoleParseError),
+}
+
+type CommandRunner =
+    Box<dyn FnMut(&mut World, &[String]) -> Result<(), ConsoleParseError> + Send + Sync>;
+
+struct ConsoleCommand {
+    usage: String,
+    run: CommandRunner,
+}
+
+/// The commands that can be run from the console, each bound to a system.
+///
+/// A command's system takes its arguments as [`In<A>`](In), where `A` is parsed from the
+/// command line with [`parse_args`]. Commands are usually added with
+/// [`AddConsoleCommand::add_console_command`].
+///
+/// ```
+/// # use bevy_console::ConsoleCommands;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::{FromReflect, Reflect};
+/// #[derive(Resource, Default)]
+/// struct Gravity(f32);
+///
+/// #[derive(Reflect, FromReflect)]
+/// struct SetGravity {
+///     value: f32,
+/// }
+///
+/// fn set_gravity(In(args): In<SetGravity>, mut gravity: ResMut<Gravity>) {
+///     gravity.0 = args.value;
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Gravity>();
+///
+/// let mut commands = ConsoleCommands::default();
+/// commands.add("gravity", set_gravity);
+/// commands.run(&mut world, "gravity value=-4.5").unwrap();
+///
+/// assert_eq!(world.resource::<Gravity>().0, -4.5);
+/// ```
+#[derive(Resource, Default)]
+pub struct ConsoleCommands {
+    commands: HashMap<String, ConsoleCommand>,
+}
+
+impl ConsoleCommands {
+    /// Adds a command called `name` that runs `system` with the parsed arguments.
+    ///
+    /// Replaces any existing command with the same name.
+    pub fn add<A, M>(&mut self, name: impl Into<String>, system: impl IntoSystem<A, (), M>)
+    where
+        A: FromReflect + Typed,
+    {
+        let name = name.into();
+        let usage = usage::<A>(&name);
+        let mut system: BoxedSystem<A> = Box::new(IntoSystem::into_system(system));
+        let mut initialized = false;
+        let run = move |world: &mut World, args: &[String]| {
+            let args = parse_args::<A>(args)?;
+            if !initialized {
+                system.initialize(world);
+                initialized = true;
+            }
+            system.run(args, world);
+            system.apply_buffers(world);
+            Ok(())
+        };
+        self.commands.insert(
+            name,
+            ConsoleCommand {
+                usage,
+                run: Box::new(run),
+            },
+        );
+    }
+
+    /// Removes the command called `name`, returning `true` if it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.commands.remove(name).is_some()
+    }
+
+    /// Returns `true` if there is a command called `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.commands.contains_key(name)
+    }
+
+    /// Returns the usage string of the command called `name`, like `spawn <count: u32>`.
+    pub fn usage(&self, name: &str) -> Option<&str> {
+        self.commands
+            .get(name)
+            .map(|command| command.usage.as_str())
+    }
+
+    /// Returns the names of all commands, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.commands.keys().map(String::as_str)
+    }
+
+    /// Parses the command `line` and runs the command it names on the `world`.
+    ///
+    /// Empty lines are ignored.
+    pub fn run(&mut self, world: &mut World, line: &str) -> Result<(), ConsoleCommandError> {
+        let tokens = tokenize(line)?;
+        let Some((name, args)) = tokens.split_first() else {
+            return Ok(());
+        };
+        let command = self
+            .commands
+            .get_mut(name)
+            .ok_or_else(|| ConsoleCommandError::UnknownCommand(name.clone()))?;
+        (command.run)(world, args).map_err(|error| ConsoleCommandError::InvalidArguments {
+            name: name.clone(),
+            usage: command.usage.clone(),
+            error,
+        })
+    }
+}
+
+/// Describes the arguments of a command, like `spawn <count: u32> <name: alloc::string::String>`.
+fn usage<A: Typed>(name: &str) -> String {
+    let mut usage = name.to_string();
+    let mut push = |name: &str, type_name: &str| {
+        usage.push_str(&format!(" <{name}: {type_name}>"));
+    };
+    match A::type_info() {
+        TypeInfo::Struct(info) => {
+            for field in info.iter() {
+                push(field.name(), field.type_name());
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            for field in info.iter() {
+                push(&field.index().to_string(), field.type_name());
+            }
+        }
+        TypeInfo::Tuple(info) => {
+            for field in info.iter() {
+                push(&field.index().to_string(), field.type_name());
+            }
+        }
+        TypeInfo::Value(info) => push("0", info.type_name()),
+        _ => {}
+    }
+    usage
+}
+
+/// Adds console commands to an [`App`](bevy_app::App).
+pub trait AddConsoleCommand {
+    /// Adds a command called `name` that runs `system` with the parsed arguments.
+    ///
+    /// See [`ConsoleCommands`] for details.
+    fn add_console_command<A, M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<A, (), M>,
+    ) -> &mut Self
+    where
+        A: FromReflect + Typed;
+}
+
+impl AddConsoleCommand for bevy_app::App {
+    fn add_console_command<A, M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<A, (), M>,
+    ) -> &mut Self
+    where
+        A: FromReflect + Typed,
+    {
+        self.world
+            .get_resource_or_insert_with(ConsoleCommands::default)
+            .add(name, system);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConsoleCommandError, ConsoleCommands};
+    use crate::parse::ConsoleParseError;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{FromReflect, Reflect};
+
+    #[derive(Component)]
+    struct Crab;
+
+    #[derive(Reflect, FromReflect)]
+    struct Spawn {
+        count: u32,
+    }
+
+    fn spawn(In(args): In<Spawn>, mut commands: Commands) {
+        for _ in 0..args.count {
+            commands.spawn(Crab);
+        }
+    }
+
+    #[test]
+    fn run_commands() {
+        let mut world = World::new();
+        let mut commands = ConsoleCommands::default();
+        commands.add("spawn", spawn);
+        commands.add(
+            "despawn_all",
+            |mut commands: Commands, crabs: Query<Entity, With<Crab>>| {
+                for crab in &crabs {
+                    commands.entity(crab).despawn();
+                }
+            },
+        );
+
+        commands.run(&mut world, "spawn 3").unwrap();
+        assert_eq!(world.query::<&Crab>().iter(&world).count(), 3);
+        commands.run(&mut world, "spawn count=2").unwrap();
+        assert_eq!(world.query::<&Crab>().iter(&world).count(), 5);
+        commands.run(&mut world, "despawn_all").unwrap();
+        assert_eq!(world.query::<&Crab>().iter(&world).count(), 0);
+        commands.run(&mut world, "   ").unwrap();
+
+        assert_eq!(
+            commands.run(&mut world, "jump"),
+            Err(ConsoleCommandError::UnknownCommand("jump".to_string()))
+        );
+        assert_eq!(
+            commands.run(&mut world, "spawn"),
+            Err(ConsoleCommandError::InvalidArguments {
+                name: "spawn".to_string(),
+                usage: "spawn <count: u32>".to_string(),
+                error: ConsoleParseError::MissingArgument("count".to_string()),
+            })
+        );
+        assert_eq!(commands.usage("despawn_all"), Some("despawn_all"));
+    }
+}
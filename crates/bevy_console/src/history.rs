@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+
+use bevy_ecs::system::Resource;
+
+/// A command line that was run in the console, along with what it printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleEntry {
+    /// The command line as it was entered.
+    pub line: String,
+    /// The lines printed while the command ran, see [`ConsoleHistory::print`].
+    pub output: Vec<String>,
+    /// The error the command failed with, if any.
+    pub error: Option<String>,
+}
+
+/// The commands that were run in the console, oldest first.
+///
+/// Also keeps a cursor for recalling earlier command lines with [`ConsoleHistory::previous`]
+/// and [`ConsoleHistory::next`], like the arrow keys of a terminal.
+#[derive(Resource, Debug, Clone)]
+pub struct ConsoleHistory {
+    entries: VecDeque<ConsoleEntry>,
+    max_len: usize,
+    /// The index of the recalled entry, if any.
+    cursor: Option<usize>,
+}
+
+impl Default for ConsoleHistory {
+    fn default() -> Self {
+        Self::with_max_len(100)
+    }
+}
+
+impl ConsoleHistory {
+    /// Creates an empty history that keeps at most `max_len` entries.
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_len,
+            cursor: None,
+        }
+    }
+
+    /// Returns the entries, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &ConsoleEntry> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// Returns the most recent entry.
+    pub fn last(&self) -> Option<&ConsoleEntry> {
+        self.entries.back()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an entry for a command `line` that is about to run, dropping the oldest entry if
+    /// the history is full. Resets the recall cursor.
+    pub fn push(&mut self, line: impl Into<String>) {
+        if self.max_len == 0 {
+            return;
+        }
+        if self.entries.len() == self.max_len {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(ConsoleEntry {
+            line: line.into(),
+            output: Vec::new(),
+            error: None,
+        });
+        self.cursor = None;
+    }
+
+    /// Adds a line of output to the most recent entry.
+    ///
+    /// Console commands can take `ResMut<ConsoleHistory>` to print their results.
+    pub fn print(&mut self, output: impl Into<String>) {
+        if let Some(entry) = self.entries.back_mut() {
+            entry.output.push(output.into());
+        }
+    }
+
+    /// Records that the most recent entry failed with `error`.
+    pub fn set_error(&mut self, error: impl ToString) {
+        if let Some(entry) = self.entries.back_mut() {
+            entry.error = Some(error.to_string());
+        }
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cursor = None;
+    }
+
+    /// Moves the recall cursor to the previous command line and returns it, stopping at the
+    /// oldest one.
+    pub fn previous(&mut self) -> Option<&str> {
+        let index = match self.cursor {
+            Some(index) => index.saturating_sub(1),
+            None => self.entries.len().checked_sub(1)?,
+        };
+        self.cursor = Some(index);
+        Some(&self.entries[index].line)
+    }
+
+    /// Moves the recall cursor to the next command line and returns it, or returns `None` once
+    /// the cursor moves past the most recent one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&str> {
+        let index = self.cursor? + 1;
+        if index == self.entries.len() {
+            self.cursor = None;
+            return None;
+        }
+        self.cursor = Some(index);
+        Some(&self.entries[index].line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConsoleHistory;
+
+    #[test]
+    fn recall_history() {
+        let mut history = ConsoleHistory::with_max_len(3);
+        assert_eq!(history.previous(), None);
+
+        for line in ["a", "b", "c", "d"] {
+            history.push(line);
+        }
+        history.print("done");
+        history.set_error("oops");
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.last().unwrap().output, ["done"]);
+        assert_eq!(history.last().unwrap().error.as_deref(), Some("oops"));
+
+        assert_eq!(history.previous(), Some("d"));
+        assert_eq!(history.previous(), Some("c"));
+        assert_eq!(history.previous(), Some("b"));
+        assert_eq!(history.previous(), Some("b"));
+        assert_eq!(history.next(), Some("c"));
+        assert_eq!(history.next(), Some("d"));
+        assert_eq!(history.next(), None);
+        assert_eq!(history.next(), None);
+
+        history.previous();
+        history.push("e");
+        assert_eq!(history.previous(), Some("e"));
+    }
+}
@@ -0,0 +1,392 @@
+//! A debug console for Bevy apps.
+//!
+//! Console commands are bound to systems that take their arguments as [`In<A>`](In), where `A`
+//! is parsed from the command line using reflection. Add the [`ConsolePlugin`] and register
+//! commands with [`AddConsoleCommand::add_console_command`]:
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_console::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_reflect::{FromReflect, Reflect};
+//! #[derive(Resource, Default)]
+//! struct Score(u32);
+//!
+//! #[derive(Reflect, FromReflect)]
+//! struct SetScore {
+//!     score: u32,
+//! }
+//!
+//! fn set_score(In(args): In<SetScore>, mut score: ResMut<Score>, mut history: ResMut<ConsoleHistory>) {
+//!     score.0 = args.score;
+//!     history.print(format!("score set to {}", args.score));
+//! }
+//!
+//! App::new()
+//!     .init_resource::<Score>()
+//!     .add_console_command("score", set_score);
+//! ```
+//!
+//! Typing `score 100` or `score score=100` into the console and pressing enter then sets the
+//! score to 100.
+
+mod command;
+mod history;
+mod parse;
+
+pub use command::*;
+pub use history::*;
+pub use parse::*;
+
+pub mod prelude {
+    //! The Bevy Console Prelude.
+    #[doc(hidden)]
+    pub use crate::{
+        console_closed, AddConsoleCommand, Console, ConsoleCommands, ConsoleHistory, ConsolePlugin,
+    };
+}
+
+use bevy_a11y::Focus;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonState, Input, InputSystem,
+};
+use bevy_window::ReceivedCharacter;
+
+/// Adds a debug console, toggled with [`ConsoleSettings::toggle_key`].
+///
+/// While the console is open it holds the keyboard [`Focus`]: typed characters edit the
+/// [`Console::input`] line, enter runs it with [`ConsoleCommands`] and the up and down arrows
+/// recall earlier lines from the [`ConsoleHistory`].
+///
+/// This plugin only handles input and running commands; displaying the console is up to the app.
+/// It requires the `InputPlugin` and the `WindowPlugin`.
+#[derive(Default)]
+pub struct ConsolePlugin;
+
+/// The systems that handle console input and run submitted commands.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct ConsoleSystem;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        let entity = app.world.spawn_empty().id();
+        app.insert_resource(Console::new(entity))
+            .init_resource::<Focus>()
+            .init_resource::<ConsoleCommands>()
+            .init_resource::<ConsoleHistory>()
+            .init_resource::<ConsoleSettings>()
+            .configure_set(
+                ConsoleSystem
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(InputSystem),
+            )
+            .add_systems(
+                (console_input_system, run_console_commands)
+                    .chain()
+                    .in_set(ConsoleSystem),
+            );
+    }
+}
+
+/// Configures the [`ConsolePlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct ConsoleSettings {
+    /// The key that opens and closes the console. Defaults to [`KeyCode::Grave`].
+    pub toggle_key: KeyCode,
+    /// Whether keyboard input is hidden from the rest of the app while the console is open, by
+    /// resetting the [`Input<KeyCode>`] resources after the console has read them.
+    /// Defaults to `true`.
+    pub capture_keyboard: bool,
+}
+
+impl Default for ConsoleSettings {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyCode::Grave,
+            capture_keyboard: true,
+        }
+    }
+}
+
+/// The state of the console.
+#[derive(Resource, Debug)]
+pub struct Console {
+    /// The command line being edited.
+    pub input: String,
+    open: bool,
+    entity: Entity,
+    previous_focus: Option<Entity>,
+    submitted: Vec<String>,
+}
+
+impl Console {
+    fn new(entity: Entity) -> Self {
+        Self {
+            input: String::new(),
+            open: false,
+            entity,
+            previous_focus: None,
+            submitted: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the console is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The entity that holds the keyboard [`Focus`] while the console is open.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Opens the console, moving the keyboard [`Focus`] to [`Console::entity`].
+    pub fn open(&mut self, focus: &mut Focus) {
+        if !self.open {
+            self.open = true;
+            self.previous_focus = focus.replace(self.entity);
+        }
+    }
+
+    /// Closes the console, giving the keyboard [`Focus`] back to the entity that had it
+    /// when the console was opened.
+    pub fn close(&mut self, focus: &mut Focus) {
+        if self.open {
+            self.open = false;
+            if **focus == Some(self.entity) {
+                **focus = self.previous_focus;
+            }
+            self.previous_focus = None;
+        }
+    }
+
+    /// Queues a command line to be run by [`run_console_commands`].
+    pub fn submit(&mut self, line: impl Into<String>) {
+        self.submitted.push(line.into());
+    }
+}
+
+/// A run condition that is `true` while the console isn't open, for systems that should ignore
+/// keyboard input while it is.
+pub fn console_closed(console: Option<Res<Console>>) -> bool {
+    !matches!(console, Some(console) if console.is_open())
+}
+
+/// Opens and closes the [`Console`] and edits its input line.
+#[allow(clippy::too_many_arguments)]
+pub fn console_input_system(
+    mut console: ResMut<Console>,
+    mut history: ResMut<ConsoleHistory>,
+    mut focus: ResMut<Focus>,
+    settings: Res<ConsoleSettings>,
+    mut keys: EventReader<KeyboardInput>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut keyboard: ResMut<Input<KeyCode>>,
+    keyboard_windows: Option<ResMut<Input<(Entity, KeyCode)>>>,
+) {
+    if console.open && **focus != Some(console.entity) {
+        // Something else took the keyboard focus.
+        console.open = false;
+        console.previous_focus = None;
+    }
+    let was_open = console.open;
+
+    // Characters are only read after the keys, so the toggle key isn't typed into the console.
+    let mut read_characters = console.open;
+    for key in keys.iter() {
+        let (Some(key_code), ButtonState::Pressed) = (key.key_code, key.state) else {
+            continue;
+        };
+        if key_code == settings.toggle_key && !key.repeat {
+            if console.open {
+                console.close(&mut focus);
+            } else {
+                console.open(&mut focus);
+            }
+            read_characters = false;
+            continue;
+        }
+        if !console.open {
+            continue;
+        }
+        match key_code {
+            KeyCode::Escape => {
+                console.close(&mut focus);
+                read_characters = false;
+            }
+            KeyCode::Back => {
+                console.input.pop();
+            }
+            KeyCode::Return => {
+                let line = std::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.submit(line);
+                }
+            }
+            KeyCode::Up => {
+                if let Some(line) = history.previous() {
+                    console.input = line.to_string();
+                }
+            }
+            KeyCode::Down => {
+                console.input = history.next().unwrap_or_default().to_string();
+            }
+            _ => {}
+        }
+    }
+
+    if read_characters {
+        for character in characters.iter() {
+            if !character.char.is_control() {
+                console.input.push(character.char);
+            }
+        }
+    } else {
+        characters.clear();
+    }
+
+    if settings.capture_keyboard && (was_open || console.open) {
+        keyboard.reset_all();
+        if let Some(mut keyboard_windows) = keyboard_windows {
+            keyboard_windows.reset_all();
+        }
+    }
+}
+
+/// Runs the command lines submitted to the [`Console`], recording them in the
+/// [`ConsoleHistory`].
+///
+/// While a command runs, the [`ConsoleCommands`] resource is removed from the world.
+pub fn run_console_commands(world: &mut World) {
+    if world.resource::<Console>().submitted.is_empty() {
+        return;
+    }
+    let lines = std::mem::take(&mut world.resource_mut::<Console>().submitted);
+    world.resource_scope(|world, mut commands: Mut<ConsoleCommands>| {
+        for line in lines {
+            world.resource_mut::<ConsoleHistory>().push(line.clone());
+            if let Err(error) = commands.run(world, &line) {
+                world.resource_mut::<ConsoleHistory>().set_error(error);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy_a11y::Focus;
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+    use bevy_input::{
+        keyboard::{KeyCode, KeyboardInput},
+        ButtonState, Input, InputPlugin,
+    };
+    use bevy_window::ReceivedCharacter;
+
+    #[derive(Resource, Default)]
+    struct Said(Vec<String>);
+
+    fn say(In(text): In<String>, mut said: ResMut<Said>) {
+        said.0.push(text);
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for char in text.chars() {
+            app.world.send_event(ReceivedCharacter {
+                window: Entity::PLACEHOLDER,
+                char,
+            });
+        }
+    }
+
+    #[test]
+    fn console_runs_typed_commands() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .add_event::<ReceivedCharacter>()
+            .add_plugin(ConsolePlugin)
+            .init_resource::<Said>()
+            .add_console_command("say", say);
+
+        let previous_focus = app.world.spawn_empty().id();
+        **app.world.resource_mut::<Focus>() = Some(previous_focus);
+
+        press(&mut app, KeyCode::Grave);
+        type_text(&mut app, "`");
+        app.update();
+        let console = app.world.resource::<Console>();
+        assert!(console.is_open());
+        assert!(console.input.is_empty());
+        assert_eq!(**app.world.resource::<Focus>(), Some(console.entity()));
+
+        type_text(&mut app, "say \"hi there\"xy");
+        app.update();
+        press(&mut app, KeyCode::Back);
+        press(&mut app, KeyCode::Back);
+        app.update();
+        assert_eq!(app.world.resource::<Console>().input, "say \"hi there\"");
+        assert!(!app
+            .world
+            .resource::<Input<KeyCode>>()
+            .pressed(KeyCode::Back));
+
+        press(&mut app, KeyCode::Return);
+        app.update();
+        press(&mut app, KeyCode::Return);
+        type_text(&mut app, "jump");
+        app.update();
+        press(&mut app, KeyCode::Return);
+        app.update();
+        assert_eq!(app.world.resource::<Said>().0, ["hi there"]);
+
+        let history = app.world.resource::<ConsoleHistory>();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.last().unwrap().line, "jump");
+        assert_eq!(
+            history.last().unwrap().error.as_deref(),
+            Some("unknown command `jump`")
+        );
+
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Up);
+        app.update();
+        assert_eq!(app.world.resource::<Console>().input, "say \"hi there\"");
+
+        press(&mut app, KeyCode::Escape);
+        app.update();
+        assert!(!app.world.resource::<Console>().is_open());
+        assert_eq!(**app.world.resource::<Focus>(), Some(previous_focus));
+    }
+
+    #[test]
+    fn console_closes_when_focus_moves() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .add_event::<ReceivedCharacter>()
+            .add_plugin(ConsolePlugin);
+
+        press(&mut app, KeyCode::Grave);
+        app.update();
+        assert!(app.world.resource::<Console>().is_open());
+
+        let other = app.world.spawn_empty().id();
+        **app.world.resource_mut::<Focus>() = Some(other);
+        app.update();
+        assert!(!app.world.resource::<Console>().is_open());
+        assert_eq!(**app.world.resource::<Focus>(), Some(other));
+    }
+}
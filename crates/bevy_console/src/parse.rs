@@ -0,0 +1,276 @@
+use std::any::TypeId;
+
+use bevy_reflect::{
+    DynamicStruct, DynamicTuple, DynamicTupleStruct, FromReflect, Reflect, TypeInfo, Typed,
+};
+use thiserror::Error;
+
+/// An error that occurs when a console command line can't be parsed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleParseError {
+    #[error("unterminated quoted string")]
+    UnterminatedQuote,
+    #[error("expected at most {expected} arguments, found {found}")]
+    TooManyArguments { expected: usize, found: usize },
+    #[error("missing argument `{0}`")]
+    MissingArgument(String),
+    #[error("argument `{0}` was given more than once")]
+    DuplicateArgument(String),
+    #[error("invalid value `{value}` for argument `{argument}`, expected {type_name}")]
+    InvalidValue {
+        argument: String,
+        value: String,
+        type_name: &'static str,
+    },
+    #[error("arguments of type {0} can't be parsed from the console")]
+    UnsupportedType(&'static str),
+}
+
+/// Splits a console line into whitespace-separated tokens.
+///
+/// Tokens can be wrapped in double quotes to include whitespace, and `\"` and `\\` escape
+/// a quote or backslash inside quotes.
+pub fn tokenize(line: &str) -> Result<Vec<String>, ConsoleParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(&first) = chars.peek() else {
+            return Ok(tokens);
+        };
+
+        let mut token = String::new();
+        if first == '"' {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(c @ ('"' | '\\')) => token.push(c),
+                        Some(c) => {
+                            token.push('\\');
+                            token.push(c);
+                        }
+                        None => return Err(ConsoleParseError::UnterminatedQuote),
+                    },
+                    Some(c) => token.push(c),
+                    None => return Err(ConsoleParseError::UnterminatedQuote),
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                token.push(c);
+            }
+        }
+        tokens.push(token);
+    }
+}
+
+/// Parses the arguments of a console command into a value of type `A`, using its reflected
+/// [`TypeInfo`].
+///
+/// The following argument types are supported:
+/// - Structs with named fields. Fields are filled in order by positional arguments, or by
+///   name with `field=value` arguments.
+/// - Tuple structs and tuples, filled in order by positional arguments.
+/// - A single primitive value.
+///
+/// Every field must be a `bool`, `char`, `String`, integer or floating point number.
+pub fn parse_args<A: FromReflect + Typed>(args: &[String]) -> Result<A, ConsoleParseError> {
+    let type_info = A::type_info();
+    let value: Box<dyn Reflect> = match type_info {
+        TypeInfo::Struct(info) => {
+            let mut values = vec![None; info.field_len()];
+            let mut next_positional = 0;
+            for arg in args {
+                let named = arg
+                    .split_once('=')
+                    .and_then(|(name, value)| Some((info.index_of(name)?, value)));
+                let (index, value) = match named {
+                    Some((index, value)) => {
+                        if values[index].is_some() {
+                            let name = info.field_at(index).unwrap().name();
+                            return Err(ConsoleParseError::DuplicateArgument(name.to_string()));
+                        }
+                        (index, value)
+                    }
+                    None => {
+                        while next_positional < values.len() && values[next_positional].is_some() {
+                            next_positional += 1;
+                        }
+                        if next_positional == values.len() {
+                            return Err(ConsoleParseError::TooManyArguments {
+                                expected: values.len(),
+                                found: args.len(),
+                            });
+                        }
+                        (next_positional, arg.as_str())
+                    }
+                };
+                values[index] = Some(value);
+            }
+
+            let mut dynamic = DynamicStruct::default();
+            for (field, value) in info.iter().zip(values) {
+                let value = value
+                    .ok_or_else(|| ConsoleParseError::MissingArgument(field.name().to_string()))?;
+                let value = parse_value(field.name(), value, field.type_id(), field.type_name())?;
+                dynamic.insert_boxed(field.name(), value);
+            }
+            Box::new(dynamic)
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut dynamic = DynamicTupleStruct::default();
+            for (field, value) in info.iter().zip(positional(args, info.field_len())?) {
+                let name = field.index().to_string();
+                let value = parse_value(&name, value, field.type_id(), field.type_name())?;
+                dynamic.insert_boxed(value);
+            }
+            Box::new(dynamic)
+        }
+        TypeInfo::Tuple(info) => {
+            let mut dynamic = DynamicTuple::default();
+            for (field, value) in info.iter().zip(positional(args, info.field_len())?) {
+                let name = field.index().to_string();
+                let value = parse_value(&name, value, field.type_id(), field.type_name())?;
+                dynamic.insert_boxed(value);
+            }
+            Box::new(dynamic)
+        }
+        TypeInfo::Value(info) => {
+            let value = positional(args, 1)?[0];
+            parse_value("0", value, info.type_id(), info.type_name())?
+        }
+        _ => return Err(ConsoleParseError::UnsupportedType(type_info.type_name())),
+    };
+    A::from_reflect(&*value).ok_or(ConsoleParseError::UnsupportedType(type_info.type_name()))
+}
+
+/// Returns exactly `len` positional arguments.
+fn positional(args: &[String], len: usize) -> Result<Vec<&str>, ConsoleParseError> {
+    if args.len() > len {
+        return Err(ConsoleParseError::TooManyArguments {
+            expected: len,
+            found: args.len(),
+        });
+    }
+    if args.len() < len {
+        return Err(ConsoleParseError::MissingArgument(args.len().to_string()));
+    }
+    Ok(args.iter().map(String::as_str).collect())
+}
+
+fn parse_value(
+    argument: &str,
+    value: &str,
+    type_id: TypeId,
+    type_name: &'static str,
+) -> Result<Box<dyn Reflect>, ConsoleParseError> {
+    macro_rules! parse {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return value
+                        .parse::<$ty>()
+                        .map(|value| Box::new(value) as Box<dyn Reflect>)
+                        .map_err(|_| ConsoleParseError::InvalidValue {
+                            argument: argument.to_string(),
+                            value: value.to_string(),
+                            type_name,
+                        });
+                }
+            )*
+        };
+    }
+
+    parse!(
+        bool, char, String, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32,
+        f64
+    );
+    Err(ConsoleParseError::UnsupportedType(type_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, tokenize, ConsoleParseError};
+    use bevy_reflect::{FromReflect, Reflect};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Spawn {
+        count: u32,
+        name: String,
+        scale: f32,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Teleport(i32, i32);
+
+    fn args(line: &str) -> Vec<String> {
+        tokenize(line).unwrap()
+    }
+
+    #[test]
+    fn tokenize_quotes() {
+        assert_eq!(
+            args(r#"  say "hello world" "a \"b\"" c\d  "#),
+            ["say", "hello world", r#"a "b""#, r"c\d"]
+        );
+        assert_eq!(
+            tokenize(r#"say "oops"#),
+            Err(ConsoleParseError::UnterminatedQuote)
+        );
+        assert!(args("   ").is_empty());
+    }
+
+    #[test]
+    fn parse_struct_arguments() {
+        let expected = Spawn {
+            count: 3,
+            name: "big crab".to_string(),
+            scale: 1.5,
+        };
+        assert_eq!(
+            parse_args::<Spawn>(&args(r#"3 "big crab" 1.5"#)),
+            Ok(expected)
+        );
+        assert_eq!(
+            parse_args::<Spawn>(&args(r#"scale=1.5 3 "name=big crab""#)),
+            Ok(Spawn {
+                count: 3,
+                name: "big crab".to_string(),
+                scale: 1.5,
+            })
+        );
+        assert_eq!(
+            parse_args::<Spawn>(&args("3 crab")),
+            Err(ConsoleParseError::MissingArgument("scale".to_string()))
+        );
+        assert_eq!(
+            parse_args::<Spawn>(&args("three crab 1.0")),
+            Err(ConsoleParseError::InvalidValue {
+                argument: "count".to_string(),
+                value: "three".to_string(),
+                type_name: "u32",
+            })
+        );
+        assert_eq!(
+            parse_args::<Spawn>(&args("1 crab 1.0 extra")),
+            Err(ConsoleParseError::TooManyArguments {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn parse_positional_arguments() {
+        assert_eq!(parse_args::<Teleport>(&args("-4 12")), Ok(Teleport(-4, 12)));
+        assert_eq!(parse_args::<(bool, char)>(&args("true x")), Ok((true, 'x')));
+        assert_eq!(parse_args::<()>(&[]), Ok(()));
+        assert_eq!(parse_args::<f64>(&args("0.25")), Ok(0.25));
+        assert_eq!(
+            parse_args::<Vec<u32>>(&args("1 2")),
+            Err(ConsoleParseError::UnsupportedType("alloc::vec::Vec<u32>"))
+        );
+    }
+}
@@ -13,12 +13,13 @@ pub mod graph {
         pub const BLOOM: &str = "bloom";
         pub const TONEMAPPING: &str = "tonemapping";
         pub const FXAA: &str = "fxaa";
+        pub const POST_PROCESS: &str = "post_process";
         pub const UPSCALING: &str = "upscaling";
         pub const END_MAIN_PASS_POST_PROCESSING: &str = "end_main_pass_post_processing";
     }
 }
 
-use std::cmp::Reverse;
+use std::{cmp::Reverse, ops::Range};
 
 pub use camera_3d::*;
 pub use main_pass_3d_node::*;
@@ -31,8 +32,9 @@ use bevy_render::{
     prelude::Msaa,
     render_graph::{EmptyNode, RenderGraph, SlotInfo, SlotType},
     render_phase::{
-        sort_phase_system, CachedRenderPipelinePhaseItem, DrawFunctionId, DrawFunctions, PhaseItem,
-        RenderPhase,
+        add_instances_to_batch, group_instance_batches, sort_phase_system, BatchResult,
+        BatchedPhaseItem, CachedRenderPipelinePhaseItem, DrawFunctionId, DrawFunctions,
+        InstanceBatchKey, PhaseItem, RenderPhase,
     },
     render_resource::{
         CachedRenderPipelineId, Extent3d, TextureDescriptor, TextureDimension, TextureFormat,
@@ -138,6 +140,11 @@ pub struct Opaque3d {
     pub pipeline: CachedRenderPipelineId,
     pub entity: Entity,
     pub draw_function: DrawFunctionId,
+    /// Set if the item can be drawn with the same instanced draw call as the other items with the
+    /// same key.
+    pub batch_key: Option<InstanceBatchKey>,
+    /// The range of instances drawn by this item, which is assigned after sorting.
+    pub batch_range: Option<Range<u32>>,
 }
 
 impl PhaseItem for Opaque3d {
@@ -163,6 +170,7 @@ impl PhaseItem for Opaque3d {
     fn sort(items: &mut [Self]) {
         // Key negated to match reversed SortKey ordering
         radsort::sort_by_key(items, |item| -item.distance);
+        group_instance_batches(items, |item| item.distance, |item| item.batch_key);
     }
 }
 
@@ -173,11 +181,39 @@ impl CachedRenderPipelinePhaseItem for Opaque3d {
     }
 }
 
+impl BatchedPhaseItem for Opaque3d {
+    #[inline]
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+
+    /// Merges the instances of consecutive items with the same [`InstanceBatchKey`].
+    #[inline]
+    fn add_to_batch(&mut self, other: &Self) -> BatchResult {
+        add_instances_to_batch(
+            (self.batch_key, self.pipeline, self.draw_function),
+            &mut self.batch_range,
+            (other.batch_key, other.pipeline, other.draw_function),
+            &other.batch_range,
+        )
+    }
+}
+
 pub struct AlphaMask3d {
     pub distance: f32,
     pub pipeline: CachedRenderPipelineId,
     pub entity: Entity,
     pub draw_function: DrawFunctionId,
+    /// Set if the item can be drawn with the same instanced draw call as the other items with the
+    /// same key.
+    pub batch_key: Option<InstanceBatchKey>,
+    /// The range of instances drawn by this item, which is assigned after sorting.
+    pub batch_range: Option<Range<u32>>,
 }
 
 impl PhaseItem for AlphaMask3d {
@@ -203,6 +239,7 @@ impl PhaseItem for AlphaMask3d {
     fn sort(items: &mut [Self]) {
         // Key negated to match reversed SortKey ordering
         radsort::sort_by_key(items, |item| -item.distance);
+        group_instance_batches(items, |item| item.distance, |item| item.batch_key);
     }
 }
 
@@ -213,6 +250,29 @@ impl CachedRenderPipelinePhaseItem for AlphaMask3d {
     }
 }
 
+impl BatchedPhaseItem for AlphaMask3d {
+    #[inline]
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+
+    /// Merges the instances of consecutive items with the same [`InstanceBatchKey`].
+    #[inline]
+    fn add_to_batch(&mut self, other: &Self) -> BatchResult {
+        add_instances_to_batch(
+            (self.batch_key, self.pipeline, self.draw_function),
+            &mut self.batch_range,
+            (other.batch_key, other.pipeline, other.draw_function),
+            &other.batch_range,
+        )
+    }
+}
+
 pub struct Transparent3d {
     pub distance: f32,
     pub pipeline: CachedRenderPipelineId,
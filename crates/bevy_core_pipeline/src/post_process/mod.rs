@@ -0,0 +1,532 @@
+use crate::{core_2d, core_3d, fullscreen_vertex_shader::fullscreen_shader_vertex_state};
+use bevy_app::prelude::*;
+use bevy_asset::{
+    load_internal_asset, AddAsset, AssetEvent, AssetServer, Assets, Handle, HandleUntyped,
+};
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeUuid;
+use bevy_render::{
+    camera::ExtractedCamera,
+    prelude::Image,
+    render_asset::{PrepareAssetSet, RenderAssets},
+    render_graph::RenderGraph,
+    render_resource::*,
+    renderer::RenderDevice,
+    texture::{BevyDefault, FallbackImage},
+    view::{ExtractedView, ViewTarget},
+    Extract, ExtractSchedule, RenderApp, RenderSet,
+};
+use bevy_utils::{default, HashMap, HashSet};
+use std::{hash::Hash, marker::PhantomData};
+
+mod node;
+
+pub use node::PostProcessNode;
+
+const POST_PROCESS_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 8413930548253471205);
+
+/// A fullscreen effect of the [`PostProcessStack`](bevy_render::camera::PostProcessStack) of a
+/// camera.
+///
+/// The fragment shader reads the output of the previous effect (or of the main pass, for the first
+/// effect) and writes the input of the next one. The engine takes care of the intermediate
+/// textures. Post process materials are used alongside [`PostProcessMaterialPlugin`], which has to
+/// be added for every material type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_core_pipeline::post_process::PostProcessMaterial;
+/// # use bevy_reflect::TypeUuid;
+/// # use bevy_render::render_resource::{AsBindGroup, ShaderRef};
+/// #[derive(AsBindGroup, TypeUuid, Clone)]
+/// #[uuid = "0a5b5a95-1fd3-4ac4-a6f6-5a5ec9f4e1b4"]
+/// pub struct Vignette {
+///     #[uniform(0)]
+///     intensity: f32,
+/// }
+///
+/// impl PostProcessMaterial for Vignette {
+///     fn fragment_shader() -> ShaderRef {
+///         "shaders/vignette.wgsl".into()
+///     }
+/// }
+/// ```
+///
+/// The effect is then applied by pushing a handle of the material onto the stack of a camera,
+/// e.g. `camera.post_process_stack.push(materials.add(Vignette { intensity: 0.5 }))`.
+///
+/// The source texture is bound in group 0 by importing `bevy_core_pipeline::post_process`, the
+/// material is bound in group 1:
+///
+/// ```wgsl
+/// #import bevy_core_pipeline::fullscreen_vertex_shader
+/// #import bevy_core_pipeline::post_process
+///
+/// @group(1) @binding(0)
+/// var<uniform> intensity: f32;
+///
+/// @fragment
+/// fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+///     let color = textureSample(screen_texture, screen_sampler, in.uv);
+///     let vignette = 1.0 - intensity * length(in.uv - 0.5);
+///     return vec4<f32>(color.rgb * vignette, color.a);
+/// }
+/// ```
+pub trait PostProcessMaterial:
+    AsBindGroup + Send + Sync + Clone + TypeUuid + Sized + 'static
+{
+    /// Returns this material's fragment shader. Its entry point has to be called `fragment`.
+    fn fragment_shader() -> ShaderRef;
+
+    /// Customizes the default [`RenderPipelineDescriptor`].
+    #[allow(unused_variables)]
+    #[inline]
+    fn specialize(descriptor: &mut RenderPipelineDescriptor, key: PostProcessMaterialKey<Self>) {}
+}
+
+/// Adds support for the [`PostProcessStack`](bevy_render::camera::PostProcessStack) of cameras.
+///
+/// This is part of the [`CorePipelinePlugin`](crate::CorePipelinePlugin), the effects are applied
+/// after tonemapping and FXAA. Each [`PostProcessMaterial`] type additionally requires its
+/// [`PostProcessMaterialPlugin`].
+pub struct PostProcessPlugin;
+
+impl Plugin for PostProcessPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            POST_PROCESS_SHADER_HANDLE,
+            "post_process.wgsl",
+            Shader::from_wgsl
+        );
+
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Ok(render_app) => render_app,
+            Err(_) => return,
+        };
+        render_app
+            .init_resource::<PostProcessPipeline>()
+            .add_system(prepare_post_process_passes.in_set(RenderSet::Prepare));
+
+        {
+            let post_process_node = PostProcessNode::new(&mut render_app.world);
+            let mut binding = render_app.world.resource_mut::<RenderGraph>();
+            let graph = binding.get_sub_graph_mut(core_3d::graph::NAME).unwrap();
+
+            graph.add_node(core_3d::graph::node::POST_PROCESS, post_process_node);
+
+            graph.add_slot_edge(
+                graph.input_node().id,
+                core_3d::graph::input::VIEW_ENTITY,
+                core_3d::graph::node::POST_PROCESS,
+                PostProcessNode::IN_VIEW,
+            );
+
+            graph.add_node_edge(
+                core_3d::graph::node::TONEMAPPING,
+                core_3d::graph::node::POST_PROCESS,
+            );
+            if graph.get_node_id(core_3d::graph::node::FXAA).is_ok() {
+                graph.add_node_edge(
+                    core_3d::graph::node::FXAA,
+                    core_3d::graph::node::POST_PROCESS,
+                );
+            }
+            graph.add_node_edge(
+                core_3d::graph::node::POST_PROCESS,
+                core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+            );
+        }
+        {
+            let post_process_node = PostProcessNode::new(&mut render_app.world);
+            let mut binding = render_app.world.resource_mut::<RenderGraph>();
+            let graph = binding.get_sub_graph_mut(core_2d::graph::NAME).unwrap();
+
+            graph.add_node(core_2d::graph::node::POST_PROCESS, post_process_node);
+
+            graph.add_slot_edge(
+                graph.input_node().id,
+                core_2d::graph::input::VIEW_ENTITY,
+                core_2d::graph::node::POST_PROCESS,
+                PostProcessNode::IN_VIEW,
+            );
+
+            graph.add_node_edge(
+                core_2d::graph::node::TONEMAPPING,
+                core_2d::graph::node::POST_PROCESS,
+            );
+            if graph.get_node_id(core_2d::graph::node::FXAA).is_ok() {
+                graph.add_node_edge(
+                    core_2d::graph::node::FXAA,
+                    core_2d::graph::node::POST_PROCESS,
+                );
+            }
+            graph.add_node_edge(
+                core_2d::graph::node::POST_PROCESS,
+                core_2d::graph::node::END_MAIN_PASS_POST_PROCESSING,
+            );
+        }
+    }
+}
+
+/// The bind group layout and sampler of the source texture of post processing effects, bound in
+/// group 0.
+#[derive(Resource)]
+pub struct PostProcessPipeline {
+    pub source_layout: BindGroupLayout,
+    pub sampler: Sampler,
+}
+
+impl FromWorld for PostProcessPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let render_device = render_world.resource::<RenderDevice>();
+        let source_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("post_process_source_bind_group_layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let sampler = render_device.create_sampler(&SamplerDescriptor {
+            mipmap_filter: FilterMode::Linear,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..default()
+        });
+
+        PostProcessPipeline {
+            source_layout,
+            sampler,
+        }
+    }
+}
+
+/// A queued effect of the post process stack of a view.
+#[derive(Clone)]
+pub struct PostProcessPass {
+    pub pipeline_id: CachedRenderPipelineId,
+    pub material_bind_group: BindGroup,
+}
+
+/// The passes of the post process stack of a view, in the order of the stack.
+///
+/// Effects whose material isn't prepared yet are `None` and skipped.
+#[derive(Component, Default)]
+pub struct ViewPostProcessPasses(pub Vec<Option<PostProcessPass>>);
+
+/// Inserts empty [`ViewPostProcessPasses`] for all views with a post process stack, which are
+/// filled by the queue systems of the [`PostProcessMaterialPlugin`]s.
+pub fn prepare_post_process_passes(
+    mut commands: Commands,
+    views: Query<(Entity, &ExtractedCamera), With<ExtractedView>>,
+) {
+    for (entity, camera) in &views {
+        if camera.post_process_stack.is_empty() {
+            continue;
+        }
+        commands.entity(entity).insert(ViewPostProcessPasses(vec![
+            None;
+            camera.post_process_stack.len()
+        ]));
+    }
+}
+
+/// Adds the necessary ECS resources and render logic to use the given [`PostProcessMaterial`]
+/// asset type in the post process stack of cameras.
+pub struct PostProcessMaterialPlugin<M: PostProcessMaterial>(PhantomData<M>);
+
+impl<M: PostProcessMaterial> Default for PostProcessMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<M: PostProcessMaterial> Plugin for PostProcessMaterialPlugin<M>
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.add_asset::<M>();
+
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .init_resource::<PostProcessMaterialPipeline<M>>()
+                .init_resource::<ExtractedPostProcessMaterials<M>>()
+                .init_resource::<RenderPostProcessMaterials<M>>()
+                .init_resource::<SpecializedRenderPipelines<PostProcessMaterialPipeline<M>>>()
+                .add_systems((
+                    extract_post_process_materials::<M>.in_schedule(ExtractSchedule),
+                    prepare_post_process_materials::<M>
+                        .in_set(RenderSet::Prepare)
+                        .after(PrepareAssetSet::PreAssetPrepare),
+                    queue_post_process_passes::<M>.in_set(RenderSet::Queue),
+                ));
+        }
+    }
+}
+
+/// Render pipeline data for a given [`PostProcessMaterial`].
+#[derive(Resource)]
+pub struct PostProcessMaterialPipeline<M: PostProcessMaterial> {
+    pub source_layout: BindGroupLayout,
+    pub material_layout: BindGroupLayout,
+    pub fragment_shader: Handle<Shader>,
+    marker: PhantomData<M>,
+}
+
+impl<M: PostProcessMaterial> FromWorld for PostProcessMaterialPipeline<M> {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let render_device = world.resource::<RenderDevice>();
+
+        PostProcessMaterialPipeline {
+            source_layout: world
+                .resource::<PostProcessPipeline>()
+                .source_layout
+                .clone(),
+            material_layout: M::bind_group_layout(render_device),
+            fragment_shader: match M::fragment_shader() {
+                ShaderRef::Default => panic!(
+                    "post process material `{}` requires a fragment shader",
+                    std::any::type_name::<M>()
+                ),
+                ShaderRef::Handle(handle) => handle,
+                ShaderRef::Path(path) => asset_server.load(path),
+            },
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct PostProcessMaterialKey<M: PostProcessMaterial> {
+    pub texture_format: TextureFormat,
+    pub bind_group_data: M::Data,
+}
+
+impl<M: PostProcessMaterial> Eq for PostProcessMaterialKey<M> where M::Data: PartialEq {}
+
+impl<M: PostProcessMaterial> PartialEq for PostProcessMaterialKey<M>
+where
+    M::Data: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.texture_format == other.texture_format && self.bind_group_data == other.bind_group_data
+    }
+}
+
+impl<M: PostProcessMaterial> Clone for PostProcessMaterialKey<M>
+where
+    M::Data: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            texture_format: self.texture_format,
+            bind_group_data: self.bind_group_data.clone(),
+        }
+    }
+}
+
+impl<M: PostProcessMaterial> Hash for PostProcessMaterialKey<M>
+where
+    M::Data: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.texture_format.hash(state);
+        self.bind_group_data.hash(state);
+    }
+}
+
+impl<M: PostProcessMaterial> SpecializedRenderPipeline for PostProcessMaterialPipeline<M>
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    type Key = PostProcessMaterialKey<M>;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut descriptor = RenderPipelineDescriptor {
+            label: Some("post_process_material".into()),
+            layout: vec![self.source_layout.clone(), self.material_layout.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: self.fragment_shader.clone(),
+                shader_defs: vec![],
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: key.texture_format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: Vec::new(),
+        };
+        M::specialize(&mut descriptor, key);
+        descriptor
+    }
+}
+
+pub struct PreparedPostProcessMaterial<T: PostProcessMaterial> {
+    pub bindings: Vec<OwnedBindingResource>,
+    pub bind_group: BindGroup,
+    pub key: T::Data,
+}
+
+#[derive(Resource)]
+struct ExtractedPostProcessMaterials<M: PostProcessMaterial> {
+    extracted: Vec<(Handle<M>, M)>,
+    removed: Vec<Handle<M>>,
+}
+
+impl<M: PostProcessMaterial> Default for ExtractedPostProcessMaterials<M> {
+    fn default() -> Self {
+        Self {
+            extracted: Default::default(),
+            removed: Default::default(),
+        }
+    }
+}
+
+/// Stores all prepared representations of [`PostProcessMaterial`] assets for as long as they exist.
+#[derive(Resource)]
+pub struct RenderPostProcessMaterials<T: PostProcessMaterial>(
+    pub HashMap<Handle<T>, PreparedPostProcessMaterial<T>>,
+);
+
+impl<T: PostProcessMaterial> Default for RenderPostProcessMaterials<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+/// This system extracts all created or modified assets of the corresponding
+/// [`PostProcessMaterial`] type into the "render world".
+fn extract_post_process_materials<M: PostProcessMaterial>(
+    mut commands: Commands,
+    mut events: Extract<EventReader<AssetEvent<M>>>,
+    assets: Extract<Res<Assets<M>>>,
+) {
+    let mut changed_assets = HashSet::default();
+    let mut removed = Vec::new();
+    for event in events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                changed_assets.insert(handle.clone_weak());
+            }
+            AssetEvent::Removed { handle } => {
+                changed_assets.remove(handle);
+                removed.push(handle.clone_weak());
+            }
+            AssetEvent::Evicted { .. } | AssetEvent::DependencyModified { .. } => {}
+        }
+    }
+
+    let mut extracted_assets = Vec::new();
+    for handle in changed_assets.drain() {
+        if let Some(asset) = assets.get(&handle) {
+            extracted_assets.push((handle, asset.clone()));
+        }
+    }
+
+    commands.insert_resource(ExtractedPostProcessMaterials {
+        extracted: extracted_assets,
+        removed,
+    });
+}
+
+/// This system prepares all assets of the corresponding [`PostProcessMaterial`] type
+/// which where extracted this frame for the GPU.
+fn prepare_post_process_materials<M: PostProcessMaterial>(
+    mut prepare_next_frame: Local<Vec<(Handle<M>, M)>>,
+    mut extracted_assets: ResMut<ExtractedPostProcessMaterials<M>>,
+    mut render_materials: ResMut<RenderPostProcessMaterials<M>>,
+    render_device: Res<RenderDevice>,
+    images: Res<RenderAssets<Image>>,
+    fallback_image: Res<FallbackImage>,
+    pipeline: Res<PostProcessMaterialPipeline<M>>,
+) {
+    for removed in std::mem::take(&mut extracted_assets.removed) {
+        render_materials.0.remove(&removed);
+    }
+
+    let queued_assets = std::mem::take(&mut *prepare_next_frame);
+    for (handle, material) in queued_assets
+        .into_iter()
+        .chain(std::mem::take(&mut extracted_assets.extracted))
+    {
+        match material.as_bind_group(
+            &pipeline.material_layout,
+            &render_device,
+            &images,
+            &fallback_image,
+        ) {
+            Ok(prepared) => {
+                render_materials.0.insert(
+                    handle,
+                    PreparedPostProcessMaterial {
+                        bindings: prepared.bindings,
+                        bind_group: prepared.bind_group,
+                        key: prepared.data,
+                    },
+                );
+            }
+            Err(AsBindGroupError::RetryNextUpdate) => {
+                prepare_next_frame.push((handle, material));
+            }
+        }
+    }
+}
+
+/// Specializes the pipelines of the effects of the corresponding [`PostProcessMaterial`] type and
+/// inserts them into the [`ViewPostProcessPasses`] of the views.
+pub fn queue_post_process_passes<M: PostProcessMaterial>(
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<PostProcessMaterialPipeline<M>>>,
+    pipeline: Res<PostProcessMaterialPipeline<M>>,
+    render_materials: Res<RenderPostProcessMaterials<M>>,
+    mut views: Query<(&ExtractedView, &ExtractedCamera, &mut ViewPostProcessPasses)>,
+) where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    for (view, camera, mut passes) in &mut views {
+        let texture_format = if view.hdr {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+        for (id, pass) in camera.post_process_stack.iter().zip(&mut passes.0) {
+            let Some(material) = render_materials.0.get(&Handle::weak(*id)) else {
+                continue;
+            };
+            let pipeline_id = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                PostProcessMaterialKey {
+                    texture_format,
+                    bind_group_data: material.key.clone(),
+                },
+            );
+            *pass = Some(PostProcessPass {
+                pipeline_id,
+                material_bind_group: material.bind_group.clone(),
+            });
+        }
+    }
+}
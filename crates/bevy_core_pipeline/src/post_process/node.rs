@@ -0,0 +1,100 @@
+use crate::post_process::{PostProcessPipeline, ViewPostProcessPasses};
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::QueryState;
+use bevy_render::{
+    render_graph::{Node, NodeRunError, RenderGraphContext, SlotInfo, SlotType},
+    render_resource::{
+        BindGroupDescriptor, BindGroupEntry, BindingResource, Operations, PipelineCache,
+        RenderPassColorAttachment, RenderPassDescriptor,
+    },
+    renderer::RenderContext,
+    view::{ExtractedView, ViewTarget},
+};
+
+/// Applies the [`ViewPostProcessPasses`] of a view in order, swapping the main textures of the
+/// [`ViewTarget`] after each pass.
+pub struct PostProcessNode {
+    query: QueryState<(&'static ViewTarget, &'static ViewPostProcessPasses), With<ExtractedView>>,
+}
+
+impl PostProcessNode {
+    pub const IN_VIEW: &'static str = "view";
+
+    pub fn new(world: &mut World) -> Self {
+        Self {
+            query: QueryState::new(world),
+        }
+    }
+}
+
+impl Node for PostProcessNode {
+    fn input(&self) -> Vec<SlotInfo> {
+        vec![SlotInfo::new(PostProcessNode::IN_VIEW, SlotType::Entity)]
+    }
+
+    fn update(&mut self, world: &mut World) {
+        self.query.update_archetypes(world);
+    }
+
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let post_process_pipeline = world.resource::<PostProcessPipeline>();
+
+        let (target, passes) = match self.query.get_manual(world, view_entity) {
+            Ok(result) => result,
+            Err(_) => return Ok(()),
+        };
+
+        for pass in passes.0.iter().flatten() {
+            let Some(pipeline) = pipeline_cache.get_render_pipeline(pass.pipeline_id) else {
+                continue;
+            };
+
+            let post_process = target.post_process_write();
+            let source_bind_group =
+                render_context
+                    .render_device()
+                    .create_bind_group(&BindGroupDescriptor {
+                        label: Some("post_process_source_bind_group"),
+                        layout: &post_process_pipeline.source_layout,
+                        entries: &[
+                            BindGroupEntry {
+                                binding: 0,
+                                resource: BindingResource::TextureView(post_process.source),
+                            },
+                            BindGroupEntry {
+                                binding: 1,
+                                resource: BindingResource::Sampler(&post_process_pipeline.sampler),
+                            },
+                        ],
+                    });
+
+            let pass_descriptor = RenderPassDescriptor {
+                label: Some("post_process_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: post_process.destination,
+                    resolve_target: None,
+                    ops: Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+            };
+
+            let mut render_pass = render_context
+                .command_encoder()
+                .begin_render_pass(&pass_descriptor);
+
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, &source_bind_group, &[]);
+            render_pass.set_bind_group(1, &pass.material_bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+#define_import_path bevy_core_pipeline::post_process
+
+// The output of the previous effect of the post process stack.
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+@group(0) @binding(1)
+var screen_sampler: sampler;
@@ -26,12 +26,15 @@
 
 pub mod node;
 
-use std::cmp::Reverse;
+use std::{cmp::Reverse, ops::Range};
 
 use bevy_ecs::prelude::*;
 use bevy_reflect::Reflect;
 use bevy_render::{
-    render_phase::{CachedRenderPipelinePhaseItem, DrawFunctionId, PhaseItem},
+    render_phase::{
+        add_instances_to_batch, group_instance_batches, BatchResult, BatchedPhaseItem,
+        CachedRenderPipelinePhaseItem, DrawFunctionId, InstanceBatchKey, PhaseItem,
+    },
     render_resource::{CachedRenderPipelineId, Extent3d, TextureFormat},
     texture::CachedTexture,
 };
@@ -74,6 +77,11 @@ pub struct Opaque3dPrepass {
     pub entity: Entity,
     pub pipeline_id: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    /// Set if the item can be drawn with the same instanced draw call as the other items with the
+    /// same key.
+    pub batch_key: Option<InstanceBatchKey>,
+    /// The range of instances drawn by this item, which is assigned after sorting.
+    pub batch_range: Option<Range<u32>>,
 }
 
 impl PhaseItem for Opaque3dPrepass {
@@ -99,6 +107,7 @@ impl PhaseItem for Opaque3dPrepass {
     fn sort(items: &mut [Self]) {
         // Key negated to match reversed SortKey ordering
         radsort::sort_by_key(items, |item| -item.distance);
+        group_instance_batches(items, |item| item.distance, |item| item.batch_key);
     }
 }
 
@@ -109,6 +118,29 @@ impl CachedRenderPipelinePhaseItem for Opaque3dPrepass {
     }
 }
 
+impl BatchedPhaseItem for Opaque3dPrepass {
+    #[inline]
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+
+    /// Merges the instances of consecutive items with the same [`InstanceBatchKey`].
+    #[inline]
+    fn add_to_batch(&mut self, other: &Self) -> BatchResult {
+        add_instances_to_batch(
+            (self.batch_key, self.pipeline_id, self.draw_function),
+            &mut self.batch_range,
+            (other.batch_key, other.pipeline_id, other.draw_function),
+            &other.batch_range,
+        )
+    }
+}
+
 /// Alpha mask phase of the 3D prepass.
 ///
 /// Sorted front-to-back by the z-distance in front of the camera.
@@ -119,6 +151,11 @@ pub struct AlphaMask3dPrepass {
     pub entity: Entity,
     pub pipeline_id: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    /// Set if the item can be drawn with the same instanced draw call as the other items with the
+    /// same key.
+    pub batch_key: Option<InstanceBatchKey>,
+    /// The range of instances drawn by this item, which is assigned after sorting.
+    pub batch_range: Option<Range<u32>>,
 }
 
 impl PhaseItem for AlphaMask3dPrepass {
@@ -144,6 +181,7 @@ impl PhaseItem for AlphaMask3dPrepass {
     fn sort(items: &mut [Self]) {
         // Key negated to match reversed SortKey ordering
         radsort::sort_by_key(items, |item| -item.distance);
+        group_instance_batches(items, |item| item.distance, |item| item.batch_key);
     }
 }
 
@@ -153,3 +191,26 @@ impl CachedRenderPipelinePhaseItem for AlphaMask3dPrepass {
         self.pipeline_id
     }
 }
+
+impl BatchedPhaseItem for AlphaMask3dPrepass {
+    #[inline]
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+
+    /// Merges the instances of consecutive items with the same [`InstanceBatchKey`].
+    #[inline]
+    fn add_to_batch(&mut self, other: &Self) -> BatchResult {
+        add_instances_to_batch(
+            (self.batch_key, self.pipeline_id, self.draw_function),
+            &mut self.batch_range,
+            (other.batch_key, other.pipeline_id, other.draw_function),
+            &other.batch_range,
+        )
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{DiagnosticId, Diagnostics};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::{HashMap, Instant};
+
+/// Adds [`DiagnosticBudgets`] to an App, which send [`BudgetExceeded`] events whenever a
+/// measurement of a [`Diagnostic`](crate::Diagnostic) goes over its budget.
+///
+/// Budgets can be set for any diagnostic, like the frame time of the
+/// [`FrameTimeDiagnosticsPlugin`](crate::FrameTimeDiagnosticsPlugin) or the schedule time of the
+/// [`ScheduleTimeDiagnosticsPlugin`](crate::ScheduleTimeDiagnosticsPlugin).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_diagnostic::{BudgetExceeded, DiagnosticBudgets, FrameTimeDiagnosticsPlugin};
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut budgets: ResMut<DiagnosticBudgets>) {
+///     // frame time is measured in milliseconds
+///     budgets.set(FrameTimeDiagnosticsPlugin::FRAME_TIME, 1000.0 / 60.0);
+/// }
+///
+/// fn lower_quality(mut exceeded: EventReader<BudgetExceeded>) {
+///     for event in exceeded.iter() {
+///         println!("over budget: {} > {}", event.value, event.budget);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// # bevy_ecs::system::assert_is_system(lower_quality);
+/// ```
+#[derive(Default)]
+pub struct BudgetDiagnosticsPlugin;
+
+impl Plugin for BudgetDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticBudgets>()
+            .add_event::<BudgetExceeded>()
+            .add_system(Self::budget_system.in_base_set(CoreSet::Last));
+    }
+}
+
+impl BudgetDiagnosticsPlugin {
+    /// Sends a [`BudgetExceeded`] event for every new measurement that is over its budget.
+    pub fn budget_system(
+        mut budgets: ResMut<DiagnosticBudgets>,
+        diagnostics: Res<Diagnostics>,
+        mut exceeded: EventWriter<BudgetExceeded>,
+    ) {
+        for (&id, budget) in budgets.budgets.iter_mut() {
+            let Some(measurement) = diagnostics.get_measurement(id) else {
+                continue;
+            };
+            if budget.last_checked == Some(measurement.time) {
+                continue;
+            }
+            budget.last_checked = Some(measurement.time);
+            if measurement.value > budget.max {
+                exceeded.send(BudgetExceeded {
+                    id,
+                    value: measurement.value,
+                    budget: budget.max,
+                    time: measurement.time,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Budget {
+    max: f64,
+    last_checked: Option<Instant>,
+}
+
+/// The budgets of [`Diagnostic`](crate::Diagnostic)s, checked by the [`BudgetDiagnosticsPlugin`].
+///
+/// A budget is the maximum value a measurement of the diagnostic may have, in the unit of the
+/// diagnostic, e.g. milliseconds for
+/// [`FrameTimeDiagnosticsPlugin::FRAME_TIME`](crate::FrameTimeDiagnosticsPlugin::FRAME_TIME).
+#[derive(Debug, Default, Resource)]
+pub struct DiagnosticBudgets {
+    budgets: HashMap<DiagnosticId, Budget>,
+}
+
+impl DiagnosticBudgets {
+    /// Sets the budget of the diagnostic with the given `id` to `max`.
+    pub fn set(&mut self, id: DiagnosticId, max: f64) {
+        self.budgets.insert(
+            id,
+            Budget {
+                max,
+                last_checked: None,
+            },
+        );
+    }
+
+    /// Removes the budget of the diagnostic with the given `id`, returning it if it existed.
+    pub fn remove(&mut self, id: DiagnosticId) -> Option<f64> {
+        self.budgets.remove(&id).map(|budget| budget.max)
+    }
+
+    /// Gets the budget of the diagnostic with the given `id`.
+    pub fn get(&self, id: DiagnosticId) -> Option<f64> {
+        self.budgets.get(&id).map(|budget| budget.max)
+    }
+
+    /// Return an iterator over all diagnostic ids and their budgets.
+    pub fn iter(&self) -> impl Iterator<Item = (DiagnosticId, f64)> + '_ {
+        self.budgets.iter().map(|(&id, budget)| (id, budget.max))
+    }
+}
+
+/// Sent by the [`BudgetDiagnosticsPlugin`] when a measurement of a diagnostic is over its budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetExceeded {
+    /// The id of the diagnostic.
+    pub id: DiagnosticId,
+    /// The offending measurement.
+    pub value: f64,
+    /// The budget of the diagnostic at the time of the measurement.
+    pub budget: f64,
+    /// The time of the measurement.
+    pub time: Instant,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Diagnostic;
+    use bevy_ecs::event::Events;
+
+    const ID: DiagnosticId = DiagnosticId::from_u128(1);
+
+    #[test]
+    fn budget_system() {
+        let mut world = World::new();
+        world.init_resource::<DiagnosticBudgets>();
+        world.init_resource::<Events<BudgetExceeded>>();
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.add(Diagnostic::new(ID, "test", 20));
+        world.insert_resource(diagnostics);
+        world.resource_mut::<DiagnosticBudgets>().set(ID, 10.0);
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(BudgetDiagnosticsPlugin::budget_system);
+        let mut run = |world: &mut World, value: Option<f64>| {
+            if let Some(value) = value {
+                world
+                    .resource_mut::<Diagnostics>()
+                    .add_measurement(ID, || value);
+            }
+            schedule.run(world);
+            world
+                .resource_mut::<Events<BudgetExceeded>>()
+                .drain()
+                .map(|event| (event.value, event.budget))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(&mut world, None), []);
+        assert_eq!(run(&mut world, Some(5.0)), []);
+        assert_eq!(run(&mut world, Some(12.0)), [(12.0, 10.0)]);
+        // the same measurement is only reported once
+        assert_eq!(run(&mut world, None), []);
+        assert_eq!(run(&mut world, Some(10.0)), []);
+    }
+
+    #[test]
+    fn schedule_time_budget() {
+        let mut app = App::new();
+        app.init_resource::<Diagnostics>()
+            .add_plugin(crate::ScheduleTimeDiagnosticsPlugin)
+            .add_plugin(BudgetDiagnosticsPlugin);
+        app.world
+            .resource_mut::<DiagnosticBudgets>()
+            .set(crate::ScheduleTimeDiagnosticsPlugin::SCHEDULE_TIME, -1.0);
+
+        app.update();
+        let diagnostics = app.world.resource::<Diagnostics>();
+        let measurement = diagnostics
+            .get_measurement(crate::ScheduleTimeDiagnosticsPlugin::SCHEDULE_TIME)
+            .unwrap();
+        assert!(measurement.value >= 0.0);
+
+        app.update();
+        let exceeded = app.world.resource::<Events<BudgetExceeded>>();
+        assert!(!exceeded.is_empty());
+    }
+}
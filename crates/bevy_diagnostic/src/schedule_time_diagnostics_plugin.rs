@@ -0,0 +1,48 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::Instant;
+
+use crate::{Diagnostic, DiagnosticId, Diagnostics};
+
+/// Adds "schedule time" diagnostic to an App, measuring how long the main schedule runs each
+/// update.
+///
+/// Unlike the frame time, it doesn't include the time spent between updates. The measurement
+/// starts in [`CoreSet::First`] and ends in [`CoreSet::Last`].
+#[derive(Default)]
+pub struct ScheduleTimeDiagnosticsPlugin;
+
+impl Plugin for ScheduleTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduleStart>()
+            .add_startup_system(Self::setup_system)
+            .add_system(Self::start_system.in_base_set(CoreSet::First))
+            .add_system(Self::diagnostic_system.in_base_set(CoreSet::Last));
+    }
+}
+
+/// The time the main schedule started running in the current update.
+#[derive(Resource, Default)]
+struct ScheduleStart(Option<Instant>);
+
+impl ScheduleTimeDiagnosticsPlugin {
+    pub const SCHEDULE_TIME: DiagnosticId =
+        DiagnosticId::from_u128(128450337962290356093584870374128731025);
+
+    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+        diagnostics
+            .add(Diagnostic::new(Self::SCHEDULE_TIME, "schedule_time", 20).with_suffix("ms"));
+    }
+
+    fn start_system(mut start: ResMut<ScheduleStart>) {
+        start.0 = Some(Instant::now());
+    }
+
+    fn diagnostic_system(mut diagnostics: ResMut<Diagnostics>, start: Res<ScheduleStart>) {
+        if let Some(start) = start.0 {
+            diagnostics.add_measurement(Self::SCHEDULE_TIME, || {
+                start.elapsed().as_secs_f64() * 1000.0
+            });
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! A C-compatible API for embedding an [`App`] in non-Rust hosts, such as launchers, engines or
+//! test rigs.
+//!
+//! With the `embed` feature enabled, the functions are exported unmangled, so linking this crate
+//! into a `cdylib` or `staticlib` makes them available to the host. Only one crate in the final
+//! library may enable the feature, otherwise the symbols collide. Without it, the functions can
+//! still be called from Rust, e.g. to wrap them in exports of another name.
+//!
+//! An app is created with [`bevy_app_new`], configured by loading dynamic plugins, driven by
+//! calling [`bevy_app_update`] once per frame, and destroyed with [`bevy_app_shutdown`]:
+//!
+//! ```c
+//! typedef struct EmbeddedApp EmbeddedApp;
+//!
+//! EmbeddedApp *app = bevy_app_new();
+//! if (!bevy_app_add_dynamic_plugin(app, "libgame.so")) {
+//!     /* handle the error */
+//! }
+//! while (bevy_app_update(app)) {
+//!     bevy_app_send_key(app, "Space", true);
+//! }
+//! bevy_app_shutdown(app);
+//! ```
+//!
+//! Input is sent as the events of `bevy_input`, so the plugins have to add the `InputPlugin` for
+//! it to be received.
+//!
+//! Panics never unwind into the host. A panic while updating the app is treated like an exit, so
+//! [`bevy_app_update`] returns `false` from then on, while other functions return their failure
+//! value.
+
+use crate::dynamically_load_plugin;
+use bevy_app::{App, AppExit};
+use bevy_ecs::{
+    entity::Entity,
+    event::{Event, Events, ManualEventReader},
+};
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    ButtonState,
+};
+use bevy_math::Vec2;
+use bevy_reflect::{DynamicEnum, DynamicVariant, FromReflect, TypeInfo, Typed};
+use libloading::Library;
+use std::{
+    ffi::{c_char, CStr},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+/// An [`App`] driven by a non-Rust host through the functions of this module.
+///
+/// The dynamic libraries of the loaded plugins are kept loaded until the app is dropped.
+pub struct EmbeddedApp {
+    // declared before `libraries` so that it is dropped before the plugin code is unloaded
+    app: App,
+    exit_reader: ManualEventReader<AppExit>,
+    is_set_up: bool,
+    has_exited: bool,
+    libraries: Vec<Library>,
+}
+
+impl EmbeddedApp {
+    /// Returns the embedded app.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the embedded app mutably.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Sends `event` if events of its type were added to the app, returning whether it was sent.
+    fn send_event<E: Event>(&mut self, event: E) -> bool {
+        match self.app.world.get_resource_mut::<Events<E>>() {
+            Some(mut events) => {
+                events.send(event);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Creates a new app with the default schedules and without any plugins.
+///
+/// The returned app has to be destroyed with [`bevy_app_shutdown`]. Returns a null pointer if
+/// the app could not be created.
+#[cfg_attr(feature = "embed", no_mangle)]
+pub extern "C" fn bevy_app_new() -> *mut EmbeddedApp {
+    catch_unwind(|| {
+        Box::into_raw(Box::new(EmbeddedApp {
+            app: App::new(),
+            exit_reader: ManualEventReader::default(),
+            is_set_up: false,
+            has_exited: false,
+            libraries: Vec::new(),
+        }))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Loads the dynamic plugin at the nul-terminated `path` and adds it to the app, see
+/// [`dynamically_load_plugin`].
+///
+/// Returns `false` if the plugin could not be loaded.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`] and `path` must be a valid nul-terminated
+/// string. The requirements of [`dynamically_load_plugin`] apply to the plugin.
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_add_dynamic_plugin(
+    app: *mut EmbeddedApp,
+    path: *const c_char,
+) -> bool {
+    let app = &mut *app;
+    let Ok(path) = CStr::from_ptr(path).to_str() else {
+        return false;
+    };
+    catch_unwind(AssertUnwindSafe(|| match dynamically_load_plugin(path) {
+        Ok((library, plugin)) => {
+            // keep the library loaded even if building the plugin panics, as the app may already
+            // hold code or data of it
+            app.libraries.push(library);
+            plugin.build(&mut app.app);
+            true
+        }
+        Err(_) => false,
+    }))
+    .unwrap_or(false)
+}
+
+/// Runs a single frame of the app, setting up its plugins first if this is the first frame.
+///
+/// Returns `false` once the app has exited, after which it should be shut down.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`].
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_update(app: *mut EmbeddedApp) -> bool {
+    let app = &mut *app;
+    if app.has_exited {
+        return false;
+    }
+    let exited = catch_unwind(AssertUnwindSafe(|| {
+        if !app.is_set_up {
+            app.is_set_up = true;
+            app.app.setup();
+        }
+        app.app.update();
+        app.app.poll_exit(&mut app.exit_reader).is_some()
+    }));
+    app.has_exited = exited.unwrap_or(true);
+    !app.has_exited
+}
+
+/// Sends a [`KeyboardInput`] event for the key with the nul-terminated `key_name`, which is the
+/// name of a [`KeyCode`] variant, e.g. `"Space"` or `"A"`.
+///
+/// Returns `false` if the key name is unknown or the app doesn't receive keyboard input.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`] and `key_name` must be a valid
+/// nul-terminated string.
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_send_key(
+    app: *mut EmbeddedApp,
+    key_name: *const c_char,
+    pressed: bool,
+) -> bool {
+    let app = &mut *app;
+    let Ok(key_name) = CStr::from_ptr(key_name).to_str() else {
+        return false;
+    };
+    catch_unwind(AssertUnwindSafe(|| send_key(app, key_name, pressed))).unwrap_or(false)
+}
+
+fn send_key(app: &mut EmbeddedApp, key_name: &str, pressed: bool) -> bool {
+    let TypeInfo::Enum(key_codes) = KeyCode::type_info() else {
+        unreachable!("KeyCode is an enum");
+    };
+    // only `from_reflect` known variants, as it panics on unknown ones
+    if !key_codes.contains_variant(key_name) {
+        return false;
+    }
+    let Some(key_code) = KeyCode::from_reflect(&DynamicEnum::new(
+        key_codes.type_name(),
+        key_name,
+        DynamicVariant::Unit,
+    )) else {
+        return false;
+    };
+    app.send_event(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(key_code),
+        state: button_state(pressed),
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    })
+}
+
+/// Sends a [`MouseButtonInput`] event. The `button` is `0` for the left, `1` for the right, `2`
+/// for the middle button, and the number of the [`MouseButton::Other`] button otherwise.
+///
+/// Returns `false` if the app doesn't receive mouse input.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`].
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_send_mouse_button(
+    app: *mut EmbeddedApp,
+    button: u16,
+    pressed: bool,
+) -> bool {
+    let button = match button {
+        0 => MouseButton::Left,
+        1 => MouseButton::Right,
+        2 => MouseButton::Middle,
+        other => MouseButton::Other(other),
+    };
+    send_event(
+        app,
+        MouseButtonInput {
+            button,
+            state: button_state(pressed),
+            window: Entity::PLACEHOLDER,
+        },
+    )
+}
+
+/// Sends a [`MouseMotion`] event with the given delta.
+///
+/// Returns `false` if the app doesn't receive mouse input.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`].
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_send_mouse_motion(app: *mut EmbeddedApp, x: f32, y: f32) -> bool {
+    send_event(
+        app,
+        MouseMotion {
+            delta: Vec2::new(x, y),
+        },
+    )
+}
+
+/// Sends a [`MouseWheel`] event scrolling by the given number of lines.
+///
+/// Returns `false` if the app doesn't receive mouse input.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`].
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_send_mouse_wheel(app: *mut EmbeddedApp, x: f32, y: f32) -> bool {
+    send_event(
+        app,
+        MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x,
+            y,
+        },
+    )
+}
+
+/// Runs the shutdown schedule of the app, unless it already exited, and destroys it.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`] and must not be used afterwards.
+#[cfg_attr(feature = "embed", no_mangle)]
+pub unsafe extern "C" fn bevy_app_shutdown(app: *mut EmbeddedApp) {
+    let mut app = Box::from_raw(app);
+    // a panic while dropping the app is caught as well, so the plugin libraries are unloaded last
+    let libraries = std::mem::take(&mut app.libraries);
+    let _ = catch_unwind(AssertUnwindSafe(move || {
+        if app.is_set_up && !app.has_exited {
+            app.app.shutdown();
+        }
+    }));
+    drop(libraries);
+}
+
+/// Sends `event` to `app` without letting a panic unwind into the host.
+///
+/// # Safety
+///
+/// `app` must have been created by [`bevy_app_new`].
+unsafe fn send_event<E: Event>(app: *mut EmbeddedApp, event: E) -> bool {
+    let app = &mut *app;
+    catch_unwind(AssertUnwindSafe(|| app.send_event(event))).unwrap_or(false)
+}
+
+fn button_state(pressed: bool) -> ButtonState {
+    if pressed {
+        ButtonState::Pressed
+    } else {
+        ButtonState::Released
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::Plugin;
+    use bevy_input::{Input, InputPlugin};
+    use std::ffi::CString;
+
+    struct PanicPlugin;
+
+    impl Plugin for PanicPlugin {
+        fn build(&self, app: &mut App) {
+            app.add_system(panicking_system);
+        }
+    }
+
+    fn c_string(string: &str) -> CString {
+        CString::new(string).unwrap()
+    }
+
+    fn panicking_system() {
+        panic!("the system panicked");
+    }
+
+    #[test]
+    fn embedded_app_receives_input() {
+        let app = bevy_app_new();
+        assert!(!app.is_null());
+        // SAFETY: `app` was created by `bevy_app_new` and the strings outlive the calls
+        unsafe {
+            assert!(!bevy_app_send_key(app, c_string("Space").as_ptr(), true));
+            (*app).app_mut().add_plugin(InputPlugin);
+            assert!(bevy_app_send_key(app, c_string("Space").as_ptr(), true));
+            assert!(!bevy_app_send_key(app, c_string("NotAKey").as_ptr(), true));
+            assert!(bevy_app_send_mouse_button(app, 0, true));
+            assert!(bevy_app_send_mouse_motion(app, 1.0, 2.0));
+            assert!(bevy_app_send_mouse_wheel(app, 0.0, 1.0));
+            assert!(!bevy_app_add_dynamic_plugin(
+                app,
+                c_string("does/not/exist.so").as_ptr()
+            ));
+
+            assert!(bevy_app_update(app));
+            assert!((*app)
+                .app()
+                .world
+                .resource::<Input<KeyCode>>()
+                .pressed(KeyCode::Space));
+            bevy_app_shutdown(app);
+        }
+    }
+
+    #[test]
+    fn embedded_app_exits() {
+        let app = bevy_app_new();
+        // SAFETY: `app` was created by `bevy_app_new`
+        unsafe {
+            assert!(bevy_app_update(app));
+            (*app).app_mut().world.send_event(AppExit);
+            assert!(!bevy_app_update(app));
+            assert!(!bevy_app_update(app));
+            bevy_app_shutdown(app);
+        }
+    }
+
+    #[test]
+    fn embedded_app_catches_panics() {
+        let app = bevy_app_new();
+        // SAFETY: `app` was created by `bevy_app_new`
+        unsafe {
+            (*app).app_mut().add_plugin(PanicPlugin);
+            assert!(!bevy_app_update(app));
+            assert!(!bevy_app_update(app));
+            bevy_app_shutdown(app);
+        }
+    }
+}
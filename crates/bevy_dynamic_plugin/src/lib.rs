@@ -1,3 +1,6 @@
+pub mod embed;
 mod loader;
+mod stable;
 
 pub use loader::*;
+pub use stable::*;
@@ -2,6 +2,7 @@ use libloading::{Library, Symbol};
 use std::ffi::OsStr;
 use thiserror::Error;
 
+use crate::dynamically_load_stable_plugin;
 use bevy_app::{App, CreatePlugin, Plugin};
 
 /// Errors that can occur when loading a dynamic plugin
@@ -11,6 +12,12 @@ pub enum DynamicPluginLoadError {
     Library(libloading::Error),
     #[error("dynamic library does not contain a valid Bevy dynamic plugin")]
     Plugin(libloading::Error),
+    #[error("stable plugin was compiled for ABI version {found}, expected {expected}")]
+    AbiVersion { expected: u32, found: u32 },
+    #[error("stable plugin was compiled with ABI hash {found:#x}, expected {expected:#x}")]
+    AbiHash { expected: u64, found: u64 },
+    #[error("stable plugin was compiled for engine version {found}, expected {expected}")]
+    EngineVersion { expected: String, found: String },
 }
 
 /// Dynamically links a plugin at the given path. The plugin must export a function with the
@@ -21,6 +28,10 @@ pub enum DynamicPluginLoadError {
 /// The specified plugin must be linked against the exact same libbevy.so as this program.
 /// In addition the `_bevy_create_plugin` symbol must not be manually created, but instead created
 /// by deriving `DynamicPlugin` on a unit struct implementing [`Plugin`].
+///
+/// Plugins which can't guarantee this should be exported as a
+/// [`StablePlugin`](bevy_app::StablePlugin) and loaded with [`dynamically_load_stable_plugin`],
+/// which rejects plugins compiled against a different engine version.
 pub unsafe fn dynamically_load_plugin<P: AsRef<OsStr>>(
     path: P,
 ) -> Result<(Library, Box<dyn Plugin>), DynamicPluginLoadError> {
@@ -37,6 +48,14 @@ pub trait DynamicPluginExt {
     ///
     /// Same as [`dynamically_load_plugin`].
     unsafe fn load_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self;
+
+    /// Loads and builds a [`StablePlugin`](bevy_app::StablePlugin), panicking if it can't be
+    /// loaded.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`dynamically_load_stable_plugin`].
+    unsafe fn load_stable_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self;
 }
 
 impl DynamicPluginExt for App {
@@ -46,4 +65,10 @@ impl DynamicPluginExt for App {
         plugin.build(self);
         self
     }
+
+    unsafe fn load_stable_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self {
+        let (lib, _) = dynamically_load_stable_plugin(path, self).unwrap();
+        std::mem::forget(lib); // Ensure that the library is not automatically unloaded
+        self
+    }
 }
@@ -0,0 +1,303 @@
+use crate::DynamicPluginLoadError;
+use bevy_app::{
+    AbiField, AbiStr, App, AppTypeRegistry, AppVTable, StableLogLevel, StablePluginInfo, ABI_HASH,
+    ENGINE_VERSION, PLUGIN_ABI_VERSION,
+};
+use bevy_ecs::reflect::ReflectResource;
+use bevy_reflect::{serde::TypedReflectDeserializer, DynamicTypeDescriptor};
+use bevy_utils::tracing::{debug, error, info, warn};
+use libloading::{Library, Symbol};
+use serde::de::DeserializeSeed;
+use std::{
+    ffi::{c_void, OsStr},
+    mem::MaybeUninit,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+type StablePluginAbiVersionFn = unsafe extern "C" fn() -> u32;
+type StablePluginAbiHashFn = unsafe extern "C" fn() -> u64;
+type StablePluginInfoFn = unsafe extern "C" fn(*mut StablePluginInfo);
+type StablePluginBuildFn = unsafe extern "C" fn(*const AppVTable);
+
+/// Dynamically links a [`StablePlugin`](bevy_app::StablePlugin) at the given path and builds it
+/// with `app`, returning the name of the plugin.
+///
+/// Unlike [`dynamically_load_plugin`](crate::dynamically_load_plugin), the plugin is rejected
+/// with [`DynamicPluginLoadError::AbiVersion`], [`DynamicPluginLoadError::AbiHash`] or
+/// [`DynamicPluginLoadError::EngineVersion`] if it was compiled against a different engine,
+/// instead of causing undefined behavior.
+///
+/// # Safety
+///
+/// The `_bevy_stable_plugin_*` symbols must not be manually created, but instead created by
+/// `export_stable_plugin!`. The plugin must not panic in
+/// [`StablePlugin::build`](bevy_app::StablePlugin::build), as unwinding out of an `extern "C"`
+/// function is undefined behavior.
+pub unsafe fn dynamically_load_stable_plugin<P: AsRef<OsStr>>(
+    path: P,
+    app: &mut App,
+) -> Result<(Library, String), DynamicPluginLoadError> {
+    let lib = Library::new(path).map_err(DynamicPluginLoadError::Library)?;
+    let abi_version_fn: Symbol<StablePluginAbiVersionFn> = lib
+        .get(b"_bevy_stable_plugin_abi_version")
+        .map_err(DynamicPluginLoadError::Plugin)?;
+    let abi_hash_fn: Symbol<StablePluginAbiHashFn> = lib
+        .get(b"_bevy_stable_plugin_abi_hash")
+        .map_err(DynamicPluginLoadError::Plugin)?;
+    let info_fn: Symbol<StablePluginInfoFn> = lib
+        .get(b"_bevy_stable_plugin_info")
+        .map_err(DynamicPluginLoadError::Plugin)?;
+    let build_fn: Symbol<StablePluginBuildFn> = lib
+        .get(b"_bevy_stable_plugin_build")
+        .map_err(DynamicPluginLoadError::Plugin)?;
+
+    // the signatures of these two functions never change, nothing else is called before both match
+    let abi_version = abi_version_fn();
+    if abi_version != PLUGIN_ABI_VERSION {
+        return Err(DynamicPluginLoadError::AbiVersion {
+            expected: PLUGIN_ABI_VERSION,
+            found: abi_version,
+        });
+    }
+    let abi_hash = abi_hash_fn();
+    if abi_hash != ABI_HASH {
+        return Err(DynamicPluginLoadError::AbiHash {
+            expected: ABI_HASH,
+            found: abi_hash,
+        });
+    }
+
+    let mut info = MaybeUninit::uninit();
+    info_fn(info.as_mut_ptr());
+    let info = info.assume_init();
+    let engine_version = info.engine_version.as_str();
+    if engine_version != ENGINE_VERSION {
+        return Err(DynamicPluginLoadError::EngineVersion {
+            expected: ENGINE_VERSION.to_string(),
+            found: engine_version.to_string(),
+        });
+    }
+    let name = info.name.as_str().to_string();
+
+    let vtable = app_vtable(app);
+    build_fn(&vtable);
+    Ok((lib, name))
+}
+
+/// Creates the table used by stable plugins to interact with `app`, which must outlive all uses of
+/// the table.
+fn app_vtable(app: &mut App) -> AppVTable {
+    AppVTable {
+        abi_version: PLUGIN_ABI_VERSION,
+        app: app as *mut App as *mut c_void,
+        is_type_registered,
+        insert_resource,
+        log,
+        register_component,
+    }
+}
+
+unsafe extern "C" fn is_type_registered(app: *mut c_void, type_name: AbiStr) -> bool {
+    let app = &*(app as *const App);
+    let type_name = type_name.as_str();
+    catch_unwind(AssertUnwindSafe(|| {
+        app.world
+            .get_resource::<AppTypeRegistry>()
+            .map_or(false, |registry| {
+                registry.read().get_with_name(type_name).is_some()
+            })
+    }))
+    .unwrap_or(false)
+}
+
+unsafe extern "C" fn insert_resource(app: *mut c_void, type_name: AbiStr, value: AbiStr) -> bool {
+    let app = &mut *(app as *mut App);
+    let (type_name, value) = (type_name.as_str(), value.as_str());
+    catch_unwind(AssertUnwindSafe(|| {
+        let Some(registry) = app.world.get_resource::<AppTypeRegistry>().cloned() else {
+            return false;
+        };
+        let registry = registry.read();
+        let Some(registration) = registry.get_with_name(type_name) else {
+            return false;
+        };
+        let Some(reflect_resource) = registration.data::<ReflectResource>() else {
+            return false;
+        };
+        let Ok(mut deserializer) = ron::de::Deserializer::from_str(value) else {
+            return false;
+        };
+        let Ok(resource) =
+            TypedReflectDeserializer::new(registration, &registry).deserialize(&mut deserializer)
+        else {
+            return false;
+        };
+        reflect_resource.insert(&mut app.world, &*resource);
+        true
+    }))
+    .unwrap_or(false)
+}
+
+unsafe extern "C" fn log(_app: *mut c_void, level: u8, message: AbiStr) {
+    let message = message.as_str();
+    match StableLogLevel::from_u8(level) {
+        Some(StableLogLevel::Error) => error!("{message}"),
+        Some(StableLogLevel::Warn) => warn!("{message}"),
+        Some(StableLogLevel::Info) => info!("{message}"),
+        Some(StableLogLevel::Debug) => debug!("{message}"),
+        None => warn!("stable plugin logged with invalid level {level}: {message}"),
+    }
+}
+
+unsafe extern "C" fn register_component(
+    app: *mut c_void,
+    type_name: AbiStr,
+    fields: *const AbiField,
+    field_count: usize,
+) -> bool {
+    let app = &mut *(app as *mut App);
+    let mut descriptor = DynamicTypeDescriptor::new(type_name.as_str());
+    if field_count > 0 {
+        for field in std::slice::from_raw_parts(fields, field_count) {
+            descriptor = descriptor.with_field(field.name.as_str(), field.type_name.as_str());
+        }
+    }
+    catch_unwind(AssertUnwindSafe(|| {
+        app.register_dynamic_component(&descriptor).is_ok()
+    }))
+    .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{app_vtable, log};
+    use bevy_app::{AbiStr, App, AppTypeRegistry, StableApp, StableLogLevel};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+    use bevy_utils::tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        subscriber::{self, Subscriber},
+        Event, Level, Metadata,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Difficulty {
+        enemy_health: f32,
+    }
+
+    #[test]
+    fn stable_app_inserts_registered_resources() {
+        let mut app = App::new();
+        app.register_type::<Difficulty>();
+        let type_name = std::any::type_name::<Difficulty>();
+
+        let vtable = app_vtable(&mut app);
+        // SAFETY: the table was created by the host for `app`, which outlives it
+        let stable_app = unsafe { StableApp::from_vtable(&vtable) };
+        assert!(stable_app.is_type_registered(type_name));
+        assert!(!stable_app.is_type_registered("my_game::Unknown"));
+        assert!(stable_app.insert_resource(type_name, "(enemy_health: 2.0)"));
+        assert!(!stable_app.insert_resource(type_name, "(enemy_health: \"high\")"));
+        assert!(!stable_app.insert_resource("my_game::Unknown", "()"));
+
+        assert_eq!(
+            app.world.resource::<Difficulty>(),
+            &Difficulty { enemy_health: 2.0 }
+        );
+    }
+
+    #[test]
+    fn stable_app_registers_components() {
+        let mut app = App::new();
+        let vtable = app_vtable(&mut app);
+        // SAFETY: the table was created by the host for `app`, which outlives it
+        let stable_app = unsafe { StableApp::from_vtable(&vtable) };
+        assert!(stable_app.register_component("my_mod::Elite", &[("health_factor", "f32")]));
+        assert!(stable_app.is_type_registered("my_mod::Elite"));
+        assert!(!stable_app.register_component("my_mod::Elite", &[]));
+        assert!(!stable_app.register_component("my_mod::Broken", &[("value", "my_mod::Unknown")]));
+        assert!(!stable_app.is_type_registered("my_mod::Broken"));
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let registration = registry.get_with_name("my_mod::Elite").unwrap();
+        assert!(registration
+            .data::<bevy_ecs::reflect::ReflectComponent>()
+            .is_some());
+    }
+
+    /// Records the level and message of every event.
+    #[derive(Default, Clone)]
+    struct RecordingSubscriber(Arc<Mutex<Vec<(Level, String)>>>);
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event) {
+            struct MessageVisitor(String);
+
+            impl Visit for MessageVisitor {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0 = format!("{value:?}");
+                    }
+                }
+            }
+
+            let mut visitor = MessageVisitor(String::new());
+            event.record(&mut visitor);
+            self.0
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), visitor.0));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn invalid_log_levels_are_logged_as_warnings() {
+        let mut app = App::new();
+        let subscriber = RecordingSubscriber::default();
+        subscriber::with_default(subscriber.clone(), || {
+            // SAFETY: the levels are validated by the host, and the messages are valid strings
+            unsafe {
+                log(
+                    &mut app as *mut App as *mut _,
+                    StableLogLevel::Error as u8,
+                    AbiStr::new("valid"),
+                );
+                log(
+                    &mut app as *mut App as *mut _,
+                    u8::MAX,
+                    AbiStr::new("invalid"),
+                );
+            }
+        });
+
+        assert_eq!(
+            *subscriber.0.lock().unwrap(),
+            vec![
+                (Level::ERROR, "valid".to_string()),
+                (
+                    Level::WARN,
+                    "stable plugin logged with invalid level 255: invalid".to_string()
+                ),
+            ]
+        );
+    }
+}
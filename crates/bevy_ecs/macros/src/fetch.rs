@@ -5,7 +5,8 @@ use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
     punctuated::Punctuated,
-    Attribute, Data, DataStruct, DeriveInput, Field, Fields,
+    Attribute, Data, DataStruct, DeriveInput, Expr, Field, Fields, GenericArgument, PathArguments,
+    Type,
 };
 
 use crate::bevy_ecs_path;
@@ -21,6 +22,7 @@ static DERIVE_ATTRIBUTE_NAME: &str = "derive";
 
 mod field_attr_keywords {
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(default);
 }
 
 pub static WORLD_QUERY_ATTRIBUTE_NAME: &str = "world_query";
@@ -88,6 +90,19 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
     };
     let (user_impl_generics_with_world, user_ty_generics_with_world, user_where_clauses_with_world) =
         user_generics_with_world.split_for_impl();
+    let user_generics_with_borrow = {
+        let mut generics = ast.generics.clone();
+        generics.params.insert(0, parse_quote!('__a));
+        generics
+    };
+    let (_, user_ty_generics_with_borrow, _) = user_generics_with_borrow.split_for_impl();
+    let user_generics_with_borrow_and_world = {
+        let mut generics = user_generics_with_world.clone();
+        generics.params.insert(0, parse_quote!('__a));
+        generics
+    };
+    let (user_impl_generics_with_borrow_and_world, _, _) =
+        user_generics_with_borrow_and_world.split_for_impl();
 
     let struct_name = ast.ident.clone();
     let read_only_struct_name = if fetch_struct_attributes.is_mutable {
@@ -129,11 +144,42 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
     let mut field_idents = Vec::new();
     let mut field_types = Vec::new();
     let mut read_only_field_types = Vec::new();
+    let mut default_field_visibilities = Vec::new();
+    let mut default_field_idents = Vec::new();
+    let mut default_field_methods = Vec::new();
+    let mut default_field_types = Vec::new();
+    let mut default_field_values = Vec::new();
 
     for field in fields {
-        let WorldQueryFieldInfo { is_ignored, attrs } = read_world_query_field_info(field);
+        let WorldQueryFieldInfo {
+            is_ignored,
+            default,
+            attrs,
+        } = read_world_query_field_info(field);
 
         let field_ident = field.ident.as_ref().unwrap().clone();
+        if let Some(default) = default {
+            if is_ignored {
+                panic!("The `default` attribute can't be used on ignored fields");
+            }
+            let component_ty = optional_component_type(&field.ty).unwrap_or_else(|| {
+                panic!(
+                    "The `default` attribute is expected on fields of type `Option<&T>` or `Option<&mut T>`, found `{}`",
+                    field.ty.to_token_stream()
+                )
+            });
+            default_field_visibilities.push(field.vis.clone());
+            default_field_idents.push(field_ident.clone());
+            default_field_methods.push(Ident::new(
+                &format!("{field_ident}_or_default"),
+                Span::call_site(),
+            ));
+            default_field_types.push(component_ty);
+            default_field_values.push(default.map_or_else(
+                || quote!(::core::default::Default::default()),
+                |expr| quote!(#expr),
+            ));
+        }
         if is_ignored {
             ignored_field_attrs.push(attrs);
             ignored_field_visibilities.push(field.vis.clone());
@@ -188,6 +234,28 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
             }
         };
 
+        let default_methods = if default_field_idents.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                impl #user_impl_generics_with_world #item_struct_name #user_ty_generics_with_world #user_where_clauses_with_world {
+                    #(
+                        #[doc = "Returns a copy of `"]
+                        #[doc = stringify!(#default_field_idents)]
+                        #[doc = "`, or its default value if the entity doesn't have it."]
+                        #default_field_visibilities fn #default_field_methods(&self) -> #default_field_types
+                        where
+                            #default_field_types: ::core::clone::Clone,
+                        {
+                            ::core::option::Option::as_deref(&self.#default_field_idents)
+                                .cloned()
+                                .unwrap_or_else(|| #default_field_values)
+                        }
+                    )*
+                }
+            }
+        };
+
         let query_impl = quote! {
             #[doc(hidden)]
             #[doc = "Automatically generated internal [`WorldQuery`] fetch type for [`"]
@@ -327,10 +395,104 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
                 }
             }
         };
-        (item_struct, query_impl)
+        (quote! { #item_struct #default_methods }, query_impl)
     };
 
     let (mutable_struct, mutable_impl) = impl_fetch(false);
+
+    // The bounds are higher-ranked, so that they aren't trivially false if a field doesn't
+    // implement `IntoReadOnlyItem`. The conversions are then just unavailable.
+    let mut into_read_only_where_clause = user_where_clauses
+        .cloned()
+        .unwrap_or_else(|| parse_quote!(where));
+    for field_type in &field_types {
+        into_read_only_where_clause
+            .predicates
+            .push(parse_quote!(for<'__x> #field_type: #path::query::IntoReadOnlyItem));
+        if !fetch_struct_attributes.is_mutable {
+            into_read_only_where_clause
+                .predicates
+                .push(parse_quote!(for<'__x> #field_type: #path::query::ReadOnlyWorldQuery));
+        }
+    }
+    let into_read_only_body = if fetch_struct_attributes.is_mutable {
+        quote! {
+            #read_only_item_struct_name {
+                #(
+                    #field_idents: <#field_types as #path::query::IntoReadOnlyItem>::into_readonly(item.#field_idents),
+                )*
+                #(
+                    #ignored_field_idents: item.#ignored_field_idents,
+                )*
+            }
+        }
+    } else {
+        quote! { item }
+    };
+    let into_read_only_impl = quote! {
+        impl #user_impl_generics #path::query::IntoReadOnlyItem
+            for #struct_name #user_ty_generics #into_read_only_where_clause {
+            fn into_readonly<'__w>(
+                item: <Self as #path::query::WorldQuery>::Item<'__w>
+            ) -> #path::query::ROQueryItem<'__w, Self> {
+                #into_read_only_body
+            }
+
+            fn as_readonly<'__a, '__w>(
+                item: &'__a <Self as #path::query::WorldQuery>::Item<'__w>
+            ) -> #path::query::ROQueryItem<'__a, Self> {
+                #read_only_item_struct_name {
+                    #(
+                        #field_idents: <#field_types as #path::query::IntoReadOnlyItem>::as_readonly(&item.#field_idents),
+                    )*
+                    #(
+                        #ignored_field_idents: ::core::default::Default::default(),
+                    )*
+                }
+            }
+        }
+    };
+
+    // The conversions are bounded by the fields like the `IntoReadOnlyItem` impl they forward to.
+    // A bound on the struct itself would keep the compiler from normalizing its item type to the
+    // item struct if the struct is generic.
+    let read_only_conversions = if fetch_struct_attributes.is_mutable {
+        let conversion_where_clause = &into_read_only_where_clause;
+        quote! {
+            impl #user_impl_generics_with_world #item_struct_name #user_ty_generics_with_world
+                #conversion_where_clause {
+                #[doc = "Converts this item into a [`"]
+                #[doc = stringify!(#read_only_item_struct_name)]
+                #[doc = "`]."]
+                #visibility fn into_readonly(self) -> #read_only_item_struct_name #user_ty_generics_with_world {
+                    <#struct_name #user_ty_generics as #path::query::IntoReadOnlyItem>::into_readonly(self)
+                }
+
+                #[doc = "Borrows this item as a [`"]
+                #[doc = stringify!(#read_only_item_struct_name)]
+                #[doc = "`]."]
+                #visibility fn as_readonly<'__a>(&'__a self) -> #read_only_item_struct_name #user_ty_generics_with_borrow {
+                    <#struct_name #user_ty_generics as #path::query::IntoReadOnlyItem>::as_readonly(self)
+                }
+            }
+
+            impl #user_impl_generics_with_world ::core::convert::From<#item_struct_name #user_ty_generics_with_world>
+                for #read_only_item_struct_name #user_ty_generics_with_world #conversion_where_clause {
+                fn from(item: #item_struct_name #user_ty_generics_with_world) -> Self {
+                    item.into_readonly()
+                }
+            }
+
+            impl #user_impl_generics_with_borrow_and_world ::core::convert::From<&'__a #item_struct_name #user_ty_generics_with_world>
+                for #read_only_item_struct_name #user_ty_generics_with_borrow #conversion_where_clause {
+                fn from(item: &'__a #item_struct_name #user_ty_generics_with_world) -> Self {
+                    item.as_readonly()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
     let (read_only_struct, read_only_impl) = if fetch_struct_attributes.is_mutable {
         let (readonly_state, read_only_impl) = impl_fetch(true);
         let read_only_structs = quote! {
@@ -393,8 +555,12 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
             #mutable_impl
 
             #read_only_impl
+
+            #into_read_only_impl
         };
 
+        #read_only_conversions
+
         #[allow(dead_code)]
         const _: () = {
             fn assert_readonly<T>()
@@ -430,34 +596,40 @@ pub fn derive_world_query_impl(ast: DeriveInput) -> TokenStream {
 struct WorldQueryFieldInfo {
     /// Has `#[fetch(ignore)]` or `#[filter_fetch(ignore)]` attribute.
     is_ignored: bool,
+    /// Has a `#[world_query(default)]` or `#[world_query(default = expr)]` attribute.
+    default: Option<Option<Expr>>,
     /// All field attributes except for `world_query` ones.
     attrs: Vec<Attribute>,
 }
 
 fn read_world_query_field_info(field: &Field) -> WorldQueryFieldInfo {
-    let is_ignored = field
-        .attrs
-        .iter()
-        .find(|attr| {
-            attr.path
-                .get_ident()
-                .map_or(false, |ident| ident == WORLD_QUERY_ATTRIBUTE_NAME)
+    let mut is_ignored = false;
+    let mut default = None;
+    if let Some(attr) = field.attrs.iter().find(|attr| {
+        attr.path
+            .get_ident()
+            .map_or(false, |ident| ident == WORLD_QUERY_ATTRIBUTE_NAME)
+    }) {
+        attr.parse_args_with(|input: ParseStream| {
+            if input
+                .parse::<Option<field_attr_keywords::ignore>>()?
+                .is_some()
+            {
+                is_ignored = true;
+            } else if input
+                .parse::<Option<field_attr_keywords::default>>()?
+                .is_some()
+            {
+                default = Some(if input.parse::<Option<syn::token::Eq>>()?.is_some() {
+                    Some(input.parse::<Expr>()?)
+                } else {
+                    None
+                });
+            }
+            Ok(())
         })
-        .map_or(false, |attr| {
-            let mut is_ignored = false;
-            attr.parse_args_with(|input: ParseStream| {
-                if input
-                    .parse::<Option<field_attr_keywords::ignore>>()?
-                    .is_some()
-                {
-                    is_ignored = true;
-                }
-                Ok(())
-            })
-            .unwrap_or_else(|_| panic!("Invalid `{WORLD_QUERY_ATTRIBUTE_NAME}` attribute format"));
-
-            is_ignored
-        });
+        .unwrap_or_else(|_| panic!("Invalid `{WORLD_QUERY_ATTRIBUTE_NAME}` attribute format"));
+    }
 
     let attrs = field
         .attrs
@@ -470,5 +642,27 @@ fn read_world_query_field_info(field: &Field) -> WorldQueryFieldInfo {
         .cloned()
         .collect();
 
-    WorldQueryFieldInfo { is_ignored, attrs }
+    WorldQueryFieldInfo {
+        is_ignored,
+        default,
+        attrs,
+    }
+}
+
+/// Returns `T` if `ty` is `Option<&T>` or `Option<&mut T>`.
+fn optional_component_type(ty: &Type) -> Option<Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(Type::Reference(reference)) => Some((*reference.elem).clone()),
+        _ => None,
+    }
 }
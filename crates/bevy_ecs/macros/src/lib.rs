@@ -469,6 +469,13 @@ pub fn derive_schedule_label(input: TokenStream) -> TokenStream {
 }
 
 /// Derive macro generating an impl of the trait `SystemSet`.
+///
+/// Sets are compared and hashed with the `Eq` and `Hash` impls of the type, so enum variants
+/// and structs with fields define one distinct set per value, e.g. `PhysicsStep(0)` and
+/// `PhysicsStep(1)` are different sets. This allows families of parameterized sets without
+/// declaring a unit variant for each of them.
+///
+/// Add `#[system_set(base)]` to the type to derive `BaseSystemSet` instead of `FreeSystemSet`.
 #[proc_macro_derive(SystemSet, attributes(system_set))]
 pub fn derive_system_set(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
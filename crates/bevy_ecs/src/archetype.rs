@@ -21,9 +21,10 @@
 
 use crate::{
     bundle::BundleId,
-    component::{ComponentId, StorageType},
+    component::{ComponentId, Components, StorageType},
     entity::{Entity, EntityLocation},
     storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
+    world::UntypedArchetypeInvariant,
 };
 use std::{
     hash::Hash,
@@ -597,6 +598,7 @@ pub struct Archetypes {
     pub(crate) archetypes: Vec<Archetype>,
     pub(crate) archetype_component_count: usize,
     archetype_ids: bevy_utils::HashMap<ArchetypeIdentity, ArchetypeId>,
+    pub(crate) invariants: Vec<UntypedArchetypeInvariant>,
 }
 
 impl Archetypes {
@@ -605,8 +607,14 @@ impl Archetypes {
             archetypes: Vec::new(),
             archetype_ids: Default::default(),
             archetype_component_count: 0,
+            invariants: Vec::new(),
         };
-        archetypes.get_id_or_insert(TableId::empty(), Vec::new(), Vec::new());
+        archetypes.get_id_or_insert(
+            &Components::default(),
+            TableId::empty(),
+            Vec::new(),
+            Vec::new(),
+        );
         archetypes
     }
 
@@ -672,13 +680,26 @@ impl Archetypes {
         self.archetypes.iter()
     }
 
+    /// Returns the [`ArchetypeInvariant`]s registered with
+    /// [`World::register_archetype_invariant`].
+    ///
+    /// [`ArchetypeInvariant`]: crate::world::ArchetypeInvariant
+    /// [`World::register_archetype_invariant`]: crate::world::World::register_archetype_invariant
+    #[inline]
+    pub fn invariants(&self) -> &[UntypedArchetypeInvariant] {
+        &self.invariants
+    }
+
     /// Gets the archetype id matching the given inputs or inserts a new one if it doesn't exist.
     /// `table_components` and `sparse_set_components` must be sorted
     ///
+    /// In debug builds, new archetypes are checked against the registered archetype invariants.
+    ///
     /// # Safety
     /// [`TableId`] must exist in tables
     pub(crate) fn get_id_or_insert(
         &mut self,
+        components: &Components,
         table_id: TableId,
         table_components: Vec<ComponentId>,
         sparse_set_components: Vec<ComponentId>,
@@ -690,6 +711,7 @@ impl Archetypes {
 
         let archetypes = &mut self.archetypes;
         let archetype_component_count = &mut self.archetype_component_count;
+        let invariants = &self.invariants;
         *self
             .archetype_ids
             .entry(archetype_identity)
@@ -703,14 +725,19 @@ impl Archetypes {
                 *archetype_component_count += sparse_set_components.len();
                 let sparse_set_archetype_components =
                     (sparse_start..*archetype_component_count).map(ArchetypeComponentId);
-                archetypes.push(Archetype::new(
+                let archetype = Archetype::new(
                     id,
                     table_id,
                     table_components.into_iter().zip(table_archetype_components),
                     sparse_set_components
                         .into_iter()
                         .zip(sparse_set_archetype_components),
-                ));
+                );
+                #[cfg(debug_assertions)]
+                for invariant in invariants {
+                    invariant.validate(&archetype, components);
+                }
+                archetypes.push(archetype);
                 id
             })
     }
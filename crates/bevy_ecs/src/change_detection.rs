@@ -23,6 +23,52 @@ pub const CHECK_TICK_THRESHOLD: u32 = 518_400_000;
 /// Changes stop being detected once they become this old.
 pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
 
+/// Configures how [`World::check_change_ticks`](crate::world::World::check_change_ticks) scans
+/// the change ticks of a [`World`](crate::world::World).
+///
+/// By default, all change ticks are scanned at once every [`CHECK_TICK_THRESHOLD`] world tick
+/// increments. For worlds with a lot of entities, this can cause a noticeable stall, so the scan
+/// can instead be spread across multiple calls by setting a `batch_size`.
+///
+/// A scan has to finish within `CHECK_TICK_THRESHOLD - interval` ticks after it started, so that
+/// no change can get older than [`MAX_CHANGE_AGE`]. Once that time is up, the rest of the scan
+/// is done at once, so the `interval` should be lowered to leave room for incremental scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeTickScanSettings {
+    /// The minimum number of world tick increments between the start of two scans.
+    ///
+    /// Must not be greater than [`CHECK_TICK_THRESHOLD`].
+    pub interval: u32,
+    /// The number of component values to check per call, or `None` to check all of them at once.
+    ///
+    /// At least one table or sparse set is checked per call, regardless of its size.
+    /// Resources and system ticks are always checked together in the last call of a scan.
+    pub batch_size: Option<usize>,
+}
+
+impl ChangeTickScanSettings {
+    /// Settings that spread each scan across multiple calls, checking about `batch_size`
+    /// component values per call.
+    ///
+    /// Scans start twice as often as by default, leaving them half of the
+    /// [`CHECK_TICK_THRESHOLD`] to finish.
+    pub const fn incremental(batch_size: usize) -> Self {
+        Self {
+            interval: CHECK_TICK_THRESHOLD / 2,
+            batch_size: Some(batch_size),
+        }
+    }
+}
+
+impl Default for ChangeTickScanSettings {
+    fn default() -> Self {
+        Self {
+            interval: CHECK_TICK_THRESHOLD,
+            batch_size: None,
+        }
+    }
+}
+
 /// Types that can read change detection information.
 /// This change detection is controlled by [`DetectChangesMut`] types such as [`ResMut`].
 ///
@@ -727,7 +773,8 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         change_detection::{
-            Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
+            ChangeTickScanSettings, Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD,
+            MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
         system::{IntoSystem, Query, System},
@@ -833,6 +880,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn incremental_change_tick_scan() {
+        #[derive(Component)]
+        struct D;
+
+        #[derive(Component)]
+        struct E;
+
+        fn all_clamped(world: &mut World) -> bool {
+            let change_tick = world.change_tick();
+            world.query::<Ref<C>>().iter(world).all(|tracker| {
+                change_tick.relative_to(*tracker.ticks.added).get() <= MAX_CHANGE_AGE
+                    && change_tick.relative_to(*tracker.ticks.changed).get() <= MAX_CHANGE_AGE
+            })
+        }
+
+        let mut world = World::new();
+        world.set_change_tick_scan_settings(ChangeTickScanSettings::incremental(1));
+        world.spawn(C);
+        let entity_d = world.spawn((C, D)).id();
+        let entity_e = world.spawn((C, E)).id();
+
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+
+        // only the tables up to the one storing `C` are checked
+        world.check_change_ticks();
+        assert!(!all_clamped(&mut world));
+        let change_tick = world.change_tick();
+        let ticks = world.entity(entity_d).get_change_ticks::<C>().unwrap();
+        assert!(change_tick.relative_to(ticks.added).get() > MAX_CHANGE_AGE);
+
+        // moving an entity into an already checked table doesn't let it escape the scan
+        world.entity_mut(entity_e).remove::<E>();
+        while world.change_tick_scan_in_progress {
+            world.check_change_ticks();
+        }
+        assert!(all_clamped(&mut world));
+
+        // a scan that takes too long is finished at once
+        let change_tick = world.change_tick.get_mut();
+        *change_tick = change_tick.wrapping_add(MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD);
+        world.check_change_ticks();
+        assert!(world.change_tick_scan_in_progress);
+        let change_tick = world.change_tick.get_mut();
+        *change_tick = change_tick.wrapping_add(CHECK_TICK_THRESHOLD / 2);
+        world.check_change_ticks();
+        assert!(!world.change_tick_scan_in_progress);
+        assert!(all_clamped(&mut world));
+    }
+
     #[test]
     fn mut_from_res_mut() {
         let mut component_ticks = ComponentTicks {
@@ -0,0 +1,396 @@
+use crate::{
+    self as bevy_ecs,
+    component::Component,
+    entity::Entity,
+    system::{Command, Resource},
+    world::World,
+};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// The entities whose despawn was deferred by a [`StrongEntity`].
+#[derive(Resource, Default)]
+pub(crate) struct DeferredDespawns(pub(crate) Vec<Entity>);
+
+/// Set in [`HandleShared::state`] once the despawn of the entity was requested.
+const DESPAWN_REQUESTED: usize = 1 << (usize::BITS - 1);
+/// Set in [`HandleShared::state`] once the entity was despawned.
+const DESPAWNED: usize = 1 << (usize::BITS - 2);
+/// The bits of [`HandleShared::state`] counting the strong handles.
+const STRONG_COUNT: usize = DESPAWNED - 1;
+
+/// The state shared between an entity and some of its [`StrongEntity`] and [`WeakEntity`] handles.
+///
+/// The strong handle count and the despawn flags live in a single atomic, so that a strong handle
+/// can't be created after the entity was found to be free to despawn.
+struct HandleShared {
+    state: AtomicUsize,
+}
+
+impl HandleShared {
+    fn new(strong: usize) -> Arc<Self> {
+        Arc::new(Self {
+            state: AtomicUsize::new(strong),
+        })
+    }
+
+    /// Adds a strong handle, unless the despawn of the entity was requested.
+    fn try_acquire(&self) -> bool {
+        self.state
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |state| {
+                (state & (DESPAWN_REQUESTED | DESPAWNED) == 0).then_some(state + 1)
+            })
+            .is_ok()
+    }
+
+    fn strong_count(&self) -> usize {
+        self.state.load(Ordering::SeqCst) & STRONG_COUNT
+    }
+}
+
+/// The component linking an entity to the state of its handles.
+///
+/// It is inserted when the first handle to the entity is created, and marks the handles as dead
+/// once it is dropped, i.e. when the entity is despawned. Handles created through
+/// [`EntityCommands`](crate::system::EntityCommands) bring their own state, which is attached
+/// when the command is applied.
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub(crate) struct EntityHandles {
+    shared: Vec<Arc<HandleShared>>,
+}
+
+impl EntityHandles {
+    pub(crate) fn new() -> Self {
+        Self {
+            shared: vec![HandleShared::new(0)],
+        }
+    }
+
+    /// Creates a new strong handle, or returns `None` if a despawn was already requested.
+    pub(crate) fn strong(&self, entity: Entity) -> Option<StrongEntity> {
+        let shared = &self.shared[0];
+        shared.try_acquire().then(|| StrongEntity {
+            entity,
+            shared: shared.clone(),
+        })
+    }
+
+    pub(crate) fn weak(&self, entity: Entity) -> WeakEntity {
+        WeakEntity {
+            entity,
+            shared: self.shared[0].clone(),
+        }
+    }
+
+    fn attach(&mut self, shared: Arc<HandleShared>) {
+        if self.shared[0].state.load(Ordering::SeqCst) & DESPAWN_REQUESTED != 0 {
+            shared.state.fetch_or(DESPAWN_REQUESTED, Ordering::SeqCst);
+        }
+        self.shared.push(shared);
+    }
+
+    /// Requests the despawn of the entity, returning `true` if it can be despawned right away.
+    ///
+    /// Otherwise it can be despawned once [`EntityHandles::is_released`] returns `true`.
+    pub(crate) fn request_despawn(&self) -> bool {
+        self.shared.iter().fold(true, |released, shared| {
+            let state = shared.state.fetch_or(DESPAWN_REQUESTED, Ordering::SeqCst);
+            released && state & STRONG_COUNT == 0
+        })
+    }
+
+    /// Returns `true` if no strong handles to the entity are left.
+    pub(crate) fn is_released(&self) -> bool {
+        self.shared.iter().all(|shared| shared.strong_count() == 0)
+    }
+}
+
+impl Drop for EntityHandles {
+    fn drop(&mut self) {
+        for shared in &self.shared {
+            shared.state.fetch_or(DESPAWNED, Ordering::SeqCst);
+        }
+    }
+}
+
+/// A [`Command`] that attaches handles created by
+/// [`EntityCommands::strong_entity`](crate::system::EntityCommands::strong_entity) or
+/// [`EntityCommands::weak_entity`](crate::system::EntityCommands::weak_entity) to their entity.
+pub(crate) struct AttachEntityHandles {
+    entity: Entity,
+    shared: Arc<HandleShared>,
+}
+
+impl Command for AttachEntityHandles {
+    fn write(self, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(self.entity) else {
+            self.shared
+                .state
+                .fetch_or(DESPAWN_REQUESTED | DESPAWNED, Ordering::SeqCst);
+            return;
+        };
+        if let Some(mut handles) = entity.get_mut::<EntityHandles>() {
+            handles.attach(self.shared);
+        } else {
+            entity.insert(EntityHandles {
+                shared: vec![self.shared],
+            });
+        }
+    }
+}
+
+/// Creates a [`StrongEntity`] to the `entity` and the [`Command`] attaching it.
+pub(crate) fn deferred_strong(entity: Entity) -> (StrongEntity, AttachEntityHandles) {
+    let shared = HandleShared::new(1);
+    let handle = StrongEntity {
+        entity,
+        shared: shared.clone(),
+    };
+    (handle, AttachEntityHandles { entity, shared })
+}
+
+/// Creates a [`WeakEntity`] to the `entity` and the [`Command`] attaching it.
+pub(crate) fn deferred_weak(entity: Entity) -> (WeakEntity, AttachEntityHandles) {
+    let shared = HandleShared::new(0);
+    let handle = WeakEntity {
+        entity,
+        shared: shared.clone(),
+    };
+    (handle, AttachEntityHandles { entity, shared })
+}
+
+/// The result of [`World::despawn`] and [`EntityMut::despawn`](crate::world::EntityMut::despawn).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DespawnResult {
+    /// The entity was despawned.
+    Despawned,
+    /// The entity was only marked for despawning, because a [`StrongEntity`] handle to it exists.
+    ///
+    /// It is despawned by [`World::despawn_released_entities`] once all strong handles are dropped.
+    Deferred,
+    /// The entity doesn't exist.
+    NotFound,
+}
+
+/// A handle to an [`Entity`] that keeps it from being despawned.
+///
+/// While any strong handle to an entity exists, [`World::despawn`](crate::world::World::despawn)
+/// only marks the entity for despawning. The entity is actually despawned by
+/// [`World::despawn_released_entities`](crate::world::World::despawn_released_entities) once the
+/// last strong handle is dropped.
+///
+/// Strong handles are created with [`World::strong_entity`](crate::world::World::strong_entity) or
+/// [`EntityCommands::strong_entity`](crate::system::EntityCommands::strong_entity), and can be
+/// [downgraded](Self::downgrade) to a [`WeakEntity`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// let mut world = World::new();
+/// let entity = world.spawn_empty().id();
+/// let handle = world.strong_entity(entity).unwrap();
+///
+/// world.despawn(entity);
+/// world.despawn_released_entities();
+/// assert!(world.get_entity(entity).is_some());
+///
+/// drop(handle);
+/// world.despawn_released_entities();
+/// assert!(world.get_entity(entity).is_none());
+/// ```
+pub struct StrongEntity {
+    entity: Entity,
+    shared: Arc<HandleShared>,
+}
+
+impl StrongEntity {
+    /// Returns the entity this handle points to.
+    #[inline]
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Creates a [`WeakEntity`] pointing to the same entity.
+    pub fn downgrade(&self) -> WeakEntity {
+        WeakEntity {
+            entity: self.entity,
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Clone for StrongEntity {
+    fn clone(&self) -> Self {
+        self.shared.state.fetch_add(1, Ordering::SeqCst);
+        Self {
+            entity: self.entity,
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for StrongEntity {
+    fn drop(&mut self) {
+        self.shared.state.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl PartialEq for StrongEntity {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity
+    }
+}
+
+impl Eq for StrongEntity {}
+
+impl fmt::Debug for StrongEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StrongEntity").field(&self.entity).finish()
+    }
+}
+
+/// A handle to an [`Entity`] that doesn't keep it from being despawned, but can cheaply check
+/// whether it is still alive.
+///
+/// Weak handles are created with [`World::weak_entity`](crate::world::World::weak_entity),
+/// [`EntityCommands::weak_entity`](crate::system::EntityCommands::weak_entity) or
+/// [`StrongEntity::downgrade`].
+#[derive(Clone)]
+pub struct WeakEntity {
+    entity: Entity,
+    shared: Arc<HandleShared>,
+}
+
+impl WeakEntity {
+    /// Returns the entity this handle points to.
+    #[inline]
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns `true` if the entity hasn't been despawned yet.
+    ///
+    /// An entity whose despawn is deferred by a [`StrongEntity`] is still alive.
+    pub fn is_alive(&self) -> bool {
+        self.shared.state.load(Ordering::SeqCst) & DESPAWNED == 0
+    }
+
+    /// Creates a [`StrongEntity`] pointing to the same entity.
+    ///
+    /// Returns `None` if the entity was despawned or its despawn was requested.
+    pub fn upgrade(&self) -> Option<StrongEntity> {
+        self.shared.try_acquire().then(|| StrongEntity {
+            entity: self.entity,
+            shared: self.shared.clone(),
+        })
+    }
+}
+
+impl PartialEq for WeakEntity {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity
+    }
+}
+
+impl Eq for WeakEntity {}
+
+impl fmt::Debug for WeakEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WeakEntity").field(&self.entity).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DespawnResult, EntityHandles};
+    use crate::{
+        system::{CommandQueue, Commands},
+        world::World,
+    };
+    use std::any::TypeId;
+
+    #[test]
+    fn strong_entity_defers_despawn() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let weak = world.weak_entity(entity).unwrap();
+        let strong = world.strong_entity(entity).unwrap();
+        let strong2 = weak.upgrade().unwrap();
+        assert_eq!(strong, strong2);
+        assert_eq!(strong.downgrade(), weak);
+
+        assert_eq!(world.despawn(entity), DespawnResult::Deferred);
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_some());
+        assert!(weak.is_alive());
+        assert!(weak.upgrade().is_none());
+        assert!(world.strong_entity(entity).is_none());
+
+        drop(strong);
+        world.despawn_released_entities();
+        assert!(weak.is_alive());
+
+        drop(strong2);
+        assert!(weak.is_alive());
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_none());
+        assert!(!weak.is_alive());
+        assert!(world.weak_entity(entity).is_none());
+
+        let entity = world.spawn_empty().id();
+        let weak = world.weak_entity(entity).unwrap();
+        assert_eq!(world.despawn(entity), DespawnResult::Despawned);
+        assert!(!weak.is_alive());
+        assert_eq!(world.despawn(entity), DespawnResult::NotFound);
+    }
+
+    #[test]
+    fn entity_mut_despawn_is_deferred() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        assert!(world.components().get_id(TypeId::of::<EntityHandles>()).is_none());
+        let strong = world.strong_entity(entity).unwrap();
+
+        assert_eq!(world.entity_mut(entity).despawn(), DespawnResult::Deferred);
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_some());
+
+        drop(strong);
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_none());
+    }
+
+    #[test]
+    fn entity_handles_from_commands() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let existing = world.strong_entity(entity).unwrap();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let strong = commands.entity(entity).strong_entity();
+        let weak = commands.entity(entity).weak_entity();
+        let missing = commands.spawn_empty().id();
+        let dead = commands.entity(missing).weak_entity();
+        commands.entity(missing).despawn();
+        queue.apply(&mut world);
+        assert!(!dead.is_alive());
+        assert!(dead.upgrade().is_none());
+
+        assert_eq!(world.despawn(entity), DespawnResult::Deferred);
+        drop(existing);
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_some());
+        assert!(weak.upgrade().is_none());
+
+        drop(strong);
+        world.despawn_released_entities();
+        assert!(world.get_entity(entity).is_none());
+        assert!(!weak.is_alive());
+    }
+}
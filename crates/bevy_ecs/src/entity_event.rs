@@ -0,0 +1,351 @@
+//! Events addressed to specific entities.
+
+use crate as bevy_ecs;
+use crate::{
+    entity::{Entities, Entity},
+    event::Event,
+    system::{Local, Res, ResMut, Resource, SystemParam},
+};
+use bevy_utils::HashMap;
+
+#[derive(Debug)]
+struct EntityEventInstance<E: Event> {
+    id: usize,
+    event: E,
+}
+
+/// A collection of events of type `E` that are each addressed to a specific [`Entity`], like
+/// damage dealt to a target or an interaction with an object.
+///
+/// Unlike [`Events`](crate::event::Events), the events are stored per entity, so reading the events
+/// of one entity doesn't require filtering the events of all entities.
+/// Events are sent with an [`EntityEventWriter`] and read with an [`EntityEventReader`].
+///
+/// # Lifetime
+///
+/// Like [`Events`](crate::event::Events), the events are double buffered: events survive until
+/// the end of the update after the one they were sent in, as long as [`EntityEvents::update`] is
+/// called once per update. Events addressed to entities that have been despawned are dropped
+/// by [`EntityEvents::update_system`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity_event::{EntityEventReader, EntityEventWriter, EntityEvents};
+/// struct Damage(f32);
+///
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn deal_damage(mut damage: EntityEventWriter<Damage>, targets: Query<Entity, With<Health>>) {
+///     for target in &targets {
+///         damage.send(target, Damage(10.0));
+///     }
+/// }
+///
+/// fn apply_damage(mut damage: EntityEventReader<Damage>, mut targets: Query<(Entity, &mut Health)>) {
+///     for (target, mut health) in &mut targets {
+///         for Damage(amount) in damage.read(target) {
+///             health.0 -= amount;
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<EntityEvents<Damage>>();
+/// let target = world.spawn(Health(100.0)).id();
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_systems((EntityEvents::<Damage>::update_system, deal_damage, apply_damage).chain());
+/// schedule.run(&mut world);
+///
+/// assert_eq!(world.get::<Health>(target).unwrap().0, 90.0);
+/// ```
+#[derive(Debug, Resource)]
+pub struct EntityEvents<E: Event> {
+    events: HashMap<Entity, Vec<EntityEventInstance<E>>>,
+    event_count: usize,
+    /// The id of the oldest event that may still be stored.
+    start_event_count: usize,
+    /// The value of `event_count` when [`EntityEvents::update`] was last called.
+    last_update_event_count: usize,
+}
+
+// Derived Default impl would incorrectly require E: Default
+impl<E: Event> Default for EntityEvents<E> {
+    fn default() -> Self {
+        Self {
+            events: Default::default(),
+            event_count: 0,
+            start_event_count: 0,
+            last_update_event_count: 0,
+        }
+    }
+}
+
+impl<E: Event> EntityEvents<E> {
+    /// Sends an `event` addressed to `entity`.
+    pub fn send(&mut self, entity: Entity, event: E) {
+        self.events
+            .entry(entity)
+            .or_default()
+            .push(EntityEventInstance {
+                id: self.event_count,
+                event,
+            });
+        self.event_count += 1;
+    }
+
+    /// Sends the default value of the event to `entity`. Useful when the event is an empty struct.
+    pub fn send_default(&mut self, entity: Entity)
+    where
+        E: Default,
+    {
+        self.send(entity, Default::default());
+    }
+
+    /// Iterates over all stored events addressed to `entity`, oldest first.
+    ///
+    /// As events are stored for two updates, this returns the same events in two consecutive
+    /// updates. Use an [`EntityEventReader`] to only read each event once.
+    pub fn get(&self, entity: Entity) -> impl ExactSizeIterator<Item = &E> + '_ {
+        self.get_since(entity, 0)
+    }
+
+    fn get_since(&self, entity: Entity, id: usize) -> impl ExactSizeIterator<Item = &E> + '_ {
+        let events = self.events.get(&entity).map_or(&[][..], Vec::as_slice);
+        let start = events.partition_point(|instance| instance.id < id);
+        events[start..].iter().map(|instance| &instance.event)
+    }
+
+    /// Returns the number of stored events, addressed to any entity.
+    pub fn len(&self) -> usize {
+        self.events.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if there are no stored events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the entities that have stored events.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.events.keys().copied()
+    }
+
+    /// Removes all events addressed to `entity`.
+    pub fn remove(&mut self, entity: Entity) {
+        self.events.remove(&entity);
+    }
+
+    /// Removes all events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.start_event_count = self.event_count;
+        self.last_update_event_count = self.event_count;
+    }
+
+    /// Drops the events sent before the previous call to this method. In general, this should be
+    /// called once per frame/update.
+    pub fn update(&mut self) {
+        let start_event_count = self.last_update_event_count;
+        self.events.retain(|_, events| {
+            let start = events.partition_point(|instance| instance.id < start_event_count);
+            events.drain(..start);
+            !events.is_empty()
+        });
+        self.start_event_count = start_event_count;
+        self.last_update_event_count = self.event_count;
+    }
+
+    /// A system that calls [`EntityEvents::update`] once per frame and drops the events
+    /// addressed to despawned entities.
+    pub fn update_system(mut events: ResMut<Self>, entities: &Entities) {
+        events.update();
+        events.events.retain(|&entity, _| entities.contains(entity));
+    }
+}
+
+/// Tracks which events addressed to each entity an [`EntityEventReader`] has already read.
+#[derive(Debug, Default)]
+pub struct EntityEventCursors {
+    /// The id of the first event each entity's events haven't been read from.
+    cursors: HashMap<Entity, usize>,
+    prune_threshold: usize,
+}
+
+impl EntityEventCursors {
+    const MIN_PRUNE_THRESHOLD: usize = 64;
+
+    fn get(&self, entity: Entity) -> usize {
+        self.cursors.get(&entity).copied().unwrap_or(0)
+    }
+
+    fn set<E: Event>(&mut self, entity: Entity, events: &EntityEvents<E>) {
+        self.cursors.insert(entity, events.event_count);
+        if self.cursors.len() > self.prune_threshold {
+            // Cursors that point before the oldest stored event behave exactly like missing ones.
+            self.cursors
+                .retain(|_, cursor| *cursor > events.start_event_count);
+            self.prune_threshold = (self.cursors.len() * 2).max(Self::MIN_PRUNE_THRESHOLD);
+        }
+    }
+}
+
+/// Reads the events of type `E` addressed to specific entities, tracking which events have
+/// already been read for each entity.
+///
+/// See [`EntityEvents`] for an example.
+#[derive(SystemParam, Debug)]
+pub struct EntityEventReader<'w, 's, E: Event> {
+    cursors: Local<'s, EntityEventCursors>,
+    events: Res<'w, EntityEvents<E>>,
+}
+
+impl<'w, 's, E: Event> EntityEventReader<'w, 's, E> {
+    /// Iterates over the events addressed to `entity` this [`EntityEventReader`] has not seen yet.
+    /// Subsequent reads for `entity` will not include these events again.
+    pub fn read(&mut self, entity: Entity) -> impl ExactSizeIterator<Item = &E> + '_ {
+        let start = self.cursors.get(entity);
+        self.cursors.set(entity, &self.events);
+        self.events.get_since(entity, start)
+    }
+
+    /// Returns the number of events addressed to `entity` that are available to be read.
+    pub fn len(&self, entity: Entity) -> usize {
+        self.events
+            .get_since(entity, self.cursors.get(entity))
+            .len()
+    }
+
+    /// Returns `true` if there are no events addressed to `entity` available to read.
+    pub fn is_empty(&self, entity: Entity) -> bool {
+        self.len(entity) == 0
+    }
+
+    /// Consumes all available events addressed to `entity`.
+    pub fn clear(&mut self, entity: Entity) {
+        self.cursors.set(entity, &self.events);
+    }
+}
+
+/// Sends events of type `E` addressed to specific entities.
+///
+/// See [`EntityEvents`] for an example.
+#[derive(SystemParam)]
+pub struct EntityEventWriter<'w, E: Event> {
+    events: ResMut<'w, EntityEvents<E>>,
+}
+
+impl<'w, E: Event> EntityEventWriter<'w, E> {
+    /// Sends an `event` addressed to `entity`.
+    pub fn send(&mut self, entity: Entity, event: E) {
+        self.events.send(entity, event);
+    }
+
+    /// Sends each event to the entity it is paired with.
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = (Entity, E)>) {
+        for (entity, event) in events {
+            self.events.send(entity, event);
+        }
+    }
+
+    /// Sends the default value of the event to `entity`. Useful when the event is an empty struct.
+    pub fn send_default(&mut self, entity: Entity)
+    where
+        E: Default,
+    {
+        self.events.send_default(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityEventReader, EntityEvents};
+    use crate::{prelude::*, system::SystemState};
+
+    #[derive(Debug, PartialEq)]
+    struct Hit(u32);
+
+    #[test]
+    fn events_are_addressed_to_entities() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut events = EntityEvents::<Hit>::default();
+        events.send(a, Hit(1));
+        events.send(b, Hit(2));
+        events.send(a, Hit(3));
+
+        assert_eq!(events.get(a).collect::<Vec<_>>(), [&Hit(1), &Hit(3)]);
+        assert_eq!(events.get(b).collect::<Vec<_>>(), [&Hit(2)]);
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn events_are_double_buffered() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+
+        let mut events = EntityEvents::<Hit>::default();
+        events.send(a, Hit(1));
+        events.update();
+        events.send(a, Hit(2));
+        assert_eq!(events.get(a).collect::<Vec<_>>(), [&Hit(1), &Hit(2)]);
+
+        events.update();
+        assert_eq!(events.get(a).collect::<Vec<_>>(), [&Hit(2)]);
+
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn reader_reads_each_event_once() {
+        let mut world = World::new();
+        world.init_resource::<EntityEvents<Hit>>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut state = SystemState::<EntityEventReader<Hit>>::new(&mut world);
+
+        world.resource_mut::<EntityEvents<Hit>>().send(a, Hit(1));
+        world.resource_mut::<EntityEvents<Hit>>().send(b, Hit(2));
+
+        let mut reader = state.get(&world);
+        assert_eq!(reader.len(a), 1);
+        assert_eq!(reader.read(a).collect::<Vec<_>>(), [&Hit(1)]);
+        assert!(reader.is_empty(a));
+        assert_eq!(reader.len(b), 1);
+
+        world.resource_mut::<EntityEvents<Hit>>().update();
+        world.resource_mut::<EntityEvents<Hit>>().send(a, Hit(3));
+
+        let mut reader = state.get(&world);
+        assert_eq!(reader.read(a).collect::<Vec<_>>(), [&Hit(3)]);
+        assert_eq!(reader.read(b).collect::<Vec<_>>(), [&Hit(2)]);
+        assert_eq!(reader.read(b).len(), 0);
+    }
+
+    #[test]
+    fn events_of_despawned_entities_are_dropped() {
+        let mut world = World::new();
+        world.init_resource::<EntityEvents<Hit>>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        world.resource_mut::<EntityEvents<Hit>>().send(a, Hit(1));
+        world.resource_mut::<EntityEvents<Hit>>().send(b, Hit(2));
+        world.despawn(a);
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(EntityEvents::<Hit>::update_system);
+        schedule.run(&mut world);
+
+        let events = world.resource::<EntityEvents<Hit>>();
+        assert_eq!(events.entities().collect::<Vec<_>>(), [b]);
+        assert_eq!(events.get(a).len(), 0);
+    }
+}
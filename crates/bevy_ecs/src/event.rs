@@ -1,8 +1,16 @@
 //! Event handling types.
 
 use crate as bevy_ecs;
-use crate::system::{Local, Res, ResMut, Resource, SystemParam};
+use crate::{
+    component::Tick,
+    system::{Local, ReadOnlySystemParam, Res, ResMut, Resource, SystemMeta, SystemParam},
+    world::World,
+};
+#[cfg(feature = "trace_events")]
+use bevy_utils::tracing::info;
 use bevy_utils::tracing::trace;
+#[cfg(feature = "trace_events")]
+use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 use std::{fmt, hash::Hash, iter::Chain, marker::PhantomData, slice::Iter};
 /// A type that can be stored in an [`Events<E>`] resource
@@ -114,6 +122,10 @@ struct EventInstance<E: Event> {
 ///
 /// The buffers in [`Events`] will grow indefinitely if [`update`](Events::update) is never called.
 ///
+/// By default, the buffers keep their capacity when they are cleared, so a one-time burst of
+/// events permanently increases their memory usage. Use [`Events::set_capacity_policy`], or insert
+/// an [`EventCapacityPolicy`] resource to change the default of all [`Events`], to release it.
+///
 /// An alternative call pattern would be to call [`update`](Events::update)
 /// manually across frames to control when events are cleared.
 /// This complicates consumption and risks ever-expanding memory usage if not cleaned up,
@@ -131,6 +143,7 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     events_b: EventSequence<E>,
     event_count: usize,
+    capacity_policy: Option<EventCapacityPolicy>,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -140,6 +153,45 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            capacity_policy: None,
+        }
+    }
+}
+
+/// Controls what happens to the memory of the buffers of [`Events`] when they are cleared
+/// by [`Events::update`].
+///
+/// The policy of a single [`Events`] resource is set with [`Events::set_capacity_policy`].
+/// When inserted as a resource, this is the default policy of all [`Events`] that don't have
+/// their own policy, as used by [`Events::update_system`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventCapacityPolicy {
+    /// The buffers keep their capacity, so they never have to reallocate once they are large
+    /// enough, but never release the memory of a burst of events either.
+    #[default]
+    Retain,
+    /// A cleared buffer is shrunk if its capacity is more than twice the number of events sent in
+    /// the last two updates. It is shrunk to that number of events, but never below
+    /// `min_capacity`.
+    ///
+    /// This releases the memory of bursts of events within a few updates, while buffers that are
+    /// regularly filled keep their capacity.
+    Shrink {
+        /// The capacity buffers are never shrunk below.
+        min_capacity: usize,
+    },
+}
+
+impl EventCapacityPolicy {
+    fn apply<E: Event>(self, cleared: &mut EventSequence<E>, recent_len: usize) {
+        match self {
+            EventCapacityPolicy::Retain => {}
+            EventCapacityPolicy::Shrink { min_capacity } => {
+                let target = recent_len.max(min_capacity);
+                if cleared.capacity() > target.saturating_mul(2) {
+                    cleared.shrink_to(target);
+                }
+            }
         }
     }
 }
@@ -183,10 +235,14 @@ impl<E: Event> DerefMut for EventSequence<E> {
 }
 
 /// Reads events of type `T` in order and tracks which events have already been read.
+///
+/// With the `trace_events` feature, reads are recorded as tracing events, see [`EventSystemName`].
 #[derive(SystemParam, Debug)]
 pub struct EventReader<'w, 's, E: Event> {
     reader: Local<'s, ManualEventReader<E>>,
     events: Res<'w, Events<E>>,
+    #[cfg_attr(not(feature = "trace_events"), allow(dead_code))]
+    system_name: EventSystemName,
 }
 
 impl<'w, 's, E: Event> EventReader<'w, 's, E> {
@@ -194,11 +250,17 @@ impl<'w, 's, E: Event> EventReader<'w, 's, E> {
     /// [`EventReader`]'s event counter, which means subsequent event reads will not include events
     /// that happened before now.
     pub fn iter(&mut self) -> ManualEventIterator<'_, E> {
-        self.reader.iter(&self.events)
+        self.iter_with_id().without_id()
     }
 
     /// Like [`iter`](Self::iter), except also returning the [`EventId`] of the events.
     pub fn iter_with_id(&mut self) -> ManualEventIteratorWithId<'_, E> {
+        #[cfg(feature = "trace_events")]
+        self.system_name.record::<E>(
+            "events read",
+            self.events.event_count - self.reader.len(&self.events),
+            self.events.event_count,
+        );
         self.reader.iter_with_id(&self.events)
     }
 
@@ -291,20 +353,24 @@ impl<'a, 'w, 's, E: Event> IntoIterator for &'a mut EventReader<'w, 's, E> {
 /// }
 /// ```
 /// Note that this is considered *non-idiomatic*, and should only be used when `EventWriter` will not work.
+///
+/// With the `trace_events` feature, sends are recorded as tracing events, see [`EventSystemName`].
 #[derive(SystemParam)]
 pub struct EventWriter<'w, E: Event> {
     events: ResMut<'w, Events<E>>,
+    #[cfg_attr(not(feature = "trace_events"), allow(dead_code))]
+    system_name: EventSystemName,
 }
 
 impl<'w, E: Event> EventWriter<'w, E> {
     /// Sends an `event`. [`EventReader`]s can then read the event.
     /// See [`Events`] for details.
     pub fn send(&mut self, event: E) {
-        self.events.send(event);
+        self.record_sent(|events| events.send(event));
     }
 
     pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) {
-        self.events.extend(events);
+        self.record_sent(|e| e.extend(events));
     }
 
     /// Sends the default value of the event. Useful when the event is an empty struct.
@@ -312,7 +378,72 @@ impl<'w, E: Event> EventWriter<'w, E> {
     where
         E: Default,
     {
-        self.events.send_default();
+        self.record_sent(Events::send_default);
+    }
+
+    #[inline]
+    fn record_sent(&mut self, send: impl FnOnce(&mut Events<E>)) {
+        #[cfg(feature = "trace_events")]
+        let start = self.events.event_count;
+        send(&mut self.events);
+        #[cfg(feature = "trace_events")]
+        self.system_name
+            .record::<E>("events sent", start, self.events.event_count);
+    }
+}
+
+/// The name of the system an [`EventReader`] or [`EventWriter`] belongs to.
+///
+/// With the `trace_events` feature, [`EventWriter`]s and [`EventReader`]s record the events
+/// they send and read as `INFO` level tracing events with the target `bevy_ecs::event`.
+/// Each record contains the name of the system, the type name of the events and the range of
+/// [`EventId`]s sent or read, so tools consuming the traces can reconstruct which systems an
+/// event flowed through during a frame. Events sent directly through [`Events`], e.g. by
+/// [`World::send_event`], are not recorded.
+///
+/// Without the feature, this is a zero-sized type that doesn't record anything.
+#[derive(Debug, Clone, Default)]
+pub struct EventSystemName {
+    #[cfg(feature = "trace_events")]
+    name: Cow<'static, str>,
+}
+
+impl EventSystemName {
+    #[cfg(feature = "trace_events")]
+    fn record<E: Event>(&self, message: &str, start: usize, end: usize) {
+        if start < end {
+            info!(
+                target: "bevy_ecs::event",
+                system = &*self.name,
+                event = std::any::type_name::<E>(),
+                ids = ?(start..end),
+                "{message}"
+            );
+        }
+    }
+}
+
+// SAFETY: Only reads internal system state
+unsafe impl ReadOnlySystemParam for EventSystemName {}
+
+// SAFETY: `EventSystemName` doesn't require any world access
+unsafe impl SystemParam for EventSystemName {
+    type State = ();
+    type Item<'w, 's> = EventSystemName;
+
+    fn init_state(_world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {}
+
+    #[allow(unused_variables)]
+    unsafe fn get_param<'w, 's>(
+        _state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        _world: &'w World,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        EventSystemName {
+            #[cfg(feature = "trace_events")]
+            name: system_meta.name.clone(),
+        }
     }
 }
 
@@ -545,9 +676,18 @@ impl<E: Event> Events<E> {
 
     /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
     /// called once per frame/update.
+    ///
+    /// The cleared buffer is shrunk according to the [capacity policy](Events::set_capacity_policy)
+    /// of the events, or [`EventCapacityPolicy::Retain`] if none is set.
     pub fn update(&mut self) {
+        self.update_with_policy(self.capacity_policy.unwrap_or_default());
+    }
+
+    fn update_with_policy(&mut self, policy: EventCapacityPolicy) {
         std::mem::swap(&mut self.events_a, &mut self.events_b);
+        let recent_len = self.events_a.len() + self.events_b.len();
         self.events_b.clear();
+        policy.apply(&mut self.events_b, recent_len);
         self.events_b.start_event_count = self.event_count;
         debug_assert_eq!(
             self.events_a.start_event_count + self.events_a.len(),
@@ -555,9 +695,37 @@ impl<E: Event> Events<E> {
         );
     }
 
+    /// Sets the [`EventCapacityPolicy`] of these events, overriding the default policy
+    /// set with the [`EventCapacityPolicy`] resource.
+    pub fn set_capacity_policy(&mut self, policy: EventCapacityPolicy) {
+        self.capacity_policy = Some(policy);
+    }
+
+    /// Returns the [`EventCapacityPolicy`] of these events, if one was set with
+    /// [`Events::set_capacity_policy`].
+    pub fn capacity_policy(&self) -> Option<EventCapacityPolicy> {
+        self.capacity_policy
+    }
+
+    /// Returns the number of bytes allocated for the two event buffers.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        (self.events_a.capacity() + self.events_b.capacity())
+            * std::mem::size_of::<EventInstance<E>>()
+    }
+
     /// A system that calls [`Events::update`] once per frame.
-    pub fn update_system(mut events: ResMut<Self>) {
-        events.update();
+    ///
+    /// Events without their own [capacity policy](Events::set_capacity_policy) use the
+    /// [`EventCapacityPolicy`] resource, if it exists.
+    pub fn update_system(
+        mut events: ResMut<Self>,
+        default_policy: Option<Res<EventCapacityPolicy>>,
+    ) {
+        let policy = events
+            .capacity_policy
+            .or_else(|| default_policy.map(|policy| *policy))
+            .unwrap_or_default();
+        events.update_with_policy(policy);
     }
 
     #[inline]
@@ -838,6 +1006,47 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[test]
+    fn test_events_capacity_policy() {
+        let mut events = Events::<TestEvent>::default();
+        events.extend((0..1000).map(|i| TestEvent { i }));
+        events.update();
+        events.update();
+        assert!(events.events_b.capacity() >= 1000);
+
+        let mut events = Events::<TestEvent>::default();
+        events.set_capacity_policy(EventCapacityPolicy::Shrink { min_capacity: 16 });
+        events.extend((0..1000).map(|i| TestEvent { i }));
+        events.update();
+        // The burst is still readable, so its buffer isn't cleared yet.
+        assert_eq!(events.len(), 1000);
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.update();
+        events.update();
+        assert!(events.events_a.capacity() < 1000);
+        assert!(events.events_b.capacity() < 1000);
+    }
+
+    #[test]
+    fn test_events_default_capacity_policy() {
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world.insert_resource(EventCapacityPolicy::Shrink { min_capacity: 0 });
+        world
+            .resource_mut::<Events<TestEvent>>()
+            .extend((0..1000).map(|i| TestEvent { i }));
+
+        let mut schedule = crate::schedule::Schedule::new();
+        schedule.add_system(Events::<TestEvent>::update_system);
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+
+        let events = world.resource::<Events<TestEvent>>();
+        assert_eq!(events.allocated_bytes(), 0);
+    }
+
     #[test]
     fn test_event_reader_len_empty() {
         let events = Events::<TestEvent>::default();
@@ -9,11 +9,13 @@ pub mod bundle;
 pub mod change_detection;
 pub mod component;
 pub mod entity;
+pub mod entity_event;
 pub mod event;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
 pub mod removal_detection;
+pub mod resource_lifecycle;
 pub mod schedule;
 pub mod storage;
 pub mod system;
@@ -34,9 +36,11 @@ pub mod prelude {
         change_detection::{DetectChanges, DetectChangesMut, Mut, Ref},
         component::Component,
         entity::Entity,
+        entity_event::{EntityEventReader, EntityEventWriter, EntityEvents},
         event::{Event, EventReader, EventWriter, Events},
         query::{Added, AnyOf, Changed, Or, QueryState, With, Without},
         removal_detection::RemovedComponents,
+        resource_lifecycle::ResourceLifecycle,
         schedule::{
             apply_state_transition, apply_system_buffers, common_conditions::*, Condition,
             IntoSystemConfig, IntoSystemConfigs, IntoSystemSet, IntoSystemSetConfig,
@@ -46,8 +50,9 @@ pub mod prelude {
         system::{
             adapter as system_adapter,
             adapter::{dbg, error, ignore, info, unwrap, warn},
-            Commands, Deferred, In, IntoPipeSystem, IntoSystem, Local, NonSend, NonSendMut,
-            ParallelCommands, ParamSet, Query, Res, ResMut, Resource, System, SystemParamFunction,
+            Commands, Deferred, DeferredWorld, In, IntoPipeSystem, IntoSystem, Local, NonSend,
+            NonSendMut, ParallelCommands, ParamSet, Query, Res, ResMut, Resource, System,
+            SystemParamFunction,
         },
         world::{FromWorld, World},
     };
@@ -66,7 +71,7 @@ mod tests {
         bundle::Bundle,
         change_detection::Ref,
         component::{Component, ComponentId},
-        entity::Entity,
+        entity::{DespawnResult, Entity},
         query::{Added, Changed, FilteredAccess, ReadOnlyWorldQuery, With, Without},
         system::Resource,
         world::{Mut, World},
@@ -294,7 +299,7 @@ mod tests {
         let e = world.spawn((TableStored("abc"), A(123))).id();
         let f = world.spawn((TableStored("def"), A(456))).id();
         assert_eq!(world.entities.len(), 2);
-        assert!(world.despawn(e));
+        assert_eq!(world.despawn(e), DespawnResult::Despawned);
         assert_eq!(world.entities.len(), 1);
         assert!(world.get::<TableStored>(e).is_none());
         assert!(world.get::<A>(e).is_none());
@@ -309,7 +314,7 @@ mod tests {
         let e = world.spawn((TableStored("abc"), SparseStored(123))).id();
         let f = world.spawn((TableStored("def"), SparseStored(456))).id();
         assert_eq!(world.entities.len(), 2);
-        assert!(world.despawn(e));
+        assert_eq!(world.despawn(e), DespawnResult::Despawned);
         assert_eq!(world.entities.len(), 1);
         assert!(world.get::<TableStored>(e).is_none());
         assert!(world.get::<SparseStored>(e).is_none());
@@ -712,7 +717,7 @@ mod tests {
         let mut i32_bool_query = world.query::<(&A, &B)>();
         assert!(i32_bool_query.get(&world, a).is_err());
         assert_eq!(i32_bool_query.get(&world, c).unwrap(), (&A(789), &B(1)));
-        assert!(world.despawn(a));
+        assert_eq!(world.despawn(a), DespawnResult::Despawned);
         assert!(i32_query.get(&world, a).is_err());
     }
 
@@ -929,7 +934,7 @@ mod tests {
         );
 
         // removing an unchanged entity should not change changed state
-        assert!(world.despawn(e2));
+        assert_eq!(world.despawn(e2), DespawnResult::Despawned);
         assert_eq!(
             get_filtered::<Changed<A>>(&mut world),
             vec![e3, e1],
@@ -937,7 +942,7 @@ mod tests {
         );
 
         // removing a changed entity should remove it from enumeration
-        assert!(world.despawn(e1));
+        assert_eq!(world.despawn(e1), DespawnResult::Despawned);
         assert_eq!(
             get_filtered::<Changed<A>>(&mut world),
             vec![e3],
@@ -1015,7 +1020,7 @@ mod tests {
         );
 
         // removing an unchanged entity should not change changed state
-        assert!(world.despawn(e2));
+        assert_eq!(world.despawn(e2), DespawnResult::Despawned);
         assert_eq!(
             get_filtered::<Changed<SparseStored>>(&mut world),
             vec![e3, e1],
@@ -1023,7 +1028,7 @@ mod tests {
         );
 
         // removing a changed entity should remove it from enumeration
-        assert!(world.despawn(e1));
+        assert_eq!(world.despawn(e1), DespawnResult::Despawned);
         assert_eq!(
             get_filtered::<Changed<SparseStored>>(&mut world),
             vec![e3],
@@ -54,6 +54,9 @@ pub struct Access<T: SparseSetIndex> {
     /// Is `true` if this has access to all elements in the collection?
     /// This field is a performance optimization for `&World` (also harder to mess up for soundness).
     reads_all: bool,
+    /// Is `true` if this has exclusive access to all elements in the collection?
+    /// This field is a performance optimization for [`DeferredWorld`](crate::system::DeferredWorld).
+    writes_all: bool,
     marker: PhantomData<T>,
 }
 
@@ -66,6 +69,7 @@ impl<T: SparseSetIndex + fmt::Debug> fmt::Debug for Access<T> {
             )
             .field("writes", &FormattedBitSet::<T>::new(&self.writes))
             .field("reads_all", &self.reads_all)
+            .field("writes_all", &self.writes_all)
             .finish()
     }
 }
@@ -80,6 +84,7 @@ impl<T: SparseSetIndex> Access<T> {
     pub const fn new() -> Self {
         Self {
             reads_all: false,
+            writes_all: false,
             reads_and_writes: FixedBitSet::new(),
             writes: FixedBitSet::new(),
             marker: PhantomData,
@@ -115,7 +120,7 @@ impl<T: SparseSetIndex> Access<T> {
 
     /// Returns `true` if this can exclusively access the element given by `index`.
     pub fn has_write(&self, index: T) -> bool {
-        self.writes.contains(index.sparse_set_index())
+        self.writes_all || self.writes.contains(index.sparse_set_index())
     }
 
     /// Sets this as having access to all indexed elements (i.e. `&World`).
@@ -128,9 +133,23 @@ impl<T: SparseSetIndex> Access<T> {
         self.reads_all
     }
 
+    /// Sets this as having exclusive access to all indexed elements
+    /// (i.e. [`DeferredWorld`](crate::system::DeferredWorld)).
+    pub fn write_all(&mut self) {
+        self.reads_all = true;
+        self.writes_all = true;
+    }
+
+    /// Returns `true` if this has exclusive access to all indexed elements
+    /// (i.e. [`DeferredWorld`](crate::system::DeferredWorld)).
+    pub fn has_write_all(&self) -> bool {
+        self.writes_all
+    }
+
     /// Removes all accesses.
     pub fn clear(&mut self) {
         self.reads_all = false;
+        self.writes_all = false;
         self.reads_and_writes.clear();
         self.writes.clear();
     }
@@ -138,6 +157,7 @@ impl<T: SparseSetIndex> Access<T> {
     /// Adds all access from `other`.
     pub fn extend(&mut self, other: &Access<T>) {
         self.reads_all = self.reads_all || other.reads_all;
+        self.writes_all = self.writes_all || other.writes_all;
         self.reads_and_writes.union_with(&other.reads_and_writes);
         self.writes.union_with(&other.writes);
     }
@@ -147,6 +167,15 @@ impl<T: SparseSetIndex> Access<T> {
     /// `Access` instances are incompatible if one can write
     /// an element that the other can read or write.
     pub fn is_compatible(&self, other: &Access<T>) -> bool {
+        // Only systems that do not access data are compatible with systems that write everything.
+        if self.writes_all {
+            return !other.reads_all && other.reads_and_writes.count_ones(..) == 0;
+        }
+
+        if other.writes_all {
+            return !self.reads_all && self.reads_and_writes.count_ones(..) == 0;
+        }
+
         // Only systems that do not write data are compatible with systems that operate on `&World`.
         if self.reads_all {
             return other.writes.count_ones(..) == 0;
@@ -163,6 +192,14 @@ impl<T: SparseSetIndex> Access<T> {
     /// Returns a vector of elements that the access and `other` cannot access at the same time.
     pub fn get_conflicts(&self, other: &Access<T>) -> Vec<T> {
         let mut conflicts = FixedBitSet::default();
+        if self.writes_all {
+            conflicts.extend(other.reads_and_writes.ones());
+        }
+
+        if other.writes_all {
+            conflicts.extend(self.reads_and_writes.ones());
+        }
+
         if self.reads_all {
             conflicts.extend(other.writes.ones());
         }
@@ -323,6 +360,11 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     pub fn read_all(&mut self) {
         self.access.read_all();
     }
+
+    /// Sets the underlying unfiltered access as having exclusive access to all indexed elements.
+    pub fn write_all(&mut self) {
+        self.access.write_all();
+    }
 }
 
 /// A collection of [`FilteredAccess`] instances.
@@ -466,6 +508,35 @@ mod tests {
         assert!(access_b.is_compatible(&access_a));
     }
 
+    #[test]
+    fn write_all_access_conflicts() {
+        // write_all / single read
+        let mut access_a = Access::<usize>::default();
+        access_a.grow(10);
+        access_a.add_read(0);
+
+        let mut access_b = Access::<usize>::default();
+        access_b.write_all();
+
+        assert!(!access_b.is_compatible(&access_a));
+        assert!(!access_a.is_compatible(&access_b));
+        assert_eq!(access_b.get_conflicts(&access_a), vec![0]);
+
+        // write_all / read_all
+        let mut access_a = Access::<usize>::default();
+        access_a.read_all();
+
+        assert!(!access_b.is_compatible(&access_a));
+        assert!(!access_a.is_compatible(&access_b));
+
+        // write_all / no access
+        let access_a = Access::<usize>::default();
+
+        assert!(access_b.is_compatible(&access_a));
+        assert!(access_a.is_compatible(&access_b));
+        assert!(access_b.has_write(3));
+    }
+
     #[test]
     fn access_get_conflicts() {
         let mut access_a = Access::<usize>::default();
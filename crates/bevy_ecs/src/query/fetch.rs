@@ -190,6 +190,39 @@ use std::{cell::UnsafeCell, marker::PhantomData};
 /// # bevy_ecs::system::assert_is_system(my_system);
 /// ```
 ///
+/// ## Default values for optional fields
+///
+/// Fields of type `Option<&T>` or `Option<&mut T>` can be marked with `#[world_query(default)]`.
+/// This generates a `<field>_or_default` method on the query items that returns a copy of the component,
+/// or its [`Default`] value if the entity doesn't have it. A different value can be given with
+/// `#[world_query(default = expression)]`. The component must implement [`Clone`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::WorldQuery;
+/// #
+/// #[derive(Component, Clone, Default)]
+/// struct Armor(f32);
+///
+/// #[derive(Component, Clone)]
+/// struct Speed(f32);
+///
+/// #[derive(WorldQuery)]
+/// struct MovementQuery {
+///     #[world_query(default)]
+///     armor: Option<&'static Armor>,
+///     #[world_query(default = Speed(1.0))]
+///     speed: Option<&'static Speed>,
+/// }
+///
+/// fn my_system(query: Query<MovementQuery>) {
+///     for movement in &query {
+///         let speed = movement.speed_or_default().0 / (1.0 + movement.armor_or_default().0);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(my_system);
+/// ```
+///
 /// ## Deriving traits for query items
 ///
 /// The `WorldQuery` derive macro does not automatically implement the traits of the struct to the query item types.
@@ -245,6 +278,15 @@ use std::{cell::UnsafeCell, marker::PhantomData};
 /// }
 /// ```
 ///
+/// Mutable subqueries can be used in a query marked with `#[world_query(mutable)]`.
+/// The generated read-only variant then uses the read-only variant of the subquery,
+/// so `MyQueryReadOnlyItem` contains a `SubQueryReadOnlyItem`.
+///
+/// The items of a mutable query can be converted into their read-only counterparts with
+/// `MyQueryItem::into_readonly`, or borrowed as one with `MyQueryItem::as_readonly`.
+/// Both are also available as `From` impls for `MyQueryReadOnlyItem`,
+/// provided all fields implement [`IntoReadOnlyItem`].
+///
 /// ## Filters
 ///
 /// Since the query filter type parameter is `WorldQuery`, it is also possible to use this macro to create filters.
@@ -433,6 +475,19 @@ pub unsafe trait WorldQuery {
 /// This must only be implemented for read-only [`WorldQuery`]'s.
 pub unsafe trait ReadOnlyWorldQuery: WorldQuery<ReadOnly = Self> {}
 
+/// A [`WorldQuery`] whose items can be converted to items of its read-only variant.
+///
+/// `#[derive(WorldQuery)]` implements this trait if all fields implement it, and uses it to
+/// generate `From` impls and `into_readonly`/`as_readonly` methods from the items of mutable queries
+/// to their read-only items.
+pub trait IntoReadOnlyItem: WorldQuery {
+    /// Converts `item` into an item of [`WorldQuery::ReadOnly`].
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self>;
+
+    /// Borrows `item` as an item of [`WorldQuery::ReadOnly`].
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self>;
+}
+
 /// The `Fetch` of a [`WorldQuery`], which is used to store state for each archetype/table.
 pub type QueryFetch<'w, Q> = <Q as WorldQuery>::Fetch<'w>;
 /// The item type returned when a [`WorldQuery`] is iterated over
@@ -511,6 +566,16 @@ unsafe impl WorldQuery for Entity {
 /// SAFETY: access is read only
 unsafe impl ReadOnlyWorldQuery for Entity {}
 
+impl IntoReadOnlyItem for Entity {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        *item
+    }
+}
+
 #[doc(hidden)]
 pub struct ReadFetch<'w, T> {
     // T::Storage = TableStorage
@@ -649,6 +714,16 @@ unsafe impl<T: Component> WorldQuery for &T {
 /// SAFETY: access is read only
 unsafe impl<T: Component> ReadOnlyWorldQuery for &T {}
 
+impl<T: Component> IntoReadOnlyItem for &T {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        item
+    }
+}
+
 #[doc(hidden)]
 pub struct RefFetch<'w, T> {
     // T::Storage = TableStorage
@@ -810,6 +885,19 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
 /// SAFETY: access is read only
 unsafe impl<'__w, T: Component> ReadOnlyWorldQuery for Ref<'__w, T> {}
 
+impl<'__w, T: Component> IntoReadOnlyItem for Ref<'__w, T> {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        Ref {
+            value: item.value,
+            ticks: item.ticks.clone(),
+        }
+    }
+}
+
 #[doc(hidden)]
 pub struct WriteFetch<'w, T> {
     // T::Storage = TableStorage
@@ -968,6 +1056,16 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
     }
 }
 
+impl<T: Component> IntoReadOnlyItem for &mut T {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item.value
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        item.value
+    }
+}
+
 #[doc(hidden)]
 pub struct OptionFetch<'w, T: WorldQuery> {
     fetch: T::Fetch<'w>,
@@ -1075,6 +1173,16 @@ unsafe impl<T: WorldQuery> WorldQuery for Option<T> {
 /// SAFETY: [`OptionFetch`] is read only because `T` is read only
 unsafe impl<T: ReadOnlyWorldQuery> ReadOnlyWorldQuery for Option<T> {}
 
+impl<T: IntoReadOnlyItem> IntoReadOnlyItem for Option<T> {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item.map(T::into_readonly)
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        item.as_ref().map(T::as_readonly)
+    }
+}
+
 macro_rules! impl_tuple_fetch {
     ($(($name: ident, $state: ident)),*) => {
         #[allow(non_snake_case)]
@@ -1174,6 +1282,20 @@ macro_rules! impl_tuple_fetch {
         /// SAFETY: each item in the tuple is read only
         unsafe impl<$($name: ReadOnlyWorldQuery),*> ReadOnlyWorldQuery for ($($name,)*) {}
 
+        #[allow(non_snake_case)]
+        #[allow(clippy::unused_unit)]
+        impl<$($name: IntoReadOnlyItem),*> IntoReadOnlyItem for ($($name,)*) {
+            fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+                let ($($name,)*) = item;
+                ($($name::into_readonly($name),)*)
+            }
+
+            fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+                let ($($name,)*) = item;
+                ($($name::as_readonly($name),)*)
+            }
+        }
+
     };
 }
 
@@ -1316,6 +1438,20 @@ macro_rules! impl_anytuple_fetch {
         /// SAFETY: each item in the tuple is read only
         unsafe impl<$($name: ReadOnlyWorldQuery),*> ReadOnlyWorldQuery for AnyOf<($($name,)*)> {}
 
+        #[allow(non_snake_case)]
+        #[allow(clippy::unused_unit)]
+        impl<$($name: IntoReadOnlyItem),*> IntoReadOnlyItem for AnyOf<($($name,)*)> {
+            fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+                let ($($name,)*) = item;
+                ($($name.map($name::into_readonly),)*)
+            }
+
+            fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+                let ($($name,)*) = item;
+                ($($name.as_ref().map($name::as_readonly),)*)
+            }
+        }
+
     };
 }
 
@@ -1388,3 +1524,90 @@ unsafe impl<Q: WorldQuery> WorldQuery for NopWorldQuery<Q> {
 
 /// SAFETY: `NopFetch` never accesses any data
 unsafe impl<Q: WorldQuery> ReadOnlyWorldQuery for NopWorldQuery<Q> {}
+
+impl<Q: WorldQuery> IntoReadOnlyItem for NopWorldQuery<Q> {
+    fn into_readonly<'w>(_item: Self::Item<'w>) -> ROQueryItem<'w, Self> {}
+
+    fn as_readonly<'a, 'w>(_item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {}
+}
+
+/// [`WorldQuery`] that turns `Query<Q>` into `Query<EntityWorldQuery<Q>>`, which returns the
+/// [`Entity`] of each match without fetching any of the data of `Q`.
+///
+/// This will rarely be useful to consumers of `bevy_ecs`, see [`Query::iter_entities`](crate::system::Query::iter_entities) instead.
+pub struct EntityWorldQuery<Q: WorldQuery>(PhantomData<Q>);
+
+/// SAFETY: `Self::ReadOnly` is `Self`
+unsafe impl<Q: WorldQuery> WorldQuery for EntityWorldQuery<Q> {
+    type Fetch<'w> = ();
+    type Item<'w> = Entity;
+    type ReadOnly = Self;
+    type State = Q::State;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Entity) -> Entity {
+        item
+    }
+
+    const IS_DENSE: bool = Q::IS_DENSE;
+
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn init_fetch(_world: &World, _state: &Q::State, _last_run: Tick, _this_run: Tick) {}
+
+    unsafe fn clone_fetch<'w>(_fetch: &Self::Fetch<'w>) -> Self::Fetch<'w> {}
+
+    #[inline(always)]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &Q::State,
+        _archetype: &Archetype,
+        _tables: &Table,
+    ) {
+    }
+
+    #[inline(always)]
+    unsafe fn set_table<'w>(_fetch: &mut (), _state: &Q::State, _table: &Table) {}
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+        entity
+    }
+
+    fn update_component_access(_state: &Q::State, _access: &mut FilteredAccess<ComponentId>) {}
+
+    fn update_archetype_component_access(
+        _state: &Q::State,
+        _archetype: &Archetype,
+        _access: &mut Access<ArchetypeComponentId>,
+    ) {
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        Q::init_state(world)
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        Q::matches_component_set(state, set_contains_id)
+    }
+}
+
+/// SAFETY: `EntityWorldQuery` only reads the entity, which is always available
+unsafe impl<Q: WorldQuery> ReadOnlyWorldQuery for EntityWorldQuery<Q> {}
+
+impl<Q: WorldQuery> IntoReadOnlyItem for EntityWorldQuery<Q> {
+    fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+        item
+    }
+
+    fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+        *item
+    }
+}
@@ -10,7 +10,7 @@ use bevy_ptr::{ThinSlicePtr, UnsafeCellDeref};
 use bevy_utils::all_tuples;
 use std::{cell::UnsafeCell, marker::PhantomData};
 
-use super::ReadOnlyWorldQuery;
+use super::{IntoReadOnlyItem, ROQueryItem, ReadOnlyWorldQuery};
 
 /// Filter that selects entities with a component `T`.
 ///
@@ -111,6 +111,12 @@ unsafe impl<T: Component> WorldQuery for With<T> {
 // SAFETY: no component access or archetype component access
 unsafe impl<T: Component> ReadOnlyWorldQuery for With<T> {}
 
+impl<T: Component> IntoReadOnlyItem for With<T> {
+    fn into_readonly<'w>(_item: Self::Item<'w>) -> ROQueryItem<'w, Self> {}
+
+    fn as_readonly<'a, 'w>(_item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {}
+}
+
 /// Filter that selects entities without a component `T`.
 ///
 /// This is the negation of [`With`].
@@ -207,6 +213,12 @@ unsafe impl<T: Component> WorldQuery for Without<T> {
 // SAFETY: no component access or archetype component access
 unsafe impl<T: Component> ReadOnlyWorldQuery for Without<T> {}
 
+impl<T: Component> IntoReadOnlyItem for Without<T> {
+    fn into_readonly<'w>(_item: Self::Item<'w>) -> ROQueryItem<'w, Self> {}
+
+    fn as_readonly<'a, 'w>(_item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {}
+}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
@@ -382,6 +394,16 @@ macro_rules! impl_query_filter_tuple {
 
         // SAFETY: filters are read only
         unsafe impl<$($filter: ReadOnlyWorldQuery),*> ReadOnlyWorldQuery for Or<($($filter,)*)> {}
+
+        impl<$($filter: WorldQuery),*> IntoReadOnlyItem for Or<($($filter,)*)> {
+            fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+                item
+            }
+
+            fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+                *item
+            }
+        }
     };
 }
 
@@ -551,6 +573,16 @@ macro_rules! impl_tick_filter {
 
         /// SAFETY: read-only access
         unsafe impl<T: Component> ReadOnlyWorldQuery for $name<T> {}
+
+        impl<T: Component> IntoReadOnlyItem for $name<T> {
+            fn into_readonly<'w>(item: Self::Item<'w>) -> ROQueryItem<'w, Self> {
+                item
+            }
+
+            fn as_readonly<'a, 'w>(item: &'a Self::Item<'w>) -> ROQueryItem<'a, Self> {
+                *item
+            }
+        }
     };
 }
 
@@ -61,7 +61,7 @@ impl<T> DebugCheckedUnwrap for Option<T> {
 #[cfg(test)]
 mod tests {
     use super::{ReadOnlyWorldQuery, WorldQuery};
-    use crate::prelude::{AnyOf, Changed, Entity, Or, QueryState, With, Without};
+    use crate::prelude::{AnyOf, Changed, Entity, Or, QueryState, Ref, With, Without};
     use crate::query::{ArchetypeFilter, QueryCombinationIter};
     use crate::schedule::{IntoSystemConfigs, Schedule};
     use crate::system::{IntoSystem, Query, System, SystemState};
@@ -82,6 +82,32 @@ mod tests {
     #[component(storage = "SparseSet")]
     struct Sparse(usize);
 
+    #[test]
+    fn mixed_storage_query_sparse_set_lookups() {
+        let mut world = World::new();
+        world.spawn(A(1));
+        world.spawn((A(2), B(2)));
+        world.spawn((A(3), Sparse(3)));
+        let entity = world.spawn((A(4), B(4), Sparse(4))).id();
+        world.spawn(A(5));
+
+        let mut query = world.query::<(&A, Option<&Sparse>)>();
+        let values = query.iter(&world).map(|(a, _)| a.0).collect::<Vec<usize>>();
+        // Entities without sparse set components are iterated first.
+        assert_eq!(values, [1, 5, 2, 3, 4]);
+        assert_eq!(query.sparse_set_lookups(&world), 2);
+
+        world.entity_mut(entity).remove::<Sparse>();
+        let values = query.iter(&world).map(|(a, _)| a.0).collect::<Vec<usize>>();
+        assert_eq!(values, [1, 5, 2, 4, 3]);
+        assert_eq!(query.sparse_set_lookups(&world), 1);
+
+        let query = world.query_filtered::<&A, With<Sparse>>();
+        assert_eq!(query.sparse_set_lookups(&world), 0);
+        let query = world.query::<&Sparse>();
+        assert_eq!(query.sparse_set_lookups(&world), 1);
+    }
+
     #[test]
     fn query() {
         let mut world = World::new();
@@ -672,6 +698,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn derived_worldquery_composition() {
+        #[derive(Component, Debug, Default, PartialEq, Clone, Copy)]
+        struct Bonus(usize);
+
+        #[derive(WorldQuery)]
+        #[world_query(mutable)]
+        struct Movement {
+            a: &'static mut A,
+            #[world_query(default)]
+            b: Option<&'static Bonus>,
+            #[world_query(default = C(1))]
+            c: Option<&'static mut C>,
+        }
+
+        #[derive(WorldQuery)]
+        #[world_query(mutable)]
+        struct Nested {
+            entity: Entity,
+            movement: Movement,
+        }
+
+        let mut world = World::new();
+        let e1 = world.spawn((A(1), Bonus(2), C(3))).id();
+        let e2 = world.spawn(A(4)).id();
+
+        let mut query = world.query::<Nested>();
+        for mut item in query.iter_mut(&mut world) {
+            let MovementItem { a, b, c } = &mut item.movement;
+            a.0 += b.map_or(0, |b| b.0) + c.as_ref().map_or(0, |c| c.0);
+            assert_eq!(
+                item.movement.c_or_default(),
+                if item.entity == e1 { C(3) } else { C(1) }
+            );
+        }
+
+        let values = query
+            .iter(&world)
+            .map(|item: NestedReadOnlyItem| {
+                let movement: MovementReadOnlyItem = item.movement;
+                (
+                    item.entity,
+                    *movement.a,
+                    movement.b_or_default(),
+                    movement.c_or_default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [(e1, A(6), Bonus(2), C(3)), (e2, A(4), Bonus(0), C(1))]
+        );
+    }
+
+    #[test]
+    fn derived_worldquery_read_only_conversion() {
+        #[derive(WorldQuery)]
+        #[world_query(mutable)]
+        struct Movement {
+            a: &'static mut A,
+            b: Option<&'static mut B>,
+            c: Ref<'static, C>,
+        }
+
+        #[derive(WorldQuery)]
+        #[world_query(mutable)]
+        struct Nested {
+            entity: Entity,
+            movement: Movement,
+            d: AnyOf<(&'static mut D,)>,
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn((A(1), B(2), C(3), D(4))).id();
+
+        let mut query = world.query::<Nested>();
+        let mut item = query.single_mut(&mut world);
+        item.movement.a.0 += 1;
+
+        let borrowed = NestedReadOnlyItem::from(&item);
+        assert_eq!(borrowed.entity, entity);
+        assert_eq!(borrowed.movement.a, &A(2));
+        assert_eq!(borrowed.movement.b, Some(&B(2)));
+        assert_eq!(*borrowed.movement.c, C(3));
+        assert_eq!(borrowed.d, (Some(&D(4)),));
+
+        let movement: MovementReadOnlyItem = item.movement.into();
+        assert_eq!(movement.a, &A(2));
+        assert_eq!(movement.b, Some(&B(2)));
+        assert_eq!(*movement.c, C(3));
+
+        #[derive(WorldQuery)]
+        #[world_query(mutable)]
+        struct Generic<T: Component> {
+            value: &'static mut T,
+            movement: Movement,
+        }
+
+        let mut query = world.query::<Generic<D>>();
+        let item = query.single_mut(&mut world);
+        let generic = item.as_readonly();
+        assert_eq!(generic.value, &D(4));
+        assert_eq!(generic.movement.a, &A(2));
+        let generic: GenericReadOnlyItem<D> = item.into();
+        assert_eq!(generic.value, &D(4));
+    }
+
     #[test]
     fn many_entities() {
         let mut world = World::new();
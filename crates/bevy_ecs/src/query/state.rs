@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
-    component::{ComponentId, Tick},
+    component::{ComponentId, StorageType, Tick},
     entity::Entity,
     prelude::FromWorld,
     query::{
@@ -13,10 +13,11 @@ use crate::{
 use bevy_tasks::ComputeTaskPool;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::Instrument;
+use bevy_utils::HashSet;
 use fixedbitset::FixedBitSet;
 use std::{borrow::Borrow, fmt, mem::MaybeUninit};
 
-use super::{NopWorldQuery, QueryManyIter, ROQueryItem, ReadOnlyWorldQuery};
+use super::{EntityWorldQuery, NopWorldQuery, QueryManyIter, ROQueryItem, ReadOnlyWorldQuery};
 
 /// Provides scoped access to a [`World`] state according to a given [`WorldQuery`] and query filter.
 #[repr(C)]
@@ -34,6 +35,9 @@ pub struct QueryState<Q: WorldQuery, F: ReadOnlyWorldQuery = ()> {
     pub(crate) matched_table_ids: Vec<TableId>,
     // NOTE: we maintain both a ArchetypeId bitset and a vec because iterating the vec is faster
     pub(crate) matched_archetype_ids: Vec<ArchetypeId>,
+    // The first `dense_archetype_count` archetypes of `matched_archetype_ids` need no sparse set
+    // lookups, so iteration visits them in one dense pass before the archetypes that do
+    dense_archetype_count: usize,
     pub(crate) fetch_state: Q::State,
     pub(crate) filter_state: F::State,
 }
@@ -74,6 +78,14 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         unsafe { self.as_transmuted_state::<NopWorldQuery<Q>, F>() }
     }
 
+    /// Converts this `QueryState` reference to a `QueryState` that only returns the [`Entity`]
+    /// of each match, which can be faster.
+    pub fn as_entities(&self) -> &QueryState<EntityWorldQuery<Q>, F> {
+        // SAFETY: `EntityWorldQuery` doesn't have any accesses and defers to
+        // `Q` for table/archetype matching
+        unsafe { self.as_transmuted_state::<EntityWorldQuery<Q>, F>() }
+    }
+
     /// Converts this `QueryState` reference to any other `QueryState` with
     /// the same `WorldQuery::State` associated types.
     ///
@@ -117,6 +129,7 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
             archetype_generation: ArchetypeGeneration::initial(),
             matched_table_ids: Vec::new(),
             matched_archetype_ids: Vec::new(),
+            dense_archetype_count: 0,
             fetch_state,
             filter_state,
             component_access,
@@ -188,6 +201,14 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
                 self.matched_archetypes.grow(archetype_index + 1);
                 self.matched_archetypes.set(archetype_index, true);
                 self.matched_archetype_ids.push(archetype.id());
+                if self.sparse_set_lookups_per_entity(archetype) == 0 {
+                    // Move the archetype to the end of the dense pass, in place of the first
+                    // archetype of the sparse pass.
+                    let last = self.matched_archetype_ids.len() - 1;
+                    self.matched_archetype_ids
+                        .swap(self.dense_archetype_count, last);
+                    self.dense_archetype_count += 1;
+                }
             }
             let table_index = archetype.table_id().index();
             if !self.matched_tables.contains(table_index) {
@@ -198,6 +219,39 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         }
     }
 
+    /// Returns the number of sparse set lookups a full iteration over the query performs for the
+    /// given [`World`].
+    ///
+    /// Components stored in [`Table`](crate::storage::Table)s are fetched by iterating their
+    /// columns, while every [`SparseSet`](crate::component::StorageType::SparseSet) component has
+    /// to be looked up per entity. Iteration visits the archetypes that need no lookups in a dense
+    /// first pass, followed by a second pass over the archetypes that do. Queries with a high
+    /// count are candidates for moving hot components to table storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `world.id()` does not equal the current [`QueryState`] internal id.
+    pub fn sparse_set_lookups(&self, world: &World) -> usize {
+        self.validate_world(world);
+        self.matched_archetype_ids[self.dense_archetype_count..]
+            .iter()
+            .map(|&archetype_id| {
+                let archetype = &world.archetypes()[archetype_id];
+                archetype.len() * self.sparse_set_lookups_per_entity(archetype)
+            })
+            .sum()
+    }
+
+    /// Returns the number of sparse set components the query fetches for each entity of
+    /// `archetype`.
+    fn sparse_set_lookups_per_entity(&self, archetype: &Archetype) -> usize {
+        self.component_access
+            .access()
+            .reads_and_writes()
+            .filter(|&id| archetype.get_storage_type(id) == Some(StorageType::SparseSet))
+            .count()
+    }
+
     /// Gets the query result for the given [`World`] and [`Entity`].
     ///
     /// This can only be called for read-only queries, see [`Self::get_mut`] for write-queries.
@@ -337,6 +391,87 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         }
     }
 
+    /// Returns the read-only query results for the given slice of [`Entity`], in the same order.
+    ///
+    /// This is the slice-based variant of [`get_many`](Self::get_many), for when the number of
+    /// entities isn't known at compile time. The entities do not need to be unique.
+    ///
+    /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is
+    /// returned instead.
+    #[inline]
+    pub fn get_many_slice<'w>(
+        &mut self,
+        world: &'w World,
+        entities: &[Entity],
+    ) -> Result<Vec<ROQueryItem<'w, Q>>, QueryEntityError> {
+        self.update_archetypes(world);
+
+        // SAFETY: update_archetypes validates the `World` matches
+        unsafe {
+            self.get_many_slice_read_only_manual(
+                world,
+                entities,
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+        }
+    }
+
+    /// Returns the query results for the given slice of [`Entity`], in the same order.
+    ///
+    /// This is the slice-based variant of [`get_many_mut`](Self::get_many_mut). Unlike
+    /// [`iter_many_mut`](Self::iter_many_mut), the entities are checked to be disjoint up front, so
+    /// all of the returned items can be held at the same time.
+    ///
+    /// In case of a nonexisting entity, mismatched component or an entity that is included more
+    /// than once, a [`QueryEntityError`] is returned instead.
+    ///
+    /// ```rust
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_ecs::query::QueryEntityError;
+    ///
+    /// #[derive(Component, PartialEq, Debug)]
+    /// struct A(usize);
+    ///
+    /// let mut world = World::new();
+    /// let entities: Vec<Entity> = (0..3).map(|i| world.spawn(A(i)).id()).collect();
+    ///
+    /// let mut query_state = world.query::<&mut A>();
+    ///
+    /// for mut a in query_state.get_many_slice_mut(&mut world, &entities).unwrap() {
+    ///     a.0 += 5;
+    /// }
+    ///
+    /// let component_values = query_state.get_many_slice(&world, &entities).unwrap();
+    /// assert_eq!(component_values, [&A(5), &A(6), &A(7)]);
+    ///
+    /// let duplicated = [entities[0], entities[1], entities[0]];
+    /// assert_eq!(
+    ///     query_state.get_many_slice_mut(&mut world, &duplicated).unwrap_err(),
+    ///     QueryEntityError::AliasedMutability(entities[0])
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_many_slice_mut<'w>(
+        &mut self,
+        world: &'w mut World,
+        entities: &[Entity],
+    ) -> Result<Vec<Q::Item<'w>>, QueryEntityError> {
+        self.update_archetypes(world);
+
+        let change_tick = world.change_tick();
+        // SAFETY: method requires exclusive world access
+        // and world has been validated via update_archetypes
+        unsafe {
+            self.get_many_slice_unchecked_manual(
+                world,
+                entities,
+                world.last_change_tick(),
+                change_tick,
+            )
+        }
+    }
+
     #[inline]
     pub fn get_manual<'w>(
         &self,
@@ -491,6 +626,58 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         Ok(values.map(|x| x.assume_init()))
     }
 
+    /// Gets the read-only query results for the given [`World`] and slice of [`Entity`], where the last change and
+    /// the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This must be called on the same `World` that the `Query` was generated from:
+    /// use `QueryState::validate_world` to verify this.
+    pub(crate) unsafe fn get_many_slice_read_only_manual<'w>(
+        &self,
+        world: &'w World,
+        entities: &[Entity],
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Result<Vec<ROQueryItem<'w, Q>>, QueryEntityError> {
+        let readonly = self.as_readonly();
+        entities
+            .iter()
+            // SAFETY: fetch is read-only
+            // and world must be validated
+            .map(|&entity| readonly.get_unchecked_manual(world, entity, last_run, this_run))
+            .collect()
+    }
+
+    /// Gets the query results for the given [`World`] and slice of [`Entity`], where the last change and
+    /// the current change tick are given.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for unique access to subsets of the entity-component data.
+    /// To be safe, make sure mutable queries have unique access to the components they query.
+    ///
+    /// This must be called on the same `World` that the `Query` was generated from:
+    /// use `QueryState::validate_world` to verify this.
+    pub(crate) unsafe fn get_many_slice_unchecked_manual<'w>(
+        &self,
+        world: &'w World,
+        entities: &[Entity],
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Result<Vec<Q::Item<'w>>, QueryEntityError> {
+        // Verify that all entities are unique
+        let mut seen = HashSet::with_capacity(entities.len());
+        if let Some(&duplicate) = entities.iter().find(|&&entity| !seen.insert(entity)) {
+            return Err(QueryEntityError::AliasedMutability(duplicate));
+        }
+
+        entities
+            .iter()
+            .map(|&entity| self.get_unchecked_manual(world, entity, last_run, this_run))
+            .collect()
+    }
+
     /// Returns an [`Iterator`] over the query results for the given [`World`].
     ///
     /// This can only be called for read-only queries, see [`Self::iter_mut`] for write-queries.
@@ -1178,12 +1365,18 @@ impl std::error::Error for QueryEntityError {}
 impl fmt::Display for QueryEntityError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            QueryEntityError::QueryDoesNotMatch(_) => {
-                write!(f, "The given entity does not have the requested component.")
+            QueryEntityError::QueryDoesNotMatch(entity) => write!(
+                f,
+                "The given entity {entity:?} does not have the requested component."
+            ),
+            QueryEntityError::NoSuchEntity(entity) => {
+                write!(f, "The requested entity {entity:?} does not exist.")
             }
-            QueryEntityError::NoSuchEntity(_) => write!(f, "The requested entity does not exist."),
-            QueryEntityError::AliasedMutability(_) => {
-                write!(f, "The entity was requested mutably more than once.")
+            QueryEntityError::AliasedMutability(entity) => {
+                write!(
+                    f,
+                    "The entity {entity:?} was requested mutably more than once."
+                )
             }
         }
     }
@@ -1267,6 +1460,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_many_slice_unchecked_manual_uniqueness() {
+        let mut world = World::new();
+
+        let entities: Vec<Entity> = (0..10).map(|_| world.spawn_empty().id()).collect();
+
+        let query_state = world.query::<Entity>();
+
+        // These don't matter for the test
+        let last_change_tick = world.last_change_tick();
+        let change_tick = world.change_tick();
+
+        // SAFETY: mutable access is not checked, but we own the world and don't use the query results
+        let unchecked = |entities: &[Entity]| unsafe {
+            query_state.get_many_slice_unchecked_manual(
+                &world,
+                entities,
+                last_change_tick,
+                change_tick,
+            )
+        };
+
+        assert_eq!(unchecked(&entities).unwrap(), entities);
+        assert_eq!(unchecked(&[]).unwrap(), []);
+        assert_eq!(
+            unchecked(&[entities[3], entities[1], entities[3], entities[1]]).unwrap_err(),
+            QueryEntityError::AliasedMutability(entities[3])
+        );
+        assert_eq!(
+            unchecked(&[entities[0], entities[9], entities[9]]).unwrap_err(),
+            QueryEntityError::AliasedMutability(entities[9])
+        );
+        assert_eq!(
+            QueryEntityError::AliasedMutability(entities[9]).to_string(),
+            format!(
+                "The entity {:?} was requested mutably more than once.",
+                entities[9]
+            )
+        );
+    }
+
     #[test]
     #[should_panic]
     fn right_world_get() {
@@ -1,8 +1,9 @@
 //! Types that enable reflection support.
 
 use crate::{
+    self as bevy_ecs,
     change_detection::Mut,
-    component::Component,
+    component::{Component, TableStorage},
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
     system::Resource,
     world::{
@@ -11,9 +12,35 @@ use crate::{
     },
 };
 use bevy_reflect::{
-    impl_from_reflect_value, impl_reflect_value, FromType, Reflect, ReflectDeserialize,
-    ReflectSerialize,
+    impl_from_reflect_value, impl_reflect_value, visit_dynamic_type_slot, DynamicStruct,
+    DynamicTypeSlot, DynamicTypeSlotVisitor, FromType, Reflect, ReflectRef, Struct, TypeRegistry,
+    TypeRegistryArc,
 };
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use std::ops::{Deref, DerefMut};
+use thiserror::Error;
+
+/// A [`Resource`] storing [`TypeRegistry`] for
+/// type registrations relevant to a whole app.
+#[derive(Resource, Clone, Default)]
+pub struct AppTypeRegistry(pub TypeRegistryArc);
+
+impl Deref for AppTypeRegistry {
+    type Target = TypeRegistryArc;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AppTypeRegistry {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
 
 /// A struct used to operate on reflected [`Component`] of a type.
 ///
@@ -172,6 +199,97 @@ impl ReflectComponent {
     pub fn new(fns: ReflectComponentFns) -> Self {
         Self(fns)
     }
+
+    /// Creates the [`ReflectComponent`] of the [dynamic type](TypeRegistry::register_dynamic)
+    /// in the [`DynamicTypeSlot`] `slot`, whose values are stored in the slot's [`Component`].
+    ///
+    /// As the fields of the type are unknown to it, [`ReflectComponent::from_world`] returns an
+    /// empty [`DynamicStruct`], and the other functions panic if they are given a value that isn't
+    /// a struct.
+    ///
+    /// Returns `None` if `slot` is not less than [`MAX_DYNAMIC_TYPES`](bevy_reflect::MAX_DYNAMIC_TYPES).
+    pub fn dynamic(slot: usize) -> Option<Self> {
+        visit_dynamic_type_slot(slot, DynamicComponentVisitor).map(Self)
+    }
+}
+
+impl<const N: usize> Component for DynamicTypeSlot<N> {
+    type Storage = TableStorage;
+}
+
+struct DynamicComponentVisitor;
+
+impl DynamicTypeSlotVisitor for DynamicComponentVisitor {
+    type Output = ReflectComponentFns;
+
+    fn visit<const N: usize>(self) -> ReflectComponentFns {
+        ReflectComponentFns {
+            from_world: |_| Box::<DynamicStruct>::default(),
+            insert: |entity, reflected_component| {
+                entity.insert(DynamicTypeSlot::<N>(clone_dynamic_struct(
+                    reflected_component,
+                )));
+            },
+            apply: |entity, reflected_component| {
+                let mut component = entity.get_mut::<DynamicTypeSlot<N>>().unwrap();
+                component.0.apply(reflected_component);
+            },
+            apply_or_insert: |entity, reflected_component| {
+                if let Some(mut component) = entity.get_mut::<DynamicTypeSlot<N>>() {
+                    component.0.apply(reflected_component);
+                } else {
+                    entity.insert(DynamicTypeSlot::<N>(clone_dynamic_struct(
+                        reflected_component,
+                    )));
+                }
+            },
+            remove: |entity| {
+                entity.remove::<DynamicTypeSlot<N>>();
+            },
+            contains: |entity| entity.contains::<DynamicTypeSlot<N>>(),
+            copy: |source_world, destination_world, source_entity, destination_entity| {
+                let source_component = source_world
+                    .get::<DynamicTypeSlot<N>>(source_entity)
+                    .unwrap();
+                let destination_component =
+                    DynamicTypeSlot::<N>(source_component.0.clone_dynamic());
+                destination_world
+                    .entity_mut(destination_entity)
+                    .insert(destination_component);
+            },
+            reflect: |entity| {
+                entity
+                    .get::<DynamicTypeSlot<N>>()
+                    .map(|c| &c.0 as &dyn Reflect)
+            },
+            reflect_mut: |entity| {
+                entity.get_mut::<DynamicTypeSlot<N>>().map(|c| Mut {
+                    value: &mut c.value.0 as &mut dyn Reflect,
+                    ticks: c.ticks,
+                })
+            },
+            reflect_unchecked_mut: |entity| {
+                // SAFETY: reflect_unchecked_mut is an unsafe function pointer used by
+                // `reflect_unchecked_mut` which must be called with an UnsafeEntityCell with access to the component of the slot on the `entity`
+                unsafe {
+                    entity.get_mut::<DynamicTypeSlot<N>>().map(|c| Mut {
+                        value: &mut c.value.0 as &mut dyn Reflect,
+                        ticks: c.ticks,
+                    })
+                }
+            },
+        }
+    }
+}
+
+fn clone_dynamic_struct(value: &dyn Reflect) -> DynamicStruct {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.clone_dynamic(),
+        _ => panic!(
+            "the value of the dynamic component `{}` is not a struct",
+            value.type_name()
+        ),
+    }
 }
 
 impl<C: Component + Reflect + FromWorld> FromType<C> for ReflectComponent {
@@ -407,7 +525,225 @@ impl<C: Resource + Reflect + FromWorld> FromType<C> for ReflectResource {
     }
 }
 
+/// An error that occurs when spawning an entity from reflected components with
+/// [`World::spawn_reflected`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SpawnReflectedError {
+    #[error("the unregistered type `{type_name}` can't be spawned. consider registering the type using `app.register_type::<T>()`")]
+    UnregisteredType { type_name: String },
+    #[error("the unregistered component `{type_name}` can't be spawned. consider adding `#[reflect(Component)]` to your type")]
+    UnregisteredComponent { type_name: String },
+}
+
+/// Looks up the [`ReflectComponent`] of each of the `components` in the `registry`.
+fn reflect_components(
+    registry: &TypeRegistry,
+    components: &[Box<dyn Reflect>],
+) -> Result<Vec<ReflectComponent>, SpawnReflectedError> {
+    components
+        .iter()
+        .map(|component| {
+            let type_name = component.type_name();
+            let registration = registry.get_with_name(type_name).ok_or_else(|| {
+                SpawnReflectedError::UnregisteredType {
+                    type_name: type_name.to_string(),
+                }
+            })?;
+            registration
+                .data::<ReflectComponent>()
+                .cloned()
+                .ok_or_else(|| SpawnReflectedError::UnregisteredComponent {
+                    type_name: type_name.to_string(),
+                })
+        })
+        .collect()
+}
+
+/// Inserts the reflected `components` into the `entity`, resolving their component types
+/// in the `registry`.
+///
+/// Nothing is inserted if any of the `components` can't be resolved.
+pub(crate) fn insert_reflected(
+    entity: &mut EntityMut,
+    registry: &TypeRegistry,
+    components: &[Box<dyn Reflect>],
+) -> Result<(), SpawnReflectedError> {
+    let reflect_components = reflect_components(registry, components)?;
+    for (reflect_component, component) in reflect_components.iter().zip(components) {
+        reflect_component.insert(entity, &**component);
+    }
+    Ok(())
+}
+
+impl World {
+    /// Spawns a new [`Entity`] with the given reflected `components`, returning the
+    /// [`EntityMut`] of the spawned entity.
+    ///
+    /// Each value is inserted as the component type it was registered as in the
+    /// [`AppTypeRegistry`] with `#[reflect(Component)]`. This allows spawning entities from
+    /// data, like configuration files or scripts, without going through a scene.
+    ///
+    /// No entity is spawned if any of the values has no registered component type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world has no [`AppTypeRegistry`] resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::{prelude::*, reflect::{AppTypeRegistry, ReflectComponent}};
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Health>();
+    ///
+    /// let entity = world.spawn_reflected(vec![Box::new(Health(10))]).unwrap().id();
+    /// assert_eq!(world.get::<Health>(entity), Some(&Health(10)));
+    /// ```
+    pub fn spawn_reflected(
+        &mut self,
+        components: Vec<Box<dyn Reflect>>,
+    ) -> Result<EntityMut, SpawnReflectedError> {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        self.spawn_reflected_with_registry(&registry, components)
+    }
+
+    /// Like [`World::spawn_reflected`], but resolves the component types in the given
+    /// `registry` instead of the [`AppTypeRegistry`].
+    pub fn spawn_reflected_with_registry(
+        &mut self,
+        registry: &TypeRegistry,
+        components: Vec<Box<dyn Reflect>>,
+    ) -> Result<EntityMut, SpawnReflectedError> {
+        let reflect_components = reflect_components(registry, &components)?;
+        let mut entity = self.spawn_empty();
+        for (reflect_component, component) in reflect_components.iter().zip(&components) {
+            reflect_component.insert(&mut entity, &**component);
+        }
+        Ok(entity)
+    }
+
+    /// Spawns a copy of the `source` entity and returns it.
+    ///
+    /// Every component of `source` whose type is registered in the [`AppTypeRegistry`] with
+    /// `#[reflect(Component)]` is cloned through reflection. Components that aren't registered
+    /// that way, or that opted out with `#[reflect(NoClone)]` (see [`ReflectNoClone`]), are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world has no [`AppTypeRegistry`] resource or `source` does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::{prelude::*, reflect::{AppTypeRegistry, ReflectComponent}};
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Health>();
+    ///
+    /// let prefab = world.spawn(Health(10)).id();
+    /// let clone = world.clone_entity(prefab);
+    /// assert_eq!(world.get::<Health>(clone), Some(&Health(10)));
+    /// ```
+    pub fn clone_entity(&mut self, source: Entity) -> Entity {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        self.clone_entity_with_registry(&registry, source)
+    }
+
+    /// Like [`World::clone_entity`], but resolves the component types in the given `registry`
+    /// instead of the [`AppTypeRegistry`].
+    pub fn clone_entity_with_registry(
+        &mut self,
+        registry: &TypeRegistry,
+        source: Entity,
+    ) -> Entity {
+        let components = clone_reflected_components(self, registry, source);
+        let mut entity = self.spawn_empty();
+        for (reflect_component, component) in &components {
+            reflect_component.insert(&mut entity, &**component);
+        }
+        entity.id()
+    }
+}
+
+/// Clones the components of the `source` entity that can be cloned by [`World::clone_entity`].
+///
+/// # Panics
+///
+/// Panics if `source` does not exist.
+fn clone_reflected_components(
+    world: &World,
+    registry: &TypeRegistry,
+    source: Entity,
+) -> Vec<(ReflectComponent, Box<dyn Reflect>)> {
+    let source = world.entity(source);
+    source
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(component_id)?.type_id()?;
+            let registration = registry.get(type_id)?;
+            if registration.data::<ReflectNoClone>().is_some() {
+                return None;
+            }
+            let reflect_component = registration.data::<ReflectComponent>()?;
+            let component = reflect_component.reflect(source)?.clone_value();
+            Some((reflect_component.clone(), component))
+        })
+        .collect()
+}
+
+/// Clones the components of the `source` entity into the existing `destination` entity, like
+/// [`World::clone_entity`] does for a new entity.
+///
+/// # Panics
+///
+/// Panics if either entity does not exist.
+pub(crate) fn clone_reflected_into(
+    world: &mut World,
+    registry: &TypeRegistry,
+    source: Entity,
+    destination: Entity,
+) {
+    let components = clone_reflected_components(world, registry, source);
+    let mut destination = world.entity_mut(destination);
+    for (reflect_component, component) in &components {
+        reflect_component.insert(&mut destination, &**component);
+    }
+}
+
+/// Type data that excludes a [`Component`] type from [`World::clone_entity`].
+///
+/// Register it with `#[reflect(NoClone)]` for components that must stay unique to their entity,
+/// like the links of a hierarchy.
+#[derive(Clone, Copy, Debug)]
+pub struct ReflectNoClone;
+
+impl<C: Component> FromType<C> for ReflectNoClone {
+    fn from_type() -> Self {
+        ReflectNoClone
+    }
+}
+
+#[cfg(feature = "serialize")]
 impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
+#[cfg(not(feature = "serialize"))]
+impl_reflect_value!(Entity(Hash, PartialEq));
 impl_from_reflect_value!(Entity);
 
 #[derive(Clone)]
@@ -0,0 +1,239 @@
+//! Alerting events when a resource is inserted into or removed from the world.
+
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Components, Tick},
+    event::{Events, ManualEventIterator, ManualEventReader},
+    prelude::Local,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::World,
+};
+use std::any::TypeId;
+
+/// An event sent by the [`World`] when a resource appears or disappears.
+///
+/// Replacing the value of a resource that is already present doesn't send an event, use change
+/// detection for that instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResourceLifecycleEvent {
+    /// The resource with the given id was inserted while it wasn't present.
+    Inserted(ComponentId),
+    /// The resource with the given id was removed while it was present.
+    Removed(ComponentId),
+}
+
+impl ResourceLifecycleEvent {
+    /// Returns the [`ComponentId`] of the resource this event is about.
+    pub fn component_id(&self) -> ComponentId {
+        match *self {
+            ResourceLifecycleEvent::Inserted(id) | ResourceLifecycleEvent::Removed(id) => id,
+        }
+    }
+
+    /// Returns `true` if this event is about the resource or non-send resource of type `R`.
+    pub fn is<R: 'static>(&self, components: &Components) -> bool {
+        components.get_resource_id(TypeId::of::<R>()) == Some(self.component_id())
+    }
+}
+
+/// The [`ResourceLifecycleEvent`]s of a [`World`], see [`World::resource_lifecycle_events`].
+#[derive(Default, Debug)]
+pub struct ResourceLifecycleEvents {
+    events: Events<ResourceLifecycleEvent>,
+}
+
+impl ResourceLifecycleEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self) {
+        self.events.update();
+    }
+
+    /// Returns the underlying [`Events`].
+    pub fn events(&self) -> &Events<ResourceLifecycleEvent> {
+        &self.events
+    }
+
+    pub fn send(&mut self, event: ResourceLifecycleEvent) {
+        self.events.send(event);
+    }
+}
+
+/// A [`SystemParam`] that reads the [`ResourceLifecycleEvent`]s of the world, e.g. to lazily
+/// initialize a subsystem once the resource it depends on is inserted.
+///
+/// This acts effectively the same as an [`EventReader`](crate::event::EventReader).
+///
+/// If you are using `bevy_ecs` as a standalone crate, note that the events will not be
+/// automatically cleared for you, and will need to be manually flushed using
+/// [`World::clear_trackers`](crate::world::World::clear_trackers).
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::resource_lifecycle::ResourceLifecycle;
+/// #
+/// # #[derive(Resource)]
+/// # struct AudioDevice;
+/// fn setup_audio(mut lifecycle: ResourceLifecycle) {
+///     if lifecycle.inserted::<AudioDevice>() {
+///         println!("an audio device is available");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(setup_audio);
+/// ```
+#[derive(SystemParam)]
+pub struct ResourceLifecycle<'w, 's> {
+    reader: Local<'s, ManualEventReader<ResourceLifecycleEvent>>,
+    events: &'w ResourceLifecycleEvents,
+    components: &'w Components,
+}
+
+impl<'w, 's> ResourceLifecycle<'w, 's> {
+    /// Iterates over the events this [`ResourceLifecycle`] has not seen yet. This updates the
+    /// [`ResourceLifecycle`]'s event counter, which means subsequent event reads will not include
+    /// events that happened before now.
+    pub fn iter(&mut self) -> ManualEventIterator<'_, ResourceLifecycleEvent> {
+        self.reader.iter(&self.events.events)
+    }
+
+    /// Consumes the unread events and returns `true` if the resource or non-send resource of type
+    /// `R` was inserted in any of them.
+    pub fn inserted<R: 'static>(&mut self) -> bool {
+        let components = self.components;
+        // counted rather than short-circuited, so that all events are consumed
+        self.iter()
+            .filter(|event| {
+                matches!(event, ResourceLifecycleEvent::Inserted(_)) && event.is::<R>(components)
+            })
+            .count()
+            > 0
+    }
+
+    /// Consumes the unread events and returns `true` if the resource or non-send resource of type
+    /// `R` was removed in any of them.
+    pub fn removed<R: 'static>(&mut self) -> bool {
+        let components = self.components;
+        // counted rather than short-circuited, so that all events are consumed
+        self.iter()
+            .filter(|event| {
+                matches!(event, ResourceLifecycleEvent::Removed(_)) && event.is::<R>(components)
+            })
+            .count()
+            > 0
+    }
+
+    /// Determines the number of events available to be read without consuming any.
+    pub fn len(&self) -> usize {
+        self.reader.len(&self.events.events)
+    }
+
+    /// Returns `true` if there are no events available to read.
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty(&self.events.events)
+    }
+
+    /// Consumes all available events.
+    pub fn clear(&mut self) {
+        self.reader.clear(&self.events.events);
+    }
+}
+
+// SAFETY: Only reads World resource lifecycle events
+unsafe impl<'a> ReadOnlySystemParam for &'a ResourceLifecycleEvents {}
+
+// SAFETY: no resource value access, resource lifecycle events can be read in parallel and are
+// never mutably borrowed during system execution
+unsafe impl<'a> SystemParam for &'a ResourceLifecycleEvents {
+    type State = ();
+    type Item<'w, 's> = &'w ResourceLifecycleEvents;
+
+    fn init_state(_world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {}
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        _state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        world: &'w World,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        world.resource_lifecycle_events()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Resource)]
+    struct Device;
+
+    #[derive(Resource, Default)]
+    struct Observed {
+        inserted: usize,
+        removed: usize,
+    }
+
+    fn observe(mut lifecycle: ResourceLifecycle, mut observed: ResMut<Observed>) {
+        let events: Vec<_> = lifecycle.iter().copied().collect();
+        let components = lifecycle.components;
+        for event in events.iter().filter(|event| event.is::<Device>(components)) {
+            match event {
+                ResourceLifecycleEvent::Inserted(_) => observed.inserted += 1,
+                ResourceLifecycleEvent::Removed(_) => observed.removed += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn resource_lifecycle_events() {
+        let mut world = World::new();
+        world.init_resource::<Observed>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(observe);
+
+        world.insert_resource(Device);
+        // replacing a present resource is not a lifecycle event
+        world.insert_resource(Device);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Observed>().inserted, 1);
+
+        world.remove_resource::<Device>();
+        // removing an absent resource is not a lifecycle event
+        world.remove_resource::<Device>();
+        world.get_resource_or_insert_with(|| Device);
+        schedule.run(&mut world);
+        let observed = world.resource::<Observed>();
+        assert_eq!((observed.inserted, observed.removed), (2, 1));
+
+        world.clear_resources();
+        world.init_resource::<Observed>();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Observed>().removed, 1);
+    }
+
+    #[test]
+    fn typed_lifecycle_queries() {
+        #[derive(Resource, Default)]
+        struct Changes(Vec<(bool, bool)>);
+
+        fn check(mut lifecycle: ResourceLifecycle, mut changes: ResMut<Changes>) {
+            let inserted = lifecycle.inserted::<Device>();
+            changes.0.push((inserted, lifecycle.is_empty()));
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Changes>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(check);
+
+        world.insert_resource(Device);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Changes>().0, [(true, true), (false, true)]);
+    }
+}
@@ -1,6 +1,12 @@
 use std::borrow::Cow;
 
-use crate::system::{CombinatorSystem, Combine, IntoSystem, ReadOnlySystem, System};
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    query::Access,
+    system::{CombinatorSystem, Combine, IntoSystem, LocalValue, ReadOnlySystem, System},
+    world::World,
+};
 
 pub type BoxedCondition = Box<dyn ReadOnlySystem<In = (), Out = bool>>;
 
@@ -8,6 +14,11 @@ pub type BoxedCondition = Box<dyn ReadOnlySystem<In = (), Out = bool>>;
 ///
 /// Implemented for functions and closures that convert into [`System<In=(), Out=bool>`](crate::system::System)
 /// with [read-only](crate::system::ReadOnlySystemParam) parameters.
+///
+/// Change detection in conditions, like [`DetectChanges::is_changed`](crate::change_detection::DetectChanges::is_changed),
+/// [`Changed`](crate::query::Changed) filters or [`SystemChangeTick`](crate::system::SystemChangeTick),
+/// is relative to the last time the condition itself was evaluated. Conditions that are evaluated sparsely,
+/// e.g. because they are short-circuited by [`Condition::and_then`], still see all changes since then.
 pub trait Condition<Marker>: sealed::Condition<Marker> {
     /// Returns a new run condition that only returns `true`
     /// if both this one and the passed `and_then` return `true`.
@@ -106,6 +117,41 @@ pub trait Condition<Marker>: sealed::Condition<Marker> {
         let name = format!("{} || {}", a.name(), b.name());
         CombinatorSystem::new(a, b, Cow::Owned(name))
     }
+
+    /// Returns a new run condition that inverts the output of this one.
+    ///
+    /// This is the method form of [`common_conditions::not`], which reads better
+    /// at the end of a chain of combinators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Resource, PartialEq)]
+    /// struct Paused;
+    ///
+    /// # let mut app = Schedule::new();
+    /// # let mut world = World::new();
+    /// # #[derive(Resource)] struct C(bool);
+    /// # fn my_system(mut c: ResMut<C>) { c.0 = true; }
+    /// app.add_system(
+    ///     // Only run the system while the game is not paused.
+    ///     my_system.run_if(resource_exists::<Paused>().not()),
+    /// );
+    /// #
+    /// # world.insert_resource(C(false));
+    /// # world.insert_resource(Paused);
+    /// # app.run(&mut world);
+    /// # assert!(!world.resource::<C>().0);
+    /// #
+    /// # world.remove_resource::<Paused>();
+    /// # app.run(&mut world);
+    /// # assert!(world.resource::<C>().0);
+    /// ```
+    fn not(self) -> NotSystem<Self::System> {
+        NotSystem::new(IntoSystem::into_system(self))
+    }
 }
 
 impl<Marker, F> Condition<Marker> for F where F: sealed::Condition<Marker> {}
@@ -131,13 +177,13 @@ mod sealed {
 }
 
 pub mod common_conditions {
-    use super::Condition;
+    use super::{Condition, NotSystem};
     use crate::{
         change_detection::DetectChanges,
-        event::{Event, EventReader},
-        prelude::{Component, Query, With},
+        event::{Event, Events, ManualEventReader},
+        prelude::{Changed, Component, Query, With},
         schedule::{State, States},
-        system::{In, IntoPipeSystem, Res, Resource},
+        system::{Local, Res, Resource},
     };
 
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
@@ -809,6 +855,9 @@ pub mod common_conditions {
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
     /// if there are any new events of the given type since it was last called.
     ///
+    /// Events that were sent since then but already dropped, e.g. because the condition wasn't
+    /// evaluated for several frames, still count as new events.
+    ///
     /// # Example
     ///
     /// ```
@@ -841,12 +890,17 @@ pub mod common_conditions {
     /// app.run(&mut world);
     /// assert_eq!(world.resource::<Counter>().0, 1);
     /// ```
-    pub fn on_event<T: Event>() -> impl FnMut(EventReader<T>) -> bool + Clone {
-        // The events need to be consumed, so that there are no false positives on subsequent
-        // calls of the run condition. Simply checking `is_empty` would not be enough.
-        // PERF: note that `count` is efficient (not actually looping/iterating),
-        // due to Bevy having a specialized implementation for events.
-        move |mut reader: EventReader<T>| reader.iter().count() > 0
+    pub fn on_event<T: Event>(
+    ) -> impl FnMut(Res<Events<T>>, Local<ManualEventReader<T>>) -> bool + Clone {
+        move |events: Res<Events<T>>, mut reader: Local<ManualEventReader<T>>| {
+            // Events sent before the condition was last skipped for a while may already have been
+            // dropped, so they are counted as missed rather than unread.
+            let any_events = !reader.is_empty(&events) || reader.missed_events(&events) > 0;
+            // The events need to be consumed, so that there are no false positives on subsequent
+            // calls of the run condition.
+            reader.clear(&events);
+            any_events
+        }
     }
 
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
@@ -886,6 +940,58 @@ pub mod common_conditions {
         move |query: Query<(), With<T>>| !query.is_empty()
     }
 
+    /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
+    /// if any component of the given type has been added or changed since the condition
+    /// was last checked.
+    ///
+    /// Changes are detected relative to the last time the condition was evaluated, so changes
+    /// made while it wasn't evaluated are not missed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::new();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// app.add_system(
+    ///     my_system.run_if(any_component_changed::<MyComponent>()),
+    /// );
+    ///
+    /// #[derive(Component)]
+    /// struct MyComponent(u8);
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// // No `MyComponent` has been added or changed so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// let entity = world.spawn(MyComponent(0)).id();
+    ///
+    /// // A `MyComponent` was just added so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// // Nothing changed since the condition was last checked so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// world.get_mut::<MyComponent>(entity).unwrap().0 = 1;
+    ///
+    /// // A `MyComponent` was just changed so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 2);
+    /// ```
+    pub fn any_component_changed<T: Component>() -> impl FnMut(Query<(), Changed<T>>) -> bool + Clone
+    {
+        move |query: Query<(), Changed<T>>| !query.is_empty()
+    }
+
     /// Generates a [`Condition`](super::Condition) that inverses the result of passed one.
     ///
     /// # Example
@@ -915,11 +1021,11 @@ pub mod common_conditions {
     /// app.run(&mut world);
     /// assert_eq!(world.resource::<Counter>().0, 0);
     /// ```
-    pub fn not<Marker, T>(condition: T) -> impl Condition<()>
+    pub fn not<Marker, T>(condition: T) -> NotSystem<T::System>
     where
         T: Condition<Marker>,
     {
-        condition.pipe(|In(val): In<bool>| !val)
+        condition.not()
     }
 }
 
@@ -971,6 +1077,98 @@ where
     }
 }
 
+/// Inverts the output of a run condition.
+///
+/// Created by [`Condition::not`] or [`common_conditions::not`].
+pub struct NotSystem<T> {
+    condition: T,
+    name: Cow<'static, str>,
+}
+
+impl<T: System<In = (), Out = bool>> NotSystem<T> {
+    /// Creates a run condition that inverts the output of `condition`.
+    pub fn new(condition: T) -> Self {
+        let name = format!("!{}", condition.name());
+        Self {
+            condition,
+            name: Cow::Owned(name),
+        }
+    }
+}
+
+impl<T: System<In = (), Out = bool>> System for NotSystem<T> {
+    type In = ();
+    type Out = bool;
+
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.condition.component_access()
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.condition.archetype_component_access()
+    }
+
+    fn is_send(&self) -> bool {
+        self.condition.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.condition.is_exclusive()
+    }
+
+    unsafe fn run_unsafe(&mut self, input: Self::In, world: &World) -> Self::Out {
+        // SAFETY: The caller upholds the same invariants as for the wrapped condition.
+        !self.condition.run_unsafe(input, world)
+    }
+
+    fn run(&mut self, input: Self::In, world: &mut World) -> Self::Out {
+        !self.condition.run(input, world)
+    }
+
+    fn apply_buffers(&mut self, world: &mut World) {
+        self.condition.apply_buffers(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.condition.initialize(world);
+    }
+
+    fn set_local_value(&mut self, value: LocalValue) {
+        self.condition.set_local_value(value);
+    }
+
+    fn update_archetype_component_access(&mut self, world: &World) {
+        self.condition.update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.condition.check_change_tick(change_tick);
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.condition.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.condition.set_last_run(last_run);
+    }
+
+    fn default_system_sets(&self) -> Vec<Box<dyn crate::schedule::SystemSet>> {
+        self.condition.default_system_sets()
+    }
+}
+
+// SAFETY: The wrapped condition is read-only, and inverting its output does not access the world.
+unsafe impl<T: ReadOnlySystem<In = (), Out = bool>> ReadOnlySystem for NotSystem<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::Condition;
@@ -1030,6 +1228,8 @@ mod tests {
         schedule.add_system(increment_counter.run_if(every_other_time.or_else(|| true)));
         // Run every other cycle
         schedule.add_system(increment_counter.run_if(every_other_time.and_then(|| true)));
+        // Never run
+        schedule.add_system(increment_counter.run_if((|| true).not()));
 
         schedule.run(&mut world);
         assert_eq!(world.resource::<Counter>().0, 2);
@@ -1074,4 +1274,62 @@ mod tests {
         schedule.run(&mut world);
         assert_eq!(world.resource::<Counter>().0, 0);
     }
+
+    #[test]
+    fn change_conditions_evaluated_sparsely() {
+        use crate::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Gate(bool);
+
+        #[derive(Resource, Default)]
+        struct Value(usize);
+
+        #[derive(Component)]
+        struct C;
+
+        struct E;
+
+        let gate = |gate: Res<Gate>| gate.0;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Value>();
+        world.init_resource::<Events<E>>();
+        world.insert_resource(Gate(true));
+        let entity = world.spawn(C).id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(Events::<E>::update_system).add_systems(
+            (
+                increment_counter.run_if(gate.and_then(resource_changed::<Value>())),
+                increment_counter.run_if(gate.and_then(any_component_changed::<C>())),
+                increment_counter.run_if(gate.and_then(on_event::<E>())),
+            )
+                .after(Events::<E>::update_system),
+        );
+
+        // `Value` and `C` were just added.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+
+        // Change everything while the conditions are short-circuited for several frames,
+        // long enough for the event to be dropped.
+        world.resource_mut::<Gate>().0 = false;
+        world.resource_mut::<Value>().0 += 1;
+        world.entity_mut(entity).insert(C);
+        world.send_event(E);
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.resource::<Counter>().0, 2);
+
+        world.resource_mut::<Gate>().0 = true;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 5);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 5);
+    }
 }
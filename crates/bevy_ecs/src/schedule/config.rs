@@ -1,21 +1,25 @@
 use bevy_utils::all_tuples;
+use std::{any::TypeId, borrow::Cow};
 
 use crate::{
     schedule::{
         condition::{BoxedCondition, Condition},
         graph_utils::{Ambiguity, Dependency, DependencyKind, GraphInfo},
-        set::{BoxedSystemSet, IntoSystemSet, SystemSet},
+        set::{BoxedSystemSet, IntoSystemSet, SystemInstanceSet, SystemSet},
     },
-    system::{BoxedSystem, IntoSystem, System},
+    system::{BoxedSystem, IntoSystem, LocalValue, System},
 };
 
-use super::{BaseSystemSet, FreeSystemSet};
+use super::{BaseSystemSet, FreeSystemSet, LogLevel, ThreadAffinity};
 
 /// A [`SystemSet`] with scheduling metadata.
 pub struct SystemSetConfig {
     pub(super) set: BoxedSystemSet,
     pub(super) graph_info: GraphInfo,
     pub(super) conditions: Vec<BoxedCondition>,
+    pub(super) propagate_conditions: bool,
+    pub(super) ambiguity_policy: Option<LogLevel>,
+    pub(super) denied_access: Vec<(TypeId, &'static str)>,
 }
 
 impl SystemSetConfig {
@@ -31,6 +35,9 @@ impl SystemSetConfig {
             set,
             graph_info: GraphInfo::system_set(),
             conditions: Vec::new(),
+            propagate_conditions: false,
+            ambiguity_policy: None,
+            denied_access: Vec::new(),
         }
     }
 }
@@ -40,6 +47,8 @@ pub struct SystemConfig {
     pub(super) system: BoxedSystem,
     pub(super) graph_info: GraphInfo,
     pub(super) conditions: Vec<BoxedCondition>,
+    pub(super) affinity: ThreadAffinity,
+    pub(super) run_once: bool,
 }
 
 impl SystemConfig {
@@ -52,6 +61,8 @@ impl SystemConfig {
             system,
             graph_info,
             conditions: Vec::new(),
+            affinity: ThreadAffinity::default(),
+            run_once: false,
         }
     }
 }
@@ -108,6 +119,20 @@ pub trait IntoSystemSetConfig: Sized {
     fn after<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfig {
         self.into_config().after(set)
     }
+    /// Run before all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of the systems in this set applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn before_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfig {
+        self.into_config().before_and_flush(set)
+    }
+    /// Run after all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of `set` applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn after_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfig {
+        self.into_config().after_and_flush(set)
+    }
     /// Run the systems in this set only if the [`Condition`] is `true`.
     ///
     /// The `Condition` will be evaluated at most once (per schedule run),
@@ -115,6 +140,72 @@ pub trait IntoSystemSetConfig: Sized {
     fn run_if<M>(self, condition: impl Condition<M>) -> SystemSetConfig {
         self.into_config().run_if(condition)
     }
+    /// Evaluate the [`Condition`]s of this set separately for each system in it,
+    /// instead of once for the whole set.
+    ///
+    /// Each system in the set will then only run if the conditions are `true`
+    /// right before that system prepares to run, as if they had been added to each
+    /// system with [`run_if`](IntoSystemConfig::run_if).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # struct MySet;
+    /// # let mut schedule = Schedule::new();
+    /// # fn a() {}
+    /// # fn b() {}
+    /// # fn condition() -> bool { true }
+    /// schedule.configure_set(MySet.run_if(condition).propagate_conditions());
+    /// schedule.add_systems((a, b).in_set(MySet));
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Unlike [`distributive_run_if`](IntoSystemConfigs::distributive_run_if), the systems
+    /// share a single instance of each condition, so conditions with local state (like
+    /// [`run_once`](super::common_conditions::run_once)) see every evaluation.
+    fn propagate_conditions(self) -> SystemSetConfig {
+        self.into_config().propagate_conditions()
+    }
+    /// Determines how ambiguities (systems with conflicting access but indeterminate order)
+    /// involving systems in this set are handled, overriding
+    /// [`ScheduleBuildSettings::ambiguity_detection`](super::ScheduleBuildSettings::ambiguity_detection)
+    /// for them.
+    ///
+    /// If the systems of an ambiguity are in multiple sets with a policy, the most severe one
+    /// applies. This allows critical sets to fail the schedule build on ambiguities, while the
+    /// rest of the schedule only warns about them.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::LogLevel;
+    /// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # struct PhysicsSet;
+    /// # let mut schedule = Schedule::new();
+    /// schedule.configure_set(PhysicsSet.ambiguity_policy(LogLevel::Error));
+    /// ```
+    fn ambiguity_policy(self, level: LogLevel) -> SystemSetConfig {
+        self.into_config().ambiguity_policy(level)
+    }
+    /// Forbids the systems in this set from accessing the component or resource `T`.
+    ///
+    /// Building the schedule fails with a [`DeniedAccess`](super::ScheduleBuildError::DeniedAccess)
+    /// error if a system in this set reads or writes `T`, including through `&World`.
+    /// Exclusive systems are not checked, as their access is not known.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # struct ExtractSet;
+    /// # #[derive(Resource)]
+    /// # struct Simulation;
+    /// # let mut schedule = Schedule::new();
+    /// // Extract systems must not touch the simulation state.
+    /// schedule.configure_set(ExtractSet.deny_access::<Simulation>());
+    /// ```
+    fn deny_access<T: 'static>(self) -> SystemSetConfig {
+        self.into_config().deny_access::<T>()
+    }
     /// Suppress warnings and errors that would result from systems in this set having ambiguities
     /// (conflicting access but indeterminate order) with systems in `set`.
     fn ambiguous_with<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfig {
@@ -201,11 +292,43 @@ impl IntoSystemSetConfig for SystemSetConfig {
         self
     }
 
+    fn before_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::BeforeAndFlush,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
+    fn after_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::AfterAndFlush,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
     fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
         self.conditions.push(new_condition(condition));
         self
     }
 
+    fn propagate_conditions(mut self) -> Self {
+        self.propagate_conditions = true;
+        self
+    }
+
+    fn ambiguity_policy(mut self, level: LogLevel) -> Self {
+        self.ambiguity_policy = Some(level);
+        self
+    }
+
+    fn deny_access<T: 'static>(mut self) -> Self {
+        self.denied_access
+            .push((TypeId::of::<T>(), std::any::type_name::<T>()));
+        self
+    }
+
     fn ambiguous_with<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
         ambiguous_with(&mut self.graph_info, Box::new(set.into_system_set()));
         self
@@ -250,6 +373,20 @@ where
     fn after<M>(self, set: impl IntoSystemSet<M>) -> Config {
         self.into_config().after(set)
     }
+    /// Run before all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of this system applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn before_and_flush<M>(self, set: impl IntoSystemSet<M>) -> Config {
+        self.into_config().before_and_flush(set)
+    }
+    /// Run after all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of `set` applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn after_and_flush<M>(self, set: impl IntoSystemSet<M>) -> Config {
+        self.into_config().after_and_flush(set)
+    }
     /// Run only if the [`Condition`] is `true`.
     ///
     /// The `Condition` will be evaluated at most once (per schedule run),
@@ -267,6 +404,95 @@ where
     fn ambiguous_with_all(self) -> Config {
         self.into_config().ambiguous_with_all()
     }
+    /// Use `value` as the initial value of the system's [`Local<T>`](crate::system::Local)
+    /// parameter, instead of creating it with [`FromWorld`](crate::world::FromWorld).
+    ///
+    /// This allows adding the same system multiple times with different configurations.
+    /// If the system has multiple `Local<T>` parameters, only the first one is initialized
+    /// with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics right away if the system doesn't support initial `Local` values, like combined
+    /// or piped systems. Function systems don't panic here, but when they are initialized
+    /// without having a `Local<T>` parameter. Values passed to a system that was already
+    /// initialized are never used.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Default)]
+    /// struct Greeting(&'static str);
+    ///
+    /// fn greet(greeting: Local<Greeting>) {
+    ///     println!("{}", greeting.0);
+    /// }
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_system(greet.with_local(Greeting("hello")));
+    /// schedule.add_system(greet.with_local(Greeting("bonjour")));
+    /// # schedule.run(&mut World::new());
+    /// ```
+    fn with_local<T: Send + 'static>(self, value: T) -> Config {
+        self.into_config().with_local(value)
+    }
+    /// Label this instance of the system function, so other systems can be ordered relative to
+    /// it with [`SystemTypeSet::instance`](super::SystemTypeSet::instance) even if the function
+    /// is added more than once.
+    ///
+    /// See [`SystemTypeSet::instance`](super::SystemTypeSet::instance) for an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system isn't built from a function, as it then has no
+    /// [`SystemTypeSet`](super::SystemTypeSet).
+    #[track_caller]
+    fn instance(self, label: impl Into<Cow<'static, str>>) -> Config {
+        self.into_config().instance(label)
+    }
+    /// Run this system on the threads given by `affinity` when the schedule is run by the
+    /// [`MultiThreaded`](super::ExecutorKind::MultiThreaded) executor.
+    ///
+    /// Useful for systems that need to control their latency, like audio or GPU submission.
+    /// See [`ThreadAffinity`] for details.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ThreadAffinity;
+    /// fn submit_audio() {}
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_system(submit_audio.on_thread(ThreadAffinity::Main));
+    /// # schedule.run(&mut World::new());
+    /// ```
+    fn on_thread(self, affinity: ThreadAffinity) -> Config {
+        self.into_config().on_thread(affinity)
+    }
+    /// Remove this system from the schedule after the first run in which its conditions passed.
+    ///
+    /// Once retired, neither the system nor its conditions are evaluated again, which makes
+    /// this a cheaper alternative to guarding one-shot initialization with a `Local<bool>`.
+    /// The system still counts as completed for the systems ordered after it.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Counter(usize);
+    ///
+    /// fn setup(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Counter>();
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_system(setup.run_once());
+    /// schedule.run(&mut world);
+    /// schedule.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    /// ```
+    fn run_once(self) -> Config {
+        self.into_config().run_once()
+    }
 }
 
 impl<Marker, F> IntoSystemConfig<Marker> for F
@@ -338,6 +564,22 @@ impl IntoSystemConfig<()> for SystemConfig {
         self
     }
 
+    fn before_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::BeforeAndFlush,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
+    fn after_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::AfterAndFlush,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
     fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
         self.conditions.push(new_condition(condition));
         self
@@ -352,6 +594,42 @@ impl IntoSystemConfig<()> for SystemConfig {
         self.graph_info.ambiguous_with = Ambiguity::IgnoreAll;
         self
     }
+
+    fn with_local<T: Send + 'static>(mut self, value: T) -> Self {
+        self.system.set_local_value(LocalValue::new(value));
+        self
+    }
+
+    fn on_thread(mut self, affinity: ThreadAffinity) -> Self {
+        self.affinity = affinity;
+        self
+    }
+
+    fn run_once(mut self) -> Self {
+        self.run_once = true;
+        self
+    }
+
+    #[track_caller]
+    fn instance(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        let label = label.into();
+        let instance_sets: Vec<_> = self
+            .system
+            .default_system_sets()
+            .into_iter()
+            .filter(|set| set.system_type().is_some())
+            .map(|type_set| SystemInstanceSet::new(type_set, label.clone()))
+            .collect();
+        assert!(
+            !instance_sets.is_empty(),
+            "System `{}` has no `SystemTypeSet`, so its instances can't be labeled.",
+            self.system.name()
+        );
+        for set in instance_sets {
+            self.graph_info.sets.push(Box::new(set));
+        }
+        self
+    }
 }
 
 /// A collection of [`SystemConfig`].
@@ -392,6 +670,22 @@ where
         self.into_configs().after(set)
     }
 
+    /// Run before all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of these systems applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn before_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemConfigs {
+        self.into_configs().before_and_flush(set)
+    }
+
+    /// Run after all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of `set` applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn after_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemConfigs {
+        self.into_configs().after_and_flush(set)
+    }
+
     /// Add a run condition to each contained system.
     ///
     /// Each system will receive its own clone of the [`Condition`] and will only run
@@ -509,6 +803,30 @@ impl IntoSystemConfigs<()> for SystemConfigs {
         self
     }
 
+    fn before_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.systems {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::BeforeAndFlush,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
+    fn after_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.systems {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::AfterAndFlush,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
     fn distributive_run_if<M>(mut self, condition: impl Condition<M> + Clone) -> SystemConfigs {
         for config in &mut self.systems {
             config.conditions.push(new_condition(condition.clone()));
@@ -578,6 +896,22 @@ where
         self.into_configs().after(set)
     }
 
+    /// Run before all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of the systems in these sets applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn before_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfigs {
+        self.into_configs().before_and_flush(set)
+    }
+
+    /// Run after all systems in `set`, with the [`Commands`](crate::system::Commands) and other
+    /// system buffers of `set` applied in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`](crate::schedule::ScheduleBuildSettings).
+    fn after_and_flush<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfigs {
+        self.into_configs().after_and_flush(set)
+    }
+
     /// Suppress warnings and errors that would result from systems in these sets having ambiguities
     /// (conflicting access but indeterminate order) with systems in `set`.
     fn ambiguous_with<M>(self, set: impl IntoSystemSet<M>) -> SystemSetConfigs {
@@ -669,6 +1003,30 @@ impl IntoSystemSetConfigs for SystemSetConfigs {
         self
     }
 
+    fn before_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.sets {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::BeforeAndFlush,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
+    fn after_and_flush<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.sets {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::AfterAndFlush,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
     fn ambiguous_with<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
         let set = set.into_system_set();
         for config in &mut self.sets {
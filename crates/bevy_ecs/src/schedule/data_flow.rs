@@ -0,0 +1,355 @@
+use std::fmt::Write;
+
+use bevy_utils::{get_short_name, HashMap, HashSet};
+
+use crate::{
+    component::ComponentId,
+    query::Access,
+    schedule::{NodeId, ScheduleLabel, Schedules},
+    world::World,
+};
+
+/// A system in a [`DataFlowGraph`].
+#[derive(Debug, Clone)]
+pub struct DataFlowSystem {
+    /// The label of the schedule containing the system, formatted with [`Debug`].
+    pub schedule: String,
+    /// The id of the system in its schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+}
+
+/// An edge of a [`DataFlowGraph`], from a system writing a component or resource to a system
+/// reading the written value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataFlowEdge {
+    /// The index of the writing system in [`DataFlowGraph::systems`].
+    pub producer: usize,
+    /// The index of the reading system in [`DataFlowGraph::systems`].
+    pub consumer: usize,
+    /// The component or resource.
+    pub data: ComponentId,
+    /// Whether the consumer runs before the producer, and thus reads the value written in the
+    /// previous frame.
+    pub previous_frame: bool,
+}
+
+/// The flow of components and resources between the systems of several schedules, computed from
+/// the access declared by the systems.
+///
+/// Each system reading some data is connected to the system which last wrote it, either earlier in
+/// the same frame or, if no system wrote it yet, in the previous frame. This helps to find systems
+/// which are placed in the wrong schedule, e.g. reading a `Transform` one frame late, and work
+/// which is never used.
+///
+/// The schedules are assumed to run once per frame in the given order, and systems to run in the
+/// order of [`Schedule::systems`](super::Schedule::systems). Systems which aren't ordered relative to
+/// each other may run in a different order, use the ambiguity detection of the schedules to find
+/// them. Exclusive systems and systems reading the whole [`World`] are not included, as their
+/// access isn't declared.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{DataFlowGraph, ScheduleLabel};
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Render;
+///
+/// fn count(mut score: ResMut<Score>) {
+///     score.0 += 1;
+/// }
+///
+/// fn draw(score: Res<Score>) {
+///     println!("{}", score.0);
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Score>();
+/// let mut schedules = Schedules::new();
+/// let mut update = Schedule::new();
+/// update.add_system(count);
+/// update.initialize(&mut world).unwrap();
+/// schedules.insert(Update, update);
+/// let mut render = Schedule::new();
+/// render.add_system(draw);
+/// render.initialize(&mut world).unwrap();
+/// schedules.insert(Render, render);
+/// world.insert_resource(schedules);
+///
+/// let graph = DataFlowGraph::new(&world, [&Update as &dyn ScheduleLabel, &Render]);
+/// assert_eq!(graph.edges().len(), 1);
+/// println!("{}", graph.to_dot());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DataFlowGraph {
+    systems: Vec<DataFlowSystem>,
+    edges: Vec<DataFlowEdge>,
+    unread_writes: Vec<(usize, ComponentId)>,
+    data_names: HashMap<ComponentId, String>,
+}
+
+impl DataFlowGraph {
+    /// Computes the data flow between the systems of the schedules with the given `labels` in the
+    /// [`Schedules`] of the `world`, running in the given order.
+    ///
+    /// The schedules must be initialized, e.g. by [`Schedule::initialize`](super::Schedule::initialize)
+    /// or by running them once. Labels without a schedule are skipped.
+    pub fn new<'a>(world: &World, labels: impl IntoIterator<Item = &'a dyn ScheduleLabel>) -> Self {
+        let mut graph = Self::default();
+        let Some(schedules) = world.get_resource::<Schedules>() else {
+            return graph;
+        };
+
+        let mut accesses: Vec<&Access<ComponentId>> = Vec::new();
+        for label in labels {
+            let Some(schedule) = schedules.get(label) else {
+                continue;
+            };
+            for (id, system) in schedule.systems() {
+                let access = system.component_access();
+                if system.is_exclusive() || access.has_read_all() {
+                    continue;
+                }
+                graph.systems.push(DataFlowSystem {
+                    schedule: format!("{label:?}"),
+                    id,
+                    name: system.name().to_string(),
+                });
+                accesses.push(access);
+            }
+        }
+
+        // Systems reading data before it is written in a frame read the value of the last writer
+        // of the previous frame.
+        let mut writers: HashMap<ComponentId, (usize, bool)> = HashMap::default();
+        for (index, access) in accesses.iter().enumerate() {
+            for data in access.writes() {
+                writers.insert(data, (index, true));
+            }
+        }
+
+        let mut read_writes = HashSet::new();
+        for (consumer, access) in accesses.iter().enumerate() {
+            for data in access.reads_and_writes() {
+                graph.data_names.entry(data).or_insert_with(|| {
+                    world
+                        .components()
+                        .get_info(data)
+                        .map_or_else(|| format!("{data:?}"), |info| info.name().to_string())
+                });
+                let Some(&(producer, previous_frame)) = writers.get(&data) else {
+                    continue;
+                };
+                // A system reading its own writes from the previous frame doesn't pass any data on.
+                if producer == consumer {
+                    continue;
+                }
+                graph.edges.push(DataFlowEdge {
+                    producer,
+                    consumer,
+                    data,
+                    previous_frame,
+                });
+                read_writes.insert((producer, data));
+            }
+            for data in access.writes() {
+                writers.insert(data, (consumer, false));
+            }
+        }
+
+        for (producer, access) in accesses.iter().enumerate() {
+            for data in access.writes() {
+                if !read_writes.contains(&(producer, data)) {
+                    graph.unread_writes.push((producer, data));
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Returns the systems of the graph, in the order they run.
+    pub fn systems(&self) -> &[DataFlowSystem] {
+        &self.systems
+    }
+
+    /// Returns all edges of the graph.
+    pub fn edges(&self) -> &[DataFlowEdge] {
+        &self.edges
+    }
+
+    /// Returns the edges along which the component or resource with the given id flows.
+    pub fn edges_of(&self, data: ComponentId) -> impl Iterator<Item = &DataFlowEdge> {
+        self.edges.iter().filter(move |edge| edge.data == data)
+    }
+
+    /// Returns the edges whose consumer reads the value written in the previous frame.
+    ///
+    /// These often point to a system placed in the wrong schedule, which adds a frame of latency.
+    pub fn previous_frame_edges(&self) -> impl Iterator<Item = &DataFlowEdge> {
+        self.edges.iter().filter(|edge| edge.previous_frame)
+    }
+
+    /// Returns the writes which are never read by another system before being overwritten, as
+    /// pairs of the index of the writing system and the written component or resource.
+    ///
+    /// These point to redundant work, unless the data is read outside of the schedules, e.g. by
+    /// exclusive systems or when extracting it for rendering.
+    pub fn unread_writes(&self) -> &[(usize, ComponentId)] {
+        &self.unread_writes
+    }
+
+    /// Returns the name of the component or resource with the given id.
+    pub fn data_name(&self, data: ComponentId) -> Option<&str> {
+        self.data_names.get(&data).map(String::as_str)
+    }
+
+    /// Exports the graph in the [DOT format](https://graphviz.org/doc/info/lang.html), with a
+    /// cluster per schedule and dashed edges for values read in the next frame.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph data_flow {\n");
+        let mut cluster = None;
+        for (index, system) in self.systems.iter().enumerate() {
+            if cluster != Some(&system.schedule) {
+                if cluster.is_some() {
+                    dot.push_str("  }\n");
+                }
+                let _ = writeln!(
+                    dot,
+                    "  subgraph cluster_{index} {{\n    label=\"{}\";",
+                    escape(&system.schedule)
+                );
+                cluster = Some(&system.schedule);
+            }
+            let _ = writeln!(
+                dot,
+                "    system_{index} [label=\"{}\"];",
+                escape(&get_short_name(&system.name))
+            );
+        }
+        if cluster.is_some() {
+            dot.push_str("  }\n");
+        }
+        for edge in &self.edges {
+            let name = self
+                .data_name(edge.data)
+                .map_or_else(|| format!("{:?}", edge.data), get_short_name);
+            let style = if edge.previous_frame {
+                ", style=dashed"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "  system_{} -> system_{} [label=\"{}\"{style}];",
+                edge.producer,
+                edge.consumer,
+                escape(&name)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::schedule::{DataFlowGraph, ScheduleLabel};
+
+    #[derive(Resource, Default)]
+    struct Position(f32);
+
+    #[derive(Resource, Default)]
+    struct Unused(f32);
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    enum TestSchedule {
+        Update,
+        Render,
+    }
+
+    fn move_player(mut position: ResMut<Position>, mut unused: ResMut<Unused>) {
+        position.0 += 1.0;
+        unused.0 += 1.0;
+    }
+
+    fn follow_camera(_position: Res<Position>) {}
+
+    fn draw(_position: Res<Position>) {}
+
+    fn exclusive(_world: &mut World) {}
+
+    #[test]
+    fn data_flows_across_schedules() {
+        let mut world = World::new();
+        world.init_resource::<Position>();
+        world.init_resource::<Unused>();
+
+        let mut update = Schedule::new();
+        update.add_systems((follow_camera.before(move_player), move_player, exclusive));
+        update.initialize(&mut world).unwrap();
+        let mut render = Schedule::new();
+        render.add_system(draw);
+        render.initialize(&mut world).unwrap();
+        let mut schedules = Schedules::new();
+        schedules.insert(TestSchedule::Update, update);
+        schedules.insert(TestSchedule::Render, render);
+        world.insert_resource(schedules);
+
+        let graph = DataFlowGraph::new(
+            &world,
+            [
+                &TestSchedule::Update as &dyn ScheduleLabel,
+                &TestSchedule::Render,
+            ],
+        );
+        assert_eq!(graph.systems().len(), 3);
+
+        let system_index = |name: &str| {
+            graph
+                .systems()
+                .iter()
+                .position(|system| system.name.ends_with(name))
+                .unwrap()
+        };
+        let (mover, camera, drawer) = (
+            system_index("move_player"),
+            system_index("follow_camera"),
+            system_index("draw"),
+        );
+        let position = world.components().resource_id::<Position>().unwrap();
+        let unused = world.components().resource_id::<Unused>().unwrap();
+
+        let mut edges = graph
+            .edges_of(position)
+            .map(|edge| (edge.producer, edge.consumer, edge.previous_frame))
+            .collect::<Vec<_>>();
+        edges.sort();
+        let mut expected = vec![(mover, camera, true), (mover, drawer, false)];
+        expected.sort();
+        assert_eq!(edges, expected);
+        assert_eq!(graph.previous_frame_edges().count(), 1);
+        assert_eq!(graph.unread_writes(), &[(mover, unused)]);
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("label=\"Update\""));
+        assert!(dot.contains(&format!(
+            "system_{mover} -> system_{camera} [label=\"Position\", style=dashed];"
+        )));
+        assert!(dot.contains(&format!(
+            "system_{mover} -> system_{drawer} [label=\"Position\"];"
+        )));
+    }
+}
@@ -6,9 +6,13 @@ pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
 
+use std::{any::Any, borrow::Cow};
+
+use bevy_utils::tracing::warn;
 use fixedbitset::FixedBitSet;
 
 use crate::{
+    event::Events,
     schedule::{BoxedCondition, NodeId},
     system::BoxedSystem,
     world::World,
@@ -20,6 +24,7 @@ pub(super) trait SystemExecutor: Send + Sync {
     fn init(&mut self, schedule: &SystemSchedule);
     fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World);
     fn set_apply_final_buffers(&mut self, value: bool);
+    fn set_panic_behavior(&mut self, value: PanicBehavior);
 }
 
 /// Specifies how a [`Schedule`](super::Schedule) will be run.
@@ -43,6 +48,126 @@ pub enum ExecutorKind {
     MultiThreaded,
 }
 
+/// Specifies which threads a system may run on, see
+/// [`IntoSystemConfig::on_thread`](super::IntoSystemConfig::on_thread).
+///
+/// Only the [`MultiThreaded`](ExecutorKind::MultiThreaded) executor honors affinities, the other
+/// executors run all systems on the thread that runs the schedule. Exclusive systems and systems
+/// that access `!Send` data always run on the main thread, regardless of their affinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThreadAffinity {
+    /// Run on any thread of the [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool).
+    #[default]
+    Compute,
+    /// Run on a thread of the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool).
+    ///
+    /// A compute thread waits for the system to complete, so this is only useful to keep
+    /// latency-sensitive systems off the busy compute threads.
+    AsyncCompute,
+    /// Run on a thread of the [`IoTaskPool`](bevy_tasks::IoTaskPool).
+    ///
+    /// A compute thread waits for the system to complete, like with
+    /// [`AsyncCompute`](ThreadAffinity::AsyncCompute).
+    Io,
+    /// Run on the main thread, which runs the schedule or ticks the [`MainThreadExecutor`].
+    Main,
+}
+
+/// Specifies what a [`Schedule`](super::Schedule) does when one of its systems panics, see
+/// [`Schedule::set_panic_behavior`](super::Schedule::set_panic_behavior).
+///
+/// Except for [`Abort`](PanicBehavior::Abort), the schedule recovers from the panic and sends a
+/// [`SystemPanicked`] event describing the skipped systems. Recovering requires panics to unwind,
+/// with `panic = "abort"` the process is aborted regardless of this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PanicBehavior {
+    /// Propagates the panic out of [`Schedule::run`](super::Schedule::run), aborting the run.
+    #[default]
+    Abort,
+    /// Skips all systems of the current run that haven't started yet.
+    ///
+    /// Systems that are already running in parallel are finished.
+    SkipFrame,
+    /// Skips the systems of the current run that are ordered after the panicked system,
+    /// directly or transitively. All other systems run as usual.
+    SkipDependents,
+}
+
+/// An event sent when a system panicked and its [`Schedule`](super::Schedule) recovered
+/// according to its [`PanicBehavior`].
+///
+/// The event is only sent if the [`Events<SystemPanicked>`] resource exists, `bevy_app` adds it
+/// by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemPanicked {
+    /// The name of the system that panicked.
+    pub system: Cow<'static, str>,
+    /// The panic message, if it was a string.
+    pub message: Option<String>,
+    /// The names of the systems that were skipped because of the panic.
+    pub skipped: Vec<Cow<'static, str>>,
+}
+
+impl SystemPanicked {
+    pub(super) fn new(
+        system_index: usize,
+        message: Option<String>,
+        skipped: &FixedBitSet,
+        name: impl Fn(usize) -> Cow<'static, str>,
+    ) -> Self {
+        Self {
+            system: name(system_index),
+            message,
+            skipped: skipped.ones().map(name).collect(),
+        }
+    }
+
+    pub(super) fn send(self, world: &mut World) {
+        warn!(
+            "Recovered from a panic in system `{}`, skipped {:?}",
+            self.system, self.skipped
+        );
+        if let Some(mut events) = world.get_resource_mut::<Events<SystemPanicked>>() {
+            events.send(self);
+        }
+    }
+}
+
+/// Returns the message of a panic `payload`, if it is a string.
+pub(super) fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+/// Returns the systems the `behavior` skips because the system at `system_index` panicked,
+/// excluding the `finished` ones.
+pub(super) fn systems_to_skip<'a>(
+    behavior: PanicBehavior,
+    system_index: usize,
+    dependents: impl Fn(usize) -> &'a [usize],
+    finished: &FixedBitSet,
+) -> FixedBitSet {
+    let mut skipped = FixedBitSet::with_capacity(finished.len());
+    match behavior {
+        PanicBehavior::Abort => {}
+        PanicBehavior::SkipFrame => skipped.insert_range(..),
+        PanicBehavior::SkipDependents => {
+            let mut stack = vec![system_index];
+            while let Some(index) = stack.pop() {
+                for &dependent in dependents(index) {
+                    if !skipped.put(dependent) {
+                        stack.push(dependent);
+                    }
+                }
+            }
+        }
+    }
+    skipped.difference_with(finished);
+    skipped
+}
+
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
 /// (along with dependency information for multi-threaded execution).
 ///
@@ -52,6 +177,11 @@ pub enum ExecutorKind {
 pub struct SystemSchedule {
     pub(super) systems: Vec<BoxedSystem>,
     pub(super) system_conditions: Vec<Vec<BoxedCondition>>,
+    pub(super) system_affinities: Vec<ThreadAffinity>,
+    /// Systems that are retired after their first run.
+    pub(super) run_once_systems: FixedBitSet,
+    /// `run_once` systems that have run and are skipped without evaluating their conditions.
+    pub(super) retired_systems: FixedBitSet,
     pub(super) set_conditions: Vec<Vec<BoxedCondition>>,
     pub(super) system_ids: Vec<NodeId>,
     pub(super) set_ids: Vec<NodeId>,
@@ -59,6 +189,7 @@ pub struct SystemSchedule {
     pub(super) system_dependents: Vec<Vec<usize>>,
     pub(super) sets_with_conditions_of_systems: Vec<FixedBitSet>,
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    pub(super) sets_with_propagated_conditions: FixedBitSet,
 }
 
 impl SystemSchedule {
@@ -66,6 +197,9 @@ impl SystemSchedule {
         Self {
             systems: Vec::new(),
             system_conditions: Vec::new(),
+            system_affinities: Vec::new(),
+            run_once_systems: FixedBitSet::new(),
+            retired_systems: FixedBitSet::new(),
             set_conditions: Vec::new(),
             system_ids: Vec::new(),
             set_ids: Vec::new(),
@@ -73,6 +207,7 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            sets_with_propagated_conditions: FixedBitSet::new(),
         }
     }
 }
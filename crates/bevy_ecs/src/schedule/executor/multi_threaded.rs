@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
-use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
+use bevy_tasks::{
+    AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, Scope, TaskPool, ThreadExecutor,
+};
 use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Instrument};
-use std::panic::AssertUnwindSafe;
+use std::{any::Any, panic::AssertUnwindSafe};
 
 use async_channel::{Receiver, Sender};
 use fixedbitset::FixedBitSet;
@@ -15,7 +17,8 @@ use crate::{
     prelude::Resource,
     query::Access,
     schedule::{
-        is_apply_system_buffers, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        is_apply_system_buffers, panic_message, systems_to_skip, BoxedCondition, ExecutorKind,
+        PanicBehavior, SystemExecutor, SystemPanicked, SystemSchedule, ThreadAffinity,
     },
     system::BoxedSystem,
     world::World,
@@ -23,6 +26,15 @@ use crate::{
 
 use crate as bevy_ecs;
 
+/// Scopes of the task pools that systems can be pinned to with [`ThreadAffinity`], which are
+/// opened around the scope of the executor so their systems run on threads of their own pool.
+///
+/// A scope is only opened if a system of the schedule is pinned to its pool.
+struct PinnedScopes<'a, 'b, 'env> {
+    async_compute: Option<&'a Scope<'a, 'env, ()>>,
+    io: Option<&'b Scope<'b, 'env, ()>>,
+}
+
 /// A funky borrow split of [`SystemSchedule`] required by the [`MultiThreadedExecutor`].
 struct SyncUnsafeSchedule<'a> {
     systems: &'a [SyncUnsafeCell<BoxedSystem>],
@@ -34,6 +46,9 @@ struct Conditions<'a> {
     set_conditions: &'a mut [Vec<BoxedCondition>],
     sets_with_conditions_of_systems: &'a [FixedBitSet],
     systems_in_sets_with_conditions: &'a [FixedBitSet],
+    sets_with_propagated_conditions: &'a FixedBitSet,
+    run_once_systems: &'a FixedBitSet,
+    retired_systems: &'a mut FixedBitSet,
 }
 
 impl SyncUnsafeSchedule<'_> {
@@ -45,6 +60,9 @@ impl SyncUnsafeSchedule<'_> {
                 set_conditions: &mut schedule.set_conditions,
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
+                sets_with_propagated_conditions: &schedule.sets_with_propagated_conditions,
+                run_once_systems: &schedule.run_once_systems,
+                retired_systems: &mut schedule.retired_systems,
             },
         }
     }
@@ -61,14 +79,23 @@ struct SystemTaskMetadata {
     is_send: bool,
     /// Is `true` if the system is exclusive.
     is_exclusive: bool,
+    /// The threads the system should run on.
+    affinity: ThreadAffinity,
+}
+
+/// The outcome of a system task, sent to the [`MultiThreadedExecutor`].
+struct SystemResult {
+    system_index: usize,
+    /// The panic payload, if the system panicked.
+    panic: Option<Box<dyn Any + Send>>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
 pub struct MultiThreadedExecutor {
     /// Sends system completion events.
-    sender: Sender<usize>,
+    sender: Sender<SystemResult>,
     /// Receives system completion events.
-    receiver: Receiver<usize>,
+    receiver: Receiver<SystemResult>,
     /// Metadata for scheduling and running system tasks.
     system_task_metadata: Vec<SystemTaskMetadata>,
     /// Union of the accesses of all currently running systems.
@@ -99,6 +126,10 @@ pub struct MultiThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies system buffers after all systems have run
     apply_final_buffers: bool,
+    /// What to do when a system panics.
+    panic_behavior: PanicBehavior,
+    /// Systems that panicked during this run, with the systems skipped because of them.
+    panicked_systems: Vec<(usize, Option<String>, FixedBitSet)>,
 }
 
 impl Default for MultiThreadedExecutor {
@@ -116,6 +147,10 @@ impl SystemExecutor for MultiThreadedExecutor {
         self.apply_final_buffers = value;
     }
 
+    fn set_panic_behavior(&mut self, value: PanicBehavior) {
+        self.panic_behavior = value;
+    }
+
     fn init(&mut self, schedule: &SystemSchedule) {
         // pre-allocate space
         let sys_count = schedule.system_ids.len();
@@ -140,6 +175,7 @@ impl SystemExecutor for MultiThreadedExecutor {
                 dependents: schedule.system_dependents[index].clone(),
                 is_send: schedule.systems[index].is_send(),
                 is_exclusive: schedule.systems[index].is_exclusive(),
+                affinity: schedule.system_affinities[index],
             });
         }
 
@@ -175,44 +211,39 @@ impl SystemExecutor for MultiThreadedExecutor {
             mut conditions,
         } = SyncUnsafeSchedule::new(schedule);
 
-        ComputeTaskPool::init(TaskPool::default).scope_with_executor(
-            false,
-            thread_executor,
-            |scope| {
-                // the executor itself is a `Send` future so that it can run
-                // alongside systems that claim the local thread
-                let executor = async {
-                    while self.num_completed_systems < num_systems {
-                        // SAFETY: self.ready_systems does not contain running systems
-                        unsafe {
-                            self.spawn_system_tasks(scope, systems, &mut conditions, world);
-                        }
-
-                        if self.num_running_systems > 0 {
-                            // wait for systems to complete
-                            let index =
-                                self.receiver.recv().await.expect(
-                                    "A system has panicked so the executor cannot continue.",
-                                );
-
-                            self.finish_system_and_signal_dependents(index);
-
-                            while let Ok(index) = self.receiver.try_recv() {
-                                self.finish_system_and_signal_dependents(index);
-                            }
-
-                            self.rebuild_active_access();
-                        }
-                    }
-                };
-
-                #[cfg(feature = "trace")]
-                let executor_span = info_span!("multithreaded executor");
-                #[cfg(feature = "trace")]
-                let executor = executor.instrument(executor_span);
-                scope.spawn(executor);
-            },
-        );
+        // Systems pinned to another pool are spawned on a scope of that pool, so they don't
+        // occupy a thread of the compute pool while they run.
+        let is_pinned = |affinity| {
+            self.system_task_metadata
+                .iter()
+                .any(|meta| meta.affinity == affinity)
+        };
+        let async_compute_pool = is_pinned(ThreadAffinity::AsyncCompute)
+            .then(|| &**AsyncComputeTaskPool::init(TaskPool::default));
+        let io_pool = is_pinned(ThreadAffinity::Io).then(|| &**IoTaskPool::init(TaskPool::default));
+        pinned_scope(async_compute_pool, |async_compute| {
+            pinned_scope(io_pool, |io| {
+                let pinned = PinnedScopes { async_compute, io };
+                ComputeTaskPool::init(TaskPool::default).scope_with_executor(
+                    false,
+                    thread_executor,
+                    |scope| {
+                        // the executor itself is a `Send` future so that it can run
+                        // alongside systems that claim the local thread
+                        // SAFETY: the world and the systems are only accessed by the executor
+                        let executor = unsafe {
+                            self.run_systems(scope, &pinned, systems, &mut conditions, world)
+                        };
+
+                        #[cfg(feature = "trace")]
+                        let executor_span = info_span!("multithreaded executor");
+                        #[cfg(feature = "trace")]
+                        let executor = executor.instrument(executor_span);
+                        scope.spawn(executor);
+                    },
+                );
+            });
+        });
 
         if self.apply_final_buffers {
             // Do one final apply buffers after all systems have completed
@@ -224,6 +255,18 @@ impl SystemExecutor for MultiThreadedExecutor {
             debug_assert!(self.unapplied_systems.is_clear());
         }
 
+        if !self.panicked_systems.is_empty() {
+            // SAFETY: all systems have completed, and so no outstanding accesses remain
+            let world = unsafe { &mut *world.get() };
+            for (system_index, message, skipped) in self.panicked_systems.drain(..) {
+                SystemPanicked::new(system_index, message, &skipped, |index| {
+                    // SAFETY: all systems have completed, no other reference exists
+                    unsafe { &*systems[index].get() }.name()
+                })
+                .send(world);
+            }
+        }
+
         debug_assert!(self.ready_systems.is_clear());
         debug_assert!(self.running_systems.is_clear());
         self.active_access.clear();
@@ -254,18 +297,59 @@ impl MultiThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_buffers: true,
+            panic_behavior: PanicBehavior::Abort,
+            panicked_systems: Vec::new(),
+        }
+    }
+
+    /// Runs the systems as their dependencies complete, until all of them have run.
+    ///
+    /// # Safety
+    /// Caller must ensure that nothing else accesses the world or the systems while the returned
+    /// future runs.
+    async unsafe fn run_systems<'scope, 'env: 'scope>(
+        &mut self,
+        scope: &Scope<'_, 'scope, ()>,
+        pinned: &PinnedScopes<'_, '_, 'env>,
+        systems: &'env [SyncUnsafeCell<BoxedSystem>],
+        conditions: &mut Conditions<'_>,
+        world: &'env SyncUnsafeCell<World>,
+    ) {
+        while self.num_completed_systems < systems.len() {
+            // SAFETY: self.ready_systems does not contain running systems
+            unsafe {
+                self.spawn_system_tasks(scope, pinned, systems, conditions, world);
+            }
+
+            if self.num_running_systems > 0 {
+                // wait for systems to complete
+                let result = self
+                    .receiver
+                    .recv()
+                    .await
+                    .expect("A system has panicked so the executor cannot continue.");
+
+                self.finish_system_and_signal_dependents(result);
+
+                while let Ok(result) = self.receiver.try_recv() {
+                    self.finish_system_and_signal_dependents(result);
+                }
+
+                self.rebuild_active_access();
+            }
         }
     }
 
     /// # Safety
     /// Caller must ensure that `self.ready_systems` does not contain any systems that
     /// have been mutably borrowed (such as the systems currently running).
-    unsafe fn spawn_system_tasks<'scope>(
+    unsafe fn spawn_system_tasks<'scope, 'env: 'scope>(
         &mut self,
         scope: &Scope<'_, 'scope, ()>,
-        systems: &'scope [SyncUnsafeCell<BoxedSystem>],
+        pinned: &PinnedScopes<'_, '_, 'env>,
+        systems: &'env [SyncUnsafeCell<BoxedSystem>],
         conditions: &mut Conditions,
-        cell: &'scope SyncUnsafeCell<World>,
+        cell: &'env SyncUnsafeCell<World>,
     ) {
         if self.exclusive_running {
             return;
@@ -300,6 +384,10 @@ impl MultiThreadedExecutor {
                 continue;
             }
 
+            if conditions.run_once_systems.contains(system_index) {
+                conditions.retired_systems.insert(system_index);
+            }
+
             self.running_systems.insert(system_index);
             self.num_running_systems += 1;
 
@@ -315,7 +403,7 @@ impl MultiThreadedExecutor {
 
             // SAFETY: No other reference to this system exists.
             unsafe {
-                self.spawn_system_task(scope, system_index, systems, world);
+                self.spawn_system_task(scope, pinned, system_index, systems, world);
             }
         }
 
@@ -330,6 +418,11 @@ impl MultiThreadedExecutor {
         conditions: &mut Conditions,
         world: &World,
     ) -> bool {
+        if conditions.retired_systems.contains(system_index) {
+            // retired systems are skipped without accessing anything
+            return true;
+        }
+
         let system_meta = &self.system_task_metadata[system_index];
         if system_meta.is_exclusive && self.num_running_systems > 0 {
             return false;
@@ -390,6 +483,10 @@ impl MultiThreadedExecutor {
         conditions: &mut Conditions,
         world: &World,
     ) -> bool {
+        if conditions.retired_systems.contains(system_index) {
+            return false;
+        }
+
         let mut should_run = !self.skipped_systems.contains(system_index);
         for set_idx in conditions.sets_with_conditions_of_systems[system_index].ones() {
             if self.evaluated_sets.contains(set_idx) {
@@ -400,6 +497,12 @@ impl MultiThreadedExecutor {
             let set_conditions_met =
                 evaluate_and_fold_conditions(&mut conditions.set_conditions[set_idx], world);
 
+            if conditions.sets_with_propagated_conditions.contains(set_idx) {
+                // propagated conditions are evaluated again for every system in the set
+                should_run &= set_conditions_met;
+                continue;
+            }
+
             if !set_conditions_met {
                 self.skipped_systems
                     .union_with(&conditions.systems_in_sets_with_conditions[set_idx]);
@@ -424,12 +527,13 @@ impl MultiThreadedExecutor {
 
     /// # Safety
     /// Caller must not alias systems that are running.
-    unsafe fn spawn_system_task<'scope>(
+    unsafe fn spawn_system_task<'scope, 'env: 'scope>(
         &mut self,
         scope: &Scope<'_, 'scope, ()>,
+        pinned: &PinnedScopes<'_, '_, 'env>,
         system_index: usize,
-        systems: &'scope [SyncUnsafeCell<BoxedSystem>],
-        world: &'scope World,
+        systems: &'env [SyncUnsafeCell<BoxedSystem>],
+        world: &'env World,
     ) {
         // SAFETY: this system is not running, no other reference exists
         let system = unsafe { &mut *systems[system_index].get() };
@@ -440,6 +544,7 @@ impl MultiThreadedExecutor {
         let system_span = info_span!("system", name = &*system.name());
 
         let sender = self.sender.clone();
+        let panic_behavior = self.panic_behavior;
         let task = async move {
             #[cfg(feature = "trace")]
             let system_guard = system_span.enter();
@@ -449,15 +554,7 @@ impl MultiThreadedExecutor {
             }));
             #[cfg(feature = "trace")]
             drop(system_guard);
-            if res.is_err() {
-                // close the channel to propagate the error to the
-                // multithreaded executor
-                sender.close();
-            } else {
-                sender
-                    .try_send(system_index)
-                    .unwrap_or_else(|error| unreachable!("{}", error));
-            }
+            send_result(&sender, system_index, res, panic_behavior);
         };
 
         #[cfg(feature = "trace")]
@@ -467,11 +564,23 @@ impl MultiThreadedExecutor {
         self.active_access
             .extend(&system_meta.archetype_component_access);
 
-        if system_meta.is_send {
-            scope.spawn(task);
-        } else {
+        if !system_meta.is_send {
             self.local_thread_running = true;
             scope.spawn_on_external(task);
+            return;
+        }
+
+        match system_meta.affinity {
+            ThreadAffinity::Compute => scope.spawn(task),
+            ThreadAffinity::AsyncCompute => pinned
+                .async_compute
+                .expect("a scope is opened for pools with pinned systems")
+                .spawn(task),
+            ThreadAffinity::Io => pinned
+                .io
+                .expect("a scope is opened for pools with pinned systems")
+                .spawn(task),
+            ThreadAffinity::Main => scope.spawn_on_external(task),
         }
     }
 
@@ -493,6 +602,7 @@ impl MultiThreadedExecutor {
         let system_span = info_span!("system", name = &*system.name());
 
         let sender = self.sender.clone();
+        let panic_behavior = self.panic_behavior;
         if is_apply_system_buffers(system) {
             // TODO: avoid allocation
             let unapplied_systems = self.unapplied_systems.clone();
@@ -505,15 +615,7 @@ impl MultiThreadedExecutor {
                 }));
                 #[cfg(feature = "trace")]
                 drop(system_guard);
-                if res.is_err() {
-                    // close the channel to propagate the error to the
-                    // multithreaded executor
-                    sender.close();
-                } else {
-                    sender
-                        .try_send(system_index)
-                        .unwrap_or_else(|error| unreachable!("{}", error));
-                }
+                send_result(&sender, system_index, res, panic_behavior);
             };
 
             #[cfg(feature = "trace")]
@@ -528,15 +630,7 @@ impl MultiThreadedExecutor {
                 }));
                 #[cfg(feature = "trace")]
                 drop(system_guard);
-                if res.is_err() {
-                    // close the channel to propagate the error to the
-                    // multithreaded executor
-                    sender.close();
-                } else {
-                    sender
-                        .try_send(system_index)
-                        .unwrap_or_else(|error| unreachable!("{}", error));
-                }
+                send_result(&sender, system_index, res, panic_behavior);
             };
 
             #[cfg(feature = "trace")]
@@ -548,7 +642,8 @@ impl MultiThreadedExecutor {
         self.local_thread_running = true;
     }
 
-    fn finish_system_and_signal_dependents(&mut self, system_index: usize) {
+    fn finish_system_and_signal_dependents(&mut self, result: SystemResult) {
+        let system_index = result.system_index;
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
         }
@@ -563,9 +658,30 @@ impl MultiThreadedExecutor {
         self.running_systems.set(system_index, false);
         self.completed_systems.insert(system_index);
         self.unapplied_systems.insert(system_index);
+        if let Some(payload) = result.panic {
+            self.recover_from_panic(system_index, payload);
+        }
         self.signal_dependents(system_index);
     }
 
+    /// Skips the systems the panic behavior requires, the buffers of the panicked system are
+    /// not applied.
+    fn recover_from_panic(&mut self, system_index: usize, payload: Box<dyn Any + Send>) {
+        self.unapplied_systems.set(system_index, false);
+        let mut finished = self.completed_systems.clone();
+        finished.union_with(&self.running_systems);
+        finished.union_with(&self.skipped_systems);
+        let skipped = systems_to_skip(
+            self.panic_behavior,
+            system_index,
+            |index| &self.system_task_metadata[index].dependents,
+            &finished,
+        );
+        self.skipped_systems.union_with(&skipped);
+        self.panicked_systems
+            .push((system_index, panic_message(&*payload), skipped));
+    }
+
     fn skip_system_and_signal_dependents(&mut self, system_index: usize) {
         self.num_completed_systems += 1;
         self.completed_systems.insert(system_index);
@@ -605,6 +721,45 @@ fn apply_system_buffers(
     }
 }
 
+/// Reports the outcome of the system at `system_index` to the executor.
+fn send_result(
+    sender: &Sender<SystemResult>,
+    system_index: usize,
+    res: std::thread::Result<()>,
+    panic_behavior: PanicBehavior,
+) {
+    let panic = match res {
+        Ok(()) => None,
+        Err(_) if panic_behavior == PanicBehavior::Abort => {
+            // close the channel to propagate the error to the
+            // multithreaded executor
+            sender.close();
+            return;
+        }
+        Err(payload) => Some(payload),
+    };
+    sender
+        .try_send(SystemResult {
+            system_index,
+            panic,
+        })
+        .unwrap_or_else(|error| unreachable!("{}", error));
+}
+
+/// Runs `f` with a scope of `pool`, or with `None` if there is no pool.
+fn pinned_scope<'env>(
+    pool: Option<&TaskPool>,
+    f: impl for<'scope> FnOnce(Option<&'scope Scope<'scope, 'env, ()>>),
+) {
+    match pool {
+        // don't tick the pool's executor here, or pinned systems could run on this thread
+        Some(pool) => {
+            pool.scope_with_executor(false, None, |scope| f(Some(scope)));
+        }
+        None => f(None),
+    }
+}
+
 fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &World) -> bool {
     // not short-circuiting is intentional
     #[allow(clippy::unnecessary_fold)]
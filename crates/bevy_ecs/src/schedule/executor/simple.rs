@@ -1,9 +1,13 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
 use fixedbitset::FixedBitSet;
+use std::panic::AssertUnwindSafe;
 
 use crate::{
-    schedule::{BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        panic_message, systems_to_skip, BoxedCondition, ExecutorKind, PanicBehavior,
+        SystemExecutor, SystemPanicked, SystemSchedule,
+    },
     world::World,
 };
 
@@ -15,6 +19,8 @@ pub struct SimpleExecutor {
     evaluated_sets: FixedBitSet,
     /// Systems that have run or been skipped.
     completed_systems: FixedBitSet,
+    /// What to do when a system panics.
+    panic_behavior: PanicBehavior,
 }
 
 impl SystemExecutor for SimpleExecutor {
@@ -26,6 +32,10 @@ impl SystemExecutor for SimpleExecutor {
         // do nothing. simple executor does not do a final sync
     }
 
+    fn set_panic_behavior(&mut self, panic_behavior: PanicBehavior) {
+        self.panic_behavior = panic_behavior;
+    }
+
     fn init(&mut self, schedule: &SystemSchedule) {
         let sys_count = schedule.system_ids.len();
         let set_count = schedule.set_ids.len();
@@ -35,6 +45,11 @@ impl SystemExecutor for SimpleExecutor {
 
     fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
         for system_index in 0..schedule.systems.len() {
+            if schedule.retired_systems.contains(system_index) {
+                self.completed_systems.insert(system_index);
+                continue;
+            }
+
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
             #[cfg(feature = "trace")]
@@ -50,6 +65,12 @@ impl SystemExecutor for SimpleExecutor {
                 let set_conditions_met =
                     evaluate_and_fold_conditions(&mut schedule.set_conditions[set_idx], world);
 
+                if schedule.sets_with_propagated_conditions.contains(set_idx) {
+                    // propagated conditions are evaluated again for every system in the set
+                    should_run &= set_conditions_met;
+                    continue;
+                }
+
                 if !set_conditions_met {
                     self.completed_systems
                         .union_with(&schedule.systems_in_sets_with_conditions[set_idx]);
@@ -75,14 +96,44 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            if schedule.run_once_systems.contains(system_index) {
+                schedule.retired_systems.insert(system_index);
+            }
+
             let system = &mut schedule.systems[system_index];
             #[cfg(feature = "trace")]
             let system_span = info_span!("system", name = &*name).entered();
-            system.run((), world);
+            let res = if self.panic_behavior == PanicBehavior::Abort {
+                system.run((), world);
+                Ok(())
+            } else {
+                std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    system.run((), world);
+                }))
+            };
             #[cfg(feature = "trace")]
             system_span.exit();
 
-            system.apply_buffers(world);
+            match res {
+                Ok(()) => system.apply_buffers(world),
+                Err(payload) => {
+                    // the buffers of the panicked system are not applied
+                    let skipped = systems_to_skip(
+                        self.panic_behavior,
+                        system_index,
+                        |index| &schedule.system_dependents[index],
+                        &self.completed_systems,
+                    );
+                    self.completed_systems.union_with(&skipped);
+                    SystemPanicked::new(
+                        system_index,
+                        panic_message(&*payload),
+                        &skipped,
+                        |index| schedule.systems[index].name(),
+                    )
+                    .send(world);
+                }
+            }
         }
 
         self.evaluated_sets.clear();
@@ -95,6 +146,7 @@ impl SimpleExecutor {
         Self {
             evaluated_sets: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
+            panic_behavior: PanicBehavior::Abort,
         }
     }
 }
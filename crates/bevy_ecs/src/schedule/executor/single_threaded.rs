@@ -1,10 +1,12 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
 use fixedbitset::FixedBitSet;
+use std::{any::Any, panic::AssertUnwindSafe};
 
 use crate::{
     schedule::{
-        is_apply_system_buffers, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        is_apply_system_buffers, panic_message, systems_to_skip, BoxedCondition, ExecutorKind,
+        PanicBehavior, SystemExecutor, SystemPanicked, SystemSchedule,
     },
     world::World,
 };
@@ -23,6 +25,8 @@ pub struct SingleThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies system buffers after all systems have run
     apply_final_buffers: bool,
+    /// What to do when a system panics.
+    panic_behavior: PanicBehavior,
 }
 
 impl SystemExecutor for SingleThreadedExecutor {
@@ -34,6 +38,10 @@ impl SystemExecutor for SingleThreadedExecutor {
         self.apply_final_buffers = apply_final_buffers;
     }
 
+    fn set_panic_behavior(&mut self, panic_behavior: PanicBehavior) {
+        self.panic_behavior = panic_behavior;
+    }
+
     fn init(&mut self, schedule: &SystemSchedule) {
         // pre-allocate space
         let sys_count = schedule.system_ids.len();
@@ -45,6 +53,11 @@ impl SystemExecutor for SingleThreadedExecutor {
 
     fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
         for system_index in 0..schedule.systems.len() {
+            if schedule.retired_systems.contains(system_index) {
+                self.completed_systems.insert(system_index);
+                continue;
+            }
+
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
             #[cfg(feature = "trace")]
@@ -60,6 +73,12 @@ impl SystemExecutor for SingleThreadedExecutor {
                 let set_conditions_met =
                     evaluate_and_fold_conditions(&mut schedule.set_conditions[set_idx], world);
 
+                if schedule.sets_with_propagated_conditions.contains(set_idx) {
+                    // propagated conditions are evaluated again for every system in the set
+                    should_run &= set_conditions_met;
+                    continue;
+                }
+
                 if !set_conditions_met {
                     self.completed_systems
                         .union_with(&schedule.systems_in_sets_with_conditions[set_idx]);
@@ -85,6 +104,10 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            if schedule.run_once_systems.contains(system_index) {
+                schedule.retired_systems.insert(system_index);
+            }
+
             let system = &mut schedule.systems[system_index];
             if is_apply_system_buffers(system) {
                 #[cfg(feature = "trace")]
@@ -95,10 +118,22 @@ impl SystemExecutor for SingleThreadedExecutor {
             } else {
                 #[cfg(feature = "trace")]
                 let system_span = info_span!("system", name = &*name).entered();
-                system.run((), world);
+                let res = if self.panic_behavior == PanicBehavior::Abort {
+                    system.run((), world);
+                    Ok(())
+                } else {
+                    std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        system.run((), world);
+                    }))
+                };
                 #[cfg(feature = "trace")]
                 system_span.exit();
-                self.unapplied_systems.insert(system_index);
+                match res {
+                    Ok(()) => {
+                        self.unapplied_systems.insert(system_index);
+                    }
+                    Err(payload) => self.recover_from_panic(schedule, world, system_index, payload),
+                }
             }
         }
 
@@ -117,9 +152,32 @@ impl SingleThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_buffers: true,
+            panic_behavior: PanicBehavior::Abort,
         }
     }
 
+    /// Skips the systems the panic behavior requires, the buffers of the panicked system are
+    /// not applied.
+    fn recover_from_panic(
+        &mut self,
+        schedule: &SystemSchedule,
+        world: &mut World,
+        system_index: usize,
+        payload: Box<dyn Any + Send>,
+    ) {
+        let skipped = systems_to_skip(
+            self.panic_behavior,
+            system_index,
+            |index| &schedule.system_dependents[index],
+            &self.completed_systems,
+        );
+        self.completed_systems.union_with(&skipped);
+        SystemPanicked::new(system_index, panic_message(&*payload), &skipped, |index| {
+            schedule.systems[index].name()
+        })
+        .send(world);
+    }
+
     fn apply_system_buffers(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
         for system_index in self.unapplied_systems.ones() {
             let system = &mut schedule.systems[system_index];
@@ -41,6 +41,10 @@ pub(crate) enum DependencyKind {
     Before,
     /// A node that should be succeeded.
     After,
+    /// A node that should be preceded, with system buffers applied in between.
+    BeforeAndFlush,
+    /// A node that should be succeeded, with system buffers applied in between.
+    AfterAndFlush,
 }
 
 /// An edge to be added to the dependency graph.
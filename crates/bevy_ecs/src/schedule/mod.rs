@@ -1,5 +1,6 @@
 mod condition;
 mod config;
+mod data_flow;
 mod executor;
 mod graph_utils;
 #[allow(clippy::module_inception)]
@@ -9,6 +10,7 @@ mod state;
 
 pub use self::condition::*;
 pub use self::config::*;
+pub use self::data_flow::*;
 pub use self::executor::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
@@ -115,6 +117,111 @@ mod tests {
 
             schedule.run(&mut world);
         }
+
+        #[test]
+        #[cfg(not(miri))]
+        fn thread_affinity() {
+            use bevy_tasks::{AsyncComputeTaskPool, TaskPoolBuilder};
+            use std::sync::Mutex;
+            use std::thread::{self, ThreadId};
+
+            #[derive(Resource, Default)]
+            struct Threads(Mutex<Vec<(u32, ThreadId, Option<String>)>>);
+
+            fn record(tag: u32) -> impl FnMut(Res<Threads>) {
+                move |threads: Res<Threads>| {
+                    let thread = thread::current();
+                    let name = thread.name().map(str::to_string);
+                    threads.0.lock().unwrap().push((tag, thread.id(), name));
+                }
+            }
+
+            AsyncComputeTaskPool::init(|| {
+                TaskPoolBuilder::new()
+                    .num_threads(1)
+                    .thread_name("AsyncComputeTaskPool".to_string())
+                    .build()
+            });
+
+            let mut world = World::default();
+            world.init_resource::<Threads>();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+            schedule.add_systems((
+                record(0).on_thread(ThreadAffinity::Main),
+                record(1).on_thread(ThreadAffinity::AsyncCompute),
+            ));
+            schedule.run(&mut world);
+
+            let threads = world.resource::<Threads>().0.lock().unwrap();
+            assert_eq!(threads.len(), 2);
+            for (tag, id, name) in threads.iter() {
+                match tag {
+                    0 => assert_eq!(*id, thread::current().id()),
+                    _ => assert!(name.as_ref().unwrap().starts_with("AsyncComputeTaskPool")),
+                }
+            }
+        }
+
+        #[test]
+        fn panic_behavior() {
+            use crate::event::Events;
+
+            fn first(mut order: ResMut<SystemOrder>) {
+                order.0.push(0);
+            }
+            fn panicking(_: ResMut<SystemOrder>) {
+                panic!("boom");
+            }
+            fn dependent(mut order: ResMut<SystemOrder>) {
+                order.0.push(1);
+            }
+            fn transitive_dependent(mut order: ResMut<SystemOrder>) {
+                order.0.push(2);
+            }
+            fn unrelated(mut order: ResMut<SystemOrder>) {
+                order.0.push(3);
+            }
+
+            for behavior in [PanicBehavior::SkipFrame, PanicBehavior::SkipDependents] {
+                for executor in [
+                    ExecutorKind::SingleThreaded,
+                    ExecutorKind::Simple,
+                    ExecutorKind::MultiThreaded,
+                ] {
+                    let mut world = World::default();
+                    world.init_resource::<SystemOrder>();
+                    world.init_resource::<Events<SystemPanicked>>();
+
+                    let mut schedule = Schedule::default();
+                    schedule
+                        .set_executor_kind(executor)
+                        .set_panic_behavior(behavior)
+                        .add_systems((first, panicking, dependent, transitive_dependent).chain())
+                        .add_system(unrelated.after(first));
+                    schedule.run(&mut world);
+
+                    let mut ran = world.resource::<SystemOrder>().0.clone();
+                    ran.sort();
+                    let events: Vec<_> = world
+                        .resource_mut::<Events<SystemPanicked>>()
+                        .drain()
+                        .collect();
+                    assert_eq!(events.len(), 1);
+                    assert!(events[0].system.ends_with("panicking"));
+                    assert_eq!(events[0].message.as_deref(), Some("boom"));
+
+                    if behavior == PanicBehavior::SkipDependents {
+                        assert_eq!(ran, vec![0, 3]);
+                        assert_eq!(events[0].skipped.len(), 2);
+                    } else {
+                        // whether `unrelated` ran before the panic depends on the executor
+                        assert!(ran == vec![0] || ran == vec![0, 3]);
+                        assert_eq!(ran.len() + events[0].skipped.len(), 4);
+                    }
+                }
+            }
+        }
     }
 
     mod system_ordering {
@@ -158,6 +265,34 @@ mod tests {
             );
         }
 
+        #[test]
+        fn order_parameterized_sets() {
+            #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+            enum Step {
+                Substep(u8),
+                Player { id: u32 },
+            }
+
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+
+            schedule.configure_sets((Step::Substep(0), Step::Substep(1), Step::Substep(2)).chain());
+            schedule.configure_set(Step::Player { id: 7 }.after(Step::Substep(2)));
+            schedule.configure_set(Step::Player { id: 3 }.before(Step::Substep(0)));
+            schedule.add_systems((
+                make_function_system(4).in_set(Step::Player { id: 7 }),
+                make_function_system(2).in_set(Step::Substep(1)),
+                make_function_system(3).in_set(Step::Substep(2)),
+                make_function_system(1).in_set(Step::Substep(0)),
+                make_function_system(0).in_set(Step::Player { id: 3 }),
+            ));
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 2, 3, 4]);
+        }
+
         #[test]
         fn order_exclusive_systems() {
             let mut world = World::default();
@@ -195,6 +330,172 @@ mod tests {
             schedule.run(&mut world);
             assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 2, 3]);
         }
+
+        #[test]
+        fn merge_schedule() {
+            let mut world = World::new();
+            world.init_resource::<SystemOrder>();
+
+            let mut standalone = Schedule::new();
+            standalone
+                .add_systems((make_function_system(1), make_function_system(2)).chain())
+                .add_system(make_function_system(4).run_if(|| false))
+                .configure_set(TestSet::B.run_if(|| true))
+                .add_system(
+                    make_exclusive_system(5)
+                        .in_set(TestSet::B)
+                        .after(TestSet::A),
+                );
+
+            // the standalone schedule can be run before merging it
+            standalone.run(&mut world);
+            let mut order = world.resource::<SystemOrder>().0.clone();
+            order.sort();
+            assert_eq!(order, vec![1, 2, 5]);
+            world.insert_resource(SystemOrder::default());
+
+            let mut schedule = Schedule::new();
+            schedule
+                .add_system(make_function_system(0).in_set(TestSet::A))
+                .add_system(make_function_system(3).in_set(TestSet::C))
+                .merge(TestSet::X, standalone)
+                .configure_set(TestSet::X.after(TestSet::A).before(TestSet::C));
+            schedule.run(&mut world);
+
+            let order = &world.resource::<SystemOrder>().0;
+            assert_eq!(order.len(), 5);
+            assert_eq!(order[0], 0);
+            assert_eq!(order[4], 3);
+            assert!(order.contains(&1) && order.contains(&2) && order.contains(&5));
+            assert!(
+                order.iter().position(|&tag| tag == 1) < order.iter().position(|&tag| tag == 2)
+            );
+
+            // `TestSet::B` is nested in the namespace, and its system isn't added to it directly
+            let graph = schedule.graph();
+            let set_id = |set: TestSet| {
+                graph
+                    .system_sets()
+                    .find(|(_, s, _, _)| *s == &set as &dyn SystemSet)
+                    .unwrap()
+                    .0
+            };
+            let hierarchy = graph.hierarchy().graph();
+            assert!(hierarchy.contains_edge(set_id(TestSet::X), set_id(TestSet::B)));
+            assert!(!hierarchy.contains_edge(set_id(TestSet::X), set_id(TestSet::A)));
+            assert_eq!(
+                hierarchy
+                    .neighbors_directed(
+                        set_id(TestSet::X),
+                        bevy_utils::petgraph::Direction::Outgoing
+                    )
+                    .count(),
+                4
+            );
+        }
+    }
+
+    mod auto_insert_apply_buffers {
+        use super::*;
+        use crate::{component::Component, system::Commands, world::World};
+
+        #[derive(Component)]
+        struct Marker;
+
+        #[derive(Resource, Default)]
+        struct Seen(Vec<usize>);
+
+        fn spawn(mut commands: Commands) {
+            commands.spawn(Marker);
+        }
+
+        fn count(world: &mut World) {
+            let count = world.query::<&Marker>().iter(world).count();
+            world.resource_mut::<Seen>().0.push(count);
+        }
+
+        fn sync_points(schedule: &Schedule) -> usize {
+            schedule.graph().auto_sync_points().len()
+        }
+
+        #[test]
+        fn flush_between_systems() {
+            for executor in [
+                ExecutorKind::SingleThreaded,
+                ExecutorKind::Simple,
+                ExecutorKind::MultiThreaded,
+            ] {
+                let mut world = World::new();
+                world.init_resource::<Seen>();
+
+                let mut schedule = Schedule::new();
+                schedule.set_executor_kind(executor);
+                schedule.add_systems((spawn, count.after_and_flush(spawn)));
+                schedule.run(&mut world);
+
+                assert_eq!(world.resource::<Seen>().0, vec![1]);
+                assert_eq!(sync_points(&schedule), 1);
+            }
+        }
+
+        #[test]
+        fn flush_between_sets() {
+            let mut world = World::new();
+            world.init_resource::<Seen>();
+
+            let mut schedule = Schedule::new();
+            schedule.configure_set(TestSet::A.before_and_flush(TestSet::B));
+            schedule.add_systems((spawn.in_set(TestSet::A), count.in_set(TestSet::B)));
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<Seen>().0, vec![1]);
+        }
+
+        #[test]
+        fn share_sync_points() {
+            fn spawn_again(mut commands: Commands) {
+                commands.spawn(Marker);
+            }
+
+            let mut world = World::new();
+            world.init_resource::<Seen>();
+            world.init_resource::<SystemOrder>();
+
+            let mut schedule = Schedule::new();
+            schedule.add_systems((
+                spawn,
+                spawn_again,
+                count.after_and_flush(spawn),
+                make_function_system(0).after_and_flush(spawn_again),
+            ));
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<Seen>().0, vec![2]);
+            assert_eq!(sync_points(&schedule), 1);
+
+            // a system that depends on a flush after `count` needs a second sync point
+            schedule.add_system(make_function_system(1).after_and_flush(count));
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<Seen>().0, vec![2, 4]);
+            assert_eq!(sync_points(&schedule), 2);
+        }
+
+        #[test]
+        fn disable_auto_insert() {
+            let mut world = World::new();
+            world.init_resource::<Seen>();
+
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                auto_insert_apply_buffers: false,
+                ..Default::default()
+            });
+            schedule.add_systems((spawn, count.after_and_flush(spawn)));
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<Seen>().0, vec![0]);
+            assert_eq!(sync_points(&schedule), 0);
+        }
     }
 
     mod conditions {
@@ -222,6 +523,40 @@ mod tests {
             assert_eq!(world.resource::<SystemOrder>().0, vec![0]);
         }
 
+        #[test]
+        fn run_once_system() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<RunConditionBool>();
+            world.init_resource::<SystemOrder>();
+
+            schedule.add_systems(
+                (
+                    make_function_system(0)
+                        .run_if(|condition: Res<RunConditionBool>| condition.0)
+                        .run_once(),
+                    make_function_system(1),
+                )
+                    .chain(),
+            );
+
+            // the system isn't retired while its conditions fail
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![1]);
+
+            world.resource_mut::<RunConditionBool>().0 = true;
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![1, 0, 1, 1]);
+
+            // the system stays retired when the schedule is rebuilt
+            schedule.add_system(make_function_system(2));
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0.len(), 6);
+            assert!(!world.resource::<SystemOrder>().0[4..].contains(&0));
+        }
+
         #[test]
         fn systems_with_distributive_condition() {
             let mut world = World::default();
@@ -248,6 +583,37 @@ mod tests {
             assert_eq!(world.resource::<SystemOrder>().0, vec![0]);
         }
 
+        #[test]
+        fn system_set_with_propagated_condition() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.insert_resource(RunConditionBool(true));
+            world.init_resource::<SystemOrder>();
+
+            fn change_condition(mut condition: ResMut<RunConditionBool>) {
+                condition.0 = false;
+            }
+
+            schedule.configure_set(
+                TestSet::A
+                    .run_if(|condition: Res<RunConditionBool>| condition.0)
+                    .propagate_conditions(),
+            );
+            schedule.add_systems(
+                (
+                    make_function_system(0),
+                    change_condition,
+                    make_function_system(1),
+                )
+                    .chain()
+                    .in_set(TestSet::A),
+            );
+
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0]);
+        }
+
         #[test]
         fn run_exclusive_system_with_condition() {
             let mut world = World::default();
@@ -568,6 +934,46 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn system_instance_sets() {
+            use crate::system::Local;
+
+            fn record(tag: Local<u32>, mut order: ResMut<SystemOrder>) {
+                order.0.push(*tag);
+            }
+
+            let mut world = World::new();
+            world.init_resource::<SystemOrder>();
+
+            // Instances of `record` can be ordered relative to each other by label, even though
+            // ordering relative to `record` itself is ambiguous.
+            let mut schedule = Schedule::new();
+            schedule.add_systems((
+                record.with_local(2u32).instance("c"),
+                record
+                    .with_local(1u32)
+                    .instance("b")
+                    .before(record.into_system_set().instance("c")),
+                record
+                    .with_local(0u32)
+                    .instance("a")
+                    .before(record.into_system_set().instance("b")),
+            ));
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 2]);
+
+            // Labels must identify a single instance to be used for ordering.
+            let mut schedule = Schedule::new();
+            schedule.add_system(record.instance("a"));
+            schedule.add_system(record.instance("a"));
+            schedule.add_system(named_system.after(record.into_system_set().instance("a")));
+            let result = schedule.initialize(&mut world);
+            assert!(matches!(
+                result,
+                Err(ScheduleBuildError::SystemTypeSetAmbiguity(_))
+            ));
+        }
+
         #[test]
         #[should_panic]
         fn configure_system_type_set() {
@@ -663,6 +1069,138 @@ mod tests {
             let result = schedule.initialize(&mut world);
             assert!(matches!(result, Err(ScheduleBuildError::Ambiguity)));
         }
+
+        #[test]
+        fn deny_access() {
+            use crate::{component::Component, system::Query};
+
+            #[derive(Resource)]
+            struct X;
+
+            #[derive(Component)]
+            struct Y;
+
+            fn res_ref(_x: Res<X>) {}
+            fn query_mut(_y: Query<&mut Y>) {}
+            fn read_world(_world: &World) {}
+
+            let mut world = World::new();
+
+            let mut schedule = Schedule::new();
+            schedule.configure_set(TestSet::A.deny_access::<X>().deny_access::<Y>());
+            schedule.configure_set(TestSet::B.in_set(TestSet::A));
+            schedule.add_systems((named_system.in_set(TestSet::B), res_ref, query_mut));
+            schedule.initialize(&mut world).unwrap();
+
+            for system in [
+                res_ref.in_set(TestSet::B),
+                query_mut.in_set(TestSet::A),
+                read_world.in_set(TestSet::A),
+            ] {
+                let mut schedule = Schedule::new();
+                schedule.configure_set(TestSet::A.deny_access::<X>().deny_access::<Y>());
+                schedule.configure_set(TestSet::B.in_set(TestSet::A));
+                schedule.add_system(system);
+                let result = schedule.initialize(&mut world);
+                assert!(matches!(
+                    result,
+                    Err(ScheduleBuildError::DeniedAccess { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn read_only_world_ambiguity() {
+            #[derive(Resource)]
+            struct X;
+
+            fn res_ref(_x: Res<X>) {}
+            fn res_mut(_x: ResMut<X>) {}
+            fn read_world(_world: &World) {}
+
+            let mut world = World::new();
+            world.insert_resource(X);
+
+            // `&World` only conflicts with systems that write data.
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            });
+            schedule.add_systems((read_world, res_ref, counting_system));
+            schedule.initialize(&mut world).unwrap();
+
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            });
+            schedule.add_systems((read_world, res_mut));
+            let result = schedule.initialize(&mut world);
+            assert!(matches!(result, Err(ScheduleBuildError::Ambiguity)));
+
+            // Its access is resolved to reads of the data written in the schedule.
+            let mut schedule = Schedule::new();
+            schedule.add_systems((read_world, res_ref, res_mut.after(res_ref)));
+            schedule.initialize(&mut world).unwrap();
+            let x = world.components().resource_id::<X>().unwrap();
+            let graph = schedule.graph();
+            let (read_world_id, _) = schedule
+                .systems()
+                .find(|(_, system)| system.component_access().has_read_all())
+                .unwrap();
+            let access = graph.component_access(read_world_id).unwrap();
+            assert!(!access.has_read_all());
+            assert_eq!(access.reads().collect::<Vec<_>>(), vec![x]);
+            assert_eq!(access.writes().count(), 0);
+            assert_eq!(graph.conflicting_systems().len(), 1);
+            assert_eq!(graph.conflicting_systems()[0].2, vec![x]);
+        }
+
+        #[test]
+        fn set_ambiguity_policy() {
+            #[derive(Resource)]
+            struct X;
+
+            fn res_ref(_x: Res<X>) {}
+            fn res_mut(_x: ResMut<X>) {}
+
+            let mut world = World::new();
+
+            // The set policy is stricter than the schedule's.
+            let mut schedule = Schedule::new();
+            schedule.configure_set(TestSet::A.ambiguity_policy(LogLevel::Error));
+            schedule.add_systems((res_ref, res_mut.in_set(TestSet::A)));
+            let result = schedule.initialize(&mut world);
+            assert!(matches!(result, Err(ScheduleBuildError::Ambiguity)));
+
+            // Ambiguities outside the set are unaffected.
+            let mut schedule = Schedule::new();
+            schedule.configure_set(TestSet::A.ambiguity_policy(LogLevel::Error));
+            schedule.add_systems((res_ref, res_mut, named_system.in_set(TestSet::A)));
+            let result = schedule.initialize(&mut world);
+            assert!(result.is_ok());
+
+            // The set policy is more lenient than the schedule's.
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            });
+            schedule.configure_set(TestSet::A.ambiguity_policy(LogLevel::Ignore));
+            schedule.configure_set(TestSet::B.in_set(TestSet::A));
+            schedule.add_systems((res_ref.in_set(TestSet::B), res_mut));
+            let result = schedule.initialize(&mut world);
+            assert!(result.is_ok());
+
+            // The most severe policy of the sets containing the systems applies.
+            let mut schedule = Schedule::new();
+            schedule.configure_set(TestSet::C.ambiguity_policy(LogLevel::Warn));
+            schedule.configure_set(TestSet::D.ambiguity_policy(LogLevel::Error));
+            schedule.add_systems((res_ref.in_set(TestSet::C), res_mut.in_set(TestSet::D)));
+            let result = schedule.initialize(&mut world);
+            assert!(matches!(result, Err(ScheduleBuildError::Ambiguity)));
+        }
     }
 
     mod base_sets {
@@ -1,4 +1,5 @@
 use std::{
+    any::TypeId,
     fmt::{Debug, Write},
     result::Result,
 };
@@ -18,8 +19,9 @@ use fixedbitset::FixedBitSet;
 use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
+    query::Access,
     schedule::*,
-    system::{BoxedSystem, Resource, System},
+    system::{BoxedSystem, IntoSystem, Resource, System},
     world::World,
 };
 
@@ -164,6 +166,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    panic_behavior: PanicBehavior,
 }
 
 impl Default for Schedule {
@@ -180,6 +183,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            panic_behavior: PanicBehavior::default(),
         }
     }
 
@@ -213,6 +217,39 @@ impl Schedule {
         self
     }
 
+    /// Merges the systems and system sets of `other` into this schedule, nesting them in the
+    /// `namespace` set.
+    ///
+    /// The ordering, run conditions and set membership configured in `other` are preserved, so a
+    /// plugin can build and test its schedule standalone and then compose it into another one.
+    /// The `namespace` can be configured like any other set, e.g. to order the merged systems
+    /// relative to the rest of this schedule. System sets (like base sets) that already exist in
+    /// this schedule are shared rather than duplicated, and aren't nested in the `namespace`.
+    ///
+    /// If `other` has already been run, it must have been run on the same [`World`] as this schedule.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # struct PhysicsSet;
+    /// # fn integrate() {}
+    /// # fn collide() {}
+    /// # fn render() {}
+    /// let mut physics = Schedule::new();
+    /// physics.add_systems((integrate, collide).chain());
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule
+    ///     .add_system(render)
+    ///     .merge(PhysicsSet, physics)
+    ///     .configure_set(PhysicsSet.before(render));
+    /// ```
+    pub fn merge(&mut self, namespace: impl SystemSet, mut other: Schedule) -> &mut Self {
+        other.graph.restore_systems(&mut other.executable);
+        self.graph.merge(Box::new(namespace), other.graph);
+        self
+    }
+
     /// Changes miscellaneous build settings.
     pub fn set_build_settings(&mut self, settings: ScheduleBuildSettings) -> &mut Self {
         self.graph.settings = settings;
@@ -228,6 +265,7 @@ impl Schedule {
     pub fn set_executor_kind(&mut self, executor: ExecutorKind) -> &mut Self {
         if executor != self.executor.kind() {
             self.executor = make_executor(executor);
+            self.executor.set_panic_behavior(self.panic_behavior);
             self.executor_initialized = false;
         }
         self
@@ -242,6 +280,21 @@ impl Schedule {
         self
     }
 
+    /// Returns what happens when a system of this schedule panics.
+    pub fn get_panic_behavior(&self) -> PanicBehavior {
+        self.panic_behavior
+    }
+
+    /// Sets what happens when a system of this schedule panics, see [`PanicBehavior`].
+    ///
+    /// By default, the panic is propagated out of [`Schedule::run`]. Long-running tools can
+    /// instead skip the affected systems and react to the [`SystemPanicked`] event.
+    pub fn set_panic_behavior(&mut self, panic_behavior: PanicBehavior) -> &mut Self {
+        self.panic_behavior = panic_behavior;
+        self.executor.set_panic_behavior(panic_behavior);
+        self
+    }
+
     /// Runs all systems in this schedule on the `world`, using its current execution strategy.
     pub fn run(&mut self, world: &mut World) {
         world.check_change_ticks();
@@ -256,8 +309,7 @@ impl Schedule {
     pub fn initialize(&mut self, world: &mut World) -> Result<(), ScheduleBuildError> {
         if self.graph.changed {
             self.graph.initialize(world);
-            self.graph
-                .update_schedule(&mut self.executable, world.components())?;
+            self.graph.update_schedule(&mut self.executable, world)?;
             self.graph.changed = false;
             self.executor_initialized = false;
         }
@@ -280,6 +332,19 @@ impl Schedule {
         &mut self.graph
     }
 
+    /// Returns an iterator over the systems of the executable schedule, in the order they would
+    /// run on a single thread.
+    ///
+    /// Systems are only moved into the executable schedule by [`Schedule::initialize`], which also
+    /// initializes their access.
+    pub fn systems(&self) -> impl Iterator<Item = (NodeId, &dyn System<In = (), Out = ()>)> {
+        self.executable
+            .system_ids
+            .iter()
+            .copied()
+            .zip(self.executable.systems.iter().map(|system| &**system))
+    }
+
     /// Iterates the change ticks of all systems in the schedule and clamps any older than
     /// [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE).
     /// This prevents overflow and thus prevents false positives.
@@ -361,6 +426,12 @@ pub enum BaseSetMembership {
 struct SystemSetNode {
     inner: BoxedSystemSet,
     base_set_membership: BaseSetMembership,
+    /// Whether the conditions of this set are evaluated separately for each system in it.
+    propagate_conditions: bool,
+    /// How ambiguities involving systems in this set are handled, if overridden.
+    ambiguity_policy: Option<LogLevel>,
+    /// The components and resources the systems in this set must not access.
+    denied_access: Vec<(TypeId, &'static str)>,
 }
 
 impl SystemSetNode {
@@ -368,6 +439,9 @@ impl SystemSetNode {
         Self {
             inner: set,
             base_set_membership: BaseSetMembership::Uncalculated,
+            propagate_conditions: false,
+            ambiguity_policy: None,
+            denied_access: Vec::new(),
         }
     }
 
@@ -384,6 +458,12 @@ impl SystemSetNode {
 struct SystemNode {
     inner: Option<BoxedSystem>,
     base_set_membership: BaseSetMembership,
+    affinity: ThreadAffinity,
+    /// Is `true` if the system is removed after its first run, see
+    /// [`IntoSystemConfig::run_once`].
+    run_once: bool,
+    /// Is `true` if the system is a `run_once` system that has already run.
+    retired: bool,
 }
 
 impl SystemNode {
@@ -391,6 +471,9 @@ impl SystemNode {
         Self {
             inner: Some(system),
             base_set_membership: BaseSetMembership::Uncalculated,
+            affinity: ThreadAffinity::default(),
+            run_once: false,
+            retired: false,
         }
     }
 
@@ -424,6 +507,9 @@ pub struct ScheduleGraph {
     ambiguous_with_flattened: UnGraphMap<NodeId, ()>,
     ambiguous_with_all: HashSet<NodeId>,
     conflicting_systems: Vec<(NodeId, NodeId, Vec<ComponentId>)>,
+    world_read_access: HashMap<NodeId, Access<ComponentId>>,
+    flush_edges: HashSet<(NodeId, NodeId)>,
+    auto_sync_points: Vec<NodeId>,
     changed: bool,
     settings: ScheduleBuildSettings,
     default_base_set: Option<BoxedSystemSet>,
@@ -446,6 +532,9 @@ impl ScheduleGraph {
             ambiguous_with_flattened: UnGraphMap::new(),
             ambiguous_with_all: HashSet::new(),
             conflicting_systems: Vec::new(),
+            world_read_access: HashMap::new(),
+            flush_edges: HashSet::new(),
+            auto_sync_points: Vec::new(),
             changed: false,
             settings: default(),
             default_base_set: None,
@@ -556,6 +645,27 @@ impl ScheduleGraph {
         &self.conflicting_systems
     }
 
+    /// Returns the access of the system at the given [`NodeId`] used to detect conflicts, if it
+    /// exists.
+    ///
+    /// Systems reading the whole [`World`] only conflict with the components and resources
+    /// written by other systems of the schedule, so their access is resolved to reads of this
+    /// data by [`ScheduleGraph::build_schedule`].
+    pub fn component_access(&self, id: NodeId) -> Option<&Access<ComponentId>> {
+        self.world_read_access
+            .get(&id)
+            .or_else(|| Some(self.get_system_at(id)?.component_access()))
+    }
+
+    /// Returns the [`apply_system_buffers`] sync points that were inserted automatically for
+    /// [`before_and_flush`](IntoSystemConfig::before_and_flush) and
+    /// [`after_and_flush`](IntoSystemConfig::after_and_flush) orderings.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_buffers`].
+    pub fn auto_sync_points(&self) -> &[NodeId] {
+        &self.auto_sync_points
+    }
+
     fn add_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) {
         let SystemConfigs { systems, chained } = systems.into_configs();
         let mut system_iter = systems.into_iter();
@@ -586,6 +696,8 @@ impl ScheduleGraph {
             system,
             graph_info,
             conditions,
+            affinity,
+            run_once,
         } = system.into_config();
 
         let id = NodeId::System(self.systems.len());
@@ -595,7 +707,10 @@ impl ScheduleGraph {
 
         // system init has to be deferred (need `&mut World`)
         self.uninit.push((id, 0));
-        self.systems.push(SystemNode::new(system));
+        let mut system_node = SystemNode::new(system);
+        system_node.affinity = affinity;
+        system_node.run_once = run_once;
+        self.systems.push(system_node);
         self.system_conditions.push(Some(conditions));
 
         Ok(id)
@@ -631,6 +746,9 @@ impl ScheduleGraph {
             set,
             graph_info,
             mut conditions,
+            propagate_conditions,
+            ambiguity_policy,
+            mut denied_access,
         } = set.into_config();
 
         let id = match self.system_set_ids.get(&set) {
@@ -638,6 +756,16 @@ impl ScheduleGraph {
             None => self.add_set(set.dyn_clone()),
         };
 
+        if propagate_conditions {
+            self.system_sets[id.index()].propagate_conditions = true;
+        }
+        if ambiguity_policy.is_some() {
+            self.system_sets[id.index()].ambiguity_policy = ambiguity_policy;
+        }
+        self.system_sets[id.index()]
+            .denied_access
+            .append(&mut denied_access);
+
         // graph updates are immediate
         self.update_graphs(id, graph_info, set.is_base())?;
 
@@ -782,10 +910,16 @@ impl ScheduleGraph {
             .map(|Dependency { kind, set }| (kind, self.system_set_ids[&set]))
         {
             let (lhs, rhs) = match kind {
-                DependencyKind::Before => (id, set),
-                DependencyKind::After => (set, id),
+                DependencyKind::Before | DependencyKind::BeforeAndFlush => (id, set),
+                DependencyKind::After | DependencyKind::AfterAndFlush => (set, id),
             };
             self.dependency.graph.add_edge(lhs, rhs, ());
+            if matches!(
+                kind,
+                DependencyKind::BeforeAndFlush | DependencyKind::AfterAndFlush
+            ) {
+                self.flush_edges.insert((lhs, rhs));
+            }
 
             // ensure set also appears in hierarchy graph
             self.hierarchy.graph.add_node(set);
@@ -1062,6 +1196,33 @@ impl ScheduleGraph {
             }
         }
 
+        // check that systems don't access data denied by their sets
+        for (&id, systems) in set_systems.iter() {
+            for &(type_id, type_name) in &self.system_sets[id.index()].denied_access {
+                let component_ids = [
+                    components.get_id(type_id),
+                    components.get_resource_id(type_id),
+                ];
+                for &system_id in systems {
+                    let access = self.systems[system_id.index()]
+                        .get()
+                        .unwrap()
+                        .component_access();
+                    if component_ids
+                        .iter()
+                        .flatten()
+                        .any(|&component_id| access.has_read(component_id))
+                    {
+                        return Err(ScheduleBuildError::DeniedAccess {
+                            system: self.get_node_name(&system_id),
+                            set: self.get_node_name(&id),
+                            access: type_name.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
         // check that there are no edges to system-type sets that have multiple instances
         for (&id, systems) in set_systems.iter() {
             let set = &self.system_sets[id.index()];
@@ -1088,34 +1249,50 @@ impl ScheduleGraph {
         // flatten: combine `in_set` with `before` and `after` information
         // have to do it like this to preserve transitivity
         let mut dependency_flattened = self.dependency.graph.clone();
+        let mut flush_edges_flattened = self.flush_edges.clone();
         let mut temp = Vec::new();
         for (&set, systems) in set_systems.iter() {
             if systems.is_empty() {
                 for a in dependency_flattened.neighbors_directed(set, Direction::Incoming) {
                     for b in dependency_flattened.neighbors_directed(set, Direction::Outgoing) {
-                        temp.push((a, b));
+                        let flush = flush_edges_flattened.contains(&(a, set))
+                            || flush_edges_flattened.contains(&(set, b));
+                        temp.push((a, b, flush));
                     }
                 }
             } else {
                 for a in dependency_flattened.neighbors_directed(set, Direction::Incoming) {
+                    let flush = flush_edges_flattened.contains(&(a, set));
                     for &sys in systems {
-                        temp.push((a, sys));
+                        temp.push((a, sys, flush));
                     }
                 }
 
                 for b in dependency_flattened.neighbors_directed(set, Direction::Outgoing) {
+                    let flush = flush_edges_flattened.contains(&(set, b));
                     for &sys in systems {
-                        temp.push((sys, b));
+                        temp.push((sys, b, flush));
                     }
                 }
             }
 
             dependency_flattened.remove_node(set);
-            for (a, b) in temp.drain(..) {
+            for (a, b, flush) in temp.drain(..) {
                 dependency_flattened.add_edge(a, b, ());
+                if flush {
+                    flush_edges_flattened.insert((a, b));
+                }
             }
         }
 
+        // sync points added by earlier builds stay in the schedule
+        for &sync_point in &self.auto_sync_points {
+            dependency_flattened.add_node(sync_point);
+        }
+        if self.settings.auto_insert_apply_buffers {
+            self.auto_insert_apply_buffers(&mut dependency_flattened, &flush_edges_flattened)?;
+        }
+
         // topsort
         self.dependency_flattened.topsort = self
             .topsort_graph(&dependency_flattened, ReportCycles::Dependency)
@@ -1159,6 +1336,32 @@ impl ScheduleGraph {
 
         self.ambiguous_with_flattened = ambiguous_with_flattened;
 
+        // resolve the access of systems reading the whole world to reads of the data written by
+        // the other systems, unless a system may write anything
+        let mut written = Access::default();
+        let mut writes_all = false;
+        for system in self.systems.iter().filter_map(|node| node.get()) {
+            let access = system.component_access();
+            writes_all |= access.has_write_all();
+            if !system.is_exclusive() {
+                for data in access.writes() {
+                    written.add_read(data);
+                }
+            }
+        }
+        self.world_read_access.clear();
+        if !writes_all {
+            for (index, node) in self.systems.iter().enumerate() {
+                let Some(system) = node.get() else {
+                    continue;
+                };
+                if !system.is_exclusive() && system.component_access().has_read_all() {
+                    self.world_read_access
+                        .insert(NodeId::System(index), written.clone());
+                }
+            }
+        }
+
         // check for conflicts
         let mut conflicting_systems = Vec::new();
         for &(a, b) in &flat_results.disconnected {
@@ -1174,8 +1377,8 @@ impl ScheduleGraph {
             if system_a.is_exclusive() || system_b.is_exclusive() {
                 conflicting_systems.push((a, b, Vec::new()));
             } else {
-                let access_a = system_a.component_access();
-                let access_b = system_b.component_access();
+                let access_a = self.component_access(a).unwrap();
+                let access_b = self.component_access(b).unwrap();
                 if !access_a.is_compatible(access_b) {
                     let conflicts = access_a.get_conflicts(access_b);
                     conflicting_systems.push((a, b, conflicts));
@@ -1183,11 +1386,27 @@ impl ScheduleGraph {
             }
         }
 
-        if self.settings.ambiguity_detection != LogLevel::Ignore
-            && self.contains_conflicts(&conflicting_systems)
-        {
-            self.report_conflicts(&conflicting_systems, components);
-            if matches!(self.settings.ambiguity_detection, LogLevel::Error) {
+        let mut ambiguity_policies = HashMap::new();
+        let mut reported_conflicts = Vec::new();
+        let mut ambiguity_error = false;
+        for conflict @ (a, b, _) in &conflicting_systems {
+            let policy_a = self.ambiguity_policy_of(*a, &mut ambiguity_policies);
+            let policy_b = self.ambiguity_policy_of(*b, &mut ambiguity_policies);
+            let policy = match policy_a.max(policy_b) {
+                Some(policy) => policy,
+                None => self.settings.ambiguity_detection.clone(),
+            };
+            match policy {
+                LogLevel::Ignore => continue,
+                LogLevel::Warn => {}
+                LogLevel::Error => ambiguity_error = true,
+            }
+            reported_conflicts.push(conflict.clone());
+        }
+
+        if self.contains_conflicts(&reported_conflicts) {
+            self.report_conflicts(&reported_conflicts, components);
+            if ambiguity_error {
                 return Err(ScheduleBuildError::Ambiguity);
             }
         }
@@ -1230,6 +1449,13 @@ impl ScheduleGraph {
 
         let sys_count = self.systems.len();
         let set_with_conditions_count = hg_set_ids.len();
+
+        let mut sets_with_propagated_conditions =
+            FixedBitSet::with_capacity(set_with_conditions_count);
+        for (idx, id) in hg_set_ids.iter().enumerate() {
+            sets_with_propagated_conditions
+                .set(idx, self.system_sets[id.index()].propagate_conditions);
+        }
         let node_count = self.systems.len() + self.system_sets.len();
 
         // get the number of dependencies and the immediate dependents of each system
@@ -1282,9 +1508,25 @@ impl ScheduleGraph {
             }
         }
 
+        let system_affinities = dg_system_ids
+            .iter()
+            .map(|id| self.systems[id.index()].affinity)
+            .collect();
+
+        let mut run_once_systems = FixedBitSet::with_capacity(sys_count);
+        let mut retired_systems = FixedBitSet::with_capacity(sys_count);
+        for (i, id) in dg_system_ids.iter().enumerate() {
+            let system_node = &self.systems[id.index()];
+            run_once_systems.set(i, system_node.run_once);
+            retired_systems.set(i, system_node.retired);
+        }
+
         Ok(SystemSchedule {
             systems: Vec::with_capacity(sys_count),
             system_conditions: Vec::with_capacity(sys_count),
+            system_affinities,
+            run_once_systems,
+            retired_systems,
             set_conditions: Vec::with_capacity(set_with_conditions_count),
             system_ids: dg_system_ids,
             set_ids: hg_set_ids,
@@ -1292,26 +1534,108 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            sets_with_propagated_conditions,
         })
     }
 
+    /// Inserts [`apply_system_buffers`] sync points into the flattened dependency graph, so the
+    /// system buffers of the source of each flush edge are applied before its target runs.
+    ///
+    /// Each system is assigned the number of sync points that have to run before it. Flush edges
+    /// into systems with the same number share a sync point, so as few as possible are added.
+    fn auto_insert_apply_buffers(
+        &mut self,
+        dependency_flattened: &mut GraphMap<NodeId, (), Directed>,
+        flush_edges: &HashSet<(NodeId, NodeId)>,
+    ) -> Result<(), ScheduleBuildError> {
+        let topsort = self
+            .topsort_graph(dependency_flattened, ReportCycles::Dependency)
+            .map_err(|_| ScheduleBuildError::DependencyCycle)?;
+
+        let mut distances: HashMap<NodeId, usize> = HashMap::with_capacity(topsort.len());
+        for &node in &topsort {
+            let distance = distances.get(&node).copied().unwrap_or(0);
+            for target in dependency_flattened.neighbors_directed(node, Direction::Outgoing) {
+                let flush = flush_edges.contains(&(node, target));
+                let target_distance = distances.entry(target).or_insert(0);
+                *target_distance = (*target_distance).max(distance + usize::from(flush));
+            }
+        }
+
+        let mut sync_edges = Vec::new();
+        for &(a, b) in flush_edges {
+            if a.is_system() && b.is_system() && dependency_flattened.contains_edge(a, b) {
+                // `b` runs after at least one more sync point than `a`
+                let sync_point = self.auto_sync_point(distances[&b] - 1);
+                sync_edges.push((a, sync_point, b));
+            }
+        }
+        for (a, sync_point, b) in sync_edges {
+            dependency_flattened.add_edge(a, sync_point, ());
+            dependency_flattened.add_edge(sync_point, b, ());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `index`-th automatically inserted [`apply_system_buffers`] sync point,
+    /// adding it and any before it if they don't exist yet.
+    fn auto_sync_point(&mut self, index: usize) -> NodeId {
+        while self.auto_sync_points.len() <= index {
+            let id = NodeId::System(self.systems.len());
+            let system: BoxedSystem = Box::new(IntoSystem::into_system(apply_system_buffers));
+            self.systems.push(SystemNode::new(system));
+            self.system_conditions.push(Some(Vec::new()));
+            // sync points aren't configured by users, so their ambiguities aren't reported
+            self.ambiguous_with_all.insert(id);
+            self.uninit.push((id, 0));
+            self.auto_sync_points.push(id);
+        }
+        self.auto_sync_points[index]
+    }
+
     fn update_schedule(
         &mut self,
         schedule: &mut SystemSchedule,
-        components: &Components,
+        world: &mut World,
     ) -> Result<(), ScheduleBuildError> {
         if !self.uninit.is_empty() {
             return Err(ScheduleBuildError::Uninitialized);
         }
 
-        // move systems out of old schedule
-        for ((id, system), conditions) in schedule
+        self.restore_systems(schedule);
+
+        *schedule = self.build_schedule(world.components())?;
+        // initialize the sync points added while building
+        self.initialize(world);
+
+        // move systems into new schedule
+        for &id in &schedule.system_ids {
+            let system = self.systems[id.index()].inner.take().unwrap();
+            let conditions = self.system_conditions[id.index()].take().unwrap();
+            schedule.systems.push(system);
+            schedule.system_conditions.push(conditions);
+        }
+
+        for &id in &schedule.set_ids {
+            let conditions = self.system_set_conditions[id.index()].take().unwrap();
+            schedule.set_conditions.push(conditions);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the systems and conditions of the executable `schedule` back into the graph.
+    fn restore_systems(&mut self, schedule: &mut SystemSchedule) {
+        for (index, ((id, system), conditions)) in schedule
             .system_ids
             .drain(..)
             .zip(schedule.systems.drain(..))
             .zip(schedule.system_conditions.drain(..))
+            .enumerate()
         {
             self.systems[id.index()].inner = Some(system);
+            self.systems[id.index()].retired = schedule.retired_systems.contains(index);
             self.system_conditions[id.index()] = Some(conditions);
         }
 
@@ -1322,23 +1646,143 @@ impl ScheduleGraph {
         {
             self.system_set_conditions[id.index()] = Some(conditions);
         }
+    }
 
-        *schedule = self.build_schedule(components)?;
+    /// Adds the nodes and edges of `other` to this graph, nesting its nodes in the `namespace` set.
+    ///
+    /// Expects the systems of `other` to be in the graph, see [`Self::restore_systems`].
+    fn merge(&mut self, namespace: BoxedSystemSet, mut other: ScheduleGraph) {
+        let namespace_id = match self.system_set_ids.get(&namespace) {
+            Some(&id) => id,
+            None => self.add_set(namespace),
+        };
+        self.hierarchy.graph.add_node(namespace_id);
+        self.dependency.graph.add_node(namespace_id);
+
+        // systems and conditions of `other` that were already initialized aren't initialized again
+        let mut uninit: HashMap<NodeId, usize> = HashMap::with_capacity(other.uninit.len());
+        for &(other_id, i) in &other.uninit {
+            let start = uninit.entry(other_id).or_insert(i);
+            *start = (*start).min(i);
+        }
+
+        // sets of `other` that already exist here are shared and, like base sets and system type
+        // sets, stay outside of the namespace
+        let mut ids = HashMap::with_capacity(other.system_sets.len() + other.systems.len());
+        let mut namespaced = HashSet::with_capacity(other.system_sets.len());
+        for (index, (set_node, conditions)) in other
+            .system_sets
+            .drain(..)
+            .zip(other.system_set_conditions.drain(..))
+            .enumerate()
+        {
+            let id = match self.system_set_ids.get(&set_node.inner) {
+                Some(&id) => id,
+                None => {
+                    let id = self.add_set(set_node.inner.dyn_clone());
+                    if !set_node.inner.is_base() && !set_node.is_system_type() {
+                        namespaced.insert(id);
+                    }
+                    id
+                }
+            };
+            let node = &mut self.system_sets[id.index()];
+            node.propagate_conditions |= set_node.propagate_conditions;
+            if set_node.ambiguity_policy.is_some() {
+                node.ambiguity_policy = set_node.ambiguity_policy;
+            }
+            node.denied_access.extend(set_node.denied_access);
+            if let Some(mut conditions) = conditions {
+                let set_conditions =
+                    self.system_set_conditions[id.index()].get_or_insert_with(Vec::new);
+                if let Some(i) = uninit.get(&NodeId::Set(index)) {
+                    self.uninit.push((id, set_conditions.len() + i));
+                }
+                set_conditions.append(&mut conditions);
+            }
+            ids.insert(NodeId::Set(index), id);
+        }
 
-        // move systems into new schedule
-        for &id in &schedule.system_ids {
-            let system = self.systems[id.index()].inner.take().unwrap();
-            let conditions = self.system_conditions[id.index()].take().unwrap();
-            schedule.systems.push(system);
-            schedule.system_conditions.push(conditions);
+        // sync points are added again when this schedule is built
+        for (index, (system_node, conditions)) in other
+            .systems
+            .drain(..)
+            .zip(other.system_conditions.drain(..))
+            .enumerate()
+        {
+            let other_id = NodeId::System(index);
+            if other.auto_sync_points.contains(&other_id) {
+                continue;
+            }
+            let id = NodeId::System(self.systems.len());
+            let mut node = SystemNode::new(system_node.inner.expect(
+                "systems of a merged schedule are moved back into its graph before merging",
+            ));
+            node.affinity = system_node.affinity;
+            node.run_once = system_node.run_once;
+            node.retired = system_node.retired;
+            self.systems.push(node);
+            self.system_conditions
+                .push(Some(conditions.unwrap_or_default()));
+            if uninit.contains_key(&other_id) {
+                self.uninit.push((id, 0));
+            }
+            namespaced.insert(id);
+            ids.insert(other_id, id);
         }
 
-        for &id in &schedule.set_ids {
-            let conditions = self.system_set_conditions[id.index()].take().unwrap();
-            schedule.set_conditions.push(conditions);
+        for other_id in other.hierarchy.graph.nodes() {
+            let Some(&id) = ids.get(&other_id) else {
+                continue;
+            };
+            self.hierarchy.graph.add_node(id);
+            self.dependency.graph.add_node(id);
+        }
+        for (parent, child, _) in other.hierarchy.graph.all_edges() {
+            if let (Some(&parent), Some(&child)) = (ids.get(&parent), ids.get(&child)) {
+                self.hierarchy.graph.add_edge(parent, child, ());
+            }
+        }
+        for (other_before, other_after, _) in other.dependency.graph.all_edges() {
+            if let (Some(&before), Some(&after)) = (ids.get(&other_before), ids.get(&other_after)) {
+                self.dependency.graph.add_edge(before, after, ());
+                if other.flush_edges.contains(&(other_before, other_after)) {
+                    self.flush_edges.insert((before, after));
+                }
+            }
+        }
+        for (a, b, _) in other.ambiguous_with.all_edges() {
+            if let (Some(&a), Some(&b)) = (ids.get(&a), ids.get(&b)) {
+                self.ambiguous_with.add_edge(a, b, ());
+            }
         }
+        self.ambiguous_with_all.extend(
+            other
+                .ambiguous_with_all
+                .iter()
+                .filter_map(|other_id| ids.get(other_id)),
+        );
+        self.maybe_default_base_set.extend(
+            other
+                .maybe_default_base_set
+                .iter()
+                .filter_map(|other_id| ids.get(other_id)),
+        );
 
-        Ok(())
+        // only the outermost namespaced nodes are added to the namespace, to avoid redundant
+        // hierarchy edges
+        for &id in &namespaced {
+            let nested = self
+                .hierarchy
+                .graph
+                .neighbors_directed(id, Direction::Incoming)
+                .any(|parent| namespaced.contains(&parent));
+            if !nested {
+                self.hierarchy.graph.add_edge(namespace_id, id, ());
+            }
+        }
+
+        self.changed = true;
     }
 
     fn set_default_base_set(&mut self, set: Option<BoxedSystemSet>) {
@@ -1556,6 +2000,31 @@ impl ScheduleGraph {
         warn!("{}", string);
     }
 
+    /// Returns the most severe ambiguity policy of the sets containing the system `id`, if any of
+    /// them has one.
+    fn ambiguity_policy_of(
+        &self,
+        id: NodeId,
+        cache: &mut HashMap<NodeId, Option<LogLevel>>,
+    ) -> Option<LogLevel> {
+        if let Some(policy) = cache.get(&id) {
+            return policy.clone();
+        }
+        let mut policy = None;
+        let mut visited = HashSet::new();
+        self.traverse_sets_containing_node(id, &mut |set_id| {
+            if !visited.insert(set_id) {
+                return false;
+            }
+            policy = policy
+                .clone()
+                .max(self.system_sets[set_id.index()].ambiguity_policy.clone());
+            true
+        });
+        cache.insert(id, policy.clone());
+        policy
+    }
+
     fn traverse_sets_containing_node(&self, id: NodeId, f: &mut impl FnMut(NodeId) -> bool) {
         for (set_id, _, _) in self.hierarchy.graph.edges_directed(id, Direction::Incoming) {
             if f(set_id) {
@@ -1606,13 +2075,22 @@ pub enum ScheduleBuildError {
     #[error("`{0:?}` and `{1:?}` have a `before`-`after` relationship (which may be transitive) but share systems.")]
     SetsHaveOrderButIntersect(String, String),
     /// Tried to order a system (set) relative to all instances of some system function.
-    #[error("Tried to order against `fn {0:?}` in a schedule that has more than one `{0:?}` instance. `fn {0:?}` is a `SystemTypeSet` and cannot be used for ordering if ambiguous. Use a different set without this restriction.")]
+    #[error("Tried to order against `fn {0:?}` in a schedule that has more than one `{0:?}` instance. `fn {0:?}` is a `SystemTypeSet` and cannot be used for ordering if ambiguous. Label the instances with `instance` and order relative to a specific one, or use a different set without this restriction.")]
     SystemTypeSetAmbiguity(String),
     /// Systems with conflicting access have indeterminate run order.
     ///
     /// This error is disabled by default, but can be opted-in using [`ScheduleBuildSettings`].
     #[error("Systems with conflicting access have indeterminate run order.")]
     Ambiguity,
+    /// A system accesses a component or resource that one of its sets denies access to.
+    ///
+    /// See [`IntoSystemSetConfig::deny_access`].
+    #[error("System `{system:?}` accesses `{access}`, but it is in the set `{set:?}` which denies access to it.")]
+    DeniedAccess {
+        system: String,
+        set: String,
+        access: String,
+    },
     /// Tried to run a schedule before all of its systems have been initialized.
     #[error("Systems in schedule have not been initialized.")]
     Uninitialized,
@@ -1633,7 +2111,9 @@ pub enum ScheduleBuildError {
 }
 
 /// Specifies how schedule construction should respond to detecting a certain kind of issue.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Levels are ordered by severity, from [`LogLevel::Ignore`] to [`LogLevel::Error`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
     /// Occurrences are completely ignored.
     Ignore,
@@ -1649,6 +2129,9 @@ pub struct ScheduleBuildSettings {
     /// Determines whether the presence of ambiguities (systems with conflicting access but indeterminate order)
     /// is only logged or also results in an [`Ambiguity`](ScheduleBuildError::Ambiguity) error.
     ///
+    /// Can be overridden for the systems of a set with
+    /// [`IntoSystemSetConfig::ambiguity_policy`].
+    ///
     /// Defaults to [`LogLevel::Ignore`].
     pub ambiguity_detection: LogLevel,
     /// Determines whether the presence of redundant edges in the hierarchy of system sets is only
@@ -1665,6 +2148,17 @@ pub struct ScheduleBuildSettings {
     ///
     /// Defaults to `true`.
     pub report_sets: bool,
+    /// If set to true, [`apply_system_buffers`] sync points are inserted automatically between
+    /// systems ordered with [`before_and_flush`](IntoSystemConfig::before_and_flush) or
+    /// [`after_and_flush`](IntoSystemConfig::after_and_flush), so the system buffers (like
+    /// [`Commands`](crate::system::Commands)) of the earlier systems are applied before the
+    /// later ones run. Systems that need the same number of preceding sync points share them.
+    ///
+    /// If set to false, these orderings behave like [`before`](IntoSystemConfig::before) and
+    /// [`after`](IntoSystemConfig::after).
+    ///
+    /// Defaults to `true`.
+    pub auto_insert_apply_buffers: bool,
 }
 
 impl Default for ScheduleBuildSettings {
@@ -1680,6 +2174,7 @@ impl ScheduleBuildSettings {
             hierarchy_detection: LogLevel::Warn,
             use_shortnames: true,
             report_sets: true,
+            auto_insert_apply_buffers: true,
         }
     }
 }
@@ -1,4 +1,5 @@
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
@@ -77,12 +78,36 @@ impl Clone for Box<dyn SystemSet> {
 /// - You cannot manually add members.
 /// - You cannot configure them.
 /// - You cannot order something relative to one if it has more than one member.
+///   Label the instances with [`IntoSystemConfig::instance`](super::IntoSystemConfig::instance)
+///   and order relative to a specific one with [`SystemTypeSet::instance`] instead.
 pub struct SystemTypeSet<T: 'static>(PhantomData<fn() -> T>);
 
 impl<T: 'static> SystemTypeSet<T> {
     pub(crate) fn new() -> Self {
         Self(PhantomData)
     }
+
+    /// Returns the [`SystemInstanceSet`] of the instance of this system that was labeled
+    /// `label` with [`IntoSystemConfig::instance`](super::IntoSystemConfig::instance).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # fn spawn_enemies() {}
+    /// fn move_enemies() {}
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_system(move_enemies.instance("before_spawn"));
+    /// schedule.add_system(move_enemies.instance("after_spawn"));
+    /// schedule.add_system(
+    ///     spawn_enemies
+    ///         .after(move_enemies.into_system_set().instance("before_spawn"))
+    ///         .before(move_enemies.into_system_set().instance("after_spawn")),
+    /// );
+    /// # schedule.run(&mut World::new());
+    /// ```
+    pub fn instance(self, label: impl Into<Cow<'static, str>>) -> SystemInstanceSet {
+        SystemInstanceSet::new(Box::new(self), label.into())
+    }
 }
 
 impl<T> Debug for SystemTypeSet<T> {
@@ -126,6 +151,62 @@ impl<T> SystemSet for SystemTypeSet<T> {
     }
 }
 
+/// A [`SystemSet`] containing a single labeled instance of a system function, allowing to order
+/// relative to it when the function was added more than once.
+///
+/// Systems are labeled with [`IntoSystemConfig::instance`](super::IntoSystemConfig::instance), and
+/// the set of a labeled instance is returned by [`SystemTypeSet::instance`].
+/// Like [`SystemTypeSet`]s, these sets are automatically populated, and thus cannot be configured
+/// or have members added manually.
+#[derive(Clone)]
+pub struct SystemInstanceSet {
+    type_set: BoxedSystemSet,
+    label: Cow<'static, str>,
+}
+
+impl SystemInstanceSet {
+    pub(crate) fn new(type_set: BoxedSystemSet, label: Cow<'static, str>) -> Self {
+        debug_assert!(type_set.system_type().is_some());
+        Self { type_set, label }
+    }
+
+    /// Returns the label of the instance.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl PartialEq for SystemInstanceSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.type_set.as_ref() == other.type_set.as_ref()
+    }
+}
+
+impl Eq for SystemInstanceSet {}
+
+impl Hash for SystemInstanceSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_set.hash(state);
+        self.label.hash(state);
+    }
+}
+
+impl Debug for SystemInstanceSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}[{:?}]", self.type_set, self.label)
+    }
+}
+
+impl SystemSet for SystemInstanceSet {
+    fn system_type(&self) -> Option<TypeId> {
+        self.type_set.system_type()
+    }
+
+    fn dyn_clone(&self) -> Box<dyn SystemSet> {
+        Box::new(self.clone())
+    }
+}
+
 /// Types that can be converted into a [`SystemSet`].
 pub trait IntoSystemSet<Marker>: Sized {
     type Set: SystemSet;
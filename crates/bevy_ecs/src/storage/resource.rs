@@ -62,6 +62,11 @@ impl<const SEND: bool> ResourceData<SEND> {
         }
     }
 
+    /// Returns the number of bytes allocated for the resource value and its change detection ticks.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.column.allocated_bytes()
+    }
+
     /// Returns true if the resource is populated.
     #[inline]
     pub fn is_present(&self) -> bool {
@@ -8,6 +8,10 @@ use std::{cell::UnsafeCell, hash::Hash, marker::PhantomData};
 
 type EntityIndex = u32;
 
+fn allocated_bytes<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * std::mem::size_of::<T>()
+}
+
 #[derive(Debug)]
 pub(crate) struct SparseArray<I, V = I> {
     values: Vec<Option<V>>,
@@ -102,6 +106,11 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         self.values.clear();
     }
 
+    /// Returns the number of bytes allocated for the sparse values.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        allocated_bytes(&self.values)
+    }
+
     /// Converts the [`SparseArray`] into an immutable variant.
     pub(crate) fn into_immutable(self) -> ImmutableSparseArray<I, V> {
         ImmutableSparseArray {
@@ -337,6 +346,14 @@ impl ComponentSparseSet {
         }
     }
 
+    /// Returns the number of bytes allocated for the component values, their change detection ticks
+    /// and the entity lookup.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.dense.allocated_bytes()
+            + allocated_bytes(&self.entities)
+            + self.sparse.allocated_bytes()
+    }
+
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.dense.check_change_ticks(change_tick);
     }
@@ -565,6 +582,8 @@ impl_sparse_set_index!(u8, u16, u32, u64, usize);
 #[derive(Default)]
 pub struct SparseSets {
     sets: SparseSet<ComponentId, ComponentSparseSet>,
+    /// The index of the next sparse set to check in an incremental change tick scan.
+    check_cursor: usize,
 }
 
 impl SparseSets {
@@ -619,10 +638,21 @@ impl SparseSets {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for set in self.sets.values_mut() {
+    /// Checks the change ticks of the sparse sets that haven't been checked yet in the current
+    /// scan, until the `budget` of component values is used up.
+    ///
+    /// Returns `true` and ends the scan once all sparse sets have been checked.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick, budget: &mut usize) -> bool {
+        for set in self.sets.values_mut().skip(self.check_cursor) {
+            if *budget == 0 {
+                return false;
+            }
             set.check_change_ticks(change_tick);
+            *budget = budget.saturating_sub(set.len());
+            self.check_cursor += 1;
         }
+        self.check_cursor = 0;
+        true
     }
 }
 
@@ -491,6 +491,13 @@ impl Column {
         self.changed_ticks.clear();
     }
 
+    /// Returns the number of bytes allocated for the component values and their change detection ticks.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.data.layout().size() * self.data.capacity()
+            + (self.added_ticks.capacity() + self.changed_ticks.capacity())
+                * std::mem::size_of::<UnsafeCell<Tick>>()
+    }
+
     #[inline]
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for component_ticks in &mut self.added_ticks {
@@ -778,6 +785,11 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the [`Column`]s of the [`Table`] along with the [`ComponentId`] they store.
+    pub(crate) fn iter_with_ids(&self) -> impl Iterator<Item = (ComponentId, &Column)> {
+        self.columns.iter().map(|(id, column)| (*id, column))
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         self.entities.clear();
@@ -793,6 +805,8 @@ impl Table {
 pub struct Tables {
     tables: Vec<Table>,
     table_ids: HashMap<Vec<ComponentId>, TableId>,
+    /// The index of the next table to check in an incremental change tick scan.
+    check_cursor: usize,
 }
 
 impl Default for Tables {
@@ -801,6 +815,7 @@ impl Default for Tables {
         Tables {
             tables: vec![empty_table],
             table_ids: HashMap::default(),
+            check_cursor: 0,
         }
     }
 }
@@ -831,13 +846,18 @@ impl Tables {
         self.tables.get(id.index())
     }
 
-    /// Fetches mutable references to two different [`Table`]s.
+    /// Fetches mutable references to two different [`Table`]s, in order to move rows from `a` to `b`.
     ///
     /// # Panics
     ///
     /// Panics if `a` and `b` are equal.
     #[inline]
     pub(crate) fn get_2_mut(&mut self, a: TableId, b: TableId) -> (&mut Table, &mut Table) {
+        // Rows moved from a table that wasn't checked yet into one that already was would escape
+        // the current change tick scan, so check the destination again.
+        if b.index() < self.check_cursor && a.index() >= self.check_cursor {
+            self.check_cursor = b.index();
+        }
         if a.index() > b.index() {
             let (b_slice, a_slice) = self.tables.split_at_mut(a.index());
             (&mut a_slice[0], &mut b_slice[b.index()])
@@ -886,10 +906,21 @@ impl Tables {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for table in &mut self.tables {
+    /// Checks the change ticks of the tables that haven't been checked yet in the current scan,
+    /// until the `budget` of component values is used up.
+    ///
+    /// Returns `true` and ends the scan once all tables have been checked.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick, budget: &mut usize) -> bool {
+        while let Some(table) = self.tables.get_mut(self.check_cursor) {
+            if *budget == 0 {
+                return false;
+            }
             table.check_change_ticks(change_tick);
+            *budget = budget.saturating_sub(table.entity_count() * table.component_count());
+            self.check_cursor += 1;
         }
+        self.check_cursor = 0;
+        true
     }
 }
 
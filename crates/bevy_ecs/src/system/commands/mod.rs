@@ -4,7 +4,7 @@ mod parallel_scope;
 use crate::{
     self as bevy_ecs,
     bundle::Bundle,
-    entity::{Entities, Entity},
+    entity::{deferred_strong, deferred_weak, Entities, Entity, StrongEntity, WeakEntity},
     world::{FromWorld, World},
 };
 use bevy_ecs_macros::SystemParam;
@@ -259,6 +259,52 @@ impl<'w, 's> Commands<'w, 's> {
         e
     }
 
+    /// Pushes a [`Command`] to the queue for creating a new entity with the given reflected
+    /// `components`, and returns its corresponding [`EntityCommands`].
+    ///
+    /// See [`World::spawn_reflected`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the world has no
+    /// [`AppTypeRegistry`](crate::reflect::AppTypeRegistry) resource, or if any of the values
+    /// has no registered component type.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn spawn_reflected<'a>(
+        &'a mut self,
+        components: Vec<Box<dyn bevy_reflect::Reflect>>,
+    ) -> EntityCommands<'w, 's, 'a> {
+        let entity = self.entities.reserve_entity();
+        self.add(InsertReflected { entity, components });
+        EntityCommands {
+            entity,
+            commands: self,
+        }
+    }
+
+    /// Pushes a [`Command`] to the queue for spawning a copy of the `source` entity, and returns
+    /// the [`EntityCommands`] of the copy.
+    ///
+    /// See [`World::clone_entity`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the world has no
+    /// [`AppTypeRegistry`](crate::reflect::AppTypeRegistry) resource, or if `source` does not
+    /// exist.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn clone_entity<'a>(&'a mut self, source: Entity) -> EntityCommands<'w, 's, 'a> {
+        let destination = self.entities.reserve_entity();
+        self.add(CloneEntity {
+            source,
+            destination,
+        });
+        EntityCommands {
+            entity: destination,
+            commands: self,
+        }
+    }
+
     /// Returns the [`EntityCommands`] for the requested [`Entity`].
     ///
     /// # Panics
@@ -786,6 +832,29 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         });
     }
 
+    /// Returns a [`StrongEntity`] handle to the entity, which keeps it from being despawned once
+    /// the command queue is applied.
+    ///
+    /// Until then, the handle can't keep the entity from being despawned. If the entity doesn't
+    /// exist when the command is applied, the handle is dead.
+    ///
+    /// See [`World::strong_entity`] for more details.
+    pub fn strong_entity(&mut self) -> StrongEntity {
+        let (handle, command) = deferred_strong(self.entity);
+        self.commands.add(command);
+        handle
+    }
+
+    /// Returns a [`WeakEntity`] handle to the entity, which is attached to it once the command
+    /// queue is applied.
+    ///
+    /// See [`World::weak_entity`] for more details.
+    pub fn weak_entity(&mut self) -> WeakEntity {
+        let (handle, command) = deferred_weak(self.entity);
+        self.commands.add(command);
+        handle
+    }
+
     /// Pushes an [`EntityCommand`] to the queue, which will get executed for the current [`Entity`].
     ///
     /// # Examples
@@ -939,6 +1008,62 @@ where
     }
 }
 
+/// A [`Command`] that inserts reflected components into an entity, resolving their component
+/// types in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry).
+#[cfg(feature = "bevy_reflect")]
+pub struct InsertReflected {
+    /// The entity the components are inserted into.
+    pub entity: Entity,
+    /// The reflected values, each inserted as the component type it is registered as.
+    pub components: Vec<Box<dyn bevy_reflect::Reflect>>,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl Command for InsertReflected {
+    fn write(self, world: &mut World) {
+        let registry = world.resource::<crate::reflect::AppTypeRegistry>().clone();
+        let registry = registry.read();
+        if let Some(mut entity) = world.get_entity_mut(self.entity) {
+            if let Err(error) =
+                crate::reflect::insert_reflected(&mut entity, &registry, &self.components)
+            {
+                panic!(
+                    "Could not insert reflected components for entity {:?}: {error}",
+                    self.entity
+                );
+            }
+        } else {
+            panic!("error[B0003]: Could not insert reflected components for entity {:?} because it doesn't exist in this World.", self.entity);
+        }
+    }
+}
+
+/// A [`Command`] that clones the reflected components of an entity into another entity, see
+/// [`World::clone_entity`].
+#[cfg(feature = "bevy_reflect")]
+#[derive(Debug)]
+pub struct CloneEntity {
+    /// The entity whose components are cloned.
+    pub source: Entity,
+    /// The entity the clones are inserted into.
+    pub destination: Entity,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl Command for CloneEntity {
+    fn write(self, world: &mut World) {
+        let registry = world.resource::<crate::reflect::AppTypeRegistry>().clone();
+        let registry = registry.read();
+        if world.get_entity(self.source).is_none() {
+            panic!(
+                "error[B0003]: Could not clone entity {:?} because it doesn't exist in this World.",
+                self.source
+            );
+        }
+        crate::reflect::clone_reflected_into(world, &registry, self.source, self.destination);
+    }
+}
+
 #[derive(Debug)]
 pub struct Remove<T> {
     pub entity: Entity,
@@ -1162,4 +1287,41 @@ mod tests {
         assert!(!world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    #[cfg(feature = "bevy_reflect")]
+    fn spawn_reflected() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Health(u32);
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Name(String);
+
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Name>();
+        }
+
+        let mut queue = CommandQueue::default();
+        let entity = Commands::new(&mut queue, &world)
+            .spawn_reflected(vec![
+                Box::new(Health(3)),
+                Box::new(Name("crab".to_string())),
+            ])
+            .insert(W(1u32))
+            .id();
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<Health>(entity), Some(&Health(3)));
+        assert_eq!(world.get::<Name>(entity), Some(&Name("crab".to_string())));
+        assert_eq!(world.get::<W<u32>>(entity).unwrap().0, 1);
+    }
 }
@@ -0,0 +1,375 @@
+use crate::{
+    change_detection::{Mut, TicksMut},
+    component::{Component, Tick},
+    entity::Entity,
+    event::{Event, Events},
+    query::FilteredAccess,
+    schedule::{BoxedScheduleLabel, ScheduleLabel},
+    system::{BoxedSystem, Command, Commands, IntoSystem, Resource, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+
+/// A [`SystemParam`] that grants a regular system direct, access-checked mutation of the
+/// [`World`] for a narrow set of operations, and defers the operations which need exclusive
+/// world access, like running schedules or one-shot systems.
+///
+/// Components and resources can be read and changed in place, and events can be sent directly.
+/// Structural changes, like spawning entities or inserting components, go through
+/// [`DeferredWorld::commands`] instead, and [`DeferredWorld::run_schedule`] and
+/// [`DeferredWorld::run_system`] are queued like commands as well. Queued operations are performed
+/// with exclusive world access the next time the system's buffers are applied, in order with the
+/// system's other commands.
+///
+/// # Access
+///
+/// A [`DeferredWorld`] may write to every component and resource, so it conflicts with all
+/// other system parameters that access data, and a system using it can't share a schedule step
+/// with systems that read or write any data. Unlike an exclusive system, it can still run in
+/// parallel with systems that don't access data, and it doesn't force an exclusive sync point.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct LevelLoading;
+///
+/// #[derive(Resource)]
+/// struct LevelFinished(bool);
+///
+/// fn load_next_level(mut deferred: DeferredWorld) {
+///     if deferred.resource::<LevelFinished>().0 {
+///         deferred.resource_mut::<LevelFinished>().0 = false;
+///         deferred.run_schedule(LevelLoading);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(load_next_level);
+/// ```
+pub struct DeferredWorld<'w, 's> {
+    world: UnsafeWorldCell<'w>,
+    commands: Commands<'w, 's>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+// SAFETY: `write_all` access is set and conflicts result in a panic
+unsafe impl SystemParam for DeferredWorld<'_, '_> {
+    type State = <Commands<'static, 'static> as SystemParam>::State;
+    type Item<'w, 's> = DeferredWorld<'w, 's>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let mut filtered_access = FilteredAccess::default();
+        filtered_access.write_all();
+        if !system_meta
+            .component_access_set
+            .combined_access()
+            .is_compatible(filtered_access.access())
+        {
+            panic!(
+                "DeferredWorld in system {} conflicts with a previous system parameter. Allowing this would break Rust's mutability rules",
+                system_meta.name
+            );
+        }
+        system_meta.component_access_set.add(filtered_access);
+        system_meta.archetype_component_access.write_all();
+
+        Commands::init_state(world, system_meta)
+    }
+
+    fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {
+        Commands::apply(state, system_meta, world);
+    }
+
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: &'w World,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        DeferredWorld {
+            world: world.as_unsafe_world_cell_migration_internal(),
+            // SAFETY: `Commands` only accesses the command queue and `Entities`, which
+            // don't conflict with the component and resource access of the `DeferredWorld`.
+            commands: unsafe { Commands::get_param(state, system_meta, world, change_tick) },
+            last_run: system_meta.last_run,
+            this_run: change_tick,
+        }
+    }
+}
+
+impl<'w, 's> DeferredWorld<'w, 's> {
+    /// Returns the [`Commands`] of this system, for structural changes like spawning entities
+    /// or inserting components.
+    pub fn commands(&mut self) -> &mut Commands<'w, 's> {
+        &mut self.commands
+    }
+
+    /// Retrieves a reference to the component `T` of the given `entity`.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component.
+    pub fn get<T: Component>(&self, entity: Entity) -> Option<&T> {
+        // SAFETY: the `DeferredWorld` has read access to all components, and the `&self`
+        // borrow ensures no mutable reference obtained through it exists.
+        unsafe { self.world.get_entity(entity)?.get::<T>() }
+    }
+
+    /// Retrieves a mutable reference to the component `T` of the given `entity`.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component.
+    pub fn get_mut<T: Component>(&mut self, entity: Entity) -> Option<Mut<'_, T>> {
+        // SAFETY: the `DeferredWorld` has write access to all components, and the `&mut self`
+        // borrow ensures no other reference obtained through it exists.
+        unsafe {
+            self.world
+                .get_entity(entity)?
+                .get_mut_using_ticks::<T>(self.last_run, self.this_run)
+        }
+    }
+
+    /// Retrieves a reference to the resource `R`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource doesn't exist.
+    pub fn resource<R: Resource>(&self) -> &R {
+        self.get_resource().unwrap_or_else(|| {
+            panic!(
+                "Requested resource {} does not exist in the `World`.",
+                std::any::type_name::<R>()
+            )
+        })
+    }
+
+    /// Retrieves a mutable reference to the resource `R`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource doesn't exist.
+    pub fn resource_mut<R: Resource>(&mut self) -> Mut<'_, R> {
+        self.get_resource_mut().unwrap_or_else(|| {
+            panic!(
+                "Requested resource {} does not exist in the `World`.",
+                std::any::type_name::<R>()
+            )
+        })
+    }
+
+    /// Retrieves a reference to the resource `R`, if it exists.
+    pub fn get_resource<R: Resource>(&self) -> Option<&R> {
+        // SAFETY: the `DeferredWorld` has read access to all resources, and the `&self`
+        // borrow ensures no mutable reference obtained through it exists.
+        unsafe { self.world.get_resource::<R>() }
+    }
+
+    /// Retrieves a mutable reference to the resource `R`, if it exists.
+    pub fn get_resource_mut<R: Resource>(&mut self) -> Option<Mut<'_, R>> {
+        let component_id = self.world.components().resource_id::<R>()?;
+        // SAFETY: the `DeferredWorld` has write access to all resources, and the `&mut self`
+        // borrow ensures no other reference obtained through it exists.
+        let (ptr, ticks) = unsafe { self.world.get_resource_with_ticks(component_id) }?;
+        Some(Mut {
+            // SAFETY: `component_id` belongs to `R` and no other reference to the resource exists
+            value: unsafe { ptr.assert_unique().deref_mut::<R>() },
+            // SAFETY: no other reference to the ticks exists
+            ticks: unsafe { TicksMut::from_tick_cells(ticks, self.last_run, self.this_run) },
+        })
+    }
+
+    /// Sends the `event` immediately, so that readers running later in the frame see it.
+    ///
+    /// Returns `false` if the [`Events<E>`] resource doesn't exist.
+    pub fn send_event<E: Event>(&mut self, event: E) -> bool {
+        match self.get_resource_mut::<Events<E>>() {
+            Some(mut events) => {
+                events.send(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Runs the [`Schedule`](crate::schedule::Schedule) associated with the `label` a single time,
+    /// the next time the system's buffers are applied.
+    ///
+    /// See [`World::run_schedule`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule doesn't exist when the operation is performed.
+    pub fn run_schedule(&mut self, label: impl ScheduleLabel) {
+        self.commands.add(RunSchedule {
+            label: Box::new(label),
+        });
+    }
+
+    /// Runs the `system` a single time with exclusive world access, applying its buffers
+    /// immediately afterwards, the next time the system's buffers are applied.
+    ///
+    /// The system is initialized every time it is run, so its [`Local`](crate::system::Local)
+    /// state and change detection ticks are not preserved.
+    pub fn run_system<M>(&mut self, system: impl IntoSystem<(), (), M>) {
+        self.commands.add(RunSystem {
+            system: Box::new(IntoSystem::into_system(system)),
+        });
+    }
+}
+
+/// A [`Command`] that runs the [`Schedule`](crate::schedule::Schedule) associated with the
+/// `label` a single time. See [`DeferredWorld::run_schedule`].
+pub struct RunSchedule {
+    /// The label of the schedule to run.
+    pub label: BoxedScheduleLabel,
+}
+
+impl Command for RunSchedule {
+    fn write(self, world: &mut World) {
+        world.run_schedule_ref(&*self.label);
+    }
+}
+
+/// A [`Command`] that runs a system a single time. See [`DeferredWorld::run_system`].
+pub struct RunSystem {
+    /// The system to run. It is initialized right before it runs.
+    pub system: BoxedSystem,
+}
+
+impl Command for RunSystem {
+    fn write(mut self, world: &mut World) {
+        self.system.initialize(world);
+        self.system.run((), world);
+        self.system.apply_buffers(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeferredWorld;
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ScheduleLabel, Schedules},
+    };
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Nested;
+
+    fn increment(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn run_schedule() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Schedules>();
+
+        let mut nested = Schedule::new();
+        nested.add_system(increment);
+        world.add_schedule(nested, Nested);
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(|mut deferred: DeferredWorld| {
+            deferred.run_schedule(Nested);
+            deferred.run_schedule(Nested);
+        });
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn run_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(|mut deferred: DeferredWorld| {
+            deferred.run_system(increment);
+            deferred.run_system(|mut commands: Commands| {
+                commands.spawn(A);
+            });
+        });
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+    }
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    #[derive(Resource)]
+    struct Target(Entity);
+
+    #[test]
+    fn direct_mutation() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Events<Damaged>>();
+        let entity = world.spawn(Health(10)).id();
+        world.insert_resource(Target(entity));
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(|mut deferred: DeferredWorld| {
+            let target = deferred.resource::<Target>().0;
+            deferred.get_mut::<Health>(target).unwrap().0 -= 3;
+            deferred.resource_mut::<Counter>().0 += 1;
+            assert!(deferred.send_event(Damaged));
+            deferred.commands().spawn(A);
+        });
+        // Changes made through the `DeferredWorld` are visible to change detection
+        schedule.add_system(
+            (|health: Query<Ref<Health>>, counter: Res<Counter>| {
+                assert!(health.single().is_changed());
+                assert!(counter.is_changed());
+            })
+            .after(apply_system_buffers),
+        );
+
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Health>(entity).unwrap().0, 7);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert_eq!(world.resource::<Events<Damaged>>().len(), 1);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+    }
+
+    struct Damaged;
+
+    #[test]
+    #[should_panic]
+    fn conflicts_with_other_access() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(|_counter: Res<Counter>, _deferred: DeferredWorld| {});
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn conflicts_with_other_systems() {
+        use crate::schedule::{LogLevel, ScheduleBuildSettings};
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let mut schedule = Schedule::new();
+        schedule.set_build_settings(ScheduleBuildSettings {
+            ambiguity_detection: LogLevel::Error,
+            ..Default::default()
+        });
+        // No data access, so no ambiguity
+        schedule.add_systems((|_deferred: DeferredWorld| {}, |_commands: Commands| {}));
+        schedule.run(&mut world);
+
+        schedule.add_system(|_counter: Res<Counter>| {});
+        assert!(schedule.initialize(&mut world).is_err());
+    }
+}
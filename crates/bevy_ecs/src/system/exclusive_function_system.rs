@@ -4,7 +4,7 @@ use crate::{
     query::Access,
     system::{
         check_system_change_tick, ExclusiveSystemParam, ExclusiveSystemParamItem, In, IntoSystem,
-        System, SystemMeta,
+        LocalValue, System, SystemMeta,
     },
     world::{World, WorldId},
 };
@@ -135,6 +135,12 @@ where
         self.world_id = Some(world.id());
         self.system_meta.last_run = world.change_tick().relative_to(Tick::MAX);
         self.param_state = Some(F::Param::init(world, &mut self.system_meta));
+
+        self.system_meta.assert_local_values_taken();
+    }
+
+    fn set_local_value(&mut self, value: LocalValue) {
+        self.system_meta.set_local_value(value);
     }
 
     fn update_archetype_component_access(&mut self, _world: &World) {}
@@ -50,8 +50,11 @@ impl<'_s, T: FromWorld + Send + 'static> ExclusiveSystemParam for Local<'_s, T>
     type State = SyncCell<T>;
     type Item<'s> = Local<'s, T>;
 
-    fn init(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
-        SyncCell::new(T::from_world(world))
+    fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let value = system_meta
+            .take_local_value()
+            .unwrap_or_else(|| T::from_world(world));
+        SyncCell::new(value)
     }
 
     fn get_param<'s>(state: &'s mut Self::State, _system_meta: &SystemMeta) -> Self::Item<'s> {
@@ -3,7 +3,10 @@ use crate::{
     component::{ComponentId, Tick},
     prelude::FromWorld,
     query::{Access, FilteredAccessSet},
-    system::{check_system_change_tick, ReadOnlySystemParam, System, SystemParam, SystemParamItem},
+    system::{
+        check_system_change_tick, LocalValue, ReadOnlySystemParam, System, SystemParam,
+        SystemParamItem,
+    },
     world::{World, WorldId},
 };
 
@@ -13,7 +16,6 @@ use std::{any::TypeId, borrow::Cow, marker::PhantomData};
 use super::ReadOnlySystem;
 
 /// The metadata of a [`System`].
-#[derive(Clone)]
 pub struct SystemMeta {
     pub(crate) name: Cow<'static, str>,
     pub(crate) component_access_set: FilteredAccessSet<ComponentId>,
@@ -22,6 +24,24 @@ pub struct SystemMeta {
     // SystemParams from overriding each other
     is_send: bool,
     pub(crate) last_run: Tick,
+    /// Initial values for [`Local`](crate::system::Local) parameters, taken when they are
+    /// initialized.
+    local_values: Vec<LocalValue>,
+}
+
+// Manual impl, as the initial `Local` values can't be cloned. They are only needed until the
+// system is initialized.
+impl Clone for SystemMeta {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            component_access_set: self.component_access_set.clone(),
+            archetype_component_access: self.archetype_component_access.clone(),
+            is_send: self.is_send,
+            last_run: self.last_run,
+            local_values: Vec::new(),
+        }
+    }
 }
 
 impl SystemMeta {
@@ -32,6 +52,7 @@ impl SystemMeta {
             component_access_set: FilteredAccessSet::default(),
             is_send: true,
             last_run: Tick::new(0),
+            local_values: Vec::new(),
         }
     }
 
@@ -54,6 +75,37 @@ impl SystemMeta {
     pub fn set_non_send(&mut self) {
         self.is_send = false;
     }
+
+    /// Sets the initial value of the first [`Local`](crate::system::Local) parameter of the
+    /// value's type, replacing any previous initial value of that type.
+    pub(crate) fn set_local_value(&mut self, value: LocalValue) {
+        self.local_values
+            .retain(|other| other.type_id != value.type_id);
+        self.local_values.push(value);
+    }
+
+    /// Takes the initial value of a `Local<T>` parameter, if there is one.
+    pub(crate) fn take_local_value<T: 'static>(&mut self) -> Option<T> {
+        let index = self
+            .local_values
+            .iter()
+            .position(|value| value.type_id == TypeId::of::<T>())?;
+        self.local_values.remove(index).downcast()
+    }
+
+    /// Panics if an initial [`Local`](crate::system::Local) value wasn't taken by any parameter
+    /// when the system was initialized.
+    pub(crate) fn assert_local_values_taken(&self) {
+        if let Some(value) = self.local_values.first() {
+            panic!(
+                "System `{}` was given an initial `Local` value of type `{}`, but has no \
+                `Local<{}>` parameter.",
+                self.name,
+                value.type_name(),
+                value.type_name(),
+            );
+        }
+    }
 }
 
 // TODO: Actually use this in FunctionSystem. We should probably only do this once Systems are constructed using a World reference
@@ -486,6 +538,12 @@ where
         self.world_id = Some(world.id());
         self.system_meta.last_run = world.change_tick().relative_to(Tick::MAX);
         self.param_state = Some(F::Param::init_state(world, &mut self.system_meta));
+
+        self.system_meta.assert_local_values_taken();
+    }
+
+    fn set_local_value(&mut self, value: LocalValue) {
+        self.system_meta.set_local_value(value);
     }
 
     fn update_archetype_component_access(&mut self, world: &World) {
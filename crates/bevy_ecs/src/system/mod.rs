@@ -86,7 +86,9 @@
 //! - [`Res`] and `Option<Res>`
 //! - [`ResMut`] and `Option<ResMut>`
 //! - [`Commands`]
+//! - [`DeferredWorld`]
 //! - [`Local`]
+//! - [`Deferred`]
 //! - [`EventReader`](crate::event::EventReader)
 //! - [`EventWriter`](crate::event::EventWriter)
 //! - [`NonSend`] and `Option<NonSend>`
@@ -104,6 +106,7 @@
 
 mod combinator;
 mod commands;
+mod deferred_world;
 mod exclusive_function_system;
 mod exclusive_system_param;
 mod function_system;
@@ -115,6 +118,7 @@ mod system_piping;
 
 pub use combinator::*;
 pub use commands::*;
+pub use deferred_world::*;
 pub use exclusive_function_system::*;
 pub use exclusive_system_param::*;
 pub use function_system::*;
@@ -166,7 +170,7 @@ mod tests {
         prelude::AnyOf,
         query::{Added, Changed, Or, With, Without},
         removal_detection::RemovedComponents,
-        schedule::{apply_system_buffers, IntoSystemConfigs, Schedule},
+        schedule::{apply_system_buffers, IntoSystemConfig, IntoSystemConfigs, Schedule},
         system::{
             Commands, IntoSystem, Local, NonSend, NonSendMut, ParamSet, Query, QueryComponentError,
             Res, ResMut, Resource, System, SystemState,
@@ -600,6 +604,65 @@ mod tests {
         assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
     }
 
+    #[test]
+    fn local_system_with_initial_value() {
+        let mut world = World::default();
+        world.insert_resource(Total(0));
+
+        #[derive(Resource)]
+        struct Total(u32);
+
+        struct Step {
+            value: u32,
+        }
+
+        impl FromWorld for Step {
+            fn from_world(world: &mut World) -> Self {
+                Step {
+                    value: world.resource::<Total>().0 + 1,
+                }
+            }
+        }
+
+        fn count(mut step: Local<Step>, other_step: Local<Step>, mut total: ResMut<Total>) {
+            total.0 += step.value + other_step.value;
+            step.value *= 10;
+        }
+
+        fn count_exclusive(world: &mut World, step: Local<Step>) {
+            world.resource_mut::<Total>().0 += step.value;
+        }
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems(
+            (
+                count.with_local(Step { value: 100 }),
+                count,
+                count_exclusive.with_local(Step { value: 1000 }),
+            )
+                .chain(),
+        );
+
+        // Uninitialized locals are created with `FromWorld` when the schedule is initialized.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Total>().0, (100 + 1) + (1 + 1) + 1000);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<Total>().0,
+            1103 + (1000 + 1) + (10 + 1) + 1000
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "but has no `Local<u32>` parameter")]
+    fn local_value_without_local_param() {
+        fn sys(_local: Local<u8>) {}
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(sys.with_local(1u32));
+        schedule.run(&mut World::default());
+    }
+
     #[test]
     fn non_send_option_system() {
         let mut world = World::default();
@@ -809,6 +872,53 @@ mod tests {
         with_filter.run((), &mut world);
     }
 
+    #[test]
+    fn query_iter_entities() {
+        #[derive(Resource)]
+        struct Expected(Vec<Entity>);
+
+        fn sys(
+            with_c: Query<&A, With<C>>,
+            entity_last: Query<(&A, Entity), With<C>>,
+            changed: Query<&A, Changed<A>>,
+            expected: Res<Expected>,
+            mut ran: Local<bool>,
+        ) {
+            let mut entities = with_c.iter_entities().collect::<Vec<_>>();
+            entities.sort();
+            assert_eq!(entities, expected.0);
+
+            let mut entities = Vec::new();
+            with_c.for_each_entity(|entity| entities.push(entity));
+            entities.sort();
+            assert_eq!(entities, expected.0);
+
+            let mut entities = entity_last
+                .iter()
+                .map(|(_, entity)| entity)
+                .collect::<Vec<_>>();
+            entities.sort();
+            assert_eq!(entities, expected.0);
+
+            // every `A` counts as changed in the first run only
+            let expected_changed = if *ran { 0 } else { 3 };
+            assert_eq!(changed.iter_entities().count(), expected_changed);
+            *ran = true;
+        }
+
+        let mut world = World::default();
+        let mut expected = vec![world.spawn((A, C)).id(), world.spawn((A, B, C)).id()];
+        expected.sort();
+        world.spawn(A);
+        world.spawn(C);
+        world.insert_resource(Expected(expected));
+
+        let mut system = IntoSystem::into_system(sys);
+        system.initialize(&mut world);
+        system.run((), &mut world);
+        system.run((), &mut world);
+    }
+
     #[test]
     #[allow(clippy::too_many_arguments)]
     fn can_have_16_parameters() {
@@ -2,8 +2,9 @@ use crate::{
     component::{Component, Tick},
     entity::Entity,
     query::{
-        BatchingStrategy, QueryCombinationIter, QueryEntityError, QueryIter, QueryManyIter,
-        QueryParIter, QuerySingleError, QueryState, ROQueryItem, ReadOnlyWorldQuery, WorldQuery,
+        BatchingStrategy, EntityWorldQuery, QueryCombinationIter, QueryEntityError, QueryIter,
+        QueryManyIter, QueryParIter, QuerySingleError, QueryState, ROQueryItem, ReadOnlyWorldQuery,
+        WorldQuery,
     },
     world::{Mut, World},
 };
@@ -412,6 +413,41 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the [`Entity`] of each query item.
+    ///
+    /// None of the query's data is fetched, so this is faster than iterating the query and
+    /// picking out an [`Entity`] from each item. Filters still apply.
+    ///
+    /// # Example
+    ///
+    /// Here, the `despawn_system` despawns every entity that has a `Health` component:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(f32);
+    /// fn despawn_system(mut commands: Commands, query: Query<&mut Health>) {
+    ///     for entity in query.iter_entities() {
+    ///         commands.entity(entity).despawn();
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(despawn_system);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`for_each_entity`](Self::for_each_entity) for the closure based alternative.
+    #[inline]
+    pub fn iter_entities(&self) -> QueryIter<'_, 's, EntityWorldQuery<Q>, F> {
+        // SAFETY: `EntityWorldQuery` doesn't access any data, and the filter only reads
+        unsafe {
+            self.state
+                .as_entities()
+                .iter_unchecked_manual(self.world, self.last_run, self.this_run)
+        }
+    }
+
     /// Returns a [`QueryCombinationIter`] over all combinations of `K` read-only query items without repetition.
     ///
     /// # Example
@@ -718,6 +754,42 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
         };
     }
 
+    /// Runs `f` on the [`Entity`] of each query item.
+    ///
+    /// None of the query's data is fetched, so this is faster than running [`for_each`](Self::for_each)
+    /// and picking out an [`Entity`] from each item. Filters still apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// fn player_count_system(query: Query<(), With<Player>>) {
+    ///     let mut players = Vec::new();
+    ///     query.for_each_entity(|entity| players.push(entity));
+    ///     println!("{} players", players.len());
+    /// }
+    /// # bevy_ecs::system::assert_is_system(player_count_system);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`iter_entities`](Self::iter_entities) for the iterator based alternative.
+    #[inline]
+    pub fn for_each_entity(&self, f: impl FnMut(Entity)) {
+        // SAFETY: `EntityWorldQuery` doesn't access any data, and the filter only reads
+        unsafe {
+            self.state.as_entities().for_each_unchecked_manual(
+                self.world,
+                f,
+                self.last_run,
+                self.this_run,
+            );
+        };
+    }
+
     /// Returns a parallel iterator over the query results for the given [`World`].
     ///
     /// This can only be called for read-only queries, see [`par_iter_mut`] for write-queries.
@@ -863,6 +935,32 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
         self.get_many(entities).unwrap()
     }
 
+    /// Returns the read-only query items for the given slice of [`Entity`].
+    ///
+    /// The returned query items are in the same order as the input.
+    /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is returned instead.
+    /// The elements of the slice do not need to be unique, unlike `get_many_slice_mut`.
+    ///
+    /// # See also
+    ///
+    /// - [`get_many`](Self::get_many) if the number of entities is known at compile time.
+    /// - [`get_many_slice_mut`](Self::get_many_slice_mut) to get mutable query items.
+    #[inline]
+    pub fn get_many_slice(
+        &self,
+        entities: &[Entity],
+    ) -> Result<Vec<ROQueryItem<'_, Q>>, QueryEntityError> {
+        // SAFETY: it is the scheduler's responsibility to ensure that `Query` is never handed out on the wrong `World`.
+        unsafe {
+            self.state.get_many_slice_read_only_manual(
+                self.world,
+                entities,
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
     /// Returns the query item for the given [`Entity`].
     ///
     /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is returned instead.
@@ -974,6 +1072,58 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
         self.get_many_mut(entities).unwrap()
     }
 
+    /// Returns the query items for the given slice of [`Entity`].
+    ///
+    /// The returned query items are in the same order as the input.
+    /// Unlike [`iter_many_mut`](Self::iter_many_mut), the entities are checked to be disjoint up front,
+    /// so all of the items can be held at the same time.
+    /// In case of a nonexisting entity, mismatched component or an entity that is included more than once,
+    /// a [`QueryEntityError`] is returned instead, which names the offending entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Group(Vec<Entity>);
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #
+    /// fn share_health(groups: Query<&Group>, mut members: Query<&mut Health>) {
+    ///     for group in &groups {
+    ///         let Ok(mut healths) = members.get_many_slice_mut(&group.0) else {
+    ///             continue;
+    ///         };
+    ///         let total: u32 = healths.iter().map(|health| health.0).sum();
+    ///         for health in &mut healths {
+    ///             health.0 = total / group.0.len() as u32;
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(share_health);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`get_many_mut`](Self::get_many_mut) if the number of entities is known at compile time.
+    /// - [`get_many_slice`](Self::get_many_slice) to get read-only query items.
+    #[inline]
+    pub fn get_many_slice_mut(
+        &mut self,
+        entities: &[Entity],
+    ) -> Result<Vec<Q::Item<'_>>, QueryEntityError> {
+        // SAFETY: scheduler ensures safe Query world access
+        unsafe {
+            self.state.get_many_slice_unchecked_manual(
+                self.world,
+                entities,
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
     /// Returns the query item for the given [`Entity`].
     ///
     /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is returned instead.
@@ -1305,6 +1455,14 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
             .is_empty(self.world, self.last_run, self.this_run)
     }
 
+    /// Returns the number of sparse set lookups a full iteration over the query performs.
+    ///
+    /// See [`QueryState::sparse_set_lookups`] for details.
+    #[inline]
+    pub fn sparse_set_lookups(&self) -> usize {
+        self.state.sparse_set_lookups(self.world)
+    }
+
     /// Returns `true` if the given [`Entity`] matches the query.
     ///
     /// # Example
@@ -2,7 +2,10 @@ use bevy_utils::tracing::warn;
 use core::fmt::Debug;
 
 use crate::component::Tick;
-use crate::{archetype::ArchetypeComponentId, component::ComponentId, query::Access, world::World};
+use crate::{
+    archetype::ArchetypeComponentId, component::ComponentId, query::Access, system::LocalValue,
+    world::World,
+};
 
 use std::any::TypeId;
 use std::borrow::Cow;
@@ -59,6 +62,22 @@ pub trait System: Send + Sync + 'static {
     fn apply_buffers(&mut self, world: &mut World);
     /// Initialize the system.
     fn initialize(&mut self, _world: &mut World);
+    /// Sets the initial value of the system's [`Local`](crate::system::Local) parameter of the
+    /// value's type, which is used instead of [`FromWorld`](crate::world::FromWorld) when the
+    /// system is initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system doesn't support initial `Local` values.
+    /// Function systems instead panic when they are initialized if they don't have a `Local`
+    /// parameter of the value's type.
+    fn set_local_value(&mut self, value: LocalValue) {
+        panic!(
+            "System `{}` doesn't support initial `Local` values, but was given one of type `{}`.",
+            self.name(),
+            value.type_name()
+        );
+    }
     /// Update the system's archetype component [`Access`].
     fn update_archetype_component_access(&mut self, world: &World);
     fn check_change_tick(&mut self, change_tick: Tick);
@@ -17,6 +17,7 @@ pub use bevy_ecs_macros::SystemParam;
 use bevy_ptr::UnsafeCellDeref;
 use bevy_utils::{all_tuples, synccell::SyncCell};
 use std::{
+    any::{Any, TypeId},
     borrow::Cow,
     fmt::Debug,
     ops::{Deref, DerefMut},
@@ -677,6 +678,33 @@ unsafe impl SystemParam for &'_ World {
 /// // .add_system(reset_to_system(my_config))
 /// # assert_is_system(reset_to_system(Config(10)));
 /// ```
+///
+/// Alternatively, the initial value of a [`Local`] can be passed in when adding the system with
+/// [`with_local`](crate::schedule::IntoSystemConfig::with_local), so the same system can be added
+/// multiple times with different configurations:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Default)]
+/// struct Step(u32);
+///
+/// #[derive(Resource, Default)]
+/// struct Counter(u32);
+///
+/// fn count(step: Local<Step>, mut counter: ResMut<Counter>) {
+///     counter.0 += step.0;
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Counter>();
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_system(count.with_local(Step(1)));
+/// schedule.add_system(count.with_local(Step(10)));
+/// schedule.run(&mut world);
+///
+/// assert_eq!(world.resource::<Counter>().0, 11);
+/// ```
 pub struct Local<'s, T: FromWorld + Send + 'static>(pub(crate) &'s mut T);
 
 // SAFETY: Local only accesses internal state
@@ -736,8 +764,11 @@ unsafe impl<'a, T: FromWorld + Send + 'static> SystemParam for Local<'a, T> {
     type State = SyncCell<T>;
     type Item<'w, 's> = Local<'s, T>;
 
-    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
-        SyncCell::new(T::from_world(world))
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let value = system_meta
+            .take_local_value()
+            .unwrap_or_else(|| T::from_world(world));
+        SyncCell::new(value)
     }
 
     #[inline]
@@ -751,6 +782,47 @@ unsafe impl<'a, T: FromWorld + Send + 'static> SystemParam for Local<'a, T> {
     }
 }
 
+/// The initial value of a [`Local`] parameter, used instead of [`FromWorld`] when the system is
+/// initialized.
+///
+/// Usually created by [`IntoSystemConfig::with_local`](crate::schedule::IntoSystemConfig::with_local).
+pub struct LocalValue {
+    pub(crate) type_id: TypeId,
+    type_name: &'static str,
+    value: SyncCell<Box<dyn Any + Send>>,
+}
+
+impl LocalValue {
+    /// Creates the initial value of a `Local<T>` parameter.
+    pub fn new<T: Send + 'static>(value: T) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            value: SyncCell::new(Box::new(value)),
+        }
+    }
+
+    /// Returns the name of the type of the value.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub(crate) fn downcast<T: 'static>(self) -> Option<T> {
+        SyncCell::to_inner(self.value)
+            .downcast()
+            .ok()
+            .map(|value| *value)
+    }
+}
+
+impl Debug for LocalValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalValue")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Types that can be used with [`Deferred<T>`] in systems.
 /// This allows storing system-local data which is used to defer [`World`] mutations.
 ///
@@ -762,10 +834,12 @@ pub trait SystemBuffer: FromWorld + Send + 'static {
     fn apply(&mut self, system_meta: &SystemMeta, world: &mut World);
 }
 
-/// A [`SystemParam`] that stores a buffer which gets applied to the [`World`] at the end of a stage.
+/// A [`SystemParam`] that stores a buffer which gets applied to the [`World`] at the next sync point,
+/// i.e. the next time [`apply_system_buffers`] runs after the system.
 /// This is used internally by [`Commands`] to defer `World` mutations.
 ///
 /// [`Commands`]: crate::system::Commands
+/// [`apply_system_buffers`]: crate::schedule::apply_system_buffers
 ///
 /// # Examples
 ///
@@ -805,7 +879,7 @@ pub trait SystemBuffer: FromWorld + Send + 'static {
 /// struct AlarmFlag(bool);
 ///
 /// impl AlarmFlag {
-///     /// Sounds the alarm at the end of the current stage.
+///     /// Sounds the alarm at the next sync point.
 ///     pub fn flag(&mut self) {
 ///         self.0 = true;
 ///     }
@@ -813,7 +887,7 @@ pub trait SystemBuffer: FromWorld + Send + 'static {
 ///
 /// impl SystemBuffer for AlarmFlag {
 ///     // When `AlarmFlag` is used in a system, this function will get
-///     // called at the end of the system's stage.
+///     // called at the next sync point after the system ran.
 ///     fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
 ///         if self.0 {
 ///             world.resource_mut::<Alarm>().0 = true;
@@ -0,0 +1,345 @@
+use std::marker::PhantomData;
+
+use bevy_utils::HashSet;
+
+use crate::{
+    archetype::Archetype,
+    bundle::Bundle,
+    component::{ComponentId, Components},
+    world::World,
+};
+
+/// A rule about which combinations of components entities are allowed to have,
+/// registered with [`World::register_archetype_invariant`].
+///
+/// Every archetype whose components satisfy the `premise` must also satisfy the `consequence`.
+/// In debug builds, archetypes are checked against all registered invariants when they are
+/// created, panicking if an invariant is violated.
+///
+/// Invariants are checked for every archetype an entity passes through, not just the final one.
+/// Components that require each other should therefore be inserted in the same [`Bundle`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::ArchetypeInvariant;
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// let mut world = World::new();
+/// world.register_archetype_invariant(ArchetypeInvariant::<Player, Health>::requires());
+///
+/// // Fine, as the components are inserted together.
+/// world.spawn((Player, Health(100.0)));
+/// ```
+pub struct ArchetypeInvariant<B1: Bundle, B2: Bundle = B1> {
+    /// Defines which archetypes the invariant applies to.
+    pub premise: ArchetypeStatement<B1>,
+    /// Must be true for every archetype the `premise` is true for.
+    pub consequence: ArchetypeStatement<B2>,
+}
+
+impl<B1: Bundle, B2: Bundle> ArchetypeInvariant<B1, B2> {
+    /// Entities with all components of `B1` must also have all components of `B2`.
+    #[inline]
+    pub fn requires() -> Self {
+        Self {
+            premise: ArchetypeStatement::all_of(),
+            consequence: ArchetypeStatement::all_of(),
+        }
+    }
+
+    /// Entities with all components of `B1` must not have any of the components of `B2`.
+    #[inline]
+    pub fn excludes() -> Self {
+        Self {
+            premise: ArchetypeStatement::all_of(),
+            consequence: ArchetypeStatement::none_of(),
+        }
+    }
+
+    /// Erases the types of the invariant, initializing the components of `B1` and `B2`.
+    pub fn into_untyped(self, world: &mut World) -> UntypedArchetypeInvariant {
+        UntypedArchetypeInvariant {
+            premise: self.premise.into_untyped(world),
+            consequence: self.consequence.into_untyped(world),
+        }
+    }
+}
+
+impl<B: Bundle> ArchetypeInvariant<B> {
+    /// Entities may have at most one of the components of `B`.
+    #[inline]
+    pub fn mutually_exclusive() -> Self {
+        Self {
+            premise: ArchetypeStatement::at_least_one_of(),
+            consequence: ArchetypeStatement::at_most_one_of(),
+        }
+    }
+}
+
+/// A statement about the components of an archetype, used by [`ArchetypeInvariant`].
+pub enum ArchetypeStatement<B: Bundle> {
+    /// The archetype contains all components of `B`.
+    AllOf(PhantomData<B>),
+    /// The archetype contains at least one of the components of `B`.
+    AtLeastOneOf(PhantomData<B>),
+    /// The archetype contains at most one of the components of `B`.
+    AtMostOneOf(PhantomData<B>),
+    /// The archetype contains none of the components of `B`.
+    NoneOf(PhantomData<B>),
+}
+
+impl<B: Bundle> ArchetypeStatement<B> {
+    /// Creates an [`ArchetypeStatement::AllOf`].
+    #[inline]
+    pub const fn all_of() -> Self {
+        ArchetypeStatement::AllOf(PhantomData)
+    }
+
+    /// Creates an [`ArchetypeStatement::AtLeastOneOf`].
+    #[inline]
+    pub const fn at_least_one_of() -> Self {
+        ArchetypeStatement::AtLeastOneOf(PhantomData)
+    }
+
+    /// Creates an [`ArchetypeStatement::AtMostOneOf`].
+    #[inline]
+    pub const fn at_most_one_of() -> Self {
+        ArchetypeStatement::AtMostOneOf(PhantomData)
+    }
+
+    /// Creates an [`ArchetypeStatement::NoneOf`].
+    #[inline]
+    pub const fn none_of() -> Self {
+        ArchetypeStatement::NoneOf(PhantomData)
+    }
+
+    /// Erases the type of the statement, initializing the components of `B`.
+    pub fn into_untyped(self, world: &mut World) -> UntypedArchetypeStatement {
+        let mut component_ids = HashSet::default();
+        B::component_ids(&mut world.components, &mut world.storages, &mut |id| {
+            component_ids.insert(id);
+        });
+        match self {
+            ArchetypeStatement::AllOf(_) => UntypedArchetypeStatement::AllOf(component_ids),
+            ArchetypeStatement::AtLeastOneOf(_) => {
+                UntypedArchetypeStatement::AtLeastOneOf(component_ids)
+            }
+            ArchetypeStatement::AtMostOneOf(_) => {
+                UntypedArchetypeStatement::AtMostOneOf(component_ids)
+            }
+            ArchetypeStatement::NoneOf(_) => UntypedArchetypeStatement::NoneOf(component_ids),
+        }
+    }
+}
+
+/// A type-erased [`ArchetypeInvariant`], for use with dynamic components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UntypedArchetypeInvariant {
+    /// Defines which archetypes the invariant applies to.
+    pub premise: UntypedArchetypeStatement,
+    /// Must be true for every archetype the `premise` is true for.
+    pub consequence: UntypedArchetypeStatement,
+}
+
+impl UntypedArchetypeInvariant {
+    /// Returns `true` if the `archetype` upholds the invariant.
+    pub fn test(&self, archetype: &Archetype) -> bool {
+        !self.premise.test(archetype) || self.consequence.test(archetype)
+    }
+
+    /// Panics with a message naming the components involved if the `archetype`
+    /// doesn't uphold the invariant.
+    pub(crate) fn validate(&self, archetype: &Archetype, components: &Components) {
+        if !self.test(archetype) {
+            panic!(
+                "Archetype invariant violated: entities with {} must have {}, but an entity with \
+                [{}] was created. Components that require each other must be inserted in the \
+                same bundle.",
+                self.premise.describe(components),
+                self.consequence.describe(components),
+                component_names(archetype.components(), components),
+            );
+        }
+    }
+}
+
+/// A type-erased [`ArchetypeStatement`], for use with dynamic components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UntypedArchetypeStatement {
+    /// The archetype contains all of the components.
+    AllOf(HashSet<ComponentId>),
+    /// The archetype contains at least one of the components.
+    AtLeastOneOf(HashSet<ComponentId>),
+    /// The archetype contains at most one of the components.
+    AtMostOneOf(HashSet<ComponentId>),
+    /// The archetype contains none of the components.
+    NoneOf(HashSet<ComponentId>),
+}
+
+impl UntypedArchetypeStatement {
+    /// Returns the components the statement is about.
+    pub fn component_ids(&self) -> &HashSet<ComponentId> {
+        match self {
+            UntypedArchetypeStatement::AllOf(ids)
+            | UntypedArchetypeStatement::AtLeastOneOf(ids)
+            | UntypedArchetypeStatement::AtMostOneOf(ids)
+            | UntypedArchetypeStatement::NoneOf(ids) => ids,
+        }
+    }
+
+    /// Returns `true` if the statement is true for the `archetype`.
+    pub fn test(&self, archetype: &Archetype) -> bool {
+        let mut contained = self
+            .component_ids()
+            .iter()
+            .filter(|&&id| archetype.contains(id));
+        match self {
+            UntypedArchetypeStatement::AllOf(ids) => contained.count() == ids.len(),
+            UntypedArchetypeStatement::AtLeastOneOf(_) => contained.next().is_some(),
+            UntypedArchetypeStatement::AtMostOneOf(_) => contained.nth(1).is_none(),
+            UntypedArchetypeStatement::NoneOf(_) => contained.next().is_none(),
+        }
+    }
+
+    fn describe(&self, components: &Components) -> String {
+        let quantifier = match self {
+            UntypedArchetypeStatement::AllOf(_) => "all of",
+            UntypedArchetypeStatement::AtLeastOneOf(_) => "at least one of",
+            UntypedArchetypeStatement::AtMostOneOf(_) => "at most one of",
+            UntypedArchetypeStatement::NoneOf(_) => "none of",
+        };
+        let names = component_names(self.component_ids().iter().copied(), components);
+        format!("{quantifier} [{names}]")
+    }
+}
+
+fn component_names(ids: impl Iterator<Item = ComponentId>, components: &Components) -> String {
+    let mut names: Vec<_> = ids
+        .map(|id| components.get_name(id).unwrap_or("<unknown component>"))
+        .collect();
+    names.sort_unstable();
+    names.join(", ")
+}
+
+impl World {
+    /// Registers an [`ArchetypeInvariant`], a rule about which combinations of components
+    /// entities are allowed to have.
+    ///
+    /// In debug builds, all existing and future archetypes are checked against the invariant,
+    /// panicking with a message naming the components involved if it is violated.
+    /// In release builds, invariants are not checked.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::ArchetypeInvariant;
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let mut world = World::new();
+    /// world.register_archetype_invariant(ArchetypeInvariant::<(Player, Enemy)>::mutually_exclusive());
+    ///
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     world.spawn((Player, Enemy));
+    /// }));
+    /// // Spawning panics in debug builds only.
+    /// assert_eq!(result.is_err(), cfg!(debug_assertions));
+    /// ```
+    pub fn register_archetype_invariant<B1: Bundle, B2: Bundle>(
+        &mut self,
+        invariant: ArchetypeInvariant<B1, B2>,
+    ) {
+        let invariant = invariant.into_untyped(self);
+        self.register_untyped_archetype_invariant(invariant);
+    }
+
+    /// Registers an [`UntypedArchetypeInvariant`].
+    ///
+    /// See [`World::register_archetype_invariant`] for details.
+    pub fn register_untyped_archetype_invariant(&mut self, invariant: UntypedArchetypeInvariant) {
+        #[cfg(debug_assertions)]
+        for archetype in self.archetypes.iter() {
+            invariant.validate(archetype, &self.components);
+        }
+        self.archetypes.invariants.push(invariant);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArchetypeInvariant, ArchetypeStatement};
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct C;
+
+    #[test]
+    fn invariants_are_upheld() {
+        let mut world = World::new();
+        world.register_archetype_invariant(ArchetypeInvariant::<A, B>::requires());
+        world.register_archetype_invariant(ArchetypeInvariant::<(A, C)>::mutually_exclusive());
+        world.register_archetype_invariant(ArchetypeInvariant::<C, A>::excludes());
+        world.register_archetype_invariant(ArchetypeInvariant {
+            premise: ArchetypeStatement::<C>::all_of(),
+            consequence: ArchetypeStatement::<(A, B)>::at_least_one_of(),
+        });
+
+        let entity = world.spawn((A, B)).id();
+        world.entity_mut(entity).remove::<A>();
+        world.spawn((C, B));
+        world.spawn(B);
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(
+            expected = "entities with all of [bevy_ecs::world::archetype_invariants::tests::A] must have all of [bevy_ecs::world::archetype_invariants::tests::B]"
+        )
+    )]
+    fn requires_violated_on_insert() {
+        let mut world = World::new();
+        world.register_archetype_invariant(ArchetypeInvariant::<A, B>::requires());
+
+        let entity = world.spawn(B).id();
+        world.entity_mut(entity).remove::<B>().insert(A);
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Archetype invariant violated")
+    )]
+    fn mutually_exclusive_violated_on_spawn() {
+        let mut world = World::new();
+        world.register_archetype_invariant(ArchetypeInvariant::<(A, C)>::mutually_exclusive());
+
+        world.spawn((A, C));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "Archetype invariant violated")
+    )]
+    fn existing_archetypes_are_validated() {
+        let mut world = World::new();
+        world.spawn((A, B));
+
+        world.register_archetype_invariant(ArchetypeInvariant::<A, C>::requires());
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     bundle::{Bundle, BundleInfo},
     change_detection::MutUntyped,
     component::{Component, ComponentId, ComponentTicks, Components, StorageType},
-    entity::{Entities, Entity, EntityLocation},
+    entity::{DeferredDespawns, DespawnResult, Entities, Entity, EntityHandles, EntityLocation},
     removal_detection::RemovedComponentEvents,
     storage::Storages,
     world::{Mut, World},
@@ -87,6 +87,17 @@ impl<'w> EntityRef<'w> {
             .contains_type_id(type_id)
     }
 
+    /// Returns an iterator over the [`ComponentId`]s of the components of the entity.
+    ///
+    /// Together with [`EntityRef::get_by_id`], this allows walking all components of an entity
+    /// without knowing their types at compile time, e.g. for inspectors and serializers.
+    /// Use [`World::components`] to look up the [`ComponentInfo`](crate::component::ComponentInfo)
+    /// of each component.
+    #[inline]
+    pub fn components(&self) -> impl Iterator<Item = ComponentId> + 'w {
+        self.world.archetypes[self.location.archetype_id].components()
+    }
+
     #[inline]
     pub fn get<T: Component>(&self) -> Option<&'w T> {
         // SAFETY: &self implies shared access for duration of returned value
@@ -219,6 +230,15 @@ impl<'w> EntityMut<'w> {
             .contains_type_id(type_id)
     }
 
+    /// Returns an iterator over the [`ComponentId`]s of the components of the entity.
+    ///
+    /// Together with [`EntityMut::get_by_id`] and [`EntityMut::get_mut_by_id`], this allows
+    /// walking all components of an entity without knowing their types at compile time.
+    #[inline]
+    pub fn components(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.archetype().components()
+    }
+
     #[inline]
     pub fn get<T: Component>(&self) -> Option<&'_ T> {
         // SAFETY: &self implies shared access for duration of returned value
@@ -496,7 +516,28 @@ impl<'w> EntityMut<'w> {
         self
     }
 
-    pub fn despawn(self) {
+    /// Despawns the entity, unless a [`StrongEntity`](crate::entity::StrongEntity) handle to it
+    /// exists. See [`World::despawn`].
+    pub fn despawn(self) -> DespawnResult {
+        let deferred = self
+            .get::<EntityHandles>()
+            .map_or(false, |handles| !handles.request_despawn());
+        if !deferred {
+            self.despawn_released();
+            return DespawnResult::Despawned;
+        }
+        let entity = self.entity;
+        let mut deferred = self
+            .world
+            .get_resource_or_insert_with(DeferredDespawns::default);
+        if !deferred.0.contains(&entity) {
+            deferred.0.push(entity);
+        }
+        DespawnResult::Deferred
+    }
+
+    /// Despawns the entity, ignoring its [`StrongEntity`](crate::entity::StrongEntity) handles.
+    pub(crate) fn despawn_released(self) {
         debug!("Despawning entity {:?}", self.entity);
         let world = self.world;
         world.flush();
@@ -754,6 +795,7 @@ unsafe fn remove_bundle_from_archetype(
         }
 
         let new_archetype_id = archetypes.get_id_or_insert(
+            components,
             next_table_id,
             next_table_components,
             next_sparse_set_components,
@@ -929,6 +971,68 @@ mod tests {
         assert!(entity.get_mut_by_id(invalid_component_id).is_none());
     }
 
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct SparseComponent(u32);
+
+    #[test]
+    fn entity_ref_components() {
+        let mut world = World::new();
+        let entity = world.spawn((TestComponent(1), SparseComponent(2))).id();
+        let test_id = world.init_component::<TestComponent>();
+        let sparse_id = world.init_component::<SparseComponent>();
+
+        let entity = world.entity(entity);
+        let mut components: Vec<_> = entity.components().collect();
+        components.sort();
+        assert_eq!(components, [test_id, sparse_id]);
+
+        let sum: u32 = entity
+            .components()
+            .map(|id| {
+                let ptr = entity.get_by_id(id).unwrap();
+                // SAFETY: the component types match the ids
+                unsafe {
+                    if id == test_id {
+                        ptr.deref::<TestComponent>().0
+                    } else {
+                        ptr.deref::<SparseComponent>().0
+                    }
+                }
+            })
+            .sum();
+        assert_eq!(sum, 3);
+
+        let empty = world.spawn_empty().id();
+        assert_eq!(world.entity(empty).components().count(), 0);
+    }
+
+    #[test]
+    fn entity_mut_components() {
+        let mut world = World::new();
+        let entity = world.spawn((TestComponent(1), SparseComponent(2))).id();
+        let test_id = world.init_component::<TestComponent>();
+
+        let mut entity_mut = world.entity_mut(entity);
+        let components: Vec<_> = entity_mut.components().collect();
+        assert_eq!(components.len(), 2);
+        for id in components {
+            let component = entity_mut.get_mut_by_id(id).unwrap();
+            // SAFETY: the component types match the ids
+            unsafe {
+                let ptr = component.into_inner();
+                if id == test_id {
+                    ptr.deref_mut::<TestComponent>().0 += 10;
+                } else {
+                    ptr.deref_mut::<SparseComponent>().0 += 10;
+                }
+            }
+        }
+
+        assert_eq!(entity_mut.get::<TestComponent>().unwrap().0, 11);
+        assert_eq!(entity_mut.get::<SparseComponent>().unwrap().0, 12);
+    }
+
     // regression test for https://github.com/bevyengine/bevy/pull/7387
     #[test]
     fn entity_mut_world_scope_panic() {
@@ -0,0 +1,163 @@
+use crate::{
+    component::ComponentId,
+    entity::Entity,
+    event::{Event, Events},
+    storage::Storages,
+    world::World,
+};
+use bevy_utils::HashMap;
+
+/// The kind of storage a [`TypeMemoryUsage`] entry was measured in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryUsageKind {
+    /// Component values stored in [`Table`](crate::storage::Table)s.
+    Table,
+    /// Component values stored in [`ComponentSparseSet`](crate::storage::ComponentSparseSet)s.
+    SparseSet,
+    /// A [`Resource`](crate::system::Resource) or non-send resource.
+    Resource,
+    /// The event buffers of an [`Events`] resource.
+    Events,
+}
+
+/// The memory used by a single component, resource or event type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMemoryUsage {
+    /// The [`ComponentId`] of the component or resource.
+    pub component_id: ComponentId,
+    /// The type name of the component or resource.
+    pub name: String,
+    /// The storage the memory was measured in.
+    pub kind: MemoryUsageKind,
+    /// The number of bytes allocated for this type.
+    pub bytes: usize,
+}
+
+/// A summary of the memory allocated by the storages of a [`World`], returned by
+/// [`World::memory_usage`].
+///
+/// All sizes are in bytes and count allocated capacity, not just the values that are currently
+/// stored. Memory owned by the values themselves (like the contents of a `Vec` component) is not
+/// included.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Bytes allocated by [`Table`](crate::storage::Table)s, including their entity lists.
+    pub tables: usize,
+    /// Bytes allocated by [`ComponentSparseSet`](crate::storage::ComponentSparseSet)s, including
+    /// their entity lookups.
+    pub sparse_sets: usize,
+    /// Bytes allocated by resources and non-send resources.
+    pub resources: usize,
+    /// Bytes allocated by the buffers of [`Events`] registered with
+    /// [`World::track_event_memory_usage`].
+    pub events: usize,
+    /// The memory used by each type, sorted from largest to smallest.
+    pub types: Vec<TypeMemoryUsage>,
+}
+
+impl MemoryUsage {
+    /// Returns the total number of bytes allocated.
+    pub fn total(&self) -> usize {
+        self.tables + self.sparse_sets + self.resources + self.events
+    }
+
+    /// Returns the `n` types that use the most memory.
+    pub fn top(&self, n: usize) -> &[TypeMemoryUsage] {
+        &self.types[..n.min(self.types.len())]
+    }
+}
+
+impl World {
+    /// Returns a [`MemoryUsage`] summary of the bytes allocated by the tables, sparse sets,
+    /// resources and event buffers of this [`World`].
+    ///
+    /// Event buffers are only included for event types registered with
+    /// [`World::track_event_memory_usage`], which `App::add_event` does automatically.
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|i| Position(i as f32, 0.0)));
+    ///
+    /// let usage = world.memory_usage();
+    /// assert!(usage.tables >= 100 * std::mem::size_of::<Position>());
+    /// assert_eq!(usage.top(1)[0].name, std::any::type_name::<Position>());
+    /// ```
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let Storages {
+            tables,
+            sparse_sets,
+            resources,
+            non_send_resources,
+        } = &self.storages;
+
+        let mut usage = MemoryUsage::default();
+        let mut types = HashMap::<(ComponentId, MemoryUsageKind), usize>::default();
+
+        for table in tables.iter() {
+            usage.tables += table.entity_capacity() * std::mem::size_of::<Entity>();
+            for (component_id, column) in table.iter_with_ids() {
+                let bytes = column.allocated_bytes();
+                usage.tables += bytes;
+                *types
+                    .entry((component_id, MemoryUsageKind::Table))
+                    .or_default() += bytes;
+            }
+        }
+
+        for (component_id, sparse_set) in sparse_sets.iter() {
+            let bytes = sparse_set.allocated_bytes();
+            usage.sparse_sets += bytes;
+            types.insert((component_id, MemoryUsageKind::SparseSet), bytes);
+        }
+
+        let resources = resources
+            .iter()
+            .map(|(id, data)| (id, data.allocated_bytes()))
+            .chain(
+                non_send_resources
+                    .iter()
+                    .map(|(id, data)| (id, data.allocated_bytes())),
+            );
+        for (component_id, bytes) in resources {
+            usage.resources += bytes;
+            types.insert((component_id, MemoryUsageKind::Resource), bytes);
+        }
+
+        for (component_id, allocated_bytes) in self.tracked_events.iter() {
+            let bytes = allocated_bytes(self);
+            usage.events += bytes;
+            types.insert((*component_id, MemoryUsageKind::Events), bytes);
+        }
+
+        usage.types = types
+            .into_iter()
+            .map(|((component_id, kind), bytes)| TypeMemoryUsage {
+                component_id,
+                name: self.components.get_name(component_id).unwrap().to_string(),
+                kind,
+                bytes,
+            })
+            .collect();
+        usage
+            .types
+            .sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        usage
+    }
+
+    /// Includes the buffers of the [`Events<E>`] resource in [`World::memory_usage`].
+    ///
+    /// This is called by `App::add_event`, so it is only needed for events that are added manually.
+    pub fn track_event_memory_usage<E: Event>(&mut self) {
+        let component_id = self.components.init_resource::<Events<E>>();
+        self.tracked_events.insert(component_id, |world| {
+            world
+                .get_resource::<Events<E>>()
+                .map_or(0, Events::allocated_bytes)
+        });
+    }
+}
@@ -1,25 +1,35 @@
+mod archetype_invariants;
 mod entity_ref;
 pub mod error;
+mod memory_usage;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 mod world_cell;
+mod world_handle;
 
 pub use crate::change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD};
+pub use archetype_invariants::*;
 pub use entity_ref::{EntityMut, EntityRef};
+pub use memory_usage::*;
 pub use spawn_batch::*;
 pub use world_cell::*;
+pub use world_handle::*;
 
 use crate::{
     archetype::{ArchetypeComponentId, ArchetypeId, ArchetypeRow, Archetypes},
     bundle::{Bundle, BundleInserter, BundleSpawner, Bundles},
-    change_detection::{MutUntyped, TicksMut},
+    change_detection::{ChangeTickScanSettings, MutUntyped, TicksMut},
     component::{Component, ComponentDescriptor, ComponentId, ComponentInfo, Components, Tick},
-    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity::{
+        AllocAtWithoutReplacement, DeferredDespawns, DespawnResult, Entities, Entity,
+        EntityHandles, EntityLocation, StrongEntity, WeakEntity,
+    },
     event::{Event, Events},
-    query::{DebugCheckedUnwrap, QueryState, ReadOnlyWorldQuery, WorldQuery},
+    query::{DebugCheckedUnwrap, QueryIter, QueryState, ReadOnlyWorldQuery, WorldQuery},
     removal_detection::RemovedComponentEvents,
+    resource_lifecycle::{ResourceLifecycleEvent, ResourceLifecycleEvents},
     schedule::{Schedule, ScheduleLabel, Schedules},
-    storage::{ResourceData, Storages},
+    storage::{ResourceData, SparseSet, Storages},
     system::Resource,
     world::error::TryRunScheduleError,
 };
@@ -63,11 +73,16 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) removed_components: RemovedComponentEvents,
+    pub(crate) resource_lifecycle_events: ResourceLifecycleEvents,
     /// Access cache used by [WorldCell]. Is only accessed in the `Drop` impl of `WorldCell`.
     pub(crate) archetype_component_access: ArchetypeComponentAccess,
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) change_tick_scan_settings: ChangeTickScanSettings,
+    pub(crate) change_tick_scan_in_progress: bool,
+    /// Measures the event buffers included in [`World::memory_usage`].
+    pub(crate) tracked_events: SparseSet<ComponentId, fn(&World) -> usize>,
 }
 
 impl Default for World {
@@ -80,12 +95,16 @@ impl Default for World {
             storages: Default::default(),
             bundles: Default::default(),
             removed_components: Default::default(),
+            resource_lifecycle_events: Default::default(),
             archetype_component_access: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            change_tick_scan_settings: Default::default(),
+            change_tick_scan_in_progress: false,
+            tracked_events: SparseSet::new(),
         }
     }
 }
@@ -171,6 +190,13 @@ impl World {
         &self.removed_components
     }
 
+    /// Retrieves this world's [`ResourceLifecycleEvents`] collection, which records the resources
+    /// that were inserted or removed.
+    #[inline]
+    pub fn resource_lifecycle_events(&self) -> &ResourceLifecycleEvents {
+        &self.resource_lifecycle_events
+    }
+
     /// Retrieves a [`WorldCell`], which safely enables multiple mutable World accesses at the same
     /// time, provided those accesses do not conflict with each other.
     #[inline]
@@ -608,10 +634,10 @@ impl World {
     }
 
     /// Despawns the given `entity`, if it exists. This will also remove all of the entity's
-    /// [Component]s. Returns `true` if the `entity` is successfully despawned and `false` if
-    /// the `entity` does not exist.
+    /// [Component]s. Returns [`DespawnResult::Despawned`] if the `entity` is successfully despawned
+    /// and [`DespawnResult::NotFound`] if the `entity` does not exist.
     /// ```
-    /// use bevy_ecs::{component::Component, world::World};
+    /// use bevy_ecs::{component::Component, entity::DespawnResult, world::World};
     ///
     /// #[derive(Component)]
     /// struct Position {
@@ -621,19 +647,79 @@ impl World {
     ///
     /// let mut world = World::new();
     /// let entity = world.spawn(Position { x: 0.0, y: 0.0 }).id();
-    /// assert!(world.despawn(entity));
+    /// assert_eq!(world.despawn(entity), DespawnResult::Despawned);
     /// assert!(world.get_entity(entity).is_none());
     /// assert!(world.get::<Position>(entity).is_none());
     /// ```
+    ///
+    /// If a [`StrongEntity`] handle to the entity exists, the entity is only marked for despawning
+    /// and [`DespawnResult::Deferred`] is returned. It is despawned by
+    /// [`World::despawn_released_entities`] once all strong handles are dropped.
     #[inline]
-    pub fn despawn(&mut self, entity: Entity) -> bool {
+    pub fn despawn(&mut self, entity: Entity) -> DespawnResult {
         if let Some(entity) = self.get_entity_mut(entity) {
-            entity.despawn();
-            true
+            entity.despawn()
         } else {
             warn!("error[B0003]: Could not despawn entity {:?} because it doesn't exist in this World.", entity);
-            false
+            DespawnResult::NotFound
+        }
+    }
+
+    /// Returns a [`StrongEntity`] handle to the `entity`, which keeps it from being despawned.
+    ///
+    /// Returns `None` if the entity doesn't exist or its despawn was already requested.
+    ///
+    /// The first handle to an entity inserts a hidden sparse set component into it, which moves
+    /// the entity to another archetype. Use [`EntityCommands::strong_entity`] to create a handle
+    /// without access to the [`World`].
+    ///
+    /// [`EntityCommands::strong_entity`]: crate::system::EntityCommands::strong_entity
+    pub fn strong_entity(&mut self, entity: Entity) -> Option<StrongEntity> {
+        self.entity_handles(entity)?.strong(entity)
+    }
+
+    /// Returns a [`WeakEntity`] handle to the `entity`, which can be used to check whether the
+    /// entity is still alive.
+    ///
+    /// Returns `None` if the entity doesn't exist.
+    ///
+    /// Like [`World::strong_entity`], the first handle to an entity moves it to another
+    /// archetype.
+    pub fn weak_entity(&mut self, entity: Entity) -> Option<WeakEntity> {
+        Some(self.entity_handles(entity)?.weak(entity))
+    }
+
+    fn entity_handles(&mut self, entity: Entity) -> Option<&EntityHandles> {
+        let mut entity_mut = self.get_entity_mut(entity)?;
+        if !entity_mut.contains::<EntityHandles>() {
+            entity_mut.insert(EntityHandles::new());
         }
+        self.get::<EntityHandles>(entity)
+    }
+
+    /// Despawns the entities whose despawn was deferred by a [`StrongEntity`] handle and whose
+    /// strong handles have all been dropped since.
+    ///
+    /// When using `bevy_ecs` as part of the full Bevy engine, this is called at the end of each
+    /// app update, so you don't need to call it manually.
+    pub fn despawn_released_entities(&mut self) {
+        let Some(mut deferred) = self.get_resource_mut::<DeferredDespawns>() else {
+            return;
+        };
+        let mut deferred = std::mem::take(&mut deferred.0);
+        deferred.retain(|&entity| {
+            let Some(entity) = self.get_entity_mut(entity) else {
+                return false;
+            };
+            let released = entity
+                .get::<EntityHandles>()
+                .map_or(true, EntityHandles::is_released);
+            if released {
+                entity.despawn_released();
+            }
+            !released
+        });
+        self.resource_mut::<DeferredDespawns>().0 = deferred;
     }
 
     /// Clears the internal component tracker state.
@@ -677,6 +763,7 @@ impl World {
     /// [`RemovedComponents`]: crate::removal_detection::RemovedComponents
     pub fn clear_trackers(&mut self) {
         self.removed_components.update();
+        self.resource_lifecycle_events.update();
         self.last_change_tick = self.increment_change_tick();
     }
 
@@ -771,6 +858,46 @@ impl World {
         QueryState::new(self)
     }
 
+    /// Runs an ad hoc query on the [`World`], passing its [`QueryIter`] to `f`.
+    ///
+    /// The [`QueryState`] is created for this call only and dropped before returning, so nothing
+    /// needs to be stored between calls. This is intended for tools and exclusive systems which run
+    /// many different queries, each of them only occasionally. Queries which run every frame should
+    /// store their [`QueryState`] instead (see [`World::query`]), as creating one has to match it
+    /// against all archetypes of the world.
+    ///
+    /// ```
+    /// use bevy_ecs::{prelude::*, query::QueryIter};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// let mut world = World::new();
+    /// world.spawn((Health(10), Player));
+    /// world.spawn(Health(5));
+    ///
+    /// let total = world.query_scope(|query: QueryIter<&Health, With<Player>>| {
+    ///     query.map(|health| health.0).sum::<u32>()
+    /// });
+    /// assert_eq!(total, 10);
+    ///
+    /// world.query_scope::<&mut Health, (), _>(|query| {
+    ///     for mut health in query {
+    ///         health.0 += 1;
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn query_scope<Q: WorldQuery, F: ReadOnlyWorldQuery, U>(
+        &mut self,
+        f: impl FnOnce(QueryIter<'_, '_, Q, F>) -> U,
+    ) -> U {
+        let mut state = QueryState::<Q, F>::new(self);
+        f(state.iter_mut(self))
+    }
+
     /// Returns an iterator of entities that had components of type `T` removed
     /// since the last call to [`World::clear_trackers`].
     pub fn removed<T: Component>(&self) -> impl Iterator<Item = Entity> + '_ {
@@ -891,6 +1018,8 @@ impl World {
     pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
         let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
         let (ptr, _) = self.storages.resources.get_mut(component_id)?.remove()?;
+        self.resource_lifecycle_events
+            .send(ResourceLifecycleEvent::Removed(component_id));
         // SAFETY: `component_id` was gotten via looking up the `R` type
         unsafe { Some(ptr.read::<R>()) }
     }
@@ -914,6 +1043,8 @@ impl World {
             .non_send_resources
             .get_mut(component_id)?
             .remove()?;
+        self.resource_lifecycle_events
+            .send(ResourceLifecycleEvent::Removed(component_id));
         // SAFETY: `component_id` was gotten via looking up the `R` type
         unsafe { Some(ptr.read::<R>()) }
     }
@@ -1031,15 +1162,18 @@ impl World {
         let last_change_tick = self.last_change_tick();
 
         let component_id = self.components.init_resource::<R>();
-        let data = self.initialize_resource_internal(component_id);
-        if !data.is_present() {
+        if !self.initialize_resource_internal(component_id).is_present() {
+            let data = self.initialize_resource_internal(component_id);
             OwningPtr::make(func(), |ptr| {
                 // SAFETY: component_id was just initialized and corresponds to resource of type R.
                 unsafe {
                     data.insert(ptr, change_tick);
                 }
             });
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Inserted(component_id));
         }
+        let data = self.initialize_resource_internal(component_id);
 
         // SAFETY: The resource must be present, as we would have inserted it if it was empty.
         let data = unsafe {
@@ -1376,9 +1510,14 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let data = self.initialize_resource_internal(component_id);
+        let was_present = data.is_present();
         // SAFETY: value is valid for component_id, ensured by caller
-        self.initialize_resource_internal(component_id)
-            .insert(value, change_tick);
+        data.insert(value, change_tick);
+        if !was_present {
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Inserted(component_id));
+        }
     }
 
     /// Inserts a new `!Send` resource with the given `value`. Will replace the value if it already
@@ -1401,9 +1540,14 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let data = self.initialize_non_send_internal(component_id);
+        let was_present = data.is_present();
         // SAFETY: value is valid for component_id, ensured by caller
-        self.initialize_non_send_internal(component_id)
-            .insert(value, change_tick);
+        data.insert(value, change_tick);
+        if !was_present {
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Inserted(component_id));
+        }
     }
 
     /// # Panics
@@ -1504,15 +1648,27 @@ impl World {
     /// Iterates all component change ticks and clamps any older than [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE).
     /// This prevents overflow and thus prevents false positives.
     ///
-    /// **Note:** Does nothing if the [`World`] counter has not been incremented at least [`CHECK_TICK_THRESHOLD`](crate::change_detection::CHECK_TICK_THRESHOLD)
-    /// times since the previous pass.
-    // TODO: benchmark and optimize
+    /// **Note:** Does nothing if the [`World`] counter has not been incremented at least
+    /// [`ChangeTickScanSettings::interval`] times since the previous pass started, unless an
+    /// incremental pass is still in progress. See [`World::set_change_tick_scan_settings`].
     pub fn check_change_ticks(&mut self) {
         let change_tick = self.change_tick();
-        if change_tick.relative_to(self.last_check_tick).get() < CHECK_TICK_THRESHOLD {
-            return;
+        let settings = self.change_tick_scan_settings;
+        if !self.change_tick_scan_in_progress {
+            if change_tick.relative_to(self.last_check_tick).get() < settings.interval {
+                return;
+            }
+            self.last_check_tick = change_tick;
+            self.change_tick_scan_in_progress = true;
         }
 
+        // Finish the scan at once if it would otherwise allow changes to get older than `MAX_CHANGE_AGE`.
+        let scan_age = change_tick.relative_to(self.last_check_tick).get();
+        let mut budget = match settings.batch_size {
+            Some(batch_size) if scan_age < CHECK_TICK_THRESHOLD - settings.interval => batch_size,
+            _ => usize::MAX,
+        };
+
         let Storages {
             ref mut tables,
             ref mut sparse_sets,
@@ -1522,8 +1678,11 @@ impl World {
 
         #[cfg(feature = "trace")]
         let _span = bevy_utils::tracing::info_span!("check component ticks").entered();
-        tables.check_change_ticks(change_tick);
-        sparse_sets.check_change_ticks(change_tick);
+        if !tables.check_change_ticks(change_tick, &mut budget)
+            || !sparse_sets.check_change_ticks(change_tick, &mut budget)
+        {
+            return;
+        }
         resources.check_change_ticks(change_tick);
         non_send_resources.check_change_ticks(change_tick);
 
@@ -1531,7 +1690,34 @@ impl World {
             schedules.check_change_ticks(change_tick);
         }
 
-        self.last_check_tick = change_tick;
+        self.change_tick_scan_in_progress = false;
+    }
+
+    /// Returns the [`ChangeTickScanSettings`] used by [`World::check_change_ticks`].
+    #[inline]
+    pub fn change_tick_scan_settings(&self) -> ChangeTickScanSettings {
+        self.change_tick_scan_settings
+    }
+
+    /// Configures how [`World::check_change_ticks`] scans the change ticks of this [`World`],
+    /// for example to spread each scan across multiple frames.
+    ///
+    /// ```
+    /// use bevy_ecs::{change_detection::ChangeTickScanSettings, world::World};
+    ///
+    /// let mut world = World::new();
+    /// world.set_change_tick_scan_settings(ChangeTickScanSettings::incremental(100_000));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`ChangeTickScanSettings::interval`] is greater than [`CHECK_TICK_THRESHOLD`].
+    pub fn set_change_tick_scan_settings(&mut self, settings: ChangeTickScanSettings) {
+        assert!(
+            settings.interval <= CHECK_TICK_THRESHOLD,
+            "change tick scan interval must not be greater than `CHECK_TICK_THRESHOLD`"
+        );
+        self.change_tick_scan_settings = settings;
     }
 
     /// Runs both [`clear_entities`](Self::clear_entities) and [`clear_resources`](Self::clear_resources),
@@ -1557,6 +1743,23 @@ impl World {
     /// This can easily cause systems expecting certain resources to immediately start panicking.
     /// Use with caution.
     pub fn clear_resources(&mut self) {
+        let present = self
+            .storages
+            .resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .map(|(id, _)| id)
+            .chain(
+                self.storages
+                    .non_send_resources
+                    .iter()
+                    .filter(|(_, data)| data.is_present())
+                    .map(|(id, _)| id),
+            );
+        for component_id in present {
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Removed(component_id));
+        }
         self.storages.resources.clear();
         self.storages.non_send_resources.clear();
     }
@@ -1642,10 +1845,12 @@ impl World {
     /// **You should prefer to use the typed API [`World::remove_resource`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
     pub fn remove_resource_by_id(&mut self, component_id: ComponentId) -> Option<()> {
-        self.storages
-            .resources
-            .get_mut(component_id)?
-            .remove_and_drop();
+        let data = self.storages.resources.get_mut(component_id)?;
+        if data.is_present() {
+            data.remove_and_drop();
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Removed(component_id));
+        }
         Some(())
     }
 
@@ -1657,10 +1862,12 @@ impl World {
     /// # Panics
     /// This function will panic if it isn't called from the same thread that the resource was inserted from.
     pub fn remove_non_send_by_id(&mut self, component_id: ComponentId) -> Option<()> {
-        self.storages
-            .non_send_resources
-            .get_mut(component_id)?
-            .remove_and_drop();
+        let data = self.storages.non_send_resources.get_mut(component_id)?;
+        if data.is_present() {
+            data.remove_and_drop();
+            self.resource_lifecycle_events
+                .send(ResourceLifecycleEvent::Removed(component_id));
+        }
         Some(())
     }
 
@@ -1823,10 +2030,12 @@ impl<T: Default> FromWorld for T {
 
 #[cfg(test)]
 mod tests {
-    use super::{FromWorld, World};
+    use super::{FromWorld, MemoryUsageKind, World};
     use crate::{
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
+        entity::DespawnResult,
+        event::Events,
         ptr::OwningPtr,
         system::Resource,
     };
@@ -2174,7 +2383,7 @@ mod tests {
         assert_eq!(entity_counters.len(), 4);
 
         // Despawning first entity and then validating the iteration
-        assert!(world.despawn(ent0));
+        assert_eq!(world.despawn(ent0), DespawnResult::Despawned);
 
         iterate_and_count_entities(&world, &mut entity_counters);
 
@@ -2188,9 +2397,9 @@ mod tests {
         let ent5 = world.spawn(Bar).id();
         let ent6 = world.spawn(Baz).id();
 
-        assert!(world.despawn(ent2));
-        assert!(world.despawn(ent3));
-        assert!(world.despawn(ent4));
+        assert_eq!(world.despawn(ent2), DespawnResult::Despawned);
+        assert_eq!(world.despawn(ent3), DespawnResult::Despawned);
+        assert_eq!(world.despawn(ent4), DespawnResult::Despawned);
 
         iterate_and_count_entities(&world, &mut entity_counters);
 
@@ -2200,9 +2409,9 @@ mod tests {
         assert_eq!(entity_counters.len(), 3);
 
         // Despawning remaining entities and then validating the iteration
-        assert!(world.despawn(ent1));
-        assert!(world.despawn(ent5));
-        assert!(world.despawn(ent6));
+        assert_eq!(world.despawn(ent1), DespawnResult::Despawned);
+        assert_eq!(world.despawn(ent5), DespawnResult::Despawned);
+        assert_eq!(world.despawn(ent6), DespawnResult::Despawned);
 
         iterate_and_count_entities(&world, &mut entity_counters);
 
@@ -2214,4 +2423,165 @@ mod tests {
         let mut world = World::new();
         world.spawn(());
     }
+
+    #[test]
+    fn memory_usage() {
+        #[derive(Component)]
+        struct TableComponent([u8; 64]);
+
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct SparseComponent(u32);
+
+        #[derive(Resource)]
+        struct SmallResource(u8);
+
+        struct TestEvent([u8; 16]);
+
+        let mut world = World::new();
+        world.spawn_batch((0..100).map(|_| TableComponent([0; 64])));
+        world.spawn(SparseComponent(0));
+        world.insert_resource(SmallResource(0));
+        world.track_event_memory_usage::<TestEvent>();
+        world.init_resource::<Events<TestEvent>>();
+        world.send_event(TestEvent([0; 16]));
+
+        let usage = world.memory_usage();
+        assert!(usage.tables >= 100 * 64);
+        assert!(usage.sparse_sets >= std::mem::size_of::<SparseComponent>());
+        assert!(usage.resources >= std::mem::size_of::<SmallResource>());
+        assert!(usage.events >= 16);
+        assert_eq!(
+            usage.total(),
+            usage.tables + usage.sparse_sets + usage.resources + usage.events
+        );
+
+        let top = usage.top(1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].kind, MemoryUsageKind::Table);
+        assert_eq!(top[0].name, std::any::type_name::<TableComponent>());
+        assert!(usage
+            .types
+            .iter()
+            .any(|usage| usage.kind == MemoryUsageKind::Events
+                && usage.name == std::any::type_name::<Events<TestEvent>>()));
+        assert_eq!(usage.top(usize::MAX).len(), usage.types.len());
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_reflect")]
+    fn spawn_reflected() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent, SpawnReflectedError};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Health(u32);
+
+        #[derive(Reflect, Default)]
+        struct NotAComponent;
+
+        #[derive(Reflect, Default)]
+        struct Unregistered;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<NotAComponent>();
+        }
+
+        let entity = world
+            .spawn_reflected(vec![Box::new(Health(7))])
+            .unwrap()
+            .id();
+        assert_eq!(world.get::<Health>(entity), Some(&Health(7)));
+
+        let result = world
+            .spawn_reflected(vec![Box::new(Health(1)), Box::new(NotAComponent)])
+            .map(|entity| entity.id());
+        assert_eq!(
+            result,
+            Err(SpawnReflectedError::UnregisteredComponent {
+                type_name: std::any::type_name::<NotAComponent>().to_string()
+            })
+        );
+        let result = world
+            .spawn_reflected(vec![Box::new(Unregistered)])
+            .map(|entity| entity.id());
+        assert_eq!(
+            result,
+            Err(SpawnReflectedError::UnregisteredType {
+                type_name: std::any::type_name::<Unregistered>().to_string()
+            })
+        );
+        // Nothing is spawned when a value can't be resolved.
+        assert_eq!(world.entities().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_reflect")]
+    fn clone_entity() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectNoClone};
+        use crate::system::{CommandQueue, Commands};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Health(u32);
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component, NoClone)]
+        struct Unique;
+
+        #[derive(Component, PartialEq, Debug)]
+        struct NotReflected;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Unique>();
+        }
+
+        let source = world.spawn((Health(3), Unique, NotReflected)).id();
+        let clone = world.clone_entity(source);
+        assert_ne!(clone, source);
+        assert_eq!(world.get::<Health>(clone), Some(&Health(3)));
+        assert!(world.get::<Unique>(clone).is_none());
+        assert!(world.get::<NotReflected>(clone).is_none());
+        assert!(world.get::<Unique>(source).is_some());
+
+        let mut queue = CommandQueue::default();
+        let clone = Commands::new(&mut queue, &world).clone_entity(source).id();
+        queue.apply(&mut world);
+        assert_eq!(world.get::<Health>(clone), Some(&Health(3)));
+    }
+
+    #[test]
+    fn query_scope() {
+        #[derive(Component, PartialEq, Debug)]
+        struct Value(u32);
+
+        #[derive(Component)]
+        struct Marker;
+
+        let mut world = World::new();
+        let marked = world.spawn((Value(1), Marker)).id();
+        let unmarked = world.spawn(Value(2)).id();
+
+        world.query_scope::<&mut Value, crate::query::With<Marker>, _>(|query| {
+            for mut value in query {
+                value.0 += 10;
+            }
+        });
+        assert_eq!(world.get::<Value>(marked), Some(&Value(11)));
+        assert_eq!(world.get::<Value>(unmarked), Some(&Value(2)));
+
+        let sum =
+            world.query_scope::<&Value, (), _>(|query| query.map(|value| value.0).sum::<u32>());
+        assert_eq!(sum, 13);
+    }
 }
@@ -0,0 +1,415 @@
+use std::{cell::RefCell, ffi::c_void, rc::Rc};
+
+use bevy_ptr::Ptr;
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+use crate::{
+    change_detection::MutUntyped,
+    component::{Component, ComponentId},
+    entity::Entity,
+    query::Access,
+    system::Resource,
+    world::{
+        unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell},
+        Mut, World,
+    },
+};
+
+/// An error returned when accessing the [`World`] through a [`WorldHandle`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldHandleError {
+    /// The handle was used after the scope it was created for ended.
+    #[error("The world handle was used after its scope ended.")]
+    Expired,
+    /// The access of the handle doesn't include the component or resource.
+    #[error("The world handle has no {} access to {component:?}.", if *.write { "write" } else { "read" })]
+    AccessDenied {
+        /// The component or resource.
+        component: ComponentId,
+        /// Whether write access was requested.
+        write: bool,
+    },
+    /// The component or resource is already borrowed in a conflicting way through the handle.
+    #[error("{0:?} is already borrowed through the world handle.")]
+    AlreadyBorrowed(ComponentId),
+    /// The entity doesn't exist.
+    #[error("The entity {0:?} does not exist.")]
+    NoSuchEntity(Entity),
+    /// The component or resource doesn't exist, e.g. because it was never inserted.
+    #[error("The component or resource does not exist.")]
+    NotFound,
+}
+
+const UNIQUE_BORROW: usize = usize::MAX;
+
+struct WorldHandleState {
+    // `None` once the scope of the handle ended
+    world: Option<UnsafeWorldCell<'static>>,
+    access: Access<ComponentId>,
+    // The number of shared borrows of each component or resource, or `UNIQUE_BORROW`
+    borrows: HashMap<ComponentId, usize>,
+}
+
+/// A handle to a [`World`] that can be stored in and passed to callbacks of scripting languages or
+/// C code, created with [`World::scoped_handle`].
+///
+/// Unlike a `*mut World`, the handle doesn't have a lifetime tied to the world, but is still safe
+/// to use: each access is validated at runtime. A handle which is used after its scope ended
+/// returns [`WorldHandleError::Expired`], only the components and resources in the
+/// [`Access`] of the handle can be accessed, and borrows which would alias mutably return
+/// [`WorldHandleError::AlreadyBorrowed`].
+///
+/// Borrows are only held for the duration of a closure, so they can't escape into the callbacks.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::Access;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(0));
+/// let score = world.components().resource_id::<Score>().unwrap();
+///
+/// let mut access = Access::new();
+/// access.add_write(score);
+/// let stored = world.scoped_handle(access, |handle| {
+///     // e.g. call into a script here, passing the handle
+///     handle.resource_mut(|mut score: Mut<Score>| score.0 += 1).unwrap();
+///     handle.clone()
+/// });
+///
+/// assert_eq!(world.resource::<Score>().0, 1);
+/// assert!(stored.resource(|_: &Score| ()).is_err());
+/// ```
+#[derive(Clone)]
+pub struct WorldHandle {
+    state: Rc<RefCell<WorldHandleState>>,
+}
+
+impl World {
+    /// Calls `f` with a [`WorldHandle`] allowing the given `access` to this world, and invalidates
+    /// the handle and all its clones afterwards.
+    pub fn scoped_handle<R>(
+        &mut self,
+        access: Access<ComponentId>,
+        f: impl FnOnce(&WorldHandle) -> R,
+    ) -> R {
+        let world = self.as_unsafe_world_cell();
+        // SAFETY: the lifetime is only erased while `self` is mutably borrowed, the world is
+        // removed from the state before the borrow ends.
+        let world =
+            unsafe { std::mem::transmute::<UnsafeWorldCell<'_>, UnsafeWorldCell<'static>>(world) };
+        let handle = WorldHandle {
+            state: Rc::new(RefCell::new(WorldHandleState {
+                world: Some(world),
+                access,
+                borrows: HashMap::default(),
+            })),
+        };
+
+        // Invalidate the handle even if `f` panics
+        struct Invalidate<'a>(&'a WorldHandle);
+        impl Drop for Invalidate<'_> {
+            fn drop(&mut self) {
+                self.0.state.borrow_mut().world = None;
+            }
+        }
+        let _invalidate = Invalidate(&handle);
+
+        f(&handle)
+    }
+}
+
+impl WorldHandle {
+    /// Returns `true` if the scope of the handle didn't end yet.
+    pub fn is_valid(&self) -> bool {
+        self.state.borrow().world.is_some()
+    }
+
+    /// Returns `true` if the handle may read the component or resource.
+    pub fn has_read(&self, component: ComponentId) -> bool {
+        self.state.borrow().access.has_read(component)
+    }
+
+    /// Returns `true` if the handle may write the component or resource.
+    pub fn has_write(&self, component: ComponentId) -> bool {
+        self.state.borrow().access.has_write(component)
+    }
+
+    /// Calls `f` with the resource `R`.
+    pub fn resource<R: Resource, T>(&self, f: impl FnOnce(&R) -> T) -> Result<T, WorldHandleError> {
+        let component = self.resource_id::<R>()?;
+        self.borrow(component, false, |world| {
+            // SAFETY: the handle has read access to the resource and it isn't mutably borrowed
+            unsafe { world.get_resource::<R>() }.map(f)
+        })
+    }
+
+    /// Calls `f` with the resource `R`, mutably.
+    pub fn resource_mut<R: Resource, T>(
+        &self,
+        f: impl FnOnce(Mut<R>) -> T,
+    ) -> Result<T, WorldHandleError> {
+        let component = self.resource_id::<R>()?;
+        self.borrow(component, true, |world| {
+            // SAFETY: the handle has write access to the resource and it isn't borrowed
+            unsafe { world.get_resource_mut::<R>() }.map(f)
+        })
+    }
+
+    /// Calls `f` with the component `C` of `entity`.
+    pub fn component<C: Component, T>(
+        &self,
+        entity: Entity,
+        f: impl FnOnce(&C) -> T,
+    ) -> Result<T, WorldHandleError> {
+        let component = self.component_id::<C>()?;
+        self.borrow_entity(entity, component, false, |entity| {
+            // SAFETY: the handle has read access to the component and it isn't mutably borrowed
+            unsafe { entity.get::<C>() }.map(f)
+        })
+    }
+
+    /// Calls `f` with the component `C` of `entity`, mutably.
+    pub fn component_mut<C: Component, T>(
+        &self,
+        entity: Entity,
+        f: impl FnOnce(Mut<C>) -> T,
+    ) -> Result<T, WorldHandleError> {
+        let component = self.component_id::<C>()?;
+        self.borrow_entity(entity, component, true, |entity| {
+            // SAFETY: the handle has write access to the component and it isn't borrowed
+            unsafe { entity.get_mut::<C>() }.map(f)
+        })
+    }
+
+    /// Calls `f` with the resource with the given id, e.g. to reflect it with
+    /// `ReflectFromPtr`.
+    pub fn resource_by_id<T>(
+        &self,
+        component: ComponentId,
+        f: impl FnOnce(Ptr) -> T,
+    ) -> Result<T, WorldHandleError> {
+        self.borrow(component, false, |world| {
+            // SAFETY: the handle has read access to the resource and it isn't mutably borrowed
+            unsafe { world.get_resource_by_id(component) }.map(f)
+        })
+    }
+
+    /// Calls `f` with the resource with the given id, mutably.
+    pub fn resource_mut_by_id<T>(
+        &self,
+        component: ComponentId,
+        f: impl FnOnce(MutUntyped) -> T,
+    ) -> Result<T, WorldHandleError> {
+        self.borrow(component, true, |world| {
+            // SAFETY: the handle has write access to the resource and it isn't borrowed
+            unsafe { world.get_resource_mut_by_id(component) }.map(f)
+        })
+    }
+
+    /// Calls `f` with the component with the given id of `entity`.
+    pub fn component_by_id<T>(
+        &self,
+        entity: Entity,
+        component: ComponentId,
+        f: impl FnOnce(Ptr) -> T,
+    ) -> Result<T, WorldHandleError> {
+        self.borrow_entity(entity, component, false, |entity| {
+            // SAFETY: the handle has read access to the component and it isn't mutably borrowed
+            unsafe { entity.get_by_id(component) }.map(f)
+        })
+    }
+
+    /// Calls `f` with the component with the given id of `entity`, mutably.
+    pub fn component_mut_by_id<T>(
+        &self,
+        entity: Entity,
+        component: ComponentId,
+        f: impl FnOnce(MutUntyped) -> T,
+    ) -> Result<T, WorldHandleError> {
+        self.borrow_entity(entity, component, true, |entity| {
+            // SAFETY: the handle has write access to the component and it isn't borrowed
+            unsafe { entity.get_mut_by_id(component) }.map(f)
+        })
+    }
+
+    /// Converts the handle into a pointer that can be passed to C code.
+    ///
+    /// The pointer has to be converted back with [`WorldHandle::from_raw`] to free the handle.
+    pub fn into_raw(self) -> *mut c_void {
+        Box::into_raw(Box::new(self)) as *mut c_void
+    }
+
+    /// Converts a pointer created by [`WorldHandle::into_raw`] back into the handle.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`WorldHandle::into_raw`] and not been converted back yet,
+    /// on the same thread.
+    pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        *Box::from_raw(ptr as *mut WorldHandle)
+    }
+
+    /// Borrows the handle behind a pointer created by [`WorldHandle::into_raw`], e.g. in a callback
+    /// called from C code.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`WorldHandle::into_raw`] and not been converted back during
+    /// `'a`, on the same thread.
+    pub unsafe fn from_raw_ref<'a>(ptr: *const c_void) -> &'a Self {
+        &*(ptr as *const WorldHandle)
+    }
+
+    fn resource_id<R: Resource>(&self) -> Result<ComponentId, WorldHandleError> {
+        let world = self.world()?;
+        world
+            .components()
+            .resource_id::<R>()
+            .ok_or(WorldHandleError::NotFound)
+    }
+
+    fn component_id<C: Component>(&self) -> Result<ComponentId, WorldHandleError> {
+        let world = self.world()?;
+        world
+            .components()
+            .component_id::<C>()
+            .ok_or(WorldHandleError::NotFound)
+    }
+
+    fn world(&self) -> Result<UnsafeWorldCell<'static>, WorldHandleError> {
+        self.state.borrow().world.ok_or(WorldHandleError::Expired)
+    }
+
+    fn borrow_entity<T>(
+        &self,
+        entity: Entity,
+        component: ComponentId,
+        write: bool,
+        f: impl FnOnce(UnsafeEntityCell<'_>) -> Option<T>,
+    ) -> Result<T, WorldHandleError> {
+        self.world()?
+            .get_entity(entity)
+            .ok_or(WorldHandleError::NoSuchEntity(entity))?;
+        self.borrow(component, write, |world| {
+            // The entity can't be despawned through the handle
+            f(world.get_entity(entity).unwrap())
+        })
+    }
+
+    /// Calls `f` with the world while `component` is borrowed, after validating the access.
+    fn borrow<'a, T>(
+        &'a self,
+        component: ComponentId,
+        write: bool,
+        f: impl FnOnce(UnsafeWorldCell<'a>) -> Option<T>,
+    ) -> Result<T, WorldHandleError> {
+        let world = {
+            let mut state = self.state.borrow_mut();
+            let world = state.world.ok_or(WorldHandleError::Expired)?;
+            let allowed = if write {
+                state.access.has_write(component)
+            } else {
+                state.access.has_read(component)
+            };
+            if !allowed {
+                return Err(WorldHandleError::AccessDenied { component, write });
+            }
+            let borrows = state.borrows.entry(component).or_insert(0);
+            match (write, *borrows) {
+                (false, UNIQUE_BORROW) | (true, 1..) => {
+                    return Err(WorldHandleError::AlreadyBorrowed(component));
+                }
+                (false, _) => *borrows += 1,
+                (true, _) => *borrows = UNIQUE_BORROW,
+            }
+            world
+        };
+
+        // Release the borrow even if `f` panics
+        struct Release<'a>(&'a WorldHandle, ComponentId, bool);
+        impl Drop for Release<'_> {
+            fn drop(&mut self) {
+                let mut state = self.0.state.borrow_mut();
+                let borrows = state.borrows.get_mut(&self.1).unwrap();
+                if self.2 {
+                    *borrows = 0;
+                } else {
+                    *borrows -= 1;
+                }
+            }
+        }
+        let _release = Release(self, component, write);
+
+        f(world).ok_or(WorldHandleError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorldHandle, WorldHandleError};
+    use crate as bevy_ecs;
+    use crate::{prelude::*, query::Access};
+
+    #[derive(Resource)]
+    struct Score(u32);
+
+    #[derive(Component)]
+    struct Health(f32);
+
+    #[test]
+    fn world_handle_validates_access() {
+        let mut world = World::new();
+        world.insert_resource(Score(0));
+        let entity = world.spawn(Health(1.0)).id();
+        let score = world.components().resource_id::<Score>().unwrap();
+        let health = world.init_component::<Health>();
+
+        let mut access = Access::new();
+        access.add_read(score);
+        access.add_write(health);
+        let raw = world.scoped_handle(access, |handle| {
+            assert_eq!(handle.resource(|score: &Score| score.0), Ok(0));
+            assert_eq!(
+                handle.resource_mut(|mut score: Mut<Score>| score.0 += 1),
+                Err(WorldHandleError::AccessDenied {
+                    component: score,
+                    write: true
+                })
+            );
+
+            let result = handle.component_mut(entity, |mut health: Mut<Health>| {
+                health.0 = 0.5;
+                handle.component(entity, |health: &Health| health.0)
+            });
+            assert_eq!(result, Ok(Err(WorldHandleError::AlreadyBorrowed(health))));
+            assert_eq!(
+                handle.component(entity, |health: &Health| health.0),
+                Ok(0.5)
+            );
+
+            let missing = Entity::from_raw(100);
+            assert_eq!(
+                handle.component(missing, |health: &Health| health.0),
+                Err(WorldHandleError::NoSuchEntity(missing))
+            );
+
+            let raw = handle.clone().into_raw();
+            // SAFETY: the pointer was just created on this thread
+            let borrowed = unsafe { WorldHandle::from_raw_ref(raw) };
+            assert!(borrowed.is_valid());
+            raw
+        });
+
+        // SAFETY: the pointer was created above and not converted back yet
+        let handle = unsafe { WorldHandle::from_raw(raw) };
+        assert!(!handle.is_valid());
+        assert_eq!(
+            handle.resource(|score: &Score| score.0),
+            Err(WorldHandleError::Expired)
+        );
+    }
+}
@@ -1,9 +1,39 @@
-use bevy_input::gamepad::{Gamepad, GamepadAxisType, GamepadButtonType};
+use bevy_input::{
+    devices::InputDeviceCapabilities,
+    gamepad::{Gamepad, GamepadAxisType, GamepadButtonType, GamepadInfo, GamepadPowerInfo},
+};
 
 pub fn convert_gamepad_id(gamepad_id: gilrs::GamepadId) -> Gamepad {
     Gamepad::new(gamepad_id.into())
 }
 
+pub fn convert_gamepad_info(gamepad: &gilrs::Gamepad) -> GamepadInfo {
+    GamepadInfo {
+        name: gamepad.name().into(),
+        vendor_id: gamepad.vendor_id(),
+        product_id: gamepad.product_id(),
+        power_info: convert_power_info(gamepad.power_info()),
+        capabilities: InputDeviceCapabilities {
+            has_rumble: gamepad.is_ff_supported(),
+            // gilrs doesn't expose motion sensors
+            has_gyro: false,
+            has_analog_triggers: gamepad.axis_code(gilrs::Axis::LeftZ).is_some()
+                || gamepad.axis_code(gilrs::Axis::RightZ).is_some(),
+            has_pressure: false,
+        },
+    }
+}
+
+pub fn convert_power_info(power_info: gilrs::PowerInfo) -> GamepadPowerInfo {
+    match power_info {
+        gilrs::PowerInfo::Unknown => GamepadPowerInfo::Unknown,
+        gilrs::PowerInfo::Wired => GamepadPowerInfo::Wired,
+        gilrs::PowerInfo::Discharging(level) => GamepadPowerInfo::Discharging(level),
+        gilrs::PowerInfo::Charging(level) => GamepadPowerInfo::Charging(level),
+        gilrs::PowerInfo::Charged => GamepadPowerInfo::Charged,
+    }
+}
+
 pub fn convert_button(button: gilrs::Button) -> Option<GamepadButtonType> {
     match button {
         gilrs::Button::South => Some(GamepadButtonType::South),
@@ -1,13 +1,15 @@
-use crate::converter::{convert_axis, convert_button, convert_gamepad_id};
-use bevy_ecs::event::EventWriter;
-use bevy_ecs::system::{NonSend, NonSendMut, Res};
+use crate::converter::{
+    convert_axis, convert_button, convert_gamepad_id, convert_gamepad_info, convert_power_info,
+};
+use bevy_ecs::event::{EventReader, EventWriter};
+use bevy_ecs::system::{Local, NonSend, NonSendMut, Res};
 use bevy_input::gamepad::{
     GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent,
-    GamepadSettings,
+    GamepadEvent, GamepadIndicatorRequest, GamepadPowerEvent, GamepadSettings, Gamepads,
 };
-use bevy_input::gamepad::{GamepadEvent, GamepadInfo};
 use bevy_input::prelude::{GamepadAxis, GamepadButton};
 use bevy_input::Axis;
+use bevy_utils::{tracing::warn, Duration, Instant};
 use gilrs::{ev::filter::axis_dpad_to_button, EventType, Filter, Gilrs};
 
 pub fn gilrs_event_startup_system(
@@ -15,9 +17,7 @@ pub fn gilrs_event_startup_system(
     mut connection_events: EventWriter<GamepadConnectionEvent>,
 ) {
     for (id, gamepad) in gilrs.gamepads() {
-        let info = GamepadInfo {
-            name: gamepad.name().into(),
-        };
+        let info = convert_gamepad_info(&gamepad);
 
         connection_events.send(GamepadConnectionEvent {
             gamepad: convert_gamepad_id(id),
@@ -42,10 +42,7 @@ pub fn gilrs_event_system(
         let gamepad = convert_gamepad_id(gilrs_event.id);
         match gilrs_event.event {
             EventType::Connected => {
-                let pad = gilrs.gamepad(gilrs_event.id);
-                let info = GamepadInfo {
-                    name: pad.name().into(),
-                };
+                let info = convert_gamepad_info(&gilrs.gamepad(gilrs_event.id));
 
                 events.send(
                     GamepadConnectionEvent::new(gamepad, GamepadConnection::Connected(info)).into(),
@@ -87,3 +84,47 @@ pub fn gilrs_event_system(
     }
     gilrs.inc();
 }
+
+/// How often [`gilrs_power_system`] polls the power supply state of the connected gamepads.
+const POWER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Sends a [`GamepadPowerEvent`] whenever the power supply state of a connected gamepad changes.
+///
+/// `gilrs` doesn't report these changes as events, so they are polled every [`POWER_POLL_INTERVAL`].
+pub fn gilrs_power_system(
+    gilrs: NonSend<Gilrs>,
+    gamepads: Res<Gamepads>,
+    mut events: EventWriter<GamepadEvent>,
+    mut last_poll: Local<Option<Instant>>,
+) {
+    let now = Instant::now();
+    if let Some(last_poll) = *last_poll {
+        if now - last_poll < POWER_POLL_INTERVAL {
+            return;
+        }
+    }
+    *last_poll = Some(now);
+
+    for (id, pad) in gilrs.gamepads() {
+        let gamepad = convert_gamepad_id(id);
+        let power_info = convert_power_info(pad.power_info());
+        if matches!(gamepads.power_info(gamepad), Some(old) if old != power_info) {
+            events.send(GamepadPowerEvent::new(gamepad, power_info).into());
+        }
+    }
+}
+
+/// Consumes [`GamepadIndicatorRequest`]s.
+///
+/// `gilrs` has no way to control player lights or lightbars yet, so requests are dropped
+/// with a one-time warning instead of silently piling up.
+pub fn gilrs_indicator_system(
+    mut requests: EventReader<GamepadIndicatorRequest>,
+    mut warned: Local<bool>,
+) {
+    if !requests.is_empty() && !*warned {
+        warn!("Gamepad indicators are not supported by the gilrs backend and will be ignored.");
+        *warned = true;
+    }
+    requests.clear();
+}
@@ -6,7 +6,9 @@ use bevy_ecs::prelude::*;
 use bevy_input::InputSystem;
 use bevy_utils::tracing::error;
 use gilrs::GilrsBuilder;
-use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
+use gilrs_system::{
+    gilrs_event_startup_system, gilrs_event_system, gilrs_indicator_system, gilrs_power_system,
+};
 
 #[derive(Default)]
 pub struct GilrsPlugin;
@@ -23,10 +25,15 @@ impl Plugin for GilrsPlugin {
                     .add_startup_system(
                         gilrs_event_startup_system.in_base_set(StartupSet::PreStartup),
                     )
-                    .add_system(
-                        gilrs_event_system
+                    .add_systems(
+                        (gilrs_event_system, gilrs_power_system)
                             .before(InputSystem)
                             .in_base_set(CoreSet::PreUpdate),
+                    )
+                    .add_system(
+                        gilrs_indicator_system
+                            .after(InputSystem)
+                            .in_base_set(CoreSet::PreUpdate),
                     );
             }
             Err(err) => error!("Failed to start Gilrs. {}", err),
@@ -7,7 +7,7 @@ pub use loader::*;
 
 use bevy_app::prelude::*;
 use bevy_asset::{AddAsset, Handle};
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_ecs::{prelude::Component, reflect::ReflectComponent, world::FromWorld};
 use bevy_pbr::StandardMaterial;
 use bevy_reflect::{Reflect, TypeUuid};
 use bevy_render::mesh::Mesh;
@@ -15,11 +15,16 @@ use bevy_scene::Scene;
 
 /// Adds support for glTF file loading to the app.
 #[derive(Default)]
-pub struct GltfPlugin;
+pub struct GltfPlugin {
+    /// The settings used to load all glTF files.
+    pub loader_settings: GltfLoaderSettings,
+}
 
 impl Plugin for GltfPlugin {
     fn build(&self, app: &mut App) {
-        app.init_asset_loader::<GltfLoader>()
+        let mut loader = GltfLoader::from_world(&mut app.world);
+        loader.settings = self.loader_settings.clone();
+        app.add_asset_loader(loader)
             .register_type::<GltfExtras>()
             .add_asset::<Gltf>()
             .add_asset::<GltfNode>()
@@ -40,6 +45,9 @@ pub struct Gltf {
     pub named_materials: HashMap<String, Handle<StandardMaterial>>,
     pub nodes: Vec<Handle<GltfNode>>,
     pub named_nodes: HashMap<String, Handle<GltfNode>>,
+    /// The scenes of the named nodes, containing the node and its descendants. Only populated if
+    /// [`GltfLoaderSettings::load_node_scenes`] is enabled.
+    pub named_node_scenes: HashMap<String, Handle<Scene>>,
     pub default_scene: Option<Handle<Scene>>,
     #[cfg(feature = "bevy_animation")]
     pub animations: Vec<Handle<AnimationClip>>,
@@ -47,6 +55,49 @@ pub struct Gltf {
     pub named_animations: HashMap<String, Handle<AnimationClip>>,
 }
 
+impl Gltf {
+    // The accessors below look up sub-assets by the names given in the authoring tool. Unlike
+    // labels like `"Mesh0/Primitive0"`, these don't change when the file is re-exported with its
+    // contents in a different order.
+
+    /// Returns the scene with the given name.
+    pub fn scene_by_name(&self, name: &str) -> Option<&Handle<Scene>> {
+        self.named_scenes.get(name)
+    }
+
+    /// Returns the mesh with the given name.
+    pub fn named_mesh(&self, name: &str) -> Option<&Handle<GltfMesh>> {
+        self.named_meshes.get(name)
+    }
+
+    /// Returns the material with the given name.
+    pub fn named_material(&self, name: &str) -> Option<&Handle<StandardMaterial>> {
+        self.named_materials.get(name)
+    }
+
+    /// Returns the node with the given name.
+    pub fn named_node(&self, name: &str) -> Option<&Handle<GltfNode>> {
+        self.named_nodes.get(name)
+    }
+
+    /// Returns a scene containing only the node with the given name and its descendants, which
+    /// can be spawned like any other scene.
+    ///
+    /// If several nodes share the name, the scene of the first one is returned. Returns `None`
+    /// unless [`GltfLoaderSettings::load_node_scenes`] is enabled. Animations
+    /// only play if the node is the root of the animated hierarchy, and skins only apply if all
+    /// of their joints are part of the subtree.
+    pub fn named_node_scene(&self, name: &str) -> Option<&Handle<Scene>> {
+        self.named_node_scenes.get(name)
+    }
+
+    /// Returns the animation with the given name.
+    #[cfg(feature = "bevy_animation")]
+    pub fn named_animation(&self, name: &str) -> Option<&Handle<AnimationClip>> {
+        self.named_animations.get(name)
+    }
+}
+
 /// A glTF node with all of its child nodes, its [`GltfMesh`],
 /// [`Transform`](bevy_transform::prelude::Transform) and an optional [`GltfExtras`].
 #[derive(Debug, Clone, TypeUuid)]
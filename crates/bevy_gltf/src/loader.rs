@@ -66,9 +66,45 @@ pub enum GltfError {
     GenerateTangentsError(#[from] bevy_render::mesh::GenerateTangentsError),
 }
 
+/// Controls which parts of glTF files are imported by the [`GltfLoader`], set with
+/// [`GltfPlugin::loader_settings`](crate::GltfPlugin::loader_settings).
+#[derive(Clone, Debug)]
+pub struct GltfLoaderSettings {
+    /// Whether to spawn glTF cameras as [`Camera3dBundle`]s. Defaults to `true`.
+    pub load_cameras: bool,
+    /// Whether the first camera of a file is active. All other cameras are always spawned
+    /// inactive, so that scenes don't take over the window when they are spawned. Defaults to
+    /// `false`.
+    pub activate_first_camera: bool,
+    /// Whether to spawn the lights of the `KHR_lights_punctual` extension. Defaults to `true`.
+    pub load_lights: bool,
+    /// Whether to multiply the emissive color of materials with the strength of the
+    /// `KHR_materials_emissive_strength` extension. Defaults to `true`.
+    pub load_emissive_strength: bool,
+    /// Whether to create a [`Scene`] for each named node, containing the node and its
+    /// descendants, available through [`Gltf::named_node_scene`](crate::Gltf::named_node_scene)
+    /// or the `NodeScene/<name>` label, e.g. `models/robot.gltf#NodeScene/Arm`. Further nodes
+    /// with the same name get a number as suffix, e.g. `NodeScene/Arm/1`.
+    /// Defaults to `false`.
+    pub load_node_scenes: bool,
+}
+
+impl Default for GltfLoaderSettings {
+    fn default() -> Self {
+        Self {
+            load_cameras: true,
+            activate_first_camera: false,
+            load_lights: true,
+            load_emissive_strength: true,
+            load_node_scenes: false,
+        }
+    }
+}
+
 /// Loads glTF files with all of their data as their corresponding bevy representations.
 pub struct GltfLoader {
     supported_compressed_formats: CompressedImageFormats,
+    pub(crate) settings: GltfLoaderSettings,
 }
 
 impl AssetLoader for GltfLoader {
@@ -78,7 +114,13 @@ impl AssetLoader for GltfLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            Ok(load_gltf(bytes, load_context, self.supported_compressed_formats).await?)
+            Ok(load_gltf(
+                bytes,
+                load_context,
+                self.supported_compressed_formats,
+                &self.settings,
+            )
+            .await?)
         })
     }
 
@@ -96,6 +138,7 @@ impl FromWorld for GltfLoader {
         };
         Self {
             supported_compressed_formats,
+            settings: GltfLoaderSettings::default(),
         }
     }
 }
@@ -105,6 +148,7 @@ async fn load_gltf<'a, 'b>(
     bytes: &'a [u8],
     load_context: &'a mut LoadContext<'b>,
     supported_compressed_formats: CompressedImageFormats,
+    settings: &GltfLoaderSettings,
 ) -> Result<(), GltfError> {
     let gltf = gltf::Gltf::from_slice(bytes)?;
     let buffer_data = load_buffers(&gltf, load_context, load_context.path()).await?;
@@ -113,7 +157,7 @@ async fn load_gltf<'a, 'b>(
     let mut named_materials = HashMap::default();
     let mut linear_textures = HashSet::default();
     for material in gltf.materials() {
-        let handle = load_material(&material, load_context);
+        let handle = load_material(&material, load_context, settings);
         if let Some(name) = material.name() {
             named_materials.insert(name.to_string(), handle.clone());
         }
@@ -459,62 +503,18 @@ async fn load_gltf<'a, 'b>(
 
     let mut scenes = vec![];
     let mut named_scenes = HashMap::default();
-    let mut active_camera_found = false;
+    let mut active_camera_found = !settings.activate_first_camera;
     for scene in gltf.scenes() {
-        let mut err = None;
-        let mut world = World::default();
-        let mut node_index_to_entity_map = HashMap::new();
-        let mut entity_to_skin_index_map = HashMap::new();
-
-        world
-            .spawn(SpatialBundle::INHERITED_IDENTITY)
-            .with_children(|parent| {
-                for node in scene.nodes() {
-                    let result = load_node(
-                        &node,
-                        parent,
-                        load_context,
-                        &mut node_index_to_entity_map,
-                        &mut entity_to_skin_index_map,
-                        &mut active_camera_found,
-                    );
-                    if result.is_err() {
-                        err = Some(result);
-                        return;
-                    }
-                }
-            });
-        if let Some(Err(err)) = err {
-            return Err(err);
-        }
-
-        #[cfg(feature = "bevy_animation")]
-        {
-            // for each node root in a scene, check if it's the root of an animation
-            // if it is, add the AnimationPlayer component
-            for node in scene.nodes() {
-                if animation_roots.contains(&node.index()) {
-                    world
-                        .entity_mut(*node_index_to_entity_map.get(&node.index()).unwrap())
-                        .insert(bevy_animation::AnimationPlayer::default());
-                }
-            }
-        }
-
-        for (&entity, &skin_index) in &entity_to_skin_index_map {
-            let mut entity = world.entity_mut(entity);
-            let skin = gltf.skins().nth(skin_index).unwrap();
-            let joint_entities: Vec<_> = skin
-                .joints()
-                .map(|node| node_index_to_entity_map[&node.index()])
-                .collect();
-
-            entity.insert(SkinnedMesh {
-                inverse_bindposes: skinned_mesh_inverse_bindposes[skin_index].clone(),
-                joints: joint_entities,
-            });
-        }
-
+        let world = load_scene_world(
+            scene.nodes(),
+            &gltf,
+            load_context,
+            &mut active_camera_found,
+            settings,
+            &skinned_mesh_inverse_bindposes,
+            #[cfg(feature = "bevy_animation")]
+            &animation_roots,
+        )?;
         let scene_handle = load_context
             .set_labeled_asset(&scene_label(&scene), LoadedAsset::new(Scene::new(world)));
 
@@ -524,6 +524,33 @@ async fn load_gltf<'a, 'b>(
         scenes.push(scene_handle);
     }
 
+    let mut named_node_scenes = HashMap::default();
+    if settings.load_node_scenes {
+        let labels = node_scene_labels(gltf.nodes());
+        for node in gltf.nodes() {
+            let Some(name) = node.name() else {
+                continue;
+            };
+            // Cameras of node scenes are never active, as they are usually spawned into an
+            // existing scene.
+            let world = load_scene_world(
+                std::iter::once(node.clone()),
+                &gltf,
+                load_context,
+                &mut true,
+                settings,
+                &skinned_mesh_inverse_bindposes,
+                #[cfg(feature = "bevy_animation")]
+                &animation_roots,
+            )?;
+            let scene_handle = load_context
+                .set_labeled_asset(&labels[node.index()], LoadedAsset::new(Scene::new(world)));
+            named_node_scenes
+                .entry(name.to_string())
+                .or_insert(scene_handle);
+        }
+    }
+
     load_context.set_default_asset(LoadedAsset::new(Gltf {
         default_scene: gltf
             .default_scene()
@@ -537,6 +564,7 @@ async fn load_gltf<'a, 'b>(
         named_materials,
         nodes,
         named_nodes,
+        named_node_scenes,
         #[cfg(feature = "bevy_animation")]
         animations,
         #[cfg(feature = "bevy_animation")]
@@ -546,6 +574,83 @@ async fn load_gltf<'a, 'b>(
     Ok(())
 }
 
+/// Builds the [`World`] of a [`Scene`] with the given root nodes and their descendants.
+#[allow(clippy::too_many_arguments)]
+fn load_scene_world<'a>(
+    roots: impl Iterator<Item = Node<'a>> + Clone,
+    gltf: &gltf::Gltf,
+    load_context: &mut LoadContext,
+    active_camera_found: &mut bool,
+    settings: &GltfLoaderSettings,
+    skinned_mesh_inverse_bindposes: &[Handle<SkinnedMeshInverseBindposes>],
+    #[cfg(feature = "bevy_animation")] animation_roots: &HashSet<&usize>,
+) -> Result<World, GltfError> {
+    let mut err = None;
+    let mut world = World::default();
+    let mut node_index_to_entity_map = HashMap::new();
+    let mut entity_to_skin_index_map = HashMap::new();
+
+    world
+        .spawn(SpatialBundle::INHERITED_IDENTITY)
+        .with_children(|parent| {
+            for node in roots.clone() {
+                let result = load_node(
+                    &node,
+                    parent,
+                    load_context,
+                    &mut node_index_to_entity_map,
+                    &mut entity_to_skin_index_map,
+                    active_camera_found,
+                    settings,
+                );
+                if result.is_err() {
+                    err = Some(result);
+                    return;
+                }
+            }
+        });
+    if let Some(Err(err)) = err {
+        return Err(err);
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    {
+        // for each node root in a scene, check if it's the root of an animation
+        // if it is, add the AnimationPlayer component
+        for node in roots {
+            if animation_roots.contains(&node.index()) {
+                world
+                    .entity_mut(*node_index_to_entity_map.get(&node.index()).unwrap())
+                    .insert(bevy_animation::AnimationPlayer::default());
+            }
+        }
+    }
+
+    for (&entity, &skin_index) in &entity_to_skin_index_map {
+        let mut entity = world.entity_mut(entity);
+        let skin = gltf.skins().nth(skin_index).unwrap();
+        let joint_entities: Option<Vec<_>> = skin
+            .joints()
+            .map(|node| node_index_to_entity_map.get(&node.index()).copied())
+            .collect();
+        // Joints outside of the scene can only be missing in node scenes.
+        let Some(joint_entities) = joint_entities else {
+            warn!(
+                "Skin {} ignored: some of its joints are not part of the scene",
+                skin_index
+            );
+            continue;
+        };
+
+        entity.insert(SkinnedMesh {
+            inverse_bindposes: skinned_mesh_inverse_bindposes[skin_index].clone(),
+            joints: joint_entities,
+        });
+    }
+
+    Ok(world)
+}
+
 fn get_gltf_extras(extras: &gltf::json::Extras) -> Option<GltfExtras> {
     extras.as_ref().map(|extras| super::GltfExtras {
         value: extras.get().to_string(),
@@ -628,7 +733,11 @@ async fn load_texture<'a>(
 }
 
 /// Loads a glTF material as a bevy [`StandardMaterial`] and returns it.
-fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<StandardMaterial> {
+fn load_material(
+    material: &Material,
+    load_context: &mut LoadContext,
+    settings: &GltfLoaderSettings,
+) -> Handle<StandardMaterial> {
     let material_label = material_label(material);
 
     let pbr = material.pbr_metallic_roughness();
@@ -665,7 +774,12 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         load_context.get_handle(path)
     });
 
-    let emissive = material.emissive_factor();
+    let mut emissive = material.emissive_factor();
+    if settings.load_emissive_strength {
+        if let Some(strength) = material.emissive_strength() {
+            emissive = emissive.map(|channel| channel * strength);
+        }
+    }
     let emissive_texture = material.emissive_texture().map(|info| {
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
@@ -707,6 +821,7 @@ fn load_node(
     node_index_to_entity_map: &mut HashMap<usize, Entity>,
     entity_to_skin_index_map: &mut HashMap<Entity, usize>,
     active_camera_found: &mut bool,
+    settings: &GltfLoaderSettings,
 ) -> Result<(), GltfError> {
     let transform = gltf_node.transform();
     let mut gltf_error = None;
@@ -722,7 +837,7 @@ fn load_node(
     }
 
     // create camera node
-    if let Some(camera) = gltf_node.camera() {
+    if let Some(camera) = gltf_node.camera().filter(|_| settings.load_cameras) {
         let projection = match camera.projection() {
             gltf::camera::Projection::Orthographic(orthographic) => {
                 let xmag = orthographic.xmag();
@@ -778,7 +893,7 @@ fn load_node(
                 // added when iterating over all the gltf materials (since the default material is
                 // not explicitly listed in the gltf).
                 if !load_context.has_labeled_asset(&material_label) {
-                    load_material(&material, load_context);
+                    load_material(&material, load_context, settings);
                 }
 
                 let primitive_label = primitive_label(&mesh, &primitive);
@@ -813,7 +928,7 @@ fn load_node(
             }
         }
 
-        if let Some(light) = gltf_node.light() {
+        if let Some(light) = gltf_node.light().filter(|_| settings.load_lights) {
             match light.kind() {
                 gltf::khr_lights_punctual::Kind::Directional => {
                     let mut entity = parent.spawn(DirectionalLightBundle {
@@ -898,6 +1013,7 @@ fn load_node(
                 node_index_to_entity_map,
                 entity_to_skin_index_map,
                 active_camera_found,
+                settings,
             ) {
                 gltf_error = Some(err);
                 return;
@@ -945,6 +1061,31 @@ fn scene_label(scene: &gltf::Scene) -> String {
     format!("Scene{}", scene.index())
 }
 
+/// Returns the labels for the scenes of the `nodes`, in the same order.
+///
+/// They are based on the names of the nodes, so that they stay stable when the file is exported
+/// again with added or reordered nodes. Nodes sharing the name of an earlier node get a number as
+/// suffix, e.g. `NodeScene/Arm/1` for the second node named `Arm`. Only unnamed nodes fall back
+/// to their index.
+fn node_scene_labels<'a>(nodes: impl Iterator<Item = gltf::Node<'a>>) -> Vec<String> {
+    let mut used = HashSet::new();
+    nodes
+        .map(|node| {
+            let Some(name) = node.name() else {
+                return format!("Node{}/Scene", node.index());
+            };
+            let mut label = format!("NodeScene/{name}");
+            let mut suffix = 1;
+            while used.contains(&label) {
+                label = format!("NodeScene/{name}/{suffix}");
+                suffix += 1;
+            }
+            used.insert(label.clone());
+            label
+        })
+        .collect()
+}
+
 fn skin_label(skin: &gltf::Skin) -> String {
     format!("Skin{}", skin.index())
 }
@@ -1161,7 +1302,7 @@ impl<'a> DataUri<'a> {
 mod test {
     use std::path::PathBuf;
 
-    use super::resolve_node_hierarchy;
+    use super::{node_scene_labels, resolve_node_hierarchy};
     use crate::GltfNode;
 
     impl GltfNode {
@@ -1265,6 +1406,36 @@ mod test {
         assert_eq!(result.len(), 0);
     }
 
+    #[test]
+    fn node_scene_labels_use_names() {
+        let gltf = gltf::Gltf::from_slice(
+            br#"{"asset": {"version": "2.0"}, "nodes": [{}, {"name": "Arm"}, {"name": "Leg"}]}"#,
+        )
+        .unwrap();
+        let labels = node_scene_labels(gltf.nodes());
+        assert_eq!(labels, ["Node0/Scene", "NodeScene/Arm", "NodeScene/Leg"]);
+    }
+
+    #[test]
+    fn node_scene_labels_are_unique() {
+        let gltf = gltf::Gltf::from_slice(
+            br#"{"asset": {"version": "2.0"}, "nodes": [
+                {"name": "Arm"}, {"name": "Arm/1"}, {"name": "Arm"}, {"name": "Arm"}
+            ]}"#,
+        )
+        .unwrap();
+        let labels = node_scene_labels(gltf.nodes());
+        assert_eq!(
+            labels,
+            [
+                "NodeScene/Arm",
+                "NodeScene/Arm/1",
+                "NodeScene/Arm/2",
+                "NodeScene/Arm/3"
+            ]
+        );
+    }
+
     #[test]
     fn node_hierarchy_missing_node() {
         let result = resolve_node_hierarchy(
@@ -4,13 +4,14 @@ use bevy_ecs::{
     entity::Entity,
     prelude::Events,
     system::{Command, Commands, EntityCommands},
-    world::{EntityMut, World},
+    world::{EntityMut, EntityRef, World},
 };
 use smallvec::SmallVec;
+use std::cmp::Ordering;
 
 // Do not use `world.send_event_batch` as it prints error message when the Events are not available in the world,
 // even though it's a valid use case to execute commands on a world without events. Loading a GLTF file for example
-fn push_events(world: &mut World, events: impl IntoIterator<Item = HierarchyEvent>) {
+pub(crate) fn push_events(world: &mut World, events: impl IntoIterator<Item = HierarchyEvent>) {
     if let Some(mut moved) = world.get_resource_mut::<Events<HierarchyEvent>>() {
         moved.extend(events);
     }
@@ -192,6 +193,52 @@ impl Command for PushChildren {
     }
 }
 
+/// Command that inserts a child at a given index of a parent's children, or moves it there if
+/// it already is one of them.
+#[derive(Debug)]
+pub struct InsertChildAt {
+    parent: Entity,
+    child: Entity,
+    index: usize,
+}
+
+impl Command for InsertChildAt {
+    fn write(self, world: &mut World) {
+        world
+            .entity_mut(self.parent)
+            .insert_child_at(self.index, self.child);
+    }
+}
+
+/// Command that moves the child at one index of a parent's children to another index.
+#[derive(Debug)]
+pub struct MoveChild {
+    parent: Entity,
+    from: usize,
+    to: usize,
+}
+
+impl Command for MoveChild {
+    fn write(self, world: &mut World) {
+        world.entity_mut(self.parent).move_child(self.from, self.to);
+    }
+}
+
+/// Command that sorts a parent's children with a comparator function.
+pub struct SortChildrenBy<F> {
+    parent: Entity,
+    compare: F,
+}
+
+impl<F> Command for SortChildrenBy<F>
+where
+    F: FnMut(&EntityRef, &EntityRef) -> Ordering + Send + 'static,
+{
+    fn write(self, world: &mut World) {
+        world.entity_mut(self.parent).sort_children_by(self.compare);
+    }
+}
+
 /// Command that removes children from an entity, and removes that child's parent.
 pub struct RemoveChildren {
     parent: Entity,
@@ -290,6 +337,23 @@ pub trait BuildChildren {
     /// will have those children removed from its list. Removing all children from a parent causes its
     /// [`Children`] component to be removed from the entity.
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self;
+    /// Inserts a single child at the given index
+    ///
+    /// If the child already is a child of this entity, it is moved to the index instead.
+    /// If the child was previously a child of another parent, that parent's [`Children`] component
+    /// will have the child removed from its list.
+    fn insert_child_at(&mut self, index: usize, child: Entity) -> &mut Self;
+    /// Moves the child at index `from` to index `to`, shifting the children in between
+    ///
+    /// # Panics
+    ///
+    /// The command panics if either index is out of bounds.
+    fn move_child(&mut self, from: usize, to: usize) -> &mut Self;
+    /// Sorts the children with a comparator function, keeping the order of equal children
+    fn sort_children_by(
+        &mut self,
+        compare: impl FnMut(&EntityRef, &EntityRef) -> Ordering + Send + 'static,
+    ) -> &mut Self;
     /// Removes the given children
     ///
     /// Removing all children from a parent causes its [`Children`] component to be removed from the entity.
@@ -346,6 +410,31 @@ impl<'w, 's, 'a> BuildChildren for EntityCommands<'w, 's, 'a> {
         self
     }
 
+    fn insert_child_at(&mut self, index: usize, child: Entity) -> &mut Self {
+        let parent = self.id();
+        self.commands().add(InsertChildAt {
+            parent,
+            child,
+            index,
+        });
+        self
+    }
+
+    fn move_child(&mut self, from: usize, to: usize) -> &mut Self {
+        let parent = self.id();
+        self.commands().add(MoveChild { parent, from, to });
+        self
+    }
+
+    fn sort_children_by(
+        &mut self,
+        compare: impl FnMut(&EntityRef, &EntityRef) -> Ordering + Send + 'static,
+    ) -> &mut Self {
+        let parent = self.id();
+        self.commands().add(SortChildrenBy { parent, compare });
+        self
+    }
+
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         self.commands().add(RemoveChildren {
@@ -447,6 +536,21 @@ pub trait BuildWorldChildren {
     fn push_children(&mut self, children: &[Entity]) -> &mut Self;
     /// Inserts children at the given index
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self;
+    /// Inserts a single child at the given index
+    ///
+    /// If the child already is a child of this entity, it is moved to the index instead.
+    fn insert_child_at(&mut self, index: usize, child: Entity) -> &mut Self;
+    /// Moves the child at index `from` to index `to`, shifting the children in between
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    fn move_child(&mut self, from: usize, to: usize) -> &mut Self;
+    /// Sorts the children with a comparator function, keeping the order of equal children
+    fn sort_children_by(
+        &mut self,
+        compare: impl FnMut(&EntityRef, &EntityRef) -> Ordering,
+    ) -> &mut Self;
     /// Removes the given children
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self;
 
@@ -514,6 +618,63 @@ impl<'w> BuildWorldChildren for EntityMut<'w> {
         self
     }
 
+    fn insert_child_at(&mut self, index: usize, child: Entity) -> &mut Self {
+        let parent = self.id();
+        let previous_index = self
+            .get::<Children>()
+            .and_then(|children| children.iter().position(|&entity| entity == child));
+        self.insert_children(index, &[child]);
+        if let Some(previous_index) = previous_index {
+            let index = self
+                .get::<Children>()
+                .and_then(|children| children.iter().position(|&entity| entity == child));
+            if index != Some(previous_index) {
+                self.world_scope(|world| {
+                    push_events(world, [HierarchyEvent::ChildrenReordered { parent }]);
+                });
+            }
+        }
+        self
+    }
+
+    fn move_child(&mut self, from: usize, to: usize) -> &mut Self {
+        let parent = self.id();
+        let len = self.get::<Children>().map_or(0, |children| children.len());
+        assert!(
+            from < len && to < len,
+            "Can't move child {from} to {to} as {parent:?} only has {len} children."
+        );
+        if from != to {
+            let mut children = self.get_mut::<Children>().unwrap();
+            let child = children.0.remove(from);
+            children.0.insert(to, child);
+            self.world_scope(|world| {
+                push_events(world, [HierarchyEvent::ChildrenReordered { parent }]);
+            });
+        }
+        self
+    }
+
+    fn sort_children_by(
+        &mut self,
+        mut compare: impl FnMut(&EntityRef, &EntityRef) -> Ordering,
+    ) -> &mut Self {
+        let parent = self.id();
+        let Some(children) = self.get::<Children>() else {
+            return self;
+        };
+        let world = self.world();
+        let mut sorted = children.0.clone();
+        sorted.sort_by(|&a, &b| compare(&world.entity(a), &world.entity(b)));
+        if sorted != children.0 {
+            self.get_mut::<Children>().unwrap().0 = sorted;
+            self.world_scope(|world| {
+                push_events(world, [HierarchyEvent::ChildrenReordered { parent }]);
+            });
+        }
+        self
+    }
+
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         self.world_scope(|world| {
@@ -547,7 +708,7 @@ mod tests {
     use super::{BuildChildren, BuildWorldChildren};
     use crate::{
         components::{Children, Parent},
-        HierarchyEvent::{self, ChildAdded, ChildMoved, ChildRemoved},
+        HierarchyEvent::{self, ChildAdded, ChildMoved, ChildRemoved, ChildrenReordered},
     };
     use smallvec::{smallvec, SmallVec};
 
@@ -994,6 +1155,104 @@ mod tests {
         assert!(world.get::<Children>(parent2).is_none());
     }
 
+    #[test]
+    fn reorder_children_world() {
+        let world = &mut World::new();
+        world.insert_resource(Events::<HierarchyEvent>::default());
+
+        let [a, b, c, d, e] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).push_children(&[b, c, d]);
+        omit_events(world, 3);
+
+        world.entity_mut(a).move_child(0, 2);
+        assert_children(world, a, Some(&[c, d, b]));
+        assert_events(world, &[ChildrenReordered { parent: a }]);
+
+        world.entity_mut(a).move_child(1, 1);
+        assert_children(world, a, Some(&[c, d, b]));
+        assert_events(world, &[]);
+
+        world.entity_mut(a).insert_child_at(1, e);
+        assert_children(world, a, Some(&[c, e, d, b]));
+        assert_parent(world, e, Some(a));
+        assert_events(
+            world,
+            &[ChildAdded {
+                child: e,
+                parent: a,
+            }],
+        );
+
+        world.entity_mut(a).insert_child_at(0, b);
+        assert_children(world, a, Some(&[b, c, e, d]));
+        assert_events(world, &[ChildrenReordered { parent: a }]);
+
+        world
+            .entity_mut(a)
+            .sort_children_by(|x, y| x.id().index().cmp(&y.id().index()));
+        assert_children(world, a, Some(&[b, c, d, e]));
+        assert_events(world, &[ChildrenReordered { parent: a }]);
+
+        world
+            .entity_mut(a)
+            .sort_children_by(|x, y| x.id().index().cmp(&y.id().index()));
+        assert_events(world, &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn move_child_out_of_bounds() {
+        let world = &mut World::new();
+        let child = world.spawn_empty().id();
+        world.spawn_empty().push_children(&[child]).move_child(0, 1);
+    }
+
+    #[test]
+    fn reorder_children_commands() {
+        #[derive(Component)]
+        struct Order(u32);
+
+        let mut world = World::new();
+        world.insert_resource(Events::<HierarchyEvent>::default());
+        let entities = world
+            .spawn_batch([Order(2), Order(0), Order(1), Order(3)])
+            .collect::<Vec<Entity>>();
+        let parent = world.spawn_empty().push_children(&entities[..3]).id();
+        omit_events(&mut world, 3);
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands
+                .entity(parent)
+                .sort_children_by(|a, b| {
+                    let a = a.get::<Order>().unwrap().0;
+                    let b = b.get::<Order>().unwrap().0;
+                    a.cmp(&b)
+                })
+                .insert_child_at(0, entities[3])
+                .move_child(0, 3);
+        }
+        queue.apply(&mut world);
+
+        assert_children(
+            &mut world,
+            parent,
+            Some(&[entities[1], entities[2], entities[0], entities[3]]),
+        );
+        assert_events(
+            &mut world,
+            &[
+                ChildrenReordered { parent },
+                ChildAdded {
+                    child: entities[3],
+                    parent,
+                },
+                ChildrenReordered { parent },
+            ],
+        );
+    }
+
     #[test]
     fn regression_push_children_same_archetype() {
         let mut world = World::new();
@@ -2,7 +2,7 @@ use bevy_ecs::{
     component::Component,
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
     prelude::FromWorld,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectNoClone},
     world::World,
 };
 use bevy_reflect::Reflect;
@@ -17,7 +17,7 @@ use std::ops::Deref;
 /// [`HierarchyQueryExt`]: crate::query_extension::HierarchyQueryExt
 /// [`Query`]: bevy_ecs::system::Query
 #[derive(Component, Debug, Reflect)]
-#[reflect(Component, MapEntities)]
+#[reflect(Component, MapEntities, NoClone)]
 pub struct Children(pub(crate) SmallVec<[Entity; 8]>);
 
 impl MapEntities for Children {
@@ -1,7 +1,7 @@
 use bevy_ecs::{
     component::Component,
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectNoClone},
     world::{FromWorld, World},
 };
 use bevy_reflect::Reflect;
@@ -15,7 +15,7 @@ use std::ops::Deref;
 /// [`HierarchyQueryExt`]: crate::query_extension::HierarchyQueryExt
 /// [`Query`]: bevy_ecs::system::Query
 #[derive(Component, Debug, Eq, PartialEq, Reflect)]
-#[reflect(Component, MapEntities, PartialEq)]
+#[reflect(Component, MapEntities, NoClone, PartialEq)]
 pub struct Parent(pub(crate) Entity);
 
 impl Parent {
@@ -28,4 +28,27 @@ pub enum HierarchyEvent {
         /// The parent the child was added to
         new_parent: Entity,
     },
+    /// Fired whenever the order of a parent's [`Children`](crate::Children) changes
+    /// without children being added or removed.
+    ChildrenReordered {
+        /// The parent whose children were reordered
+        parent: Entity,
+    },
+    /// Fired for every [`Entity`] despawned by a recursive despawn, such as
+    /// [`despawn_recursive`](crate::DespawnRecursiveExt::despawn_recursive) or
+    /// [`despawn_descendants`](crate::DespawnRecursiveExt::despawn_descendants).
+    ///
+    /// The entity no longer exists when this event is read, so systems cleaning up external
+    /// resources (e.g. physics or audio handles) should key them by [`Entity`].
+    ///
+    /// If a [`StrongEntity`](bevy_ecs::entity::StrongEntity) handle defers the despawn of an
+    /// entity, its event is sent in [`CoreSet::First`](bevy_app::CoreSet::First) once the entity
+    /// was actually despawned.
+    Despawned {
+        /// The entity that was despawned
+        entity: Entity,
+        /// The entity the recursive despawn was started on, which is only despawned itself by
+        /// [`despawn_recursive`](crate::DespawnRecursiveExt::despawn_recursive)
+        root: Entity,
+    },
 }
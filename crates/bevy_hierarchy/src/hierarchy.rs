@@ -1,7 +1,12 @@
-use crate::components::{Children, Parent};
+use crate::{
+    child_builder::{push_events, BuildWorldChildren},
+    components::{Children, Parent},
+    HierarchyEvent,
+};
 use bevy_ecs::{
-    entity::Entity,
-    system::{Command, EntityCommands},
+    entity::{DespawnResult, Entities, Entity},
+    event::{EventWriter, Events},
+    system::{Command, EntityCommands, ResMut, Resource},
     world::{EntityMut, World},
 };
 use bevy_utils::tracing::debug;
@@ -20,7 +25,18 @@ pub struct DespawnChildrenRecursive {
     pub entity: Entity,
 }
 
+/// Clones the given entity's children recursively and adds the clones to another entity
+#[derive(Debug)]
+pub struct CloneChildrenRecursive {
+    /// The entity whose children are cloned
+    pub source: Entity,
+    /// The entity the cloned children are added to
+    pub destination: Entity,
+}
+
 /// Function for despawning an entity and all its children
+///
+/// Sends a [`HierarchyEvent::Despawned`] for every despawned entity.
 pub fn despawn_with_children_recursive(world: &mut World, entity: Entity) {
     // first, make the entity's own parent forget about it
     if let Some(parent) = world.get::<Parent>(entity).map(|parent| parent.0) {
@@ -30,28 +46,109 @@ pub fn despawn_with_children_recursive(world: &mut World, entity: Entity) {
     }
 
     // then despawn the entity and all of its children
-    despawn_with_children_recursive_inner(world, entity);
+    let mut despawned = Despawned::default();
+    despawn_with_children_recursive_inner(world, entity, &mut despawned);
+    push_despawned_events(world, entity, despawned);
+}
+
+/// The entities despawned by a recursive despawn.
+#[derive(Default)]
+struct Despawned {
+    now: Vec<Entity>,
+    /// The entities whose despawn was deferred by a [`StrongEntity`](bevy_ecs::entity::StrongEntity)
+    deferred: Vec<Entity>,
 }
 
-// Should only be called by `despawn_with_children_recursive`!
-fn despawn_with_children_recursive_inner(world: &mut World, entity: Entity) {
+// Should only be called by `despawn_with_children_recursive` and `despawn_children`!
+fn despawn_with_children_recursive_inner(
+    world: &mut World,
+    entity: Entity,
+    despawned: &mut Despawned,
+) {
     if let Some(mut children) = world.get_mut::<Children>(entity) {
         for e in std::mem::take(&mut children.0) {
-            despawn_with_children_recursive_inner(world, e);
+            despawn_with_children_recursive_inner(world, e, despawned);
         }
     }
 
-    if !world.despawn(entity) {
-        debug!("Failed to despawn entity {:?}", entity);
+    match world.despawn(entity) {
+        DespawnResult::Despawned => despawned.now.push(entity),
+        DespawnResult::Deferred => despawned.deferred.push(entity),
+        DespawnResult::NotFound => debug!("Failed to despawn entity {:?}", entity),
     }
 }
 
 fn despawn_children(world: &mut World, entity: Entity) {
+    let mut despawned = Despawned::default();
     if let Some(mut children) = world.get_mut::<Children>(entity) {
         for e in std::mem::take(&mut children.0) {
-            despawn_with_children_recursive_inner(world, e);
+            despawn_with_children_recursive_inner(world, e, &mut despawned);
         }
     }
+    push_despawned_events(world, entity, despawned);
+}
+
+/// Function for cloning an entity and all its children, see [`World::clone_entity`]
+///
+/// Returns the clone of `entity`, which has no parent.
+pub fn clone_with_children_recursive(world: &mut World, entity: Entity) -> Entity {
+    let clone = world.clone_entity(entity);
+    clone_children(world, entity, clone);
+    clone
+}
+
+fn clone_children(world: &mut World, source: Entity, destination: Entity) {
+    let Some(children) = world
+        .get::<Children>(source)
+        .map(|children| children.0.clone())
+    else {
+        return;
+    };
+    let clones: Vec<Entity> = children
+        .into_iter()
+        .map(|child| clone_with_children_recursive(world, child))
+        .collect();
+    world.entity_mut(destination).push_children(&clones);
+}
+
+fn push_despawned_events(world: &mut World, root: Entity, despawned: Despawned) {
+    push_events(
+        world,
+        despawned
+            .now
+            .into_iter()
+            .map(|entity| HierarchyEvent::Despawned { entity, root }),
+    );
+    if !despawned.deferred.is_empty() && world.contains_resource::<Events<HierarchyEvent>>() {
+        world
+            .get_resource_or_insert_with(DeferredDespawnEvents::default)
+            .0
+            .extend(despawned.deferred.into_iter().map(|entity| (entity, root)));
+    }
+}
+
+/// The entities and recursive despawn roots of the [`HierarchyEvent::Despawned`] events that are
+/// sent by [`send_deferred_despawn_events`] once the entities are actually despawned.
+#[derive(Resource, Default)]
+pub(crate) struct DeferredDespawnEvents(Vec<(Entity, Entity)>);
+
+/// Sends the [`HierarchyEvent::Despawned`] events of entities whose despawn was deferred by a
+/// [`StrongEntity`](bevy_ecs::entity::StrongEntity) once they are despawned.
+pub(crate) fn send_deferred_despawn_events(
+    deferred: Option<ResMut<DeferredDespawnEvents>>,
+    entities: &Entities,
+    mut events: EventWriter<HierarchyEvent>,
+) {
+    let Some(mut deferred) = deferred else {
+        return;
+    };
+    deferred.0.retain(|&(entity, root)| {
+        let despawned = !entities.contains(entity);
+        if despawned {
+            events.send(HierarchyEvent::Despawned { entity, root });
+        }
+        !despawned
+    });
 }
 
 impl Command for DespawnRecursive {
@@ -80,12 +177,56 @@ impl Command for DespawnChildrenRecursive {
     }
 }
 
+impl Command for CloneChildrenRecursive {
+    fn write(self, world: &mut World) {
+        #[cfg(feature = "trace")]
+        let _span = bevy_utils::tracing::info_span!(
+            "command",
+            name = "CloneChildrenRecursive",
+            entity = bevy_utils::tracing::field::debug(self.source)
+        )
+        .entered();
+        clone_children(world, self.source, self.destination);
+    }
+}
+
+/// Trait that holds functions for cloning recursively down the transform hierarchy
+///
+/// The components are cloned with [`World::clone_entity`], so only components registered with
+/// `#[reflect(Component)]` are cloned. The clone of the root entity has no parent.
+pub trait CloneRecursiveExt {
+    /// Spawns a clone of the provided entity alongside clones of all descendants, and returns the
+    /// clone of the provided entity.
+    fn clone_recursive(&mut self) -> Entity;
+}
+
+impl<'w, 's, 'a> CloneRecursiveExt for EntityCommands<'w, 's, 'a> {
+    fn clone_recursive(&mut self) -> Entity {
+        let source = self.id();
+        let destination = self.commands().clone_entity(source).id();
+        self.commands().add(CloneChildrenRecursive {
+            source,
+            destination,
+        });
+        destination
+    }
+}
+
+impl<'w> CloneRecursiveExt for EntityMut<'w> {
+    fn clone_recursive(&mut self) -> Entity {
+        let entity = self.id();
+        self.world_scope(|world| clone_with_children_recursive(world, entity))
+    }
+}
+
 /// Trait that holds functions for despawning recursively down the transform hierarchy
+///
+/// A [`HierarchyEvent::Despawned`] is sent for every despawned entity.
 pub trait DespawnRecursiveExt {
     /// Despawns the provided entity alongside all descendants.
     fn despawn_recursive(self);
 
-    /// Despawns all descendants of the given entity.
+    /// Despawns all descendants of the given entity, keeping the entity itself.
     fn despawn_descendants(&mut self);
 }
 
@@ -144,7 +285,10 @@ mod tests {
     };
 
     use super::DespawnRecursiveExt;
-    use crate::{child_builder::BuildChildren, components::Children};
+    use crate::{
+        child_builder::{BuildChildren, BuildWorldChildren},
+        components::Children,
+    };
 
     #[derive(Component, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
     struct Idx(u32);
@@ -226,4 +370,144 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn despawn_events() {
+        use crate::HierarchyEvent;
+        use bevy_ecs::event::Events;
+
+        let mut world = World::default();
+        world.init_resource::<Events<HierarchyEvent>>();
+        let grandchild = world.spawn_empty().id();
+        let child = world.spawn_empty().push_children(&[grandchild]).id();
+        let root = world.spawn_empty().push_children(&[child]).id();
+        world.resource_mut::<Events<HierarchyEvent>>().clear();
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world)
+            .entity(root)
+            .despawn_descendants();
+        queue.apply(&mut world);
+
+        assert!(world.get_entity(root).is_some());
+        assert!(world.get::<Children>(root).unwrap().is_empty());
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get_entity(grandchild).is_none());
+        let events: Vec<_> = world
+            .resource_mut::<Events<HierarchyEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            [
+                HierarchyEvent::Despawned {
+                    entity: grandchild,
+                    root,
+                },
+                HierarchyEvent::Despawned {
+                    entity: child,
+                    root,
+                },
+            ]
+        );
+
+        world.entity_mut(root).despawn_recursive();
+        let events: Vec<_> = world
+            .resource_mut::<Events<HierarchyEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(events, [HierarchyEvent::Despawned { entity: root, root }]);
+    }
+
+    #[test]
+    fn deferred_despawn_events() {
+        use super::send_deferred_despawn_events;
+        use crate::HierarchyEvent;
+        use bevy_ecs::{event::Events, schedule::Schedule};
+
+        let mut world = World::default();
+        world.init_resource::<Events<HierarchyEvent>>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(send_deferred_despawn_events);
+        let child = world.spawn_empty().id();
+        let root = world.spawn_empty().push_children(&[child]).id();
+        let strong = world.strong_entity(child).unwrap();
+        world.resource_mut::<Events<HierarchyEvent>>().clear();
+
+        world.entity_mut(root).despawn_recursive();
+        schedule.run(&mut world);
+        let events: Vec<_> = world
+            .resource_mut::<Events<HierarchyEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(events, [HierarchyEvent::Despawned { entity: root, root }]);
+        assert!(world.get_entity(child).is_some());
+
+        drop(strong);
+        world.despawn_released_entities();
+        schedule.run(&mut world);
+        let events: Vec<_> = world
+            .resource_mut::<Events<HierarchyEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            [HierarchyEvent::Despawned {
+                entity: child,
+                root
+            }]
+        );
+    }
+
+    #[test]
+    fn clone_recursive() {
+        use super::CloneRecursiveExt;
+        use crate::components::Parent;
+        use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct Name(String);
+
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Name>();
+            registry.register::<Children>();
+            registry.register::<Parent>();
+        }
+
+        let root = world.spawn(Name("root".to_owned())).id();
+        let parent = world.spawn(Name("parent".to_owned())).set_parent(root).id();
+        world.spawn(Name("child".to_owned())).set_parent(parent);
+
+        let clone = world.entity_mut(parent).clone_recursive();
+        assert!(world.get::<Parent>(clone).is_none());
+        assert_eq!(world.get::<Name>(clone), Some(&Name("parent".to_owned())));
+        let cloned_children = world.get::<Children>(clone).unwrap();
+        assert_eq!(cloned_children.len(), 1);
+        let cloned_child = cloned_children[0];
+        assert_eq!(
+            world.get::<Name>(cloned_child),
+            Some(&Name("child".to_owned()))
+        );
+        assert_eq!(world.get::<Parent>(cloned_child).unwrap().get(), clone);
+        // the original hierarchy is untouched
+        assert_eq!(world.get::<Children>(parent).unwrap().len(), 1);
+        assert_eq!(world.get::<Children>(root).unwrap().len(), 1);
+
+        let mut queue = CommandQueue::default();
+        let clone = Commands::new(&mut queue, &world)
+            .entity(root)
+            .clone_recursive();
+        queue.apply(&mut world);
+        let cloned_parent = world.get::<Children>(clone).unwrap()[0];
+        assert_eq!(
+            world.get::<Name>(cloned_parent),
+            Some(&Name("parent".to_owned()))
+        );
+        assert_eq!(world.get::<Children>(cloned_parent).unwrap().len(), 1);
+    }
 }
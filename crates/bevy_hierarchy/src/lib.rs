@@ -32,6 +32,7 @@ pub mod prelude {
 }
 
 use bevy_app::prelude::*;
+use bevy_ecs::schedule::IntoSystemConfig;
 
 /// The base plugin for handling [`Parent`] and [`Children`] components
 #[derive(Default)]
@@ -42,6 +43,7 @@ impl Plugin for HierarchyPlugin {
         app.register_type::<Children>()
             .register_type::<Parent>()
             .register_type::<smallvec::SmallVec<[bevy_ecs::entity::Entity; 8]>>()
-            .add_event::<HierarchyEvent>();
+            .add_event::<HierarchyEvent>()
+            .add_system(send_deferred_despawn_events.in_base_set(CoreSet::First));
     }
 }
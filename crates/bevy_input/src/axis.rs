@@ -9,6 +9,8 @@ use std::hash::Hash;
 pub struct Axis<T> {
     /// The position data of the input devices.
     axis_data: HashMap<T, f32>,
+    /// The processed position data that smoothed input devices move towards, see [`Axis::smooth`].
+    smoothing_targets: HashMap<T, f32>,
 }
 
 impl<T> Default for Axis<T>
@@ -18,6 +20,7 @@ where
     fn default() -> Self {
         Axis {
             axis_data: HashMap::default(),
+            smoothing_targets: HashMap::default(),
         }
     }
 }
@@ -41,15 +44,64 @@ where
     /// - wasn't present before, [None] is returned.
     pub fn set(&mut self, input_device: T, position_data: f32) -> Option<f32> {
         let new_position_data = position_data.clamp(Self::MIN, Self::MAX);
+        self.smoothing_targets.remove(&input_device);
         self.axis_data.insert(input_device, new_position_data)
     }
 
+    /// Processes `position_data` with the [`AxisProcessor`] of the `input_device` in `processing`
+    /// and sets the result as the position data of the `input_device`.
+    ///
+    /// If the processor smooths the `input_device` and it already has position data, the result
+    /// only becomes the target that [`Axis::smooth`] moves the position data towards, and the
+    /// current position data is returned. If the `input_device` has no [`AxisProcessor`], this
+    /// behaves like [`Axis::set`].
+    pub fn set_processed(
+        &mut self,
+        input_device: T,
+        position_data: f32,
+        processing: &AxisProcessing<T>,
+    ) -> Option<f32> {
+        let Some(processor) = processing.get(input_device) else {
+            return self.set(input_device, position_data);
+        };
+        let target = processor
+            .process(None, position_data)
+            .clamp(Self::MIN, Self::MAX);
+        match self.get(input_device) {
+            Some(previous) if processor.smoothing > 0.0 => {
+                self.smoothing_targets.insert(input_device, target);
+                Some(previous)
+            }
+            _ => self.set(input_device, target),
+        }
+    }
+
+    /// Moves the position data of every smoothed input device one step towards the target set by
+    /// [`Axis::set_processed`].
+    ///
+    /// This is called once per frame by the gamepad systems, so smoothed axes keep converging in
+    /// frames without new input.
+    pub fn smooth(&mut self, processing: &AxisProcessing<T>) {
+        let axis_data = &mut self.axis_data;
+        self.smoothing_targets.retain(|input_device, target| {
+            let Some(position_data) = axis_data.get_mut(input_device) else {
+                return false;
+            };
+            *position_data = match processing.get(*input_device) {
+                Some(processor) => processor.smooth(*position_data, *target),
+                None => *target,
+            };
+            *position_data != *target
+        });
+    }
+
     /// Returns a position data corresponding to the `input_device`.
     pub fn get(&self, input_device: T) -> Option<f32> {
         self.axis_data.get(&input_device).copied()
     }
     /// Removes the position data of the `input_device`, returning the position data if the input device was previously set.
     pub fn remove(&mut self, input_device: T) -> Option<f32> {
+        self.smoothing_targets.remove(&input_device);
         self.axis_data.remove(&input_device)
     }
     /// Returns an iterator of all the input devices that have position data
@@ -58,11 +110,183 @@ where
     }
 }
 
+/// Post-processing applied to the raw position data of an axis by [`Axis::set_processed`].
+///
+/// The steps are applied in order:
+/// 1. Values within the dead zone are mapped to `0.0`, the remaining range is rescaled so the
+///    output still covers the full range.
+/// 2. The value is inverted if `inverted` is set.
+/// 3. The value is multiplied by `sensitivity`.
+/// 4. The value is exponentially smoothed towards the previous value by `smoothing`. [`Axis`]
+///    applies one smoothing step per frame with [`Axis::smooth`].
+///
+/// The default processor leaves the values unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisProcessor {
+    /// Absolute values below this threshold are treated as `0.0`. Must be in `0.0..1.0`.
+    pub dead_zone: f32,
+    /// Whether the value is negated.
+    pub inverted: bool,
+    /// The factor the value is multiplied by.
+    pub sensitivity: f32,
+    /// How much of the previous value is kept on every update. Must be in `0.0..1.0`, where `0.0`
+    /// disables smoothing and values closer to `1.0` react slower.
+    pub smoothing: f32,
+}
+
+impl Default for AxisProcessor {
+    fn default() -> Self {
+        AxisProcessor {
+            dead_zone: 0.0,
+            inverted: false,
+            sensitivity: 1.0,
+            smoothing: 0.0,
+        }
+    }
+}
+
+impl AxisProcessor {
+    /// Returns this processor with the given dead zone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dead_zone` is not in `0.0..1.0`.
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        assert!(
+            (0.0..1.0).contains(&dead_zone),
+            "dead_zone must be in 0.0..1.0, got {dead_zone}"
+        );
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Returns this processor with inversion enabled or disabled.
+    pub fn with_inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Returns this processor with the given sensitivity.
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Returns this processor with the given smoothing factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not in `0.0..1.0`.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        assert!(
+            (0.0..1.0).contains(&smoothing),
+            "smoothing must be in 0.0..1.0, got {smoothing}"
+        );
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Processes the raw `value` of an axis whose current value is `previous`.
+    ///
+    /// The result is not clamped, [`Axis::set`] takes care of that.
+    pub fn process(&self, previous: Option<f32>, value: f32) -> f32 {
+        let magnitude = value.abs();
+        let mut value = if magnitude < self.dead_zone {
+            0.0
+        } else {
+            value.signum() * (magnitude - self.dead_zone) / (1.0 - self.dead_zone)
+        };
+        if self.inverted {
+            value = -value;
+        }
+        value *= self.sensitivity;
+        match previous {
+            Some(previous) => self.smooth(previous, value),
+            None => value,
+        }
+    }
+
+    /// Performs one smoothing step from the `previous` value of an axis towards `target`.
+    pub fn smooth(&self, previous: f32, target: f32) -> f32 {
+        previous + (target - previous) * (1.0 - self.smoothing)
+    }
+}
+
+/// Stores the [`AxisProcessor`]s applied to the input devices of type `T` by
+/// [`Axis::set_processed`].
+///
+/// The gamepad systems use [`AxisProcessing<GamepadAxis>`](crate::gamepad::GamepadAxis) and
+/// [`AxisProcessing<GamepadButton>`](crate::gamepad::GamepadButton) when updating the
+/// corresponding [`Axis`] resources.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_ecs::system::ResMut;
+/// # use bevy_input::{gamepad::{Gamepad, GamepadAxis, GamepadAxisType}, AxisProcessing, AxisProcessor};
+/// fn invert_look(mut processing: ResMut<AxisProcessing<GamepadAxis>>) {
+///     let axis = GamepadAxis::new(Gamepad::new(0), GamepadAxisType::RightStickY);
+///     processing.insert(axis, AxisProcessor::default().with_inverted(true));
+/// }
+/// # bevy_ecs::system::assert_is_system(invert_look);
+/// ```
+#[derive(Debug, Resource)]
+pub struct AxisProcessing<T> {
+    default_processor: Option<AxisProcessor>,
+    processors: HashMap<T, AxisProcessor>,
+}
+
+impl<T> Default for AxisProcessing<T>
+where
+    T: Copy + Eq + Hash,
+{
+    fn default() -> Self {
+        AxisProcessing {
+            default_processor: None,
+            processors: HashMap::default(),
+        }
+    }
+}
+
+impl<T> AxisProcessing<T>
+where
+    T: Copy + Eq + Hash,
+{
+    /// Returns the [`AxisProcessor`] used for the `input_device`.
+    ///
+    /// Falls back to the default processor if the `input_device` has none of its own.
+    pub fn get(&self, input_device: T) -> Option<&AxisProcessor> {
+        self.processors
+            .get(&input_device)
+            .or(self.default_processor.as_ref())
+    }
+
+    /// Sets the [`AxisProcessor`] of the `input_device`, returning the previous one.
+    pub fn insert(&mut self, input_device: T, processor: AxisProcessor) -> Option<AxisProcessor> {
+        self.processors.insert(input_device, processor)
+    }
+
+    /// Removes the [`AxisProcessor`] of the `input_device`, returning it if it was set.
+    pub fn remove(&mut self, input_device: T) -> Option<AxisProcessor> {
+        self.processors.remove(&input_device)
+    }
+
+    /// Returns the [`AxisProcessor`] used for input devices without a processor of their own.
+    pub fn default_processor(&self) -> Option<&AxisProcessor> {
+        self.default_processor.as_ref()
+    }
+
+    /// Sets the [`AxisProcessor`] used for input devices without a processor of their own.
+    pub fn set_default_processor(&mut self, processor: Option<AxisProcessor>) {
+        self.default_processor = processor;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         gamepad::{Gamepad, GamepadButton, GamepadButtonType},
-        Axis,
+        Axis, AxisProcessing, AxisProcessor,
     };
 
     #[test]
@@ -142,4 +366,59 @@ mod tests {
         ));
         assert_eq!(axis.devices().count(), 1);
     }
+
+    #[test]
+    fn test_axis_processor() {
+        let dead_zone = AxisProcessor::default().with_dead_zone(0.5);
+        assert_eq!(dead_zone.process(None, 0.25), 0.0);
+        assert_eq!(dead_zone.process(None, -0.5), -0.0);
+        assert_eq!(dead_zone.process(None, 0.75), 0.5);
+        assert_eq!(dead_zone.process(None, -1.0), -1.0);
+
+        let inverted = AxisProcessor::default()
+            .with_inverted(true)
+            .with_sensitivity(2.0);
+        assert_eq!(inverted.process(None, 0.25), -0.5);
+
+        let smoothed = AxisProcessor::default().with_smoothing(0.75);
+        assert_eq!(smoothed.process(None, 1.0), 1.0);
+        assert_eq!(smoothed.process(Some(0.0), 1.0), 0.25);
+        assert_eq!(smoothed.process(Some(0.25), 1.0), 0.4375);
+    }
+
+    #[test]
+    fn test_axis_set_processed() {
+        let right_trigger = GamepadButton::new(Gamepad::new(1), GamepadButtonType::RightTrigger);
+        let left_trigger = GamepadButton::new(Gamepad::new(1), GamepadButtonType::LeftTrigger);
+        let mut axis = Axis::<GamepadButton>::default();
+        let mut processing = AxisProcessing::<GamepadButton>::default();
+
+        axis.set_processed(right_trigger, 0.5, &processing);
+        assert_eq!(axis.get(right_trigger), Some(0.5));
+
+        processing.insert(
+            right_trigger,
+            AxisProcessor::default().with_sensitivity(4.0),
+        );
+        axis.set_processed(right_trigger, 0.5, &processing);
+        assert_eq!(axis.get(right_trigger), Some(1.0));
+
+        processing.set_default_processor(Some(AxisProcessor::default().with_inverted(true)));
+        axis.set_processed(left_trigger, 0.5, &processing);
+        assert_eq!(axis.get(left_trigger), Some(-0.5));
+        axis.set_processed(right_trigger, 0.125, &processing);
+        assert_eq!(axis.get(right_trigger), Some(0.5));
+
+        processing.insert(right_trigger, AxisProcessor::default().with_smoothing(0.75));
+        assert_eq!(
+            axis.set_processed(right_trigger, 1.0, &processing),
+            Some(0.5)
+        );
+        assert_eq!(axis.get(right_trigger), Some(0.5));
+        axis.smooth(&processing);
+        assert_eq!(axis.get(right_trigger), Some(0.625));
+        axis.set(right_trigger, 0.0);
+        axis.smooth(&processing);
+        assert_eq!(axis.get(right_trigger), Some(0.0));
+    }
 }
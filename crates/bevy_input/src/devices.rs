@@ -0,0 +1,298 @@
+//! The input devices of the player.
+
+use crate::{
+    gamepad::{Gamepad, GamepadConnection, GamepadConnectionEvent},
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::{ForceTouch, TouchInput},
+};
+use bevy_ecs::{event::EventReader, system::ResMut, system::Resource};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// The kind of an [`InputDevice`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputDeviceKind {
+    /// A keyboard.
+    Keyboard,
+    /// A mouse or touchpad.
+    Mouse,
+    /// A gamepad.
+    Gamepad(Gamepad),
+    /// A touchscreen used with fingers.
+    Touchscreen,
+    /// A pen or stylus.
+    Pen,
+}
+
+/// The capabilities of an [`InputDevice`].
+///
+/// Capabilities that can't be determined on the current platform are reported as missing.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Default, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputDeviceCapabilities {
+    /// The device supports rumble / force feedback.
+    pub has_rumble: bool,
+    /// The device has a gyroscope.
+    pub has_gyro: bool,
+    /// The triggers of the device report analog values.
+    pub has_analog_triggers: bool,
+    /// The device reports how hard it is pressed, see [`ForceTouch`].
+    pub has_pressure: bool,
+}
+
+/// A connected input device, see [`InputDevices`].
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputDevice {
+    /// The kind of the device.
+    pub kind: InputDeviceKind,
+    /// The name of the device, as reported by the OS, if available.
+    pub name: Option<String>,
+    /// The capabilities of the device.
+    pub capabilities: InputDeviceCapabilities,
+}
+
+impl InputDevice {
+    /// Creates a new [`InputDevice`] of the given `kind` without a name or capabilities.
+    pub fn new(kind: InputDeviceKind) -> Self {
+        Self {
+            kind,
+            name: None,
+            capabilities: InputDeviceCapabilities::default(),
+        }
+    }
+}
+
+/// A list of the connected input devices, e.g. for settings menus to adapt to the player's
+/// hardware.
+///
+/// ## Updating
+///
+/// Gamepads are added and removed by the [`input_devices_system`] whenever a
+/// [`GamepadConnectionEvent`] is received. Keyboards, mice, touchscreens and pens are added once
+/// they send their first input, as the OS doesn't report their connection.
+#[derive(Resource, Default, Debug)]
+pub struct InputDevices {
+    devices: Vec<InputDevice>,
+}
+
+impl InputDevices {
+    /// Returns an iterator over the connected devices, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &InputDevice> {
+        self.devices.iter()
+    }
+
+    /// Returns the device of the given `kind`, if it is connected.
+    pub fn get(&self, kind: InputDeviceKind) -> Option<&InputDevice> {
+        self.devices.iter().find(|device| device.kind == kind)
+    }
+
+    /// Returns `true` if a device of the given `kind` is connected.
+    pub fn contains(&self, kind: InputDeviceKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Returns `true` if any connected gamepad has all of the given `capabilities`.
+    pub fn any_gamepad_with(&self, capabilities: InputDeviceCapabilities) -> bool {
+        self.devices.iter().any(|device| {
+            matches!(device.kind, InputDeviceKind::Gamepad(_))
+                && (!capabilities.has_rumble || device.capabilities.has_rumble)
+                && (!capabilities.has_gyro || device.capabilities.has_gyro)
+                && (!capabilities.has_analog_triggers || device.capabilities.has_analog_triggers)
+                && (!capabilities.has_pressure || device.capabilities.has_pressure)
+        })
+    }
+
+    /// Adds the `device`, replacing a device of the same kind.
+    ///
+    /// Can be used by input backends to add devices that haven't sent any input yet.
+    pub fn insert(&mut self, device: InputDevice) {
+        match self.devices.iter_mut().find(|d| d.kind == device.kind) {
+            Some(existing) => *existing = device,
+            None => self.devices.push(device),
+        }
+    }
+
+    /// Removes the device of the given `kind`, returning it if it was connected.
+    pub fn remove(&mut self, kind: InputDeviceKind) -> Option<InputDevice> {
+        let index = self.devices.iter().position(|device| device.kind == kind)?;
+        Some(self.devices.remove(index))
+    }
+
+    /// Adds a device of the given `kind` if none is connected yet, and returns it.
+    fn observe(&mut self, kind: InputDeviceKind) -> &mut InputDevice {
+        match self.devices.iter().position(|device| device.kind == kind) {
+            Some(index) => &mut self.devices[index],
+            None => {
+                self.devices.push(InputDevice::new(kind));
+                self.devices.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+/// Updates the [`InputDevices`] resource from connection and input events.
+pub fn input_devices_system(
+    mut devices: ResMut<InputDevices>,
+    mut gamepad_connections: EventReader<GamepadConnectionEvent>,
+    mut keyboard_input: EventReader<KeyboardInput>,
+    mut mouse_button_input: EventReader<MouseButtonInput>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut touch_input: EventReader<TouchInput>,
+) {
+    for event in gamepad_connections.iter() {
+        let kind = InputDeviceKind::Gamepad(event.gamepad);
+        match &event.connection {
+            GamepadConnection::Connected(info) => devices.insert(InputDevice {
+                kind,
+                name: Some(info.name.clone()),
+                capabilities: info.capabilities,
+            }),
+            GamepadConnection::Disconnected => {
+                devices.remove(kind);
+            }
+        }
+    }
+
+    if keyboard_input.iter().next().is_some() {
+        devices.observe(InputDeviceKind::Keyboard);
+    }
+    keyboard_input.clear();
+
+    let mouse_used = mouse_button_input.iter().next().is_some()
+        | mouse_motion.iter().next().is_some()
+        | mouse_wheel.iter().next().is_some();
+    if mouse_used {
+        devices.observe(InputDeviceKind::Mouse);
+    }
+    mouse_button_input.clear();
+    mouse_motion.clear();
+    mouse_wheel.clear();
+
+    for touch in touch_input.iter() {
+        let kind = match touch.force {
+            // only styluses report their altitude angle
+            Some(ForceTouch::Calibrated {
+                altitude_angle: Some(_),
+                ..
+            }) => InputDeviceKind::Pen,
+            _ => InputDeviceKind::Touchscreen,
+        };
+        let has_pressure = touch.force.is_some();
+        let device = devices.observe(kind);
+        device.capabilities.has_pressure |= has_pressure;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gamepad::GamepadInfo,
+        touch::{TouchInput, TouchPhase},
+        ButtonState,
+    };
+    use bevy_ecs::prelude::*;
+    use bevy_math::Vec2;
+
+    #[test]
+    fn track_input_devices() {
+        let mut world = World::new();
+        world.init_resource::<InputDevices>();
+        world.init_resource::<Events<GamepadConnectionEvent>>();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Events<MouseButtonInput>>();
+        world.init_resource::<Events<MouseMotion>>();
+        world.init_resource::<Events<MouseWheel>>();
+        world.init_resource::<Events<TouchInput>>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(input_devices_system);
+
+        let gamepad = Gamepad::new(0);
+        let capabilities = InputDeviceCapabilities {
+            has_rumble: true,
+            ..Default::default()
+        };
+        world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: String::from("Gamepad"),
+                capabilities,
+                ..Default::default()
+            }),
+        ));
+        world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: None,
+            state: ButtonState::Pressed,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+        world.send_event(TouchInput {
+            phase: TouchPhase::Started,
+            position: Vec2::ZERO,
+            force: Some(ForceTouch::Calibrated {
+                force: 1.0,
+                max_possible_force: 2.0,
+                altitude_angle: Some(1.0),
+            }),
+            id: 0,
+        });
+        schedule.run(&mut world);
+
+        let devices = world.resource::<InputDevices>();
+        let kinds: Vec<_> = devices.iter().map(|device| device.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                InputDeviceKind::Gamepad(gamepad),
+                InputDeviceKind::Keyboard,
+                InputDeviceKind::Pen
+            ]
+        );
+        let device = devices.get(InputDeviceKind::Gamepad(gamepad)).unwrap();
+        assert_eq!(device.name.as_deref(), Some("Gamepad"));
+        assert!(devices.any_gamepad_with(capabilities));
+        assert!(!devices.any_gamepad_with(InputDeviceCapabilities {
+            has_gyro: true,
+            ..Default::default()
+        }));
+        assert!(
+            devices
+                .get(InputDeviceKind::Pen)
+                .unwrap()
+                .capabilities
+                .has_pressure
+        );
+        assert!(!devices.contains(InputDeviceKind::Mouse));
+
+        world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Disconnected,
+        ));
+        schedule.run(&mut world);
+        assert!(!world
+            .resource::<InputDevices>()
+            .contains(InputDeviceKind::Gamepad(gamepad)));
+    }
+}
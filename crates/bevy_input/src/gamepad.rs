@@ -1,11 +1,12 @@
-use crate::{Axis, Input};
+use crate::{devices::InputDeviceCapabilities, Axis, AxisProcessing, Input};
 use bevy_ecs::event::{EventReader, EventWriter};
 use bevy_ecs::{
-    change_detection::DetectChangesMut,
-    system::{Res, ResMut, Resource},
+    change_detection::{DetectChanges, DetectChangesMut},
+    system::{Res, ResMut, Resource, SystemParam},
 };
-use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
-use bevy_utils::{tracing::info, HashMap};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, ReflectFromReflect};
+use bevy_utils::{tracing::info, HashMap, HashSet};
 use thiserror::Error;
 
 /// Errors that occur when setting axis settings for gamepad input.
@@ -57,6 +58,23 @@ pub enum ButtonSettingsError {
     },
 }
 
+/// Errors that occur when setting stick settings for gamepad input.
+#[derive(Error, Debug, PartialEq)]
+pub enum StickSettingsError {
+    /// The given parameter `deadzone` was not in range 0.0..=1.0.
+    #[error("invalid deadzone {0}, expected value [0.0..=1.0]")]
+    DeadZoneOutOfRange(f32),
+    /// The given parameter `livezone` was not in range 0.0..=1.0.
+    #[error("invalid livezone {0}, expected value [0.0..=1.0]")]
+    LiveZoneOutOfRange(f32),
+    /// Parameter `deadzone` was not less than parameter `livezone`.
+    #[error("invalid parameter values deadzone {} livezone {}, expected deadzone < livezone", .deadzone, .livezone)]
+    DeadZoneGreaterThanOrEqualToLiveZone { deadzone: f32, livezone: f32 },
+    /// The given parameter `exponent` was not positive.
+    #[error("invalid exponent {0}, expected exponent > 0.0")]
+    ExponentOutOfRange(f32),
+}
+
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
@@ -91,15 +109,69 @@ impl Gamepad {
 }
 
 /// Metadata associated with a `Gamepad`.
-#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
-#[reflect(Debug, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Debug, Default, PartialEq)]
 #[cfg_attr(
     feature = "serialize",
     derive(serde::Serialize, serde::Deserialize),
     reflect(Serialize, Deserialize)
 )]
 pub struct GamepadInfo {
+    /// The name of the gamepad, as reported by the OS.
     pub name: String,
+    /// The USB vendor ID of the gamepad, if available.
+    pub vendor_id: Option<u16>,
+    /// The product ID of the gamepad, as assigned by the vendor, if available.
+    pub product_id: Option<u16>,
+    /// The power supply state of the gamepad when it connected.
+    ///
+    /// Changes are reported with [`GamepadPowerEvent`]s and kept up to date in [`Gamepads`].
+    pub power_info: GamepadPowerInfo,
+    /// The capabilities of the gamepad, as far as the input backend can determine them.
+    pub capabilities: InputDeviceCapabilities,
+}
+
+/// The power supply state of a [`Gamepad`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Default, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadPowerInfo {
+    /// The power supply state could not be determined.
+    #[default]
+    Unknown,
+    /// The gamepad has no battery.
+    Wired,
+    /// The gamepad is running on its battery, with the given charge in percent.
+    Discharging(u8),
+    /// The battery of the gamepad is charging, with the given charge in percent.
+    Charging(u8),
+    /// The battery of the gamepad is fully charged.
+    Charged,
+}
+
+impl GamepadPowerInfo {
+    /// Returns the battery charge in percent, if the gamepad has a battery and it is known.
+    pub fn battery_level(&self) -> Option<u8> {
+        match self {
+            GamepadPowerInfo::Discharging(level) | GamepadPowerInfo::Charging(level) => {
+                Some(*level)
+            }
+            GamepadPowerInfo::Charged => Some(100),
+            GamepadPowerInfo::Unknown | GamepadPowerInfo::Wired => None,
+        }
+    }
+
+    /// Returns `true` if the battery of the gamepad is currently charging.
+    ///
+    /// This is `false` for [`GamepadPowerInfo::Charged`] and [`GamepadPowerInfo::Wired`] gamepads,
+    /// since their battery is either full or missing.
+    pub fn is_charging(&self) -> bool {
+        matches!(self, GamepadPowerInfo::Charging(_))
+    }
 }
 
 /// A collection of connected [`Gamepad`]s.
@@ -133,6 +205,16 @@ impl Gamepads {
         self.gamepads.get(&gamepad).map(|g| g.name.as_str())
     }
 
+    /// Returns the [`GamepadInfo`] of the `gamepad`, if it is connected.
+    pub fn info(&self, gamepad: Gamepad) -> Option<&GamepadInfo> {
+        self.gamepads.get(&gamepad)
+    }
+
+    /// Returns the current [`GamepadPowerInfo`] of the `gamepad`, if it is connected.
+    pub fn power_info(&self, gamepad: Gamepad) -> Option<GamepadPowerInfo> {
+        self.gamepads.get(&gamepad).map(|g| g.power_info)
+    }
+
     /// Registers the `gamepad`, marking it as connected.
     fn register(&mut self, gamepad: Gamepad, info: GamepadInfo) {
         self.gamepads.insert(gamepad, info);
@@ -144,6 +226,97 @@ impl Gamepads {
     }
 }
 
+/// The player indicator of a [`Gamepad`].
+///
+/// ## Usage
+///
+/// Controllers show which player they belong to in different ways: Xbox and Switch controllers
+/// light up player number LEDs, while DualShock 4 and DualSense controllers have a colored lightbar.
+/// Each field is only applied on controllers that support it and is ignored otherwise.
+///
+/// ## Updating
+///
+/// Indicators are assigned through the [`GamepadIndicators`] resource and sent to the backend
+/// as [`GamepadIndicatorRequest`]s by the [`gamepad_indicator_system`]. Not every backend can
+/// apply them, see [`GamepadIndicatorRequest`] for details.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Default, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadIndicator {
+    /// The player number shown by controllers with player lights, starting at `1`.
+    pub player: Option<u8>,
+    /// The sRGB color of the lightbar on controllers that have one.
+    pub color: Option<[u8; 3]>,
+}
+
+impl GamepadIndicator {
+    /// Creates a [`GamepadIndicator`] showing the given `player` number and lightbar `color`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_input::gamepad::GamepadIndicator;
+    /// #
+    /// // Player one is red.
+    /// let indicator = GamepadIndicator::new(1, [255, 0, 0]);
+    /// ```
+    pub fn new(player: u8, color: [u8; 3]) -> Self {
+        Self {
+            player: Some(player),
+            color: Some(color),
+        }
+    }
+}
+
+/// The player assignments of the connected [`Gamepad`]s.
+///
+/// ## Usage
+///
+/// Assign a [`GamepadIndicator`] to a gamepad to keep its lights in sync with the on-screen
+/// color and number of the player using it. Assignments are kept when a gamepad disconnects,
+/// and re-applied when it connects again with the same `ID`.
+///
+/// ## Updating
+///
+/// Whenever this resource changes or a gamepad connects, the [`gamepad_indicator_system`]
+/// sends a [`GamepadIndicatorRequest`] for each affected connected gamepad.
+#[derive(Resource, Default, Debug)]
+pub struct GamepadIndicators {
+    indicators: HashMap<Gamepad, GamepadIndicator>,
+}
+
+impl GamepadIndicators {
+    /// Assigns the `indicator` to the `gamepad`, returning the previous one if any.
+    pub fn set(
+        &mut self,
+        gamepad: Gamepad,
+        indicator: GamepadIndicator,
+    ) -> Option<GamepadIndicator> {
+        self.indicators.insert(gamepad, indicator)
+    }
+
+    /// Returns the indicator assigned to the `gamepad`.
+    pub fn get(&self, gamepad: Gamepad) -> Option<&GamepadIndicator> {
+        self.indicators.get(&gamepad)
+    }
+
+    /// Removes the indicator assigned to the `gamepad`, returning it if any.
+    pub fn remove(&mut self, gamepad: Gamepad) -> Option<GamepadIndicator> {
+        self.indicators.remove(&gamepad)
+    }
+
+    /// Returns an iterator over the assigned indicators in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Gamepad, &GamepadIndicator)> + '_ {
+        self.indicators
+            .iter()
+            .map(|(gamepad, indicator)| (*gamepad, indicator))
+    }
+}
+
 /// A type of a [`GamepadButton`].
 ///
 /// ## Usage
@@ -218,7 +391,7 @@ pub enum GamepadButtonType {
 ///
 /// The gamepad button resources are updated inside of the [`gamepad_button_event_system`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect, FromReflect)]
-#[reflect(Debug, Hash, PartialEq)]
+#[reflect(Debug, Hash, PartialEq, FromReflect)]
 #[cfg_attr(
     feature = "serialize",
     derive(serde::Serialize, serde::Deserialize),
@@ -296,7 +469,7 @@ pub enum GamepadAxisType {
 ///
 /// The gamepad axes resources are updated inside of the [`gamepad_axis_event_system`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect, FromReflect)]
-#[reflect(Debug, Hash, PartialEq)]
+#[reflect(Debug, Hash, PartialEq, FromReflect)]
 #[cfg_attr(
     feature = "serialize",
     derive(serde::Serialize, serde::Deserialize),
@@ -327,19 +500,117 @@ impl GamepadAxis {
     }
 }
 
+/// A type of analog stick on a gamepad, made up of two [`GamepadAxisType`]s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadStickType {
+    /// The left stick, made up of [`GamepadAxisType::LeftStickX`] and [`GamepadAxisType::LeftStickY`].
+    LeftStick,
+    /// The right stick, made up of [`GamepadAxisType::RightStickX`] and [`GamepadAxisType::RightStickY`].
+    RightStick,
+}
+
+impl GamepadStickType {
+    /// Returns the types of the horizontal and vertical axes of the stick.
+    pub fn axis_types(self) -> (GamepadAxisType, GamepadAxisType) {
+        match self {
+            GamepadStickType::LeftStick => {
+                (GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY)
+            }
+            GamepadStickType::RightStick => {
+                (GamepadAxisType::RightStickX, GamepadAxisType::RightStickY)
+            }
+        }
+    }
+}
+
+/// An analog stick of a [`Gamepad`].
+///
+/// ## Usage
+///
+/// It is used to read the position of a stick through [`GamepadSticks`] and to configure
+/// its [`StickSettings`] in the [`GamepadSettings`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect, FromReflect)]
+#[reflect(Debug, Hash, PartialEq, FromReflect)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadStick {
+    /// The gamepad on which the stick is located on.
+    pub gamepad: Gamepad,
+    /// The type of the stick.
+    pub stick_type: GamepadStickType,
+}
+
+impl GamepadStick {
+    /// Creates a new [`GamepadStick`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_input::gamepad::{GamepadStick, GamepadStickType, Gamepad};
+    /// #
+    /// let gamepad_stick = GamepadStick::new(
+    ///     Gamepad::new(1),
+    ///     GamepadStickType::LeftStick,
+    /// );
+    /// ```
+    pub fn new(gamepad: Gamepad, stick_type: GamepadStickType) -> Self {
+        Self {
+            gamepad,
+            stick_type,
+        }
+    }
+
+    /// Returns the horizontal and vertical [`GamepadAxis`] of the stick.
+    pub fn axes(self) -> (GamepadAxis, GamepadAxis) {
+        let (x, y) = self.stick_type.axis_types();
+        (
+            GamepadAxis::new(self.gamepad, x),
+            GamepadAxis::new(self.gamepad, y),
+        )
+    }
+}
+
 /// Settings for all [`Gamepad`]s.
 ///
 /// ## Usage
 ///
-/// It is used to create a `bevy` resource that stores the settings of every [`GamepadButton`] and
-/// [`GamepadAxis`]. If no user defined [`ButtonSettings`], [`AxisSettings`], or [`ButtonAxisSettings`]
-/// are defined, the default settings of each are used as a fallback accordingly.
+/// It is used to create a `bevy` resource that stores the settings of every [`GamepadButton`],
+/// [`GamepadAxis`] and [`GamepadStick`]. If no user defined [`ButtonSettings`], [`AxisSettings`],
+/// [`ButtonAxisSettings`], or [`StickSettings`] are defined, the default settings of each are used
+/// as a fallback accordingly.
 ///
 /// ## Note
 ///
 /// The [`GamepadSettings`] are used inside of `bevy_gilrs` to determine when raw gamepad events from `gilrs`,
 /// should register as a [`GamepadEvent`]. Events that don't meet the change thresholds defined in [`GamepadSettings`]
 /// will not register. To modify these settings, mutate the corresponding resource.
+///
+/// ## Loading from an asset
+///
+/// The settings are reflected and all of their types are registered by the
+/// [`InputPlugin`](crate::InputPlugin), so they can be loaded from a settings asset with
+/// `bevy_asset`'s `SettingsAssetPlugin`. Changes to the asset are applied live, which allows
+/// tweaking deadzones during playtests without recompiling:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::asset::SettingsAssetPlugin;
+/// # use bevy::input::{gamepad::GamepadSettings, InputSystem};
+/// # fn build(app: &mut App) {
+/// app.add_plugin(
+///     SettingsAssetPlugin::<GamepadSettings>::new("gamepad.settings.ron").in_set(InputSystem),
+/// );
+/// # }
+/// ```
 #[derive(Resource, Default, Debug, Reflect, FromReflect)]
 #[reflect(Debug, Default)]
 pub struct GamepadSettings {
@@ -349,12 +620,16 @@ pub struct GamepadSettings {
     pub default_axis_settings: AxisSettings,
     /// The default button axis settings.
     pub default_button_axis_settings: ButtonAxisSettings,
+    /// The default stick settings.
+    pub default_stick_settings: StickSettings,
     /// The user defined button settings.
     pub button_settings: HashMap<GamepadButton, ButtonSettings>,
     /// The user defined axis settings.
     pub axis_settings: HashMap<GamepadAxis, AxisSettings>,
     /// The user defined button axis settings.
     pub button_axis_settings: HashMap<GamepadButton, ButtonAxisSettings>,
+    /// The user defined stick settings.
+    pub stick_settings: HashMap<GamepadStick, StickSettings>,
 }
 
 impl GamepadSettings {
@@ -414,6 +689,25 @@ impl GamepadSettings {
             .get(&button)
             .unwrap_or(&self.default_button_axis_settings)
     }
+
+    /// Returns the [`StickSettings`] of the `stick`.
+    ///
+    /// If no user defined [`StickSettings`] are specified the default [`StickSettings`] get returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_input::gamepad::{GamepadSettings, GamepadStick, Gamepad, GamepadStickType};
+    /// #
+    /// # let settings = GamepadSettings::default();
+    /// let stick = GamepadStick::new(Gamepad::new(1), GamepadStickType::LeftStick);
+    /// let stick_settings = settings.get_stick_settings(stick);
+    /// ```
+    pub fn get_stick_settings(&self, stick: GamepadStick) -> &StickSettings {
+        self.stick_settings
+            .get(&stick)
+            .unwrap_or(&self.default_stick_settings)
+    }
 }
 
 /// Manages settings for gamepad buttons.
@@ -977,6 +1271,439 @@ impl ButtonAxisSettings {
     }
 }
 
+/// Settings for a [`GamepadStick`].
+///
+/// It is used inside of the [`GamepadSettings`] to define the radial dead zone and the response
+/// curve of a stick, which [`GamepadSticks`] applies to the combined position of its two axes.
+///
+/// ## Logic
+///
+/// - Positions whose length is lower than or equal to `deadzone` are rounded to zero.
+/// - Positions whose length is higher than or equal to `livezone` are scaled to a length of 1.0.
+/// - Otherwise, the length is rescaled from `deadzone..livezone` to `0.0..1.0` and raised
+///   to the power of `exponent`.
+///
+/// The direction of the position is always preserved.
+/// Unlike the per-axis [`AxisSettings`], this doesn't distort diagonal positions.
+#[derive(Debug, Clone, Reflect, FromReflect, PartialEq)]
+#[reflect(Debug, Default)]
+pub struct StickSettings {
+    deadzone: f32,
+    livezone: f32,
+    exponent: f32,
+}
+
+impl Default for StickSettings {
+    fn default() -> Self {
+        StickSettings {
+            deadzone: 0.0,
+            livezone: 1.0,
+            exponent: 1.0,
+        }
+    }
+}
+
+impl StickSettings {
+    /// Creates a new [`StickSettings`] instance.
+    ///
+    /// # Parameters
+    ///
+    /// + `deadzone` is the length below which the position is rounded to zero.
+    /// + `livezone` is the length above which the position is scaled to a length of 1.0.
+    /// + `exponent` is the exponent of the response curve, `1.0` being linear.
+    ///
+    /// Restrictions:
+    /// + `0.0 <= ``deadzone`` < ``livezone`` <= 1.0`
+    /// + `0.0 < ``exponent```
+    ///
+    /// # Errors
+    ///
+    /// If the restrictions are not met, returns one of
+    /// `StickSettingsError::DeadZoneOutOfRange`,
+    /// `StickSettingsError::LiveZoneOutOfRange`,
+    /// `StickSettingsError::DeadZoneGreaterThanOrEqualToLiveZone`, or
+    /// `StickSettingsError::ExponentOutOfRange`.
+    pub fn new(
+        deadzone: f32,
+        livezone: f32,
+        exponent: f32,
+    ) -> Result<StickSettings, StickSettingsError> {
+        if !(0.0..=1.0).contains(&deadzone) {
+            Err(StickSettingsError::DeadZoneOutOfRange(deadzone))
+        } else if !(0.0..=1.0).contains(&livezone) {
+            Err(StickSettingsError::LiveZoneOutOfRange(livezone))
+        } else if deadzone >= livezone {
+            Err(StickSettingsError::DeadZoneGreaterThanOrEqualToLiveZone { deadzone, livezone })
+        } else if exponent.is_nan() || exponent <= 0.0 {
+            Err(StickSettingsError::ExponentOutOfRange(exponent))
+        } else {
+            Ok(StickSettings {
+                deadzone,
+                livezone,
+                exponent,
+            })
+        }
+    }
+
+    /// Get the length below which the position is rounded to zero.
+    pub fn deadzone(&self) -> f32 {
+        self.deadzone
+    }
+
+    /// Get the length above which the position is scaled to a length of 1.0.
+    pub fn livezone(&self) -> f32 {
+        self.livezone
+    }
+
+    /// Get the exponent of the response curve.
+    pub fn exponent(&self) -> f32 {
+        self.exponent
+    }
+
+    /// Applies the dead zone and response curve to the raw `position` of a stick.
+    pub fn filter(&self, position: Vec2) -> Vec2 {
+        self.filter_stages(position).curve
+    }
+
+    /// Applies the dead zone and response curve to the raw `position` of a stick, returning the
+    /// position after every stage.
+    pub fn filter_stages(&self, position: Vec2) -> StickFilterStages {
+        let length = position.length();
+        if length <= self.deadzone {
+            return StickFilterStages {
+                raw: position,
+                ..Default::default()
+            };
+        }
+        let rescaled = self.rescale(length);
+        StickFilterStages {
+            raw: position,
+            deadzone: position * (rescaled / length),
+            curve: position * (rescaled.powf(self.exponent) / length),
+        }
+    }
+
+    /// Returns the length of a filtered position for the `length` of a raw position, which can be
+    /// sampled from `0.0` to `1.0` to draw the response curve.
+    pub fn response(&self, length: f32) -> f32 {
+        if length <= self.deadzone {
+            return 0.0;
+        }
+        self.rescale(length).powf(self.exponent)
+    }
+
+    /// Rescales the `length` from `deadzone..livezone` to `0.0..1.0`.
+    fn rescale(&self, length: f32) -> f32 {
+        (length.min(self.livezone) - self.deadzone) / (self.livezone - self.deadzone)
+    }
+}
+
+/// The position of a stick after every stage of [`StickSettings::filter_stages`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StickFilterStages {
+    /// The position before any processing, as returned by [`GamepadSticks::raw`].
+    pub raw: Vec2,
+    /// The position after the dead zone and live zone were applied.
+    pub deadzone: Vec2,
+    /// The position after the response curve was applied, as returned by [`GamepadSticks::get`].
+    pub curve: Vec2,
+}
+
+/// Records the [`StickFilterStages`] of the sticks of all connected gamepads every frame, so
+/// settings screens can visualize the dead zone and response curve while the user moves a stick.
+///
+/// This resource isn't added by default, insert it to enable the recording.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_input::gamepad::{GamepadStickDebug, GamepadStickType, Gamepads};
+/// # use bevy_ecs::system::Res;
+/// fn draw_stick_response(gamepads: Res<Gamepads>, debug: Res<GamepadStickDebug>) {
+///     for gamepad in gamepads.iter() {
+///         if let Some(stages) = debug.get(gamepad, GamepadStickType::LeftStick) {
+///             // Draw `stages.raw`, `stages.deadzone` and `stages.curve`.
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(draw_stick_response);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct GamepadStickDebug {
+    sticks: HashMap<GamepadStick, StickFilterStages>,
+}
+
+impl GamepadStickDebug {
+    /// Returns the stages recorded for the stick of the `gamepad` in the last update, or `None` if
+    /// the gamepad isn't connected.
+    pub fn get(&self, gamepad: Gamepad, stick_type: GamepadStickType) -> Option<StickFilterStages> {
+        self.sticks
+            .get(&GamepadStick::new(gamepad, stick_type))
+            .copied()
+    }
+
+    /// Returns an iterator over the stages recorded for all sticks in the last update.
+    pub fn iter(&self) -> impl Iterator<Item = (GamepadStick, StickFilterStages)> + '_ {
+        self.sticks.iter().map(|(&stick, &stages)| (stick, stages))
+    }
+}
+
+/// Records the [`StickFilterStages`] of all connected gamepads in the [`GamepadStickDebug`]
+/// resource, if it exists.
+pub fn gamepad_stick_debug_system(
+    mut debug: ResMut<GamepadStickDebug>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    settings: Res<GamepadSettings>,
+) {
+    debug.sticks.clear();
+    for gamepad in gamepads.iter() {
+        for stick_type in [GamepadStickType::LeftStick, GamepadStickType::RightStick] {
+            let stick = GamepadStick::new(gamepad, stick_type);
+            let (x, y) = stick.axes();
+            let position = Vec2::new(
+                axes.get(x).unwrap_or_default(),
+                axes.get(y).unwrap_or_default(),
+            );
+            let stages = settings.get_stick_settings(stick).filter_stages(position);
+            debug.sticks.insert(stick, stages);
+        }
+    }
+}
+
+/// A [`SystemParam`] that reads the positions of the [`GamepadStick`]s of all gamepads as [`Vec2`]s,
+/// with the [`StickSettings`] of the [`GamepadSettings`] applied.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_input::gamepad::{GamepadSticks, GamepadStickType, Gamepads};
+/// # use bevy_ecs::system::Res;
+/// fn move_players(gamepads: Res<Gamepads>, sticks: GamepadSticks) {
+///     for gamepad in gamepads.iter() {
+///         let direction = sticks.left(gamepad);
+///         // Move the player controlled by `gamepad` in `direction`.
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(move_players);
+/// ```
+#[derive(SystemParam)]
+pub struct GamepadSticks<'w> {
+    axes: Res<'w, Axis<GamepadAxis>>,
+    settings: Res<'w, GamepadSettings>,
+}
+
+impl<'w> GamepadSticks<'w> {
+    /// Returns the position of the `stick` with its [`StickSettings`] applied.
+    ///
+    /// Returns `None` if neither axis of the stick has a value, e.g. if the gamepad isn't connected.
+    pub fn get(&self, stick: GamepadStick) -> Option<Vec2> {
+        self.raw(stick)
+            .map(|position| self.settings.get_stick_settings(stick).filter(position))
+    }
+
+    /// Returns the position of the `stick` without applying its [`StickSettings`].
+    ///
+    /// Returns `None` if neither axis of the stick has a value, e.g. if the gamepad isn't connected.
+    pub fn raw(&self, stick: GamepadStick) -> Option<Vec2> {
+        let (x, y) = stick.axes();
+        match (self.axes.get(x), self.axes.get(y)) {
+            (None, None) => None,
+            (x, y) => Some(Vec2::new(x.unwrap_or_default(), y.unwrap_or_default())),
+        }
+    }
+
+    /// Returns the position of the left stick of the `gamepad` with its [`StickSettings`] applied,
+    /// or [`Vec2::ZERO`] if it has no value.
+    pub fn left(&self, gamepad: Gamepad) -> Vec2 {
+        self.get(GamepadStick::new(gamepad, GamepadStickType::LeftStick))
+            .unwrap_or_default()
+    }
+
+    /// Returns the position of the right stick of the `gamepad` with its [`StickSettings`] applied,
+    /// or [`Vec2::ZERO`] if it has no value.
+    pub fn right(&self, gamepad: Gamepad) -> Vec2 {
+        self.get(GamepadStick::new(gamepad, GamepadStickType::RightStick))
+            .unwrap_or_default()
+    }
+}
+
+/// The state of all buttons and axes of a [`Gamepad`] at a single point in time.
+///
+/// Snapshots are captured with [`GamepadSnapshots::capture`] and can be compared with
+/// [`GamepadSnapshot::diff`], which is useful for controller test screens and input remapping
+/// menus that need to know which input the user just used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadSnapshot {
+    gamepad: Gamepad,
+    pressed: HashSet<GamepadButtonType>,
+    buttons: HashMap<GamepadButtonType, f32>,
+    axes: HashMap<GamepadAxisType, f32>,
+}
+
+impl GamepadSnapshot {
+    /// Creates a snapshot of the `gamepad` from the given input resources.
+    pub fn new(
+        gamepad: Gamepad,
+        button_input: &Input<GamepadButton>,
+        button_axes: &Axis<GamepadButton>,
+        axes: &Axis<GamepadAxis>,
+    ) -> Self {
+        Self {
+            gamepad,
+            pressed: button_input
+                .get_pressed()
+                .filter(|button| button.gamepad == gamepad)
+                .map(|button| button.button_type)
+                .collect(),
+            buttons: button_axes
+                .devices()
+                .filter(|button| button.gamepad == gamepad)
+                .filter_map(|&button| Some((button.button_type, button_axes.get(button)?)))
+                .collect(),
+            axes: axes
+                .devices()
+                .filter(|axis| axis.gamepad == gamepad)
+                .filter_map(|&axis| Some((axis.axis_type, axes.get(axis)?)))
+                .collect(),
+        }
+    }
+
+    /// Returns the [`Gamepad`] this snapshot was captured from.
+    pub fn gamepad(&self) -> Gamepad {
+        self.gamepad
+    }
+
+    /// Returns `true` if the button was pressed when the snapshot was captured.
+    pub fn pressed(&self, button_type: GamepadButtonType) -> bool {
+        self.pressed.contains(&button_type)
+    }
+
+    /// Returns an iterator over the buttons that were pressed when the snapshot was captured.
+    pub fn get_pressed(&self) -> impl Iterator<Item = GamepadButtonType> + '_ {
+        self.pressed.iter().copied()
+    }
+
+    /// Returns the analog value of the button, if it had one when the snapshot was captured.
+    pub fn button_value(&self, button_type: GamepadButtonType) -> Option<f32> {
+        self.buttons.get(&button_type).copied()
+    }
+
+    /// Returns an iterator over the analog values of all buttons.
+    pub fn button_values(&self) -> impl Iterator<Item = (GamepadButtonType, f32)> + '_ {
+        self.buttons
+            .iter()
+            .map(|(&button_type, &value)| (button_type, value))
+    }
+
+    /// Returns the value of the axis, if it had one when the snapshot was captured.
+    pub fn axis_value(&self, axis_type: GamepadAxisType) -> Option<f32> {
+        self.axes.get(&axis_type).copied()
+    }
+
+    /// Returns an iterator over the values of all axes.
+    pub fn axis_values(&self) -> impl Iterator<Item = (GamepadAxisType, f32)> + '_ {
+        self.axes
+            .iter()
+            .map(|(&axis_type, &value)| (axis_type, value))
+    }
+
+    /// Returns the changes from the `previous` snapshot to this one.
+    ///
+    /// Buttons and axes without a value in one of the snapshots are treated as `0.0`.
+    pub fn diff(&self, previous: &GamepadSnapshot) -> GamepadSnapshotDiff {
+        fn changed<T: Copy + Eq + std::hash::Hash>(
+            current: &HashMap<T, f32>,
+            previous: &HashMap<T, f32>,
+        ) -> Vec<(T, f32)> {
+            let value = |values: &HashMap<T, f32>, key| values.get(&key).copied().unwrap_or(0.0);
+            current
+                .keys()
+                .chain(previous.keys().filter(|key| !current.contains_key(key)))
+                .filter(|&&key| value(current, key) != value(previous, key))
+                .map(|&key| (key, value(current, key)))
+                .collect()
+        }
+
+        GamepadSnapshotDiff {
+            just_pressed: self
+                .pressed
+                .difference(&previous.pressed)
+                .copied()
+                .collect(),
+            just_released: previous
+                .pressed
+                .difference(&self.pressed)
+                .copied()
+                .collect(),
+            buttons: changed(&self.buttons, &previous.buttons),
+            axes: changed(&self.axes, &previous.axes),
+        }
+    }
+}
+
+/// The changes between two [`GamepadSnapshot`]s, see [`GamepadSnapshot::diff`].
+///
+/// The entries of each list are in no particular order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamepadSnapshotDiff {
+    /// The buttons that are pressed now but weren't before.
+    pub just_pressed: Vec<GamepadButtonType>,
+    /// The buttons that were pressed before but aren't now.
+    pub just_released: Vec<GamepadButtonType>,
+    /// The buttons whose analog value changed, with their new value.
+    pub buttons: Vec<(GamepadButtonType, f32)>,
+    /// The axes whose value changed, with their new value.
+    pub axes: Vec<(GamepadAxisType, f32)>,
+}
+
+impl GamepadSnapshotDiff {
+    /// Returns `true` if nothing changed between the two snapshots.
+    pub fn is_empty(&self) -> bool {
+        self.just_pressed.is_empty()
+            && self.just_released.is_empty()
+            && self.buttons.is_empty()
+            && self.axes.is_empty()
+    }
+}
+
+/// A [`SystemParam`] that captures [`GamepadSnapshot`]s of the current gamepad input.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::gamepad::{GamepadSnapshot, GamepadSnapshots, Gamepads};
+/// fn show_changed_inputs(
+///     gamepads: Res<Gamepads>,
+///     snapshots: GamepadSnapshots,
+///     mut previous: Local<Vec<GamepadSnapshot>>,
+/// ) {
+///     let current: Vec<_> = gamepads.iter().map(|gamepad| snapshots.capture(gamepad)).collect();
+///     for snapshot in &current {
+///         if let Some(old) = previous.iter().find(|old| old.gamepad() == snapshot.gamepad()) {
+///             let diff = snapshot.diff(old);
+///             if !diff.is_empty() {
+///                 println!("{:?}: {diff:?}", snapshot.gamepad());
+///             }
+///         }
+///     }
+///     *previous = current;
+/// }
+/// # bevy_ecs::system::assert_is_system(show_changed_inputs);
+/// ```
+#[derive(SystemParam)]
+pub struct GamepadSnapshots<'w> {
+    button_input: Res<'w, Input<GamepadButton>>,
+    button_axes: Res<'w, Axis<GamepadButton>>,
+    axes: Res<'w, Axis<GamepadAxis>>,
+}
+
+impl<'w> GamepadSnapshots<'w> {
+    /// Captures the current state of all buttons and axes of the `gamepad`.
+    pub fn capture(&self, gamepad: Gamepad) -> GamepadSnapshot {
+        GamepadSnapshot::new(gamepad, &self.button_input, &self.button_axes, &self.axes)
+    }
+}
+
 /// Handles [`GamepadConnectionEvent`]s and updates gamepad resources.
 ///
 /// Updates the [`Gamepads`] resource and resets and/or initializes
@@ -1116,15 +1843,55 @@ impl GamepadButtonChangedEvent {
     }
 }
 
+/// A change of the [`GamepadPowerInfo`] of a [`Gamepad`], such as its battery running low
+/// or it being plugged in for charging.
+#[derive(Debug, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadPowerEvent {
+    /// The gamepad whose power supply state changed.
+    pub gamepad: Gamepad,
+    /// The new power supply state of the gamepad.
+    pub power_info: GamepadPowerInfo,
+}
+
+impl GamepadPowerEvent {
+    /// Creates a [`GamepadPowerEvent`] reporting the new `power_info` of the `gamepad`.
+    pub fn new(gamepad: Gamepad, power_info: GamepadPowerInfo) -> Self {
+        Self {
+            gamepad,
+            power_info,
+        }
+    }
+}
+
+/// Keeps the [`GamepadPowerInfo`] stored in [`Gamepads`] up to date with the latest [`GamepadPowerEvent`]s.
+pub fn gamepad_power_system(
+    mut gamepads: ResMut<Gamepads>,
+    mut power_events: EventReader<GamepadPowerEvent>,
+) {
+    for power_event in power_events.iter() {
+        if let Some(info) = gamepads.gamepads.get_mut(&power_event.gamepad) {
+            info.power_info = power_event.power_info;
+        }
+    }
+}
+
 /// Uses [`GamepadAxisChangedEvent`]s to update the relevant `Input` and `Axis` values.
 pub fn gamepad_axis_event_system(
     mut gamepad_axis: ResMut<Axis<GamepadAxis>>,
     mut axis_events: EventReader<GamepadAxisChangedEvent>,
+    processing: Res<AxisProcessing<GamepadAxis>>,
 ) {
     for axis_event in axis_events.iter() {
         let axis = GamepadAxis::new(axis_event.gamepad, axis_event.axis_type);
-        gamepad_axis.set(axis, axis_event.value);
+        gamepad_axis.set_processed(axis, axis_event.value, &processing);
     }
+    gamepad_axis.smooth(&processing);
 }
 
 /// Uses [`GamepadButtonChangedEvent`]s to update the relevant `Input` and `Axis` values.
@@ -1133,6 +1900,7 @@ pub fn gamepad_button_event_system(
     mut button_input: ResMut<Input<GamepadButton>>,
     mut button_axis: ResMut<Axis<GamepadButton>>,
     settings: Res<GamepadSettings>,
+    processing: Res<AxisProcessing<GamepadButton>>,
 ) {
     for button_event in button_events.iter() {
         let button = GamepadButton::new(button_event.gamepad, button_event.button_type);
@@ -1147,8 +1915,9 @@ pub fn gamepad_button_event_system(
             button_input.press(button);
         };
 
-        button_axis.set(button, value);
+        button_axis.set_processed(button, value, &processing);
     }
+    button_axis.smooth(&processing);
 }
 
 /// A gamepad event.
@@ -1167,6 +1936,7 @@ pub enum GamepadEvent {
     Connection(GamepadConnectionEvent),
     Button(GamepadButtonChangedEvent),
     Axis(GamepadAxisChangedEvent),
+    Power(GamepadPowerEvent),
 }
 
 impl From<GamepadConnectionEvent> for GamepadEvent {
@@ -1187,12 +1957,19 @@ impl From<GamepadAxisChangedEvent> for GamepadEvent {
     }
 }
 
+impl From<GamepadPowerEvent> for GamepadEvent {
+    fn from(value: GamepadPowerEvent) -> Self {
+        Self::Power(value)
+    }
+}
+
 /// Splits the [`GamepadEvent`] event stream into it's component events.
 pub fn gamepad_event_system(
     mut gamepad_events: EventReader<GamepadEvent>,
     mut connection_events: EventWriter<GamepadConnectionEvent>,
     mut button_events: EventWriter<GamepadButtonChangedEvent>,
     mut axis_events: EventWriter<GamepadAxisChangedEvent>,
+    mut power_events: EventWriter<GamepadPowerEvent>,
     mut button_input: ResMut<Input<GamepadButton>>,
 ) {
     button_input.bypass_change_detection().clear();
@@ -1203,6 +1980,69 @@ pub fn gamepad_event_system(
             }
             GamepadEvent::Button(button_event) => button_events.send(button_event.clone()),
             GamepadEvent::Axis(axis_event) => axis_events.send(axis_event.clone()),
+            GamepadEvent::Power(power_event) => power_events.send(power_event.clone()),
+        }
+    }
+}
+
+/// A request to show the given [`GamepadIndicator`] on a [`Gamepad`].
+///
+/// ## Usage
+///
+/// These are sent by the [`gamepad_indicator_system`] and consumed by the gamepad backend,
+/// which applies them on controllers that support player lights or a lightbar.
+///
+/// ## Note
+///
+/// The `gilrs` backend used by `bevy_gilrs` cannot control player lights or lightbars yet,
+/// so it drops these requests. Custom backends can read them to drive the hardware themselves.
+#[derive(Debug, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadIndicatorRequest {
+    /// The gamepad whose indicator should change.
+    pub gamepad: Gamepad,
+    /// The indicator to show on the gamepad.
+    pub indicator: GamepadIndicator,
+}
+
+impl GamepadIndicatorRequest {
+    /// Creates a [`GamepadIndicatorRequest`] to show the `indicator` on the `gamepad`.
+    pub fn new(gamepad: Gamepad, indicator: GamepadIndicator) -> Self {
+        Self { gamepad, indicator }
+    }
+}
+
+/// Sends a [`GamepadIndicatorRequest`] for every connected gamepad whose assignment in
+/// [`GamepadIndicators`] changed, and for every gamepad that connected this frame.
+pub fn gamepad_indicator_system(
+    indicators: Res<GamepadIndicators>,
+    gamepads: Res<Gamepads>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    mut requests: EventWriter<GamepadIndicatorRequest>,
+) {
+    if indicators.is_changed() {
+        connection_events.clear();
+        for (gamepad, indicator) in indicators.iter() {
+            if gamepads.contains(gamepad) {
+                requests.send(GamepadIndicatorRequest::new(gamepad, *indicator));
+            }
+        }
+    } else {
+        for connection_event in connection_events.iter() {
+            if !connection_event.connected() {
+                continue;
+            }
+            if let Some(indicator) = indicators.get(connection_event.gamepad) {
+                requests.send(GamepadIndicatorRequest::new(
+                    connection_event.gamepad,
+                    *indicator,
+                ));
+            }
         }
     }
 }
@@ -1244,7 +2084,47 @@ const ALL_AXIS_TYPES: [GamepadAxisType; 6] = [
 mod tests {
     use crate::gamepad::{AxisSettingsError, ButtonSettingsError};
 
-    use super::{AxisSettings, ButtonAxisSettings, ButtonSettings};
+    use super::{
+        AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad, GamepadButton,
+        GamepadButtonType, GamepadSettings,
+    };
+    use crate::InputPlugin;
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_reflect::{
+        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        FromReflect,
+    };
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn gamepad_settings_reflect_serialization() {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        let type_registry = app.world.resource::<AppTypeRegistry>().read();
+
+        let button = GamepadButton::new(Gamepad::new(0), GamepadButtonType::South);
+        let mut settings = GamepadSettings::default();
+        settings
+            .button_settings
+            .insert(button, ButtonSettings::new(0.5, 0.25).unwrap());
+
+        let serializer = ReflectSerializer::new(&settings, &type_registry);
+        let ron = ron::to_string(&serializer).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let value = UntypedReflectDeserializer::new(&type_registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = GamepadSettings::from_reflect(&*value).unwrap();
+
+        assert_eq!(
+            deserialized.get_button_settings(button).press_threshold(),
+            0.5
+        );
+        assert_eq!(
+            deserialized.get_button_settings(button).release_threshold(),
+            0.25
+        );
+    }
 
     fn test_button_axis_settings_filter(
         settings: ButtonAxisSettings,
@@ -1574,4 +2454,388 @@ mod tests {
             axis_settings.try_set_livezone_upperbound(0.1)
         );
     }
+
+    #[test]
+    fn test_stick_settings_filter() {
+        use super::{StickSettings, StickSettingsError};
+        use bevy_math::Vec2;
+
+        let settings = StickSettings::default();
+        assert_eq!(
+            settings.filter(Vec2::new(0.5, -0.25)),
+            Vec2::new(0.5, -0.25)
+        );
+        assert_eq!(settings.filter(Vec2::new(3.0, 4.0)), Vec2::new(0.6, 0.8));
+
+        let settings = StickSettings::new(0.25, 0.75, 2.0).unwrap();
+        assert_eq!(settings.filter(Vec2::new(0.0, 0.25)), Vec2::ZERO);
+        assert_eq!(settings.filter(Vec2::new(-0.5, 0.0)), Vec2::new(-0.25, 0.0));
+        assert_eq!(settings.filter(Vec2::new(0.0, 1.0)), Vec2::new(0.0, 1.0));
+        assert_eq!(settings.filter(Vec2::new(0.6, 0.8)), Vec2::new(0.6, 0.8));
+
+        assert_eq!(
+            StickSettings::new(-0.1, 1.0, 1.0),
+            Err(StickSettingsError::DeadZoneOutOfRange(-0.1))
+        );
+        assert_eq!(
+            StickSettings::new(0.0, 1.5, 1.0),
+            Err(StickSettingsError::LiveZoneOutOfRange(1.5))
+        );
+        assert_eq!(
+            StickSettings::new(0.5, 0.5, 1.0),
+            Err(StickSettingsError::DeadZoneGreaterThanOrEqualToLiveZone {
+                deadzone: 0.5,
+                livezone: 0.5,
+            })
+        );
+        assert_eq!(
+            StickSettings::new(0.0, 1.0, 0.0),
+            Err(StickSettingsError::ExponentOutOfRange(0.0))
+        );
+    }
+
+    #[test]
+    fn gamepad_axis_smoothing_converges_without_events() {
+        use super::{
+            gamepad_axis_event_system, GamepadAxis, GamepadAxisChangedEvent, GamepadAxisType,
+        };
+        use crate::{Axis, AxisProcessing, AxisProcessor};
+
+        let gamepad = Gamepad::new(0);
+        let axis = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+        let mut processing = AxisProcessing::<GamepadAxis>::default();
+        processing.insert(axis, AxisProcessor::default().with_smoothing(0.5));
+
+        let mut app = App::new();
+        app.add_event::<GamepadAxisChangedEvent>()
+            .init_resource::<Axis<GamepadAxis>>()
+            .insert_resource(processing)
+            .add_system(gamepad_axis_event_system);
+        app.world.resource_mut::<Axis<GamepadAxis>>().set(axis, 0.0);
+
+        app.world.send_event(GamepadAxisChangedEvent::new(
+            gamepad,
+            GamepadAxisType::LeftStickX,
+            1.0,
+        ));
+        app.update();
+        assert_eq!(
+            app.world.resource::<Axis<GamepadAxis>>().get(axis),
+            Some(0.5)
+        );
+
+        // The axis keeps moving towards the last value in frames without events.
+        app.update();
+        assert_eq!(
+            app.world.resource::<Axis<GamepadAxis>>().get(axis),
+            Some(0.75)
+        );
+        for _ in 0..30 {
+            app.update();
+        }
+        let value = app.world.resource::<Axis<GamepadAxis>>().get(axis).unwrap();
+        assert!((1.0 - value).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gamepad_sticks_apply_stick_settings() {
+        use super::{
+            Gamepad, GamepadAxis, GamepadAxisType, GamepadSettings, GamepadStick, GamepadStickType,
+            GamepadSticks, StickSettings,
+        };
+        use crate::Axis;
+        use bevy_ecs::{prelude::*, system::SystemState};
+        use bevy_math::Vec2;
+
+        let gamepad = Gamepad::new(0);
+        let left = GamepadStick::new(gamepad, GamepadStickType::LeftStick);
+        let right = GamepadStick::new(gamepad, GamepadStickType::RightStick);
+
+        let mut settings = GamepadSettings::default();
+        settings
+            .stick_settings
+            .insert(left, StickSettings::new(0.5, 1.0, 1.0).unwrap());
+
+        let mut axes = Axis::<GamepadAxis>::default();
+        axes.set(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX), 0.75);
+        axes.set(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY), 0.0);
+        axes.set(
+            GamepadAxis::new(gamepad, GamepadAxisType::RightStickY),
+            0.25,
+        );
+
+        let mut world = World::new();
+        world.insert_resource(settings);
+        world.insert_resource(axes);
+
+        let mut state = SystemState::<GamepadSticks>::new(&mut world);
+        let sticks = state.get(&world);
+        assert_eq!(sticks.raw(left), Some(Vec2::new(0.75, 0.0)));
+        assert_eq!(sticks.get(left), Some(Vec2::new(0.5, 0.0)));
+        assert_eq!(sticks.left(gamepad), Vec2::new(0.5, 0.0));
+        assert_eq!(sticks.get(right), Some(Vec2::new(0.0, 0.25)));
+        assert_eq!(sticks.right(gamepad), Vec2::new(0.0, 0.25));
+        assert_eq!(
+            sticks.get(GamepadStick::new(
+                Gamepad::new(1),
+                GamepadStickType::LeftStick
+            )),
+            None
+        );
+        assert_eq!(sticks.left(Gamepad::new(1)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn gamepad_stick_debug_records_stages() {
+        use super::{
+            gamepad_stick_debug_system, Gamepad, GamepadAxis, GamepadAxisType, GamepadSettings,
+            GamepadStick, GamepadStickDebug, GamepadStickType, Gamepads, StickFilterStages,
+            StickSettings,
+        };
+        use crate::Axis;
+        use bevy_ecs::prelude::*;
+        use bevy_math::Vec2;
+
+        let gamepad = Gamepad::new(0);
+        let mut settings = GamepadSettings::default();
+        settings.stick_settings.insert(
+            GamepadStick::new(gamepad, GamepadStickType::LeftStick),
+            StickSettings::new(0.25, 0.75, 2.0).unwrap(),
+        );
+        let mut gamepads = Gamepads::default();
+        gamepads.register(gamepad, Default::default());
+        let mut axes = Axis::<GamepadAxis>::default();
+        axes.set(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX), -0.5);
+
+        let mut world = World::new();
+        world.insert_resource(settings);
+        world.insert_resource(gamepads);
+        world.insert_resource(axes);
+        world.init_resource::<GamepadStickDebug>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(gamepad_stick_debug_system);
+        schedule.run(&mut world);
+
+        let debug = world.resource::<GamepadStickDebug>();
+        assert_eq!(
+            debug.get(gamepad, GamepadStickType::LeftStick),
+            Some(StickFilterStages {
+                raw: Vec2::new(-0.5, 0.0),
+                deadzone: Vec2::new(-0.5, 0.0),
+                curve: Vec2::new(-0.25, 0.0),
+            })
+        );
+        assert_eq!(
+            debug.get(gamepad, GamepadStickType::RightStick),
+            Some(StickFilterStages::default())
+        );
+        assert_eq!(
+            debug.get(Gamepad::new(1), GamepadStickType::LeftStick),
+            None
+        );
+
+        let settings = StickSettings::new(0.25, 0.75, 2.0).unwrap();
+        assert_eq!(settings.response(0.1), 0.0);
+        assert_eq!(settings.response(0.5), 0.25);
+        assert_eq!(settings.response(1.0), 1.0);
+    }
+
+    #[test]
+    fn gamepad_indicator_requests() {
+        use super::{
+            gamepad_connection_system, gamepad_indicator_system, Gamepad, GamepadAxis,
+            GamepadButton, GamepadConnection, GamepadConnectionEvent, GamepadIndicator,
+            GamepadIndicatorRequest, GamepadIndicators, GamepadInfo, Gamepads,
+        };
+        use crate::{Axis, Input};
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.init_resource::<Events<GamepadConnectionEvent>>();
+        world.init_resource::<Events<GamepadIndicatorRequest>>();
+        world.init_resource::<Gamepads>();
+        world.init_resource::<GamepadIndicators>();
+        world.init_resource::<Axis<GamepadAxis>>();
+        world.init_resource::<Axis<GamepadButton>>();
+        world.init_resource::<Input<GamepadButton>>();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((
+            gamepad_connection_system,
+            gamepad_indicator_system.after(gamepad_connection_system),
+        ));
+
+        let gamepad = Gamepad::new(0);
+        let indicator = GamepadIndicator::new(1, [255, 0, 0]);
+        world
+            .resource_mut::<GamepadIndicators>()
+            .set(gamepad, indicator);
+        schedule.run(&mut world);
+
+        // The gamepad is not connected yet, so nothing is requested.
+        let mut reader = world
+            .resource::<Events<GamepadIndicatorRequest>>()
+            .get_reader();
+        assert_eq!(
+            reader
+                .iter(world.resource::<Events<GamepadIndicatorRequest>>())
+                .count(),
+            0
+        );
+
+        world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: String::from("Gamepad"),
+                ..Default::default()
+            }),
+        ));
+        schedule.run(&mut world);
+
+        let requests: Vec<_> = reader
+            .iter(world.resource::<Events<GamepadIndicatorRequest>>())
+            .cloned()
+            .collect();
+        assert_eq!(requests, [GamepadIndicatorRequest::new(gamepad, indicator)]);
+
+        // Nothing changed, so nothing is requested.
+        schedule.run(&mut world);
+        assert_eq!(
+            reader
+                .iter(world.resource::<Events<GamepadIndicatorRequest>>())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn gamepad_power_events() {
+        use super::{
+            gamepad_connection_system, gamepad_event_system, gamepad_power_system, Gamepad,
+            GamepadAxis, GamepadAxisChangedEvent, GamepadButton, GamepadButtonChangedEvent,
+            GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadInfo,
+            GamepadPowerEvent, GamepadPowerInfo, Gamepads,
+        };
+        use crate::{Axis, Input};
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.init_resource::<Events<GamepadEvent>>();
+        world.init_resource::<Events<GamepadConnectionEvent>>();
+        world.init_resource::<Events<GamepadButtonChangedEvent>>();
+        world.init_resource::<Events<GamepadAxisChangedEvent>>();
+        world.init_resource::<Events<GamepadPowerEvent>>();
+        world.init_resource::<Gamepads>();
+        world.init_resource::<Axis<GamepadAxis>>();
+        world.init_resource::<Axis<GamepadButton>>();
+        world.init_resource::<Input<GamepadButton>>();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems(
+            (
+                gamepad_event_system,
+                gamepad_connection_system,
+                gamepad_power_system,
+            )
+                .chain(),
+        );
+
+        let gamepad = Gamepad::new(0);
+        world.send_event(GamepadEvent::from(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: String::from("Gamepad"),
+                power_info: GamepadPowerInfo::Discharging(80),
+                ..Default::default()
+            }),
+        )));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<Gamepads>().power_info(gamepad),
+            Some(GamepadPowerInfo::Discharging(80))
+        );
+
+        let mut reader = world.resource::<Events<GamepadPowerEvent>>().get_reader();
+        world.send_event(GamepadEvent::from(GamepadPowerEvent::new(
+            gamepad,
+            GamepadPowerInfo::Charging(20),
+        )));
+        schedule.run(&mut world);
+
+        let power_events: Vec<_> = reader
+            .iter(world.resource::<Events<GamepadPowerEvent>>())
+            .cloned()
+            .collect();
+        assert_eq!(
+            power_events,
+            [GamepadPowerEvent::new(
+                gamepad,
+                GamepadPowerInfo::Charging(20)
+            )]
+        );
+        let power_info = world.resource::<Gamepads>().power_info(gamepad).unwrap();
+        assert_eq!(power_info, GamepadPowerInfo::Charging(20));
+        assert!(power_info.is_charging());
+        assert_eq!(power_info.battery_level(), Some(20));
+
+        // Power events of disconnected gamepads are ignored.
+        let disconnected = Gamepad::new(1);
+        world.send_event(GamepadEvent::from(GamepadPowerEvent::new(
+            disconnected,
+            GamepadPowerInfo::Wired,
+        )));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Gamepads>().power_info(disconnected), None);
+    }
+
+    #[test]
+    fn gamepad_snapshot_diff() {
+        use super::{
+            Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType,
+            GamepadSnapshot,
+        };
+        use crate::{Axis, Input};
+
+        let gamepad = Gamepad::new(0);
+        let south = GamepadButton::new(gamepad, GamepadButtonType::South);
+        let trigger = GamepadButton::new(gamepad, GamepadButtonType::RightTrigger2);
+        let left_x = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+
+        let mut button_input = Input::<GamepadButton>::default();
+        let mut button_axes = Axis::<GamepadButton>::default();
+        let mut axes = Axis::<GamepadAxis>::default();
+        button_input.press(south);
+        button_input.press(GamepadButton::new(
+            Gamepad::new(1),
+            GamepadButtonType::North,
+        ));
+        button_axes.set(trigger, 0.0);
+        axes.set(left_x, 0.0);
+
+        let previous = GamepadSnapshot::new(gamepad, &button_input, &button_axes, &axes);
+        assert!(previous.pressed(GamepadButtonType::South));
+        assert_eq!(previous.get_pressed().count(), 1);
+        assert_eq!(
+            previous.button_value(GamepadButtonType::RightTrigger2),
+            Some(0.0)
+        );
+        assert_eq!(previous.axis_value(GamepadAxisType::LeftStickY), None);
+
+        let unchanged = GamepadSnapshot::new(gamepad, &button_input, &button_axes, &axes);
+        assert!(unchanged.diff(&previous).is_empty());
+
+        button_input.release(south);
+        button_input.press(GamepadButton::new(gamepad, GamepadButtonType::East));
+        button_axes.set(trigger, 0.5);
+        axes.remove(left_x);
+        axes.set(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY), -1.0);
+
+        let current = GamepadSnapshot::new(gamepad, &button_input, &button_axes, &axes);
+        let diff = current.diff(&previous);
+        assert_eq!(diff.just_pressed, [GamepadButtonType::East]);
+        assert_eq!(diff.just_released, [GamepadButtonType::South]);
+        assert_eq!(diff.buttons, [(GamepadButtonType::RightTrigger2, 0.5)]);
+        // The removed axis was already 0.0, so only the new axis changed.
+        assert_eq!(diff.axes, [(GamepadAxisType::LeftStickY, -1.0)]);
+    }
 }
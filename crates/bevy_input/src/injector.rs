@@ -0,0 +1,270 @@
+use crate::{
+    gamepad::{
+        Gamepad, GamepadAxisChangedEvent, GamepadAxisType, GamepadButtonChangedEvent,
+        GamepadButtonType, GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadInfo,
+    },
+    keyboard::{KeyCode, KeyboardInput},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    ButtonState,
+};
+use bevy_ecs::{entity::Entity, event::EventWriter, system::SystemParam};
+use bevy_math::Vec2;
+
+/// A [`SystemParam`] that injects synthetic keyboard, mouse and gamepad events into the input
+/// event streams, e.g. for on-screen controls or tutorials that simulate input.
+///
+/// The events are sent to the same event streams the input backends use, so they update the
+/// [`Input`](crate::Input) and [`Axis`](crate::Axis) resources exactly like hardware input does.
+/// Systems using the injector should run before [`InputSystem`](crate::InputSystem) to have the
+/// input applied in the same frame, otherwise it is applied in the next one.
+///
+/// ## Usage
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::{prelude::*, InputInjector};
+/// fn on_screen_jump_button(mut injector: InputInjector) {
+///     // Act as if the space bar was pressed.
+///     injector.press_key(KeyCode::Space);
+/// }
+/// # bevy_ecs::system::assert_is_system(on_screen_jump_button);
+/// ```
+///
+/// Injected keyboard and mouse button events aren't tied to a window, so they use
+/// [`Entity::PLACEHOLDER`] as their window. Use [`InputInjector::send_keyboard_input`] and
+/// [`InputInjector::send_mouse_button_input`] to inject input into a specific window.
+///
+/// Virtual gamepads have to be connected with [`InputInjector::connect_gamepad`] before their
+/// input is registered. Use ids that don't collide with the ones assigned by the gamepad backend,
+/// like `Gamepad::new(usize::MAX)`.
+#[derive(SystemParam)]
+pub struct InputInjector<'w> {
+    keyboard_events: EventWriter<'w, KeyboardInput>,
+    mouse_button_events: EventWriter<'w, MouseButtonInput>,
+    mouse_motion_events: EventWriter<'w, MouseMotion>,
+    mouse_wheel_events: EventWriter<'w, MouseWheel>,
+    gamepad_events: EventWriter<'w, GamepadEvent>,
+}
+
+impl<'w> InputInjector<'w> {
+    /// Sends a [`KeyboardInput`] event.
+    pub fn send_keyboard_input(&mut self, event: KeyboardInput) {
+        self.keyboard_events.send(event);
+    }
+
+    /// Presses the key with the given [`KeyCode`].
+    ///
+    /// Injected keys aren't tied to a physical key, so the event uses a scan code of `0`. Use
+    /// [`InputInjector::send_keyboard_input`] to inject a specific scan code.
+    pub fn press_key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+    }
+
+    /// Releases the key with the given [`KeyCode`].
+    ///
+    /// See [`InputInjector::press_key`].
+    pub fn release_key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Released);
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        self.send_keyboard_input(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    /// Sends a [`MouseButtonInput`] event.
+    pub fn send_mouse_button_input(&mut self, event: MouseButtonInput) {
+        self.mouse_button_events.send(event);
+    }
+
+    /// Presses the given [`MouseButton`].
+    pub fn press_mouse_button(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Pressed);
+    }
+
+    /// Releases the given [`MouseButton`].
+    pub fn release_mouse_button(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Released);
+    }
+
+    fn send_mouse_button(&mut self, button: MouseButton, state: ButtonState) {
+        self.send_mouse_button_input(MouseButtonInput {
+            button,
+            state,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    /// Moves the mouse by `delta`, sending a [`MouseMotion`] event.
+    pub fn move_mouse(&mut self, delta: Vec2) {
+        self.mouse_motion_events.send(MouseMotion { delta });
+    }
+
+    /// Scrolls the mouse wheel, sending a [`MouseWheel`] event.
+    pub fn scroll_mouse(&mut self, unit: MouseScrollUnit, x: f32, y: f32) {
+        self.mouse_wheel_events.send(MouseWheel { unit, x, y });
+    }
+
+    /// Sends a [`GamepadEvent`].
+    pub fn send_gamepad_event(&mut self, event: impl Into<GamepadEvent>) {
+        self.gamepad_events.send(event.into());
+    }
+
+    /// Connects a virtual [`Gamepad`] with the given [`GamepadInfo`].
+    pub fn connect_gamepad(&mut self, gamepad: Gamepad, info: GamepadInfo) {
+        self.send_gamepad_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(info),
+        ));
+    }
+
+    /// Disconnects a virtual [`Gamepad`].
+    pub fn disconnect_gamepad(&mut self, gamepad: Gamepad) {
+        self.send_gamepad_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Disconnected,
+        ));
+    }
+
+    /// Presses a button of a [`Gamepad`] all the way.
+    pub fn press_gamepad_button(&mut self, gamepad: Gamepad, button_type: GamepadButtonType) {
+        self.set_gamepad_button(gamepad, button_type, 1.0);
+    }
+
+    /// Releases a button of a [`Gamepad`].
+    pub fn release_gamepad_button(&mut self, gamepad: Gamepad, button_type: GamepadButtonType) {
+        self.set_gamepad_button(gamepad, button_type, 0.0);
+    }
+
+    /// Sets the value of a button of a [`Gamepad`], between `0.0` (released) and `1.0` (fully pressed).
+    ///
+    /// Whether this presses or releases the button is determined by the
+    /// [`GamepadSettings`](crate::gamepad::GamepadSettings), like for hardware buttons.
+    pub fn set_gamepad_button(
+        &mut self,
+        gamepad: Gamepad,
+        button_type: GamepadButtonType,
+        value: f32,
+    ) {
+        self.send_gamepad_event(GamepadButtonChangedEvent::new(gamepad, button_type, value));
+    }
+
+    /// Sets the value of an axis of a [`Gamepad`], between `-1.0` and `1.0`.
+    pub fn set_gamepad_axis(&mut self, gamepad: Gamepad, axis_type: GamepadAxisType, value: f32) {
+        self.send_gamepad_event(GamepadAxisChangedEvent::new(gamepad, axis_type, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputInjector;
+    use crate::{
+        gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
+        keyboard::{KeyCode, KeyboardInput},
+        mouse::{MouseButton, MouseButtonInput},
+        Axis, ButtonState, Input, InputPlugin, InputSystem,
+    };
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+
+    #[test]
+    fn injected_input_updates_input_resources() {
+        let gamepad = Gamepad::new(usize::MAX);
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin).add_system(
+            (move |mut injector: InputInjector, mut frame: Local<u32>| {
+                if *frame == 0 {
+                    injector.press_key(KeyCode::Space);
+                    injector.press_mouse_button(MouseButton::Left);
+                    injector.connect_gamepad(gamepad, Default::default());
+                    injector.press_gamepad_button(gamepad, GamepadButtonType::South);
+                    injector.set_gamepad_axis(gamepad, GamepadAxisType::LeftStickX, 0.5);
+                } else {
+                    injector.release_key(KeyCode::Space);
+                    injector.release_mouse_button(MouseButton::Left);
+                    injector.release_gamepad_button(gamepad, GamepadButtonType::South);
+                }
+                *frame += 1;
+            })
+            .before(InputSystem)
+            .in_base_set(CoreSet::PreUpdate),
+        );
+
+        let button = GamepadButton::new(gamepad, GamepadButtonType::South);
+        let axis = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<KeyCode>>()
+            .just_pressed(KeyCode::Space));
+        assert!(app
+            .world
+            .resource::<Input<MouseButton>>()
+            .just_pressed(MouseButton::Left));
+        assert!(app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .just_pressed(button));
+        assert_eq!(
+            app.world.resource::<Axis<GamepadAxis>>().get(axis),
+            Some(0.5)
+        );
+
+        app.update();
+        assert!(app
+            .world
+            .resource::<Input<KeyCode>>()
+            .just_released(KeyCode::Space));
+        assert!(app
+            .world
+            .resource::<Input<MouseButton>>()
+            .just_released(MouseButton::Left));
+        assert!(app
+            .world
+            .resource::<Input<GamepadButton>>()
+            .just_released(button));
+    }
+
+    #[test]
+    fn input_is_tracked_per_window() {
+        let window_a = Entity::from_raw(1);
+        let window_b = Entity::from_raw(2);
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin).add_system(
+            (move |mut injector: InputInjector| {
+                injector.send_keyboard_input(KeyboardInput {
+                    scan_code: 0,
+                    key_code: Some(KeyCode::A),
+                    state: ButtonState::Pressed,
+                    repeat: false,
+                    window: window_a,
+                });
+                injector.send_mouse_button_input(MouseButtonInput {
+                    button: MouseButton::Left,
+                    state: ButtonState::Pressed,
+                    window: window_b,
+                });
+            })
+            .before(InputSystem)
+            .in_base_set(CoreSet::PreUpdate),
+        );
+        app.update();
+
+        let keys = app.world.resource::<Input<(Entity, KeyCode)>>();
+        assert!(keys.just_pressed((window_a, KeyCode::A)));
+        assert!(!keys.pressed((window_b, KeyCode::A)));
+        assert!(app.world.resource::<Input<KeyCode>>().pressed(KeyCode::A));
+
+        let buttons = app.world.resource::<Input<(Entity, MouseButton)>>();
+        assert!(buttons.just_pressed((window_b, MouseButton::Left)));
+        assert!(!buttons.pressed((window_a, MouseButton::Left)));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{ButtonState, Input};
-use bevy_ecs::{change_detection::DetectChangesMut, event::EventReader, system::ResMut};
+use bevy_ecs::{
+    change_detection::DetectChangesMut, entity::Entity, event::EventReader, system::ResMut,
+};
 use bevy_reflect::{FromReflect, Reflect};
 
 #[cfg(feature = "serialize")]
@@ -28,10 +30,20 @@ pub struct KeyboardInput {
     pub key_code: Option<KeyCode>,
     /// The press state of the key.
     pub state: ButtonState,
+    /// Whether this is a repeated press sent by the OS while the key is held down.
+    ///
+    /// Repeats don't change the state of the [`Input<KeyCode>`](crate::Input<KeyCode>) resource,
+    /// but are useful for text navigation and other held-key behavior.
+    pub repeat: bool,
+    /// The window that received the input.
+    pub window: Entity,
 }
 
 /// Updates the [`Input<KeyCode>`] resource with the latest [`KeyboardInput`] events.
 ///
+/// The `Input<(Entity, KeyCode)>` resource is updated as well, keeping track of the keys
+/// pressed in each window, which is useful for applications with multiple windows.
+///
 /// ## Differences
 ///
 /// The main difference between the [`KeyboardInput`] event and the [`Input<KeyCode>`] or [`Input<ScanCode>`] resources is that
@@ -39,19 +51,30 @@ pub struct KeyboardInput {
 pub fn keyboard_input_system(
     mut scan_input: ResMut<Input<ScanCode>>,
     mut key_input: ResMut<Input<KeyCode>>,
+    mut window_key_input: ResMut<Input<(Entity, KeyCode)>>,
     mut keyboard_input_events: EventReader<KeyboardInput>,
 ) {
     // Avoid clearing if it's not empty to ensure change detection is not triggered.
     scan_input.bypass_change_detection().clear();
     key_input.bypass_change_detection().clear();
+    window_key_input.bypass_change_detection().clear();
     for event in keyboard_input_events.iter() {
         let KeyboardInput {
-            scan_code, state, ..
+            scan_code,
+            state,
+            window,
+            ..
         } = event;
         if let Some(key_code) = event.key_code {
             match state {
-                ButtonState::Pressed => key_input.press(key_code),
-                ButtonState::Released => key_input.release(key_code),
+                ButtonState::Pressed => {
+                    key_input.press(key_code);
+                    window_key_input.press((*window, key_code));
+                }
+                ButtonState::Released => {
+                    key_input.release(key_code);
+                    window_key_input.release((*window, key_code));
+                }
             }
         }
         match state {
@@ -1,44 +1,58 @@
 mod axis;
 /// Common run conditions
 pub mod common_conditions;
+pub mod devices;
 pub mod gamepad;
+mod injector;
 mod input;
 pub mod keyboard;
 pub mod mouse;
 pub mod touch;
 
 pub use axis::*;
+pub use injector::*;
 pub use input::*;
 
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        devices::InputDevices,
         gamepad::{
-            Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
+            Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType,
+            GamepadStickType, GamepadSticks, Gamepads,
         },
         keyboard::{KeyCode, ScanCode},
         mouse::MouseButton,
         touch::{TouchInput, Touches},
-        Axis, Input,
+        Axis, Input, InputInjector,
     };
 }
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_reflect::{FromReflect, Reflect};
+use bevy_utils::HashMap;
+use devices::{
+    input_devices_system, InputDevice, InputDeviceCapabilities, InputDeviceKind, InputDevices,
+};
 use keyboard::{keyboard_input_system, KeyCode, KeyboardInput, ScanCode};
 use mouse::{
-    mouse_button_input_system, MouseButton, MouseButtonInput, MouseMotion, MouseScrollUnit,
-    MouseWheel,
+    mouse_button_input_system, mouse_motion_samples_system, MouseButton, MouseButtonInput,
+    MouseMotion, MouseMotionSamples, MouseScrollUnit, MouseWheel,
+};
+use touch::{
+    touch_screen_input_system, ForceTouch, TouchInput, TouchPhase, TouchSettings, Touches,
 };
-use touch::{touch_screen_input_system, ForceTouch, TouchInput, TouchPhase, Touches};
 
 use gamepad::{
     gamepad_axis_event_system, gamepad_button_event_system, gamepad_connection_system,
-    gamepad_event_system, AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad, GamepadAxis,
-    GamepadAxisChangedEvent, GamepadAxisType, GamepadButton, GamepadButtonChangedEvent,
-    GamepadButtonType, GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadSettings,
-    Gamepads,
+    gamepad_event_system, gamepad_indicator_system, gamepad_power_system,
+    gamepad_stick_debug_system, AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad,
+    GamepadAxis, GamepadAxisChangedEvent, GamepadAxisType, GamepadButton,
+    GamepadButtonChangedEvent, GamepadButtonType, GamepadConnection, GamepadConnectionEvent,
+    GamepadEvent, GamepadIndicator, GamepadIndicatorRequest, GamepadIndicators, GamepadInfo,
+    GamepadPowerEvent, GamepadPowerInfo, GamepadSettings, GamepadStick, GamepadStickDebug,
+    GamepadStickType, Gamepads, StickSettings,
 };
 
 #[cfg(feature = "serialize")]
@@ -57,6 +71,7 @@ impl Plugin for InputPlugin {
             // keyboard
             .add_event::<KeyboardInput>()
             .init_resource::<Input<KeyCode>>()
+            .init_resource::<Input<(Entity, KeyCode)>>()
             .init_resource::<Input<ScanCode>>()
             .add_system(keyboard_input_system.in_set(InputSystem))
             // mouse
@@ -64,17 +79,25 @@ impl Plugin for InputPlugin {
             .add_event::<MouseMotion>()
             .add_event::<MouseWheel>()
             .init_resource::<Input<MouseButton>>()
+            .init_resource::<Input<(Entity, MouseButton)>>()
+            .init_resource::<MouseMotionSamples>()
             .add_system(mouse_button_input_system.in_set(InputSystem))
+            .add_system(mouse_motion_samples_system.in_set(InputSystem))
             // gamepad
             .add_event::<GamepadConnectionEvent>()
             .add_event::<GamepadButtonChangedEvent>()
             .add_event::<GamepadAxisChangedEvent>()
+            .add_event::<GamepadPowerEvent>()
             .add_event::<GamepadEvent>()
+            .add_event::<GamepadIndicatorRequest>()
             .init_resource::<GamepadSettings>()
             .init_resource::<Gamepads>()
+            .init_resource::<GamepadIndicators>()
             .init_resource::<Input<GamepadButton>>()
             .init_resource::<Axis<GamepadAxis>>()
             .init_resource::<Axis<GamepadButton>>()
+            .init_resource::<AxisProcessing<GamepadAxis>>()
+            .init_resource::<AxisProcessing<GamepadButton>>()
             .add_systems(
                 (
                     gamepad_event_system,
@@ -85,13 +108,28 @@ impl Plugin for InputPlugin {
                     gamepad_axis_event_system
                         .after(gamepad_event_system)
                         .after(gamepad_connection_system),
+                    gamepad_indicator_system.after(gamepad_connection_system),
+                    gamepad_power_system
+                        .after(gamepad_event_system)
+                        .after(gamepad_connection_system),
+                    gamepad_stick_debug_system
+                        .after(gamepad_axis_event_system)
+                        .run_if(resource_exists::<GamepadStickDebug>()),
                 )
                     .in_set(InputSystem),
             )
             // touch
             .add_event::<TouchInput>()
             .init_resource::<Touches>()
-            .add_system(touch_screen_input_system.in_set(InputSystem));
+            .init_resource::<TouchSettings>()
+            .add_system(touch_screen_input_system.in_set(InputSystem))
+            // devices
+            .init_resource::<InputDevices>()
+            .add_system(
+                input_devices_system
+                    .after(gamepad_connection_system)
+                    .in_set(InputSystem),
+            );
 
         // Register common types
         app.register_type::<ButtonState>();
@@ -111,19 +149,37 @@ impl Plugin for InputPlugin {
         // Register touch types
         app.register_type::<TouchInput>()
             .register_type::<ForceTouch>()
-            .register_type::<TouchPhase>();
+            .register_type::<TouchPhase>()
+            .register_type::<TouchSettings>();
+
+        // Register device types
+        app.register_type::<InputDevice>()
+            .register_type::<InputDeviceKind>()
+            .register_type::<InputDeviceCapabilities>();
 
         // Register gamepad types
         app.register_type::<Gamepad>()
             .register_type::<GamepadConnection>()
+            .register_type::<GamepadInfo>()
+            .register_type::<GamepadPowerInfo>()
+            .register_type::<GamepadPowerEvent>()
             .register_type::<GamepadButtonType>()
             .register_type::<GamepadButton>()
             .register_type::<GamepadAxisType>()
             .register_type::<GamepadAxis>()
+            .register_type::<GamepadIndicator>()
+            .register_type::<GamepadIndicatorRequest>()
             .register_type::<GamepadSettings>()
             .register_type::<ButtonSettings>()
             .register_type::<AxisSettings>()
-            .register_type::<ButtonAxisSettings>();
+            .register_type::<ButtonAxisSettings>()
+            .register_type::<GamepadStickType>()
+            .register_type::<GamepadStick>()
+            .register_type::<StickSettings>()
+            .register_type::<HashMap<GamepadButton, ButtonSettings>>()
+            .register_type::<HashMap<GamepadAxis, AxisSettings>>()
+            .register_type::<HashMap<GamepadButton, ButtonAxisSettings>>()
+            .register_type::<HashMap<GamepadStick, StickSettings>>();
     }
 }
 
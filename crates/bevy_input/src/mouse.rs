@@ -1,7 +1,14 @@
 use crate::{ButtonState, Input};
-use bevy_ecs::{change_detection::DetectChangesMut, event::EventReader, system::ResMut};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    event::EventReader,
+    system::{ResMut, Resource},
+};
 use bevy_math::Vec2;
 use bevy_reflect::{FromReflect, Reflect};
+use bevy_utils::{Duration, Instant};
+use std::collections::VecDeque;
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -26,6 +33,8 @@ pub struct MouseButtonInput {
     pub button: MouseButton,
     /// The pressed state of the button.
     pub state: ButtonState,
+    /// The window that received the input.
+    pub window: Entity,
 }
 
 /// A button on a mouse device.
@@ -77,6 +86,160 @@ pub struct MouseMotion {
     pub delta: Vec2,
 }
 
+/// A single [`MouseMotion`] reported by the pointing device, together with the time it was
+/// received.
+///
+/// See [`MouseMotionSamples`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseMotionSample {
+    /// The change in the position of the pointing device since the previous sample.
+    pub delta: Vec2,
+    /// The time the sample was received by the input backend.
+    pub timestamp: Instant,
+}
+
+/// A resource buffering the individual [`MouseMotion`] samples together with their timestamps.
+///
+/// High polling rate mice report thousands of motion samples per second, while an app typically
+/// only runs at 60 frames per second. Summing up the [`MouseMotion`] events of a frame loses
+/// the information when the motion happened inside of the frame, which makes aiming in a
+/// simulation running at a different rate than the frame rate (e.g. in `FixedUpdate`) jittery.
+/// This resource keeps the samples of the last [`retention`](Self::retention) period, so the
+/// motion can be resampled into arbitrary time intervals with [`delta_between`](Self::delta_between)
+/// and [`resample`](Self::resample).
+///
+/// The input backend records the samples with [`MouseMotionSamples::push`] as soon as they arrive
+/// and the [`mouse_motion_samples_system`] marks the ones received since the previous frame as
+/// the samples of the current frame.
+///
+/// ## Resampling into fixed time steps
+///
+/// The fixed time steps run in a frame cover the real time between the previous and the current
+/// update of the `Time` resource, shifted back by the time accumulated for the next step:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::mouse::MouseMotionSamples;
+/// # use bevy_math::Vec2;
+/// # use bevy_utils::{Duration, Instant};
+/// # #[derive(Resource)] struct Time { last_update: Instant }
+/// # #[derive(Resource)] struct FixedTime { period: Duration, accumulated: Duration }
+/// # #[derive(Component)] struct Aim(Vec2);
+/// // Runs in `FixedUpdate`.
+/// fn aim(
+///     samples: Res<MouseMotionSamples>,
+///     time: Res<Time>,
+///     fixed_time: Res<FixedTime>,
+///     mut aims: Query<&mut Aim>,
+/// ) {
+///     let end = time.last_update - fixed_time.accumulated;
+///     let delta = samples.delta_between(end - fixed_time.period, end);
+///     for mut aim in &mut aims {
+///         aim.0 += delta;
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(aim);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct MouseMotionSamples {
+    samples: VecDeque<MouseMotionSample>,
+    /// The number of samples received during the current frame.
+    frame_len: usize,
+    /// The number of samples received since the last [`MouseMotionSamples::update`].
+    new_len: usize,
+    /// How long samples are kept after they were received.
+    ///
+    /// Defaults to 250 milliseconds, which covers several frames even at low frame rates.
+    pub retention: Duration,
+}
+
+impl Default for MouseMotionSamples {
+    fn default() -> Self {
+        Self {
+            samples: VecDeque::new(),
+            frame_len: 0,
+            new_len: 0,
+            retention: Duration::from_millis(250),
+        }
+    }
+}
+
+impl MouseMotionSamples {
+    /// Records a motion sample received at `timestamp`.
+    ///
+    /// Samples have to be pushed in chronological order.
+    pub fn push(&mut self, delta: Vec2, timestamp: Instant) {
+        self.samples
+            .push_back(MouseMotionSample { delta, timestamp });
+        self.new_len += 1;
+    }
+
+    /// Marks the samples pushed since the last update as the samples of the current frame and
+    /// discards the samples that are older than [`retention`](Self::retention) at `now`.
+    pub fn update(&mut self, now: Instant) {
+        self.frame_len = self.new_len;
+        self.new_len = 0;
+        let retained = self
+            .samples
+            .iter()
+            .position(|sample| now.saturating_duration_since(sample.timestamp) <= self.retention)
+            .unwrap_or(self.samples.len());
+        self.samples.drain(..retained);
+        self.frame_len = self.frame_len.min(self.samples.len());
+    }
+
+    /// Returns an iterator over the samples received during the current frame, oldest first.
+    pub fn frame_samples(&self) -> impl Iterator<Item = &MouseMotionSample> {
+        let end = self.samples.len() - self.new_len;
+        self.samples.range(end - self.frame_len..end)
+    }
+
+    /// Returns an iterator over all retained samples, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &MouseMotionSample> {
+        self.samples.iter()
+    }
+
+    /// Returns the accumulated motion of the samples received after `start` and up to and
+    /// including `end`.
+    pub fn delta_between(&self, start: Instant, end: Instant) -> Vec2 {
+        self.samples
+            .iter()
+            .skip_while(|sample| sample.timestamp <= start)
+            .take_while(|sample| sample.timestamp <= end)
+            .map(|sample| sample.delta)
+            .sum()
+    }
+
+    /// Splits the time between `start` and `end` into `steps` intervals of equal length and
+    /// returns the accumulated motion of each interval, see
+    /// [`delta_between`](Self::delta_between).
+    ///
+    /// This is useful to distribute the motion of a frame over the fixed time steps run in it.
+    pub fn resample(
+        &self,
+        start: Instant,
+        end: Instant,
+        steps: u32,
+    ) -> impl Iterator<Item = Vec2> + '_ {
+        let step = end.saturating_duration_since(start) / steps.max(1);
+        (0..steps).map(move |i| {
+            let step_start = start + step * i;
+            let step_end = if i + 1 == steps {
+                end
+            } else {
+                step_start + step
+            };
+            self.delta_between(step_start, step_end)
+        })
+    }
+}
+
+/// Updates the [`MouseMotionSamples`] resource, marking the samples received since the last
+/// frame as the samples of the current frame.
+pub fn mouse_motion_samples_system(mut samples: ResMut<MouseMotionSamples>) {
+    samples.bypass_change_detection().update(Instant::now());
+}
+
 /// The scroll unit.
 ///
 /// Describes how a value of a [`MouseWheel`](crate::mouse::MouseWheel) event has to be interpreted.
@@ -124,19 +287,100 @@ pub struct MouseWheel {
 
 /// Updates the [`Input<MouseButton>`] resource with the latest [`MouseButtonInput`] events.
 ///
+/// The `Input<(Entity, MouseButton)>` resource is updated as well, keeping track of the buttons
+/// pressed in each window, which is useful for applications with multiple windows.
+///
 /// ## Differences
 ///
 /// The main difference between the [`MouseButtonInput`] event and the [`Input<MouseButton>`] resource is that
 /// the latter has convenient functions like [`Input::pressed`], [`Input::just_pressed`] and [`Input::just_released`].
 pub fn mouse_button_input_system(
     mut mouse_button_input: ResMut<Input<MouseButton>>,
+    mut window_mouse_button_input: ResMut<Input<(Entity, MouseButton)>>,
     mut mouse_button_input_events: EventReader<MouseButtonInput>,
 ) {
     mouse_button_input.bypass_change_detection().clear();
+    window_mouse_button_input.bypass_change_detection().clear();
     for event in mouse_button_input_events.iter() {
         match event.state {
-            ButtonState::Pressed => mouse_button_input.press(event.button),
-            ButtonState::Released => mouse_button_input.release(event.button),
+            ButtonState::Pressed => {
+                mouse_button_input.press(event.button);
+                window_mouse_button_input.press((event.window, event.button));
+            }
+            ButtonState::Released => {
+                mouse_button_input.release(event.button);
+                window_mouse_button_input.release((event.window, event.button));
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MouseMotionSamples;
+    use bevy_math::Vec2;
+    use bevy_utils::{Duration, Instant};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn frame_samples() {
+        let start = Instant::now();
+        let mut samples = MouseMotionSamples::default();
+
+        samples.push(Vec2::X, start + ms(1));
+        samples.push(Vec2::Y, start + ms(2));
+        samples.update(start + ms(16));
+        assert_eq!(
+            samples
+                .frame_samples()
+                .map(|sample| sample.delta)
+                .collect::<Vec<_>>(),
+            [Vec2::X, Vec2::Y]
+        );
+
+        samples.push(Vec2::NEG_X, start + ms(20));
+        // Samples pushed during the frame belong to the next one.
+        assert_eq!(samples.frame_samples().count(), 2);
+        samples.update(start + ms(32));
+        assert_eq!(
+            samples
+                .frame_samples()
+                .map(|sample| sample.delta)
+                .collect::<Vec<_>>(),
+            [Vec2::NEG_X]
+        );
+        assert_eq!(samples.iter().count(), 3);
+
+        samples.update(start + ms(300));
+        assert_eq!(samples.frame_samples().count(), 0);
+        assert_eq!(samples.iter().count(), 0);
+    }
+
+    #[test]
+    fn resample() {
+        let start = Instant::now();
+        let mut samples = MouseMotionSamples::default();
+        for i in 1..=8 {
+            samples.push(Vec2::new(i as f32, 0.0), start + ms(2 * i));
+        }
+        samples.update(start + ms(16));
+
+        assert_eq!(
+            samples.delta_between(start, start + ms(16)),
+            Vec2::new(36.0, 0.0)
+        );
+        assert_eq!(
+            samples.delta_between(start + ms(4), start + ms(8)),
+            Vec2::new(7.0, 0.0)
+        );
+        assert_eq!(
+            samples
+                .resample(start, start + ms(16), 2)
+                .collect::<Vec<_>>(),
+            [Vec2::new(10.0, 0.0), Vec2::new(26.0, 0.0)]
+        );
+    }
+}
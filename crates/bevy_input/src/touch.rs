@@ -1,8 +1,8 @@
 use bevy_ecs::event::EventReader;
-use bevy_ecs::system::{ResMut, Resource};
+use bevy_ecs::system::{Res, ResMut, Resource};
 use bevy_math::Vec2;
-use bevy_reflect::{FromReflect, Reflect};
-use bevy_utils::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+use bevy_utils::{HashMap, HashSet};
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -90,6 +90,28 @@ pub enum ForceTouch {
     Normalized(f64),
 }
 
+impl ForceTouch {
+    /// Returns the force normalized to the range `[0.0, 1.0]`.
+    ///
+    /// Calibrated forces are divided by their `max_possible_force`.
+    pub fn normalized(&self) -> f64 {
+        match *self {
+            ForceTouch::Calibrated {
+                force,
+                max_possible_force,
+                ..
+            } => {
+                if max_possible_force > 0.0 {
+                    (force / max_possible_force).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                }
+            }
+            ForceTouch::Normalized(force) => force.clamp(0.0, 1.0),
+        }
+    }
+}
+
 /// A phase of a [`TouchInput`](crate::touch::TouchInput).
 ///
 /// ## Usage
@@ -142,6 +164,8 @@ pub struct Touch {
     position: Vec2,
     /// The current force of the touch input.
     force: Option<ForceTouch>,
+    /// The extrapolated position of the touch input.
+    predicted_position: Vec2,
 }
 
 impl Touch {
@@ -196,6 +220,15 @@ impl Touch {
     pub fn force(&self) -> Option<ForceTouch> {
         self.force
     }
+
+    /// Returns the `predicted_position` of the touch.
+    ///
+    /// This is the current `position` extrapolated along the last movement by
+    /// [`TouchSettings::prediction`], or the current `position` if prediction is disabled.
+    #[inline]
+    pub fn predicted_position(&self) -> Vec2 {
+        self.predicted_position
+    }
 }
 
 impl From<&TouchInput> for Touch {
@@ -208,6 +241,61 @@ impl From<&TouchInput> for Touch {
             previous_force: input.force,
             position: input.position,
             force: input.force,
+            predicted_position: input.position,
+        }
+    }
+}
+
+/// Settings for filtering [`TouchInput`] events before they update the [`Touches`] resource.
+///
+/// ## Usage
+///
+/// It is used to create a `bevy` resource that configures the
+/// [`touch_screen_input_system`](crate::touch::touch_screen_input_system). The default settings
+/// don't filter anything. To modify these settings, mutate the corresponding resource.
+///
+/// ## Note
+///
+/// The platform only reports the pressure of a touch, not its contact size, so palm rejection
+/// relies on [`TouchSettings::palm_rejection_force`] and only works where a
+/// [`ForceTouch`] is provided.
+#[derive(Resource, Debug, Clone, Reflect, FromReflect)]
+#[reflect(Debug, Default)]
+pub struct TouchSettings {
+    /// The [normalized](ForceTouch::normalized) force at or above which a touch is treated as a
+    /// resting palm and ignored.
+    ///
+    /// A touch that reaches this force while pressed is cancelled. Touches without a force are
+    /// never rejected. Defaults to `None`, which disables palm rejection.
+    pub palm_rejection_force: Option<f64>,
+    /// The distance a touch has to move from its current position before the movement is
+    /// registered, filtering out jitter. Defaults to `0.0`.
+    pub movement_threshold: f32,
+    /// How far ahead [`Touch::predicted_position`] extrapolates the position of a touch, as a
+    /// multiple of its last movement.
+    ///
+    /// Increasing this hides some input latency on high-latency targets at the cost of
+    /// overshooting when the touch changes direction. Defaults to `0.0`, which disables
+    /// prediction.
+    pub prediction: f32,
+}
+
+impl Default for TouchSettings {
+    fn default() -> Self {
+        TouchSettings {
+            palm_rejection_force: None,
+            movement_threshold: 0.0,
+            prediction: 0.0,
+        }
+    }
+}
+
+impl TouchSettings {
+    /// Returns `true` if a touch with the given `force` should be rejected as a palm.
+    pub fn is_palm(&self, force: Option<ForceTouch>) -> bool {
+        match (self.palm_rejection_force, force) {
+            (Some(threshold), Some(force)) => force.normalized() >= threshold,
+            _ => false,
         }
     }
 }
@@ -232,6 +320,8 @@ pub struct Touches {
     just_released: HashMap<u64, Touch>,
     /// A collection of every [`Touch`] that just got cancelled.
     just_cancelled: HashMap<u64, Touch>,
+    /// The ids of the touches that were rejected as palms.
+    rejected: HashSet<u64>,
 }
 
 impl Touches {
@@ -300,20 +390,51 @@ impl Touches {
         self.pressed.values().next().map(|t| t.position)
     }
 
+    /// Returns `true` if the touch corresponding to the `id` was rejected as a palm.
+    ///
+    /// Rejected touches are never pressed, see [`TouchSettings::palm_rejection_force`].
+    pub fn is_rejected(&self, id: u64) -> bool {
+        self.rejected.contains(&id)
+    }
+
     /// Processes a [`TouchInput`] event by updating the `pressed`, `just_pressed`,
     /// `just_released`, and `just_cancelled` collections.
-    fn process_touch_event(&mut self, event: &TouchInput) {
+    ///
+    /// Events are filtered according to the `settings`.
+    fn process_touch_event(&mut self, event: &TouchInput, settings: &TouchSettings) {
+        if self.rejected.contains(&event.id) {
+            if matches!(event.phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+                self.rejected.remove(&event.id);
+            }
+            return;
+        }
+
         match event.phase {
             TouchPhase::Started => {
+                if settings.is_palm(event.force) {
+                    self.rejected.insert(event.id);
+                    return;
+                }
                 self.pressed.insert(event.id, event.into());
                 self.just_pressed.insert(event.id, event.into());
             }
             TouchPhase::Moved => {
                 if let Some(mut new_touch) = self.pressed.get(&event.id).cloned() {
-                    new_touch.previous_position = new_touch.position;
+                    if settings.is_palm(event.force) {
+                        // the touch turned out to be a palm, so cancel it
+                        self.pressed.remove(&event.id);
+                        self.just_cancelled.insert(event.id, new_touch);
+                        self.rejected.insert(event.id);
+                        return;
+                    }
                     new_touch.previous_force = new_touch.force;
-                    new_touch.position = event.position;
                     new_touch.force = event.force;
+                    if event.position.distance(new_touch.position) >= settings.movement_threshold {
+                        new_touch.previous_position = new_touch.position;
+                        new_touch.position = event.position;
+                        new_touch.predicted_position =
+                            new_touch.position + new_touch.delta() * settings.prediction;
+                    }
                     self.pressed.insert(event.id, new_touch);
                 }
             }
@@ -358,14 +479,19 @@ impl Touches {
 ///
 /// The main difference between the [`TouchInput`] event and the [`Touches`] resource is that
 /// the latter has convenient functions like [`Touches::just_pressed`] and [`Touches::just_released`].
+///
+/// ## Filtering
+///
+/// The events are filtered according to the [`TouchSettings`] resource.
 pub fn touch_screen_input_system(
     mut touch_state: ResMut<Touches>,
     mut touch_input_events: EventReader<TouchInput>,
+    settings: Res<TouchSettings>,
 ) {
     touch_state.update();
 
     for event in touch_input_events.iter() {
-        touch_state.process_touch_event(event);
+        touch_state.process_touch_event(event, &settings);
     }
 }
 
@@ -387,6 +513,7 @@ mod test {
             previous_force: None,
             position: Vec2::ZERO,
             force: None,
+            predicted_position: Vec2::ZERO,
         };
 
         // Add a touch to `just_pressed`, 'just_released', and 'just cancelled'
@@ -405,7 +532,10 @@ mod test {
 
     #[test]
     fn touch_process() {
-        use crate::{touch::TouchPhase, TouchInput, Touches};
+        use crate::{
+            touch::{TouchPhase, TouchSettings},
+            TouchInput, Touches,
+        };
         use bevy_math::Vec2;
 
         let mut touches = Touches::default();
@@ -420,7 +550,7 @@ mod test {
         };
 
         touches.update();
-        touches.process_touch_event(&touch_event);
+        touches.process_touch_event(&touch_event, &TouchSettings::default());
 
         assert!(touches.pressed.get(&touch_event.id).is_some());
         assert!(touches.just_pressed.get(&touch_event.id).is_some());
@@ -435,7 +565,7 @@ mod test {
         };
 
         touches.update();
-        touches.process_touch_event(&moved_touch_event);
+        touches.process_touch_event(&moved_touch_event, &TouchSettings::default());
 
         assert_eq!(
             touches
@@ -456,7 +586,7 @@ mod test {
         };
 
         touches.update();
-        touches.process_touch_event(&cancel_touch_event);
+        touches.process_touch_event(&cancel_touch_event, &TouchSettings::default());
 
         assert!(touches.just_cancelled.get(&touch_event.id).is_some());
         assert!(touches.pressed.get(&touch_event.id).is_none());
@@ -471,9 +601,9 @@ mod test {
         };
 
         touches.update();
-        touches.process_touch_event(&touch_event);
-        touches.process_touch_event(&moved_touch_event);
-        touches.process_touch_event(&end_touch_event);
+        touches.process_touch_event(&touch_event, &TouchSettings::default());
+        touches.process_touch_event(&moved_touch_event, &TouchSettings::default());
+        touches.process_touch_event(&end_touch_event, &TouchSettings::default());
 
         assert!(touches.just_released.get(&touch_event.id).is_some());
         assert!(touches.pressed.get(&touch_event.id).is_none());
@@ -482,9 +612,76 @@ mod test {
         assert!(touch.previous_position != touch.position);
     }
 
+    #[test]
+    fn touch_filtering() {
+        use crate::{
+            touch::{ForceTouch, TouchPhase, TouchSettings},
+            TouchInput, Touches,
+        };
+        use bevy_math::Vec2;
+
+        let mut touches = Touches::default();
+        let settings = TouchSettings {
+            palm_rejection_force: Some(0.8),
+            movement_threshold: 1.0,
+            prediction: 2.0,
+        };
+        let event = |phase, position, force: Option<f64>, id| TouchInput {
+            phase,
+            position,
+            force: force.map(ForceTouch::Normalized),
+            id,
+        };
+
+        // A palm is never pressed
+        touches.process_touch_event(
+            &event(TouchPhase::Started, Vec2::ZERO, Some(0.9), 1),
+            &settings,
+        );
+        touches.process_touch_event(&event(TouchPhase::Moved, Vec2::ONE, None, 1), &settings);
+        assert!(touches.is_rejected(1));
+        assert!(touches.get_pressed(1).is_none());
+        assert!(!touches.just_pressed(1));
+        touches.process_touch_event(&event(TouchPhase::Ended, Vec2::ONE, None, 1), &settings);
+        assert!(!touches.is_rejected(1));
+        assert!(!touches.just_released(1));
+
+        // Small movements are filtered out and the position is predicted
+        touches.process_touch_event(
+            &event(TouchPhase::Started, Vec2::ZERO, Some(0.2), 2),
+            &settings,
+        );
+        touches.process_touch_event(
+            &event(TouchPhase::Moved, Vec2::new(0.5, 0.0), Some(0.3), 2),
+            &settings,
+        );
+        let touch = touches.get_pressed(2).unwrap();
+        assert_eq!(touch.position(), Vec2::ZERO);
+        assert_eq!(touch.force(), Some(ForceTouch::Normalized(0.3)));
+        touches.process_touch_event(
+            &event(TouchPhase::Moved, Vec2::new(2.0, 0.0), Some(0.3), 2),
+            &settings,
+        );
+        let touch = touches.get_pressed(2).unwrap();
+        assert_eq!(touch.position(), Vec2::new(2.0, 0.0));
+        assert_eq!(touch.predicted_position(), Vec2::new(6.0, 0.0));
+
+        // A touch that turns into a palm is cancelled
+        touches.process_touch_event(
+            &event(TouchPhase::Moved, Vec2::new(3.0, 0.0), Some(0.8), 2),
+            &settings,
+        );
+        assert!(touches.get_pressed(2).is_none());
+        assert!(touches.just_cancelled(2));
+        assert!(touches.is_rejected(2));
+    }
+
     #[test]
     fn touch_pressed() {
-        use crate::{touch::TouchPhase, TouchInput, Touches};
+        use crate::{
+            touch::{TouchPhase, TouchSettings},
+            TouchInput, Touches,
+        };
         use bevy_math::Vec2;
 
         let mut touches = Touches::default();
@@ -497,7 +694,7 @@ mod test {
         };
 
         // Register the touch and test that it was registered correctly
-        touches.process_touch_event(&touch_event);
+        touches.process_touch_event(&touch_event, &TouchSettings::default());
 
         assert!(touches.get_pressed(touch_event.id).is_some());
         assert!(touches.just_pressed(touch_event.id));
@@ -506,7 +703,10 @@ mod test {
 
     #[test]
     fn touch_released() {
-        use crate::{touch::TouchPhase, TouchInput, Touches};
+        use crate::{
+            touch::{TouchPhase, TouchSettings},
+            TouchInput, Touches,
+        };
         use bevy_math::Vec2;
 
         let mut touches = Touches::default();
@@ -519,7 +719,7 @@ mod test {
         };
 
         // Register the touch and test that it was registered correctly
-        touches.process_touch_event(&touch_event);
+        touches.process_touch_event(&touch_event, &TouchSettings::default());
 
         assert!(touches.get_released(touch_event.id).is_some());
         assert!(touches.just_released(touch_event.id));
@@ -528,7 +728,10 @@ mod test {
 
     #[test]
     fn touch_cancelled() {
-        use crate::{touch::TouchPhase, TouchInput, Touches};
+        use crate::{
+            touch::{TouchPhase, TouchSettings},
+            TouchInput, Touches,
+        };
         use bevy_math::Vec2;
 
         let mut touches = Touches::default();
@@ -541,7 +744,7 @@ mod test {
         };
 
         // Register the touch and test that it was registered correctly
-        touches.process_touch_event(&touch_event);
+        touches.process_touch_event(&touch_event, &TouchSettings::default());
 
         assert!(touches.just_cancelled(touch_event.id));
         assert_eq!(touches.iter_just_cancelled().count(), 1);
@@ -114,6 +114,12 @@ pub mod audio {
     pub use bevy_audio::*;
 }
 
+#[cfg(feature = "bevy_console")]
+pub mod console {
+    //! Provides a reflection-based debug console.
+    pub use bevy_console::*;
+}
+
 #[cfg(feature = "bevy_core_pipeline")]
 pub mod core_pipeline {
     //! Core render pipeline.
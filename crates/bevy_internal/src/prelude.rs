@@ -19,6 +19,10 @@ pub use crate::audio::prelude::*;
 #[cfg(feature = "bevy_animation")]
 pub use crate::animation::prelude::*;
 
+#[doc(hidden)]
+#[cfg(feature = "bevy_console")]
+pub use crate::console::prelude::*;
+
 #[doc(hidden)]
 #[cfg(feature = "bevy_core_pipeline")]
 pub use crate::core_pipeline::prelude::*;
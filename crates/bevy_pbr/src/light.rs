@@ -4,7 +4,7 @@ use bevy_ecs::prelude::*;
 use bevy_math::{Mat4, Rect, UVec2, UVec3, Vec2, Vec3, Vec3A, Vec3Swizzles, Vec4, Vec4Swizzles};
 use bevy_reflect::prelude::*;
 use bevy_render::{
-    camera::Camera,
+    camera::{Camera, CameraProjection},
     color::Color,
     extract_resource::ExtractResource,
     prelude::Projection,
@@ -187,6 +187,10 @@ impl Default for SpotLight {
 /// App::new()
 ///     .insert_resource(DirectionalLightShadowMap { size: 2048 });
 /// ```
+///
+/// To use a lower resolution, different biases or cascades for a single directional light, e.g.
+/// cheap shadows for a fill light next to a high quality key light, add the
+/// [`DirectionalLightShadowSettings`] component to it.
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component, Default)]
 pub struct DirectionalLight {
@@ -230,6 +234,66 @@ impl Default for DirectionalLightShadowMap {
     }
 }
 
+/// Overrides the shadow settings of a single [`DirectionalLight`]. Settings which are `None` are
+/// taken from the light, its [`CascadeShadowConfig`] and the [`DirectionalLightShadowMap`].
+///
+/// This component only affects directional lights. [`PointLight`]s and [`SpotLight`]s configure
+/// their biases with their own fields, but always use the full resolution of the
+/// [`PointLightShadowMap`] and the [`DirectionalLightShadowMap`] respectively, as their shadow
+/// maps are sampled without a per-light scale.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct DirectionalLightShadowSettings {
+    /// The size of the shadow maps of the light.
+    ///
+    /// All directional and spot light shadow maps share a texture of the size of the
+    /// [`DirectionalLightShadowMap`] resource, so the size is clamped to it. Lights with a smaller
+    /// size only render into a part of their texture layers, which makes their shadow passes
+    /// cheaper.
+    pub size: Option<usize>,
+    /// Overrides [`DirectionalLight::shadow_depth_bias`].
+    pub depth_bias: Option<f32>,
+    /// Overrides [`DirectionalLight::shadow_normal_bias`].
+    pub normal_bias: Option<f32>,
+    /// Overrides the [`CascadeShadowConfig`] of the light, i.e. the number of cascades and their
+    /// bounds. Prefer using [`CascadeShadowConfigBuilder`] to construct it.
+    pub cascades: Option<CascadeShadowConfig>,
+}
+
+impl DirectionalLightShadowSettings {
+    /// Returns the size of the shadow maps of a light with these `settings`, given the
+    /// [`DirectionalLightShadowMap`].
+    pub fn clamped_size(settings: Option<&Self>, shadow_map: &DirectionalLightShadowMap) -> usize {
+        settings
+            .and_then(|settings| settings.size)
+            .map_or(shadow_map.size, |size| size.clamp(1, shadow_map.size))
+    }
+
+    /// Returns the depth bias of the `light` with these `settings`.
+    pub fn depth_bias(settings: Option<&Self>, light: &DirectionalLight) -> f32 {
+        settings
+            .and_then(|settings| settings.depth_bias)
+            .unwrap_or(light.shadow_depth_bias)
+    }
+
+    /// Returns the normal bias of the `light` with these `settings`.
+    pub fn normal_bias(settings: Option<&Self>, light: &DirectionalLight) -> f32 {
+        settings
+            .and_then(|settings| settings.normal_bias)
+            .unwrap_or(light.shadow_normal_bias)
+    }
+
+    /// Returns the cascade configuration of a light with these `settings` and the `config`.
+    pub fn cascades<'a>(
+        settings: Option<&'a Self>,
+        config: &'a CascadeShadowConfig,
+    ) -> &'a CascadeShadowConfig {
+        settings
+            .and_then(|settings| settings.cascades.as_ref())
+            .unwrap_or(config)
+    }
+}
+
 /// Controls how cascaded shadow mapping works.
 /// Prefer using [`CascadeShadowConfigBuilder`] to construct an instance.
 ///
@@ -243,7 +307,7 @@ impl Default for DirectionalLightShadowMap {
 ///   ..default()
 /// }.into();
 /// ```
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, FromReflect)]
 #[reflect(Component)]
 pub struct CascadeShadowConfig {
     /// The (positive) distance to the far boundary of each cascade.
@@ -409,6 +473,7 @@ pub fn update_directional_light_cascades(
         &DirectionalLight,
         &CascadeShadowConfig,
         &mut Cascades,
+        Option<&DirectionalLightShadowSettings>,
     )>,
 ) {
     let views = views
@@ -422,10 +487,14 @@ pub fn update_directional_light_cascades(
         })
         .collect::<Vec<_>>();
 
-    for (transform, directional_light, cascades_config, mut cascades) in lights.iter_mut() {
+    for (transform, directional_light, cascades_config, mut cascades, settings) in lights.iter_mut()
+    {
         if !directional_light.shadows_enabled {
             continue;
         }
+        let shadow_map_size =
+            DirectionalLightShadowSettings::clamped_size(settings, &directional_light_shadow_map);
+        let cascades_config = DirectionalLightShadowSettings::cascades(settings, cascades_config);
 
         // It is very important to the numerical and thus visual stability of shadows that
         // light_to_world has orthogonal upper-left 3x3 and zero translation.
@@ -463,10 +532,15 @@ pub fn update_directional_light_cascades(
                         Projection::Orthographic(projection) => {
                             frustum_corners_ortho(projection.area, z_near, z_far)
                         }
+                        Projection::Blended(projection) => frustum_corners_from_projection(
+                            projection.get_projection_matrix(),
+                            z_near,
+                            z_far,
+                        ),
                     };
                     calculate_cascade(
                         corners,
-                        directional_light_shadow_map.size as f32,
+                        shadow_map_size as f32,
                         light_to_world,
                         camera_to_light_view,
                     )
@@ -491,6 +565,30 @@ fn frustum_corners_ortho(area: Rect, z_near: f32, z_far: f32) -> [Vec3A; 8] {
     ]
 }
 
+/// Returns the corners of the frustum of a `projection` matrix which, like perspective and
+/// orthographic projections, scales x and y by a factor depending on depth.
+fn frustum_corners_from_projection(projection: Mat4, z_near: f32, z_far: f32) -> [Vec3A; 8] {
+    let corner = |ndc_x: f32, ndc_y: f32, z: f32| {
+        let w = projection.z_axis.w * z + projection.w_axis.w;
+        Vec3A::new(
+            (ndc_x * w - projection.w_axis.x) / projection.x_axis.x,
+            (ndc_y * w - projection.w_axis.y) / projection.y_axis.y,
+            z,
+        )
+    };
+    // NOTE: These vertices are in the specific order required by [`calculate_cascade`].
+    [
+        corner(1.0, -1.0, z_near),  // bottom right
+        corner(1.0, 1.0, z_near),   // top right
+        corner(-1.0, 1.0, z_near),  // top left
+        corner(-1.0, -1.0, z_near), // bottom left
+        corner(1.0, -1.0, z_far),   // bottom right
+        corner(1.0, 1.0, z_far),    // top right
+        corner(-1.0, 1.0, z_far),   // top left
+        corner(-1.0, -1.0, z_far),  // bottom left
+    ]
+}
+
 fn frustum_corners(aspect_ratio: f32, tan_half_fov: f32, z_near: f32, z_far: f32) -> [Vec3A; 8] {
     let a = z_near.abs() * tan_half_fov;
     let b = z_far.abs() * tan_half_fov;
@@ -2171,6 +2269,7 @@ pub fn check_light_mesh_visibility(
 #[cfg(test)]
 mod test {
     use super::*;
+    use bevy_render::camera::{OrthographicProjection, PerspectiveProjection};
 
     fn test_cluster_tiling(config: ClusterConfig, screen_size: UVec2) -> Clusters {
         let dims = config.dimensions_for_screen_size(screen_size);
@@ -2228,4 +2327,90 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn directional_light_shadow_settings_override_the_light() {
+        let light = DirectionalLight::default();
+        let config = CascadeShadowConfig::default();
+        let shadow_map = DirectionalLightShadowMap { size: 2048 };
+
+        assert_eq!(
+            DirectionalLightShadowSettings::clamped_size(None, &shadow_map),
+            2048
+        );
+        assert_eq!(
+            DirectionalLightShadowSettings::depth_bias(None, &light),
+            light.shadow_depth_bias
+        );
+        let default_settings = DirectionalLightShadowSettings::default();
+        assert_eq!(
+            DirectionalLightShadowSettings::normal_bias(Some(&default_settings), &light),
+            light.shadow_normal_bias
+        );
+
+        let settings = DirectionalLightShadowSettings {
+            size: Some(4096),
+            depth_bias: Some(0.1),
+            normal_bias: Some(1.0),
+            cascades: Some(
+                CascadeShadowConfigBuilder {
+                    num_cascades: 2,
+                    ..Default::default()
+                }
+                .into(),
+            ),
+        };
+        let settings = Some(&settings);
+        assert_eq!(
+            DirectionalLightShadowSettings::clamped_size(settings, &shadow_map),
+            2048
+        );
+        assert_eq!(
+            DirectionalLightShadowSettings::depth_bias(settings, &light),
+            0.1
+        );
+        assert_eq!(
+            DirectionalLightShadowSettings::normal_bias(settings, &light),
+            1.0
+        );
+        assert_eq!(
+            DirectionalLightShadowSettings::cascades(settings, &config)
+                .bounds
+                .len(),
+            2
+        );
+    }
+
+    fn assert_corners_eq(actual: [Vec3A; 8], expected: [Vec3A; 8]) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            assert!(actual.abs_diff_eq(expected, 1e-4), "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn frustum_corners_from_projection_matches_perspective_and_orthographic() {
+        let (z_near, z_far) = (-0.5, -20.0);
+
+        let mut perspective = PerspectiveProjection::default();
+        perspective.update(1280.0, 720.0);
+        assert_corners_eq(
+            frustum_corners_from_projection(perspective.get_projection_matrix(), z_near, z_far),
+            frustum_corners(
+                perspective.aspect_ratio,
+                (perspective.fov / 2.).tan(),
+                z_near,
+                z_far,
+            ),
+        );
+
+        let mut orthographic = OrthographicProjection {
+            viewport_origin: Vec2::new(0.25, 0.5),
+            ..Default::default()
+        };
+        orthographic.update(1280.0, 720.0);
+        assert_corners_eq(
+            frustum_corners_from_projection(orthographic.get_projection_matrix(), z_near, z_far),
+            frustum_corners_ortho(orthographic.area, z_near, z_far),
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    render, AlphaMode, DrawMesh, DrawPrepass, EnvironmentMapLight, MeshPipeline, MeshPipelineKey,
-    MeshUniform, PrepassPipelinePlugin, PrepassPlugin, RenderLightSystems, SetMeshBindGroup,
-    SetMeshViewBindGroup, Shadow,
+    render, AlphaMode, DrawMesh, DrawMeshInstanced, DrawPrepass, EnvironmentMapLight, MeshPipeline,
+    MeshPipelineKey, MeshUniform, NoAutomaticBatching, PrepassPipelinePlugin, PrepassPlugin,
+    RenderLightSystems, SetMeshBindGroup, SetMeshViewBindGroup, Shadow,
 };
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_asset::{AddAsset, AssetEvent, AssetServer, Assets, Handle};
@@ -20,12 +20,12 @@ use bevy_ecs::{
 use bevy_reflect::TypeUuid;
 use bevy_render::{
     extract_component::ExtractComponentPlugin,
-    mesh::{Mesh, MeshVertexBufferLayout},
+    mesh::{GpuMesh, Mesh, MeshVertexBufferLayout},
     prelude::Image,
     render_asset::{PrepareAssetSet, RenderAssets},
     render_phase::{
-        AddRenderCommand, DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult,
-        RenderPhase, SetItemPipeline, TrackedRenderPass,
+        AddRenderCommand, DrawFunctions, InstanceBatchKey, PhaseItem, RenderCommand,
+        RenderCommandResult, RenderPhase, SetItemPipeline, TrackedRenderPass,
     },
     render_resource::{
         AsBindGroup, AsBindGroupError, BindGroup, BindGroupLayout, OwnedBindingResource,
@@ -145,6 +145,18 @@ pub trait Material: AsBindGroup + Send + Sync + Clone + TypeUuid + Sized + 'stat
         ShaderRef::Default
     }
 
+    /// Returns whether meshes with this material can be drawn with instanced draw calls.
+    ///
+    /// In instanced draws, `mesh` is assigned from per instance vertex attributes and is only
+    /// available in the vertex stage. Fragment shaders of materials supporting instancing must not
+    /// read `mesh` if `MESH_INSTANCED` is defined, and can use the `instance_flags` and
+    /// `instance_data` outputs of the mesh vertex shader instead. Defaults to `true` only if the
+    /// material uses the default fragment and prepass fragment shaders.
+    fn supports_instancing() -> bool {
+        matches!(Self::fragment_shader(), ShaderRef::Default)
+            && matches!(Self::prepass_fragment_shader(), ShaderRef::Default)
+    }
+
     /// Customizes the default [`RenderPipelineDescriptor`] for a specific entity using the entity's
     /// [`MaterialPipelineKey`] and [`MeshVertexBufferLayout`] as input.
     #[allow(unused_variables)]
@@ -193,8 +205,8 @@ where
                 .init_resource::<DrawFunctions<Shadow>>()
                 .add_render_command::<Shadow, DrawPrepass<M>>()
                 .add_render_command::<Transparent3d, DrawMaterial<M>>()
-                .add_render_command::<Opaque3d, DrawMaterial<M>>()
-                .add_render_command::<AlphaMask3d, DrawMaterial<M>>()
+                .add_render_command::<Opaque3d, DrawMaterialInstanced<M>>()
+                .add_render_command::<AlphaMask3d, DrawMaterialInstanced<M>>()
                 .init_resource::<MaterialPipeline<M>>()
                 .init_resource::<ExtractedMaterials<M>>()
                 .init_resource::<RenderMaterials<M>>()
@@ -264,6 +276,8 @@ pub struct MaterialPipeline<M: Material> {
     pub material_layout: BindGroupLayout,
     pub vertex_shader: Option<Handle<Shader>>,
     pub fragment_shader: Option<Handle<Shader>>,
+    /// The value of [`Material::supports_instancing`].
+    pub supports_instancing: bool,
     marker: PhantomData<M>,
 }
 
@@ -274,6 +288,7 @@ impl<M: Material> Clone for MaterialPipeline<M> {
             material_layout: self.material_layout.clone(),
             vertex_shader: self.vertex_shader.clone(),
             fragment_shader: self.fragment_shader.clone(),
+            supports_instancing: self.supports_instancing,
             marker: PhantomData,
         }
     }
@@ -324,6 +339,7 @@ impl<M: Material> FromWorld for MaterialPipeline<M> {
                 ShaderRef::Handle(handle) => Some(handle),
                 ShaderRef::Path(path) => Some(asset_server.load(path)),
             },
+            supports_instancing: M::supports_instancing(),
             marker: PhantomData,
         }
     }
@@ -337,6 +353,14 @@ type DrawMaterial<M> = (
     DrawMesh,
 );
 
+type DrawMaterialInstanced<M> = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMaterialBindGroup<M, 1>,
+    SetMeshBindGroup<2>,
+    DrawMeshInstanced,
+);
+
 /// Sets the bind group for a given [`Material`] at the configured `I` index.
 pub struct SetMaterialBindGroup<M: Material, const I: usize>(PhantomData<M>);
 impl<P: PhaseItem, M: Material, const I: usize> RenderCommand<P> for SetMaterialBindGroup<M, I> {
@@ -369,7 +393,12 @@ pub fn queue_material_meshes<M: Material>(
     msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials<M>>,
-    material_meshes: Query<(&Handle<M>, &Handle<Mesh>, &MeshUniform)>,
+    material_meshes: Query<(
+        &Handle<M>,
+        &Handle<Mesh>,
+        &MeshUniform,
+        Option<With<NoAutomaticBatching>>,
+    )>,
     images: Res<RenderAssets<Image>>,
     mut views: Query<(
         &ExtractedView,
@@ -395,8 +424,12 @@ pub fn queue_material_meshes<M: Material>(
         mut transparent_phase,
     ) in &mut views
     {
-        let draw_opaque_pbr = opaque_draw_functions.read().id::<DrawMaterial<M>>();
-        let draw_alpha_mask_pbr = alpha_mask_draw_functions.read().id::<DrawMaterial<M>>();
+        let draw_opaque_pbr = opaque_draw_functions
+            .read()
+            .id::<DrawMaterialInstanced<M>>();
+        let draw_alpha_mask_pbr = alpha_mask_draw_functions
+            .read()
+            .id::<DrawMaterialInstanced<M>>();
         let draw_transparent_pbr = transparent_draw_functions.read().id::<DrawMaterial<M>>();
 
         let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
@@ -435,7 +468,7 @@ pub fn queue_material_meshes<M: Material>(
 
         let rangefinder = view.rangefinder3d();
         for visible_entity in &visible_entities.entities {
-            if let Ok((material_handle, mesh_handle, mesh_uniform)) =
+            if let Ok((material_handle, mesh_handle, mesh_uniform, no_automatic_batching)) =
                 material_meshes.get(*visible_entity)
             {
                 if let (Some(mesh), Some(material)) = (
@@ -460,6 +493,17 @@ pub fn queue_material_meshes<M: Material>(
                         _ => (),
                     }
 
+                    let batch_key = instance_batch_key(
+                        no_automatic_batching.is_none()
+                            && material_pipeline.vertex_shader.is_none()
+                            && material_pipeline.supports_instancing,
+                        (material_handle, material),
+                        (mesh_handle, mesh),
+                    );
+                    if batch_key.is_some() {
+                        mesh_key |= MeshPipelineKey::INSTANCED;
+                    }
+
                     let pipeline_id = pipelines.specialize(
                         &pipeline_cache,
                         &material_pipeline,
@@ -486,6 +530,8 @@ pub fn queue_material_meshes<M: Material>(
                                 draw_function: draw_opaque_pbr,
                                 pipeline: pipeline_id,
                                 distance,
+                                batch_key,
+                                batch_range: None,
                             });
                         }
                         AlphaMode::Mask(_) => {
@@ -494,6 +540,8 @@ pub fn queue_material_meshes<M: Material>(
                                 draw_function: draw_alpha_mask_pbr,
                                 pipeline: pipeline_id,
                                 distance,
+                                batch_key,
+                                batch_range: None,
                             });
                         }
                         AlphaMode::Blend
@@ -514,6 +562,28 @@ pub fn queue_material_meshes<M: Material>(
     }
 }
 
+/// Returns the [`InstanceBatchKey`] of a mesh entity, if it can be drawn with an instanced draw call.
+///
+/// Opaque and alpha masked meshes sharing the same mesh and material are drawn with a single
+/// instanced draw call. This requires the instance attributes of the default vertex shaders,
+/// which is up to the caller to check with `allow_instancing`, and doesn't support skinning.
+pub(crate) fn instance_batch_key<M: Material>(
+    allow_instancing: bool,
+    (material_handle, material): (&Handle<M>, &PreparedMaterial<M>),
+    (mesh_handle, mesh): (&Handle<Mesh>, &GpuMesh),
+) -> Option<InstanceBatchKey> {
+    (allow_instancing
+        && !mesh.layout.contains(Mesh::ATTRIBUTE_JOINT_INDEX)
+        && matches!(
+            material.properties.alpha_mode,
+            AlphaMode::Opaque | AlphaMode::Mask(_)
+        ))
+    .then(|| InstanceBatchKey {
+        mesh: mesh_handle.id(),
+        material: material_handle.id(),
+    })
+}
+
 /// Common [`Material`] properties, calculated for a specific material instance.
 pub struct MaterialProperties {
     /// The [`AlphaMode`] of this material.
@@ -575,6 +645,7 @@ pub fn extract_materials<M: Material>(
                 changed_assets.remove(handle);
                 removed.push(handle.clone_weak());
             }
+            AssetEvent::Evicted { .. } | AssetEvent::DependencyModified { .. } => {}
         }
     }
 
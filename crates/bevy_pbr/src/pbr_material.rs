@@ -460,6 +460,11 @@ impl Material for StandardMaterial {
         PBR_SHADER_HANDLE.typed().into()
     }
 
+    // Neither `pbr.wgsl` nor `pbr_prepass.wgsl` read `mesh` in the fragment stage.
+    fn supports_instancing() -> bool {
+        true
+    }
+
     #[inline]
     fn alpha_mode(&self) -> AlphaMode {
         self.alpha_mode
@@ -44,8 +44,9 @@ use bevy_render::{
 use bevy_utils::{tracing::error, HashMap};
 
 use crate::{
-    AlphaMode, DrawMesh, Material, MaterialPipeline, MaterialPipelineKey, MeshPipeline,
-    MeshPipelineKey, MeshUniform, RenderMaterials, SetMaterialBindGroup, SetMeshBindGroup,
+    instance_batch_key, AlphaMode, DrawMeshInstanced, Material, MaterialPipeline,
+    MaterialPipelineKey, MeshInstance, MeshPipeline, MeshPipelineKey, MeshUniform,
+    NoAutomaticBatching, RenderMaterials, SetMaterialBindGroup, SetMeshBindGroup,
     MAX_CASCADES_PER_LIGHT, MAX_DIRECTIONAL_LIGHTS,
 };
 
@@ -294,7 +295,11 @@ where
             bind_group_layout.insert(2, self.mesh_layout.clone());
         }
 
-        let vertex_buffer_layout = layout.get_layout(&vertex_attributes)?;
+        let mut vertex_buffers = vec![layout.get_layout(&vertex_attributes)?];
+        if key.mesh_key.contains(MeshPipelineKey::INSTANCED) {
+            shader_defs.push("MESH_INSTANCED".into());
+            vertex_buffers.push(MeshInstance::vertex_buffer_layout());
+        }
 
         // The fragment shader is only used when the normal prepass is enabled
         // or the material uses alpha cutoff values and doesn't rely on the standard prepass shader
@@ -343,7 +348,7 @@ where
                 shader: vert_shader_handle,
                 entry_point: "vertex".into(),
                 shader_defs,
-                buffers: vec![vertex_buffer_layout],
+                buffers: vertex_buffers,
             },
             fragment,
             layout: bind_group_layout,
@@ -617,7 +622,12 @@ pub fn queue_prepass_material_meshes<M: Material>(
     msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials<M>>,
-    material_meshes: Query<(&Handle<M>, &Handle<Mesh>, &MeshUniform)>,
+    material_meshes: Query<(
+        &Handle<M>,
+        &Handle<Mesh>,
+        &MeshUniform,
+        Option<With<NoAutomaticBatching>>,
+    )>,
     mut views: Query<(
         &ExtractedView,
         &VisibleEntities,
@@ -657,7 +667,7 @@ pub fn queue_prepass_material_meshes<M: Material>(
         let rangefinder = view.rangefinder3d();
 
         for visible_entity in &visible_entities.entities {
-            let Ok((material_handle, mesh_handle, mesh_uniform)) = material_meshes.get(*visible_entity) else {
+            let Ok((material_handle, mesh_handle, mesh_uniform, no_automatic_batching)) = material_meshes.get(*visible_entity) else {
                 continue;
             };
 
@@ -680,6 +690,17 @@ pub fn queue_prepass_material_meshes<M: Material>(
                 | AlphaMode::Multiply => continue,
             }
 
+            let batch_key = instance_batch_key(
+                no_automatic_batching.is_none()
+                    && prepass_pipeline.material_vertex_shader.is_none()
+                    && prepass_pipeline.material_pipeline.supports_instancing,
+                (material_handle, material),
+                (mesh_handle, mesh),
+            );
+            if batch_key.is_some() {
+                mesh_key |= MeshPipelineKey::INSTANCED;
+            }
+
             let pipeline_id = pipelines.specialize(
                 &pipeline_cache,
                 &prepass_pipeline,
@@ -706,6 +727,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
                         draw_function: opaque_draw_prepass,
                         pipeline_id,
                         distance,
+                        batch_key,
+                        batch_range: None,
                     });
                 }
                 AlphaMode::Mask(_) => {
@@ -714,6 +737,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
                         draw_function: alpha_mask_draw_prepass,
                         pipeline_id,
                         distance,
+                        batch_key,
+                        batch_range: None,
                     });
                 }
                 AlphaMode::Blend
@@ -754,5 +779,5 @@ pub type DrawPrepass<M> = (
     SetPrepassViewBindGroup<0>,
     SetMaterialBindGroup<M, 1>,
     SetMeshBindGroup<2>,
-    DrawMesh,
+    DrawMeshInstanced,
 );
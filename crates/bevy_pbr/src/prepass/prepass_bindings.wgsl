@@ -11,8 +11,13 @@ var<uniform> globals: Globals;
 
 // Material bindings will be in @group(1)
 
+#ifdef MESH_INSTANCED
+// Assigned from the per instance vertex attributes by the vertex shader.
+var<private> mesh: Mesh;
+#else
 @group(2) @binding(0)
 var<uniform> mesh: Mesh;
+#endif
 
 #ifdef SKINNED
 @group(2) @binding(1)
@@ -1,8 +1,9 @@
 use crate::{
-    directional_light_order, point_light_order, AlphaMode, AmbientLight, Cascade,
-    CascadeShadowConfig, Cascades, CascadesVisibleEntities, Clusters, CubemapVisibleEntities,
-    DirectionalLight, DirectionalLightShadowMap, DrawPrepass, EnvironmentMapLight,
-    GlobalVisiblePointLights, Material, MaterialPipelineKey, MeshPipeline, MeshPipelineKey,
+    directional_light_order, instance_batch_key, point_light_order, AlphaMode, AmbientLight,
+    Cascade, CascadeShadowConfig, Cascades, CascadesVisibleEntities, Clusters,
+    CubemapVisibleEntities, DirectionalLight, DirectionalLightShadowMap,
+    DirectionalLightShadowSettings, DrawPrepass, EnvironmentMapLight, GlobalVisiblePointLights,
+    Material, MaterialPipelineKey, MeshPipeline, MeshPipelineKey, NoAutomaticBatching,
     NotShadowCaster, PointLight, PointLightShadowMap, PrepassPipeline, RenderMaterials, SpotLight,
     VisiblePointLights,
 };
@@ -17,7 +18,8 @@ use bevy_render::{
     render_asset::RenderAssets,
     render_graph::{Node, NodeRunError, RenderGraphContext, SlotInfo, SlotType},
     render_phase::{
-        CachedRenderPipelinePhaseItem, DrawFunctionId, DrawFunctions, PhaseItem, RenderPhase,
+        add_instances_to_batch, BatchResult, BatchedPhaseItem, CachedRenderPipelinePhaseItem,
+        DrawFunctionId, DrawFunctions, InstanceBatchKey, PhaseItem, RenderPhase,
     },
     render_resource::*,
     renderer::{RenderContext, RenderDevice, RenderQueue},
@@ -33,6 +35,7 @@ use bevy_utils::{
 use std::{
     hash::Hash,
     num::{NonZeroU32, NonZeroU64},
+    ops::Range,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -68,6 +71,7 @@ pub struct ExtractedDirectionalLight {
     shadow_normal_bias: f32,
     cascade_shadow_config: CascadeShadowConfig,
     cascades: HashMap<Entity, Vec<Cascade>>,
+    shadow_map_size: u32,
 }
 
 #[derive(Copy, Clone, ShaderType, Default, Debug)]
@@ -189,6 +193,8 @@ pub struct GpuDirectionalLight {
     num_cascades: u32,
     cascades_overlap_proportion: f32,
     depth_texture_base_index: u32,
+    // The proportion of the shadow map texture covered by the shadow maps of this light
+    shadow_map_uv_scale: f32,
 }
 
 // NOTE: These must match the bit flags in bevy_pbr/src/render/mesh_view_types.wgsl!
@@ -326,6 +332,7 @@ pub fn extract_lights(
                 &CascadeShadowConfig,
                 &GlobalTransform,
                 &ComputedVisibility,
+                Option<&DirectionalLightShadowSettings>,
             ),
             Without<SpotLight>,
         >,
@@ -440,6 +447,7 @@ pub fn extract_lights(
         cascade_config,
         transform,
         visibility,
+        settings,
     ) in directional_lights.iter()
     {
         if !visibility.is_visible() {
@@ -454,11 +462,25 @@ pub fn extract_lights(
                 illuminance: directional_light.illuminance,
                 transform: *transform,
                 shadows_enabled: directional_light.shadows_enabled,
-                shadow_depth_bias: directional_light.shadow_depth_bias,
+                shadow_depth_bias: DirectionalLightShadowSettings::depth_bias(
+                    settings,
+                    directional_light,
+                ),
                 // The factor of SQRT_2 is for the worst-case diagonal offset
-                shadow_normal_bias: directional_light.shadow_normal_bias * std::f32::consts::SQRT_2,
-                cascade_shadow_config: cascade_config.clone(),
+                shadow_normal_bias: DirectionalLightShadowSettings::normal_bias(
+                    settings,
+                    directional_light,
+                ) * std::f32::consts::SQRT_2,
+                cascade_shadow_config: DirectionalLightShadowSettings::cascades(
+                    settings,
+                    cascade_config,
+                )
+                .clone(),
                 cascades: cascades.cascades.clone(),
+                shadow_map_size: DirectionalLightShadowSettings::clamped_size(
+                    settings,
+                    &directional_light_shadow_map,
+                ) as u32,
             },
             render_visible_entities,
         ));
@@ -879,6 +901,8 @@ pub fn prepare_lights(
             num_cascades: num_cascades as u32,
             cascades_overlap_proportion: light.cascade_shadow_config.overlap_proportion,
             depth_texture_base_index: num_directional_cascades_enabled as u32,
+            shadow_map_uv_scale: light.shadow_map_size as f32
+                / directional_light_shadow_map.size as f32,
         };
         if index < directional_shadow_enabled_count {
             num_directional_cascades_enabled += num_cascades;
@@ -1133,8 +1157,8 @@ pub fn prepare_lights(
                             viewport: UVec4::new(
                                 0,
                                 0,
-                                directional_light_shadow_map.size as u32,
-                                directional_light_shadow_map.size as u32,
+                                light.shadow_map_size,
+                                light.shadow_map_size,
                             ),
                             transform: GlobalTransform::from(cascade.view_transform),
                             projection: cascade.projection,
@@ -1547,7 +1571,10 @@ pub fn prepare_clusters(
 pub fn queue_shadows<M: Material>(
     shadow_draw_functions: Res<DrawFunctions<Shadow>>,
     prepass_pipeline: Res<PrepassPipeline<M>>,
-    casting_meshes: Query<(&Handle<Mesh>, &Handle<M>), Without<NotShadowCaster>>,
+    casting_meshes: Query<
+        (&Handle<Mesh>, &Handle<M>, Option<With<NoAutomaticBatching>>),
+        Without<NotShadowCaster>,
+    >,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials<M>>,
     mut pipelines: ResMut<SpecializedMeshPipelines<PrepassPipeline<M>>>,
@@ -1592,7 +1619,9 @@ pub fn queue_shadows<M: Material>(
             // NOTE: Lights with shadow mapping disabled will have no visible entities
             // so no meshes will be queued
             for entity in visible_entities.iter().copied() {
-                if let Ok((mesh_handle, material_handle)) = casting_meshes.get(entity) {
+                if let Ok((mesh_handle, material_handle, no_automatic_batching)) =
+                    casting_meshes.get(entity)
+                {
                     if let (Some(mesh), Some(material)) = (
                         render_meshes.get(mesh_handle),
                         render_materials.get(material_handle),
@@ -1613,6 +1642,16 @@ pub fn queue_shadows<M: Material>(
                             }
                             _ => {}
                         }
+                        let batch_key = instance_batch_key(
+                            no_automatic_batching.is_none()
+                                && prepass_pipeline.material_vertex_shader.is_none()
+                                && prepass_pipeline.material_pipeline.supports_instancing,
+                            (material_handle, material),
+                            (mesh_handle, mesh),
+                        );
+                        if batch_key.is_some() {
+                            mesh_key |= MeshPipelineKey::INSTANCED;
+                        }
                         let pipeline_id = pipelines.specialize(
                             &pipeline_cache,
                             &prepass_pipeline,
@@ -1636,6 +1675,8 @@ pub fn queue_shadows<M: Material>(
                             pipeline: pipeline_id,
                             entity,
                             distance: 0.0, // TODO: sort back-to-front
+                            batch_key,
+                            batch_range: None,
                         });
                     }
                 }
@@ -1649,6 +1690,11 @@ pub struct Shadow {
     pub entity: Entity,
     pub pipeline: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    /// Set if the item can be drawn with the same instanced draw call as the other items with the
+    /// same key.
+    pub batch_key: Option<InstanceBatchKey>,
+    /// The range of instances drawn by this item, which is assigned after sorting.
+    pub batch_range: Option<Range<u32>>,
 }
 
 impl PhaseItem for Shadow {
@@ -1674,7 +1720,9 @@ impl PhaseItem for Shadow {
         // The shadow phase is sorted by pipeline id for performance reasons.
         // Grouping all draw commands using the same pipeline together performs
         // better than rebinding everything at a high rate.
-        radsort::sort_by_key(items, |item| item.pipeline.id());
+        // Within each pipeline, the items which can be drawn with the same instanced draw call
+        // are grouped together.
+        items.sort_unstable_by_key(|item| (item.pipeline.id(), item.batch_key));
     }
 }
 
@@ -1685,9 +1733,36 @@ impl CachedRenderPipelinePhaseItem for Shadow {
     }
 }
 
+impl BatchedPhaseItem for Shadow {
+    #[inline]
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+
+    /// Merges the instances of consecutive items with the same [`InstanceBatchKey`].
+    #[inline]
+    fn add_to_batch(&mut self, other: &Self) -> BatchResult {
+        add_instances_to_batch(
+            (self.batch_key, self.pipeline, self.draw_function),
+            &mut self.batch_range,
+            (other.batch_key, other.pipeline, other.draw_function),
+            &other.batch_range,
+        )
+    }
+}
+
 pub struct ShadowPassNode {
     main_view_query: QueryState<&'static ViewLightEntities>,
-    view_light_query: QueryState<(&'static ShadowView, &'static RenderPhase<Shadow>)>,
+    view_light_query: QueryState<(
+        &'static ShadowView,
+        &'static ExtractedView,
+        &'static RenderPhase<Shadow>,
+    )>,
 }
 
 impl ShadowPassNode {
@@ -1720,7 +1795,7 @@ impl Node for ShadowPassNode {
         let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
         if let Ok(view_lights) = self.main_view_query.get_manual(world, view_entity) {
             for view_light_entity in view_lights.lights.iter().copied() {
-                let (view_light, shadow_phase) = self
+                let (view_light, extracted_view, shadow_phase) = self
                     .view_light_query
                     .get_manual(world, view_light_entity)
                     .unwrap();
@@ -1742,6 +1817,9 @@ impl Node for ShadowPassNode {
                             stencil_ops: None,
                         }),
                     });
+                // Lights with a lower resolution only render into a part of the texture
+                let viewport = extracted_view.viewport.as_vec4();
+                render_pass.set_viewport(viewport.x, viewport.y, viewport.z, viewport.w, 0.0, 1.0);
 
                 shadow_phase.render(&mut render_pass, world, view_light_entity);
             }
@@ -1,13 +1,14 @@
 use crate::{
     environment_map, prepass, EnvironmentMapLight, FogMeta, GlobalLightMeta, GpuFog, GpuLights,
-    GpuPointLights, LightMeta, NotShadowCaster, NotShadowReceiver, ShadowSamplers,
+    GpuPointLights, LightMeta, NotShadowCaster, NotShadowReceiver, Shadow, ShadowSamplers,
     ViewClusterBindings, ViewFogUniformOffset, ViewLightsUniformOffset, ViewShadowBindings,
     CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT, MAX_CASCADES_PER_LIGHT, MAX_DIRECTIONAL_LIGHTS,
 };
 use bevy_app::{IntoSystemAppConfigs, Plugin};
 use bevy_asset::{load_internal_asset, Assets, Handle, HandleUntyped};
 use bevy_core_pipeline::{
-    prepass::ViewPrepassTextures,
+    core_3d::{AlphaMask3d, Opaque3d},
+    prepass::{AlphaMask3dPrepass, Opaque3dPrepass, ViewPrepassTextures},
     tonemapping::{
         get_lut_bind_group_layout_entries, get_lut_bindings, Tonemapping, TonemappingLuts,
     },
@@ -17,10 +18,13 @@ use bevy_ecs::{
     query::ROQueryItem,
     system::{lifetimeless::*, SystemParamItem, SystemState},
 };
-use bevy_math::{Mat3A, Mat4, Vec2};
-use bevy_reflect::TypeUuid;
+use bevy_math::{Mat3A, Mat4, Vec2, Vec4};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeUuid};
 use bevy_render::{
-    extract_component::{ComponentUniforms, DynamicUniformIndex, UniformComponentPlugin},
+    extract_component::{
+        ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+        UniformComponentPlugin,
+    },
     globals::{GlobalsBuffer, GlobalsUniform},
     mesh::{
         skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
@@ -28,7 +32,10 @@ use bevy_render::{
     },
     prelude::Msaa,
     render_asset::RenderAssets,
-    render_phase::{PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass},
+    render_phase::{
+        sort_phase_system, BatchedPhaseItem, InstanceBatchKey, PhaseItem, RenderCommand,
+        RenderCommandResult, RenderPhase, TrackedRenderPass,
+    },
     render_resource::*,
     renderer::{RenderDevice, RenderQueue},
     texture::{
@@ -39,6 +46,7 @@ use bevy_render::{
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
 use bevy_transform::components::GlobalTransform;
+use bytemuck::{Pod, Zeroable};
 use std::num::NonZeroU64;
 
 #[derive(Default)]
@@ -101,26 +109,59 @@ impl Plugin for MeshRenderPlugin {
         load_internal_asset!(app, MESH_SHADER_HANDLE, "mesh.wgsl", Shader::from_wgsl);
         load_internal_asset!(app, SKINNING_HANDLE, "skinning.wgsl", Shader::from_wgsl);
 
-        app.add_plugin(UniformComponentPlugin::<MeshUniform>::default());
+        app.register_type::<MeshInstanceData>()
+            .register_type::<NoAutomaticBatching>()
+            .add_plugin(UniformComponentPlugin::<MeshUniform>::default())
+            .add_plugin(ExtractComponentPlugin::<NoAutomaticBatching>::default());
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<MeshPipeline>()
                 .init_resource::<SkinnedMeshUniform>()
+                .init_resource::<MeshInstanceBuffer>()
                 .add_systems((extract_meshes, extract_skinned_meshes).in_schedule(ExtractSchedule))
                 .add_systems((
                     prepare_skinned_meshes.in_set(RenderSet::Prepare),
                     queue_mesh_bind_group.in_set(RenderSet::Queue),
                     queue_mesh_view_bind_groups.in_set(RenderSet::Queue),
+                    batch_mesh_instances
+                        .in_set(RenderSet::PhaseSort)
+                        .after(sort_phase_system::<Opaque3d>)
+                        .after(sort_phase_system::<AlphaMask3d>)
+                        .after(sort_phase_system::<Opaque3dPrepass>)
+                        .after(sort_phase_system::<AlphaMask3dPrepass>)
+                        .after(sort_phase_system::<Shadow>),
                 ));
         }
     }
 }
 
+/// User data of a mesh entity, which is available as `mesh.instance_data` in the shaders of its
+/// material.
+///
+/// This is mainly useful to vary the appearance of meshes drawn with a single instanced draw call,
+/// e.g. to tint each of them differently. In fragment shaders of instanced draws, the data is only
+/// available as the `instance_data` output of the mesh vertex shader, see
+/// [`Material::supports_instancing`](crate::Material::supports_instancing).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct MeshInstanceData(pub Vec4);
+
+/// Add this component to a mesh entity to always draw it with a separate draw call.
+///
+/// Otherwise, opaque and alpha masked meshes which share the same mesh and material are drawn with
+/// a single instanced draw call in the main pass, the prepass and the shadow passes, unless they
+/// are skinned, their material uses a custom vertex shader or it doesn't
+/// [support instancing](crate::Material::supports_instancing).
+#[derive(Component, Clone, Copy, Debug, Default, ExtractComponent, Reflect)]
+#[reflect(Component, Default)]
+pub struct NoAutomaticBatching;
+
 #[derive(Component, ShaderType, Clone)]
 pub struct MeshUniform {
     pub transform: Mat4,
     pub inverse_transpose_model: Mat4,
+    pub instance_data: Vec4,
     pub flags: u32,
 }
 
@@ -147,6 +188,7 @@ pub fn extract_meshes(
             &ComputedVisibility,
             &GlobalTransform,
             &Handle<Mesh>,
+            Option<&MeshInstanceData>,
             Option<With<NotShadowReceiver>>,
             Option<With<NotShadowCaster>>,
         )>,
@@ -156,7 +198,7 @@ pub fn extract_meshes(
     let mut not_caster_commands = Vec::with_capacity(*prev_not_caster_commands_len);
     let visible_meshes = meshes_query.iter().filter(|(_, vis, ..)| vis.is_visible());
 
-    for (entity, _, transform, handle, not_receiver, not_caster) in visible_meshes {
+    for (entity, _, transform, handle, instance_data, not_receiver, not_caster) in visible_meshes {
         let transform = transform.compute_matrix();
         let mut flags = if not_receiver.is_some() {
             MeshFlags::empty()
@@ -170,6 +212,7 @@ pub fn extract_meshes(
             flags: flags.bits,
             transform,
             inverse_transpose_model: transform.inverse().transpose(),
+            instance_data: instance_data.map_or(Vec4::ZERO, |instance_data| instance_data.0),
         };
         if not_caster.is_some() {
             not_caster_commands.push((entity, (handle.clone_weak(), uniform, NotShadowCaster)));
@@ -572,6 +615,7 @@ bitflags::bitflags! {
         const ALPHA_MASK                        = (1 << 5);
         const ENVIRONMENT_MAP                   = (1 << 6);
         const DEPTH_CLAMP_ORTHO                 = (1 << 7);
+        const INSTANCED                         = (1 << 8);
         const BLEND_RESERVED_BITS               = Self::BLEND_MASK_BITS << Self::BLEND_SHIFT_BITS; // ← Bitmask reserving bits for the blend state
         const BLEND_OPAQUE                      = (0 << Self::BLEND_SHIFT_BITS);                   // ← Values are just sequential within the mask, and can range from 0 to 3
         const BLEND_PREMULTIPLIED_ALPHA         = (1 << Self::BLEND_SHIFT_BITS);                   //
@@ -707,7 +751,11 @@ impl SpecializedMeshPipeline for MeshPipeline {
             bind_group_layout.push(self.mesh_layout.clone());
         };
 
-        let vertex_buffer_layout = layout.get_layout(&vertex_attributes)?;
+        let mut vertex_buffers = vec![layout.get_layout(&vertex_attributes)?];
+        if key.contains(MeshPipelineKey::INSTANCED) {
+            shader_defs.push("MESH_INSTANCED".into());
+            vertex_buffers.push(MeshInstance::vertex_buffer_layout());
+        }
 
         let (label, blend, depth_write_enabled);
         let pass = key.intersection(MeshPipelineKey::BLEND_RESERVED_BITS);
@@ -793,7 +841,7 @@ impl SpecializedMeshPipeline for MeshPipeline {
                 shader: MESH_SHADER_HANDLE.typed::<Shader>(),
                 entry_point: "vertex".into(),
                 shader_defs: shader_defs.clone(),
-                buffers: vec![vertex_buffer_layout],
+                buffers: vertex_buffers,
             },
             fragment: Some(FragmentState {
                 shader: MESH_SHADER_HANDLE.typed::<Shader>(),
@@ -1173,13 +1221,187 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh {
     }
 }
 
+/// The per instance vertex data of a mesh drawn with [`MeshPipelineKey::INSTANCED`], which is
+/// taken from its [`MeshUniform`].
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct MeshInstance {
+    pub model: [[f32; 4]; 4],
+    /// The first three columns of the inverse transpose model matrix.
+    pub inverse_transpose_model: [[f32; 4]; 3],
+    pub instance_data: [f32; 4],
+    pub flags: u32,
+}
+
+impl MeshInstance {
+    /// The first shader location of the per instance vertex attributes.
+    pub const SHADER_LOCATION: u32 = 7;
+
+    /// Returns the layout of the instance vertex buffer, which is bound to slot 1.
+    pub fn vertex_buffer_layout() -> VertexBufferLayout {
+        let mut attributes: Vec<_> = (0..8)
+            .map(|i| VertexAttribute {
+                format: VertexFormat::Float32x4,
+                offset: i * VertexFormat::Float32x4.size(),
+                shader_location: Self::SHADER_LOCATION + i as u32,
+            })
+            .collect();
+        attributes.push(VertexAttribute {
+            format: VertexFormat::Uint32,
+            offset: 8 * VertexFormat::Float32x4.size(),
+            shader_location: Self::SHADER_LOCATION + 8,
+        });
+        VertexBufferLayout {
+            array_stride: std::mem::size_of::<MeshInstance>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes,
+        }
+    }
+}
+
+impl From<&MeshUniform> for MeshInstance {
+    fn from(uniform: &MeshUniform) -> Self {
+        let [x, y, z, _] = uniform.inverse_transpose_model.to_cols_array_2d();
+        Self {
+            model: uniform.transform.to_cols_array_2d(),
+            inverse_transpose_model: [x, y, z],
+            instance_data: uniform.instance_data.to_array(),
+            flags: uniform.flags,
+        }
+    }
+}
+
+/// The [`MeshInstance`]s of the instanced draws of all views, written by
+/// [`batch_mesh_instances`].
+#[derive(Resource)]
+pub struct MeshInstanceBuffer {
+    pub buffer: BufferVec<MeshInstance>,
+}
+
+impl Default for MeshInstanceBuffer {
+    fn default() -> Self {
+        Self {
+            buffer: BufferVec::new(BufferUsages::VERTEX),
+        }
+    }
+}
+
+/// Writes the [`MeshInstance`]s of the phase items with a batch key of all views to the
+/// [`MeshInstanceBuffer`], and merges consecutive items drawing the same mesh with the same
+/// material into a single instanced draw.
+#[allow(clippy::too_many_arguments)]
+pub fn batch_mesh_instances(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut instance_buffer: ResMut<MeshInstanceBuffer>,
+    mesh_uniforms: Query<&MeshUniform>,
+    mut opaque_phases: Query<&mut RenderPhase<Opaque3d>>,
+    mut alpha_mask_phases: Query<&mut RenderPhase<AlphaMask3d>>,
+    mut opaque_prepass_phases: Query<&mut RenderPhase<Opaque3dPrepass>>,
+    mut alpha_mask_prepass_phases: Query<&mut RenderPhase<AlphaMask3dPrepass>>,
+    mut shadow_phases: Query<&mut RenderPhase<Shadow>>,
+) {
+    let buffer = &mut instance_buffer.buffer;
+    buffer.clear();
+    for mut phase in &mut opaque_phases {
+        batch_phase_instances(&mut phase, |item| item.batch_key, &mesh_uniforms, buffer);
+    }
+    for mut phase in &mut alpha_mask_phases {
+        batch_phase_instances(&mut phase, |item| item.batch_key, &mesh_uniforms, buffer);
+    }
+    for mut phase in &mut opaque_prepass_phases {
+        batch_phase_instances(&mut phase, |item| item.batch_key, &mesh_uniforms, buffer);
+    }
+    for mut phase in &mut alpha_mask_prepass_phases {
+        batch_phase_instances(&mut phase, |item| item.batch_key, &mesh_uniforms, buffer);
+    }
+    for mut phase in &mut shadow_phases {
+        batch_phase_instances(&mut phase, |item| item.batch_key, &mesh_uniforms, buffer);
+    }
+    buffer.write_buffer(&render_device, &render_queue);
+}
+
+fn batch_phase_instances<I: BatchedPhaseItem>(
+    phase: &mut RenderPhase<I>,
+    batch_key: impl Fn(&I) -> Option<InstanceBatchKey>,
+    mesh_uniforms: &Query<&MeshUniform>,
+    buffer: &mut BufferVec<MeshInstance>,
+) {
+    for item in &mut phase.items {
+        if batch_key(item).is_none() {
+            continue;
+        }
+        if let Ok(mesh_uniform) = mesh_uniforms.get(item.entity()) {
+            let index = buffer.push(mesh_uniform.into()) as u32;
+            *item.batch_range_mut() = Some(index..index + 1);
+        }
+    }
+    phase.batch();
+}
+
+/// Like [`DrawMesh`], but draws the batch range of the item as instances from the
+/// [`MeshInstanceBuffer`] if it is set.
+pub struct DrawMeshInstanced;
+impl<P: BatchedPhaseItem> RenderCommand<P> for DrawMeshInstanced {
+    type Param = (SRes<RenderAssets<Mesh>>, SRes<MeshInstanceBuffer>);
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = Read<Handle<Mesh>>;
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        mesh_handle: ROQueryItem<'_, Self::ItemWorldQuery>,
+        (meshes, instance_buffer): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(gpu_mesh) = meshes.into_inner().get(mesh_handle) else {
+            return RenderCommandResult::Failure;
+        };
+        let instances = match item.batch_range() {
+            Some(batch_range) => {
+                let Some(buffer) = instance_buffer.into_inner().buffer.buffer() else {
+                    return RenderCommandResult::Failure;
+                };
+                pass.set_vertex_buffer(1, buffer.slice(..));
+                batch_range.clone()
+            }
+            None => 0..1,
+        };
+        pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
+        match &gpu_mesh.buffer_info {
+            GpuBufferInfo::Indexed {
+                buffer,
+                index_format,
+                count,
+            } => {
+                pass.set_index_buffer(buffer.slice(..), 0, *index_format);
+                pass.draw_indexed(0..*count, 0, instances);
+            }
+            GpuBufferInfo::NonIndexed { vertex_count } => {
+                pass.draw(0..*vertex_count, instances);
+            }
+        }
+        RenderCommandResult::Success
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::MeshPipelineKey;
+    use super::{MeshInstance, MeshPipelineKey};
     #[test]
     fn mesh_key_msaa_samples() {
         for i in [1, 2, 4, 8, 16, 32, 64, 128] {
             assert_eq!(MeshPipelineKey::from_msaa_samples(i).msaa_samples(), i);
         }
     }
+
+    #[test]
+    fn mesh_instance_layout() {
+        let layout = MeshInstance::vertex_buffer_layout();
+        assert_eq!(layout.array_stride, 132);
+        let flags = layout.attributes.last().unwrap();
+        assert_eq!(flags.offset, 128);
+        // The last location must be below the downlevel limit of 16 vertex attributes.
+        assert_eq!(flags.shader_location, 15);
+    }
 }
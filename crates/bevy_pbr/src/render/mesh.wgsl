@@ -24,6 +24,17 @@ struct Vertex {
     @location(5) joint_indices: vec4<u32>,
     @location(6) joint_weights: vec4<f32>,
 #endif
+#ifdef MESH_INSTANCED
+    @location(7) instance_model_0: vec4<f32>,
+    @location(8) instance_model_1: vec4<f32>,
+    @location(9) instance_model_2: vec4<f32>,
+    @location(10) instance_model_3: vec4<f32>,
+    @location(11) instance_inverse_transpose_model_0: vec4<f32>,
+    @location(12) instance_inverse_transpose_model_1: vec4<f32>,
+    @location(13) instance_inverse_transpose_model_2: vec4<f32>,
+    @location(14) instance_data: vec4<f32>,
+    @location(15) instance_flags: u32,
+#endif
 };
 
 struct VertexOutput {
@@ -35,6 +46,27 @@ struct VertexOutput {
 fn vertex(vertex: Vertex) -> VertexOutput {
     var out: VertexOutput;
 
+#ifdef MESH_INSTANCED
+    mesh = Mesh(
+        mat4x4<f32>(
+            vertex.instance_model_0,
+            vertex.instance_model_1,
+            vertex.instance_model_2,
+            vertex.instance_model_3
+        ),
+        mat4x4<f32>(
+            vertex.instance_inverse_transpose_model_0,
+            vertex.instance_inverse_transpose_model_1,
+            vertex.instance_inverse_transpose_model_2,
+            vec4<f32>(0.0, 0.0, 0.0, 1.0)
+        ),
+        vertex.instance_data,
+        vertex.instance_flags
+    );
+    out.instance_flags = vertex.instance_flags;
+    out.instance_data = vertex.instance_data;
+#endif
+
 #ifdef SKINNED
     var model = skin_model(vertex.joint_indices, vertex.joint_weights);
 #else
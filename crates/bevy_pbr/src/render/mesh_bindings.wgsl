@@ -2,8 +2,13 @@
 
 #import bevy_pbr::mesh_types
 
+#ifdef MESH_INSTANCED
+// Assigned from the per instance vertex attributes by the vertex shader.
+var<private> mesh: Mesh;
+#else
 @group(2) @binding(0)
 var<uniform> mesh: Mesh;
+#endif
 #ifdef SKINNED
 @group(2) @binding(1)
 var<uniform> joint_matrices: SkinnedMesh;
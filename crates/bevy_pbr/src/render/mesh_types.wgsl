@@ -3,6 +3,8 @@
 struct Mesh {
     model: mat4x4<f32>,
     inverse_transpose_model: mat4x4<f32>,
+    // User data of the mesh instance, see the `MeshInstanceData` component.
+    instance_data: vec4<f32>,
     // 'flags' is a bit field indicating various options. u32 is 32 bits so we have up to 32 options.
     flags: u32,
 };
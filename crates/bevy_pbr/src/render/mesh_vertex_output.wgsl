@@ -11,3 +11,7 @@
 #ifdef VERTEX_COLORS
 @location(4) color: vec4<f32>,
 #endif
+#ifdef MESH_INSTANCED
+@location(5) @interpolate(flat) instance_flags: u32,
+@location(6) @interpolate(flat) instance_data: vec4<f32>,
+#endif
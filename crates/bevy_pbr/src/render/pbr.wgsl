@@ -92,7 +92,11 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
         pbr_input.V = calculate_view(in.world_position, pbr_input.is_orthographic);
         pbr_input.occlusion = occlusion;
 
+#ifdef MESH_INSTANCED
+        pbr_input.flags = in.instance_flags;
+#else
         pbr_input.flags = mesh.flags;
+#endif
 
         output_color = pbr(pbr_input);
     } else {
@@ -132,7 +132,8 @@ fn sample_cascade(light_id: u32, cascade_index: u32, frag_position: vec4<f32>, s
     // compute texture coordinates for shadow lookup, compensating for the Y-flip difference
     // between the NDC and texture coordinates
     let flip_correction = vec2<f32>(0.5, -0.5);
-    let light_local = offset_position_ndc.xy * flip_correction + vec2<f32>(0.5, 0.5);
+    let light_local = (offset_position_ndc.xy * flip_correction + vec2<f32>(0.5, 0.5))
+        * (*light).shadow_map_uv_scale;
 
     let depth = offset_position_ndc.z;
     // do the lookup, using HW PCF and comparison
@@ -139,6 +139,8 @@ fn queue_wireframes(
                         pipeline: pipeline_id,
                         draw_function: draw_custom,
                         distance: rangefinder.distance(&mesh_uniform.transform),
+                        batch_key: None,
+                        batch_range: None,
                     });
                 }
             };
@@ -25,6 +25,10 @@ const HASH_ATTR: &str = "Hash";
 // but useful to know exist nonetheless
 pub(crate) const REFLECT_DEFAULT: &str = "ReflectDefault";
 
+// The serialization traits, whose type data only exists with the `serialize` feature
+const SERIALIZE_ATTR: &str = "Serialize";
+const DESERIALIZE_ATTR: &str = "Deserialize";
+
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
@@ -158,6 +162,8 @@ impl ReflectTraits {
                         HASH_ATTR => {
                             traits.hash = traits.hash.merge(TraitImpl::Implemented(span))?;
                         }
+                        // Without the `serialize` feature there is no serialization type data to register
+                        SERIALIZE_ATTR | DESERIALIZE_ATTR if !cfg!(feature = "serialize") => {}
                         // We only track reflected idents for traits not considered special
                         _ => {
                             // Create the reflect ident
@@ -17,7 +17,9 @@ pub(crate) fn impl_get_type_registration(
     serialization_denylist: Option<&BitSet<u32>>,
 ) -> proc_macro2::TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let serialization_data = serialization_denylist.map(|denylist| {
+    let serialization_data = serialization_denylist
+        .filter(|_| cfg!(feature = "serialize"))
+        .map(|denylist| {
         let denylist = denylist.into_iter();
         quote! {
             let ignored_indices = ::core::iter::IntoIterator::into_iter([#(#denylist),*]);
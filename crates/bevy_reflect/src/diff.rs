@@ -0,0 +1,434 @@
+use crate::{Reflect, ReflectMut, ReflectRef};
+use thiserror::Error;
+
+/// A structured delta between two reflected values, created by [`Reflect::diff`] and applied
+/// with [`Reflect::apply_diff`].
+///
+/// Only the parts that differ are stored, which makes diffs useful for sending changes over the
+/// network or recording undo steps without serializing the whole value.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Diff, Reflect};
+/// #[derive(Reflect, Clone)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let before = Player {
+///     name: "Ferris".to_string(),
+///     health: 100,
+///     items: vec!["sword".to_string()],
+/// };
+/// let mut after = before.clone();
+/// after.health = 80;
+/// after.items.push("shield".to_string());
+///
+/// let diff = before.diff(&after);
+/// assert!(matches!(diff, Diff::Fields(ref fields) if fields.len() == 2));
+///
+/// let mut value = before.clone();
+/// value.apply_diff(&diff).unwrap();
+/// assert_eq!(value.health, 80);
+/// assert_eq!(value.items, ["sword", "shield"]);
+/// ```
+#[derive(Debug)]
+pub enum Diff {
+    /// The values are equal.
+    Unchanged,
+    /// The value has to be replaced as a whole.
+    ///
+    /// This is the case for changed value types (like `u32` or `String`), enums that changed
+    /// their variant, and values whose types differ.
+    Replaced(Box<dyn Reflect>),
+    /// Some fields of a struct, tuple struct, tuple, array or enum variant changed.
+    ///
+    /// Contains the index and diff of each changed field.
+    Fields(Vec<(usize, Diff)>),
+    /// Elements of a list were changed, inserted or removed.
+    ///
+    /// The changes have to be applied in order, each index refers to the list as modified by the
+    /// changes before it.
+    List(Vec<ListChange>),
+    /// Entries of a map were changed, inserted or removed.
+    Map(Vec<MapChange>),
+}
+
+/// A change to a list, see [`Diff::List`].
+#[derive(Debug)]
+pub enum ListChange {
+    /// The element at the index changed.
+    Changed(usize, Diff),
+    /// The element was inserted at the index.
+    Inserted(usize, Box<dyn Reflect>),
+    /// The element at the index was removed.
+    Removed(usize),
+}
+
+/// A change to a map, see [`Diff::Map`].
+#[derive(Debug)]
+pub enum MapChange {
+    /// The value of the key changed.
+    Changed(Box<dyn Reflect>, Diff),
+    /// The key was inserted with the value.
+    Inserted(Box<dyn Reflect>, Box<dyn Reflect>),
+    /// The key was removed.
+    Removed(Box<dyn Reflect>),
+}
+
+/// An error returned when a [`Diff`] doesn't fit the value it is applied to.
+///
+/// The value may have been partially modified when this error is returned.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ApplyDiffError {
+    #[error("the diff doesn't match the kind of the value")]
+    MismatchedKinds,
+    #[error("the value doesn't have a field or element at the index {0}")]
+    InvalidIndex(usize),
+    #[error("the map doesn't contain a changed or removed key")]
+    MissingKey,
+}
+
+impl Diff {
+    /// Returns `true` if this is [`Diff::Unchanged`].
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Diff::Unchanged)
+    }
+}
+
+pub(crate) fn diff(value: &dyn Reflect, other: &dyn Reflect) -> Diff {
+    if value.type_name() != other.type_name() {
+        return Diff::Replaced(other.clone_value());
+    }
+
+    let replaced = || Diff::Replaced(other.clone_value());
+    match (value.reflect_ref(), other.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => diff_fields(
+            a.field_len(),
+            b.field_len(),
+            |i| a.field_at(i),
+            |i| b.field_at(i),
+        )
+        .unwrap_or_else(replaced),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            diff_fields(a.field_len(), b.field_len(), |i| a.field(i), |i| b.field(i))
+                .unwrap_or_else(replaced)
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            diff_fields(a.field_len(), b.field_len(), |i| a.field(i), |i| b.field(i))
+                .unwrap_or_else(replaced)
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            diff_fields(a.len(), b.len(), |i| a.get(i), |i| b.get(i)).unwrap_or_else(replaced)
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) if a.variant_name() == b.variant_name() => {
+            diff_fields(
+                a.field_len(),
+                b.field_len(),
+                |i| a.field_at(i),
+                |i| b.field_at(i),
+            )
+            .unwrap_or_else(replaced)
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            // trim the common prefix and suffix, so that a single insertion or removal doesn't
+            // show up as a change of all following elements
+            let prefix = (0..a.len().min(b.len()))
+                .take_while(|&i| diff(a.get(i).unwrap(), b.get(i).unwrap()).is_unchanged())
+                .count();
+            let suffix = (0..a.len().min(b.len()) - prefix)
+                .take_while(|&i| {
+                    let (a, b) = (a.get(a.len() - 1 - i), b.get(b.len() - 1 - i));
+                    diff(a.unwrap(), b.unwrap()).is_unchanged()
+                })
+                .count();
+            let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+
+            let mut changes = Vec::new();
+            let mut index = prefix;
+            while index < a_end.min(b_end) {
+                let diff = diff(a.get(index).unwrap(), b.get(index).unwrap());
+                if !diff.is_unchanged() {
+                    changes.push(ListChange::Changed(index, diff));
+                }
+                index += 1;
+            }
+            for index in index..b_end {
+                changes.push(ListChange::Inserted(
+                    index,
+                    b.get(index).unwrap().clone_value(),
+                ));
+            }
+            for _ in b_end..a_end {
+                changes.push(ListChange::Removed(index));
+            }
+
+            if changes.is_empty() {
+                Diff::Unchanged
+            } else {
+                Diff::List(changes)
+            }
+        }
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+            let mut changes = Vec::new();
+            for (key, a_value) in a.iter() {
+                match b.get(key) {
+                    Some(b_value) => {
+                        let diff = diff(a_value, b_value);
+                        if !diff.is_unchanged() {
+                            changes.push(MapChange::Changed(key.clone_value(), diff));
+                        }
+                    }
+                    None => changes.push(MapChange::Removed(key.clone_value())),
+                }
+            }
+            for (key, b_value) in b.iter() {
+                if a.get(key).is_none() {
+                    changes.push(MapChange::Inserted(
+                        key.clone_value(),
+                        b_value.clone_value(),
+                    ));
+                }
+            }
+
+            if changes.is_empty() {
+                Diff::Unchanged
+            } else {
+                Diff::Map(changes)
+            }
+        }
+        (ReflectRef::Value(a), ReflectRef::Value(b)) if a.reflect_partial_eq(b) == Some(true) => {
+            Diff::Unchanged
+        }
+        _ => replaced(),
+    }
+}
+
+/// Diffs the fields of two values of the same kind, see [`Diff::Fields`].
+///
+/// Returns `None` if the number of fields differs.
+fn diff_fields<'a>(
+    a_len: usize,
+    b_len: usize,
+    a: impl Fn(usize) -> Option<&'a dyn Reflect>,
+    b: impl Fn(usize) -> Option<&'a dyn Reflect>,
+) -> Option<Diff> {
+    if a_len != b_len {
+        return None;
+    }
+
+    let fields: Vec<_> = (0..a_len)
+        .map(|index| (index, diff(a(index).unwrap(), b(index).unwrap())))
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .collect();
+
+    if fields.is_empty() {
+        Some(Diff::Unchanged)
+    } else {
+        Some(Diff::Fields(fields))
+    }
+}
+
+pub(crate) fn apply_diff(value: &mut dyn Reflect, diff: &Diff) -> Result<(), ApplyDiffError> {
+    match diff {
+        Diff::Unchanged => {}
+        Diff::Replaced(replacement) => value.apply(&**replacement),
+        Diff::Fields(fields) => {
+            for (index, diff) in fields {
+                let field = match value.reflect_mut() {
+                    ReflectMut::Struct(value) => value.field_at_mut(*index),
+                    ReflectMut::TupleStruct(value) => value.field_mut(*index),
+                    ReflectMut::Tuple(value) => value.field_mut(*index),
+                    ReflectMut::Array(value) => value.get_mut(*index),
+                    ReflectMut::Enum(value) => value.field_at_mut(*index),
+                    _ => return Err(ApplyDiffError::MismatchedKinds),
+                };
+                let field = field.ok_or(ApplyDiffError::InvalidIndex(*index))?;
+                apply_diff(field, diff)?;
+            }
+        }
+        Diff::List(changes) => {
+            let ReflectMut::List(list) = value.reflect_mut() else {
+                return Err(ApplyDiffError::MismatchedKinds);
+            };
+            for change in changes {
+                match change {
+                    ListChange::Changed(index, diff) => {
+                        let element = list
+                            .get_mut(*index)
+                            .ok_or(ApplyDiffError::InvalidIndex(*index))?;
+                        apply_diff(element, diff)?;
+                    }
+                    ListChange::Inserted(index, element) => {
+                        if *index > list.len() {
+                            return Err(ApplyDiffError::InvalidIndex(*index));
+                        }
+                        list.insert(*index, element.clone_value());
+                    }
+                    ListChange::Removed(index) => {
+                        if *index >= list.len() {
+                            return Err(ApplyDiffError::InvalidIndex(*index));
+                        }
+                        list.remove(*index);
+                    }
+                }
+            }
+        }
+        Diff::Map(changes) => {
+            let ReflectMut::Map(map) = value.reflect_mut() else {
+                return Err(ApplyDiffError::MismatchedKinds);
+            };
+            for change in changes {
+                match change {
+                    MapChange::Changed(key, diff) => {
+                        let value = map.get_mut(&**key).ok_or(ApplyDiffError::MissingKey)?;
+                        apply_diff(value, diff)?;
+                    }
+                    MapChange::Inserted(key, value) => {
+                        map.insert_boxed(key.clone_value(), value.clone_value());
+                    }
+                    MapChange::Removed(key) => {
+                        map.remove(&**key).ok_or(ApplyDiffError::MissingKey)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Foo {
+        a: u32,
+        b: (String, Shape),
+        list: Vec<u32>,
+        map: HashMap<u32, String>,
+    }
+
+    fn foo() -> Foo {
+        Foo {
+            a: 1,
+            b: ("b".to_string(), Shape::Circle(1.0)),
+            list: vec![1, 2, 3, 4],
+            map: HashMap::from([(1, "one".to_string()), (2, "two".to_string())]),
+        }
+    }
+
+    fn assert_round_trip(before: &Foo, after: &Foo) -> Diff {
+        let diff = before.diff(after);
+        let mut value = before.clone();
+        value.apply_diff(&diff).unwrap();
+        assert_eq!(&value, after);
+        diff
+    }
+
+    #[test]
+    fn unchanged() {
+        assert!(foo().diff(&foo()).is_unchanged());
+        assert!(assert_round_trip(&foo(), &foo()).is_unchanged());
+    }
+
+    #[test]
+    fn changed_fields() {
+        let mut after = foo();
+        after.a = 2;
+        after.b.1 = Shape::Circle(2.0);
+
+        let diff = assert_round_trip(&foo(), &after);
+        let Diff::Fields(fields) = diff else {
+            panic!("expected changed fields");
+        };
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].0, 0);
+        // only the field of the tuple variant changed
+        assert!(matches!(
+            &fields[1],
+            (1, Diff::Fields(fields)) if matches!(&fields[..], [(1, Diff::Fields(_))])
+        ));
+    }
+
+    #[test]
+    fn changed_variant() {
+        let mut after = foo();
+        after.b.1 = Shape::Rect {
+            width: 1.0,
+            height: 2.0,
+        };
+        assert_round_trip(&foo(), &after);
+    }
+
+    #[test]
+    fn list_changes() {
+        for list in [
+            vec![],
+            vec![1, 2, 3, 4, 5],
+            vec![0, 1, 2, 3, 4],
+            vec![1, 2, 5, 3, 4],
+            vec![1, 4],
+            vec![2, 3, 4],
+            vec![1, 5, 6, 7, 8, 4],
+        ] {
+            let mut after = foo();
+            after.list = list;
+            assert_round_trip(&foo(), &after);
+            assert_round_trip(&after, &foo());
+        }
+
+        let mut after = foo();
+        after.list.insert(2, 10);
+        let Diff::Fields(fields) = foo().diff(&after) else {
+            panic!("expected changed fields");
+        };
+        assert!(matches!(
+            &fields[..],
+            [(2, Diff::List(changes))] if matches!(&changes[..], [ListChange::Inserted(2, _)])
+        ));
+    }
+
+    #[test]
+    fn map_changes() {
+        let mut after = foo();
+        after.map.remove(&1);
+        after.map.insert(2, "deux".to_string());
+        after.map.insert(3, "three".to_string());
+
+        let diff = assert_round_trip(&foo(), &after);
+        let Diff::Fields(fields) = diff else {
+            panic!("expected changed fields");
+        };
+        let [(3, Diff::Map(changes))] = &fields[..] else {
+            panic!("expected changed map");
+        };
+        assert_eq!(changes.len(), 3);
+    }
+
+    #[test]
+    fn mismatched_diff() {
+        let diff = Diff::List(vec![ListChange::Removed(0)]);
+        assert_eq!(
+            foo().apply_diff(&diff),
+            Err(ApplyDiffError::MismatchedKinds)
+        );
+
+        let diff = Diff::Fields(vec![(2, Diff::List(vec![ListChange::Removed(4)]))]);
+        assert_eq!(
+            foo().apply_diff(&diff),
+            Err(ApplyDiffError::InvalidIndex(4))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::DynamicStruct;
+use thiserror::Error;
+
+/// A description of a struct type that is defined at runtime, e.g. by a modding or scripting
+/// layer, rather than in Rust.
+///
+/// Registering the descriptor with [`TypeRegistry::register_dynamic`] makes values of the type
+/// serializable and deserializable like the values of registered Rust types. Values of the type
+/// are represented by [`DynamicStruct`]s named after the [`type_name`](Self::type_name). Each
+/// registered type is identified by a [`DynamicTypeSlot`], which also allows using it as a
+/// component and storing it in scenes once `ReflectComponent` data is inserted for it.
+///
+/// With the `serialize` feature, descriptors can themselves be (de)serialized, so that the
+/// schema can be shipped alongside the data.
+///
+/// [`TypeRegistry::register_dynamic`]: crate::TypeRegistry::register_dynamic
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicTypeDescriptor {
+    /// The full name of the type, which has to be unique in the registry.
+    pub type_name: String,
+    /// The fields of the struct in the order they are defined.
+    pub fields: Vec<DynamicFieldDescriptor>,
+}
+
+/// A field of a [`DynamicTypeDescriptor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicFieldDescriptor {
+    /// The name of the field.
+    pub name: String,
+    /// The full name of the field's type, which has to be registered.
+    ///
+    /// This can be a Rust type or another dynamic type.
+    pub type_name: String,
+}
+
+impl DynamicTypeDescriptor {
+    /// Creates a descriptor for the struct type `type_name` without fields.
+    pub fn new(type_name: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field with the given name and the full name of its type.
+    pub fn with_field(mut self, name: impl Into<String>, type_name: impl Into<String>) -> Self {
+        self.fields.push(DynamicFieldDescriptor {
+            name: name.into(),
+            type_name: type_name.into(),
+        });
+        self
+    }
+}
+
+/// The maximum number of dynamic types a [`TypeRegistry`](crate::TypeRegistry) can hold, which
+/// is the number of [`DynamicTypeSlot`]s.
+pub const MAX_DYNAMIC_TYPES: usize = 64;
+
+/// The Rust type standing in for the dynamic type registered in slot `N` of a
+/// [`TypeRegistry`](crate::TypeRegistry).
+///
+/// Runtime-defined types have no [`TypeId`](std::any::TypeId) of their own, so each registered
+/// dynamic type is assigned the next free slot, and the [`TypeId`](std::any::TypeId) of the slot
+/// type identifies it, e.g. in the registry or as a component. The value of the dynamic type is
+/// the wrapped [`DynamicStruct`].
+///
+/// Slots are assigned per registry, so the same slot can identify different types in different
+/// registries.
+pub struct DynamicTypeSlot<const N: usize>(pub DynamicStruct);
+
+/// A generic function over the [`DynamicTypeSlot`]s, called by [`visit_dynamic_type_slot`].
+pub trait DynamicTypeSlotVisitor {
+    /// The value returned by the visitor.
+    type Output;
+
+    /// Visits the slot `N`.
+    fn visit<const N: usize>(self) -> Self::Output;
+}
+
+/// Calls `visitor` with the number of the `slot` as a const generic parameter, which allows
+/// creating type data for the [`DynamicTypeSlot`] of a
+/// [dynamic registration](crate::TypeRegistration::dynamic_slot).
+///
+/// Returns `None` if `slot` is not less than [`MAX_DYNAMIC_TYPES`].
+pub fn visit_dynamic_type_slot<V: DynamicTypeSlotVisitor>(
+    slot: usize,
+    visitor: V,
+) -> Option<V::Output> {
+    macro_rules! visit_slots {
+        ($($slot:literal)*) => {
+            match slot {
+                $($slot => Some(visitor.visit::<$slot>()),)*
+                _ => None,
+            }
+        };
+    }
+    visit_slots!(
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+        32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61
+        62 63
+    )
+}
+
+/// An error returned by [`TypeRegistry::register_dynamic`](crate::TypeRegistry::register_dynamic).
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DynamicTypeError {
+    /// A Rust or dynamic type with the name of the descriptor is already registered.
+    #[error("a type with the name `{0}` is already registered")]
+    AlreadyRegistered(String),
+    /// The descriptor has two fields with the same name.
+    #[error("the field `{0}` is defined more than once")]
+    DuplicateField(String),
+    /// The type of a field of the descriptor is not registered.
+    #[error("the type `{type_name}` of the field `{field}` is not registered")]
+    UnregisteredFieldType {
+        /// The name of the field.
+        field: String,
+        /// The name of the field's type.
+        type_name: String,
+    },
+    /// All [`MAX_DYNAMIC_TYPES`] slots of the registry are taken.
+    #[error("no more than {MAX_DYNAMIC_TYPES} dynamic types can be registered")]
+    TooManyTypes,
+}
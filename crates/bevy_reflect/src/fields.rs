@@ -23,6 +23,21 @@ impl NamedField {
         }
     }
 
+    /// Create a new [`NamedField`] whose type is described by `type_name` and `type_id`.
+    pub(crate) fn new_dynamic(
+        name: &'static str,
+        type_name: &'static str,
+        type_id: TypeId,
+    ) -> Self {
+        Self {
+            name,
+            type_name,
+            type_id,
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -1,5 +1,6 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
+#[cfg(feature = "serialize")]
 use crate::{ReflectDeserialize, ReflectSerialize};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_struct, impl_reflect_value};
 use glam::*;
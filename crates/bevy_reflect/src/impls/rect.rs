@@ -1,5 +1,6 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
+#[cfg(feature = "serialize")]
 use crate::{ReflectDeserialize, ReflectSerialize};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect_derive::impl_reflect_struct;
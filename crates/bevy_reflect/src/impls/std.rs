@@ -3,10 +3,11 @@ use crate::{self as bevy_reflect, ReflectFromPtr, ReflectOwned};
 use crate::{
     map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap, Enum,
     EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter,
-    Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TupleVariantInfo,
-    TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
-    VariantInfo, VariantType,
+    Reflect, ReflectMut, ReflectRef, TupleVariantInfo, TypeInfo, TypeRegistration, Typed,
+    UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter, VariantInfo, VariantType,
 };
+#[cfg(feature = "serialize")]
+use crate::{ReflectDeserialize, ReflectSerialize};
 
 use crate::utility::{reflect_hasher, GenericTypeInfoCell, NonGenericTypeInfoCell};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
@@ -1047,8 +1048,10 @@ impl Typed for Cow<'static, str> {
 impl GetTypeRegistration for Cow<'static, str> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Cow<'static, str>>();
+        #[cfg(feature = "serialize")]
         registration.insert::<ReflectDeserialize>(FromType::<Cow<'static, str>>::from_type());
         registration.insert::<ReflectFromPtr>(FromType::<Cow<'static, str>>::from_type());
+        #[cfg(feature = "serialize")]
         registration.insert::<ReflectSerialize>(FromType::<Cow<'static, str>>::from_type());
         registration
     }
@@ -1169,17 +1172,18 @@ impl FromReflect for &'static Path {
 #[cfg(test)]
 mod tests {
     use crate as bevy_reflect;
-    use crate::{
-        Enum, FromReflect, Reflect, ReflectSerialize, TypeInfo, TypeRegistry, Typed, VariantInfo,
-        VariantType,
-    };
+    use crate::{Enum, FromReflect, Reflect, TypeInfo, Typed, VariantInfo, VariantType};
     use bevy_utils::HashMap;
-    use bevy_utils::{Duration, Instant};
+    use bevy_utils::Instant;
     use std::f32::consts::{PI, TAU};
     use std::path::Path;
 
     #[test]
+    #[cfg(feature = "serialize")]
     fn can_serialize_duration() {
+        use crate::{ReflectSerialize, TypeRegistry};
+        use bevy_utils::Duration;
+
         let mut type_registry = TypeRegistry::default();
         type_registry.register::<Duration>();
 
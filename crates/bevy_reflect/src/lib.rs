@@ -409,6 +409,20 @@
 //! This can be useful for generating documentation for scripting language interop or
 //! for displaying tooltips in an editor.
 //!
+//! ## `serialize`
+//!
+//! | Default | Dependencies                                                  |
+//! | :-----: | :-----------------------------------------------------------: |
+//! | ✅      | [`serde`], [`erased_serde`], [`bevy_reflect_derive/serialize`] |
+//!
+//! This feature enables the [`serde`](mod@serde) module, the [`ReflectSerialize`] and [`ReflectDeserialize`]
+//! type data, and [`Reflect::serializable`].
+//!
+//! Disabling default features gives a minimal profile for targets with tight binary-size budgets:
+//! the derive macros, the dynamic types and the [`TypeRegistry`] keep working,
+//! but `Serialize` and `Deserialize` in `#[reflect(...)]` attributes are ignored
+//! and fields marked with `#[reflect(skip_serializing)]` don't register any serialization data.
+//!
 //! [Reflection]: https://en.wikipedia.org/wiki/Reflective_programming
 //! [Bevy]: https://bevyengine.org/
 //! [limitations]: #limitations
@@ -434,9 +448,13 @@
 //! [`smallvec`]: https://docs.rs/smallvec/latest/smallvec/
 //! [orphan rule]: https://doc.rust-lang.org/book/ch10-02-traits.html#implementing-a-trait-on-a-type:~:text=But%20we%20can%E2%80%99t,implementation%20to%20use.
 //! [`bevy_reflect_derive/documentation`]: bevy_reflect_derive
+//! [`bevy_reflect_derive/serialize`]: bevy_reflect_derive
+//! [`erased_serde`]: https://docs.rs/erased-serde/latest/erased_serde/
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod diff;
+mod dynamic_type;
 mod fields;
 mod from_reflect;
 mod list;
@@ -469,6 +487,7 @@ mod impls {
 }
 
 mod enums;
+#[cfg(feature = "serialize")]
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -477,12 +496,16 @@ pub mod prelude {
     pub use crate::std_traits::*;
     #[doc(hidden)]
     pub use crate::{
-        reflect_trait, FromReflect, GetField, GetTupleStructField, Reflect, ReflectDeserialize,
-        ReflectSerialize, Struct, TupleStruct,
+        reflect_trait, FromReflect, GetField, GetTupleStructField, Reflect, Struct, TupleStruct,
     };
+    #[cfg(feature = "serialize")]
+    #[doc(hidden)]
+    pub use crate::{ReflectDeserialize, ReflectSerialize};
 }
 
 pub use array::*;
+pub use diff::{ApplyDiffError, Diff, ListChange, MapChange};
+pub use dynamic_type::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -499,6 +522,7 @@ pub use type_registry::*;
 pub use type_uuid::*;
 
 pub use bevy_reflect_derive::*;
+#[cfg(feature = "serialize")]
 pub use erased_serde;
 
 #[doc(hidden)]
@@ -535,9 +559,13 @@ pub mod __macro_exports {
 #[allow(clippy::disallowed_types, clippy::approx_constant)]
 mod tests {
     #[cfg(feature = "glam")]
-    use ::glam::{vec3, Vec3};
+    use ::glam::vec3;
+    #[cfg(all(feature = "glam", feature = "serialize"))]
+    use ::glam::Vec3;
+    #[cfg(feature = "serialize")]
     use ::serde::{de::DeserializeSeed, Deserialize, Serialize};
     use bevy_utils::HashMap;
+    #[cfg(feature = "serialize")]
     use ron::{
         ser::{to_string_pretty, PrettyConfig},
         Deserializer,
@@ -548,6 +576,7 @@ mod tests {
     use super::prelude::*;
     use super::*;
     use crate as bevy_reflect;
+    #[cfg(feature = "serialize")]
     use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
 
     #[test]
@@ -917,6 +946,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serialize")]
     fn reflect_serialize() {
         #[derive(Reflect)]
         struct Foo {
@@ -1640,6 +1670,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         use super::*;
 
         #[test]
+        #[cfg(feature = "serialize")]
         fn vec3_serialization() {
             let v = vec3(12.0, 3.0, -6.9);
 
@@ -1666,6 +1697,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         }
 
         #[test]
+        #[cfg(feature = "serialize")]
         fn vec3_deserialization() {
             let data = r#"
 {
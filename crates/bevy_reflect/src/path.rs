@@ -180,6 +180,31 @@ pub enum ReflectPathError<'a> {
 /// );
 /// ```
 ///
+/// # Optional Access
+///
+/// Prefixing an access with `?` makes it optional: if the element it accesses doesn't exist,
+/// e.g. because the field is missing, the enum is a different variant or the index is out of
+/// bounds, the `*_optional` methods like [`path_optional`][GetPath::path_optional] return `None`
+/// instead of an error. Errors of the other accesses, as well as invalid path strings, are
+/// still returned. The other methods ignore the `?` and always return the error.
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// struct MyStruct {
+///   value: Vec<Option<u32>>
+/// }
+///
+/// let my_struct = MyStruct {
+///   value: vec![None, Some(123)],
+/// };
+/// assert_eq!(my_struct.path_optional::<u32>("value[1]?.0").unwrap(), Some(&123));
+/// assert_eq!(my_struct.path_optional::<u32>("value[0]?.0").unwrap(), None);
+/// assert_eq!(my_struct.path_optional::<u32>("value?[5].0").unwrap(), None);
+/// assert!(my_struct.path::<u32>("value[0]?.0").is_err());
+/// ```
+///
 /// [`Struct`]: crate::Struct
 /// [`Tuple`]: crate::Tuple
 /// [`TupleStruct`]: crate::TupleStruct
@@ -205,6 +230,26 @@ pub trait GetPath {
         path: &'p str,
     ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>>;
 
+    /// Returns a reference to the value specified by `path`, or `None` if an
+    /// [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// To retrieve a statically typed reference, use
+    /// [`path_optional`][GetPath::path_optional].
+    fn reflect_path_optional<'r, 'p>(
+        &'r self,
+        path: &'p str,
+    ) -> Result<Option<&'r dyn Reflect>, ReflectPathError<'p>>;
+
+    /// Returns a mutable reference to the value specified by `path`, or `None` if an
+    /// [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// To retrieve a statically typed mutable reference, use
+    /// [`path_optional_mut`][GetPath::path_optional_mut].
+    fn reflect_path_optional_mut<'r, 'p>(
+        &'r mut self,
+        path: &'p str,
+    ) -> Result<Option<&'r mut dyn Reflect>, ReflectPathError<'p>>;
+
     /// Returns a statically typed reference to the value specified by `path`.
     ///
     /// This will automatically handle downcasting to type `T`.
@@ -235,6 +280,40 @@ pub trait GetPath {
                 .ok_or(ReflectPathError::InvalidDowncast)
         })
     }
+
+    /// Returns a statically typed reference to the value specified by `path`, or `None` if an
+    /// [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// The downcast to type `T` is handled like in [`path`][GetPath::path].
+    fn path_optional<'r, 'p, T: Reflect>(
+        &'r self,
+        path: &'p str,
+    ) -> Result<Option<&'r T>, ReflectPathError<'p>> {
+        self.reflect_path_optional(path).and_then(|p| {
+            p.map(|p| {
+                p.downcast_ref::<T>()
+                    .ok_or(ReflectPathError::InvalidDowncast)
+            })
+            .transpose()
+        })
+    }
+
+    /// Returns a statically typed mutable reference to the value specified by `path`, or `None`
+    /// if an [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// The downcast to type `T` is handled like in [`path_mut`][GetPath::path_mut].
+    fn path_optional_mut<'r, 'p, T: Reflect>(
+        &'r mut self,
+        path: &'p str,
+    ) -> Result<Option<&'r mut T>, ReflectPathError<'p>> {
+        self.reflect_path_optional_mut(path).and_then(|p| {
+            p.map(|p| {
+                p.downcast_mut::<T>()
+                    .ok_or(ReflectPathError::InvalidDowncast)
+            })
+            .transpose()
+        })
+    }
 }
 
 impl<T: Reflect> GetPath for T {
@@ -251,6 +330,20 @@ impl<T: Reflect> GetPath for T {
     ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
         (self as &mut dyn Reflect).reflect_path_mut(path)
     }
+
+    fn reflect_path_optional<'r, 'p>(
+        &'r self,
+        path: &'p str,
+    ) -> Result<Option<&'r dyn Reflect>, ReflectPathError<'p>> {
+        (self as &dyn Reflect).reflect_path_optional(path)
+    }
+
+    fn reflect_path_optional_mut<'r, 'p>(
+        &'r mut self,
+        path: &'p str,
+    ) -> Result<Option<&'r mut dyn Reflect>, ReflectPathError<'p>> {
+        (self as &mut dyn Reflect).reflect_path_optional_mut(path)
+    }
 }
 
 impl GetPath for dyn Reflect {
@@ -258,23 +351,79 @@ impl GetPath for dyn Reflect {
         &'r self,
         path: &'p str,
     ) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
-        let mut current: &dyn Reflect = self;
-        for (access, current_index) in PathParser::new(path) {
-            current = access?.read_element(current, current_index)?;
-        }
-        Ok(current)
+        read_path(self, PathParser::new(path), false).map(Option::unwrap)
     }
 
     fn reflect_path_mut<'r, 'p>(
         &'r mut self,
         path: &'p str,
     ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
-        let mut current: &mut dyn Reflect = self;
-        for (access, current_index) in PathParser::new(path) {
-            current = access?.read_element_mut(current, current_index)?;
+        read_path_mut(self, PathParser::new(path), false).map(Option::unwrap)
+    }
+
+    fn reflect_path_optional<'r, 'p>(
+        &'r self,
+        path: &'p str,
+    ) -> Result<Option<&'r dyn Reflect>, ReflectPathError<'p>> {
+        read_path(self, PathParser::new(path), true)
+    }
+
+    fn reflect_path_optional_mut<'r, 'p>(
+        &'r mut self,
+        path: &'p str,
+    ) -> Result<Option<&'r mut dyn Reflect>, ReflectPathError<'p>> {
+        read_path_mut(self, PathParser::new(path), true)
+    }
+}
+
+/// Applies the `accesses` to `root` one after another.
+///
+/// Returns `None` if `allow_optional` is `true` and an optional access failed.
+fn read_path<'r, 'p>(
+    root: &'r dyn Reflect,
+    accesses: impl Iterator<Item = (Result<AccessRef<'p>, ReflectPathError<'p>>, usize)>,
+    allow_optional: bool,
+) -> Result<Option<&'r dyn Reflect>, ReflectPathError<'p>> {
+    let mut current = root;
+    let mut optional = false;
+    for (access, current_index) in accesses {
+        let access = access?;
+        if let AccessRef::Optional = access {
+            optional = allow_optional;
+            continue;
+        }
+        match access.read_element(current, current_index) {
+            Ok(element) => current = element,
+            Err(_) if optional => return Ok(None),
+            Err(err) => return Err(err),
+        }
+        optional = false;
+    }
+    Ok(Some(current))
+}
+
+/// Like [`read_path`], but for mutable references.
+fn read_path_mut<'r, 'p>(
+    root: &'r mut dyn Reflect,
+    accesses: impl Iterator<Item = (Result<AccessRef<'p>, ReflectPathError<'p>>, usize)>,
+    allow_optional: bool,
+) -> Result<Option<&'r mut dyn Reflect>, ReflectPathError<'p>> {
+    let mut current = root;
+    let mut optional = false;
+    for (access, current_index) in accesses {
+        let access = access?;
+        if let AccessRef::Optional = access {
+            optional = allow_optional;
+            continue;
         }
-        Ok(current)
+        match access.read_element_mut(current, current_index) {
+            Ok(element) => current = element,
+            Err(_) if optional => return Ok(None),
+            Err(err) => return Err(err),
+        }
+        optional = false;
     }
+    Ok(Some(current))
 }
 
 /// A pre-parsed path to an element within a type.
@@ -307,6 +456,7 @@ impl ParsedPath {
     /// - Unnamed field access (`.1`)
     /// - Field index access (`#0`)
     /// - Sequence access (`[2]`)
+    /// - [Optional access](GetPath#optional-access) (`?.field`)
     ///
     /// # Example
     /// ```
@@ -358,11 +508,7 @@ impl ParsedPath {
         &'p self,
         root: &'r dyn Reflect,
     ) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
-        let mut current = root;
-        for (access, current_index) in self.0.iter() {
-            current = access.to_ref().read_element(current, *current_index)?;
-        }
-        Ok(current)
+        read_path(root, self.accesses(), false).map(Option::unwrap)
     }
 
     /// Gets a mutable reference to the specified element on the given [`Reflect`] object.
@@ -374,11 +520,37 @@ impl ParsedPath {
         &'p mut self,
         root: &'r mut dyn Reflect,
     ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
-        let mut current = root;
-        for (access, current_index) in self.0.iter() {
-            current = access.to_ref().read_element_mut(current, *current_index)?;
-        }
-        Ok(current)
+        read_path_mut(root, self.accesses(), false).map(Option::unwrap)
+    }
+
+    /// Gets a read-only reference to the specified element on the given [`Reflect`] object, or
+    /// `None` if an [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// Returns an error if a non-optional access is invalid for the provided type.
+    pub fn reflect_element_optional<'r, 'p>(
+        &'p self,
+        root: &'r dyn Reflect,
+    ) -> Result<Option<&'r dyn Reflect>, ReflectPathError<'p>> {
+        read_path(root, self.accesses(), true)
+    }
+
+    /// Gets a mutable reference to the specified element on the given [`Reflect`] object, or
+    /// `None` if an [optional access](GetPath#optional-access) of the path failed.
+    ///
+    /// Returns an error if a non-optional access is invalid for the provided type.
+    pub fn reflect_element_optional_mut<'r, 'p>(
+        &'p self,
+        root: &'r mut dyn Reflect,
+    ) -> Result<Option<&'r mut dyn Reflect>, ReflectPathError<'p>> {
+        read_path_mut(root, self.accesses(), true)
+    }
+
+    fn accesses(
+        &self,
+    ) -> impl Iterator<Item = (Result<AccessRef<'_>, ReflectPathError<'_>>, usize)> {
+        self.0
+            .iter()
+            .map(|(access, current_index)| (Ok(access.to_ref()), *current_index))
     }
 
     /// Gets a typed, read-only reference to the specified element on the given [`Reflect`] object.
@@ -437,6 +609,7 @@ impl fmt::Display for ParsedPath {
                     index.fmt(f)?;
                     Token::CLOSE_BRACKET.fmt(f)?;
                 }
+                Access::Optional => Token::QUESTION.fmt(f)?,
             }
         }
         Ok(())
@@ -454,6 +627,8 @@ enum Access {
     FieldIndex(usize),
     TupleIndex(usize),
     ListIndex(usize),
+    /// Marks the next access as optional.
+    Optional,
 }
 
 impl Access {
@@ -463,6 +638,7 @@ impl Access {
             Self::FieldIndex(value) => AccessRef::FieldIndex(*value),
             Self::TupleIndex(value) => AccessRef::TupleIndex(*value),
             Self::ListIndex(value) => AccessRef::ListIndex(*value),
+            Self::Optional => AccessRef::Optional,
         }
     }
 }
@@ -479,6 +655,7 @@ enum AccessRef<'a> {
     FieldIndex(usize),
     TupleIndex(usize),
     ListIndex(usize),
+    Optional,
 }
 
 impl<'a> AccessRef<'a> {
@@ -488,6 +665,7 @@ impl<'a> AccessRef<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(*value),
             Self::TupleIndex(value) => Access::TupleIndex(*value),
             Self::ListIndex(value) => Access::ListIndex(*value),
+            Self::Optional => Access::Optional,
         }
     }
 
@@ -712,13 +890,21 @@ impl<'a> PathParser<'a> {
                 self.index += 1;
                 return Some(Token::CloseBracket);
             }
+            Token::QUESTION => {
+                self.index += 1;
+                return Some(Token::Question);
+            }
             _ => {}
         }
 
         // we can assume we are parsing an ident now
         for (char_index, character) in self.path[self.index..].chars().enumerate() {
             match character {
-                Token::DOT | Token::CROSSHATCH | Token::OPEN_BRACKET | Token::CLOSE_BRACKET => {
+                Token::DOT
+                | Token::CROSSHATCH
+                | Token::OPEN_BRACKET
+                | Token::CLOSE_BRACKET
+                | Token::QUESTION => {
                     let ident = Token::Ident(&self.path[self.index..self.index + char_index]);
                     self.index += char_index;
                     return Some(ident);
@@ -777,6 +963,14 @@ impl<'a> PathParser<'a> {
                 index: current_index,
                 token: Token::CLOSE_BRACKET_STR,
             }),
+            Token::Question => {
+                if self.index >= self.path.len() {
+                    return Err(ReflectPathError::ExpectedIdent {
+                        index: current_index,
+                    });
+                }
+                Ok(AccessRef::Optional)
+            }
             Token::Ident(value) => value
                 .parse::<usize>()
                 .map(AccessRef::TupleIndex)
@@ -800,6 +994,7 @@ enum Token<'a> {
     CrossHatch,
     OpenBracket,
     CloseBracket,
+    Question,
     Ident(&'a str),
 }
 
@@ -808,6 +1003,7 @@ impl<'a> Token<'a> {
     const CROSSHATCH: char = '#';
     const OPEN_BRACKET: char = '[';
     const CLOSE_BRACKET: char = ']';
+    const QUESTION: char = '?';
     const OPEN_BRACKET_STR: &'static str = "[";
     const CLOSE_BRACKET_STR: &'static str = "]";
 }
@@ -908,6 +1104,23 @@ mod tests {
                 (Access::FieldIndex(1), 4)
             ]
         );
+        assert_eq!(
+            &*ParsedPath::parse("x?.foo").unwrap().0,
+            &[
+                (Access::Field("x".to_string()), 1),
+                (Access::Optional, 2),
+                (Access::Field("foo".to_string()), 3)
+            ]
+        );
+        assert_eq!(
+            &*ParsedPath::parse("y?[1]").unwrap().0,
+            &[
+                (Access::Field("y".to_string()), 1),
+                (Access::Optional, 2),
+                (Access::ListIndex(1), 3)
+            ]
+        );
+        assert_eq!(ParsedPath::parse("x?.foo").unwrap().to_string(), "x?.foo");
     }
 
     #[test]
@@ -1075,4 +1288,78 @@ mod tests {
             Err(ReflectPathError::IndexParseError(_))
         ));
     }
+
+    #[test]
+    fn reflect_path_nested() {
+        #[derive(Reflect)]
+        struct A {
+            pairs: Vec<(u32, f32)>,
+            grid: Vec<Vec<F>>,
+        }
+
+        let mut a = A {
+            pairs: vec![(1, 1.5), (2, 2.5)],
+            grid: vec![
+                vec![F::Unit],
+                vec![F::Tuple(3, 4), F::Struct { value: 'q' }],
+            ],
+        };
+
+        assert_eq!(*a.path::<f32>("pairs[1].1").unwrap(), 2.5);
+        assert_eq!(*a.path::<u32>("grid[1][0].1").unwrap(), 4);
+        assert_eq!(*a.path::<char>("grid[1][1].value").unwrap(), 'q');
+
+        *a.path_mut::<u32>("pairs[0].0").unwrap() = 10;
+        *a.path_mut::<char>("grid[1][1]#0").unwrap() = 'r';
+        assert_eq!(a.pairs[0], (10, 1.5));
+        assert_eq!(a.grid[1][1], F::Struct { value: 'r' });
+    }
+
+    #[test]
+    fn reflect_path_optional() {
+        #[derive(Reflect)]
+        struct A {
+            grid: Vec<Vec<F>>,
+        }
+
+        let mut a = A {
+            grid: vec![vec![F::Unit], vec![F::Tuple(3, 4)]],
+        };
+
+        assert_eq!(a.path_optional::<u32>("grid[1][0]?.1").unwrap(), Some(&4));
+        assert_eq!(a.path_optional::<u32>("grid[0][0]?.1").unwrap(), None);
+        assert_eq!(a.path_optional::<u32>("grid?[2][0].1").unwrap(), None);
+        assert_eq!(a.path_optional::<char>("grid[1][0]?.value").unwrap(), None);
+
+        // only the marked access is optional
+        assert_eq!(
+            a.reflect_path_optional("grid?[0][0].1").err().unwrap(),
+            ReflectPathError::ExpectedTupleVariant { index: 12 }
+        );
+        assert_eq!(
+            a.path_optional::<char>("grid[1][0]?.1").err().unwrap(),
+            ReflectPathError::InvalidDowncast
+        );
+        assert_eq!(
+            a.reflect_path_optional("grid?").err().unwrap(),
+            ReflectPathError::ExpectedIdent { index: 5 }
+        );
+
+        // the non-optional methods report the error
+        assert_eq!(
+            a.reflect_path("grid[0][0]?.1").err().unwrap(),
+            ReflectPathError::ExpectedTupleVariant { index: 12 }
+        );
+
+        *a.path_optional_mut::<u32>("grid[1][0]?.0")
+            .unwrap()
+            .unwrap() = 5;
+        assert_eq!(a.grid[1][0], F::Tuple(5, 4));
+        assert!(a.path_optional_mut::<u32>("grid[0]?[1]").unwrap().is_none());
+
+        let path = ParsedPath::parse("grid[0][0]?.0").unwrap();
+        assert!(path.reflect_element_optional(&a).unwrap().is_none());
+        assert!(path.reflect_element_optional_mut(&mut a).unwrap().is_none());
+        assert!(path.reflect_element(&a).is_err());
+    }
 }
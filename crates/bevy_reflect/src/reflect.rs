@@ -1,7 +1,9 @@
+#[cfg(feature = "serialize")]
+use crate::serde::Serializable;
 use crate::{
-    array_debug, enum_debug, list_debug, map_debug, serde::Serializable, struct_debug, tuple_debug,
-    tuple_struct_debug, Array, Enum, List, Map, Struct, Tuple, TupleStruct, TypeInfo, Typed,
-    ValueInfo,
+    array_debug, diff, enum_debug, list_debug, map_debug, struct_debug, tuple_debug,
+    tuple_struct_debug, ApplyDiffError, Array, Diff, Enum, List, Map, Struct, Tuple, TupleStruct,
+    TypeInfo, Typed, ValueInfo,
 };
 use std::{
     any::{self, Any, TypeId},
@@ -176,6 +178,26 @@ pub trait Reflect: Any + Send + Sync {
     /// use those subtraits' respective `clone_dynamic` methods.
     fn clone_value(&self) -> Box<dyn Reflect>;
 
+    /// Returns the changes that turn this value into `other`.
+    ///
+    /// See [`Diff`] for more details.
+    fn diff(&self, other: &dyn Reflect) -> Diff {
+        diff::diff(self.as_reflect(), other)
+    }
+
+    /// Applies a [`Diff`] created by [`Reflect::diff`] to this value.
+    ///
+    /// If the diff doesn't fit this value, returns an error and this value may be partially
+    /// modified.
+    ///
+    /// # Panics
+    ///
+    /// Replaced values are applied with [`Reflect::apply`], which panics if their types
+    /// don't match.
+    fn apply_diff(&mut self, diff: &Diff) -> Result<(), ApplyDiffError> {
+        diff::apply_diff(self.as_reflect_mut(), diff)
+    }
+
     /// Returns a hash of the value (which includes the type).
     ///
     /// If the underlying type does not support hashing, returns `None`.
@@ -213,6 +235,7 @@ pub trait Reflect: Any + Send + Sync {
     /// Returns a serializable version of the value.
     ///
     /// If the underlying type does not support serialization, returns `None`.
+    #[cfg(feature = "serialize")]
     fn serializable(&self) -> Option<Serializable> {
         None
     }
@@ -2,9 +2,9 @@ use crate::serde::SerializationData;
 use crate::{
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
     DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, Tuple, TupleInfo, TupleStruct,
-    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
-    VariantInfo,
+    ReflectDeserialize, ReflectFromReflect, StructInfo, StructVariantInfo, Tuple, TupleInfo,
+    TupleStruct, TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -738,6 +738,11 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
                 registration: value_registration,
                 registry: self.registry,
             })?;
+            // Dynamic keys can't be hashed, so convert them to their concrete type if possible.
+            let key = match key_registration.data::<ReflectFromReflect>() {
+                Some(from_reflect) => from_reflect.from_reflect(&*key).unwrap_or(key),
+                None => key,
+            };
             dynamic_map.insert_boxed(key, value);
         }
 
@@ -1096,7 +1101,9 @@ mod tests {
 
     use crate as bevy_reflect;
     use crate::serde::{TypedReflectDeserializer, UntypedReflectDeserializer};
-    use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
+    use crate::{
+        DynamicEnum, FromReflect, Reflect, ReflectDeserialize, ReflectFromReflect, TypeRegistry,
+    };
 
     #[derive(Reflect, FromReflect, Debug, PartialEq)]
     struct MyStruct {
@@ -1335,6 +1342,35 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_map_with_reflected_keys() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq, Eq, Hash)]
+        #[reflect(Hash, FromReflect)]
+        struct Key {
+            id: u32,
+        }
+
+        let input = r#"{
+            (id: 1): 10,
+            (id: 2): 20,
+        }"#;
+
+        let mut registry = get_registry();
+        registry.register::<Key>();
+        registry.register::<HashMap<Key, usize>>();
+        let registration = registry.get(TypeId::of::<HashMap<Key, usize>>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output =
+            <HashMap<Key, usize> as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(output.get(&Key { id: 1 }), Some(&10));
+        assert_eq!(output.get(&Key { id: 2 }), Some(&20));
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, FromReflect, Debug, PartialEq)]
@@ -92,4 +92,49 @@ mod tests {
             "Expected {expected:?} found {deserialized:?}"
         );
     }
+
+    #[test]
+    fn test_serialization_dynamic_type() {
+        use crate::DynamicTypeDescriptor;
+
+        let mut registry = TypeRegistry::default();
+        registry
+            .register_dynamic(
+                &DynamicTypeDescriptor::new("my_mod::Stats").with_field("speed", "f32"),
+            )
+            .unwrap();
+        registry
+            .register_dynamic(
+                &DynamicTypeDescriptor::new("my_mod::Enemy")
+                    .with_field("health", "u32")
+                    .with_field("stats", "my_mod::Stats"),
+            )
+            .unwrap();
+
+        let mut stats = DynamicStruct::default();
+        stats.set_name("my_mod::Stats".to_string());
+        stats.insert("speed", 1.5f32);
+        let mut enemy = DynamicStruct::default();
+        enemy.set_name("my_mod::Enemy".to_string());
+        enemy.insert("health", 100u32);
+        enemy.insert("stats", stats);
+
+        let serializer = ReflectSerializer::new(&enemy, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"my_mod::Enemy":(health:100,stats:(speed:1.5))}"#
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = value.take::<DynamicStruct>().unwrap();
+
+        assert_eq!(deserialized.type_name(), "my_mod::Enemy");
+        assert!(
+            enemy.reflect_partial_eq(&deserialized).unwrap(),
+            "Expected {enemy:?} found {deserialized:?}"
+        );
+    }
 }
@@ -114,6 +114,21 @@ impl StructInfo {
         }
     }
 
+    /// Create a new [`StructInfo`] for a struct type that is only known at runtime, whose values
+    /// are represented by [`DynamicStruct`]s and which is identified by `type_id`.
+    pub(crate) fn new_dynamic(
+        name: &'static str,
+        type_name: &'static str,
+        type_id: TypeId,
+        fields: &[NamedField],
+    ) -> Self {
+        Self {
+            type_name,
+            type_id,
+            ..Self::new::<DynamicStruct>(name, fields)
+        }
+    }
+
     /// Sets the docstring for this struct.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -1,8 +1,14 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
+#[cfg(feature = "serialize")]
+use crate::serde::Serializable;
+use crate::{
+    visit_dynamic_type_slot, DynamicTypeDescriptor, DynamicTypeError, DynamicTypeSlot,
+    DynamicTypeSlotVisitor, NamedField, Reflect, StructInfo, TypeInfo, Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(feature = "serialize")]
 use serde::Deserialize;
 use std::{any::TypeId, fmt::Debug, sync::Arc};
 
@@ -23,6 +29,7 @@ pub struct TypeRegistry {
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
+    dynamic_type_count: usize,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -66,6 +73,7 @@ impl TypeRegistry {
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            dynamic_type_count: 0,
         }
     }
 
@@ -127,6 +135,106 @@ impl TypeRegistry {
             .insert(registration.type_id(), registration);
     }
 
+    /// Registers a struct type that is defined at runtime, see [`DynamicTypeDescriptor`], and
+    /// returns its registration, e.g. to insert type data for it.
+    ///
+    /// The type is identified by the [`DynamicTypeSlot`] assigned to it, whose number is returned
+    /// by [`TypeRegistration::dynamic_slot`]. Its [`TypeInfo`] is leaked to provide the same
+    /// `'static` lifetime as the info of Rust types.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, DynamicTypeDescriptor, TypeRegistry};
+    /// let mut registry = TypeRegistry::new();
+    /// registry
+    ///     .register_dynamic(
+    ///         &DynamicTypeDescriptor::new("my_mod::Poison")
+    ///             .with_field("damage", "f32")
+    ///             .with_field("ticks", "u32"),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let mut poison = DynamicStruct::default();
+    /// poison.set_name("my_mod::Poison".to_string());
+    /// poison.insert("damage", 2.5f32);
+    /// poison.insert("ticks", 3u32);
+    /// // `poison` can now be serialized with `ReflectSerializer`
+    /// # let _ = poison;
+    /// assert!(registry.get_with_name("my_mod::Poison").is_some());
+    /// ```
+    pub fn register_dynamic(
+        &mut self,
+        descriptor: &DynamicTypeDescriptor,
+    ) -> Result<&mut TypeRegistration, DynamicTypeError> {
+        if self.get_with_name(&descriptor.type_name).is_some() {
+            return Err(DynamicTypeError::AlreadyRegistered(
+                descriptor.type_name.clone(),
+            ));
+        }
+
+        // validate all fields before leaking anything
+        let mut field_registrations = Vec::with_capacity(descriptor.fields.len());
+        for (index, field) in descriptor.fields.iter().enumerate() {
+            if descriptor.fields[..index]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                return Err(DynamicTypeError::DuplicateField(field.name.clone()));
+            }
+            let registration = self.get_with_name(&field.type_name).ok_or_else(|| {
+                DynamicTypeError::UnregisteredFieldType {
+                    field: field.name.clone(),
+                    type_name: field.type_name.clone(),
+                }
+            })?;
+            field_registrations.push(registration);
+        }
+
+        struct SlotTypeId;
+
+        impl DynamicTypeSlotVisitor for SlotTypeId {
+            type Output = TypeId;
+
+            fn visit<const N: usize>(self) -> TypeId {
+                TypeId::of::<DynamicTypeSlot<N>>()
+            }
+        }
+
+        let slot = self.dynamic_type_count;
+        let type_id =
+            visit_dynamic_type_slot(slot, SlotTypeId).ok_or(DynamicTypeError::TooManyTypes)?;
+
+        let fields: Vec<_> = descriptor
+            .fields
+            .iter()
+            .zip(field_registrations)
+            .map(|(field, registration)| {
+                NamedField::new_dynamic(
+                    leak(&field.name),
+                    registration.type_name(),
+                    registration.type_id(),
+                )
+            })
+            .collect();
+        let type_name = leak(&descriptor.type_name);
+        let short_name = bevy_utils::get_short_name(type_name);
+        let type_info = Box::leak(Box::new(TypeInfo::Struct(StructInfo::new_dynamic(
+            leak(&short_name),
+            type_name,
+            type_id,
+            &fields,
+        ))));
+
+        self.add_registration(TypeRegistration {
+            short_name,
+            data: HashMap::default(),
+            type_info,
+            dynamic_slot: Some(slot),
+        });
+        self.dynamic_type_count += 1;
+        Ok(self.registrations.get_mut(&type_id).unwrap())
+    }
+
     /// Registers the type data `D` for type `T`.
     ///
     /// Most of the time [`TypeRegistry::register`] can be used instead to register a type you derived [`Reflect`] for.
@@ -264,6 +372,10 @@ impl TypeRegistry {
     }
 }
 
+fn leak(string: &str) -> &'static str {
+    Box::leak(string.to_string().into_boxed_str())
+}
+
 impl TypeRegistryArc {
     /// Takes a read lock on the underlying [`TypeRegistry`].
     pub fn read(&self) -> RwLockReadGuard<'_, TypeRegistry> {
@@ -306,6 +418,7 @@ pub struct TypeRegistration {
     short_name: String,
     data: HashMap<TypeId, Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    dynamic_slot: Option<usize>,
 }
 
 impl Debug for TypeRegistration {
@@ -365,9 +478,16 @@ impl TypeRegistration {
             data: HashMap::default(),
             short_name: bevy_utils::get_short_name(type_name),
             type_info: T::type_info(),
+            dynamic_slot: None,
         }
     }
 
+    /// Returns the number of the [`DynamicTypeSlot`] of the type if it was
+    /// [registered dynamically](TypeRegistry::register_dynamic).
+    pub fn dynamic_slot(&self) -> Option<usize> {
+        self.dynamic_slot
+    }
+
     /// Returns the [short name] of the type.
     ///
     /// [short name]: bevy_utils::get_short_name
@@ -394,6 +514,7 @@ impl Clone for TypeRegistration {
             data,
             short_name: self.short_name.clone(),
             type_info: self.type_info,
+            dynamic_slot: self.dynamic_slot,
         }
     }
 }
@@ -431,6 +552,7 @@ pub trait FromType<T> {
     fn from_type() -> Self;
 }
 
+#[cfg(feature = "serialize")]
 /// A struct used to serialize reflected instances of a type.
 ///
 /// A `ReflectSerialize` for type `T` can be obtained via
@@ -440,6 +562,7 @@ pub struct ReflectSerialize {
     get_serializable: for<'a> fn(value: &'a dyn Reflect) -> Serializable,
 }
 
+#[cfg(feature = "serialize")]
 impl<T: Reflect + erased_serde::Serialize> FromType<T> for ReflectSerialize {
     fn from_type() -> Self {
         ReflectSerialize {
@@ -453,6 +576,7 @@ impl<T: Reflect + erased_serde::Serialize> FromType<T> for ReflectSerialize {
     }
 }
 
+#[cfg(feature = "serialize")]
 impl ReflectSerialize {
     /// Turn the value into a serializable representation
     pub fn get_serializable<'a>(&self, value: &'a dyn Reflect) -> Serializable<'a> {
@@ -460,6 +584,7 @@ impl ReflectSerialize {
     }
 }
 
+#[cfg(feature = "serialize")]
 /// A struct used to deserialize reflected instances of a type.
 ///
 /// A `ReflectDeserialize` for type `T` can be obtained via
@@ -471,6 +596,7 @@ pub struct ReflectDeserialize {
     ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
 }
 
+#[cfg(feature = "serialize")]
 impl ReflectDeserialize {
     /// Deserializes a reflected value.
     ///
@@ -487,6 +613,7 @@ impl ReflectDeserialize {
     }
 }
 
+#[cfg(feature = "serialize")]
 impl<T: for<'a> Deserialize<'a> + Reflect> FromType<T> for ReflectDeserialize {
     fn from_type() -> Self {
         ReflectDeserialize {
@@ -655,4 +782,88 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn test_register_dynamic() {
+        use crate::{DynamicTypeDescriptor, DynamicTypeError, TypeInfo, TypeRegistry};
+
+        let mut registry = TypeRegistry::new();
+        let descriptor = DynamicTypeDescriptor::new("my_mod::Enemy")
+            .with_field("health", "u32")
+            .with_field("name", "alloc::string::String");
+        registry.register_dynamic(&descriptor).unwrap();
+
+        let registration = registry.get_with_name("my_mod::Enemy").unwrap();
+        assert_eq!(registration.short_name(), "Enemy");
+        let TypeInfo::Struct(info) = registration.type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(info.name(), "Enemy");
+        assert_eq!(info.field_names(), ["health", "name"]);
+        assert_eq!(
+            info.field("health").unwrap().type_id(),
+            std::any::TypeId::of::<u32>()
+        );
+        assert!(registry
+            .iter()
+            .any(|registration| registration.type_name() == "my_mod::Enemy"));
+
+        assert_eq!(
+            registry.register_dynamic(&descriptor).unwrap_err(),
+            DynamicTypeError::AlreadyRegistered("my_mod::Enemy".to_string())
+        );
+        assert_eq!(
+            registry
+                .register_dynamic(
+                    &DynamicTypeDescriptor::new("my_mod::Boss")
+                        .with_field("a", "u8")
+                        .with_field("a", "u8")
+                )
+                .unwrap_err(),
+            DynamicTypeError::DuplicateField("a".to_string())
+        );
+        assert_eq!(
+            registry
+                .register_dynamic(
+                    &DynamicTypeDescriptor::new("my_mod::Boss")
+                        .with_field("minion", "my_mod::Minion")
+                )
+                .unwrap_err(),
+            DynamicTypeError::UnregisteredFieldType {
+                field: "minion".to_string(),
+                type_name: "my_mod::Minion".to_string()
+            }
+        );
+        assert!(registry.get_with_name("my_mod::Boss").is_none());
+    }
+
+    #[test]
+    fn test_dynamic_type_slots() {
+        use crate::{
+            DynamicTypeDescriptor, DynamicTypeError, DynamicTypeSlot, TypeRegistry,
+            MAX_DYNAMIC_TYPES,
+        };
+        use std::any::TypeId;
+
+        let mut registry = TypeRegistry::new();
+        for index in 0..MAX_DYNAMIC_TYPES {
+            let registration = registry
+                .register_dynamic(&DynamicTypeDescriptor::new(format!("my_mod::Type{index}")))
+                .unwrap();
+            assert_eq!(registration.dynamic_slot(), Some(index));
+        }
+        assert_eq!(
+            registry
+                .register_dynamic(&DynamicTypeDescriptor::new("my_mod::OneTooMany"))
+                .unwrap_err(),
+            DynamicTypeError::TooManyTypes
+        );
+
+        // each dynamic type has a type id of its own
+        let first = registry.get(TypeId::of::<DynamicTypeSlot<0>>()).unwrap();
+        assert_eq!(first.type_name(), "my_mod::Type0");
+        let second = registry.get(TypeId::of::<DynamicTypeSlot<1>>()).unwrap();
+        assert_eq!(second.type_name(), "my_mod::Type1");
+        assert!(registry.get(TypeId::of::<crate::DynamicStruct>()).is_none());
+    }
 }
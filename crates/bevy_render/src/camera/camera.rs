@@ -6,7 +6,7 @@ use crate::{
     view::{ColorGrading, ExtractedView, ExtractedWindows, VisibleEntities},
     Extract,
 };
-use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_asset::{Asset, AssetEvent, Assets, Handle, HandleId, HandleUntyped};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     change_detection::DetectChanges,
@@ -117,6 +117,9 @@ pub struct Camera {
     /// "write their results on top" of previous camera results, and include them as a part of their render results. This is enabled by default to ensure
     /// cameras with MSAA enabled layer their results in the same way as cameras without MSAA enabled by default.  
     pub msaa_writeback: bool,
+    /// The fullscreen post processing effects applied to the output of this camera, in order.
+    #[reflect(ignore)]
+    pub post_process_stack: PostProcessStack,
 }
 
 impl Default for Camera {
@@ -130,10 +133,67 @@ impl Default for Camera {
             output_mode: Default::default(),
             hdr: false,
             msaa_writeback: true,
+            post_process_stack: Default::default(),
         }
     }
 }
 
+/// An ordered stack of fullscreen post processing effects applied to the output of a [`Camera`].
+///
+/// Each effect is a handle to a post processing material asset, e.g. one implementing
+/// `bevy_core_pipeline::post_process::PostProcessMaterial`. The effects are applied in the order
+/// they were pushed, each one reading the output of the previous one. Effects whose material type
+/// isn't registered or whose material isn't loaded yet are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct PostProcessStack {
+    effects: Vec<HandleUntyped>,
+}
+
+impl PostProcessStack {
+    /// Adds the effect to the end of the stack.
+    pub fn push<T: Asset>(&mut self, effect: Handle<T>) {
+        self.effects.push(effect.clone_untyped());
+    }
+
+    /// Inserts the effect at the `index` of the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert<T: Asset>(&mut self, index: usize, effect: Handle<T>) {
+        self.effects.insert(index, effect.clone_untyped());
+    }
+
+    /// Removes and returns the effect at the `index` of the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> HandleUntyped {
+        self.effects.remove(index)
+    }
+
+    /// Removes all effects from the stack.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    /// Returns the number of effects in the stack.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns `true` if the stack contains no effects.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns an iterator over the effects in the order they are applied.
+    pub fn iter(&self) -> impl Iterator<Item = &HandleUntyped> {
+        self.effects.iter()
+    }
+}
+
 impl Camera {
     /// Converts a physical size in this `Camera` to a logical size.
     #[inline]
@@ -563,6 +623,8 @@ pub struct ExtractedCamera {
     pub output_mode: CameraOutputMode,
     pub msaa_writeback: bool,
     pub sorted_camera_index_for_target: usize,
+    /// The ids of the effects of the [`Camera::post_process_stack`].
+    pub post_process_stack: Vec<HandleId>,
 }
 
 pub fn extract_cameras(
@@ -608,6 +670,11 @@ pub fn extract_cameras(
                     msaa_writeback: camera.msaa_writeback,
                     // this will be set in sort_cameras
                     sorted_camera_index_for_target: 0,
+                    post_process_stack: camera
+                        .post_process_stack
+                        .iter()
+                        .map(|effect| effect.id())
+                        .collect(),
                 },
                 ExtractedView {
                     projection: camera.projection_matrix(),
@@ -0,0 +1,247 @@
+use crate::{
+    camera::{Camera, RenderTarget},
+    texture::{BevyDefault, Image},
+};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    prelude::With,
+    reflect::ReflectComponent,
+    system::{Query, ResMut},
+};
+use bevy_math::{UVec2, Vec2};
+use bevy_reflect::prelude::*;
+use bevy_reflect::FromReflect;
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+use wgpu::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
+
+/// The size of an [`ImageRenderTarget`].
+#[derive(Debug, Clone, Copy, Reflect, FromReflect)]
+pub enum ImageRenderTargetSize {
+    /// A fixed size in physical pixels.
+    Fixed(UVec2),
+    /// The physical size of the window multiplied by `scale`, which follows the window when it is
+    /// resized.
+    Window { window: WindowRef, scale: f32 },
+}
+
+impl Default for ImageRenderTargetSize {
+    fn default() -> Self {
+        Self::Window {
+            window: WindowRef::Primary,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Renders a [`Camera`] to an [`Image`] which is created and resized automatically.
+///
+/// The [`image`](ImageRenderTarget::image) handle stays the same when the image is resized, so
+/// it can be used in materials and UI like any other image. The [`Camera::target`] is set to the
+/// image by [`update_image_render_targets`].
+///
+/// ```
+/// # use bevy_asset::Assets;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::UVec2;
+/// # use bevy_render::camera::{Camera, ImageRenderTarget, ImageRenderTargetSize};
+/// # use bevy_render::texture::Image;
+/// fn spawn_minimap_camera(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+///     let target = ImageRenderTarget::new(ImageRenderTargetSize::Fixed(UVec2::splat(256)), &mut images);
+///     // `target.image` can be displayed by a material or a UI node
+///     commands.spawn((Camera::default(), target));
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_minimap_camera);
+/// ```
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct ImageRenderTarget {
+    /// The image the camera renders to.
+    pub image: Handle<Image>,
+    /// The size the image is kept at by [`update_image_render_targets`].
+    pub size: ImageRenderTargetSize,
+}
+
+impl ImageRenderTarget {
+    /// Creates the target image with the [`TextureFormat::bevy_default`] format.
+    pub fn new(size: ImageRenderTargetSize, images: &mut Assets<Image>) -> Self {
+        Self::with_format(size, TextureFormat::bevy_default(), images)
+    }
+
+    /// Creates the target image with the given `format`.
+    pub fn with_format(
+        size: ImageRenderTargetSize,
+        format: TextureFormat,
+        images: &mut Assets<Image>,
+    ) -> Self {
+        let extent = match size {
+            ImageRenderTargetSize::Fixed(size) => extent(size),
+            // resized once the window is known
+            ImageRenderTargetSize::Window { .. } => extent(UVec2::ONE),
+        };
+        let mut image = Image {
+            texture_descriptor: TextureDescriptor {
+                label: Some("image_render_target"),
+                size: extent,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            },
+            ..Default::default()
+        };
+        image.resize(extent);
+        Self {
+            image: images.add(image),
+            size,
+        }
+    }
+}
+
+fn extent(size: UVec2) -> Extent3d {
+    Extent3d {
+        width: size.x.max(1),
+        height: size.y.max(1),
+        depth_or_array_layers: 1,
+    }
+}
+
+/// Points the [`Camera::target`] of cameras with an [`ImageRenderTarget`] to its image, and
+/// resizes the image to the [`ImageRenderTargetSize`].
+pub fn update_image_render_targets(
+    mut cameras: Query<(&mut Camera, &ImageRenderTarget)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let primary_window = primary_window.iter().next();
+
+    for (mut camera, target) in &mut cameras {
+        let retarget =
+            !matches!(&camera.target, RenderTarget::Image(image) if *image == target.image);
+        if retarget {
+            camera.target = RenderTarget::Image(target.image.clone());
+        }
+
+        let size = match target.size {
+            ImageRenderTargetSize::Fixed(size) => size,
+            ImageRenderTargetSize::Window { window, scale } => {
+                let Some(window) = window
+                    .normalize(primary_window)
+                    .and_then(|window| windows.get(window.entity()).ok())
+                else {
+                    continue;
+                };
+                let size = Vec2::new(
+                    window.physical_width() as f32,
+                    window.physical_height() as f32,
+                );
+                (size * scale).as_uvec2()
+            }
+        };
+        let size = extent(size);
+
+        // only mutably access the image if needed, as this sends an asset event which makes the
+        // `camera_system` update the target info of the camera
+        let resize = images
+            .get(&target.image)
+            .map_or(false, |image| image.texture_descriptor.size != size);
+        if resize || retarget {
+            if let Some(image) = images.get_mut(&target.image) {
+                if resize {
+                    image.resize(size);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_window::WindowResolution;
+
+    fn image_size(app: &App, target: &ImageRenderTarget) -> UVec2 {
+        let size = app
+            .world
+            .resource::<Assets<Image>>()
+            .get(&target.image)
+            .unwrap()
+            .texture_descriptor
+            .size;
+        UVec2::new(size.width, size.height)
+    }
+
+    #[test]
+    fn image_follows_window_size() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_system(update_image_render_targets);
+
+        let window = app
+            .world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(800.0, 600.0),
+                    ..Default::default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        let target = ImageRenderTarget::new(
+            ImageRenderTargetSize::Window {
+                window: WindowRef::Primary,
+                scale: 0.5,
+            },
+            &mut app.world.resource_mut::<Assets<Image>>(),
+        );
+        let camera = app.world.spawn((Camera::default(), target.clone())).id();
+
+        app.update();
+        assert!(matches!(
+            &app.world.get::<Camera>(camera).unwrap().target,
+            RenderTarget::Image(image) if *image == target.image
+        ));
+        assert_eq!(image_size(&app, &target), UVec2::new(400, 300));
+
+        app.world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(1000.0, 200.0);
+        app.update();
+        assert_eq!(image_size(&app, &target), UVec2::new(500, 100));
+    }
+
+    #[test]
+    fn fixed_size_is_restored() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_system(update_image_render_targets);
+
+        let target = ImageRenderTarget::new(
+            ImageRenderTargetSize::Fixed(UVec2::new(64, 32)),
+            &mut app.world.resource_mut::<Assets<Image>>(),
+        );
+        app.world.spawn((Camera::default(), target.clone()));
+        app.update();
+        assert_eq!(image_size(&app, &target), UVec2::new(64, 32));
+
+        app.world
+            .resource_mut::<Assets<Image>>()
+            .get_mut(&target.image)
+            .unwrap()
+            .resize(extent(UVec2::new(8, 8)));
+        app.update();
+        assert_eq!(image_size(&app, &target), UVec2::new(64, 32));
+    }
+}
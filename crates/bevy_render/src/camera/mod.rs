@@ -1,14 +1,16 @@
 #[allow(clippy::module_inception)]
 mod camera;
 mod camera_driver_node;
+mod image_render_target;
 mod projection;
 
 pub use camera::*;
 pub use camera_driver_node::*;
+pub use image_render_target::*;
 pub use projection::*;
 
 use crate::{render_graph::RenderGraph, ExtractSchedule, RenderApp, RenderSet};
-use bevy_app::{App, IntoSystemAppConfig, Plugin};
+use bevy_app::{App, CoreSet, IntoSystemAppConfig, Plugin, StartupSet};
 use bevy_ecs::schedule::IntoSystemConfig;
 
 #[derive(Default)]
@@ -22,9 +24,21 @@ impl Plugin for CameraPlugin {
             .register_type::<ScalingMode>()
             .register_type::<CameraRenderGraph>()
             .register_type::<RenderTarget>()
+            .register_type::<ImageRenderTarget>()
+            .register_type::<ImageRenderTargetSize>()
+            .register_type::<BlendedProjection>()
             .add_plugin(CameraProjectionPlugin::<Projection>::default())
             .add_plugin(CameraProjectionPlugin::<OrthographicProjection>::default())
-            .add_plugin(CameraProjectionPlugin::<PerspectiveProjection>::default());
+            .add_plugin(CameraProjectionPlugin::<PerspectiveProjection>::default())
+            .add_systems((
+                update_image_render_targets
+                    .on_startup()
+                    .in_base_set(StartupSet::PostStartup)
+                    .before(CameraUpdateSystem),
+                update_image_render_targets
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(CameraUpdateSystem),
+            ));
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
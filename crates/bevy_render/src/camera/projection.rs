@@ -68,6 +68,7 @@ pub trait CameraProjection {
 pub enum Projection {
     Perspective(PerspectiveProjection),
     Orthographic(OrthographicProjection),
+    Blended(BlendedProjection),
 }
 
 impl From<PerspectiveProjection> for Projection {
@@ -82,11 +83,18 @@ impl From<OrthographicProjection> for Projection {
     }
 }
 
+impl From<BlendedProjection> for Projection {
+    fn from(p: BlendedProjection) -> Self {
+        Self::Blended(p)
+    }
+}
+
 impl CameraProjection for Projection {
     fn get_projection_matrix(&self) -> Mat4 {
         match self {
             Projection::Perspective(projection) => projection.get_projection_matrix(),
             Projection::Orthographic(projection) => projection.get_projection_matrix(),
+            Projection::Blended(projection) => projection.get_projection_matrix(),
         }
     }
 
@@ -94,6 +102,7 @@ impl CameraProjection for Projection {
         match self {
             Projection::Perspective(projection) => projection.update(width, height),
             Projection::Orthographic(projection) => projection.update(width, height),
+            Projection::Blended(projection) => projection.update(width, height),
         }
     }
 
@@ -101,6 +110,7 @@ impl CameraProjection for Projection {
         match self {
             Projection::Perspective(projection) => projection.far(),
             Projection::Orthographic(projection) => projection.far(),
+            Projection::Blended(projection) => projection.far(),
         }
     }
 }
@@ -325,3 +335,124 @@ impl Default for OrthographicProjection {
         }
     }
 }
+
+/// A camera projection which blends between a [`PerspectiveProjection`] and an
+/// [`OrthographicProjection`], for smooth transitions between both.
+///
+/// The projection matrices are interpolated linearly, which keeps straight lines straight and
+/// results in a valid view frustum for every `t`, so culling works throughout the transition.
+/// Animate `t` from `0.0` to `1.0` to transition from the perspective to the orthographic view.
+///
+/// Use [`BlendedProjection::dolly_zoom`] to create an orthographic projection which matches the
+/// perspective at a focus distance, so only the perspective of objects off that plane changes.
+#[derive(Debug, Clone, Reflect, FromReflect)]
+#[reflect(Default)]
+pub struct BlendedProjection {
+    /// The projection used when `t` is `0.0`.
+    pub perspective: PerspectiveProjection,
+    /// The projection used when `t` is `1.0`.
+    ///
+    /// The `near` plane should match the one of the `perspective` projection, otherwise the near
+    /// plane moves during the transition.
+    pub orthographic: OrthographicProjection,
+    /// The blend factor from the `perspective` (`0.0`) to the `orthographic` (`1.0`) projection,
+    /// clamped to that range.
+    pub t: f32,
+}
+
+impl BlendedProjection {
+    /// Creates a blend from the `perspective` projection to an orthographic projection which shows
+    /// the same area at `focus_distance` from the camera, starting at `t` = `0.0`.
+    pub fn dolly_zoom(perspective: PerspectiveProjection, focus_distance: f32) -> Self {
+        let height = 2.0 * focus_distance * (perspective.fov / 2.0).tan();
+        let orthographic = OrthographicProjection {
+            near: perspective.near,
+            far: perspective.far,
+            scaling_mode: ScalingMode::FixedVertical(height),
+            ..Default::default()
+        };
+        Self {
+            perspective,
+            orthographic,
+            t: 0.0,
+        }
+    }
+}
+
+impl CameraProjection for BlendedProjection {
+    fn get_projection_matrix(&self) -> Mat4 {
+        let t = self.t.clamp(0.0, 1.0);
+        self.perspective.get_projection_matrix() * (1.0 - t)
+            + self.orthographic.get_projection_matrix() * t
+    }
+
+    fn update(&mut self, width: f32, height: f32) {
+        self.perspective.update(width, height);
+        self.orthographic.update(width, height);
+    }
+
+    fn far(&self) -> f32 {
+        let t = self.t.clamp(0.0, 1.0);
+        self.perspective.far() * (1.0 - t) + self.orthographic.far() * t
+    }
+}
+
+impl Default for BlendedProjection {
+    fn default() -> Self {
+        Self::dolly_zoom(Default::default(), 10.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec3;
+
+    #[test]
+    fn blended_projection_matches_its_projections_at_the_ends() {
+        let mut projection = BlendedProjection::dolly_zoom(PerspectiveProjection::default(), 5.0);
+        projection.update(1280.0, 720.0);
+
+        projection.t = 0.0;
+        assert_eq!(
+            projection.get_projection_matrix(),
+            projection.perspective.get_projection_matrix()
+        );
+        assert_eq!(projection.far(), projection.perspective.far());
+
+        projection.t = 1.0;
+        assert_eq!(
+            projection.get_projection_matrix(),
+            projection.orthographic.get_projection_matrix()
+        );
+        assert_eq!(projection.far(), projection.orthographic.far());
+
+        // `t` is clamped
+        projection.t = 2.0;
+        assert_eq!(
+            projection.get_projection_matrix(),
+            projection.orthographic.get_projection_matrix()
+        );
+    }
+
+    #[test]
+    fn dolly_zoom_keeps_the_focus_plane() {
+        let mut projection = BlendedProjection::dolly_zoom(PerspectiveProjection::default(), 5.0);
+        projection.update(1280.0, 720.0);
+
+        let point = Vec3::new(1.5, -0.5, -5.0);
+        let ndc = |projection: &BlendedProjection| {
+            projection.get_projection_matrix().project_point3(point)
+        };
+        projection.t = 0.0;
+        let perspective = ndc(&projection);
+        for t in [0.25, 0.5, 1.0] {
+            projection.t = t;
+            let blended = ndc(&projection);
+            assert!(
+                blended.truncate().abs_diff_eq(perspective.truncate(), 1e-5),
+                "{blended} != {perspective} at t = {t}"
+            );
+        }
+    }
+}
@@ -0,0 +1,461 @@
+mod node;
+
+pub use node::*;
+
+use crate::{
+    extract_component::{ExtractComponent, ExtractComponentPlugin},
+    render_asset::RenderAssets,
+    render_graph::RenderGraph,
+    render_resource::{
+        AsBindGroup, AsBindGroupError, BindGroup, BindGroupLayout, Buffer, BufferDescriptor,
+        BufferUsages, CachedComputePipelineId, ComputePipelineDescriptor, MapMode, PipelineCache,
+        Shader, ShaderDefVal,
+    },
+    renderer::RenderDevice,
+    texture::{FallbackImage, Image},
+    RenderApp, RenderSet,
+};
+use async_channel::{Receiver, Sender};
+use bevy_app::{App, CoreSet, Plugin};
+use bevy_asset::{AddAsset, Assets, Handle, HandleId};
+use bevy_ecs::{prelude::*, query::QueryItem};
+use bevy_math::UVec3;
+use bevy_reflect::TypeUuid;
+use bevy_utils::{tracing::warn, HashMap};
+use std::{borrow::Cow, marker::PhantomData};
+
+/// Adds support for reading the results of [`ComputeJob`]s back into [`ComputeBuffer`] assets,
+/// and dispatches the jobs of all [`ComputeJobPlugin`]s.
+#[derive(Default)]
+pub struct ComputePlugin;
+
+impl Plugin for ComputePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<ComputeBuffer>();
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        let (sender, receiver) = async_channel::unbounded();
+        let (mapped_sender, mapped_receiver) = async_channel::unbounded();
+        render_app
+            .insert_resource(ComputeReadbackSender(sender))
+            .insert_resource(MappedReadbacks {
+                sender: mapped_sender,
+                receiver: mapped_receiver,
+            })
+            .init_resource::<ReadbackStagingBuffers>()
+            .add_system(send_compute_readbacks.in_set(RenderSet::Prepare))
+            .add_system(
+                map_compute_readbacks
+                    .in_set(RenderSet::Cleanup)
+                    .before(World::clear_entities),
+            );
+
+        let compute_node = ComputeNode::new(&mut render_app.world);
+        let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+        render_graph.add_node(crate::main_graph::node::COMPUTE, compute_node);
+        render_graph.add_node_edge(
+            crate::main_graph::node::COMPUTE,
+            crate::main_graph::node::CAMERA_DRIVER,
+        );
+
+        app.insert_resource(ComputeReadbackReceiver(receiver))
+            .add_system(apply_compute_readbacks.in_base_set(CoreSet::PreUpdate));
+    }
+}
+
+/// Adds support for dispatching [`ComputeJob`]s whose resources are bound with `B`.
+///
+/// Requires the [`ComputePlugin`], which is part of the `RenderPlugin`.
+pub struct ComputeJobPlugin<B: AsBindGroup>(PhantomData<B>);
+
+impl<B: AsBindGroup> Default for ComputeJobPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: AsBindGroup + Clone + Send + Sync + 'static> Plugin for ComputeJobPlugin<B> {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ExtractComponentPlugin::<ComputeJob<B>>::default());
+
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .init_resource::<ComputeJobPipelines<B>>()
+                .add_system(queue_compute_jobs::<B>.in_set(RenderSet::Queue));
+        }
+    }
+}
+
+/// Raw bytes which receive the results of a [`ComputeReadback`].
+#[derive(Debug, Clone, Default, TypeUuid)]
+#[uuid = "b8a6c1f4-7d2e-4c35-9b0a-3e61f8d2a7c9"]
+pub struct ComputeBuffer {
+    pub data: Vec<u8>,
+}
+
+impl ComputeBuffer {
+    /// Creates a buffer holding the bytes of the `data`.
+    pub fn from_slice<T: bytemuck::Pod>(data: &[T]) -> Self {
+        Self {
+            data: bytemuck::cast_slice(data).to_vec(),
+        }
+    }
+
+    /// Creates a zeroed buffer of `size` bytes.
+    pub fn zeroed(size: usize) -> Self {
+        Self {
+            data: vec![0; size],
+        }
+    }
+
+    /// Interprets the data of the buffer as a slice of `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the data isn't a multiple of the size of `T`.
+    pub fn cast_slice<T: bytemuck::Pod>(&self) -> &[T] {
+        bytemuck::cast_slice(&self.data)
+    }
+}
+
+/// Copies the `source` buffer into the `target` asset after the [`ComputeJob`] was dispatched.
+///
+/// The `source` must have [`BufferUsages::COPY_SRC`] and a size which is a multiple of
+/// [`wgpu::COPY_BUFFER_ALIGNMENT`]. It is usually also bound as a storage buffer of the job.
+///
+/// The data arrives in the main world a frame or two after the dispatch, because the GPU has to
+/// finish the work first. Each time, the `target` is updated with [`Assets::get_mut`], which sends
+/// an [`AssetEvent::Modified`](bevy_asset::AssetEvent::Modified).
+#[derive(Debug, Clone)]
+pub struct ComputeReadback {
+    pub source: Buffer,
+    pub target: Handle<ComputeBuffer>,
+}
+
+/// Dispatches the compute shader every frame, before any camera is rendered.
+///
+/// The resources of the job are bound to group 0 by their [`AsBindGroup`] implementation, which
+/// is usually derived. The bindings must be visible to the compute stage, and the
+/// [`ComputeJobPlugin`] of the type has to be added to the app. Jobs whose shader or bindings
+/// aren't loaded yet are skipped.
+///
+/// ```
+/// # use bevy_asset::{Assets, AssetServer};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::UVec3;
+/// # use bevy_render::{
+/// #     compute::{ComputeBuffer, ComputeJob, ComputeReadback},
+/// #     render_resource::{AsBindGroup, Buffer, BufferDescriptor, BufferUsages},
+/// #     renderer::RenderDevice,
+/// # };
+/// #[derive(AsBindGroup, Clone)]
+/// struct ParticleBindings {
+///     #[storage(0, buffer, visibility(compute))]
+///     particles: Buffer,
+/// }
+///
+/// fn setup(
+///     mut commands: Commands,
+///     asset_server: Res<AssetServer>,
+///     render_device: Res<RenderDevice>,
+///     mut buffers: ResMut<Assets<ComputeBuffer>>,
+/// ) {
+///     let particles = render_device.create_buffer(&BufferDescriptor {
+///         label: Some("particles"),
+///         size: 1024 * 16,
+///         usage: BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+///         mapped_at_creation: false,
+///     });
+///     commands.spawn(ComputeJob {
+///         workgroups: UVec3::new(16, 1, 1),
+///         readbacks: vec![ComputeReadback {
+///             source: particles.clone(),
+///             target: buffers.add(ComputeBuffer::default()),
+///         }],
+///         ..ComputeJob::new(
+///             asset_server.load("shaders/particles.wgsl"),
+///             "update",
+///             ParticleBindings { particles },
+///         )
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+#[derive(Component, Clone)]
+pub struct ComputeJob<B: AsBindGroup> {
+    /// The WGSL shader containing the entry point.
+    pub shader: Handle<Shader>,
+    pub shader_defs: Vec<ShaderDefVal>,
+    pub entry_point: Cow<'static, str>,
+    /// The resources bound to group 0.
+    pub bindings: B,
+    /// The number of workgroups dispatched in each dimension.
+    pub workgroups: UVec3,
+    pub readbacks: Vec<ComputeReadback>,
+}
+
+impl<B: AsBindGroup> ComputeJob<B> {
+    /// Creates a job dispatching a single workgroup of the `entry_point` in the `shader`.
+    pub fn new(
+        shader: Handle<Shader>,
+        entry_point: impl Into<Cow<'static, str>>,
+        bindings: B,
+    ) -> Self {
+        Self {
+            shader,
+            shader_defs: Vec::new(),
+            entry_point: entry_point.into(),
+            bindings,
+            workgroups: UVec3::ONE,
+            readbacks: Vec::new(),
+        }
+    }
+}
+
+impl<B: AsBindGroup + Clone + Send + Sync + 'static> ExtractComponent for ComputeJob<B> {
+    type Query = &'static Self;
+    type Filter = ();
+    type Out = Self;
+
+    fn extract_component(job: QueryItem<'_, Self::Query>) -> Option<Self> {
+        Some(job.clone())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ComputeJobPipelineKey {
+    shader: Handle<Shader>,
+    shader_defs: Vec<ShaderDefVal>,
+    entry_point: Cow<'static, str>,
+}
+
+/// The bind group layout of `B` and the pipelines of the [`ComputeJob`]s using it, which are
+/// shared by jobs with the same shader.
+#[derive(Resource)]
+pub struct ComputeJobPipelines<B: AsBindGroup> {
+    pub layout: BindGroupLayout,
+    pipelines: HashMap<ComputeJobPipelineKey, CachedComputePipelineId>,
+    marker: PhantomData<B>,
+}
+
+impl<B: AsBindGroup> FromWorld for ComputeJobPipelines<B> {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            layout: B::bind_group_layout(world.resource::<RenderDevice>()),
+            pipelines: HashMap::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A [`ComputeJob`] whose pipeline and bind group are ready, dispatched by the [`ComputeNode`].
+#[derive(Component)]
+pub struct PreparedComputeJob {
+    pub pipeline: CachedComputePipelineId,
+    pub bind_group: BindGroup,
+    pub workgroups: UVec3,
+    pub readbacks: Vec<PreparedReadback>,
+}
+
+/// A copy of a buffer into a mappable staging buffer of the same size.
+#[derive(Clone)]
+pub struct PreparedReadback {
+    pub source: Buffer,
+    pub staging: Buffer,
+    pub target: HandleId,
+}
+
+/// Sends the data read back from the GPU to the main world.
+#[derive(Resource)]
+pub struct ComputeReadbackSender(Sender<(HandleId, Vec<u8>)>);
+
+/// Receives the data read back from the GPU in the main world.
+#[derive(Resource)]
+pub struct ComputeReadbackReceiver(Receiver<(HandleId, Vec<u8>)>);
+
+/// Staging buffers whose mapping has completed.
+#[derive(Resource)]
+struct MappedReadbacks {
+    sender: Sender<PreparedReadback>,
+    receiver: Receiver<PreparedReadback>,
+}
+
+/// The staging buffers of each readback target which are currently unused, so they can be reused
+/// instead of allocating new ones every frame.
+///
+/// A buffer is taken out while it is copied to and mapped, and returned once its data was sent to
+/// the main world.
+#[derive(Resource, Default)]
+struct ReadbackStagingBuffers {
+    buffers: HashMap<HandleId, Vec<Buffer>>,
+}
+
+impl ReadbackStagingBuffers {
+    fn take(&mut self, target: HandleId, size: u64) -> Option<Buffer> {
+        let buffers = self.buffers.get_mut(&target)?;
+        // The size of the source changed, so the old staging buffers are useless
+        buffers.retain(|buffer| buffer.size() == size);
+        buffers.pop()
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn queue_compute_jobs<B: AsBindGroup + Send + Sync + 'static>(
+    mut commands: Commands,
+    jobs: Query<(Entity, &ComputeJob<B>)>,
+    mut pipelines: ResMut<ComputeJobPipelines<B>>,
+    mut staging_buffers: ResMut<ReadbackStagingBuffers>,
+    pipeline_cache: Res<PipelineCache>,
+    render_device: Res<RenderDevice>,
+    images: Res<RenderAssets<Image>>,
+    fallback_image: Res<FallbackImage>,
+) {
+    let pipelines = &mut *pipelines;
+    for (entity, job) in &jobs {
+        let key = ComputeJobPipelineKey {
+            shader: job.shader.clone_weak(),
+            shader_defs: job.shader_defs.clone(),
+            entry_point: job.entry_point.clone(),
+        };
+        let pipeline = *pipelines.pipelines.entry(key).or_insert_with_key(|key| {
+            pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+                label: Some("compute_job_pipeline".into()),
+                layout: vec![pipelines.layout.clone()],
+                push_constant_ranges: Vec::new(),
+                shader: key.shader.clone(),
+                shader_defs: key.shader_defs.clone(),
+                entry_point: key.entry_point.clone(),
+            })
+        });
+        // only dispatch, and read back, jobs whose pipeline is ready
+        if pipeline_cache.get_compute_pipeline(pipeline).is_none() {
+            continue;
+        }
+
+        let bind_group = match job.bindings.as_bind_group(
+            &pipelines.layout,
+            &render_device,
+            &images,
+            &fallback_image,
+        ) {
+            Ok(prepared) => prepared.bind_group,
+            Err(AsBindGroupError::RetryNextUpdate) => continue,
+        };
+
+        let readbacks = job
+            .readbacks
+            .iter()
+            .map(|readback| {
+                let target = readback.target.id();
+                let size = readback.source.size();
+                let staging = staging_buffers.take(target, size).unwrap_or_else(|| {
+                    render_device.create_buffer(&BufferDescriptor {
+                        label: Some("compute_readback_staging_buffer"),
+                        size,
+                        usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                        mapped_at_creation: false,
+                    })
+                });
+                PreparedReadback {
+                    source: readback.source.clone(),
+                    staging,
+                    target,
+                }
+            })
+            .collect();
+
+        commands.entity(entity).insert(PreparedComputeJob {
+            pipeline,
+            bind_group,
+            workgroups: job.workgroups,
+            readbacks,
+        });
+    }
+}
+
+/// Maps the staging buffers of the readbacks copied this frame.
+fn map_compute_readbacks(jobs: Query<&PreparedComputeJob>, mapped: Res<MappedReadbacks>) {
+    for readback in jobs.iter().flat_map(|job| &job.readbacks) {
+        let sender = mapped.sender.clone();
+        let mapped_readback = readback.clone();
+        readback
+            .staging
+            .slice(..)
+            .map_async(MapMode::Read, move |result| match result {
+                Ok(()) => {
+                    let _ = sender.try_send(mapped_readback);
+                }
+                Err(err) => warn!("Failed to map compute readback buffer: {err}"),
+            });
+    }
+}
+
+/// Sends the data of the mapped staging buffers to the main world, and returns the buffers to the
+/// [`ReadbackStagingBuffers`].
+fn send_compute_readbacks(
+    mapped: Res<MappedReadbacks>,
+    sender: Res<ComputeReadbackSender>,
+    mut staging_buffers: ResMut<ReadbackStagingBuffers>,
+) {
+    while let Ok(readback) = mapped.receiver.try_recv() {
+        let data = readback.staging.slice(..).get_mapped_range().to_vec();
+        readback.staging.unmap();
+        let _ = sender.0.try_send((readback.target, data));
+        staging_buffers
+            .buffers
+            .entry(readback.target)
+            .or_default()
+            .push(readback.staging);
+    }
+}
+
+/// Writes the data read back from the GPU into the [`ComputeBuffer`] assets.
+///
+/// Readbacks whose target was removed in the meantime are dropped.
+pub fn apply_compute_readbacks(
+    receiver: Res<ComputeReadbackReceiver>,
+    mut buffers: ResMut<Assets<ComputeBuffer>>,
+) {
+    while let Ok((target, data)) = receiver.0.try_recv() {
+        if let Some(buffer) = buffers.get_mut(&Handle::weak(target)) {
+            buffer.data = data;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::AssetEvent;
+
+    #[test]
+    fn readbacks_are_written_to_the_target() {
+        let mut app = App::new();
+        let (sender, receiver) = async_channel::unbounded();
+        app.add_plugin(bevy_asset::AssetPlugin::default())
+            .add_asset::<ComputeBuffer>()
+            .insert_resource(ComputeReadbackReceiver(receiver))
+            .add_system(apply_compute_readbacks);
+
+        let target = app
+            .world
+            .resource_mut::<Assets<ComputeBuffer>>()
+            .add(ComputeBuffer::zeroed(8));
+        sender
+            .try_send((target.id(), bytemuck::cast_slice(&[1u32, 2]).to_vec()))
+            .unwrap();
+        app.update();
+
+        let buffers = app.world.resource::<Assets<ComputeBuffer>>();
+        assert_eq!(buffers.get(&target).unwrap().cast_slice::<u32>(), &[1, 2]);
+
+        let events = app.world.resource::<Events<AssetEvent<ComputeBuffer>>>();
+        assert!(events
+            .get_reader()
+            .iter(events)
+            .any(|event| matches!(event, AssetEvent::Modified { handle } if *handle == target)));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    compute::PreparedComputeJob,
+    render_graph::{Node, NodeRunError, RenderGraphContext},
+    render_resource::{ComputePassDescriptor, PipelineCache},
+    renderer::RenderContext,
+};
+use bevy_ecs::{prelude::*, query::QueryState};
+
+/// Dispatches the [`PreparedComputeJob`]s and copies their readbacks into the staging buffers.
+pub struct ComputeNode {
+    query: QueryState<&'static PreparedComputeJob>,
+}
+
+impl ComputeNode {
+    pub fn new(world: &mut World) -> Self {
+        Self {
+            query: QueryState::new(world),
+        }
+    }
+}
+
+impl Node for ComputeNode {
+    fn update(&mut self, world: &mut World) {
+        self.query.update_archetypes(world);
+    }
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        for job in self.query.iter_manual(world) {
+            let Some(pipeline) = pipeline_cache.get_compute_pipeline(job.pipeline) else {
+                continue;
+            };
+
+            {
+                let mut pass =
+                    render_context
+                        .command_encoder()
+                        .begin_compute_pass(&ComputePassDescriptor {
+                            label: Some("compute_job_pass"),
+                        });
+                pass.set_pipeline(pipeline);
+                pass.set_bind_group(0, &job.bind_group, &[]);
+                pass.dispatch_workgroups(job.workgroups.x, job.workgroups.y, job.workgroups.z);
+            }
+
+            for readback in &job.readbacks {
+                render_context.command_encoder().copy_buffer_to_buffer(
+                    &readback.source,
+                    0,
+                    &readback.staging,
+                    0,
+                    readback.source.size(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ extern crate core;
 
 pub mod camera;
 pub mod color;
+pub mod compute;
 pub mod extract_component;
 mod extract_param;
 pub mod extract_resource;
@@ -45,6 +46,7 @@ pub use once_cell;
 
 use crate::{
     camera::CameraPlugin,
+    compute::ComputePlugin,
     mesh::MeshPlugin,
     render_resource::{PipelineCache, Shader, ShaderLoader},
     renderer::{render_system, DefaultApi, RenderApi, RenderInstance},
@@ -175,6 +177,7 @@ impl DerefMut for MainWorld {
 pub mod main_graph {
     pub mod node {
         pub const CAMERA_DRIVER: &str = "camera_driver";
+        pub const COMPUTE: &str = "compute";
     }
 }
 
@@ -188,7 +191,8 @@ impl Plugin for RenderPlugin {
         app.add_asset::<Shader>()
             .add_debug_asset::<Shader>()
             .init_asset_loader::<ShaderLoader>()
-            .init_debug_asset_loader::<ShaderLoader>();
+            .init_debug_asset_loader::<ShaderLoader>()
+            .init_resource::<render_graph::RenderGraphEdits>();
 
         if !app.world.contains_resource::<RenderApi>() {
             app.world.insert_resource(RenderApi(Box::new(DefaultApi)));
@@ -244,7 +248,8 @@ impl Plugin for RenderPlugin {
             render_app.edit_schedule(ExtractSchedule, |schedule| {
                 schedule
                     .set_apply_final_buffers(false)
-                    .add_system(PipelineCache::extract_shaders);
+                    .add_system(PipelineCache::extract_shaders)
+                    .add_system(render_graph::extract_render_graph_edits);
             });
 
             // This set applies the commands from the extract stage while the render schedule
@@ -263,6 +268,7 @@ impl Plugin for RenderPlugin {
             render_app
                 .add_schedule(CoreSchedule::Main, render_schedule)
                 .init_resource::<render_graph::RenderGraph>()
+                .init_resource::<render_graph::RenderGraphEdits>()
                 .insert_resource(RenderInstance(instance))
                 .insert_resource(device)
                 .insert_resource(queue)
@@ -313,10 +319,12 @@ impl Plugin for RenderPlugin {
             .add_plugin(CameraPlugin)
             .add_plugin(ViewPlugin)
             .add_plugin(MeshPlugin)
+            .add_plugin(ComputePlugin)
             .add_plugin(GlobalsPlugin);
 
         app.register_type::<color::Color>()
             .register_type::<primitives::Aabb>()
+            .register_type::<primitives::BoundingSphere>()
             .register_type::<primitives::CascadesFrusta>()
             .register_type::<primitives::CubemapFrusta>()
             .register_type::<primitives::Frustum>();
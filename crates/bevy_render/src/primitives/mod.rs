@@ -4,6 +4,16 @@ use bevy_reflect::{FromReflect, Reflect};
 use bevy_utils::HashMap;
 
 /// An axis-aligned bounding box.
+///
+/// Used for frustum culling of the entity it is attached to. It is computed from the [`Mesh`]
+/// of the entity by [`calculate_bounds`] if missing, and recomputed when the mesh changes.
+/// It can be inserted manually to override the computed bounds, e.g. for meshes whose vertices
+/// are animated in a shader. Add [`NoAutoAabb`] to prevent it from being recomputed, or a
+/// [`BoundingSphere`] to cull the entity with a sphere instead.
+///
+/// [`Mesh`]: crate::mesh::Mesh
+/// [`calculate_bounds`]: crate::view::calculate_bounds
+/// [`NoAutoAabb`]: crate::view::NoAutoAabb
 #[derive(Component, Clone, Copy, Debug, Default, Reflect, FromReflect)]
 #[reflect(Component)]
 pub struct Aabb {
@@ -59,6 +69,36 @@ impl From<Sphere> for Aabb {
     }
 }
 
+/// A bounding sphere in the local space of the entity it is attached to.
+///
+/// If present, it is used for frustum culling of the entity instead of its [`Aabb`], which is
+/// useful for procedural or animated meshes that stay within a fixed radius. Unlike the [`Aabb`],
+/// it is never computed, and entities with only a [`BoundingSphere`] are also culled.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct BoundingSphere {
+    pub center: Vec3A,
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    /// Returns the sphere in world space, conservatively scaled by the largest scale of the
+    /// `local_to_world` transform.
+    #[inline]
+    pub fn to_world(&self, local_to_world: &Mat4) -> Sphere {
+        let scale = local_to_world
+            .x_axis
+            .truncate()
+            .length()
+            .max(local_to_world.y_axis.truncate().length())
+            .max(local_to_world.z_axis.truncate().length());
+        Sphere {
+            center: local_to_world.transform_point3a(self.center),
+            radius: self.radius * scale,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Sphere {
     pub center: Vec3A,
@@ -145,6 +145,7 @@ fn extract_render_asset<A: RenderAsset>(
                 changed_assets.remove(handle);
                 removed.push(handle.clone_weak());
             }
+            AssetEvent::Evicted { .. } | AssetEvent::DependencyModified { .. } => {}
         }
     }
 
@@ -0,0 +1,226 @@
+use crate::{
+    render_graph::{Node, NodeId, NodeLabel, NodeState, RenderGraph, SlotLabel},
+    MainWorld,
+};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    system::{ResMut, Resource},
+};
+use std::borrow::Cow;
+
+/// A single structural change to a [`RenderGraph`], see [`RenderGraphEditBatch`].
+#[derive(Debug)]
+pub enum RenderGraphEdit {
+    /// Adds the node together with the edges stored in its [`NodeState`].
+    AddNode(NodeState),
+    /// Removes the node and all of its edges.
+    RemoveNode(NodeLabel),
+    /// Adds an [`Edge::NodeEdge`](super::Edge::NodeEdge).
+    AddNodeEdge {
+        output_node: NodeLabel,
+        input_node: NodeLabel,
+    },
+    /// Removes an [`Edge::NodeEdge`](super::Edge::NodeEdge).
+    RemoveNodeEdge {
+        output_node: NodeLabel,
+        input_node: NodeLabel,
+    },
+    /// Adds an [`Edge::SlotEdge`](super::Edge::SlotEdge).
+    AddSlotEdge {
+        output_node: NodeLabel,
+        output_slot: SlotLabel,
+        input_node: NodeLabel,
+        input_slot: SlotLabel,
+    },
+    /// Removes an [`Edge::SlotEdge`](super::Edge::SlotEdge).
+    RemoveSlotEdge {
+        output_node: NodeLabel,
+        output_slot: SlotLabel,
+        input_node: NodeLabel,
+        input_slot: SlotLabel,
+    },
+    /// Adds a sub graph with the given name.
+    AddSubGraph(Cow<'static, str>, RenderGraph),
+    /// Removes the sub graph with the given name.
+    RemoveSubGraph(Cow<'static, str>),
+}
+
+/// A batch of [`RenderGraphEdit`]s to a [`RenderGraph`] or one of its sub graphs, which is
+/// applied atomically by [`RenderGraph::apply_edits`].
+///
+/// If any of the edits fails, or the edited graph doesn't pass [`RenderGraph::validate`]
+/// afterwards, the graph is left unchanged.
+///
+/// ## Example
+///
+/// Inserting a post processing node between two nodes of the 3d sub graph.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_render::render_graph::{Node, NodeRunError, RenderGraphContext, RenderGraphEditBatch, RenderGraphEdits};
+/// # use bevy_render::renderer::RenderContext;
+/// # struct PostProcessNode;
+/// # impl Node for PostProcessNode {
+/// #     fn run(&self, _: &mut RenderGraphContext, _: &mut RenderContext, _: &World) -> Result<(), NodeRunError> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// fn enable_post_processing(mut edits: ResMut<RenderGraphEdits>) {
+///     edits.push(
+///         RenderGraphEditBatch::new()
+///             .in_sub_graph("core_3d")
+///             .add_node("post_process", PostProcessNode)
+///             .remove_node_edge("tonemapping", "end_main_pass_post_processing")
+///             .add_node_edge("tonemapping", "post_process")
+///             .add_node_edge("post_process", "end_main_pass_post_processing"),
+///     );
+/// }
+/// # bevy_ecs::system::assert_is_system(enable_post_processing);
+/// ```
+#[derive(Debug, Default)]
+pub struct RenderGraphEditBatch {
+    pub(crate) sub_graph: Vec<Cow<'static, str>>,
+    pub(crate) edits: Vec<RenderGraphEdit>,
+}
+
+impl RenderGraphEditBatch {
+    /// Creates an empty batch editing the main render graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the batch to the sub graph with the `name` of the currently edited graph.
+    ///
+    /// Calling this multiple times selects nested sub graphs.
+    pub fn in_sub_graph(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.sub_graph.push(name.into());
+        self
+    }
+
+    /// Adds the [`RenderGraphEdit`] to the batch.
+    pub fn with_edit(mut self, edit: RenderGraphEdit) -> Self {
+        self.edits.push(edit);
+        self
+    }
+
+    /// Adds the `node` with the `name` to the graph. Fails if the name is already present.
+    pub fn add_node<T: Node>(self, name: impl Into<Cow<'static, str>>, node: T) -> Self {
+        let mut node_state = NodeState::new(NodeId::new(), node);
+        node_state.name = Some(name.into());
+        self.with_edit(RenderGraphEdit::AddNode(node_state))
+    }
+
+    /// Removes the node referenced by the `label` and all of its edges from the graph.
+    pub fn remove_node(self, label: impl Into<NodeLabel>) -> Self {
+        self.with_edit(RenderGraphEdit::RemoveNode(label.into()))
+    }
+
+    /// Adds an [`Edge::NodeEdge`](super::Edge::NodeEdge) to the graph.
+    pub fn add_node_edge(
+        self,
+        output_node: impl Into<NodeLabel>,
+        input_node: impl Into<NodeLabel>,
+    ) -> Self {
+        self.with_edit(RenderGraphEdit::AddNodeEdge {
+            output_node: output_node.into(),
+            input_node: input_node.into(),
+        })
+    }
+
+    /// Removes an [`Edge::NodeEdge`](super::Edge::NodeEdge) from the graph.
+    pub fn remove_node_edge(
+        self,
+        output_node: impl Into<NodeLabel>,
+        input_node: impl Into<NodeLabel>,
+    ) -> Self {
+        self.with_edit(RenderGraphEdit::RemoveNodeEdge {
+            output_node: output_node.into(),
+            input_node: input_node.into(),
+        })
+    }
+
+    /// Adds an [`Edge::SlotEdge`](super::Edge::SlotEdge) to the graph.
+    pub fn add_slot_edge(
+        self,
+        output_node: impl Into<NodeLabel>,
+        output_slot: impl Into<SlotLabel>,
+        input_node: impl Into<NodeLabel>,
+        input_slot: impl Into<SlotLabel>,
+    ) -> Self {
+        self.with_edit(RenderGraphEdit::AddSlotEdge {
+            output_node: output_node.into(),
+            output_slot: output_slot.into(),
+            input_node: input_node.into(),
+            input_slot: input_slot.into(),
+        })
+    }
+
+    /// Removes an [`Edge::SlotEdge`](super::Edge::SlotEdge) from the graph.
+    pub fn remove_slot_edge(
+        self,
+        output_node: impl Into<NodeLabel>,
+        output_slot: impl Into<SlotLabel>,
+        input_node: impl Into<NodeLabel>,
+        input_slot: impl Into<SlotLabel>,
+    ) -> Self {
+        self.with_edit(RenderGraphEdit::RemoveSlotEdge {
+            output_node: output_node.into(),
+            output_slot: output_slot.into(),
+            input_node: input_node.into(),
+            input_slot: input_slot.into(),
+        })
+    }
+
+    /// Adds the `sub_graph` with the `name` to the graph. Fails if the name is already present.
+    pub fn add_sub_graph(self, name: impl Into<Cow<'static, str>>, sub_graph: RenderGraph) -> Self {
+        self.with_edit(RenderGraphEdit::AddSubGraph(name.into(), sub_graph))
+    }
+
+    /// Removes the sub graph with the `name` from the graph.
+    pub fn remove_sub_graph(self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.with_edit(RenderGraphEdit::RemoveSubGraph(name.into()))
+    }
+}
+
+/// A queue of [`RenderGraphEditBatch`]es, which are applied to the [`RenderGraph`] at the start of
+/// the next rendered frame, so the graph never changes while it is executed.
+///
+/// The resource exists in both the main world and the render world. Batches pushed in the main
+/// world are moved to the render world during extraction. Batches which fail to apply are logged
+/// and discarded.
+#[derive(Resource, Debug, Default)]
+pub struct RenderGraphEdits {
+    batches: Vec<RenderGraphEditBatch>,
+}
+
+impl RenderGraphEdits {
+    /// Queues the `batch` to be applied at the start of the next frame.
+    pub fn push(&mut self, batch: RenderGraphEditBatch) {
+        self.batches.push(batch);
+    }
+
+    /// Returns `true` if no batches are queued.
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    /// Removes all queued batches and returns them in the order they were pushed.
+    pub fn drain(&mut self) -> impl Iterator<Item = RenderGraphEditBatch> + '_ {
+        self.batches.drain(..)
+    }
+}
+
+/// Moves the [`RenderGraphEdits`] of the main world to the render world.
+pub fn extract_render_graph_edits(
+    mut main_world: ResMut<MainWorld>,
+    mut edits: ResMut<RenderGraphEdits>,
+) {
+    let Some(mut main_edits) = main_world.get_resource_mut::<RenderGraphEdits>() else {
+        return;
+    };
+    if !main_edits.is_empty() {
+        edits
+            .batches
+            .append(&mut main_edits.bypass_change_detection().batches);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     render_graph::{
         Edge, Node, NodeId, NodeLabel, NodeRunError, NodeState, RenderGraphContext,
-        RenderGraphError, SlotInfo, SlotLabel,
+        RenderGraphEdit, RenderGraphEditBatch, RenderGraphError, SlotInfo, SlotLabel,
     },
     renderer::RenderContext,
 };
@@ -126,47 +126,252 @@ impl RenderGraph {
         name: impl Into<Cow<'static, str>>,
     ) -> Result<(), RenderGraphError> {
         let name = name.into();
-        if let Some(id) = self.node_names.remove(&name) {
-            if let Some(node_state) = self.nodes.remove(&id) {
-                // Remove all edges from other nodes to this one. Note that as we're removing this
-                // node, we don't need to remove its input edges
-                for input_edge in node_state.edges.input_edges().iter() {
-                    match input_edge {
-                        Edge::SlotEdge { output_node, .. }
-                        | Edge::NodeEdge {
-                            input_node: _,
-                            output_node,
-                        } => {
-                            if let Ok(output_node) = self.get_node_state_mut(*output_node) {
-                                output_node.edges.remove_output_edge(input_edge.clone())?;
-                            }
-                        }
-                    }
+        if self.node_names.contains_key(&name) {
+            self.take_node(NodeLabel::Name(name))?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the node referenced by the `label` from the graph and returns its [`NodeState`].
+    ///
+    /// The edges of the node are removed from the other nodes, but are kept in the returned
+    /// [`NodeState`], so that they are restored when it is added again with a
+    /// [`RenderGraphEdit::AddNode`].
+    pub fn take_node(
+        &mut self,
+        label: impl Into<NodeLabel>,
+    ) -> Result<NodeState, RenderGraphError> {
+        let label = label.into();
+        let id = self.get_node_id(&label)?;
+        let node_state = self
+            .nodes
+            .remove(&id)
+            .ok_or(RenderGraphError::InvalidNode(label))?;
+        if let Some(name) = &node_state.name {
+            self.node_names.remove(name);
+        }
+
+        // Remove all edges from other nodes to this one. Note that as we're removing this
+        // node, we don't need to remove its input edges
+        for input_edge in node_state.edges.input_edges().iter() {
+            if let Ok(output_node) = self.get_node_state_mut(input_edge.get_output_node()) {
+                output_node.edges.remove_output_edge(input_edge.clone())?;
+            }
+        }
+        // Remove all edges from this node to other nodes. Note that as we're removing this
+        // node, we don't need to remove its output edges
+        for output_edge in node_state.edges.output_edges().iter() {
+            if let Ok(input_node) = self.get_node_state_mut(output_edge.get_input_node()) {
+                input_node.edges.remove_input_edge(output_edge.clone())?;
+            }
+        }
+
+        Ok(node_state)
+    }
+
+    /// Inserts the `node_state` and adds its edges to the other nodes.
+    fn insert_node(&mut self, node_state: NodeState) -> Result<NodeId, RenderGraphError> {
+        let id = node_state.id;
+        if self.nodes.contains_key(&id) {
+            return Err(RenderGraphError::NodeAlreadyExists(id.into()));
+        }
+        if let Some(name) = &node_state.name {
+            if self.node_names.contains_key(name) {
+                return Err(RenderGraphError::NodeAlreadyExists(NodeLabel::Name(
+                    name.clone(),
+                )));
+            }
+        }
+        let input_edges = node_state.edges.input_edges().iter();
+        let output_edges = node_state.edges.output_edges().iter();
+        for other in input_edges
+            .map(Edge::get_output_node)
+            .chain(output_edges.map(Edge::get_input_node))
+        {
+            if other != id && !self.nodes.contains_key(&other) {
+                return Err(RenderGraphError::InvalidNode(other.into()));
+            }
+        }
+
+        for input_edge in node_state.edges.input_edges().iter() {
+            if let Some(output_node) = self.nodes.get_mut(&input_edge.get_output_node()) {
+                output_node.edges.add_output_edge(input_edge.clone())?;
+            }
+        }
+        for output_edge in node_state.edges.output_edges().iter() {
+            if let Some(input_node) = self.nodes.get_mut(&output_edge.get_input_node()) {
+                input_node.edges.add_input_edge(output_edge.clone())?;
+            }
+        }
+        if let Some(name) = &node_state.name {
+            self.node_names.insert(name.clone(), id);
+        }
+        self.nodes.insert(id, node_state);
+
+        Ok(id)
+    }
+
+    /// Applies the [`RenderGraphEditBatch`] to this graph or the selected sub graph.
+    ///
+    /// The batch is applied atomically: if any edit fails or the edited graph does not pass
+    /// [`validate`](Self::validate), all edits are undone and the error is returned.
+    ///
+    /// This allows changing the structure of the graph at runtime, e.g. to toggle post
+    /// processing effects, instead of branching inside of the nodes every frame. Use
+    /// [`RenderGraphEdits`](super::RenderGraphEdits) to apply the edits at the start of the next
+    /// frame.
+    pub fn apply_edits(&mut self, batch: RenderGraphEditBatch) -> Result<(), RenderGraphError> {
+        let mut graph = self;
+        for name in batch.sub_graph {
+            graph = graph
+                .sub_graphs
+                .get_mut(&name)
+                .ok_or(RenderGraphError::InvalidSubGraph(name))?;
+        }
+
+        let mut undo = Vec::with_capacity(batch.edits.len());
+        let mut result = Ok(());
+        for edit in batch.edits {
+            match graph.apply_edit(edit) {
+                Ok(inverse) => undo.push(inverse),
+                Err(err) => {
+                    result = Err(err);
+                    break;
                 }
-                // Remove all edges from this node to other nodes. Note that as we're removing this
-                // node, we don't need to remove its output edges
-                for output_edge in node_state.edges.output_edges().iter() {
-                    match output_edge {
-                        Edge::SlotEdge {
-                            output_node: _,
-                            output_index: _,
-                            input_node,
-                            input_index: _,
-                        }
-                        | Edge::NodeEdge {
-                            output_node: _,
-                            input_node,
-                        } => {
-                            if let Ok(input_node) = self.get_node_state_mut(*input_node) {
-                                input_node.edges.remove_input_edge(output_edge.clone())?;
-                            }
-                        }
-                    }
+            }
+        }
+        if result.is_ok() {
+            result = graph.validate();
+        }
+
+        if result.is_err() {
+            for inverse in undo.into_iter().rev() {
+                graph
+                    .apply_edit(inverse)
+                    .expect("undoing an applied render graph edit should not fail");
+            }
+        }
+        result
+    }
+
+    /// Applies the `edit` and returns the edit undoing it.
+    fn apply_edit(&mut self, edit: RenderGraphEdit) -> Result<RenderGraphEdit, RenderGraphError> {
+        Ok(match edit {
+            RenderGraphEdit::AddNode(node_state) => {
+                RenderGraphEdit::RemoveNode(self.insert_node(node_state)?.into())
+            }
+            RenderGraphEdit::RemoveNode(label) => RenderGraphEdit::AddNode(self.take_node(label)?),
+            RenderGraphEdit::AddNodeEdge {
+                output_node,
+                input_node,
+            } => {
+                self.try_add_node_edge(&output_node, &input_node)?;
+                RenderGraphEdit::RemoveNodeEdge {
+                    output_node,
+                    input_node,
+                }
+            }
+            RenderGraphEdit::RemoveNodeEdge {
+                output_node,
+                input_node,
+            } => {
+                self.remove_node_edge(&output_node, &input_node)?;
+                RenderGraphEdit::AddNodeEdge {
+                    output_node,
+                    input_node,
+                }
+            }
+            RenderGraphEdit::AddSlotEdge {
+                output_node,
+                output_slot,
+                input_node,
+                input_slot,
+            } => {
+                self.try_add_slot_edge(
+                    &output_node,
+                    output_slot.clone(),
+                    &input_node,
+                    input_slot.clone(),
+                )?;
+                RenderGraphEdit::RemoveSlotEdge {
+                    output_node,
+                    output_slot,
+                    input_node,
+                    input_slot,
+                }
+            }
+            RenderGraphEdit::RemoveSlotEdge {
+                output_node,
+                output_slot,
+                input_node,
+                input_slot,
+            } => {
+                self.remove_slot_edge(
+                    &output_node,
+                    output_slot.clone(),
+                    &input_node,
+                    input_slot.clone(),
+                )?;
+                RenderGraphEdit::AddSlotEdge {
+                    output_node,
+                    output_slot,
+                    input_node,
+                    input_slot,
                 }
             }
+            RenderGraphEdit::AddSubGraph(name, sub_graph) => {
+                if self.sub_graphs.contains_key(&name) {
+                    return Err(RenderGraphError::SubGraphAlreadyExists(name));
+                }
+                self.sub_graphs.insert(name.clone(), sub_graph);
+                RenderGraphEdit::RemoveSubGraph(name)
+            }
+            RenderGraphEdit::RemoveSubGraph(name) => match self.sub_graphs.remove(&name) {
+                Some(sub_graph) => RenderGraphEdit::AddSubGraph(name, sub_graph),
+                None => return Err(RenderGraphError::InvalidSubGraph(name)),
+            },
+        })
+    }
+
+    /// Validates that the input slots of all nodes except the input node are connected and that
+    /// the edges of the graph don't form a cycle.
+    ///
+    /// Sub graphs are not validated.
+    pub fn validate(&self) -> Result<(), RenderGraphError> {
+        for node_state in self.nodes.values() {
+            if Some(node_state.id) != self.input_node {
+                node_state.validate_input_slots()?;
+            }
         }
 
-        Ok(())
+        // Kahn's algorithm: repeatedly visit the nodes whose inputs have all been visited.
+        let mut remaining_inputs: HashMap<NodeId, usize> = self
+            .nodes
+            .values()
+            .map(|node_state| (node_state.id, node_state.edges.input_edges().len()))
+            .collect();
+        let mut queue: Vec<NodeId> = remaining_inputs
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        while let Some(id) = queue.pop() {
+            remaining_inputs.remove(&id);
+            for edge in self.nodes[&id].edges.output_edges() {
+                let input_node = edge.get_input_node();
+                if let Some(count) = remaining_inputs.get_mut(&input_node) {
+                    *count -= 1;
+                    if *count == 0 {
+                        queue.push(input_node);
+                    }
+                }
+            }
+        }
+        match remaining_inputs.keys().next() {
+            Some(&node) => Err(RenderGraphError::Cycle { node }),
+            None => Ok(()),
+        }
     }
 
     /// Retrieves the [`NodeState`] referenced by the `label`.
@@ -630,8 +835,8 @@ impl Node for GraphInputNode {
 mod tests {
     use crate::{
         render_graph::{
-            Edge, Node, NodeId, NodeRunError, RenderGraph, RenderGraphContext, RenderGraphError,
-            SlotInfo, SlotType,
+            Edge, Node, NodeId, NodeRunError, RenderGraph, RenderGraphContext,
+            RenderGraphEditBatch, RenderGraphError, SlotInfo, SlotType,
         },
         renderer::RenderContext,
     };
@@ -803,4 +1008,129 @@ mod tests {
             "Adding to a duplicate edge should return an error"
         );
     }
+
+    #[test]
+    fn test_apply_edits() {
+        let mut graph = RenderGraph::default();
+        graph.add_node("A", TestNode::new(0, 1));
+        graph.add_node("B", TestNode::new(1, 0));
+        graph.add_slot_edge("A", 0, "B", 0);
+
+        graph
+            .apply_edits(
+                RenderGraphEditBatch::new()
+                    .add_node("C", TestNode::new(1, 1))
+                    .remove_slot_edge("A", 0, "B", 0)
+                    .add_slot_edge("A", 0, "C", 0)
+                    .add_slot_edge("C", 0, "B", 0),
+            )
+            .unwrap();
+        let c_id = graph.get_node_id("C").unwrap();
+        assert_eq!(
+            graph.get_node_state("B").unwrap().edges.input_edges(),
+            [Edge::SlotEdge {
+                output_node: c_id,
+                output_index: 0,
+                input_node: graph.get_node_id("B").unwrap(),
+                input_index: 0,
+            }]
+        );
+
+        graph
+            .apply_edits(
+                RenderGraphEditBatch::new()
+                    .remove_node("C")
+                    .add_slot_edge("A", 0, "B", 0),
+            )
+            .unwrap();
+        assert!(graph.get_node_state("C").is_err());
+        assert!(
+            graph
+                .get_node_state("A")
+                .unwrap()
+                .edges
+                .output_edges()
+                .len()
+                == 1
+        );
+    }
+
+    #[test]
+    fn test_apply_edits_is_atomic() {
+        let mut graph = RenderGraph::default();
+        let a_id = graph.add_node("A", TestNode::new(0, 1));
+        let b_id = graph.add_node("B", TestNode::new(1, 0));
+        graph.add_slot_edge("A", 0, "B", 0);
+
+        // Removing the node leaves the input slot of B unconnected.
+        assert_eq!(
+            graph.apply_edits(RenderGraphEditBatch::new().remove_node("A")),
+            Err(RenderGraphError::UnconnectedNodeInputSlot {
+                node: b_id,
+                input_slot: 0,
+            })
+        );
+        // A failing edit undoes the previous ones.
+        assert_eq!(
+            graph.apply_edits(
+                RenderGraphEditBatch::new()
+                    .add_node("C", TestNode::new(0, 0))
+                    .add_node_edge("C", "D")
+            ),
+            Err(RenderGraphError::InvalidNode("D".into()))
+        );
+        assert_eq!(
+            graph.apply_edits(RenderGraphEditBatch::new().in_sub_graph("sub")),
+            Err(RenderGraphError::InvalidSubGraph("sub".into()))
+        );
+
+        assert!(graph.get_node_state("C").is_err());
+        assert_eq!(graph.iter_nodes().count(), 2);
+        assert_eq!(
+            graph.get_node_state("B").unwrap().edges.input_edges(),
+            [Edge::SlotEdge {
+                output_node: a_id,
+                output_index: 0,
+                input_node: b_id,
+                input_index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_apply_edits_detects_cycles() {
+        let mut graph = RenderGraph::default();
+        graph.add_node("A", TestNode::new(0, 0));
+        graph.add_node("B", TestNode::new(0, 0));
+        graph.add_node_edge("A", "B");
+
+        let result = graph.apply_edits(RenderGraphEditBatch::new().add_node_edge("B", "A"));
+        assert!(matches!(result, Err(RenderGraphError::Cycle { .. })));
+        assert!(graph.validate().is_ok());
+    }
+
+    #[test]
+    fn test_apply_edits_to_sub_graph() {
+        let mut graph = RenderGraph::default();
+        graph.add_sub_graph("sub", RenderGraph::default());
+
+        graph
+            .apply_edits(
+                RenderGraphEditBatch::new()
+                    .in_sub_graph("sub")
+                    .add_sub_graph("nested", RenderGraph::default())
+                    .add_node("A", TestNode::new(0, 0)),
+            )
+            .unwrap();
+        let sub_graph = graph.get_sub_graph("sub").unwrap();
+        assert!(sub_graph.get_node_state("A").is_ok());
+        assert!(sub_graph.get_sub_graph("nested").is_some());
+
+        assert_eq!(
+            graph.apply_edits(
+                RenderGraphEditBatch::new().add_sub_graph("sub", RenderGraph::default())
+            ),
+            Err(RenderGraphError::SubGraphAlreadyExists("sub".into()))
+        );
+    }
 }
@@ -1,21 +1,30 @@
 mod context;
 mod edge;
+mod edit;
 mod graph;
 mod node;
 mod node_slot;
 
 pub use context::*;
 pub use edge::*;
+pub use edit::*;
 pub use graph::*;
 pub use node::*;
 pub use node_slot::*;
 
+use std::borrow::Cow;
 use thiserror::Error;
 
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum RenderGraphError {
     #[error("node does not exist")]
     InvalidNode(NodeLabel),
+    #[error("node already exists")]
+    NodeAlreadyExists(NodeLabel),
+    #[error("sub graph does not exist")]
+    InvalidSubGraph(Cow<'static, str>),
+    #[error("sub graph already exists")]
+    SubGraphAlreadyExists(Cow<'static, str>),
     #[error("output node slot does not exist")]
     InvalidOutputNodeSlot(SlotLabel),
     #[error("input node slot does not exist")]
@@ -43,4 +52,6 @@ pub enum RenderGraphError {
         input_slot: usize,
         occupied_by_node: NodeId,
     },
+    #[error("node is part of a cycle")]
+    Cycle { node: NodeId },
 }
@@ -34,11 +34,13 @@ pub use draw_state::*;
 pub use rangefinder::*;
 
 use crate::render_resource::{CachedRenderPipelineId, PipelineCache};
+use bevy_asset::HandleId;
 use bevy_ecs::{
     prelude::*,
     system::{lifetimeless::SRes, SystemParamItem},
 };
-use std::ops::Range;
+use bevy_utils::{FloatOrd, HashMap};
+use std::{cmp::Reverse, ops::Range};
 
 /// A collection of all rendering instructions, that will be executed by the GPU, for a
 /// single render phase for a single view.
@@ -249,6 +251,80 @@ pub enum BatchResult {
     IncompatibleItems,
 }
 
+/// Identifies [`PhaseItem`]s which draw the same mesh with the same material, so they can be
+/// drawn with a single instanced draw call.
+///
+/// Phase items supporting instancing group the items with the same key while sorting, so that
+/// items with the same key are consecutive and can be merged by
+/// [`BatchedPhaseItem::add_to_batch`]. The batch range of such items is a range of instances
+/// instead of a range of vertices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstanceBatchKey {
+    /// The id of the mesh asset.
+    pub mesh: HandleId,
+    /// The id of the material asset.
+    pub material: HandleId,
+}
+
+/// Merges the instances of an item into `batch_range` if both items have the same
+/// [`InstanceBatchKey`], pipeline and draw function, and their instances are consecutive.
+///
+/// This is meant to be used by implementations of [`BatchedPhaseItem::add_to_batch`] of phase
+/// items supporting instancing. Items without a key are never merged.
+pub fn add_instances_to_batch(
+    key: (
+        Option<InstanceBatchKey>,
+        CachedRenderPipelineId,
+        DrawFunctionId,
+    ),
+    batch_range: &mut Option<Range<u32>>,
+    other_key: (
+        Option<InstanceBatchKey>,
+        CachedRenderPipelineId,
+        DrawFunctionId,
+    ),
+    other_batch_range: &Option<Range<u32>>,
+) -> BatchResult {
+    if key.0.is_some() && key == other_key {
+        if let (Some(batch_range), Some(other_batch_range)) = (batch_range, other_batch_range) {
+            if batch_range.end == other_batch_range.start {
+                batch_range.end = other_batch_range.end;
+                return BatchResult::Success;
+            }
+        }
+    }
+    BatchResult::IncompatibleItems
+}
+
+/// Makes the items with the same [`InstanceBatchKey`] consecutive, while keeping them sorted
+/// front to back.
+///
+/// The items must already be sorted front to back by `distance`, where larger values are closer
+/// to the camera. Each batch is moved to the position of its front-most item, so the draw order
+/// stays roughly front to back and keeps the benefit of early depth testing. Items without a key
+/// keep their own distance, and the items of a batch stay sorted by distance.
+pub fn group_instance_batches<I>(
+    items: &mut [I],
+    distance: impl Fn(&I) -> f32,
+    batch_key: impl Fn(&I) -> Option<InstanceBatchKey>,
+) {
+    let mut batch_distances = HashMap::default();
+    for item in items.iter() {
+        if let Some(key) = batch_key(item) {
+            batch_distances.entry(key).or_insert_with(|| distance(item));
+        }
+    }
+    if batch_distances.is_empty() {
+        return;
+    }
+    // Stable sort, so the items stay sorted by distance within each batch
+    items.sort_by_key(|item| {
+        let key = batch_key(item);
+        let distance = key.map_or_else(|| distance(item), |key| batch_distances[&key]);
+        (Reverse(FloatOrd(distance)), key)
+    });
+}
+
 /// This system sorts the [`PhaseItem`]s of all [`RenderPhase`]s of this type.
 pub fn sort_phase_system<I: PhaseItem>(mut render_phases: Query<&mut RenderPhase<I>>) {
     for mut phase in &mut render_phases {
@@ -380,4 +456,35 @@ mod tests {
         ];
         assert_eq!(&*render_phase.items, items_batched);
     }
+
+    #[test]
+    fn instance_batches_stay_front_to_back() {
+        let key = |id| {
+            Some(InstanceBatchKey {
+                mesh: HandleId::Id(Default::default(), id),
+                material: HandleId::Id(Default::default(), 0),
+            })
+        };
+        // (distance, batch key), sorted front to back
+        let mut items = [
+            (5.0, key(1)),
+            (4.0, None),
+            (3.0, key(2)),
+            (2.0, key(1)),
+            (1.0, None),
+            (0.0, key(2)),
+        ];
+        group_instance_batches(&mut items, |item| item.0, |item| item.1);
+        assert_eq!(
+            items,
+            [
+                (5.0, key(1)),
+                (2.0, key(1)),
+                (4.0, None),
+                (3.0, key(2)),
+                (0.0, key(2)),
+                (1.0, None),
+            ]
+        );
+    }
 }
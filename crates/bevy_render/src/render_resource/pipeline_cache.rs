@@ -731,6 +731,7 @@ impl PipelineCache {
                     }
                 }
                 AssetEvent::Removed { handle } => cache.remove_shader(handle),
+                AssetEvent::Evicted { .. } | AssetEvent::DependencyModified { .. } => {}
             }
         }
     }
@@ -9,7 +9,7 @@ pub use graph_runner::*;
 pub use render_device::*;
 
 use crate::{
-    render_graph::RenderGraph,
+    render_graph::{RenderGraph, RenderGraphEdits},
     render_phase::TrackedRenderPass,
     render_resource::RenderPassDescriptor,
     settings::{WgpuSettings, WgpuSettingsPriority},
@@ -26,6 +26,13 @@ use wgpu::{
 /// Updates the [`RenderGraph`] with all of its nodes and then runs it to render the entire frame.
 pub fn render_system(world: &mut World) {
     world.resource_scope(|world, mut graph: Mut<RenderGraph>| {
+        if let Some(mut edits) = world.get_resource_mut::<RenderGraphEdits>() {
+            for batch in edits.drain() {
+                if let Err(e) = graph.apply_edits(batch) {
+                    error!("Failed to apply render graph edits: {e}");
+                }
+            }
+        }
         graph.update(world);
     });
     let graph = world.resource::<RenderGraph>();
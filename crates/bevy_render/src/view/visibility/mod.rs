@@ -3,13 +3,14 @@ mod render_layers;
 pub use render_layers::*;
 
 use bevy_app::{CoreSet, Plugin};
-use bevy_asset::{Assets, Handle};
+use bevy_asset::{AssetEvent, Assets, Handle};
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::Reflect;
 use bevy_reflect::{std_traits::ReflectDefault, FromReflect};
 use bevy_transform::components::GlobalTransform;
 use bevy_transform::TransformSystem;
+use bevy_utils::HashSet;
 use std::cell::Cell;
 use thread_local::ThreadLocal;
 
@@ -19,7 +20,7 @@ use crate::{
         Projection,
     },
     mesh::Mesh,
-    primitives::{Aabb, Frustum, Sphere},
+    primitives::{Aabb, BoundingSphere, Frustum, Sphere},
 };
 
 /// User indication of whether an entity is visible. Propagates down the entity hierarchy.
@@ -67,6 +68,7 @@ bitflags::bitflags! {
     pub(super) struct ComputedVisibilityFlags: u8 {
         const VISIBLE_IN_VIEW = 1 << 0;
         const VISIBLE_IN_HIERARCHY = 1 << 1;
+        const FRUSTUM_CULLED = 1 << 2;
     }
 }
 
@@ -95,7 +97,10 @@ impl ComputedVisibility {
     /// Reading it during [`CoreSet::Update`] will yield the value from the previous frame.
     #[inline]
     pub fn is_visible(&self) -> bool {
-        self.flags.bits == ComputedVisibilityFlags::all().bits
+        self.flags.contains(
+            ComputedVisibilityFlags::VISIBLE_IN_VIEW
+                | ComputedVisibilityFlags::VISIBLE_IN_HIERARCHY,
+        )
     }
 
     /// Whether this entity is visible in the entity hierarchy, which is determined by the [`Visibility`] component.
@@ -129,6 +134,25 @@ impl ComputedVisibility {
         self.flags.insert(ComputedVisibilityFlags::VISIBLE_IN_VIEW);
     }
 
+    /// Whether this entity is hidden only because it was outside of the [`Frustum`] of every view it
+    /// was checked against. Entities with [`NoFrustumCulling`] are never culled.
+    ///
+    /// Like [`Self::is_visible_in_view`], this is reset every frame in
+    /// [`VisibilitySystems::VisibilityPropagate`] and set in [`VisibilitySystems::CheckVisibility`].
+    /// Reading it during [`CoreSet::Update`] will yield the value from the previous frame.
+    #[inline]
+    pub fn is_frustum_culled(&self) -> bool {
+        self.flags.contains(ComputedVisibilityFlags::FRUSTUM_CULLED) && !self.is_visible_in_view()
+    }
+
+    /// Records that the entity was outside of the [`Frustum`] of a view, see
+    /// [`Self::is_frustum_culled`]. This should _only_ be called in systems with the
+    /// [`VisibilitySystems::CheckVisibility`] label.
+    #[inline]
+    pub fn set_frustum_culled(&mut self) {
+        self.flags.insert(ComputedVisibilityFlags::FRUSTUM_CULLED);
+    }
+
     #[inline]
     fn reset(&mut self, visible_in_hierarchy: bool) {
         self.flags = if visible_in_hierarchy {
@@ -158,6 +182,13 @@ pub struct VisibilityBundle {
 #[derive(Component)]
 pub struct NoFrustumCulling;
 
+/// Use this component to opt-out of recomputing the [`Aabb`] of Mesh entities when their mesh
+/// changes, e.g. to keep custom bounds which account for vertex animation.
+///
+/// The [`Aabb`] is still computed once if the entity doesn't have one.
+#[derive(Component)]
+pub struct NoAutoAabb;
+
 /// Collection of entities visible from the current view.
 ///
 /// This component contains all entities which are visible from the currently
@@ -259,10 +290,17 @@ impl Plugin for VisibilityPlugin {
     }
 }
 
+/// Computes the [`Aabb`] of Mesh entities without one, and recomputes it when the mesh handle or
+/// the mesh itself is modified, unless the entity has a [`NoAutoAabb`].
 pub fn calculate_bounds(
     mut commands: Commands,
     meshes: Res<Assets<Mesh>>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
     without_aabb: Query<(Entity, &Handle<Mesh>), (Without<Aabb>, Without<NoFrustumCulling>)>,
+    mut with_aabb: Query<
+        (Ref<Handle<Mesh>>, &mut Aabb),
+        (Without<NoFrustumCulling>, Without<NoAutoAabb>),
+    >,
 ) {
     for (entity, mesh_handle) in &without_aabb {
         if let Some(mesh) = meshes.get(mesh_handle) {
@@ -271,6 +309,24 @@ pub fn calculate_bounds(
             }
         }
     }
+
+    let modified_meshes: HashSet<_> = mesh_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } => Some(handle.id()),
+            _ => None,
+        })
+        .collect();
+    for (mesh_handle, mut aabb) in &mut with_aabb {
+        // an aabb inserted together with the mesh overrides the computed one
+        let handle_changed = mesh_handle.is_changed() && !mesh_handle.is_added();
+        if !handle_changed && !modified_meshes.contains(&mesh_handle.id()) {
+            continue;
+        }
+        if let Some(new_aabb) = meshes.get(&*mesh_handle).and_then(Mesh::compute_aabb) {
+            *aabb = new_aabb;
+        }
+    }
 }
 
 pub fn update_frusta<T: Component + CameraProjection + Send + Sync + 'static>(
@@ -356,29 +412,36 @@ fn propagate_recursive(
 pub fn check_visibility(
     mut thread_queues: Local<ThreadLocal<Cell<Vec<Entity>>>>,
     mut view_query: Query<(&mut VisibleEntities, &Frustum, Option<&RenderLayers>), With<Camera>>,
-    mut visible_aabb_query: Query<(
-        Entity,
-        &mut ComputedVisibility,
-        Option<&RenderLayers>,
-        &Aabb,
-        &GlobalTransform,
-        Option<&NoFrustumCulling>,
+    mut visible_entity_queries: ParamSet<(
+        Query<
+            (
+                Entity,
+                &mut ComputedVisibility,
+                Option<&RenderLayers>,
+                Option<&Aabb>,
+                Option<&BoundingSphere>,
+                &GlobalTransform,
+                Option<&NoFrustumCulling>,
+            ),
+            Or<(With<Aabb>, With<BoundingSphere>)>,
+        >,
+        Query<
+            (Entity, &mut ComputedVisibility, Option<&RenderLayers>),
+            (Without<Aabb>, Without<BoundingSphere>),
+        >,
     )>,
-    mut visible_no_aabb_query: Query<
-        (Entity, &mut ComputedVisibility, Option<&RenderLayers>),
-        Without<Aabb>,
-    >,
 ) {
     for (mut visible_entities, frustum, maybe_view_mask) in &mut view_query {
         let view_mask = maybe_view_mask.copied().unwrap_or_default();
 
         visible_entities.entities.clear();
-        visible_aabb_query.par_iter_mut().for_each_mut(
+        visible_entity_queries.p0().par_iter_mut().for_each_mut(
             |(
                 entity,
                 mut computed_visibility,
                 maybe_entity_mask,
-                model_aabb,
+                maybe_model_aabb,
+                maybe_bounding_sphere,
                 transform,
                 maybe_no_frustum_culling,
             )| {
@@ -393,20 +456,30 @@ pub fn check_visibility(
                     return;
                 }
 
-                // If we have an aabb and transform, do frustum culling
+                // If we have a bounding volume and transform, do frustum culling
                 if maybe_no_frustum_culling.is_none() {
                     let model = transform.compute_matrix();
-                    let model_sphere = Sphere {
-                        center: model.transform_point3a(model_aabb.center),
-                        radius: transform.radius_vec3a(model_aabb.half_extents),
-                    };
-                    // Do quick sphere-based frustum culling
-                    if !frustum.intersects_sphere(&model_sphere, false) {
-                        return;
-                    }
-                    // If we have an aabb, do aabb-based frustum culling
-                    if !frustum.intersects_obb(model_aabb, &model, true, false) {
-                        return;
+                    if let Some(bounding_sphere) = maybe_bounding_sphere {
+                        // A bounding sphere overrides the aabb
+                        if !frustum.intersects_sphere(&bounding_sphere.to_world(&model), false) {
+                            computed_visibility.set_frustum_culled();
+                            return;
+                        }
+                    } else if let Some(model_aabb) = maybe_model_aabb {
+                        let model_sphere = Sphere {
+                            center: model.transform_point3a(model_aabb.center),
+                            radius: transform.radius_vec3a(model_aabb.half_extents),
+                        };
+                        // Do quick sphere-based frustum culling
+                        if !frustum.intersects_sphere(&model_sphere, false) {
+                            computed_visibility.set_frustum_culled();
+                            return;
+                        }
+                        // If we have an aabb, do aabb-based frustum culling
+                        if !frustum.intersects_obb(model_aabb, &model, true, false) {
+                            computed_visibility.set_frustum_culled();
+                            return;
+                        }
                     }
                 }
 
@@ -418,7 +491,7 @@ pub fn check_visibility(
             },
         );
 
-        visible_no_aabb_query.par_iter_mut().for_each_mut(
+        visible_entity_queries.p1().par_iter_mut().for_each_mut(
             |(entity, mut computed_visibility, maybe_entity_mask)| {
                 // skip computing visibility for entities that are configured to be hidden. is_visible_in_view has already been set to false
                 // in visibility_propagate_system
@@ -452,7 +525,11 @@ mod test {
 
     use super::*;
 
+    use crate::render_resource::PrimitiveTopology;
+    use bevy_asset::AddAsset;
     use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{Mat4, Vec3, Vec3A};
+    use bevy_transform::components::Transform;
 
     #[test]
     fn visibility_propagation() {
@@ -651,6 +728,163 @@ mod test {
         assert!(!is_visible(root3), "a hidden root is hidden");
     }
 
+    #[test]
+    fn frustum_culled_entities() {
+        let mut app = App::new();
+        app.add_systems((
+            visibility_propagate_system,
+            check_visibility.after(visibility_propagate_system),
+        ));
+
+        let view_projection = Mat4::orthographic_rh(-1.0, 1.0, -1.0, 1.0, -1.0, 1.0);
+        app.world.spawn((
+            Camera::default(),
+            VisibleEntities::default(),
+            Frustum::from_view_projection(&view_projection),
+        ));
+
+        let mut spawn_mesh = |x: f32, no_frustum_culling: bool| {
+            let mut entity = app.world.spawn((
+                VisibilityBundle::default(),
+                Aabb::from_min_max(Vec3::splat(-0.1), Vec3::splat(0.1)),
+                GlobalTransform::from_translation(Vec3::new(x, 0.0, 0.0)),
+            ));
+            if no_frustum_culling {
+                entity.insert(NoFrustumCulling);
+            }
+            entity.id()
+        };
+        let inside = spawn_mesh(0.0, false);
+        let outside = spawn_mesh(5.0, false);
+        let outside_not_culled = spawn_mesh(5.0, true);
+
+        app.update();
+
+        let computed_visibility =
+            |entity: Entity| app.world.get::<ComputedVisibility>(entity).unwrap();
+        assert!(computed_visibility(inside).is_visible());
+        assert!(!computed_visibility(inside).is_frustum_culled());
+        assert!(!computed_visibility(outside).is_visible());
+        assert!(computed_visibility(outside).is_frustum_culled());
+        assert!(computed_visibility(outside_not_culled).is_visible());
+        assert!(!computed_visibility(outside_not_culled).is_frustum_culled());
+    }
+
+    #[test]
+    fn bounding_sphere_overrides_aabb() {
+        let mut app = App::new();
+        app.add_systems((
+            visibility_propagate_system,
+            check_visibility.after(visibility_propagate_system),
+        ));
+
+        let view_projection = Mat4::orthographic_rh(-1.0, 1.0, -1.0, 1.0, -1.0, 1.0);
+        app.world.spawn((
+            Camera::default(),
+            VisibleEntities::default(),
+            Frustum::from_view_projection(&view_projection),
+        ));
+
+        let outside_aabb = Aabb::from_min_max(Vec3::splat(-0.1), Vec3::splat(0.1));
+        let reaching_sphere = BoundingSphere {
+            center: Vec3A::ZERO,
+            radius: 4.5,
+        };
+        let transform = GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0));
+        let with_sphere = app
+            .world
+            .spawn((
+                VisibilityBundle::default(),
+                outside_aabb,
+                reaching_sphere,
+                transform,
+            ))
+            .id();
+        let sphere_only = app
+            .world
+            .spawn((VisibilityBundle::default(), reaching_sphere, transform))
+            .id();
+        let scaled_sphere = app
+            .world
+            .spawn((
+                VisibilityBundle::default(),
+                BoundingSphere {
+                    center: Vec3A::ZERO,
+                    radius: 0.1,
+                },
+                GlobalTransform::from(
+                    Transform::from_xyz(5.0, 0.0, 0.0).with_scale(Vec3::new(1.0, 50.0, 1.0)),
+                ),
+            ))
+            .id();
+        let culled_sphere = app
+            .world
+            .spawn((
+                VisibilityBundle::default(),
+                outside_aabb,
+                BoundingSphere {
+                    center: Vec3A::ZERO,
+                    radius: 0.1,
+                },
+                transform,
+            ))
+            .id();
+
+        app.update();
+
+        let computed_visibility =
+            |entity: Entity| app.world.get::<ComputedVisibility>(entity).unwrap();
+        assert!(computed_visibility(with_sphere).is_visible());
+        assert!(computed_visibility(sphere_only).is_visible());
+        assert!(computed_visibility(scaled_sphere).is_visible());
+        assert!(!computed_visibility(culled_sphere).is_visible());
+        assert!(computed_visibility(culled_sphere).is_frustum_culled());
+    }
+
+    #[test]
+    fn bounds_are_recomputed_when_the_mesh_changes() {
+        let mut app = App::new();
+        app.add_plugin(bevy_asset::AssetPlugin::default())
+            .add_asset::<Mesh>()
+            .add_system(calculate_bounds);
+
+        let mesh_with_extent = |extent: f32| {
+            let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                vec![[-extent, 0.0, 0.0], [extent, 0.0, 0.0], [0.0, extent, 0.0]],
+            );
+            mesh
+        };
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let small = meshes.add(mesh_with_extent(1.0));
+        let large = meshes.add(mesh_with_extent(3.0));
+        let entity = app.world.spawn(small.clone()).id();
+        let fixed = app.world.spawn((small.clone(), NoAutoAabb)).id();
+        app.update();
+
+        let half_extent_x =
+            |app: &App, entity: Entity| app.world.get::<Aabb>(entity).unwrap().half_extents.x;
+        assert_eq!(half_extent_x(&app, entity), 1.0);
+        assert_eq!(half_extent_x(&app, fixed), 1.0);
+
+        // modifying the mesh asset
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        *meshes.get_mut(&small).unwrap() = mesh_with_extent(2.0);
+        // the asset event is sent at the end of the frame
+        app.update();
+        app.update();
+        assert_eq!(half_extent_x(&app, entity), 2.0);
+        assert_eq!(half_extent_x(&app, fixed), 1.0);
+
+        // replacing the mesh handle
+        app.world.entity_mut(entity).insert(large.clone());
+        app.world.entity_mut(fixed).insert(large);
+        app.update();
+        assert_eq!(half_extent_x(&app, entity), 3.0);
+        assert_eq!(half_extent_x(&app, fixed), 1.0);
+    }
+
     #[test]
     fn ensure_visibility_enum_size() {
         use std::mem;
@@ -1,17 +1,20 @@
-use crate::{DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{DynamicResource, DynamicSceneBuilder, SaveableResources, Scene, SceneSpawnError};
 use anyhow::Result;
 use bevy_app::AppTypeRegistry;
+use bevy_asset::{AssetPath, Assets, Handle};
 use bevy_ecs::{
     entity::EntityMap,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
-use bevy_reflect::{Reflect, TypeRegistryArc, TypeUuid};
+use bevy_reflect::{Reflect, TypeRegistry, TypeRegistryArc, TypeUuid};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
 #[cfg(feature = "serialize")]
 use serde::Serialize;
+#[cfg(feature = "serialize")]
+use thiserror::Error;
 
 /// A collection of serializable dynamic entities, each with its own run-time defined set of components.
 /// To spawn a dynamic scene, you can use either:
@@ -23,7 +26,24 @@ use serde::Serialize;
 #[derive(Default, TypeUuid)]
 #[uuid = "749479b1-fb8c-4ff8-a775-623aa76014f5"]
 pub struct DynamicScene {
+    /// The path of the scene this scene is a variant of, if any.
+    ///
+    /// When the scene is spawned by the [`SceneSpawner`](crate::SceneSpawner), the entities of the
+    /// base scene are written first. The entities of this scene are written on top of them, so an
+    /// entity with the same id as an entity of the base scene overrides the components it declares,
+    /// while the other entities are added. This allows prefab variants, e.g. an enemy with different
+    /// stats, without duplicating the whole scene.
+    ///
+    /// The base scene has to be loaded when this scene is spawned, which the
+    /// [`SceneLoader`](crate::SceneLoader) ensures by loading it as a dependency.
+    pub base: Option<AssetPath<'static>>,
     pub entities: Vec<DynamicEntity>,
+    /// The [`SaveableResource`](crate::SaveableResource)s of the scene.
+    ///
+    /// They are written after the entities, replacing the resources of the world. Resources saved
+    /// with another version than the current one are migrated with
+    /// [`SaveableResource::migrate`](crate::SaveableResource::migrate).
+    pub resources: Vec<DynamicResource>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -42,6 +62,10 @@ impl DynamicScene {
     }
 
     /// Create a new dynamic scene from a given world.
+    ///
+    /// Only the entities are extracted, use
+    /// [`DynamicSceneBuilder::extract_saveable_resources`] to also extract the
+    /// [`SaveableResource`](crate::SaveableResource)s of the world.
     pub fn from_world(world: &World, type_registry: &AppTypeRegistry) -> Self {
         let mut builder =
             DynamicSceneBuilder::from_world_with_type_registry(world, type_registry.clone());
@@ -63,7 +87,55 @@ impl DynamicScene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        self.write_entities(world, entity_map, &type_registry)?;
+        self.write_resources(world, &type_registry)?;
+        Self::map_entities(world, entity_map, &type_registry);
+        Ok(())
+    }
+
+    /// Write the dynamic entities of this scene and of its [`base`](Self::base) scenes to the given
+    /// world, with the entities of this scene overriding the components of the base entities with
+    /// the same id.
+    ///
+    /// In addition to the errors of [`DynamicScene::write_to_world_with`], this method will return
+    /// a [`SceneSpawnError`] if a base scene isn't loaded in `scenes`, or if a scene is its own
+    /// base.
+    pub fn write_to_world_with_base(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityMap,
+        scenes: &Assets<DynamicScene>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let mut layers = vec![self];
+        let mut base = self.base.as_ref();
+        while let Some(path) = base {
+            let handle = Handle::weak(path.get_id().into());
+            let scene = scenes
+                .get(&handle)
+                .ok_or(SceneSpawnError::NonExistentScene { handle })?;
+            if layers.iter().any(|layer| std::ptr::eq(*layer, scene)) {
+                return Err(SceneSpawnError::RecursiveBase { path: path.clone() });
+            }
+            layers.push(scene);
+            base = scene.base.as_ref();
+        }
+
+        let type_registry = type_registry.read();
+        for layer in layers.iter().rev() {
+            layer.write_entities(world, entity_map, &type_registry)?;
+            layer.write_resources(world, &type_registry)?;
+        }
+        Self::map_entities(world, entity_map, &type_registry);
+        Ok(())
+    }
 
+    fn write_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityMap,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -94,6 +166,55 @@ impl DynamicScene {
             }
         }
 
+        Ok(())
+    }
+
+    fn write_resources(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        if self.resources.is_empty() {
+            return Ok(());
+        }
+        let saveable = world
+            .get_resource::<SaveableResources>()
+            .cloned()
+            .unwrap_or_default();
+
+        for resource in &self.resources {
+            let type_name = resource.value.type_name();
+            let registration = type_registry.get_with_name(type_name).ok_or_else(|| {
+                SceneSpawnError::UnregisteredType {
+                    type_name: type_name.to_string(),
+                }
+            })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_name: type_name.to_string(),
+                }
+            })?;
+
+            let saveable = saveable.get(registration);
+            let current_version = saveable.map_or(0, |saveable| saveable.version());
+            if resource.version == current_version {
+                reflect_resource.apply_or_insert(world, &*resource.value);
+            } else {
+                let migrated = saveable
+                    .and_then(|saveable| saveable.migrate(resource.version, &*resource.value))
+                    .ok_or_else(|| SceneSpawnError::ResourceVersionMismatch {
+                        type_name: type_name.to_string(),
+                        saved: resource.version,
+                        current: current_version,
+                    })?;
+                reflect_resource.apply_or_insert(world, &*migrated);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn map_entities(world: &mut World, entity_map: &EntityMap, type_registry: &TypeRegistry) {
         for registration in type_registry.iter() {
             if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
                 map_entities_reflect
@@ -101,8 +222,6 @@ impl DynamicScene {
                     .unwrap();
             }
         }
-
-        Ok(())
     }
 
     /// Write the dynamic entities and their corresponding components to the given world.
@@ -125,6 +244,62 @@ impl DynamicScene {
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the given [`SceneFormat`].
+    ///
+    /// The bytes should be saved with the [extension](SceneFormat::extension) of the format, so
+    /// that the [`SceneLoader`](crate::SceneLoader) can load them.
+    #[cfg(feature = "serialize")]
+    pub fn serialize(
+        &self,
+        registry: &TypeRegistryArc,
+        format: SceneFormat,
+    ) -> Result<Vec<u8>, SceneSerializeError> {
+        match format {
+            SceneFormat::Ron => Ok(self.serialize_ron(registry)?.into_bytes()),
+            SceneFormat::Binary => Ok(postcard::to_allocvec(&SceneSerializer::new(
+                self, registry,
+            ))?),
+        }
+    }
+}
+
+/// The format a [`DynamicScene`] is serialized into by [`DynamicScene::serialize`].
+#[cfg(feature = "serialize")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum SceneFormat {
+    /// Rust object notation (ron), a human-readable format that can be edited by hand.
+    #[default]
+    Ron,
+    /// A compact binary format, which is smaller and faster to load than ron, e.g. for large save
+    /// games.
+    ///
+    /// Binary scenes are not self-describing, so they can only be loaded with the same components
+    /// registered as when they were saved.
+    Binary,
+}
+
+#[cfg(feature = "serialize")]
+impl SceneFormat {
+    /// Returns the file extension of scenes in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            SceneFormat::Ron => "scn.ron",
+            SceneFormat::Binary => "scn.bin",
+        }
+    }
+}
+
+/// An error that occurs when serializing a [`DynamicScene`].
+#[cfg(feature = "serialize")]
+#[derive(Error, Debug)]
+pub enum SceneSerializeError {
+    /// The scene could not be serialized into ron.
+    #[error("failed to serialize scene into ron: {0}")]
+    Ron(#[from] ron::Error),
+    /// The scene could not be serialized into the binary format.
+    #[error("failed to serialize scene into binary: {0}")]
+    Binary(#[from] postcard::Error),
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -138,3 +313,174 @@ where
         .new_line("\n".to_string());
     ron::ser::to_string_pretty(&serialize, pretty_config)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DynamicEntity, DynamicResource, DynamicScene, DynamicSceneBuilder, ReflectSaveableResource,
+        SaveableResource, SceneSpawnError,
+    };
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_asset::{AddAsset, AssetPath, AssetPlugin, Assets, Handle};
+    use bevy_ecs::{
+        entity::{Entity, EntityMap},
+        prelude::{Component, ReflectComponent, ReflectResource, Resource},
+        world::{Mut, World},
+    };
+    use bevy_reflect::{Reflect, ReflectRef};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Enemy;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn entity(entity: u32, components: Vec<Box<dyn Reflect>>) -> DynamicEntity {
+        DynamicEntity { entity, components }
+    }
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<DynamicScene>()
+            .register_type::<Enemy>()
+            .register_type::<Health>();
+        let base = DynamicScene {
+            base: None,
+            resources: Vec::new(),
+            entities: vec![
+                entity(0, vec![Box::new(Enemy), Box::new(Health(10))]),
+                entity(1, vec![Box::new(Health(5))]),
+            ],
+        };
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(AssetPath::from("enemy.scn.ron").get_id(), base);
+        app
+    }
+
+    fn write(app: &mut App, scene: &DynamicScene) -> Result<EntityMap, SceneSpawnError> {
+        let type_registry = app.world.resource::<AppTypeRegistry>().clone();
+        let mut entity_map = EntityMap::default();
+        app.world
+            .resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                scene.write_to_world_with_base(world, &mut entity_map, &scenes, &type_registry)
+            })
+            .map(|_| entity_map)
+    }
+
+    #[test]
+    fn variant_overrides_base_components() {
+        let mut app = setup();
+        let variant = DynamicScene {
+            base: Some("enemy.scn.ron".into()),
+            resources: Vec::new(),
+            entities: vec![
+                entity(0, vec![Box::new(Health(50))]),
+                entity(2, vec![Box::new(Health(1))]),
+            ],
+        };
+        let entity_map = write(&mut app, &variant).unwrap();
+
+        let get = |id| entity_map.get(Entity::from_raw(id)).unwrap();
+        let world = &app.world;
+        assert!(world.entity(get(0)).contains::<Enemy>());
+        assert_eq!(world.get::<Health>(get(0)), Some(&Health(50)));
+        assert_eq!(world.get::<Health>(get(1)), Some(&Health(5)));
+        assert_eq!(world.get::<Health>(get(2)), Some(&Health(1)));
+        assert_eq!(entity_map.len(), 3);
+    }
+
+    #[test]
+    fn recursive_base() {
+        let mut app = setup();
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&Handle::weak(
+                AssetPath::from("enemy.scn.ron").get_id().into(),
+            ))
+            .unwrap()
+            .base = Some("enemy.scn.ron".into());
+        let variant = DynamicScene {
+            base: Some("enemy.scn.ron".into()),
+            resources: Vec::new(),
+            entities: Vec::new(),
+        };
+        assert!(matches!(
+            write(&mut app, &variant),
+            Err(SceneSpawnError::RecursiveBase { .. })
+        ));
+    }
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource, SaveableResource)]
+    struct Score(u32);
+
+    impl SaveableResource for Score {
+        const VERSION: u32 = 2;
+
+        // version 1 stored the score in tens
+        fn migrate(saved_version: u32, saved: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+            let ReflectRef::TupleStruct(saved) = saved.reflect_ref() else {
+                return None;
+            };
+            let tens = saved.field(0)?.downcast_ref::<u32>()?;
+            (saved_version == 1).then(|| Box::new(Score(tens * 10)) as Box<dyn Reflect>)
+        }
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Unsaved;
+
+    #[test]
+    fn saveable_resources() {
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Score>();
+        type_registry.write().register::<Unsaved>();
+
+        let mut world = World::new();
+        world.insert_resource(Score(5));
+        world.insert_resource(Unsaved);
+        assert!(DynamicScene::from_world(&world, &type_registry)
+            .resources
+            .is_empty());
+        let mut builder =
+            DynamicSceneBuilder::from_world_with_type_registry(&world, type_registry.clone());
+        builder.extract_saveable_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert_eq!(scene.resources[0].version, 2);
+
+        let write = |scene: &DynamicScene| {
+            let mut world = World::new();
+            scene
+                .write_to_world_with(&mut world, &mut EntityMap::default(), &type_registry)
+                .map(|_| world)
+        };
+        let world = write(&scene).unwrap();
+        assert_eq!(world.resource::<Score>(), &Score(5));
+        assert!(!world.contains_resource::<Unsaved>());
+
+        let saved = |version: u32| DynamicScene {
+            base: None,
+            resources: vec![DynamicResource {
+                version,
+                value: Box::new(Score(3)),
+            }],
+            entities: Vec::new(),
+        };
+        let world = write(&saved(1)).unwrap();
+        assert_eq!(world.resource::<Score>(), &Score(30));
+        assert!(matches!(
+            write(&saved(0)),
+            Err(SceneSpawnError::ResourceVersionMismatch {
+                saved: 0,
+                current: 2,
+                ..
+            })
+        ));
+    }
+}
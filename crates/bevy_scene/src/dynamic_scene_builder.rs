@@ -1,6 +1,10 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicResource, DynamicScene, SaveableResources};
 use bevy_app::AppTypeRegistry;
-use bevy_ecs::{prelude::Entity, reflect::ReflectComponent, world::World};
+use bevy_ecs::{
+    prelude::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    world::World,
+};
 use bevy_utils::default;
 use std::collections::BTreeMap;
 
@@ -32,6 +36,7 @@ use std::collections::BTreeMap;
 /// ```
 pub struct DynamicSceneBuilder<'w> {
     extracted_scene: BTreeMap<u32, DynamicEntity>,
+    extracted_resources: BTreeMap<String, DynamicResource>,
     type_registry: AppTypeRegistry,
     original_world: &'w World,
 }
@@ -42,6 +47,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     pub fn from_world(world: &'w World) -> Self {
         Self {
             extracted_scene: default(),
+            extracted_resources: default(),
             type_registry: world.resource::<AppTypeRegistry>().clone(),
             original_world: world,
         }
@@ -52,6 +58,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     pub fn from_world_with_type_registry(world: &'w World, type_registry: AppTypeRegistry) -> Self {
         Self {
             extracted_scene: default(),
+            extracted_resources: default(),
             type_registry,
             original_world: world,
         }
@@ -63,7 +70,9 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// [`Self::remove_empty_entities`] before building the scene.
     pub fn build(self) -> DynamicScene {
         DynamicScene {
+            base: None,
             entities: self.extracted_scene.into_values().collect(),
+            resources: self.extracted_resources.into_values().collect(),
         }
     }
 
@@ -144,6 +153,41 @@ impl<'w> DynamicSceneBuilder<'w> {
         drop(type_registry);
         self
     }
+
+    /// Extract the [`SaveableResource`](crate::SaveableResource)s from the builder's [`World`],
+    /// i.e. the resources registered in its [`SaveableResources`] or with the
+    /// [`ReflectSaveableResource`](crate::ReflectSaveableResource) type data.
+    ///
+    /// Only resources registered with `#[reflect(Resource)]` in the type registry are extracted.
+    pub fn extract_saveable_resources(&mut self) -> &mut Self {
+        let type_registry = self.type_registry.read();
+        let default_saveable = SaveableResources::default();
+        let saveable = self
+            .original_world
+            .get_resource::<SaveableResources>()
+            .unwrap_or(&default_saveable);
+
+        for registration in type_registry.iter() {
+            let Some(saveable) = saveable.get(registration) else {
+                continue;
+            };
+            let resource = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect(self.original_world));
+            if let Some(resource) = resource {
+                self.extracted_resources.insert(
+                    registration.type_name().to_string(),
+                    DynamicResource {
+                        version: saveable.version(),
+                        value: resource.clone_value(),
+                    },
+                );
+            }
+        }
+
+        drop(type_registry);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,127 @@
+use bevy_ecs::system::Resource;
+use bevy_reflect::{FromType, Reflect, TypeRegistration};
+use bevy_utils::HashMap;
+use std::any::TypeId;
+
+/// A resource which is included in [`DynamicScene`](crate::DynamicScene)s extracted from a world,
+/// e.g. to be part of a savegame.
+///
+/// Opt in by adding `#[reflect(SaveableResource)]` to a reflected resource, or by registering it
+/// in the [`SaveableResources`] of the world.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, reflect::ReflectResource};
+/// # use bevy_reflect::Reflect;
+/// # use bevy_scene::{ReflectSaveableResource, SaveableResource};
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource, SaveableResource)]
+/// struct Score {
+///     points: u32,
+/// }
+///
+/// impl SaveableResource for Score {
+///     const VERSION: u32 = 1;
+/// }
+/// ```
+pub trait SaveableResource: Resource {
+    /// The version of the resource, which is stored alongside it in the scene.
+    ///
+    /// Increment it whenever the fields of the resource change in a way that makes older saves
+    /// incompatible, and handle the older versions in [`SaveableResource::migrate`].
+    const VERSION: u32 = 0;
+
+    /// Converts the `saved` value of the resource stored with the `saved_version` into a value of
+    /// the current version, or returns `None` if the saved version is unsupported.
+    ///
+    /// The `saved` value is usually a dynamic type, e.g. a `DynamicStruct`, as returned by the
+    /// scene deserializer.
+    fn migrate(saved_version: u32, saved: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let _ = (saved_version, saved);
+        None
+    }
+}
+
+type MigrateFn = fn(u32, &dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+/// Type data of a [`SaveableResource`], created with `#[reflect(SaveableResource)]` or by
+/// [`SaveableResources`].
+#[derive(Clone, Copy)]
+pub struct ReflectSaveableResource {
+    version: u32,
+    migrate: MigrateFn,
+}
+
+impl ReflectSaveableResource {
+    /// Returns the current version of the resource.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Converts the `saved` value stored with the `saved_version` into the current version, see
+    /// [`SaveableResource::migrate`].
+    pub fn migrate(&self, saved_version: u32, saved: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.migrate)(saved_version, saved)
+    }
+}
+
+impl<T: SaveableResource> FromType<T> for ReflectSaveableResource {
+    fn from_type() -> Self {
+        Self {
+            version: T::VERSION,
+            migrate: T::migrate,
+        }
+    }
+}
+
+/// The registry of the resources which are extracted into [`DynamicScene`](crate::DynamicScene)s,
+/// in addition to those with the [`ReflectSaveableResource`] type data.
+///
+/// The resources also have to be registered with `#[reflect(Resource)]` in the
+/// [`AppTypeRegistry`](bevy_app::AppTypeRegistry) to be extracted and written.
+#[derive(Resource, Clone, Default)]
+pub struct SaveableResources {
+    resources: HashMap<TypeId, ReflectSaveableResource>,
+}
+
+impl SaveableResources {
+    /// Registers the [`SaveableResource`] `T`, even if it wasn't registered with
+    /// `#[reflect(SaveableResource)]`.
+    pub fn register<T: SaveableResource>(&mut self) -> &mut Self {
+        self.resources
+            .insert(TypeId::of::<T>(), FromType::<T>::from_type());
+        self
+    }
+
+    /// Registers the resource `T` with the given `version`, which is useful for resources of other
+    /// crates. Saves with a different version can't be migrated and fail to be written.
+    pub fn register_with_version<T: Resource>(&mut self, version: u32) -> &mut Self {
+        self.resources.insert(
+            TypeId::of::<T>(),
+            ReflectSaveableResource {
+                version,
+                migrate: |_, _| None,
+            },
+        );
+        self
+    }
+
+    /// Returns the [`ReflectSaveableResource`] of the type, if it is registered here or has the
+    /// type data.
+    pub fn get<'a>(
+        &'a self,
+        registration: &'a TypeRegistration,
+    ) -> Option<&'a ReflectSaveableResource> {
+        self.resources
+            .get(&registration.type_id())
+            .or_else(|| registration.data::<ReflectSaveableResource>())
+    }
+}
+
+/// A resource of a [`DynamicScene`](crate::DynamicScene), together with the version of the
+/// [`SaveableResource`] it was extracted from.
+pub struct DynamicResource {
+    /// The [`SaveableResource::VERSION`] of the resource when it was extracted.
+    pub version: u32,
+    /// The value of the resource.
+    pub value: Box<dyn Reflect>,
+}
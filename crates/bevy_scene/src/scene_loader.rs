@@ -1,4 +1,4 @@
-use crate::serde::SceneDeserializer;
+use crate::{serde::SceneDeserializer, SceneFormat};
 use anyhow::Result;
 use bevy_app::AppTypeRegistry;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
@@ -31,17 +31,28 @@ impl AssetLoader for SceneLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
             let scene_deserializer = SceneDeserializer {
                 type_registry: &self.type_registry.read(),
             };
-            let scene = scene_deserializer.deserialize(&mut deserializer)?;
-            load_context.set_default_asset(LoadedAsset::new(scene));
+            let path = load_context.path().to_string_lossy();
+            let scene = if path.ends_with(SceneFormat::Binary.extension()) {
+                let mut deserializer = postcard::Deserializer::from_bytes(bytes);
+                scene_deserializer.deserialize(&mut deserializer)?
+            } else {
+                let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
+                scene_deserializer.deserialize(&mut deserializer)?
+            };
+            let base = scene.base.clone();
+            let mut asset = LoadedAsset::new(scene);
+            if let Some(base) = base {
+                asset = asset.with_dependency(base);
+            }
+            load_context.set_default_asset(asset);
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["scn", "scn.ron"]
+        &["scn", "scn.ron", "scn.bin"]
     }
 }
@@ -1,6 +1,6 @@
 use crate::{DynamicScene, Scene};
 use bevy_app::AppTypeRegistry;
-use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_asset::{AssetEvent, AssetPath, Assets, Handle};
 use bevy_ecs::{
     entity::{Entity, EntityMap},
     event::{Events, ManualEventReader},
@@ -51,6 +51,16 @@ pub enum SceneSpawnError {
     NonExistentScene { handle: Handle<DynamicScene> },
     #[error("scene does not exist")]
     NonExistentRealScene { handle: Handle<Scene> },
+    #[error("scene is based on itself through the base scene `{path:?}`")]
+    RecursiveBase { path: AssetPath<'static> },
+    #[error("scene contains the unregistered resource `{type_name}`. consider adding `#[reflect(Resource)]` to your type")]
+    UnregisteredResource { type_name: String },
+    #[error("scene contains the resource `{type_name}` saved with version {saved}, which can't be migrated to version {current}")]
+    ResourceVersionMismatch {
+        type_name: String,
+        saved: u32,
+        current: u32,
+    },
 }
 
 impl SceneSpawner {
@@ -145,7 +155,8 @@ impl SceneSpawner {
                     .ok_or_else(|| SceneSpawnError::NonExistentScene {
                         handle: scene_handle.clone_weak(),
                     })?;
-            scene.write_to_world(world, entity_map)
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            scene.write_to_world_with_base(world, entity_map, &scenes, &type_registry)
         })
     }
 
@@ -1,25 +1,32 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicResource, DynamicScene};
 use anyhow::Result;
+use bevy_asset::AssetPath;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{
     serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
-    Reflect, TypeRegistry, TypeRegistryArc,
+    Reflect, TypeRegistration, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
 use serde::ser::SerializeMap;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt::Formatter;
+use std::{cell::Cell, fmt::Formatter};
 
 pub const SCENE_STRUCT: &str = "Scene";
+pub const SCENE_BASE: &str = "base";
 pub const SCENE_ENTITIES: &str = "entities";
+pub const SCENE_RESOURCES: &str = "resources";
 
 pub const ENTITY_STRUCT: &str = "Entity";
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+pub const RESOURCE_STRUCT: &str = "Resource";
+pub const RESOURCE_FIELD_VERSION: &str = "version";
+pub const RESOURCE_FIELD_VALUE: &str = "value";
+
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     pub registry: &'a TypeRegistryArc,
@@ -36,7 +43,18 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 1)?;
+        let base = self.scene.base.as_ref().map(format_asset_path);
+        // formats without field names read the resources and the base as optional trailing
+        // elements, so that scenes serialized before they were added stay readable, and scenes
+        // without them are serialized like before. The resources still have to be written if
+        // a base follows them.
+        let human_readable = serializer.is_human_readable();
+        let skip_resources = self.scene.resources.is_empty() && (human_readable || base.is_none());
+        let len = 1 + !skip_resources as usize + base.is_some() as usize;
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, len)?;
+        if human_readable {
+            serialize_base(&mut state, base.as_deref())?;
+        }
         state.serialize_field(
             SCENE_ENTITIES,
             &EntitiesSerializer {
@@ -44,10 +62,41 @@ impl<'a> Serialize for SceneSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if skip_resources {
+            state.skip_field(SCENE_RESOURCES)?;
+        } else {
+            state.serialize_field(
+                SCENE_RESOURCES,
+                &ResourcesSerializer {
+                    resources: &self.scene.resources,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        if !human_readable {
+            serialize_base(&mut state, base.as_deref())?;
+        }
         state.end()
     }
 }
 
+fn serialize_base<S: SerializeStruct>(state: &mut S, base: Option<&str>) -> Result<(), S::Error> {
+    match base {
+        Some(base) => state.serialize_field(SCENE_BASE, base),
+        None => state.skip_field(SCENE_BASE),
+    }
+}
+
+/// Formats `path` the way it is parsed by `AssetPath::from`, i.e. `path#label`.
+fn format_asset_path(path: &AssetPath) -> String {
+    let mut formatted = path.path().to_string_lossy().into_owned();
+    if let Some(label) = path.label() {
+        formatted.push('#');
+        formatted.push_str(label);
+    }
+    formatted
+}
+
 pub struct EntitiesSerializer<'a> {
     pub entities: &'a [DynamicEntity],
     pub registry: &'a TypeRegistryArc,
@@ -115,10 +164,63 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
     }
 }
 
+pub struct ResourcesSerializer<'a> {
+    pub resources: &'a [DynamicResource],
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ResourcesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.resources.len()))?;
+        for resource in self.resources {
+            state.serialize_entry(
+                resource.value.type_name(),
+                &ResourceSerializer {
+                    resource,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+pub struct ResourceSerializer<'a> {
+    pub resource: &'a DynamicResource,
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ResourceSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(RESOURCE_STRUCT, 2)?;
+        state.serialize_field(RESOURCE_FIELD_VERSION, &self.resource.version)?;
+        state.serialize_field(
+            RESOURCE_FIELD_VALUE,
+            &TypedReflectSerializer::new(&*self.resource.value, &self.registry.read()),
+        )?;
+        state.end()
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
+    Base,
     Entities,
+    Resources,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ResourceField {
+    Version,
+    Value,
 }
 
 #[derive(Deserialize)]
@@ -140,7 +242,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_ENTITIES],
+            &[SCENE_BASE, SCENE_ENTITIES, SCENE_RESOURCES],
             SceneVisitor {
                 type_registry: self.type_registry,
             },
@@ -163,9 +265,17 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let mut base = None;
         let mut entities = None;
+        let mut resources = None;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Base => {
+                    if base.is_some() {
+                        return Err(Error::duplicate_field(SCENE_BASE));
+                    }
+                    base = Some(AssetPath::from(map.next_value::<String>()?));
+                }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
@@ -174,12 +284,24 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                         type_registry: self.type_registry,
                     })?);
                 }
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneResourcesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
             }
         }
 
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene { entities })
+        Ok(DynamicScene {
+            base,
+            entities,
+            resources: resources.unwrap_or_default(),
+        })
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -191,8 +313,170 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                 type_registry: self.type_registry,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let resources = next_trailing_element(
+            &mut seq,
+            TrailingElement::Map,
+            SceneResourcesVisitor {
+                registry: self.type_registry,
+            },
+        )?
+        .unwrap_or_default();
+        let base = next_trailing_element(&mut seq, TrailingElement::String, BaseVisitor)?
+            .map(AssetPath::from);
+
+        Ok(DynamicScene {
+            base,
+            entities,
+            resources,
+        })
+    }
+}
+
+/// Reads an optional element at the end of a sequence, which is absent in data serialized before
+/// the element was added.
+///
+/// Formats that prefix sequences with their length report a missing element as `None`, while
+/// formats like postcard and bincode fail to read past the end of the data. Such a failure only
+/// counts as absent if it happens before the format hands any data to `visitor`, so malformed
+/// elements are still reported.
+fn next_trailing_element<'de, A, V>(
+    seq: &mut A,
+    element: TrailingElement,
+    visitor: V,
+) -> Result<Option<V::Value>, A::Error>
+where
+    A: SeqAccess<'de>,
+    V: Visitor<'de>,
+{
+    let started = Cell::new(false);
+    match seq.next_element_seed(TrailingElementDeserializer {
+        element,
+        visitor,
+        started: &started,
+    }) {
+        Err(_) if !started.get() => Ok(None),
+        result => result,
+    }
+}
+
+/// The kind of data stored in a trailing element of a scene sequence.
+#[derive(Clone, Copy)]
+enum TrailingElement {
+    Map,
+    String,
+}
+
+struct TrailingElementDeserializer<'a, V> {
+    element: TrailingElement,
+    visitor: V,
+    started: &'a Cell<bool>,
+}
+
+impl<'a, 'de, V: Visitor<'de>> DeserializeSeed<'de> for TrailingElementDeserializer<'a, V> {
+    type Value = V::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = TrailingElementVisitor {
+            visitor: self.visitor,
+            started: self.started,
+        };
+        match self.element {
+            TrailingElement::Map => deserializer.deserialize_map(visitor),
+            TrailingElement::String => deserializer.deserialize_string(visitor),
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.started.set(true);
+                self.visitor.$method(v)
+            }
+        )*
+    };
+}
+
+/// Forwards to `visitor`, recording that the format started handing over the element's data.
+struct TrailingElementVisitor<'a, V> {
+    visitor: V,
+    started: &'a Cell<bool>,
+}
+
+impl<'a, 'de, V: Visitor<'de>> Visitor<'de> for TrailingElementVisitor<'a, V> {
+    type Value = V::Value;
 
-        Ok(DynamicScene { entities })
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i64(i64),
+        visit_u64(u64),
+        visit_f64(f64),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        self.started.set(true);
+        self.visitor.visit_none()
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.started.set(true);
+        self.visitor.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.started.set(true);
+        self.visitor.visit_some(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.started.set(true);
+        self.visitor.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.started.set(true);
+        self.visitor.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.started.set(true);
+        self.visitor.visit_map(map)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.started.set(true);
+        self.visitor.visit_enum(data)
+    }
+}
+
+struct BaseVisitor;
+
+impl<'de> Visitor<'de> for BaseVisitor {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("scene base path")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_string())
     }
 }
 
@@ -321,6 +605,144 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     }
 }
 
+pub struct SceneResourcesDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneResourcesDeserializer<'a> {
+    type Value = Vec<DynamicResource>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneResourcesVisitor {
+            registry: self.type_registry,
+        })
+    }
+}
+
+struct SceneResourcesVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneResourcesVisitor<'a> {
+    type Value = Vec<DynamicResource>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of resources")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut resources = Vec::new();
+        while let Some(registration) =
+            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate resource: `{}`",
+                    registration.type_name()
+                )));
+            }
+
+            resources.push(map.next_value_seed(SceneResourceDeserializer {
+                registration,
+                registry: self.registry,
+            })?);
+        }
+
+        Ok(resources)
+    }
+}
+
+pub struct SceneResourceDeserializer<'a> {
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneResourceDeserializer<'a> {
+    type Value = DynamicResource;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            RESOURCE_STRUCT,
+            &[RESOURCE_FIELD_VERSION, RESOURCE_FIELD_VALUE],
+            SceneResourceVisitor {
+                registration: self.registration,
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct SceneResourceVisitor<'a> {
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneResourceVisitor<'a> {
+    type Value = DynamicResource;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("resource struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(RESOURCE_FIELD_VERSION))?;
+        let value = seq
+            .next_element_seed(TypedReflectDeserializer::new(
+                self.registration,
+                self.registry,
+            ))?
+            .ok_or_else(|| Error::missing_field(RESOURCE_FIELD_VALUE))?;
+
+        Ok(DynamicResource { version, value })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut version = None;
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ResourceField::Version => {
+                    if version.is_some() {
+                        return Err(Error::duplicate_field(RESOURCE_FIELD_VERSION));
+                    }
+                    version = Some(map.next_value()?);
+                }
+                ResourceField::Value => {
+                    if value.is_some() {
+                        return Err(Error::duplicate_field(RESOURCE_FIELD_VALUE));
+                    }
+                    value = Some(map.next_value_seed(TypedReflectDeserializer::new(
+                        self.registration,
+                        self.registry,
+                    ))?);
+                }
+            }
+        }
+
+        let version = version.ok_or_else(|| Error::missing_field(RESOURCE_FIELD_VERSION))?;
+        let value = value.ok_or_else(|| Error::missing_field(RESOURCE_FIELD_VALUE))?;
+        Ok(DynamicResource { version, value })
+    }
+}
+
 pub struct ComponentDeserializer<'a> {
     pub registry: &'a TypeRegistry,
 }
@@ -391,11 +813,15 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
 #[cfg(test)]
 mod tests {
     use crate::serde::{SceneDeserializer, SceneSerializer};
-    use crate::{DynamicScene, DynamicSceneBuilder};
-    use bevy_app::AppTypeRegistry;
+    use crate::{
+        DynamicResource, DynamicScene, DynamicSceneBuilder, SaveableResources, SceneFormat,
+    };
+    use bevy_app::{App, AppTypeRegistry};
     use bevy_ecs::entity::EntityMap;
-    use bevy_ecs::prelude::{Component, ReflectComponent, World};
-    use bevy_reflect::{FromReflect, Reflect, ReflectSerialize};
+    use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
+    use bevy_reflect::{
+        DynamicTypeDescriptor, DynamicTypeSlot, FromReflect, GetField, Reflect, ReflectSerialize,
+    };
     use bincode::Options;
     use serde::de::DeserializeSeed;
     use serde::Serialize;
@@ -535,6 +961,177 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_roundtrip_dynamic_components() {
+        let mut app = App::new();
+        app.register_dynamic_component(
+            &DynamicTypeDescriptor::new("my_mod::Poison")
+                .with_field("damage", "f32")
+                .with_field("ticks", "u32"),
+        )
+        .unwrap();
+        app.register_dynamic_component(
+            &DynamicTypeDescriptor::new("my_mod::Burning").with_field("ticks", "u32"),
+        )
+        .unwrap();
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+
+        let input = r#"(
+  entities: {
+    0: (
+      components: {
+        "my_mod::Poison": (
+          damage: 2.5,
+          ticks: 3,
+        ),
+        "my_mod::Burning": (
+          ticks: 1,
+        ),
+      },
+    ),
+  },
+)"#;
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+        .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        scene
+            .write_to_world(&mut world, &mut EntityMap::default())
+            .unwrap();
+
+        // the dynamic types are distinct components
+        let entity = world.iter_entities().next().unwrap().id();
+        let poison = world.get::<DynamicTypeSlot<0>>(entity).unwrap();
+        assert_eq!(poison.0.name(), "my_mod::Poison");
+        assert_eq!(poison.0.get_field::<u32>("ticks"), Some(&3));
+        let burning = world.get::<DynamicTypeSlot<1>>(entity).unwrap();
+        assert_eq!(burning.0.name(), "my_mod::Burning");
+
+        let extracted_scene = DynamicScene::from_world(&world, &registry);
+        assert_scene_eq(&scene, &extracted_scene);
+    }
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn should_roundtrip_resources() {
+        let mut world = create_world();
+        world
+            .resource_mut::<AppTypeRegistry>()
+            .write()
+            .register::<Score>();
+        let mut saveable = SaveableResources::default();
+        saveable.register_with_version::<Score>(3);
+        world.insert_resource(saveable);
+        world.insert_resource(Score(42));
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_saveable_resources();
+        let scene = builder.build();
+        let registry = world.resource::<AppTypeRegistry>();
+        let serialized_scene = scene.serialize_ron(&registry.0).unwrap();
+        let expected = r#"(
+  entities: {},
+  resources: {
+    "bevy_scene::serde::tests::Score": (
+      version: 3,
+      value: (42),
+    ),
+  },
+)"#;
+        assert_eq!(expected, serialized_scene);
+
+        for format in [SceneFormat::Ron, SceneFormat::Binary] {
+            let serialized_scene = scene.serialize(&registry.0, format).unwrap();
+            let scene_deserializer = SceneDeserializer {
+                type_registry: &registry.read(),
+            };
+            let deserialized_scene = match format {
+                SceneFormat::Ron => scene_deserializer
+                    .deserialize(&mut ron::de::Deserializer::from_bytes(&serialized_scene).unwrap())
+                    .unwrap(),
+                SceneFormat::Binary => scene_deserializer
+                    .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+                    .unwrap(),
+            };
+
+            let [DynamicResource { version, value }] = &deserialized_scene.resources[..] else {
+                panic!("expected a single resource");
+            };
+            assert_eq!(*version, 3);
+            assert!(value.reflect_partial_eq(&Score(42)).unwrap());
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_base() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene {
+            base: Some("scenes/enemy.scn.ron#Variant".into()),
+            resources: Vec::new(),
+            entities: Vec::new(),
+        };
+
+        let serialized_scene = scene.serialize_ron(&registry.0).unwrap();
+        assert_eq!(
+            r#"(
+  base: "scenes/enemy.scn.ron#Variant",
+  entities: {},
+)"#,
+            serialized_scene
+        );
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.read(),
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(&serialized_scene).unwrap())
+            .unwrap();
+        assert_eq!(scene.base, deserialized_scene.base);
+
+        let serialized_scene =
+            postcard::to_allocvec(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+        .unwrap();
+        assert_eq!(scene.base, deserialized_scene.base);
+
+        let serialized_scene =
+            bincode::serialize(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+        let deserialized_scene = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                SceneDeserializer {
+                    type_registry: &registry.read(),
+                },
+                &serialized_scene,
+            )
+            .unwrap();
+        assert_eq!(scene.base, deserialized_scene.base);
+
+        let mut serialized_scene = Vec::new();
+        SceneSerializer::new(&scene, &registry.0)
+            .serialize(&mut rmp_serde::Serializer::new(&mut serialized_scene))
+            .unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut rmp_serde::Deserializer::new(
+            serialized_scene.as_slice(),
+        ))
+        .unwrap();
+        assert_eq!(scene.base, deserialized_scene.base);
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -573,6 +1170,41 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_scene_formats() {
+        let mut world = create_world();
+
+        world.spawn(MyComponent {
+            foo: [1, 2, 3],
+            bar: (1.3, 3.7),
+            baz: MyEnum::Tuple("Hello World!".to_string()),
+        });
+
+        let registry = world.resource::<AppTypeRegistry>();
+
+        let scene = DynamicScene::from_world(&world, registry);
+
+        let ron = scene.serialize(&registry.0, SceneFormat::Ron).unwrap();
+        let binary = scene.serialize(&registry.0, SceneFormat::Binary).unwrap();
+        assert!(binary.len() < ron.len());
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.0.read(),
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut ron::de::Deserializer::from_bytes(&ron).unwrap())
+            .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.0.read(),
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&binary))
+            .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+    }
+
     #[test]
     fn should_roundtrip_messagepack() {
         let mut world = create_world();
@@ -658,6 +1290,127 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_deserialize_scenes_without_resources_and_base() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = registry.read();
+        let scene_deserializer = || SceneDeserializer {
+            type_registry: &registry,
+        };
+
+        // an empty scene, serialized before resources and base were added
+        let postcard_scene = scene_deserializer()
+            .deserialize(&mut postcard::Deserializer::from_bytes(&[0]))
+            .unwrap();
+        let bincode_scene = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(scene_deserializer(), &[0, 0, 0, 0, 0, 0, 0, 0])
+            .unwrap();
+        let messagepack_scene = scene_deserializer()
+            .deserialize(&mut rmp_serde::Deserializer::new([145, 128].as_slice()))
+            .unwrap();
+
+        for scene in [postcard_scene, bincode_scene, messagepack_scene] {
+            assert!(scene.entities.is_empty());
+            assert!(scene.resources.is_empty());
+            assert!(scene.base.is_none());
+        }
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_invalid_base() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>();
+
+        // a scene whose base is an integer instead of a path
+        let result = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut rmp_serde::Deserializer::new(
+            [147, 128, 128, 5].as_slice(),
+        ));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_invalid_resources() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = registry.read();
+        let scene_deserializer = || SceneDeserializer {
+            type_registry: &registry,
+        };
+
+        // a scene with one resource of an unregistered type, followed by a base
+        let mut serialized_scene = vec![0, 1, 7];
+        serialized_scene.extend_from_slice(b"unknown");
+        serialized_scene.extend_from_slice(&[1, 4]);
+        serialized_scene.extend_from_slice(b"base");
+        let postcard_result = scene_deserializer()
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene));
+        // a scene whose resources are an array instead of a map
+        let messagepack_result = scene_deserializer().deserialize(
+            &mut rmp_serde::Deserializer::new([146, 128, 144].as_slice()),
+        );
+
+        assert!(postcard_result.is_err());
+        assert!(messagepack_result.is_err());
+    }
+
+    #[test]
+    fn should_roundtrip_resources_and_base() {
+        let mut world = create_world();
+        world
+            .resource_mut::<AppTypeRegistry>()
+            .write()
+            .register::<Score>();
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene_with = |resources: Vec<DynamicResource>| DynamicScene {
+            base: Some("scenes/enemy.scn.ron".into()),
+            resources,
+            entities: Vec::new(),
+        };
+        let score = || DynamicResource {
+            version: 1,
+            value: Box::new(Score(7)),
+        };
+
+        for scene in [scene_with(Vec::new()), scene_with(vec![score()])] {
+            let serialized_scene =
+                postcard::to_allocvec(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+            let postcard_scene = SceneDeserializer {
+                type_registry: &registry.read(),
+            }
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+            .unwrap();
+
+            let serialized_scene =
+                bincode::serialize(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+            let bincode_scene = bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .deserialize_seed(
+                    SceneDeserializer {
+                        type_registry: &registry.read(),
+                    },
+                    &serialized_scene,
+                )
+                .unwrap();
+
+            for deserialized_scene in [postcard_scene, bincode_scene] {
+                assert_eq!(scene.base, deserialized_scene.base);
+                assert_eq!(scene.resources.len(), deserialized_scene.resources.len());
+                for (expected, received) in
+                    scene.resources.iter().zip(&deserialized_scene.resources)
+                {
+                    assert_eq!(expected.version, received.version);
+                    assert!(expected.value.reflect_partial_eq(&*received.value).unwrap());
+                }
+            }
+        }
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
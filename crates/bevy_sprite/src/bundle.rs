@@ -1,6 +1,6 @@
 use crate::{
     texture_atlas::{TextureAtlas, TextureAtlasSprite},
-    Sprite,
+    Sprite, Tilemap,
 };
 use bevy_asset::Handle;
 use bevy_ecs::bundle::Bundle;
@@ -50,3 +50,19 @@ pub struct SpriteSheetBundle {
     /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
     pub computed_visibility: ComputedVisibility,
 }
+
+/// A Bundle of components for drawing a grid of tiles from a `TextureAtlas`, see [`Tilemap`].
+#[derive(Bundle, Clone, Default)]
+pub struct TilemapBundle {
+    /// The tiles to be drawn
+    pub tilemap: Tilemap,
+    /// A handle to the texture atlas that holds the tile images
+    pub texture_atlas: Handle<TextureAtlas>,
+    /// Data pertaining to how the tilemap is drawn on the screen
+    pub transform: Transform,
+    pub global_transform: GlobalTransform,
+    /// User indication of whether an entity is visible
+    pub visibility: Visibility,
+    /// Algorithmically-computed indication of whether an entity is visible and should be extracted for rendering
+    pub computed_visibility: ComputedVisibility,
+}
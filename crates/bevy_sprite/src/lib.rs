@@ -2,18 +2,25 @@ mod bundle;
 mod dynamic_texture_atlas_builder;
 mod mesh2d;
 mod render;
+mod runtime_texture_atlas;
 mod sprite;
+mod sprite_animation;
 mod texture_atlas;
 mod texture_atlas_builder;
+mod texture_slice;
+mod tilemap;
 
 pub mod collide_aabb;
 
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        bundle::{SpriteBundle, SpriteSheetBundle},
+        bundle::{SpriteBundle, SpriteSheetBundle, TilemapBundle},
         sprite::Sprite,
+        sprite_animation::{SpriteAnimation, SpriteAnimationClip, SpriteAnimationMode},
         texture_atlas::{TextureAtlas, TextureAtlasSprite},
+        texture_slice::{BorderRect, ImageScaleMode},
+        tilemap::{Tile, Tilemap},
         ColorMaterial, ColorMesh2dBundle, TextureAtlasBuilder,
     };
 }
@@ -22,12 +29,16 @@ pub use bundle::*;
 pub use dynamic_texture_atlas_builder::*;
 pub use mesh2d::*;
 pub use render::*;
+pub use runtime_texture_atlas::*;
 pub use sprite::*;
+pub use sprite_animation::*;
 pub use texture_atlas::*;
 pub use texture_atlas_builder::*;
+pub use texture_slice::*;
+pub use tilemap::*;
 
 use bevy_app::prelude::*;
-use bevy_asset::{AddAsset, Assets, HandleUntyped};
+use bevy_asset::{load_internal_asset, AddAsset, Assets, HandleUntyped};
 use bevy_core_pipeline::core_2d::Transparent2d;
 use bevy_ecs::prelude::*;
 use bevy_reflect::TypeUuid;
@@ -46,6 +57,9 @@ pub const SPRITE_SHADER_HANDLE: HandleUntyped =
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum SpriteSystem {
     ExtractSprites,
+    AnimateSprites,
+    PackSpritesIntoAtlases,
+    UpdateTilemapChunks,
 }
 
 impl Plugin for SpritePlugin {
@@ -53,11 +67,30 @@ impl Plugin for SpritePlugin {
         let mut shaders = app.world.resource_mut::<Assets<Shader>>();
         let sprite_shader = Shader::from_wgsl(include_str!("render/sprite.wgsl"));
         shaders.set_untracked(SPRITE_SHADER_HANDLE, sprite_shader);
+        load_internal_asset!(
+            app,
+            TILEMAP_SHADER_HANDLE,
+            "tilemap/tilemap.wgsl",
+            Shader::from_wgsl
+        );
         app.add_asset::<TextureAtlas>()
             .register_asset_reflect::<TextureAtlas>()
             .register_type::<Sprite>()
             .register_type::<Anchor>()
+            .register_type::<BorderRect>()
+            .register_type::<ImageScaleMode>()
             .register_type::<Mesh2dHandle>()
+            .register_type::<SpriteAnimation>()
+            .register_type::<SpriteAnimationClip>()
+            .register_type::<SpriteAnimationMode>()
+            .register_type::<PackIntoAtlas>()
+            .register_type::<Tilemap>()
+            .register_type::<TilemapChunk>()
+            .init_resource::<RuntimeTextureAtlases>()
+            .add_event::<SpriteAnimationCompleted>()
+            .add_system(animate_sprites.in_set(SpriteSystem::AnimateSprites))
+            .add_system(pack_sprites_into_atlases.in_set(SpriteSystem::PackSpritesIntoAtlases))
+            .add_system(update_tilemap_chunks.in_set(SpriteSystem::UpdateTilemapChunks))
             .add_plugin(Mesh2dRenderPlugin)
             .add_plugin(ColorMaterialPlugin);
 
@@ -69,18 +102,31 @@ impl Plugin for SpritePlugin {
                 .init_resource::<SpriteMeta>()
                 .init_resource::<ExtractedSprites>()
                 .init_resource::<SpriteAssetEvents>()
+                .init_resource::<TilemapPipeline>()
+                .init_resource::<SpecializedRenderPipelines<TilemapPipeline>>()
+                .init_resource::<TilemapChunkBuffers>()
+                .init_resource::<TilemapImageBindGroups>()
                 .add_render_command::<Transparent2d, DrawSprite>()
+                .add_render_command::<Transparent2d, DrawTilemapChunk>()
                 .add_systems(
                     (
                         extract_sprites.in_set(SpriteSystem::ExtractSprites),
                         extract_sprite_events,
+                        extract_tilemap_chunks,
                     )
                         .in_schedule(ExtractSchedule),
                 )
+                .add_system(prepare_tilemap_chunks.in_set(RenderSet::Prepare))
                 .add_system(
                     queue_sprites
                         .in_set(RenderSet::Queue)
                         .ambiguous_with(queue_material2d_meshes::<ColorMaterial>),
+                )
+                .add_system(
+                    queue_tilemap_chunks
+                        .in_set(RenderSet::Queue)
+                        .ambiguous_with(queue_sprites)
+                        .ambiguous_with(queue_material2d_meshes::<ColorMaterial>),
                 );
         };
     }
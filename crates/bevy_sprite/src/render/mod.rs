@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use crate::{
     texture_atlas::{TextureAtlas, TextureAtlasSprite},
-    Sprite, SPRITE_SHADER_HANDLE,
+    ImageScaleMode, Sprite, SPRITE_SHADER_HANDLE,
 };
 use bevy_asset::{AssetEvent, Assets, Handle, HandleId};
 use bevy_core_pipeline::{
@@ -33,7 +33,7 @@ use bevy_render::{
     },
     Extract,
 };
-use bevy_transform::components::GlobalTransform;
+use bevy_transform::components::{GlobalTransform, Transform};
 use bevy_utils::FloatOrd;
 use bevy_utils::HashMap;
 use bytemuck::{Pod, Zeroable};
@@ -346,6 +346,12 @@ pub fn extract_sprite_events(
             AssetEvent::Removed { handle } => AssetEvent::Removed {
                 handle: handle.clone_weak(),
             },
+            AssetEvent::Evicted { handle } => AssetEvent::Evicted {
+                handle: handle.clone_weak(),
+            },
+            AssetEvent::DependencyModified { handle } => AssetEvent::DependencyModified {
+                handle: handle.clone_weak(),
+            },
         });
     }
 }
@@ -353,6 +359,7 @@ pub fn extract_sprite_events(
 pub fn extract_sprites(
     mut extracted_sprites: ResMut<ExtractedSprites>,
     texture_atlases: Extract<Res<Assets<TextureAtlas>>>,
+    images: Extract<Res<Assets<Image>>>,
     sprite_query: Extract<
         Query<(
             Entity,
@@ -360,6 +367,7 @@ pub fn extract_sprites(
             &Sprite,
             &GlobalTransform,
             &Handle<Image>,
+            Option<&ImageScaleMode>,
         )>,
     >,
     atlas_query: Extract<
@@ -373,10 +381,46 @@ pub fn extract_sprites(
     >,
 ) {
     extracted_sprites.sprites.clear();
-    for (entity, visibility, sprite, transform, handle) in sprite_query.iter() {
+    for (entity, visibility, sprite, transform, handle, scale_mode) in sprite_query.iter() {
         if !visibility.is_visible() {
             continue;
         }
+        if let Some(scale_mode) = scale_mode.filter(|mode| **mode != ImageScaleMode::Stretched) {
+            let image_rect = sprite.rect.or_else(|| {
+                images.get(handle).map(|image| Rect {
+                    min: Vec2::ZERO,
+                    max: image.size(),
+                })
+            });
+            if let Some(image_rect) = image_rect {
+                let render_size = sprite.custom_size.unwrap_or_else(|| image_rect.size());
+                // The slices are positioned relative to the anchor of the whole sprite.
+                let anchor_offset = -sprite.anchor.as_vec() * render_size;
+                for slice in scale_mode.compute_slices(image_rect, render_size) {
+                    let mut offset = slice.offset;
+                    if sprite.flip_x {
+                        offset.x = -offset.x;
+                    }
+                    if sprite.flip_y {
+                        offset.y = -offset.y;
+                    }
+                    extracted_sprites.sprites.push(ExtractedSprite {
+                        entity,
+                        color: sprite.color,
+                        transform: transform.mul_transform(Transform::from_translation(
+                            (anchor_offset + offset).extend(0.0),
+                        )),
+                        rect: Some(slice.texture_rect),
+                        custom_size: Some(slice.draw_size),
+                        flip_x: sprite.flip_x,
+                        flip_y: sprite.flip_y,
+                        image_handle_id: handle.id(),
+                        anchor: Vec2::ZERO,
+                    });
+                }
+                continue;
+            }
+        }
         // PERF: we don't check in this function that the `Image` asset is ready, since it should be in most cases and hashing the handle is expensive
         extracted_sprites.sprites.push(ExtractedSprite {
             entity,
@@ -501,7 +545,9 @@ pub fn queue_sprites(
     // If an image has changed, the GpuImage has (probably) changed
     for event in &events.images {
         match event {
-            AssetEvent::Created { .. } => None,
+            AssetEvent::Created { .. }
+            | AssetEvent::Evicted { .. }
+            | AssetEvent::DependencyModified { .. } => None,
             AssetEvent::Modified { handle } | AssetEvent::Removed { handle } => {
                 image_bind_groups.values.remove(handle)
             }
@@ -0,0 +1,294 @@
+use crate::{DynamicTextureAtlasBuilder, Sprite, TextureAtlas};
+use bevy_asset::{Assets, Handle, HandleId};
+use bevy_ecs::prelude::*;
+use bevy_log::warn;
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+    texture::{Image, ImageSampler, TextureFormatPixelInfo},
+};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// Marks a [`Sprite`] whose image should be packed into one of the shared atlases of the
+/// [`RuntimeTextureAtlases`].
+///
+/// Once the image is loaded and packed, the `Handle<Image>` of the sprite is replaced by the
+/// handle of the atlas texture, [`Sprite::rect`] is set to the area of the image in the atlas and
+/// this component is removed. Sprites sharing an atlas are drawn together, which greatly reduces
+/// the number of draw calls in scenes with many different sprite images.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct PackIntoAtlas;
+
+/// An error returned by [`RuntimeTextureAtlases::pack`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RuntimeTextureAtlasError {
+    /// The image isn't in the `Assets<Image>` yet. Packing can be retried once it is loaded.
+    #[error("the image is not loaded yet")]
+    NotLoaded,
+    /// The image, including the padding, is larger than the size of the atlases given to
+    /// [`RuntimeTextureAtlases::new`], so it doesn't fit into any atlas.
+    #[error("the image is larger than the atlases")]
+    TooLarge,
+    /// The image can't be copied into an atlas, because it is compressed, not 2D, has multiple
+    /// layers or mip levels, or uses a sampler other than the default one.
+    #[error(
+        "the image is not an uncompressed 2D image with a single mip level and the default sampler"
+    )]
+    Unsupported,
+}
+
+struct RuntimeAtlas {
+    texture_atlas: Handle<TextureAtlas>,
+    builder: DynamicTextureAtlasBuilder,
+    format: TextureFormat,
+}
+
+/// Texture atlases which images are packed into at runtime, e.g. by adding [`PackIntoAtlas`] to
+/// sprites.
+///
+/// A new atlas is created whenever an image doesn't fit into the existing atlases of its
+/// [`TextureFormat`]. Insert this resource with a different size or padding to configure the
+/// atlases.
+///
+/// The atlases don't track changes of the packed images: modifying an image after it was packed
+/// doesn't affect the atlas.
+#[derive(Resource)]
+pub struct RuntimeTextureAtlases {
+    size: Vec2,
+    padding: i32,
+    atlases: Vec<RuntimeAtlas>,
+    packed: HashMap<HandleId, (Handle<TextureAtlas>, usize)>,
+}
+
+impl Default for RuntimeTextureAtlases {
+    fn default() -> Self {
+        Self::new(Vec2::splat(2048.), 1)
+    }
+}
+
+impl RuntimeTextureAtlases {
+    /// Creates a new [`RuntimeTextureAtlases`].
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - the size of each atlas, images larger than this can't be packed
+    /// * `padding` - gap added between images in an atlas, both in x axis and y axis
+    pub fn new(size: Vec2, padding: i32) -> Self {
+        Self {
+            size,
+            padding,
+            atlases: Vec::new(),
+            packed: HashMap::default(),
+        }
+    }
+
+    /// Returns the size of each atlas.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Returns the gap added between the images in an atlas.
+    pub fn padding(&self) -> i32 {
+        self.padding
+    }
+
+    /// Returns the atlases created so far.
+    pub fn texture_atlases(&self) -> impl Iterator<Item = &Handle<TextureAtlas>> {
+        self.atlases.iter().map(|atlas| &atlas.texture_atlas)
+    }
+
+    /// Returns the atlas the image was packed into and its index in the atlas.
+    pub fn get(&self, image: &Handle<Image>) -> Option<(&Handle<TextureAtlas>, usize)> {
+        self.packed
+            .get(&image.id())
+            .map(|(texture_atlas, index)| (texture_atlas, *index))
+    }
+
+    /// Packs the image into an atlas, unless it was packed before, and returns the atlas and the
+    /// index of the image in the atlas.
+    pub fn pack(
+        &mut self,
+        image: &Handle<Image>,
+        images: &mut Assets<Image>,
+        texture_atlases: &mut Assets<TextureAtlas>,
+    ) -> Result<(Handle<TextureAtlas>, usize), RuntimeTextureAtlasError> {
+        if let Some((texture_atlas, index)) = self.packed.get(&image.id()) {
+            return Ok((texture_atlas.clone(), *index));
+        }
+        let texture = images
+            .get(image)
+            .ok_or(RuntimeTextureAtlasError::NotLoaded)?;
+        let descriptor = &texture.texture_descriptor;
+        if descriptor.dimension != TextureDimension::D2
+            || descriptor.size.depth_or_array_layers != 1
+            || descriptor.mip_level_count != 1
+            || descriptor.format.describe().block_dimensions != (1, 1)
+            || !matches!(texture.sampler_descriptor, ImageSampler::Default)
+        {
+            return Err(RuntimeTextureAtlasError::Unsupported);
+        }
+        if (descriptor.size.width as i32 + self.padding) as f32 > self.size.x
+            || (descriptor.size.height as i32 + self.padding) as f32 > self.size.y
+        {
+            return Err(RuntimeTextureAtlasError::TooLarge);
+        }
+        let format = descriptor.format;
+        let texture = texture.clone();
+
+        for atlas in self
+            .atlases
+            .iter_mut()
+            .filter(|atlas| atlas.format == format)
+        {
+            let Some(texture_atlas) = texture_atlases.get_mut(&atlas.texture_atlas) else {
+                continue;
+            };
+            if let Some(index) = atlas.builder.add_texture(texture_atlas, images, &texture) {
+                self.packed
+                    .insert(image.id(), (atlas.texture_atlas.clone(), index));
+                return Ok((atlas.texture_atlas.clone(), index));
+            }
+        }
+
+        let atlas_texture = images.add(Image::new_fill(
+            Extent3d {
+                width: self.size.x as u32,
+                height: self.size.y as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &vec![0; format.pixel_size()],
+            format,
+        ));
+        let mut texture_atlas = TextureAtlas::new_empty(atlas_texture, self.size);
+        let mut builder = DynamicTextureAtlasBuilder::new(self.size, self.padding);
+        let index = builder
+            .add_texture(&mut texture_atlas, images, &texture)
+            .ok_or(RuntimeTextureAtlasError::TooLarge)?;
+        let texture_atlas = texture_atlases.add(texture_atlas);
+        self.atlases.push(RuntimeAtlas {
+            texture_atlas: texture_atlas.clone(),
+            builder,
+            format,
+        });
+        self.packed
+            .insert(image.id(), (texture_atlas.clone(), index));
+        Ok((texture_atlas, index))
+    }
+}
+
+/// System that packs the images of sprites marked with [`PackIntoAtlas`] into the
+/// [`RuntimeTextureAtlases`] and makes the sprites use the atlas instead.
+pub fn pack_sprites_into_atlases(
+    mut commands: Commands,
+    mut runtime_atlases: ResMut<RuntimeTextureAtlases>,
+    mut images: ResMut<Assets<Image>>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut sprites: Query<(Entity, &mut Sprite, &mut Handle<Image>), With<PackIntoAtlas>>,
+) {
+    for (entity, mut sprite, mut image) in &mut sprites {
+        match runtime_atlases.pack(&image, &mut images, &mut texture_atlases) {
+            Ok((texture_atlas, index)) => {
+                let texture_atlas = texture_atlases.get(&texture_atlas).unwrap();
+                let packed = texture_atlas.textures[index];
+                // Keep showing the same region if the sprite only showed a part of its image.
+                sprite.rect = Some(match sprite.rect {
+                    Some(rect) => Rect {
+                        min: rect.min + packed.min,
+                        max: rect.max + packed.min,
+                    },
+                    None => packed,
+                });
+                *image = texture_atlas.texture.clone();
+            }
+            Err(RuntimeTextureAtlasError::NotLoaded) => continue,
+            Err(err) => warn!("Could not pack the image of sprite {entity:?} into an atlas: {err}"),
+        }
+        commands.entity(entity).remove::<PackIntoAtlas>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+
+    fn image(width: u32, height: u32, value: u8) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[value; 4],
+            TextureFormat::Rgba8UnormSrgb,
+        )
+    }
+
+    #[test]
+    fn sprites_share_runtime_atlas() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>()
+            .insert_resource(RuntimeTextureAtlases::new(Vec2::splat(16.), 0))
+            .add_system(pack_sprites_into_atlases);
+
+        let (first, second, too_large) = {
+            let mut images = app.world.resource_mut::<Assets<Image>>();
+            (
+                images.add(image(8, 8, 1)),
+                images.add(image(4, 4, 2)),
+                images.add(image(32, 4, 3)),
+            )
+        };
+        let sprites = [
+            app.world
+                .spawn((Sprite::default(), first.clone(), PackIntoAtlas))
+                .id(),
+            app.world
+                .spawn((
+                    Sprite {
+                        rect: Some(Rect::new(1., 1., 3., 3.)),
+                        ..Default::default()
+                    },
+                    second.clone(),
+                    PackIntoAtlas,
+                ))
+                .id(),
+            app.world
+                .spawn((Sprite::default(), too_large.clone(), PackIntoAtlas))
+                .id(),
+        ];
+        app.update();
+
+        let runtime_atlases = app.world.resource::<RuntimeTextureAtlases>();
+        assert_eq!(runtime_atlases.texture_atlases().count(), 1);
+        let (texture_atlas, second_index) = runtime_atlases.get(&second).unwrap();
+        let texture_atlas = app
+            .world
+            .resource::<Assets<TextureAtlas>>()
+            .get(texture_atlas)
+            .unwrap();
+        assert!(runtime_atlases.get(&too_large).is_none());
+
+        for &sprite in &sprites[..2] {
+            assert_eq!(
+                app.world.get::<Handle<Image>>(sprite),
+                Some(&texture_atlas.texture)
+            );
+            assert!(app.world.get::<PackIntoAtlas>(sprite).is_none());
+        }
+        let packed = texture_atlas.textures[second_index];
+        assert_eq!(
+            app.world.get::<Sprite>(sprites[1]).unwrap().rect,
+            Some(Rect::from_corners(packed.min + 1., packed.min + 3.))
+        );
+        assert_eq!(app.world.get::<Handle<Image>>(sprites[2]), Some(&too_large));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::TextureAtlasSprite;
+use bevy_ecs::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+use bevy_time::Time;
+use bevy_utils::HashMap;
+use std::ops::Range;
+
+/// How a [`SpriteAnimationClip`] continues after its last frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum SpriteAnimationMode {
+    /// Stop on the last frame.
+    Once,
+    /// Start again from the first frame.
+    #[default]
+    Loop,
+    /// Play the frames backwards down to the first frame, then forwards again.
+    PingPong,
+}
+
+/// A sequence of [`TextureAtlas`](crate::TextureAtlas) indices played at a fixed rate by a
+/// [`SpriteAnimation`].
+#[derive(Debug, Clone, Default, Reflect, FromReflect)]
+pub struct SpriteAnimationClip {
+    /// The texture atlas indices of the frames, in order.
+    pub frames: Vec<usize>,
+    /// The number of frames shown per second.
+    pub fps: f32,
+    /// What happens after the last frame.
+    pub mode: SpriteAnimationMode,
+}
+
+impl SpriteAnimationClip {
+    /// Creates a looping clip showing the given texture atlas indices.
+    pub fn new(frames: impl Into<Vec<usize>>, fps: f32) -> Self {
+        Self {
+            frames: frames.into(),
+            fps,
+            mode: SpriteAnimationMode::Loop,
+        }
+    }
+
+    /// Creates a looping clip showing a range of consecutive texture atlas indices, like a row
+    /// of a sprite sheet.
+    pub fn from_range(indices: Range<usize>, fps: f32) -> Self {
+        Self::new(indices.collect::<Vec<_>>(), fps)
+    }
+
+    /// Sets the [`SpriteAnimationMode`] of the clip.
+    #[must_use]
+    pub fn with_mode(mut self, mode: SpriteAnimationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Animates the [`TextureAtlasSprite::index`] of an entity by playing named
+/// [`SpriteAnimationClip`]s.
+///
+/// A [`SpriteAnimationCompleted`] event is sent each time the playing clip reaches its end.
+///
+/// ```
+/// # use bevy_sprite::{SpriteAnimation, SpriteAnimationClip, SpriteAnimationMode};
+/// let mut animation = SpriteAnimation::default()
+///     .with_clip("idle", SpriteAnimationClip::from_range(0..4, 8.0))
+///     .with_clip(
+///         "attack",
+///         SpriteAnimationClip::from_range(4..10, 12.0).with_mode(SpriteAnimationMode::Once),
+///     );
+/// animation.play("idle");
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct SpriteAnimation {
+    clips: HashMap<String, SpriteAnimationClip>,
+    current: Option<String>,
+    frame: usize,
+    elapsed: f32,
+    speed: f32,
+    paused: bool,
+    backwards: bool,
+    finished: bool,
+}
+
+impl Default for SpriteAnimation {
+    fn default() -> Self {
+        Self {
+            clips: HashMap::default(),
+            current: None,
+            frame: 0,
+            elapsed: 0.0,
+            speed: 1.0,
+            paused: false,
+            backwards: false,
+            finished: false,
+        }
+    }
+}
+
+impl SpriteAnimation {
+    /// Adds a clip with the given name, replacing any clip with the same name.
+    #[must_use]
+    pub fn with_clip(mut self, name: impl Into<String>, clip: SpriteAnimationClip) -> Self {
+        self.add_clip(name, clip);
+        self
+    }
+
+    /// Adds a clip with the given name, replacing any clip with the same name.
+    pub fn add_clip(&mut self, name: impl Into<String>, clip: SpriteAnimationClip) -> &mut Self {
+        self.clips.insert(name.into(), clip);
+        self
+    }
+
+    /// Returns the clip with the given name.
+    pub fn clip(&self, name: &str) -> Option<&SpriteAnimationClip> {
+        self.clips.get(name)
+    }
+
+    /// Starts playing the clip with the given name from its first frame, unless it is already
+    /// playing.
+    pub fn play(&mut self, name: &str) -> &mut Self {
+        if self.current.as_deref() != Some(name) {
+            self.restart(name);
+        }
+        self
+    }
+
+    /// Starts playing the clip with the given name from its first frame, even if it is already
+    /// playing.
+    pub fn restart(&mut self, name: &str) -> &mut Self {
+        self.current = Some(name.to_string());
+        self.frame = 0;
+        self.elapsed = 0.0;
+        self.backwards = false;
+        self.finished = false;
+        self
+    }
+
+    /// Stops playing the current clip, leaving the sprite on its current frame.
+    pub fn stop(&mut self) -> &mut Self {
+        self.current = None;
+        self
+    }
+
+    /// Returns the name of the clip that is playing.
+    pub fn current_clip(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Returns the position of the current frame in the [`SpriteAnimationClip::frames`] of the
+    /// playing clip.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Pauses the animation.
+    pub fn pause(&mut self) -> &mut Self {
+        self.paused = true;
+        self
+    }
+
+    /// Resumes the animation.
+    pub fn resume(&mut self) -> &mut Self {
+        self.paused = false;
+        self
+    }
+
+    /// Returns whether the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns whether the playing clip is a [`SpriteAnimationMode::Once`] clip that reached its
+    /// last frame.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the playback speed, relative to the frame rates of the clips.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the playback speed, relative to the frame rates of the clips. Negative speeds are
+    /// treated as `0.0`.
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Advances the playing clip by `delta` seconds, returning the texture atlas index of the
+    /// current frame and whether the clip reached its end.
+    fn advance(&mut self, delta: f32) -> Option<(usize, bool)> {
+        let clip = self.clips.get(self.current.as_ref()?)?;
+        if clip.frames.is_empty() {
+            return None;
+        }
+        // The clip may have been replaced by a shorter one.
+        self.frame = self.frame.min(clip.frames.len() - 1);
+
+        let mut completed = false;
+        if !self.paused && !self.finished && clip.fps > 0.0 {
+            let frame_duration = clip.fps.recip();
+            self.elapsed += delta * self.speed;
+            while self.elapsed >= frame_duration {
+                self.elapsed -= frame_duration;
+                let last = clip.frames.len() - 1;
+                match clip.mode {
+                    SpriteAnimationMode::Once => {
+                        if self.frame < last {
+                            self.frame += 1;
+                        }
+                        if self.frame == last {
+                            self.finished = true;
+                            self.elapsed = 0.0;
+                            completed = true;
+                            break;
+                        }
+                    }
+                    SpriteAnimationMode::Loop => {
+                        if self.frame < last {
+                            self.frame += 1;
+                        } else {
+                            self.frame = 0;
+                            completed = true;
+                        }
+                    }
+                    SpriteAnimationMode::PingPong => {
+                        if !self.backwards {
+                            if self.frame < last {
+                                self.frame += 1;
+                            } else {
+                                self.backwards = true;
+                            }
+                        }
+                        if self.backwards {
+                            self.frame = self.frame.saturating_sub(1);
+                            if self.frame == 0 {
+                                self.backwards = false;
+                                completed = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Some((clip.frames[self.frame], completed))
+    }
+}
+
+/// An event sent when the clip playing on a [`SpriteAnimation`] reaches its end.
+///
+/// For [`SpriteAnimationMode::Loop`] and [`SpriteAnimationMode::PingPong`] clips, this is sent
+/// once per cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteAnimationCompleted {
+    /// The entity of the [`SpriteAnimation`].
+    pub entity: Entity,
+    /// The name of the clip.
+    pub clip: String,
+}
+
+/// System that advances all [`SpriteAnimation`]s and updates their [`TextureAtlasSprite::index`].
+pub fn animate_sprites(
+    time: Res<Time>,
+    mut animations: Query<(Entity, &mut SpriteAnimation, &mut TextureAtlasSprite)>,
+    mut completed: EventWriter<SpriteAnimationCompleted>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut animation, mut sprite) in &mut animations {
+        let Some((index, clip_completed)) = animation.advance(delta) else {
+            continue;
+        };
+        if sprite.index != index {
+            sprite.index = index;
+        }
+        if clip_completed {
+            completed.send(SpriteAnimationCompleted {
+                entity,
+                clip: animation.current.clone().unwrap_or_default(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(animation: &mut SpriteAnimation, steps: usize) -> Vec<(usize, bool)> {
+        (0..steps)
+            .map(|_| animation.advance(0.1).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn sprite_animation_modes() {
+        let mut animation = SpriteAnimation::default()
+            .with_clip("loop", SpriteAnimationClip::new([4, 5, 6], 10.0))
+            .with_clip(
+                "once",
+                SpriteAnimationClip::new([4, 5, 6], 10.0).with_mode(SpriteAnimationMode::Once),
+            )
+            .with_clip(
+                "ping_pong",
+                SpriteAnimationClip::new([4, 5, 6], 10.0).with_mode(SpriteAnimationMode::PingPong),
+            );
+        assert_eq!(animation.advance(0.1), None);
+
+        animation.play("loop");
+        assert_eq!(
+            frames(&mut animation, 4),
+            [(5, false), (6, false), (4, true), (5, false)]
+        );
+
+        animation.play("once");
+        assert_eq!(
+            frames(&mut animation, 3),
+            [(5, false), (6, true), (6, false)]
+        );
+        assert!(animation.is_finished());
+
+        animation.play("ping_pong");
+        assert_eq!(
+            frames(&mut animation, 5),
+            [(5, false), (6, false), (5, false), (4, true), (5, false)]
+        );
+    }
+
+    #[test]
+    fn sprite_animation_speed_and_pause() {
+        let mut animation = SpriteAnimation::default()
+            .with_clip("run", SpriteAnimationClip::from_range(0..8, 10.0));
+        animation.play("run").set_speed(2.0);
+        assert_eq!(animation.advance(0.1), Some((2, false)));
+
+        animation.pause();
+        assert_eq!(animation.advance(1.0), Some((2, false)));
+        animation.resume();
+        // Playing an already playing clip doesn't restart it.
+        animation.play("run");
+        assert_eq!(animation.advance(0.05), Some((3, false)));
+        animation.restart("run");
+        assert_eq!(animation.advance(0.0), Some((0, false)));
+    }
+}
@@ -0,0 +1,251 @@
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+
+/// The widths of the four borders of a [`Rect`], in pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect, FromReflect)]
+#[reflect(Default)]
+pub struct BorderRect {
+    /// The width of the left border.
+    pub left: f32,
+    /// The width of the right border.
+    pub right: f32,
+    /// The height of the top border.
+    pub top: f32,
+    /// The height of the bottom border.
+    pub bottom: f32,
+}
+
+impl BorderRect {
+    /// Creates borders of the same width on all four sides.
+    pub const fn square(value: f32) -> Self {
+        Self {
+            left: value,
+            right: value,
+            top: value,
+            bottom: value,
+        }
+    }
+
+    /// Creates borders with a `horizontal` width on the left and right, and a `vertical` width
+    /// on the top and bottom.
+    pub const fn rectangle(horizontal: f32, vertical: f32) -> Self {
+        Self {
+            left: horizontal,
+            right: horizontal,
+            top: vertical,
+            bottom: vertical,
+        }
+    }
+}
+
+/// How an image is scaled to the size it is drawn with.
+///
+/// Without this component images are stretched. Add it to a sprite or a UI image to preserve the
+/// borders of panels and buttons built from 9-slice textures, or to repeat a pattern.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub enum ImageScaleMode {
+    /// Stretch the whole image to the drawn size.
+    #[default]
+    Stretched,
+    /// Split the image into nine slices along the given borders.
+    ///
+    /// The corners keep their size, the edges are stretched along one axis and the center is
+    /// stretched along both. The corners are scaled down if the drawn size is smaller than the
+    /// borders.
+    Sliced(BorderRect),
+    /// Repeat the image at its original size, starting from the top left corner. The tiles on the
+    /// right and bottom edges are cut off.
+    Tiled,
+}
+
+/// A part of an image drawn by an [`ImageScaleMode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextureSlice {
+    /// The area of the image to draw, in pixels.
+    pub texture_rect: Rect,
+    /// The size the area is drawn with.
+    pub draw_size: Vec2,
+    /// The offset of the center of the slice from the center of the drawn image, with the y axis
+    /// pointing up.
+    pub offset: Vec2,
+}
+
+impl ImageScaleMode {
+    /// Splits the `image_rect` area of an image into the slices needed to draw it with the size
+    /// `render_size`.
+    pub fn compute_slices(&self, image_rect: Rect, render_size: Vec2) -> Vec<TextureSlice> {
+        match self {
+            ImageScaleMode::Stretched => vec![TextureSlice {
+                texture_rect: image_rect,
+                draw_size: render_size,
+                offset: Vec2::ZERO,
+            }],
+            ImageScaleMode::Sliced(border) => slice(image_rect, render_size, border),
+            ImageScaleMode::Tiled => tile(image_rect, render_size),
+        }
+    }
+}
+
+fn slice(image_rect: Rect, render_size: Vec2, border: &BorderRect) -> Vec<TextureSlice> {
+    let image_size = image_rect.size();
+    let left = border.left.clamp(0.0, image_size.x);
+    let right = border.right.clamp(0.0, image_size.x - left);
+    let top = border.top.clamp(0.0, image_size.y);
+    let bottom = border.bottom.clamp(0.0, image_size.y - top);
+    // Shrink the corners when they don't fit into the drawn size.
+    let border_size = Vec2::new(left + right, top + bottom);
+    let corner_scale = Vec2::select(
+        border_size.cmpgt(render_size),
+        render_size / border_size,
+        Vec2::ONE,
+    )
+    .min_element()
+    .max(0.0);
+
+    // The texture and drawn extents of the left, center and right columns, and of the top, center
+    // and bottom rows.
+    let columns = segments(
+        image_rect.min.x,
+        image_rect.max.x,
+        left,
+        right,
+        render_size.x,
+        corner_scale,
+    );
+    let rows = segments(
+        image_rect.min.y,
+        image_rect.max.y,
+        top,
+        bottom,
+        render_size.y,
+        corner_scale,
+    );
+
+    let mut slices = Vec::with_capacity(9);
+    for &(texture_y, draw_y) in &rows {
+        for &(texture_x, draw_x) in &columns {
+            let draw_size = Vec2::new(draw_x.1 - draw_x.0, draw_y.1 - draw_y.0);
+            if draw_size.x <= 0.0
+                || draw_size.y <= 0.0
+                || texture_x.0 >= texture_x.1
+                || texture_y.0 >= texture_y.1
+            {
+                continue;
+            }
+            slices.push(TextureSlice {
+                texture_rect: Rect::new(texture_x.0, texture_y.0, texture_x.1, texture_y.1),
+                draw_size,
+                offset: Vec2::new(
+                    (draw_x.0 + draw_x.1 - render_size.x) / 2.0,
+                    (render_size.y - draw_y.0 - draw_y.1) / 2.0,
+                ),
+            });
+        }
+    }
+    slices
+}
+
+/// Splits `min..max` at the `start` and `end` borders, returning the texture range and the drawn
+/// range of each segment.
+fn segments(
+    min: f32,
+    max: f32,
+    start: f32,
+    end: f32,
+    render_length: f32,
+    corner_scale: f32,
+) -> [((f32, f32), (f32, f32)); 3] {
+    let drawn_start = start * corner_scale;
+    let drawn_end = render_length - end * corner_scale;
+    [
+        ((min, min + start), (0.0, drawn_start)),
+        ((min + start, max - end), (drawn_start, drawn_end)),
+        ((max - end, max), (drawn_end, render_length)),
+    ]
+}
+
+fn tile(image_rect: Rect, render_size: Vec2) -> Vec<TextureSlice> {
+    let tile_size = image_rect.size();
+    if tile_size.x <= 0.0 || tile_size.y <= 0.0 {
+        return Vec::new();
+    }
+    let mut slices = Vec::new();
+    let mut y = 0.0;
+    while y < render_size.y {
+        let height = tile_size.y.min(render_size.y - y);
+        let mut x = 0.0;
+        while x < render_size.x {
+            let width = tile_size.x.min(render_size.x - x);
+            let draw_size = Vec2::new(width, height);
+            slices.push(TextureSlice {
+                texture_rect: Rect::from_corners(image_rect.min, image_rect.min + draw_size),
+                draw_size,
+                offset: Vec2::new(
+                    x + (width - render_size.x) / 2.0,
+                    (render_size.y - height) / 2.0 - y,
+                ),
+            });
+            x += tile_size.x;
+        }
+        y += tile_size.y;
+    }
+    slices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sliced_keeps_corners() {
+        let slices = ImageScaleMode::Sliced(BorderRect::square(10.0))
+            .compute_slices(Rect::new(0.0, 0.0, 30.0, 30.0), Vec2::new(100.0, 50.0));
+        assert_eq!(slices.len(), 9);
+        // Top left corner
+        assert_eq!(
+            slices[0],
+            TextureSlice {
+                texture_rect: Rect::new(0.0, 0.0, 10.0, 10.0),
+                draw_size: Vec2::new(10.0, 10.0),
+                offset: Vec2::new(-45.0, 20.0),
+            }
+        );
+        // Center
+        assert_eq!(
+            slices[4],
+            TextureSlice {
+                texture_rect: Rect::new(10.0, 10.0, 20.0, 20.0),
+                draw_size: Vec2::new(80.0, 30.0),
+                offset: Vec2::ZERO,
+            }
+        );
+        // Bottom right corner
+        assert_eq!(slices[8].offset, Vec2::new(45.0, -20.0));
+
+        // The corners shrink to fit into small sizes, and the empty center is skipped.
+        let slices = ImageScaleMode::Sliced(BorderRect::square(10.0))
+            .compute_slices(Rect::new(0.0, 0.0, 30.0, 30.0), Vec2::new(10.0, 10.0));
+        assert_eq!(slices.len(), 4);
+        assert!(slices
+            .iter()
+            .all(|slice| slice.draw_size == Vec2::splat(5.0)));
+    }
+
+    #[test]
+    fn tiled_cuts_off_last_tiles() {
+        let slices = ImageScaleMode::Tiled
+            .compute_slices(Rect::new(0.0, 0.0, 16.0, 16.0), Vec2::new(40.0, 16.0));
+        assert_eq!(slices.len(), 3);
+        assert_eq!(slices[0].offset, Vec2::new(-12.0, 0.0));
+        assert_eq!(
+            slices[2],
+            TextureSlice {
+                texture_rect: Rect::new(0.0, 0.0, 8.0, 16.0),
+                draw_size: Vec2::new(8.0, 16.0),
+                offset: Vec2::new(16.0, 0.0),
+            }
+        );
+    }
+}
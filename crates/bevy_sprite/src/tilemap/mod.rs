@@ -0,0 +1,586 @@
+mod render;
+
+pub use render::*;
+
+use crate::TextureAtlas;
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_math::{UVec2, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+use bevy_render::{color::Color, prelude::SpatialBundle, primitives::Aabb, texture::Image};
+use bevy_transform::components::Transform;
+use bevy_utils::{HashMap, HashSet};
+use bytemuck::{Pod, Zeroable};
+
+/// A tile of a [`Tilemap`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, FromReflect)]
+#[reflect(Default)]
+pub struct Tile {
+    /// Texture index in the [`TextureAtlas`] of the tilemap
+    pub index: usize,
+    /// The tint color used to draw the tile, defaulting to [`Color::WHITE`]
+    pub color: Color,
+    /// Whether to flip the tile in the X axis
+    pub flip_x: bool,
+    /// Whether to flip the tile in the Y axis
+    pub flip_y: bool,
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            color: Color::WHITE,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
+
+impl Tile {
+    /// Creates a new [`Tile`] with a texture index, it should be valid in the [`TextureAtlas`] of
+    /// the tilemap.
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            ..Default::default()
+        }
+    }
+}
+
+/// A grid of [`Tile`]s drawn from a [`TextureAtlas`], see [`TilemapBundle`](crate::TilemapBundle).
+///
+/// The tiles are split into chunks, each of which keeps its tiles in a GPU buffer and is drawn
+/// with a single instanced draw call, so a tilemap needs one draw call per visible chunk instead
+/// of one entity per tile. Changing tiles only writes the changed tiles to the buffer of their
+/// chunk, editing the [`TextureAtlas`] uploads all chunks again. Chunks outside of the view are
+/// culled. The tile at `(0, 0)` is placed at the bottom left, with its bottom left corner at the
+/// origin of the tilemap.
+///
+/// Cloning a tilemap only clones its tiles, the clone spawns its own chunks once it is added to
+/// an entity.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Tilemap {
+    size: UVec2,
+    tile_size: Vec2,
+    chunk_size: UVec2,
+    tiles: Vec<Option<Tile>>,
+    #[reflect(ignore)]
+    dirty_chunks: HashSet<UVec2>,
+    #[reflect(ignore)]
+    dirty_tiles: HashSet<UVec2>,
+    #[reflect(ignore)]
+    chunks: HashMap<UVec2, Entity>,
+}
+
+impl Default for Tilemap {
+    fn default() -> Self {
+        Self::new(UVec2::ZERO, Vec2::ONE)
+    }
+}
+
+impl Clone for Tilemap {
+    fn clone(&self) -> Self {
+        // The chunks belong to the entity of the original tilemap
+        let mut tilemap = Self {
+            size: self.size,
+            tile_size: self.tile_size,
+            chunk_size: self.chunk_size,
+            tiles: self.tiles.clone(),
+            dirty_chunks: HashSet::default(),
+            dirty_tiles: HashSet::default(),
+            chunks: HashMap::default(),
+        };
+        tilemap.mark_all_chunks_dirty();
+        tilemap
+    }
+}
+
+impl Tilemap {
+    /// Creates an empty tilemap of `size` tiles, each `tile_size` units large.
+    pub fn new(size: UVec2, tile_size: Vec2) -> Self {
+        Self {
+            size,
+            tile_size,
+            chunk_size: UVec2::splat(32),
+            tiles: vec![None; (size.x * size.y) as usize],
+            dirty_chunks: HashSet::default(),
+            dirty_tiles: HashSet::default(),
+            chunks: HashMap::default(),
+        }
+    }
+
+    /// Sets the number of tiles per chunk, defaulting to 32 by 32.
+    ///
+    /// Smaller chunks cull more precisely, larger chunks need fewer draw calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the dimensions is `0`.
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: UVec2) -> Self {
+        assert!(chunk_size.cmpgt(UVec2::ZERO).all());
+        self.chunk_size = chunk_size;
+        self.dirty_tiles.clear();
+        self.mark_all_chunks_dirty();
+        self
+    }
+
+    /// Returns the number of tiles in each dimension.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Returns the size of a tile.
+    pub fn tile_size(&self) -> Vec2 {
+        self.tile_size
+    }
+
+    /// Returns the number of tiles per chunk in each dimension.
+    pub fn chunk_size(&self) -> UVec2 {
+        self.chunk_size
+    }
+
+    /// Returns the tile at `position`, or `None` if there is no tile or the position is outside
+    /// of the tilemap.
+    pub fn get(&self, position: UVec2) -> Option<&Tile> {
+        self.tile_index(position)
+            .and_then(|index| self.tiles[index].as_ref())
+    }
+
+    /// Returns a mutable reference to the tile at `position`, marking it for uploading.
+    pub fn get_mut(&mut self, position: UVec2) -> Option<&mut Tile> {
+        let index = self.tile_index(position)?;
+        let tile = self.tiles[index].as_mut()?;
+        self.dirty_tiles.insert(position);
+        Some(tile)
+    }
+
+    /// Sets or removes the tile at `position`, returning the previous tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside of the tilemap.
+    pub fn set(&mut self, position: UVec2, tile: impl Into<Option<Tile>>) -> Option<Tile> {
+        let index = self.tile_index(position).unwrap_or_else(|| {
+            panic!(
+                "Tile position {position} is outside of the tilemap of size {}",
+                self.size
+            )
+        });
+        self.dirty_tiles.insert(position);
+        std::mem::replace(&mut self.tiles[index], tile.into())
+    }
+
+    /// Sets all tiles to `tile`.
+    pub fn fill(&mut self, tile: impl Into<Option<Tile>>) {
+        self.tiles.fill(tile.into());
+        self.dirty_tiles.clear();
+        self.mark_all_chunks_dirty();
+    }
+
+    /// Returns the position of the tile containing `point`, given relative to the origin of the
+    /// tilemap, or `None` if it's outside of the tilemap.
+    pub fn tile_position(&self, point: Vec2) -> Option<UVec2> {
+        let position = (point / self.tile_size).floor();
+        if position.cmplt(Vec2::ZERO).any() || position.cmpge(self.size.as_vec2()).any() {
+            return None;
+        }
+        Some(position.as_uvec2())
+    }
+
+    /// Returns the chunk entities, which are spawned as children of the tilemap.
+    pub fn chunk_entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.chunks.values().copied()
+    }
+
+    fn tile_index(&self, position: UVec2) -> Option<usize> {
+        if position.cmpge(self.size).any() {
+            return None;
+        }
+        Some((position.y * self.size.x + position.x) as usize)
+    }
+
+    fn mark_all_chunks_dirty(&mut self) {
+        let chunk_count = (self.size + self.chunk_size - UVec2::ONE) / self.chunk_size;
+        for y in 0..chunk_count.y {
+            for x in 0..chunk_count.x {
+                self.dirty_chunks.insert(UVec2::new(x, y));
+            }
+        }
+    }
+
+    fn chunk_is_empty(&self, chunk: UVec2) -> bool {
+        let min = chunk * self.chunk_size;
+        let max = (min + self.chunk_size).min(self.size);
+        (min.y..max.y).all(|y| (min.x..max.x).all(|x| self.get(UVec2::new(x, y)).is_none()))
+    }
+
+    /// Returns the instance of the tile at `position` in its chunk, which is zeroed if there is
+    /// no tile to draw.
+    fn tile_instance(&self, position: UVec2, texture_atlas: &TextureAtlas) -> TileInstance {
+        let Some(tile) = self.get(position) else {
+            return TileInstance::default();
+        };
+        let Some(rect) = texture_atlas.textures.get(tile.index) else {
+            return TileInstance::default();
+        };
+
+        let bottom_left = (position % self.chunk_size).as_vec2() * self.tile_size;
+        // Texture coordinates grow downwards.
+        let mut uv_min = rect.min / texture_atlas.size;
+        let mut uv_max = rect.max / texture_atlas.size;
+        if tile.flip_x {
+            std::mem::swap(&mut uv_min.x, &mut uv_max.x);
+        }
+        if tile.flip_y {
+            std::mem::swap(&mut uv_min.y, &mut uv_max.y);
+        }
+        TileInstance {
+            rect: [
+                bottom_left.x,
+                bottom_left.y,
+                self.tile_size.x,
+                self.tile_size.y,
+            ],
+            uv: [uv_min.x, uv_max.y, uv_max.x, uv_min.y],
+            color: tile.color.as_linear_rgba_f32(),
+        }
+    }
+
+    /// Returns the instances of the tiles at `offset..=last` in a chunk, with instances stored
+    /// row by row.
+    fn chunk_instances(
+        &self,
+        chunk: UVec2,
+        offset: u32,
+        last: u32,
+        texture_atlas: &TextureAtlas,
+    ) -> Vec<TileInstance> {
+        let min = chunk * self.chunk_size;
+        (offset..=last)
+            .map(|index| {
+                let position =
+                    min + UVec2::new(index % self.chunk_size.x, index / self.chunk_size.x);
+                self.tile_instance(position, texture_atlas)
+            })
+            .collect()
+    }
+}
+
+/// A chunk of a [`Tilemap`], spawned as a child of the tilemap.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct TilemapChunk {
+    /// The position of the chunk, in chunks.
+    pub position: UVec2,
+}
+
+/// A tile as it is stored in the GPU buffer of its chunk, see `tilemap.wgsl`.
+///
+/// A zeroed instance covers no area, so it is used for positions without a tile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub(crate) struct TileInstance {
+    /// The bottom left corner and size of the tile, relative to the chunk
+    pub rect: [f32; 4],
+    /// The texture coordinates of the bottom left and top right corners of the tile
+    pub uv: [f32; 4],
+    /// The tint color, in linear space
+    pub color: [f32; 4],
+}
+
+/// Tile instances to write to the GPU buffer of a chunk, starting at instance `offset`.
+#[derive(Debug, Clone)]
+pub(crate) struct TileWrite {
+    pub offset: u32,
+    pub tiles: Vec<TileInstance>,
+}
+
+/// The GPU side state of a [`TilemapChunk`], holding the tiles that were changed this frame.
+///
+/// The render world keeps a buffer with an instance for each tile position of the chunk and only
+/// writes the changed ranges to it.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TilemapChunkTiles {
+    texture: Handle<Image>,
+    len: u32,
+    writes: Vec<TileWrite>,
+}
+
+/// System that writes the changed tiles of each [`Tilemap`] to its chunks, spawning and
+/// despawning chunk entities as needed. All tiles are written again when the [`TextureAtlas`] of
+/// the tilemap changed.
+pub fn update_tilemap_chunks(
+    mut commands: Commands,
+    mut tilemaps: Query<(Entity, &mut Tilemap, Ref<Handle<TextureAtlas>>)>,
+    mut chunks: Query<&mut TilemapChunkTiles>,
+    mut texture_atlas_events: EventReader<AssetEvent<TextureAtlas>>,
+    texture_atlases: Res<Assets<TextureAtlas>>,
+) {
+    // The writes of the last frame have been extracted already.
+    for mut chunk_tiles in &mut chunks {
+        if !chunk_tiles.writes.is_empty() {
+            chunk_tiles.writes.clear();
+        }
+    }
+
+    let modified_texture_atlases: HashSet<_> = texture_atlas_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } => Some(handle.id()),
+            _ => None,
+        })
+        .collect();
+    for (entity, mut tilemap, texture_atlas_handle) in &mut tilemaps {
+        let Some(texture_atlas) = texture_atlases.get(&texture_atlas_handle) else {
+            continue;
+        };
+        // Uploading the tiles is not a change of the tilemap itself.
+        let tilemap = tilemap.bypass_change_detection();
+        if texture_atlas_handle.is_changed()
+            || modified_texture_atlases.contains(&texture_atlas_handle.id())
+        {
+            tilemap.mark_all_chunks_dirty();
+        }
+
+        // Group the changed tiles by chunk, as indices of their instance.
+        let mut dirty_tiles: HashMap<UVec2, Vec<u32>> = HashMap::default();
+        for position in std::mem::take(&mut tilemap.dirty_tiles) {
+            let chunk = position / tilemap.chunk_size;
+            if tilemap.dirty_chunks.contains(&chunk) {
+                continue;
+            }
+            let local = position % tilemap.chunk_size;
+            dirty_tiles
+                .entry(chunk)
+                .or_default()
+                .push(local.y * tilemap.chunk_size.x + local.x);
+        }
+        let len = tilemap.chunk_size.x * tilemap.chunk_size.y;
+        let dirty_chunks = std::mem::take(&mut tilemap.dirty_chunks);
+        let changed_chunks = dirty_chunks.iter().map(|chunk| (*chunk, None)).chain(
+            dirty_tiles
+                .into_iter()
+                .map(|(chunk, tiles)| (chunk, Some(tiles))),
+        );
+
+        for (chunk, tiles) in changed_chunks {
+            if tilemap.chunk_is_empty(chunk) {
+                if let Some(chunk_entity) = tilemap.chunks.remove(&chunk) {
+                    commands.entity(chunk_entity).despawn_recursive();
+                }
+                continue;
+            }
+
+            let chunk_entity = tilemap.chunks.get(&chunk).copied();
+            let writes = match (tiles, chunk_entity) {
+                (Some(mut tiles), Some(_)) => {
+                    // Merge consecutive tiles into a single write.
+                    tiles.sort_unstable();
+                    let mut writes = Vec::new();
+                    let mut tiles = tiles.into_iter().peekable();
+                    while let Some(offset) = tiles.next() {
+                        let mut last = offset;
+                        while tiles.next_if_eq(&(last + 1)).is_some() {
+                            last += 1;
+                        }
+                        writes.push(TileWrite {
+                            offset,
+                            tiles: tilemap.chunk_instances(chunk, offset, last, texture_atlas),
+                        });
+                    }
+                    writes
+                }
+                _ => vec![TileWrite {
+                    offset: 0,
+                    tiles: tilemap.chunk_instances(chunk, 0, len - 1, texture_atlas),
+                }],
+            };
+
+            if let Some(chunk_entity) = chunk_entity {
+                if let Ok(mut chunk_tiles) = chunks.get_mut(chunk_entity) {
+                    chunk_tiles.texture = texture_atlas.texture.clone();
+                    chunk_tiles.writes.extend(writes);
+                }
+                continue;
+            }
+
+            let chunk_extent = tilemap.chunk_size.as_vec2() * tilemap.tile_size;
+            let chunk_entity = commands
+                .spawn((
+                    SpatialBundle::from_transform(Transform::from_translation(
+                        (chunk.as_vec2() * chunk_extent).extend(0.0),
+                    )),
+                    Aabb::from_min_max(Vec3::ZERO, chunk_extent.extend(0.0)),
+                    TilemapChunk { position: chunk },
+                    TilemapChunkTiles {
+                        texture: texture_atlas.texture.clone(),
+                        len,
+                        writes,
+                    },
+                ))
+                .id();
+            commands.entity(entity).add_child(chunk_entity);
+            tilemap.chunks.insert(chunk, chunk_entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_math::Rect;
+
+    fn setup() -> (App, Handle<TextureAtlas>) {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>()
+            .add_system(update_tilemap_chunks);
+
+        let mut texture_atlas = TextureAtlas::new_empty(Handle::default(), Vec2::new(32., 16.));
+        texture_atlas.add_texture(Rect::new(0., 0., 16., 16.));
+        texture_atlas.add_texture(Rect::new(16., 0., 32., 16.));
+        let texture_atlas = app
+            .world
+            .resource_mut::<Assets<TextureAtlas>>()
+            .add(texture_atlas);
+        (app, texture_atlas)
+    }
+
+    /// Returns the tile writes of a chunk in the last frame, as offsets and instances.
+    fn writes(app: &App, tilemap: Entity, chunk: UVec2) -> Vec<(u32, Vec<TileInstance>)> {
+        let chunk_entity = app.world.get::<Tilemap>(tilemap).unwrap().chunks[&chunk];
+        let chunk_tiles = app.world.get::<TilemapChunkTiles>(chunk_entity).unwrap();
+        chunk_tiles
+            .writes
+            .iter()
+            .map(|write| (write.offset, write.tiles.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn only_changed_tiles_are_written() {
+        let (mut app, texture_atlas) = setup();
+
+        let mut tilemap =
+            Tilemap::new(UVec2::new(4, 3), Vec2::splat(8.)).with_chunk_size(UVec2::splat(2));
+        tilemap.fill(Tile::new(0));
+        tilemap.set(UVec2::new(3, 2), None);
+        let tilemap = app.world.spawn((tilemap, texture_atlas)).id();
+        app.update();
+
+        assert_eq!(
+            app.world
+                .get::<Tilemap>(tilemap)
+                .unwrap()
+                .chunk_entities()
+                .count(),
+            4
+        );
+        // New chunks are written completely.
+        let chunk_writes = writes(&app, tilemap, UVec2::new(1, 1));
+        assert_eq!(chunk_writes.len(), 1);
+        let (offset, tiles) = &chunk_writes[0];
+        assert_eq!(*offset, 0);
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(tiles[0].rect, [0., 0., 8., 8.]);
+        assert_eq!(tiles[0].uv, [0., 1., 0.5, 0.]);
+        assert_eq!(tiles[0].color, Color::WHITE.as_linear_rgba_f32());
+        // There are no tiles at (3, 2) and above the tilemap.
+        assert!(tiles[1..]
+            .iter()
+            .all(|tile| *tile == TileInstance::default()));
+
+        let mut tilemap_mut = app.world.get_mut::<Tilemap>(tilemap).unwrap();
+        tilemap_mut.get_mut(UVec2::new(0, 0)).unwrap().index = 1;
+        tilemap_mut.set(UVec2::new(1, 0), Tile::new(1));
+        tilemap_mut.get_mut(UVec2::new(1, 1)).unwrap().flip_x = true;
+        tilemap_mut.set(UVec2::new(2, 2), None);
+        app.update();
+
+        assert_eq!(app.world.get::<Tilemap>(tilemap).unwrap().chunks.len(), 3);
+        assert!(!app
+            .world
+            .get::<Tilemap>(tilemap)
+            .unwrap()
+            .chunks
+            .contains_key(&UVec2::new(1, 1)));
+        // Adjacent tiles are merged into a single write.
+        let chunk_writes = writes(&app, tilemap, UVec2::ZERO);
+        assert_eq!(
+            chunk_writes
+                .iter()
+                .map(|(offset, tiles)| (*offset, tiles.len()))
+                .collect::<Vec<_>>(),
+            [(0, 2), (3, 1)]
+        );
+        assert_eq!(chunk_writes[0].1[0].uv, [0.5, 1., 1., 0.]);
+        assert_eq!(chunk_writes[0].1[1].rect, [8., 0., 8., 8.]);
+        assert_eq!(chunk_writes[1].1[0].uv, [0.5, 1., 0., 0.]);
+        assert!(writes(&app, tilemap, UVec2::new(1, 0)).is_empty());
+
+        app.update();
+        assert!(writes(&app, tilemap, UVec2::ZERO).is_empty());
+    }
+
+    #[test]
+    fn all_tiles_are_written_when_the_atlas_is_edited() {
+        let (mut app, texture_atlas) = setup();
+        let mut tilemap = Tilemap::new(UVec2::new(2, 2), Vec2::splat(8.));
+        tilemap.fill(Tile::new(1));
+        let tilemap = app.world.spawn((tilemap, texture_atlas.clone())).id();
+        app.update();
+        assert_eq!(
+            writes(&app, tilemap, UVec2::ZERO)[0].1[0].uv[..2],
+            [0.5, 1.0]
+        );
+
+        app.world
+            .resource_mut::<Assets<TextureAtlas>>()
+            .get_mut(&texture_atlas)
+            .unwrap()
+            .textures[1] = Rect::new(8., 0., 24., 16.);
+        // the asset event is sent at the end of the frame
+        app.update();
+        app.update();
+        let chunk_writes = writes(&app, tilemap, UVec2::ZERO);
+        assert_eq!(chunk_writes.len(), 1);
+        assert_eq!(chunk_writes[0].0, 0);
+        assert_eq!(chunk_writes[0].1.len(), 32 * 32);
+        assert_eq!(chunk_writes[0].1[0].uv[..2], [0.25, 1.0]);
+    }
+
+    #[test]
+    fn cloned_tilemaps_spawn_their_own_chunks() {
+        let (mut app, texture_atlas) = setup();
+        let mut tilemap = Tilemap::new(UVec2::new(2, 2), Vec2::splat(8.));
+        tilemap.fill(Tile::new(0));
+        let original = app.world.spawn((tilemap, texture_atlas.clone())).id();
+        app.update();
+
+        let mut tilemap = app.world.get::<Tilemap>(original).unwrap().clone();
+        assert_eq!(tilemap.chunk_entities().count(), 0);
+        tilemap.set(UVec2::ZERO, Tile::new(1));
+        let clone = app.world.spawn((tilemap, texture_atlas)).id();
+        app.update();
+
+        let chunk_entities = |entity: Entity| -> Vec<Entity> {
+            app.world
+                .get::<Tilemap>(entity)
+                .unwrap()
+                .chunk_entities()
+                .collect()
+        };
+        assert_eq!(chunk_entities(original).len(), 1);
+        assert_eq!(chunk_entities(clone).len(), 1);
+        assert_ne!(chunk_entities(original), chunk_entities(clone));
+        assert!(writes(&app, original, UVec2::ZERO).is_empty());
+        assert_eq!(writes(&app, clone, UVec2::ZERO)[0].1[0].uv[..2], [0.5, 1.0]);
+    }
+}
@@ -0,0 +1,375 @@
+use super::{TileInstance, TileWrite, TilemapChunkTiles};
+use crate::{
+    Mesh2dPipeline, Mesh2dPipelineKey, Mesh2dUniform, SetMesh2dBindGroup, SetMesh2dViewBindGroup,
+    SpriteAssetEvents,
+};
+use bevy_asset::{AssetEvent, Handle, HandleUntyped};
+use bevy_core_pipeline::{
+    core_2d::Transparent2d,
+    tonemapping::{DebandDither, Tonemapping},
+};
+use bevy_ecs::{
+    prelude::*,
+    query::ROQueryItem,
+    system::{lifetimeless::*, SystemParamItem},
+};
+use bevy_reflect::TypeUuid;
+use bevy_render::{
+    mesh::{Mesh, MeshVertexBufferLayout},
+    render_asset::RenderAssets,
+    render_phase::{
+        DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult, RenderPhase, SetItemPipeline,
+        TrackedRenderPass,
+    },
+    render_resource::*,
+    renderer::{RenderDevice, RenderQueue},
+    texture::Image,
+    view::{ComputedVisibility, ExtractedView, Msaa, VisibleEntities},
+    Extract,
+};
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::{FloatOrd, HashMap};
+
+pub const TILEMAP_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 9052612489154322177);
+
+/// A visible chunk of a tilemap in the render world.
+#[derive(Component)]
+pub struct ExtractedTilemapChunk {
+    texture: Handle<Image>,
+    len: u32,
+}
+
+/// The tile instance buffers of all tilemap chunks, which persist across frames so only the
+/// changed tiles need to be written.
+#[derive(Resource, Default)]
+pub struct TilemapChunkBuffers {
+    buffers: HashMap<Entity, Buffer>,
+    writes: Vec<(Entity, u32, Vec<TileWrite>)>,
+}
+
+#[derive(Resource, Default)]
+pub struct TilemapImageBindGroups {
+    values: HashMap<Handle<Image>, BindGroup>,
+}
+
+#[derive(Resource)]
+pub struct TilemapPipeline {
+    mesh2d_pipeline: Mesh2dPipeline,
+    texture_layout: BindGroupLayout,
+    // Tiles are built from instances only, the "mesh" has no vertex attributes.
+    mesh_layout: MeshVertexBufferLayout,
+}
+
+impl FromWorld for TilemapPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let texture_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        multisampled: false,
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+            label: Some("tilemap_texture_layout"),
+        });
+
+        TilemapPipeline {
+            mesh2d_pipeline: world.resource::<Mesh2dPipeline>().clone(),
+            texture_layout,
+            mesh_layout: Mesh::new(PrimitiveTopology::TriangleList).get_mesh_vertex_buffer_layout(),
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for TilemapPipeline {
+    type Key = Mesh2dPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut descriptor = self
+            .mesh2d_pipeline
+            .specialize(key, &self.mesh_layout)
+            .expect("the tilemap mesh layout requires no vertex attributes");
+        descriptor.vertex.shader = TILEMAP_SHADER_HANDLE.typed::<Shader>();
+        descriptor.vertex.buffers = vec![VertexBufferLayout::from_vertex_formats(
+            VertexStepMode::Instance,
+            [
+                // rect
+                VertexFormat::Float32x4,
+                // uv
+                VertexFormat::Float32x4,
+                // color
+                VertexFormat::Float32x4,
+            ],
+        )];
+        let fragment = descriptor.fragment.as_mut().unwrap();
+        fragment.shader = TILEMAP_SHADER_HANDLE.typed::<Shader>();
+        descriptor.layout = vec![
+            self.mesh2d_pipeline.view_layout.clone(),
+            self.texture_layout.clone(),
+            self.mesh2d_pipeline.mesh_layout.clone(),
+        ];
+        descriptor.label = Some("tilemap_pipeline".into());
+        descriptor
+    }
+}
+
+pub fn extract_tilemap_chunks(
+    mut commands: Commands,
+    mut buffers: ResMut<TilemapChunkBuffers>,
+    mut previous_len: Local<usize>,
+    chunks: Extract<
+        Query<(
+            Entity,
+            &TilemapChunkTiles,
+            &ComputedVisibility,
+            &GlobalTransform,
+        )>,
+    >,
+    mut removed_chunks: Extract<RemovedComponents<TilemapChunkTiles>>,
+) {
+    for entity in removed_chunks.iter() {
+        buffers.buffers.remove(&entity);
+    }
+
+    let mut values = Vec::with_capacity(*previous_len);
+    for (entity, chunk_tiles, computed_visibility, transform) in &chunks {
+        // The buffers of hidden chunks need to be kept up to date as well.
+        if !chunk_tiles.writes.is_empty() {
+            buffers
+                .writes
+                .push((entity, chunk_tiles.len, chunk_tiles.writes.clone()));
+        }
+        if !computed_visibility.is_visible() {
+            continue;
+        }
+        let transform = transform.compute_matrix();
+        values.push((
+            entity,
+            (
+                ExtractedTilemapChunk {
+                    texture: chunk_tiles.texture.clone_weak(),
+                    len: chunk_tiles.len,
+                },
+                Mesh2dUniform {
+                    flags: 0,
+                    transform,
+                    inverse_transpose_model: transform.inverse().transpose(),
+                },
+            ),
+        ));
+    }
+    *previous_len = values.len();
+    commands.insert_or_spawn_batch(values);
+}
+
+/// Writes the changed tiles to the buffers of their chunks, creating the buffers of new chunks.
+pub fn prepare_tilemap_chunks(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut buffers: ResMut<TilemapChunkBuffers>,
+) {
+    let TilemapChunkBuffers { buffers, writes } = &mut *buffers;
+    let stride = std::mem::size_of::<TileInstance>() as u64;
+    for (entity, len, writes) in writes.drain(..) {
+        let size = len as u64 * stride;
+        let buffer = buffers
+            .entry(entity)
+            .and_modify(|buffer| {
+                if buffer.size() != size {
+                    *buffer = create_tile_buffer(&render_device, size);
+                }
+            })
+            .or_insert_with(|| create_tile_buffer(&render_device, size));
+        for write in writes {
+            render_queue.write_buffer(
+                buffer,
+                write.offset as u64 * stride,
+                bytemuck::cast_slice(&write.tiles),
+            );
+        }
+    }
+}
+
+fn create_tile_buffer(render_device: &RenderDevice, size: u64) -> Buffer {
+    render_device.create_buffer(&BufferDescriptor {
+        label: Some("tilemap_chunk_buffer"),
+        size,
+        usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn queue_tilemap_chunks(
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    render_device: Res<RenderDevice>,
+    tilemap_pipeline: Res<TilemapPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TilemapPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    gpu_images: Res<RenderAssets<Image>>,
+    buffers: Res<TilemapChunkBuffers>,
+    mut image_bind_groups: ResMut<TilemapImageBindGroups>,
+    events: Res<SpriteAssetEvents>,
+    chunks: Query<(&ExtractedTilemapChunk, &Mesh2dUniform)>,
+    mut views: Query<(
+        &ExtractedView,
+        &VisibleEntities,
+        Option<&Tonemapping>,
+        Option<&DebandDither>,
+        &mut RenderPhase<Transparent2d>,
+    )>,
+) {
+    // If an image has changed, the GpuImage has (probably) changed
+    for event in &events.images {
+        match event {
+            AssetEvent::Created { .. }
+            | AssetEvent::Evicted { .. }
+            | AssetEvent::DependencyModified { .. } => None,
+            AssetEvent::Modified { handle } | AssetEvent::Removed { handle } => {
+                image_bind_groups.values.remove(handle)
+            }
+        };
+    }
+
+    if chunks.is_empty() {
+        return;
+    }
+
+    let draw_tilemap_chunk = draw_functions.read().id::<DrawTilemapChunk>();
+    for (view, visible_entities, tonemapping, dither, mut transparent_phase) in &mut views {
+        let mut view_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+            | Mesh2dPipelineKey::from_hdr(view.hdr)
+            | Mesh2dPipelineKey::from_primitive_topology(PrimitiveTopology::TriangleList);
+
+        if !view.hdr {
+            if let Some(tonemapping) = tonemapping {
+                view_key |= Mesh2dPipelineKey::TONEMAP_IN_SHADER;
+                view_key |= match tonemapping {
+                    Tonemapping::None => Mesh2dPipelineKey::TONEMAP_METHOD_NONE,
+                    Tonemapping::Reinhard => Mesh2dPipelineKey::TONEMAP_METHOD_REINHARD,
+                    Tonemapping::ReinhardLuminance => {
+                        Mesh2dPipelineKey::TONEMAP_METHOD_REINHARD_LUMINANCE
+                    }
+                    Tonemapping::AcesFitted => Mesh2dPipelineKey::TONEMAP_METHOD_ACES_FITTED,
+                    Tonemapping::AgX => Mesh2dPipelineKey::TONEMAP_METHOD_AGX,
+                    Tonemapping::SomewhatBoringDisplayTransform => {
+                        Mesh2dPipelineKey::TONEMAP_METHOD_SOMEWHAT_BORING_DISPLAY_TRANSFORM
+                    }
+                    Tonemapping::TonyMcMapface => Mesh2dPipelineKey::TONEMAP_METHOD_TONY_MC_MAPFACE,
+                    Tonemapping::BlenderFilmic => Mesh2dPipelineKey::TONEMAP_METHOD_BLENDER_FILMIC,
+                };
+            }
+            if let Some(DebandDither::Enabled) = dither {
+                view_key |= Mesh2dPipelineKey::DEBAND_DITHER;
+            }
+        }
+        let pipeline = pipelines.specialize(&pipeline_cache, &tilemap_pipeline, view_key);
+
+        for visible_entity in &visible_entities.entities {
+            let Ok((chunk, mesh2d_uniform)) = chunks.get(*visible_entity) else {
+                continue;
+            };
+            // Skip chunks whose texture is not ready yet
+            let Some(gpu_image) = gpu_images.get(&chunk.texture) else {
+                continue;
+            };
+            if !buffers.buffers.contains_key(visible_entity) {
+                continue;
+            }
+            image_bind_groups
+                .values
+                .entry(chunk.texture.clone_weak())
+                .or_insert_with(|| {
+                    render_device.create_bind_group(&BindGroupDescriptor {
+                        entries: &[
+                            BindGroupEntry {
+                                binding: 0,
+                                resource: BindingResource::TextureView(&gpu_image.texture_view),
+                            },
+                            BindGroupEntry {
+                                binding: 1,
+                                resource: BindingResource::Sampler(&gpu_image.sampler),
+                            },
+                        ],
+                        label: Some("tilemap_texture_bind_group"),
+                        layout: &tilemap_pipeline.texture_layout,
+                    })
+                });
+
+            transparent_phase.add(Transparent2d {
+                entity: *visible_entity,
+                draw_function: draw_tilemap_chunk,
+                pipeline,
+                // Chunks are sorted like 2d meshes, by the z coordinate of their translation.
+                sort_key: FloatOrd(mesh2d_uniform.transform.w_axis.z),
+                batch_range: None,
+            });
+        }
+    }
+}
+
+pub type DrawTilemapChunk = (
+    SetItemPipeline,
+    SetMesh2dViewBindGroup<0>,
+    SetTilemapTextureBindGroup<1>,
+    SetMesh2dBindGroup<2>,
+    DrawTilemapChunkTiles,
+);
+
+pub struct SetTilemapTextureBindGroup<const I: usize>;
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetTilemapTextureBindGroup<I> {
+    type Param = SRes<TilemapImageBindGroups>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = Read<ExtractedTilemapChunk>;
+
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        chunk: ROQueryItem<'w, Self::ItemWorldQuery>,
+        image_bind_groups: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(bind_group) = image_bind_groups.into_inner().values.get(&chunk.texture) else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(I, bind_group, &[]);
+        RenderCommandResult::Success
+    }
+}
+
+pub struct DrawTilemapChunkTiles;
+impl<P: PhaseItem> RenderCommand<P> for DrawTilemapChunkTiles {
+    type Param = SRes<TilemapChunkBuffers>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = Read<ExtractedTilemapChunk>;
+
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        chunk: ROQueryItem<'w, Self::ItemWorldQuery>,
+        buffers: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(buffer) = buffers.into_inner().buffers.get(&item.entity()) else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_vertex_buffer(0, buffer.slice(..));
+        // Each tile is a quad of two triangles, built in the vertex shader.
+        pass.draw(0..6, 0..chunk.len);
+        RenderCommandResult::Success
+    }
+}
@@ -0,0 +1,59 @@
+#import bevy_sprite::mesh2d_view_bindings
+#import bevy_sprite::mesh2d_bindings
+
+// NOTE: Bindings must come before functions that use them!
+#import bevy_sprite::mesh2d_functions
+
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping
+#endif
+
+@group(1) @binding(0)
+var tile_texture: texture_2d<f32>;
+@group(1) @binding(1)
+var tile_sampler: sampler;
+
+// NOTE: This must match `TileInstance` in bevy_sprite/src/tilemap/mod.rs!
+struct TileInstance {
+    // Bottom left corner and size of the tile, relative to the chunk
+    @location(0) rect: vec4<f32>,
+    // Texture coordinates of the bottom left and top right corners of the tile
+    @location(1) uv: vec4<f32>,
+    @location(2) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32, tile: TileInstance) -> VertexOutput {
+    // Two triangles covering the tile, going counter-clockwise from the bottom left corner.
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+
+    var out: VertexOutput;
+    let position = vec4<f32>(tile.rect.xy + corner * tile.rect.zw, 0.0, 1.0);
+    out.clip_position = mesh2d_position_local_to_clip(mesh.model, position);
+    out.uv = mix(tile.uv.xy, tile.uv.zw, corner);
+    out.color = tile.color;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    var color = in.color * textureSample(tile_texture, tile_sampler, in.uv);
+#ifdef TONEMAP_IN_SHADER
+    color = tone_mapping(color);
+#endif
+    return color;
+}
@@ -1,4 +1,6 @@
 use super::TaskPool;
+#[cfg(feature = "trace")]
+use tracing::Instrument;
 
 /// Provides functions for mapping read-only slices across a provided [`TaskPool`].
 pub trait ParallelSlice<T: Sync>: AsRef<[T]> {
@@ -8,6 +10,10 @@ pub trait ParallelSlice<T: Sync>: AsRef<[T]> {
     ///
     /// Returns a `Vec` of the mapped results in the same order as the input.
     ///
+    /// With the `trace` feature, each task is instrumented with a `par_chunk_map` span, which is a
+    /// child of the span entered when calling this function. Like the batches of `Query::par_iter`,
+    /// chunks mapped inside a system thus show up below the span of the system.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -39,7 +45,16 @@ pub trait ParallelSlice<T: Sync>: AsRef<[T]> {
         let f = &f;
         task_pool.scope(|scope| {
             for chunk in slice.chunks(chunk_size) {
-                scope.spawn(async move { f(chunk) });
+                let task = async move { f(chunk) };
+                #[cfg(feature = "trace")]
+                let span = tracing::info_span!(
+                    "par_chunk_map",
+                    item = std::any::type_name::<T>(),
+                    count = chunk.len(),
+                );
+                #[cfg(feature = "trace")]
+                let task = task.instrument(span);
+                scope.spawn(task);
             }
         })
     }
@@ -102,6 +117,9 @@ pub trait ParallelSliceMut<T: Send>: AsMut<[T]> {
     ///
     /// Returns a `Vec` of the mapped results in the same order as the input.
     ///
+    /// With the `trace` feature, each task is instrumented with a `par_chunk_map_mut` span, see
+    /// [`ParallelSlice::par_chunk_map`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -136,7 +154,17 @@ pub trait ParallelSliceMut<T: Send>: AsMut<[T]> {
         let f = &f;
         task_pool.scope(|scope| {
             for chunk in slice.chunks_mut(chunk_size) {
-                scope.spawn(async move { f(chunk) });
+                // The span is created first, as the chunk is moved into the task
+                #[cfg(feature = "trace")]
+                let span = tracing::info_span!(
+                    "par_chunk_map_mut",
+                    item = std::any::type_name::<T>(),
+                    count = chunk.len(),
+                );
+                let task = async move { f(chunk) };
+                #[cfg(feature = "trace")]
+                let task = task.instrument(span);
+                scope.spawn(task);
             }
         })
     }
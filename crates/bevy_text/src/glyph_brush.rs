@@ -5,9 +5,10 @@ use bevy_render::texture::Image;
 use bevy_sprite::TextureAtlas;
 use bevy_utils::tracing::warn;
 use glyph_brush_layout::{
-    BuiltInLineBreaker, FontId, GlyphPositioner, Layout, SectionGeometry, SectionGlyph,
-    SectionText, ToSectionText,
+    BuiltInLineBreaker, FontId, GlyphPositioner, Layout, LineBreak, LineBreaker, SectionGeometry,
+    SectionGlyph, SectionText, ToSectionText,
 };
+use std::ops::Range;
 
 use crate::{
     error::TextError, BreakLineOn, Font, FontAtlasSet, FontAtlasWarning, GlyphAtlasInfo,
@@ -31,19 +32,28 @@ impl Default for GlyphBrush {
 }
 
 impl GlyphBrush {
+    #[allow(clippy::too_many_arguments)]
     pub fn compute_glyphs<S: ToSectionText>(
         &self,
         sections: &[S],
         bounds: Vec2,
         text_alignment: TextAlignment,
         linebreak_behaviour: BreakLineOn,
+        text: &str,
+        section_ranges: &[Range<usize>],
+        keep_together: &[usize],
     ) -> Result<Vec<SectionGlyph>, TextError> {
         let geom = SectionGeometry {
             bounds: (bounds.x, bounds.y),
             ..Default::default()
         };
 
-        let lbb: BuiltInLineBreaker = linebreak_behaviour.into();
+        let lbb = SectionLineBreaker {
+            line_breaker: linebreak_behaviour.into(),
+            text,
+            section_ranges,
+            keep_together,
+        };
 
         let section_glyphs = Layout::default()
             .h_align(text_alignment.into())
@@ -56,6 +66,7 @@ impl GlyphBrush {
     pub fn process_glyphs(
         &self,
         glyphs: Vec<SectionGlyph>,
+        line_indices: &[usize],
         sections: &[SectionText],
         font_atlas_set_storage: &mut Assets<FontAtlasSet>,
         fonts: &Assets<Font>,
@@ -100,7 +111,7 @@ impl GlyphBrush {
         max_y = max_y.floor();
 
         let mut positioned_glyphs = Vec::new();
-        for sg in glyphs {
+        for (sg, &line_index) in glyphs.into_iter().zip(line_indices) {
             let SectionGlyph {
                 section_index: _,
                 byte_index,
@@ -151,6 +162,7 @@ impl GlyphBrush {
                     atlas_info,
                     section_index: sg.section_index,
                     byte_index,
+                    line_index,
                 });
             }
         }
@@ -166,13 +178,63 @@ impl GlyphBrush {
     }
 }
 
+/// Suppresses the soft line breaks inside the sections that are kept together.
+#[derive(Debug, Clone, Copy, Hash)]
+struct SectionLineBreaker<'a> {
+    line_breaker: BuiltInLineBreaker,
+    /// The text the laid out sections are slices of.
+    text: &'a str,
+    /// The byte range of each section in `text`.
+    section_ranges: &'a [Range<usize>],
+    /// The indices of the sections kept together.
+    keep_together: &'a [usize],
+}
+
+impl SectionLineBreaker<'_> {
+    /// Returns the index of the section with the given text.
+    ///
+    /// The line breaker is only given the text of a section, so it is found by its byte range in
+    /// the laid out text. Other texts, like the ones used to probe the breaks at the end of a
+    /// section, don't belong to any section.
+    fn section_index(&self, text: &str) -> Option<usize> {
+        let start = (text.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)?;
+        self.section_ranges
+            .iter()
+            .position(|range| range.start == start && range.len() == text.len())
+    }
+}
+
+impl LineBreaker for SectionLineBreaker<'_> {
+    fn line_breaks<'a>(&self, text: &'a str) -> Box<dyn Iterator<Item = LineBreak> + 'a> {
+        let line_breaks = self.line_breaker.line_breaks(text);
+        if self
+            .section_index(text)
+            .map_or(false, |index| self.keep_together.contains(&index))
+        {
+            Box::new(line_breaks.filter(move |line_break| {
+                matches!(line_break, LineBreak::Hard(_)) || line_break.offset() == text.len()
+            }))
+        } else {
+            line_breaks
+        }
+    }
+}
+
+/// A glyph of a laid out [`Text`](crate::Text).
 #[derive(Debug, Clone)]
 pub struct PositionedGlyph {
+    /// The center of the glyph, in the space described by
+    /// [`TextLayoutInfo`](crate::TextLayoutInfo).
     pub position: Vec2,
+    /// The size of the glyph, in physical pixels.
     pub size: Vec2,
     pub atlas_info: GlyphAtlasInfo,
+    /// The index of the [`TextSection`](crate::TextSection) this glyph belongs to.
     pub section_index: usize,
+    /// The index of the first byte of the character of this glyph in the value of its section.
     pub byte_index: usize,
+    /// The index of the line this glyph is on.
+    pub line_index: usize,
 }
 
 #[cfg(feature = "subpixel_glyph_atlas")]
@@ -20,7 +20,10 @@ pub use text2d::*;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Font, Text, Text2dBundle, TextAlignment, TextError, TextSection, TextStyle};
+    pub use crate::{
+        Font, SectionLineBreak, SectionVerticalAlign, Text, Text2dBundle, TextAlignment, TextError,
+        TextSection, TextStyle,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -75,6 +78,8 @@ impl Plugin for TextPlugin {
             .register_type::<TextStyle>()
             .register_type::<Text>()
             .register_type::<TextAlignment>()
+            .register_type::<SectionLineBreak>()
+            .register_type::<SectionVerticalAlign>()
             .init_asset_loader::<FontLoader>()
             .init_resource::<TextSettings>()
             .init_resource::<FontAtlasWarning>()
@@ -1,17 +1,18 @@
-use ab_glyph::{PxScale, ScaleFont};
+use ab_glyph::{FontArc, PxScale, PxScaleFont, ScaleFont};
 use bevy_asset::{Assets, Handle, HandleId};
 use bevy_ecs::component::Component;
 use bevy_ecs::system::Resource;
-use bevy_math::Vec2;
+use bevy_math::{Rect, Vec2};
 use bevy_render::texture::Image;
 use bevy_sprite::TextureAtlas;
 use bevy_utils::HashMap;
 
-use glyph_brush_layout::{FontId, SectionText};
+use glyph_brush_layout::{FontId, SectionGlyph, SectionText};
 
 use crate::{
     error::TextError, glyph_brush::GlyphBrush, scale_value, BreakLineOn, Font, FontAtlasSet,
-    FontAtlasWarning, PositionedGlyph, TextAlignment, TextSection, TextSettings, YAxisOrientation,
+    FontAtlasWarning, PositionedGlyph, SectionLineBreak, SectionVerticalAlign, TextAlignment,
+    TextSection, TextSettings, YAxisOrientation,
 };
 
 #[derive(Default, Resource)]
@@ -23,12 +24,52 @@ pub struct TextPipeline {
 /// Render information for a corresponding [`Text`](crate::Text) component.
 ///
 ///  Contains scaled glyphs and their size. Generated via [`TextPipeline::queue_text`].
+///
+/// The layout can be used to hit-test the text or to place other content like inline icons next
+/// to sections. All positions and sizes are in physical pixels, relative to the left edge and,
+/// depending on the [`YAxisOrientation`], the top or bottom edge of the text.
 #[derive(Component, Clone, Default, Debug)]
 pub struct TextLayoutInfo {
+    /// The glyphs of the text, in the order of their sections. Whitespace has no glyphs.
     pub glyphs: Vec<PositionedGlyph>,
+    /// The size of the laid out text.
     pub size: Vec2,
 }
 
+impl TextLayoutInfo {
+    /// Returns the rectangle covered by a glyph.
+    pub fn glyph_rect(glyph: &PositionedGlyph) -> Rect {
+        Rect::from_center_size(glyph.position, glyph.size)
+    }
+
+    /// Returns the glyph covering the given position, if any.
+    pub fn glyph_at(&self, position: Vec2) -> Option<&PositionedGlyph> {
+        self.glyphs
+            .iter()
+            .find(|glyph| Self::glyph_rect(glyph).contains(position))
+    }
+
+    /// Returns the rectangles covered by the glyphs of a section, one for each line the section
+    /// is on.
+    pub fn section_rects(&self, section_index: usize) -> Vec<Rect> {
+        let mut rects: Vec<(usize, Rect)> = Vec::new();
+        for glyph in self
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.section_index == section_index)
+        {
+            let rect = Self::glyph_rect(glyph);
+            match rects.last_mut() {
+                Some((line_index, line_rect)) if *line_index == glyph.line_index => {
+                    *line_rect = line_rect.union(rect);
+                }
+                _ => rects.push((glyph.line_index, rect)),
+            }
+        }
+        rects.into_iter().map(|(_, rect)| rect).collect()
+    }
+}
+
 impl TextPipeline {
     pub fn get_or_insert_font_id(&mut self, handle: &Handle<Font>, font: &Font) -> FontId {
         let brush = &mut self.brush;
@@ -54,10 +95,33 @@ impl TextPipeline {
         font_atlas_warning: &mut FontAtlasWarning,
         y_axis_orientation: YAxisOrientation,
     ) -> Result<TextLayoutInfo, TextError> {
+        // Sections are laid out as slices of one text, sections starting on a new line behind an
+        // extra line break
+        let mut text = String::new();
+        let mut section_ranges = Vec::with_capacity(sections.len());
+        for (index, section) in sections.iter().enumerate() {
+            let start = text.len();
+            if index > 0
+                && section.line_break == SectionLineBreak::NewLine
+                && !sections[index - 1].value.ends_with('\n')
+            {
+                text.push('\n');
+            }
+            text.push_str(&section.value);
+            section_ranges.push(start..text.len());
+        }
+        let keep_together = sections
+            .iter()
+            .enumerate()
+            .filter(|(_, section)| section.line_break == SectionLineBreak::KeepTogether)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
         let mut scaled_fonts = Vec::new();
-        let sections = sections
+        let section_texts = sections
             .iter()
-            .map(|section| {
+            .zip(&section_ranges)
+            .map(|(section, range)| {
                 let font = fonts
                     .get(&section.style.font)
                     .ok_or(TextError::NoSuchFont)?;
@@ -69,21 +133,35 @@ impl TextPipeline {
                 let section = SectionText {
                     font_id,
                     scale: PxScale::from(font_size),
-                    text: &section.value,
+                    text: &text[range.clone()],
                 };
 
                 Ok(section)
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let section_glyphs =
-            self.brush
-                .compute_glyphs(&sections, bounds, text_alignment, linebreak_behaviour)?;
+        let mut section_glyphs = self.brush.compute_glyphs(
+            &section_texts,
+            bounds,
+            text_alignment,
+            linebreak_behaviour,
+            &text,
+            &section_ranges,
+            &keep_together,
+        )?;
 
         if section_glyphs.is_empty() {
             return Ok(TextLayoutInfo::default());
         }
 
+        // Point the byte indices into the section values instead of the laid out texts
+        for sg in &mut section_glyphs {
+            let range = &section_ranges[sg.section_index];
+            sg.byte_index -= range.len() - sections[sg.section_index].value.len();
+        }
+
+        let line_indices = align_sections(&mut section_glyphs, sections, &scaled_fonts);
+
         let mut min_x: f32 = std::f32::MAX;
         let mut min_y: f32 = std::f32::MAX;
         let mut max_x: f32 = std::f32::MIN;
@@ -102,7 +180,8 @@ impl TextPipeline {
 
         let glyphs = self.brush.process_glyphs(
             section_glyphs,
-            &sections,
+            &line_indices,
+            &section_texts,
             font_atlas_set_storage,
             fonts,
             texture_atlases,
@@ -115,3 +194,182 @@ impl TextPipeline {
         Ok(TextLayoutInfo { glyphs, size })
     }
 }
+
+/// Finds the line of each glyph and moves the glyphs of the sections that aren't aligned to the
+/// baseline of their line.
+fn align_sections(
+    glyphs: &mut [SectionGlyph],
+    sections: &[TextSection],
+    scaled_fonts: &[PxScaleFont<&FontArc>],
+) -> Vec<usize> {
+    let mut line_indices = Vec::with_capacity(glyphs.len());
+    let mut line_start = 0;
+    while line_start < glyphs.len() {
+        // All glyphs of a line share its baseline
+        let baseline = glyphs[line_start].glyph.position.y;
+        let line_end = glyphs[line_start..]
+            .iter()
+            .position(|sg| sg.glyph.position.y != baseline)
+            .map_or(glyphs.len(), |len| line_start + len);
+        let line = &mut glyphs[line_start..line_end];
+        let line_index = line_indices.last().map_or(0, |index| index + 1);
+        line_indices.resize(line_end, line_index);
+
+        let (line_ascent, line_descent) = line.iter().fold((f32::MIN, f32::MAX), |(a, d), sg| {
+            let scaled_font = scaled_fonts[sg.section_index];
+            (a.max(scaled_font.ascent()), d.min(scaled_font.descent()))
+        });
+        for sg in line {
+            let scaled_font = scaled_fonts[sg.section_index];
+            let (ascent, descent) = (scaled_font.ascent(), scaled_font.descent());
+            sg.glyph.position.y += match sections[sg.section_index].vertical_align {
+                SectionVerticalAlign::Baseline => 0.0,
+                SectionVerticalAlign::Top => ascent - line_ascent,
+                SectionVerticalAlign::Middle => {
+                    (ascent + descent - line_ascent - line_descent) / 2.0
+                }
+                SectionVerticalAlign::Bottom => descent - line_descent,
+            };
+        }
+        line_start = line_end;
+    }
+    line_indices
+}
+
+#[cfg(test)]
+mod tests {
+    use ab_glyph::{point, Font as _, FontArc, Glyph, GlyphId, PxScale, PxScaleFont, ScaleFont};
+    use bevy_asset::Handle;
+    use bevy_math::{Rect, Vec2};
+    use glyph_brush_layout::{FontId, SectionGlyph, SectionText};
+
+    use super::{align_sections, TextLayoutInfo};
+    use crate::{
+        glyph_brush::GlyphBrush, BreakLineOn, GlyphAtlasInfo, PositionedGlyph,
+        SectionVerticalAlign, TextAlignment, TextSection, TextStyle,
+    };
+
+    fn font() -> FontArc {
+        FontArc::try_from_slice(include_bytes!("../../../assets/fonts/FiraMono-Medium.ttf"))
+            .unwrap()
+    }
+
+    fn section_glyph(section_index: usize, x: f32, y: f32) -> SectionGlyph {
+        SectionGlyph {
+            section_index,
+            byte_index: 0,
+            glyph: Glyph {
+                id: GlyphId(0),
+                scale: PxScale::from(10.0),
+                position: point(x, y),
+            },
+            font_id: FontId(0),
+        }
+    }
+
+    fn positioned_glyph(section_index: usize, line_index: usize, x: f32) -> PositionedGlyph {
+        PositionedGlyph {
+            position: Vec2::new(x, line_index as f32 * 10.0),
+            size: Vec2::splat(2.0),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Handle::default(),
+                glyph_index: 0,
+            },
+            section_index,
+            byte_index: 0,
+            line_index,
+        }
+    }
+
+    #[test]
+    fn align_sections_moves_glyphs_within_their_line() {
+        let font = font();
+        let scaled_fonts: Vec<PxScaleFont<&FontArc>> =
+            vec![font.as_scaled(20.0), font.as_scaled(10.0)];
+        let mut sections = vec![
+            TextSection::new("a\n", TextStyle::default()),
+            TextSection::new("b", TextStyle::default()),
+        ];
+        sections[1].vertical_align = SectionVerticalAlign::Top;
+        let mut glyphs = vec![
+            section_glyph(0, 0.0, 20.0),
+            section_glyph(1, 10.0, 20.0),
+            section_glyph(1, 0.0, 45.0),
+        ];
+
+        let line_indices = align_sections(&mut glyphs, &sections, &scaled_fonts);
+
+        assert_eq!(line_indices, vec![0, 0, 1]);
+        assert_eq!(glyphs[0].glyph.position.y, 20.0);
+        // The smaller section is moved up to the top of the first line, but stays on the
+        // baseline of the second line it has to itself
+        let ascent_difference = scaled_fonts[1].ascent() - scaled_fonts[0].ascent();
+        assert_eq!(glyphs[1].glyph.position.y, 20.0 + ascent_difference);
+        assert_eq!(glyphs[2].glyph.position.y, 45.0);
+    }
+
+    #[test]
+    fn section_rects_are_split_by_line() {
+        let layout = TextLayoutInfo {
+            glyphs: vec![
+                positioned_glyph(0, 0, 1.0),
+                positioned_glyph(1, 0, 5.0),
+                positioned_glyph(1, 0, 9.0),
+                positioned_glyph(1, 1, 1.0),
+            ],
+            size: Vec2::new(10.0, 20.0),
+        };
+
+        assert_eq!(
+            layout.section_rects(1),
+            vec![
+                Rect::new(4.0, -1.0, 10.0, 1.0),
+                Rect::new(0.0, 9.0, 2.0, 11.0)
+            ]
+        );
+        assert_eq!(
+            layout.section_rects(0),
+            vec![Rect::new(0.0, -1.0, 2.0, 1.0)]
+        );
+        assert!(layout.section_rects(2).is_empty());
+    }
+
+    #[test]
+    fn kept_together_sections_are_not_broken() {
+        let mut brush = GlyphBrush::default();
+        let font_id = brush.add_font(Handle::default(), font());
+        let text = "aaa bbb ccc ddd";
+        let section_ranges = [0..8, 8..text.len()];
+        let sections = section_ranges
+            .iter()
+            .map(|range| SectionText {
+                text: &text[range.clone()],
+                scale: PxScale::from(10.0),
+                font_id,
+            })
+            .collect::<Vec<_>>();
+        let line_count = |keep_together: &[usize]| {
+            let glyphs = brush
+                .compute_glyphs(
+                    &sections,
+                    Vec2::new(25.0, f32::MAX),
+                    TextAlignment::Left,
+                    BreakLineOn::WordBoundary,
+                    text,
+                    &section_ranges,
+                    keep_together,
+                )
+                .unwrap();
+            let mut baselines = glyphs
+                .iter()
+                .map(|sg| sg.glyph.position.y)
+                .collect::<Vec<_>>();
+            baselines.dedup();
+            baselines.len()
+        };
+
+        assert_eq!(line_count(&[]), 4);
+        assert_eq!(line_count(&[0]), 3);
+        assert_eq!(line_count(&[0, 1]), 2);
+    }
+}
@@ -112,6 +112,10 @@ impl Text {
 pub struct TextSection {
     pub value: String,
     pub style: TextStyle,
+    /// Where lines may break inside this section.
+    pub line_break: SectionLineBreak,
+    /// How this section is aligned vertically within its line.
+    pub vertical_align: SectionVerticalAlign,
 }
 
 impl TextSection {
@@ -120,6 +124,8 @@ impl TextSection {
         Self {
             value: value.into(),
             style,
+            line_break: SectionLineBreak::Auto,
+            vertical_align: SectionVerticalAlign::Baseline,
         }
     }
 
@@ -128,8 +134,55 @@ impl TextSection {
         Self {
             value: String::new(),
             style,
+            line_break: SectionLineBreak::Auto,
+            vertical_align: SectionVerticalAlign::Baseline,
         }
     }
+
+    /// Returns this [`TextSection`] with a new [`SectionLineBreak`].
+    pub fn with_line_break(mut self, line_break: SectionLineBreak) -> Self {
+        self.line_break = line_break;
+        self
+    }
+
+    /// Returns this [`TextSection`] with a new [`SectionVerticalAlign`].
+    pub fn with_vertical_align(mut self, vertical_align: SectionVerticalAlign) -> Self {
+        self.vertical_align = vertical_align;
+        self
+    }
+}
+
+/// Determines where lines may break inside a [`TextSection`].
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Serialize, Deserialize)]
+pub enum SectionLineBreak {
+    /// Lines break inside the section as determined by the [`BreakLineOn`] of the [`Text`].
+    #[default]
+    Auto,
+    /// Lines never break inside the section, except at explicit newlines.
+    /// Useful to keep names or numbers and their units together.
+    KeepTogether,
+    /// The section always starts on a new line.
+    NewLine,
+}
+
+/// Determines how a [`TextSection`] is aligned vertically within a line that mixes font sizes.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, FromReflect, Serialize, Deserialize,
+)]
+#[reflect(Serialize, Deserialize)]
+pub enum SectionVerticalAlign {
+    /// The baseline of the section is aligned with the baseline of the line.
+    #[default]
+    Baseline,
+    /// The ascent of the section is aligned with the top of the line.
+    Top,
+    /// The section is centered between the top and the bottom of the line.
+    Middle,
+    /// The descent of the section is aligned with the bottom of the line.
+    Bottom,
 }
 
 /// Describes horizontal alignment preference for positioning & bounds.
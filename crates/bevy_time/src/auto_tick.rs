@@ -0,0 +1,159 @@
+use std::ops::{Deref, DerefMut};
+
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::{FromReflect, Reflect};
+use bevy_utils::Duration;
+
+use crate::{Stopwatch, Time, Timer};
+
+/// A [`Timer`] or [`Stopwatch`] that can be ticked by [`AutoTick`].
+pub trait AutoTickable: Default + Reflect + FromReflect {
+    /// Advances the time by `delta` and returns how many times it finished.
+    fn auto_tick(&mut self, delta: Duration) -> u32;
+}
+
+impl AutoTickable for Timer {
+    fn auto_tick(&mut self, delta: Duration) -> u32 {
+        self.tick(delta).times_finished_this_tick()
+    }
+}
+
+impl AutoTickable for Stopwatch {
+    fn auto_tick(&mut self, delta: Duration) -> u32 {
+        self.tick(delta);
+        0
+    }
+}
+
+/// A component wrapping a [`Timer`] or [`Stopwatch`] that is ticked automatically with the
+/// [`Time::delta`] at the start of each update.
+///
+/// When an automatically ticked [`Timer`] finishes, a [`TimerFinished`] event addressed to its
+/// entity is sent.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// #[derive(Component)]
+/// struct Bomb;
+///
+/// fn plant_bomb(mut commands: Commands) {
+///     commands.spawn((
+///         Bomb,
+///         AutoTick(Timer::from_seconds(5.0, TimerMode::Once)),
+///     ));
+/// }
+///
+/// fn explode(mut finished: EntityEventReader<TimerFinished>, bombs: Query<Entity, With<Bomb>>) {
+///     for bomb in &bombs {
+///         if !finished.is_empty(bomb) {
+///             finished.clear(bomb);
+///             println!("boom!");
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(plant_bomb);
+/// # bevy_ecs::system::assert_is_system(explode);
+/// ```
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component)]
+pub struct AutoTick<T: AutoTickable>(pub T);
+
+impl<T: AutoTickable> Deref for AutoTick<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: AutoTickable> DerefMut for AutoTick<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// An entity event sent when the [`Timer`] of an [`AutoTick`] component or of a
+/// [`Timers<K>`](crate::Timers) component finishes.
+///
+/// The event is addressed to the entity with the component, so it is read with an
+/// [`EntityEventReader`](bevy_ecs::entity_event::EntityEventReader) for that entity.
+/// For [`AutoTick`] components, `K` is `()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerFinished<K = ()> {
+    /// The key of the timer in its [`Timers<K>`](crate::Timers) component.
+    pub key: K,
+    /// How many times the timer finished during the last tick, see
+    /// [`Timer::times_finished_this_tick`].
+    pub times: u32,
+}
+
+/// Ticks every [`AutoTick<T>`] component, sending a [`TimerFinished`] event to the entity of
+/// each one that finished.
+pub fn auto_tick_system<T: AutoTickable>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut AutoTick<T>)>,
+    mut finished: EntityEventWriter<TimerFinished>,
+) {
+    for (entity, mut auto_tick) in &mut query {
+        let times = auto_tick.auto_tick(time.delta());
+        if times > 0 {
+            finished.send(entity, TimerFinished { key: (), times });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimerMode;
+    use bevy_ecs::schedule::Schedule;
+    use bevy_utils::Instant;
+
+    #[test]
+    fn auto_tick_timers() {
+        let mut world = World::new();
+        let start = Instant::now();
+        world.insert_resource(Time::new(start));
+        world.init_resource::<EntityEvents<TimerFinished>>();
+        let timer = world
+            .spawn(AutoTick(Timer::from_seconds(1.0, TimerMode::Repeating)))
+            .id();
+        let stopwatch = world.spawn(AutoTick(Stopwatch::new())).id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((auto_tick_system::<Timer>, auto_tick_system::<Stopwatch>));
+
+        let mut update = |world: &mut World, secs: f32| {
+            world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_secs_f32(secs));
+            schedule.run(world);
+            let mut events = world.resource_mut::<EntityEvents<TimerFinished>>();
+            let finished = events
+                .entities()
+                .flat_map(|entity| events.get(entity).map(move |event| (entity, *event)))
+                .collect::<Vec<_>>();
+            events.clear();
+            finished
+        };
+
+        assert_eq!(update(&mut world, 0.0), []);
+        assert_eq!(update(&mut world, 0.5), []);
+        assert_eq!(
+            update(&mut world, 2.5),
+            [(timer, TimerFinished { key: (), times: 2 })]
+        );
+        assert_eq!(
+            world.get::<AutoTick<Timer>>(timer).unwrap().elapsed_secs(),
+            0.5
+        );
+        assert_eq!(
+            world
+                .get::<AutoTick<Stopwatch>>(stopwatch)
+                .unwrap()
+                .elapsed_secs(),
+            2.5
+        );
+    }
+}
@@ -20,6 +20,10 @@
 //!
 //! When using fixed time steps, it is advised not to rely on [`Time::delta`] or any of it's
 //! variants for game simulation, but rather use the value of [`FixedTime`] instead.
+//!
+//! [`FixedTime`] accumulates the virtual [`Time::delta`], so the schedule stops running while
+//! [`Time`] is [paused](Time::pause) and runs slower or faster with its
+//! [relative speed](Time::set_relative_speed).
 
 use crate::Time;
 use bevy_app::CoreSchedule;
@@ -150,6 +154,46 @@ mod test {
         assert_eq!(fixed_time.accumulated(), Duration::ZERO);
     }
 
+    #[test]
+    fn fixed_time_uses_virtual_time() {
+        use bevy_ecs::{
+            schedule::{Schedule, Schedules},
+            system::{ResMut, Resource},
+        };
+        use bevy_utils::Instant;
+
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Schedules>();
+        let start = Instant::now();
+        world.insert_resource(Time::new(start));
+        world.insert_resource(FixedTime::new(Duration::from_secs(1)));
+        world.init_resource::<Runs>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(|mut runs: ResMut<Runs>| runs.0 += 1);
+        world.add_schedule(schedule, CoreSchedule::FixedUpdate);
+
+        let update = |world: &mut World, secs: u64| {
+            world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_secs(secs));
+            run_fixed_update_schedule(world);
+            world.resource::<Runs>().0
+        };
+
+        assert_eq!(update(&mut world, 0), 0);
+        assert_eq!(update(&mut world, 2), 2);
+
+        world.resource_mut::<Time>().pause();
+        assert_eq!(update(&mut world, 4), 2);
+
+        world.resource_mut::<Time>().unpause();
+        world.resource_mut::<Time>().set_relative_speed(0.5);
+        assert_eq!(update(&mut world, 8), 4);
+    }
+
     #[test]
     fn repeatedly_expending_time() {
         let mut fixed_time = FixedTime::new(Duration::from_secs(1));
@@ -1,3 +1,4 @@
+mod auto_tick;
 /// Common run conditions
 pub mod common_conditions;
 pub mod fixed_timestep;
@@ -5,11 +6,14 @@ mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
 mod timer;
+mod timers;
 
+pub use auto_tick::*;
 use fixed_timestep::{run_fixed_update_schedule, FixedTime};
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
+pub use timers::*;
 
 use bevy_ecs::system::{Res, ResMut};
 use bevy_utils::{tracing::warn, Duration, Instant};
@@ -18,7 +22,10 @@ use crossbeam_channel::{Receiver, Sender};
 pub mod prelude {
     //! The Bevy Time Prelude.
     #[doc(hidden)]
-    pub use crate::{fixed_timestep::FixedTime, Time, Timer, TimerMode};
+    pub use crate::{
+        fixed_timestep::FixedTime, AutoTick, Stopwatch, Time, Timer, TimerFinished, TimerMode,
+        Timers, TimersPlugin,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -40,9 +47,17 @@ impl Plugin for TimePlugin {
             .register_type::<Timer>()
             .register_type::<Time>()
             .register_type::<Stopwatch>()
+            .register_type::<AutoTick<Timer>>()
+            .register_type::<AutoTick<Stopwatch>>()
+            .add_entity_event::<TimerFinished>()
             .init_resource::<FixedTime>()
             .configure_set(TimeSystem.in_base_set(CoreSet::First))
             .add_system(time_system.in_set(TimeSystem))
+            .add_systems(
+                (auto_tick_system::<Timer>, auto_tick_system::<Stopwatch>)
+                    .in_base_set(CoreSet::First)
+                    .after(TimeSystem),
+            )
             .add_system(run_fixed_update_schedule.in_base_set(CoreSet::FixedUpdate));
     }
 }
@@ -0,0 +1,210 @@
+use std::{hash::Hash, marker::PhantomData};
+
+use bevy_app::{App, CoreSet, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_utils::HashMap;
+
+use crate::{Time, TimeSystem, Timer, TimerFinished};
+
+/// A component holding many [`Timer`]s labeled by keys of type `K`, which are all ticked by a
+/// single [`tick_timers_system`].
+///
+/// Whenever one of the timers finishes, a [`TimerFinished<K>`] event with its key is sent to the
+/// entity. Add the [`TimersPlugin<K>`] to the app to tick the timers and set up the event.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// enum Cooldown {
+///     Attack,
+///     Dash,
+/// }
+///
+/// fn spawn_player(mut commands: Commands) {
+///     let mut cooldowns = Timers::default();
+///     cooldowns.insert(Cooldown::Attack, Timer::from_seconds(0.5, TimerMode::Once));
+///     cooldowns.insert(Cooldown::Dash, Timer::from_seconds(2.0, TimerMode::Once));
+///     commands.spawn(cooldowns);
+/// }
+///
+/// fn cooldown_finished(
+///     mut finished: EntityEventReader<TimerFinished<Cooldown>>,
+///     players: Query<Entity, With<Timers<Cooldown>>>,
+/// ) {
+///     for player in &players {
+///         for event in finished.read(player) {
+///             println!("{:?} of {:?} is ready", event.key, player);
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_player);
+/// # bevy_ecs::system::assert_is_system(cooldown_finished);
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct Timers<K: Eq + Hash + Clone + Send + Sync + 'static> {
+    timers: HashMap<K, Timer>,
+}
+
+impl<K: Eq + Hash + Clone + Send + Sync + 'static> Default for Timers<K> {
+    fn default() -> Self {
+        Self {
+            timers: HashMap::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone + Send + Sync + 'static> Timers<K> {
+    /// Inserts the `timer` with the given `key`, returning the timer it replaced, if any.
+    pub fn insert(&mut self, key: K, timer: Timer) -> Option<Timer> {
+        self.timers.insert(key, timer)
+    }
+
+    /// Removes the timer with the given `key`, returning it if it existed.
+    pub fn remove(&mut self, key: &K) -> Option<Timer> {
+        self.timers.remove(key)
+    }
+
+    /// Returns the timer with the given `key`.
+    pub fn get(&self, key: &K) -> Option<&Timer> {
+        self.timers.get(key)
+    }
+
+    /// Returns the timer with the given `key` mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Timer> {
+        self.timers.get_mut(key)
+    }
+
+    /// Returns `true` if there is a timer with the given `key`.
+    pub fn contains(&self, key: &K) -> bool {
+        self.timers.contains_key(key)
+    }
+
+    /// Returns `true` if the timer with the given `key` finished during the last tick.
+    ///
+    /// See [`Timer::just_finished`].
+    pub fn just_finished(&self, key: &K) -> bool {
+        self.get(key).map_or(false, Timer::just_finished)
+    }
+
+    /// Returns an iterator over all keys and their timers.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Timer)> {
+        self.timers.iter()
+    }
+
+    /// Returns an iterator over all keys and their timers, allowing modification of the timers.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut Timer)> {
+        self.timers.iter_mut()
+    }
+
+    /// Returns the number of timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if there are no timers.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+}
+
+/// Ticks every timer of every [`Timers<K>`] component, sending a [`TimerFinished<K>`] event to the
+/// entity for each one that finished.
+pub fn tick_timers_system<K: Eq + Hash + Clone + Send + Sync + 'static>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Timers<K>)>,
+    mut finished: EntityEventWriter<TimerFinished<K>>,
+) {
+    for (entity, mut timers) in &mut query {
+        for (key, timer) in timers.iter_mut() {
+            let times = timer.tick(time.delta()).times_finished_this_tick();
+            if times > 0 {
+                finished.send(
+                    entity,
+                    TimerFinished {
+                        key: key.clone(),
+                        times,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Adds the [`tick_timers_system`] for [`Timers<K>`] components and the [`TimerFinished<K>`] event.
+pub struct TimersPlugin<K: Eq + Hash + Clone + Send + Sync + 'static>(PhantomData<fn() -> K>);
+
+impl<K: Eq + Hash + Clone + Send + Sync + 'static> Default for TimersPlugin<K> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<K: Eq + Hash + Clone + Send + Sync + 'static> Plugin for TimersPlugin<K> {
+    fn build(&self, app: &mut App) {
+        app.add_entity_event::<TimerFinished<K>>().add_system(
+            tick_timers_system::<K>
+                .in_base_set(CoreSet::First)
+                .after(TimeSystem),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimerMode;
+    use bevy_ecs::schedule::Schedule;
+    use bevy_utils::{Duration, Instant};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Key {
+        Short,
+        Long,
+    }
+
+    #[test]
+    fn tick_timers() {
+        let mut world = World::new();
+        let start = Instant::now();
+        world.insert_resource(Time::new(start));
+        world.init_resource::<EntityEvents<TimerFinished<Key>>>();
+        let mut timers = Timers::default();
+        timers.insert(Key::Short, Timer::from_seconds(1.0, TimerMode::Repeating));
+        timers.insert(Key::Long, Timer::from_seconds(2.75, TimerMode::Once));
+        let entity = world.spawn(timers).id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(tick_timers_system::<Key>);
+
+        let mut update = |world: &mut World, secs: f32| {
+            world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_secs_f32(secs));
+            schedule.run(world);
+            let mut events = world.resource_mut::<EntityEvents<TimerFinished<Key>>>();
+            let finished = events.get(entity).copied().collect::<Vec<_>>();
+            events.clear();
+            finished
+        };
+
+        assert_eq!(update(&mut world, 0.0), []);
+        assert_eq!(
+            update(&mut world, 2.5),
+            [TimerFinished {
+                key: Key::Short,
+                times: 2,
+            }]
+        );
+        assert_eq!(
+            update(&mut world, 2.9),
+            [TimerFinished {
+                key: Key::Long,
+                times: 1,
+            }]
+        );
+        let timers = world.get::<Timers<Key>>(entity).unwrap();
+        assert!(timers.just_finished(&Key::Long));
+        assert!(!timers.just_finished(&Key::Short));
+    }
+}
@@ -99,3 +99,64 @@ impl<'w, 's, 'a> BuildChildrenTransformExt for EntityCommands<'w, 's, 'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, system::CommandQueue};
+    use bevy_math::{Quat, Vec3};
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    use super::BuildChildrenTransformExt;
+    use crate::{
+        systems::{propagate_transforms, sync_simple_transforms},
+        GlobalTransform, Transform, TransformBundle,
+    };
+
+    #[test]
+    fn reparent_in_place() {
+        ComputeTaskPool::init(TaskPool::default);
+        let mut world = World::default();
+        let mut schedule = Schedule::new();
+        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+
+        let parent = world
+            .spawn(TransformBundle::from(
+                Transform::from_xyz(1.0, 2.0, 3.0)
+                    .with_rotation(Quat::from_rotation_y(1.0))
+                    .with_scale(Vec3::splat(2.0)),
+            ))
+            .id();
+        let child = world
+            .spawn(TransformBundle::from(Transform::from_xyz(-1.0, 0.0, 4.0)))
+            .id();
+        schedule.run(&mut world);
+        let initial = *world.get::<GlobalTransform>(child).unwrap();
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world)
+            .entity(child)
+            .set_parent_in_place(parent);
+        queue.apply(&mut world);
+        schedule.run(&mut world);
+        let reparented = *world.get::<GlobalTransform>(child).unwrap();
+        assert!(reparented
+            .translation()
+            .abs_diff_eq(initial.translation(), 1e-5));
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world)
+            .entity(child)
+            .remove_parent_in_place();
+        queue.apply(&mut world);
+        schedule.run(&mut world);
+        let removed = *world.get::<GlobalTransform>(child).unwrap();
+        assert!(removed
+            .translation()
+            .abs_diff_eq(initial.translation(), 1e-5));
+        assert!(world
+            .get::<Transform>(child)
+            .unwrap()
+            .translation
+            .abs_diff_eq(initial.translation(), 1e-5));
+    }
+}
@@ -1,5 +1,7 @@
 mod global_transform;
+mod static_transform;
 mod transform;
 
 pub use global_transform::*;
+pub use static_transform::*;
 pub use transform::*;
@@ -0,0 +1,20 @@
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+
+/// Marks an entity whose descendants never move relative to it, so that transform propagation
+/// can skip its whole subtree while it is unchanged.
+///
+/// The subtree of an entity with [`StaticTransform`] is only propagated when the entity itself
+/// is affected by a change: when its own [`Transform`](super::Transform), its parent, its
+/// [`Children`](bevy_hierarchy::Children) or the [`GlobalTransform`](super::GlobalTransform)
+/// of an ancestor changes, or when the [`StaticTransform`] component is added.
+///
+/// Changes to the [`Transform`](super::Transform) or the hierarchy of its descendants are not
+/// detected. To update them, mark the [`Transform`](super::Transform) of the entity as changed,
+/// or remove the [`StaticTransform`] component, which propagates the whole subtree once.
+///
+/// This is useful for large static levels, where walking the unchanged hierarchy every frame
+/// is costly.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct StaticTransform;
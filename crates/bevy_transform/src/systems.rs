@@ -1,9 +1,10 @@
-use crate::components::{GlobalTransform, Transform};
+use crate::components::{GlobalTransform, StaticTransform, Transform};
 use bevy_ecs::{
     change_detection::Ref,
-    prelude::{Changed, DetectChanges, Entity, Query, With, Without},
+    prelude::{Changed, DetectChanges, Entity, Query, RemovedComponents, With, Without},
 };
 use bevy_hierarchy::{Children, Parent};
+use bevy_utils::HashSet;
 
 /// Update [`GlobalTransform`] component of entities that aren't in the hierarchy
 ///
@@ -25,22 +26,37 @@ pub fn sync_simple_transforms(
 /// [`Transform`] component.
 ///
 /// Third party plugins should ensure that this is used in concert with [`sync_simple_transforms`].
+///
+/// The subtrees of unchanged entities with [`StaticTransform`] are skipped. The subtrees of
+/// entities whose [`StaticTransform`] was removed are fully propagated, to catch up with the
+/// changes skipped in the meantime.
 pub fn propagate_transforms(
     mut root_query: Query<
-        (Entity, &Children, Ref<Transform>, &mut GlobalTransform),
+        (
+            Entity,
+            Ref<Children>,
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<Ref<StaticTransform>>,
+        ),
         Without<Parent>,
     >,
-    transform_query: Query<(Ref<Transform>, &mut GlobalTransform, Option<&Children>), With<Parent>>,
+    transform_query: TransformQuery,
     parent_query: Query<(Entity, Ref<Parent>)>,
+    mut removed_static_transforms: RemovedComponents<StaticTransform>,
 ) {
+    let unmarked: HashSet<Entity> = removed_static_transforms.iter().collect();
     root_query.par_iter_mut().for_each_mut(
-        |(entity, children, transform, mut global_transform)| {
-            let changed = transform.is_changed();
+        |(entity, children, transform, mut global_transform, static_transform)| {
+            let changed = transform.is_changed() || unmarked.contains(&entity);
             if changed {
                 *global_transform = GlobalTransform::from(*transform);
             }
+            if is_static_subtree(changed, &children, static_transform) {
+                return;
+            }
 
-            for (child, actual_parent) in parent_query.iter_many(children) {
+            for (child, actual_parent) in parent_query.iter_many(&*children) {
                 assert_eq!(
                     actual_parent.get(), entity,
                     "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
@@ -58,6 +74,7 @@ pub fn propagate_transforms(
                         &global_transform,
                         &transform_query,
                         &parent_query,
+                        &unmarked,
                         child,
                         changed || actual_parent.is_changed(),
                     );
@@ -67,8 +84,35 @@ pub fn propagate_transforms(
     );
 }
 
+type TransformQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, Transform>,
+        &'static mut GlobalTransform,
+        Option<Ref<'static, Children>>,
+        Option<Ref<'static, StaticTransform>>,
+    ),
+    With<Parent>,
+>;
+
+/// Returns `true` if the children of an entity can be skipped, because it is marked with an
+/// unchanged [`StaticTransform`].
+fn is_static_subtree(
+    changed: bool,
+    children: &Ref<Children>,
+    static_transform: Option<Ref<StaticTransform>>,
+) -> bool {
+    static_transform.map_or(false, |static_transform| {
+        !changed && !static_transform.is_added() && !children.is_changed()
+    })
+}
+
 /// Recursively propagates the transforms for `entity` and all of its descendants.
 ///
+/// The subtrees of the `unmarked` entities, whose [`StaticTransform`] was removed, are always
+/// propagated.
+///
 /// # Panics
 ///
 /// If `entity`'s descendants have a malformed hierarchy, this function will panic occur before propagating
@@ -82,16 +126,14 @@ pub fn propagate_transforms(
 /// is well-formed and must remain as a tree or a forest. Each entity must have at most one parent.
 unsafe fn propagate_recursive(
     parent: &GlobalTransform,
-    transform_query: &Query<
-        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
-        With<Parent>,
-    >,
+    transform_query: &TransformQuery,
     parent_query: &Query<(Entity, Ref<Parent>)>,
+    unmarked: &HashSet<Entity>,
     entity: Entity,
     mut changed: bool,
 ) {
     let (global_matrix, children) = {
-        let Ok((transform, mut global_transform, children)) =
+        let Ok((transform, mut global_transform, children, static_transform)) =
             // SAFETY: This call cannot create aliased mutable references.
             //   - The top level iteration parallelizes on the roots of the hierarchy.
             //   - The caller ensures that each child has one and only one unique parent throughout the entire
@@ -122,15 +164,18 @@ unsafe fn propagate_recursive(
                 return;
             };
 
-        changed |= transform.is_changed();
+        changed |= transform.is_changed() || unmarked.contains(&entity);
         if changed {
             *global_transform = parent.mul_transform(*transform);
         }
+        let Some(children) = children else { return };
+        if is_static_subtree(changed, &children, static_transform) {
+            return;
+        }
         (*global_transform, children)
     };
 
-    let Some(children) = children else { return };
-    for (child, actual_parent) in parent_query.iter_many(children) {
+    for (child, actual_parent) in parent_query.iter_many(&*children) {
         assert_eq!(
             actual_parent.get(), entity,
             "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
@@ -145,6 +190,7 @@ unsafe fn propagate_recursive(
                 &global_matrix,
                 transform_query,
                 parent_query,
+                unmarked,
                 child,
                 changed || actual_parent.is_changed(),
             );
@@ -155,6 +201,7 @@ unsafe fn propagate_recursive(
 #[cfg(test)]
 mod test {
     use bevy_app::prelude::*;
+    use bevy_ecs::entity::DespawnResult;
     use bevy_ecs::prelude::*;
     use bevy_ecs::system::CommandQueue;
     use bevy_math::vec3;
@@ -305,7 +352,7 @@ mod test {
             vec![children[0]]
         );
 
-        assert!(world.despawn(children[0]));
+        assert_eq!(world.despawn(children[0]), DespawnResult::Despawned);
 
         schedule.run(&mut world);
 
@@ -320,6 +367,72 @@ mod test {
         );
     }
 
+    #[test]
+    fn skip_static_subtrees() {
+        ComputeTaskPool::init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+
+        let leaf = world
+            .spawn(TransformBundle::from(Transform::from_xyz(0.0, 0.0, 1.0)))
+            .id();
+        let static_entity = world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                StaticTransform,
+            ))
+            .push_children(&[leaf])
+            .id();
+        let root = world
+            .spawn(TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)))
+            .push_children(&[static_entity])
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 1.0)
+        );
+
+        // Changes below an unchanged static entity are skipped
+        world.get_mut::<Transform>(leaf).unwrap().translation.z = 2.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 1.0)
+        );
+
+        // Changing the static entity propagates its subtree
+        world
+            .get_mut::<Transform>(static_entity)
+            .unwrap()
+            .set_changed();
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 2.0)
+        );
+
+        // So does changing an ancestor
+        world.get_mut::<Transform>(root).unwrap().translation.x = 3.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(3.0, 1.0, 2.0)
+        );
+
+        // Removing the marker propagates the changes skipped while it was static
+        world.get_mut::<Transform>(leaf).unwrap().translation.z = 4.0;
+        schedule.run(&mut world);
+        world.entity_mut(static_entity).remove::<StaticTransform>();
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(leaf).unwrap(),
+            GlobalTransform::from_xyz(3.0, 1.0, 4.0)
+        );
+    }
+
     #[test]
     fn correct_transforms_when_no_children() {
         let mut app = App::new();
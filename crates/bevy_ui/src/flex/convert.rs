@@ -1,8 +1,9 @@
 use taffy::style::LengthPercentageAuto;
 
 use crate::{
-    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, JustifyContent,
-    PositionType, Size, Style, UiRect, Val,
+    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    GridPlacement, GridTrack, GridTrackRepetition, JustifyContent, MaxTrackSizingFunction,
+    MinTrackSizingFunction, PositionType, RepeatedGridTrack, Size, Style, UiRect, Val,
 };
 
 impl Val {
@@ -121,6 +122,120 @@ pub fn from_style(scale_factor: f64, style: &Style) -> taffy::style::Style {
         aspect_ratio: style.aspect_ratio,
         gap: style.gap.scaled(scale_factor).into(),
         justify_self: None,
+        justify_items: None,
+        grid_auto_flow: style.grid_auto_flow.into(),
+        grid_template_rows: style
+            .grid_template_rows
+            .iter()
+            .map(|track| track.scaled(scale_factor))
+            .collect(),
+        grid_template_columns: style
+            .grid_template_columns
+            .iter()
+            .map(|track| track.scaled(scale_factor))
+            .collect(),
+        grid_auto_rows: style
+            .grid_auto_rows
+            .iter()
+            .map(|track| track.scaled(scale_factor))
+            .collect(),
+        grid_auto_columns: style
+            .grid_auto_columns
+            .iter()
+            .map(|track| track.scaled(scale_factor))
+            .collect(),
+        grid_row: style.grid_row.into(),
+        grid_column: style.grid_column.into(),
+    }
+}
+
+impl GridTrack {
+    fn scaled(self, scale_factor: f64) -> taffy::style::NonRepeatedTrackSizingFunction {
+        let scale = |value: f32| (scale_factor * value as f64) as f32;
+        let min = match self.min_sizing_function {
+            MinTrackSizingFunction::Px(value) => taffy::style::MinTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Points(scale(value)),
+            ),
+            MinTrackSizingFunction::Percent(value) => taffy::style::MinTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Percent(value / 100.0),
+            ),
+            MinTrackSizingFunction::MinContent => taffy::style::MinTrackSizingFunction::MinContent,
+            MinTrackSizingFunction::MaxContent => taffy::style::MinTrackSizingFunction::MaxContent,
+            MinTrackSizingFunction::Auto => taffy::style::MinTrackSizingFunction::Auto,
+        };
+        let max = match self.max_sizing_function {
+            MaxTrackSizingFunction::Px(value) => taffy::style::MaxTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Points(scale(value)),
+            ),
+            MaxTrackSizingFunction::Percent(value) => taffy::style::MaxTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Percent(value / 100.0),
+            ),
+            MaxTrackSizingFunction::MinContent => taffy::style::MaxTrackSizingFunction::MinContent,
+            MaxTrackSizingFunction::MaxContent => taffy::style::MaxTrackSizingFunction::MaxContent,
+            MaxTrackSizingFunction::FitContentPx(value) => {
+                taffy::style::MaxTrackSizingFunction::FitContent(
+                    taffy::style::LengthPercentage::Points(scale(value)),
+                )
+            }
+            MaxTrackSizingFunction::FitContentPercent(value) => {
+                taffy::style::MaxTrackSizingFunction::FitContent(
+                    taffy::style::LengthPercentage::Percent(value / 100.0),
+                )
+            }
+            MaxTrackSizingFunction::Auto => taffy::style::MaxTrackSizingFunction::Auto,
+            MaxTrackSizingFunction::Fraction(value) => {
+                taffy::style::MaxTrackSizingFunction::Fraction(value)
+            }
+        };
+        taffy::geometry::MinMax { min, max }
+    }
+}
+
+impl RepeatedGridTrack {
+    fn scaled(&self, scale_factor: f64) -> taffy::style::TrackSizingFunction {
+        let tracks = self
+            .tracks
+            .iter()
+            .map(|track| track.scaled(scale_factor))
+            .collect();
+        let repetition = match self.repetition {
+            // A single track doesn't need to be repeated.
+            GridTrackRepetition::Count(1) if self.tracks.len() == 1 => {
+                return taffy::style::TrackSizingFunction::Single(
+                    self.tracks[0].scaled(scale_factor),
+                );
+            }
+            GridTrackRepetition::Count(count) => taffy::style::GridTrackRepetition::Count(count),
+            GridTrackRepetition::AutoFill => taffy::style::GridTrackRepetition::AutoFill,
+            GridTrackRepetition::AutoFit => taffy::style::GridTrackRepetition::AutoFit,
+        };
+        taffy::style::TrackSizingFunction::Repeat(repetition, tracks)
+    }
+}
+
+impl From<GridAutoFlow> for taffy::style::GridAutoFlow {
+    fn from(value: GridAutoFlow) -> Self {
+        match value {
+            GridAutoFlow::Row => taffy::style::GridAutoFlow::Row,
+            GridAutoFlow::Column => taffy::style::GridAutoFlow::Column,
+            GridAutoFlow::RowDense => taffy::style::GridAutoFlow::RowDense,
+            GridAutoFlow::ColumnDense => taffy::style::GridAutoFlow::ColumnDense,
+        }
+    }
+}
+
+impl From<GridPlacement> for taffy::geometry::Line<taffy::style::GridPlacement> {
+    fn from(value: GridPlacement) -> Self {
+        use taffy::style_helpers::{line, span};
+        let auto = taffy::style::GridPlacement::Auto;
+        let (start, end) = match (value.start, value.span, value.end) {
+            (Some(start), _, Some(end)) => (line(start), line(end)),
+            (Some(start), span_count, None) => (line(start), span_count.map_or(auto, span)),
+            (None, Some(span_count), Some(end)) => (span(span_count), line(end)),
+            (None, None, Some(end)) => (auto, line(end)),
+            (None, span_count, None) => (auto, span_count.map_or(auto, span)),
+        };
+        Self { start, end }
     }
 }
 
@@ -173,6 +288,7 @@ impl From<Display> for taffy::style::Display {
     fn from(value: Display) -> Self {
         match value {
             Display::Flex => taffy::style::Display::Flex,
+            Display::Grid => taffy::style::Display::Grid,
             Display::None => taffy::style::Display::None,
         }
     }
@@ -282,6 +398,16 @@ mod tests {
                 width: Val::Px(0.),
                 height: Val::Percent(0.),
             },
+            grid_auto_flow: GridAutoFlow::ColumnDense,
+            grid_template_rows: vec![GridTrack::px(10.0).into(), RepeatedGridTrack::fr(2, 1.0)],
+            grid_template_columns: vec![RepeatedGridTrack::repeat(
+                GridTrackRepetition::AutoFill,
+                [GridTrack::px(20.0)],
+            )],
+            grid_auto_rows: vec![GridTrack::min_content()],
+            grid_auto_columns: Vec::new(),
+            grid_row: GridPlacement::span(3),
+            grid_column: GridPlacement::start_end(4, -1),
         };
         let taffy_style = from_style(1.0, &bevy_style);
         assert_eq!(taffy_style.display, taffy::style::Display::Flex);
@@ -407,5 +533,59 @@ mod tests {
             taffy_style.gap.height,
             taffy::style::LengthPercentage::Percent(0.)
         );
+        assert_eq!(
+            taffy_style.grid_auto_flow,
+            taffy::style::GridAutoFlow::ColumnDense
+        );
+        let points = |value| taffy::geometry::MinMax {
+            min: taffy::style::MinTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Points(value),
+            ),
+            max: taffy::style::MaxTrackSizingFunction::Fixed(
+                taffy::style::LengthPercentage::Points(value),
+            ),
+        };
+        assert_eq!(
+            taffy_style.grid_template_rows,
+            vec![
+                taffy::style::TrackSizingFunction::Single(points(10.)),
+                taffy::style::TrackSizingFunction::Repeat(
+                    taffy::style::GridTrackRepetition::Count(2),
+                    vec![taffy::geometry::MinMax {
+                        min: taffy::style::MinTrackSizingFunction::Auto,
+                        max: taffy::style::MaxTrackSizingFunction::Fraction(1.),
+                    }]
+                ),
+            ]
+        );
+        assert_eq!(
+            taffy_style.grid_template_columns,
+            vec![taffy::style::TrackSizingFunction::Repeat(
+                taffy::style::GridTrackRepetition::AutoFill,
+                vec![points(20.)]
+            )]
+        );
+        assert_eq!(
+            taffy_style.grid_auto_rows,
+            vec![taffy::geometry::MinMax {
+                min: taffy::style::MinTrackSizingFunction::MinContent,
+                max: taffy::style::MaxTrackSizingFunction::MinContent,
+            }]
+        );
+        assert!(taffy_style.grid_auto_columns.is_empty());
+        assert_eq!(
+            taffy_style.grid_row,
+            taffy::geometry::Line {
+                start: taffy::style::GridPlacement::Auto,
+                end: taffy::style::GridPlacement::Span(3),
+            }
+        );
+        assert_eq!(
+            taffy_style.grid_column,
+            taffy::geometry::Line {
+                start: taffy::style_helpers::line(4),
+                end: taffy::style_helpers::line(-1),
+            }
+        );
     }
 }
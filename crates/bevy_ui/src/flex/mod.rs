@@ -1,6 +1,6 @@
 mod convert;
 
-use crate::{CalculatedSize, Node, Style, UiScale};
+use crate::{CalculatedSize, Node, Overflow, ScrollPosition, Style, UiScale};
 use bevy_ecs::{
     change_detection::DetectChanges,
     entity::Entity,
@@ -240,6 +240,7 @@ pub fn flex_node_system(
     children_query: Query<(Entity, &Children), (With<Node>, Changed<Children>)>,
     mut removed_children: RemovedComponents<Children>,
     mut node_transform_query: Query<(Entity, &mut Node, &mut Transform, Option<&Parent>)>,
+    mut scroll_query: Query<(Entity, &mut ScrollPosition, &Style, Option<&Children>), With<Node>>,
     mut removed_nodes: RemovedComponents<Node>,
 ) {
     // assume one window for time being...
@@ -306,6 +307,41 @@ pub fn flex_node_system(
 
     let to_logical = |v| (physical_to_logical_factor * v as f64) as f32;
 
+    // clamp scroll positions to the size of the content and find the offset of the children
+    let mut scroll_offsets = HashMap::new();
+    for (entity, mut scroll_position, style, children) in &mut scroll_query {
+        if style.overflow != Overflow::Scroll {
+            continue;
+        }
+        let Ok(layout) = flex_surface.get_layout(entity) else {
+            continue;
+        };
+        let size = Vec2::new(layout.size.width, layout.size.height);
+        let content_size = children
+            .into_iter()
+            .flatten()
+            .filter_map(|&child| flex_surface.get_layout(child).ok())
+            .map(|child_layout| {
+                Vec2::new(
+                    child_layout.location.x + child_layout.size.width,
+                    child_layout.location.y + child_layout.size.height,
+                )
+            })
+            .fold(Vec2::ZERO, Vec2::max);
+        let max_offset = Vec2::new(
+            to_logical(content_size.x - size.x),
+            to_logical(content_size.y - size.y),
+        )
+        .max(Vec2::ZERO);
+        // only trigger change detection when the new value is different
+        if scroll_position.max_offset() != max_offset
+            || scroll_position.offset != scroll_position.offset.clamp(Vec2::ZERO, max_offset)
+        {
+            scroll_position.set_max_offset(max_offset);
+        }
+        scroll_offsets.insert(entity, scroll_position.offset);
+    }
+
     // PERF: try doing this incrementally
     for (entity, mut node, mut transform, parent) in &mut node_transform_query {
         let layout = flex_surface.get_layout(entity).unwrap();
@@ -325,6 +361,10 @@ pub fn flex_node_system(
                 new_position.x -= to_logical(parent_layout.size.width / 2.0);
                 new_position.y -= to_logical(parent_layout.size.height / 2.0);
             }
+            if let Some(offset) = scroll_offsets.get(&**parent) {
+                new_position.x -= offset.x;
+                new_position.y -= offset.y;
+            }
         }
         // only trigger change detection when the new value is different
         if transform.translation != new_position {
@@ -1,4 +1,4 @@
-use crate::{camera_config::UiCameraConfig, CalculatedClip, Node, UiStack};
+use crate::{camera_config::UiCameraConfig, CalculatedClip, Focus, Node, UiStack};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     change_detection::DetectChangesMut,
@@ -22,7 +22,9 @@ use smallvec::SmallVec;
 ///
 /// This is commonly queried with a `Changed<Interaction>` filter.
 ///
-/// Updated in [`ui_focus_system`].
+/// Updated in [`ui_focus_system`]. The node with the [`Focus`] is at least
+/// [`Interaction::Hovered`] while the focus is [visible](Focus::is_visible), and is clicked by
+/// [`ui_navigation_system`](crate::ui_navigation_system).
 ///
 /// If a UI node has both [`Interaction`] and [`ComputedVisibility`] components,
 /// [`Interaction`] will always be [`Interaction::None`]
@@ -137,10 +139,12 @@ pub fn ui_focus_system(
     mouse_button_input: Res<Input<MouseButton>>,
     touches_input: Res<Touches>,
     ui_stack: Res<UiStack>,
+    focus: Res<Focus>,
     mut node_query: Query<NodeQuery>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
 ) {
     let primary_window = primary_window.iter().next();
+    let focused = focus.get().filter(|_| focus.is_visible());
 
     // reset entities that were both clicked and released in the last frame
     for entity in state.entities_to_reset.drain(..) {
@@ -248,7 +252,9 @@ pub fn ui_focus_system(
                     Some(*entity)
                 } else {
                     if let Some(mut interaction) = node.interaction {
-                        if *interaction == Interaction::Hovered || (cursor_position.is_none()) {
+                        if (*interaction == Interaction::Hovered || cursor_position.is_none())
+                            && focused != Some(*entity)
+                        {
                             interaction.set_if_neq(Interaction::None);
                         }
                     }
@@ -294,9 +300,18 @@ pub fn ui_focus_system(
     while let Some(node) = iter.fetch_next() {
         if let Some(mut interaction) = node.interaction {
             // don't reset clicked nodes because they're handled separately
-            if *interaction != Interaction::Clicked {
+            if *interaction != Interaction::Clicked && focused != Some(node.entity) {
                 interaction.set_if_neq(Interaction::None);
             }
         }
     }
+
+    // the focused node is hovered even when the cursor is elsewhere
+    if let Some(focused) = focused {
+        if let Ok(mut interaction) = node_query.get_component_mut::<Interaction>(focused) {
+            if *interaction == Interaction::None {
+                *interaction = Interaction::Hovered;
+            }
+        }
+    }
 }
@@ -5,7 +5,9 @@
 mod flex;
 mod focus;
 mod geometry;
+mod navigation;
 mod render;
+mod scroll;
 mod stack;
 mod ui_node;
 
@@ -22,14 +24,17 @@ use bevy_render::extract_component::ExtractComponentPlugin;
 pub use flex::*;
 pub use focus::*;
 pub use geometry::*;
+pub use navigation::*;
 pub use render::*;
+pub use scroll::*;
 pub use ui_node::*;
 
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        camera_config::*, geometry::*, node_bundles::*, ui_node::*, widget::*, Interaction, UiScale,
+        camera_config::*, geometry::*, node_bundles::*, ui_node::*, widget::*, Focus, Focusable,
+        Interaction, ScrollInertia, ScrollPosition, UiScale,
     };
 }
 
@@ -80,6 +85,9 @@ impl Plugin for UiPlugin {
             .init_resource::<FlexSurface>()
             .init_resource::<UiScale>()
             .init_resource::<UiStack>()
+            .init_resource::<Focus>()
+            .add_event::<FocusEntered>()
+            .add_event::<FocusExited>()
             .register_type::<AlignContent>()
             .register_type::<AlignItems>()
             .register_type::<AlignSelf>()
@@ -89,13 +97,27 @@ impl Plugin for UiPlugin {
             .register_type::<FlexDirection>()
             .register_type::<FlexWrap>()
             .register_type::<FocusPolicy>()
+            .register_type::<Focusable>()
+            .register_type::<GridAutoFlow>()
+            .register_type::<GridPlacement>()
+            .register_type::<GridTrack>()
+            .register_type::<GridTrackRepetition>()
+            .register_type::<Vec<GridTrack>>()
+            .register_type::<Vec<RepeatedGridTrack>>()
             .register_type::<Interaction>()
             .register_type::<JustifyContent>()
+            .register_type::<MaxTrackSizingFunction>()
+            .register_type::<MinTrackSizingFunction>()
             .register_type::<Node>()
             // NOTE: used by Style::aspect_ratio
             .register_type::<Option<f32>>()
+            .register_type::<Option<i16>>()
+            .register_type::<Option<u16>>()
             .register_type::<Overflow>()
             .register_type::<PositionType>()
+            .register_type::<RepeatedGridTrack>()
+            .register_type::<ScrollInertia>()
+            .register_type::<ScrollPosition>()
             .register_type::<Size>()
             .register_type::<UiRect>()
             .register_type::<Style>()
@@ -107,7 +129,15 @@ impl Plugin for UiPlugin {
             .configure_set(UiSystem::Focus.in_base_set(CoreSet::PreUpdate))
             .configure_set(UiSystem::Flex.in_base_set(CoreSet::PostUpdate))
             .configure_set(UiSystem::Stack.in_base_set(CoreSet::PostUpdate))
-            .add_system(ui_focus_system.in_set(UiSystem::Focus).after(InputSystem));
+            .add_systems(
+                (
+                    ui_scroll_system,
+                    ui_navigation_system.before(ui_focus_system),
+                    ui_focus_system,
+                )
+                    .in_set(UiSystem::Focus)
+                    .after(InputSystem),
+            );
         // add these systems to front because these must run before transform update systems
         #[cfg(feature = "bevy_text")]
         app.add_system(
@@ -0,0 +1,373 @@
+use crate::{Interaction, Node};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_input::{
+    gamepad::{GamepadButton, GamepadButtonType, Gamepads},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    touch::Touches,
+    Input,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::view::ComputedVisibility;
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::FloatOrd;
+
+/// Marks a UI node that can be focused with keyboard and gamepad navigation.
+///
+/// The arrow keys and the d-pad move the [`Focus`] to the closest focusable node in that
+/// direction, while <kbd>Tab</kbd> and <kbd>Shift</kbd>+<kbd>Tab</kbd> cycle through the focusable
+/// nodes in reading order. Clicking a focusable node also focuses it, without showing the focus.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct Focusable;
+
+/// The [`Focusable`] node that keyboard and gamepad input is directed at.
+///
+/// The focused node is [`Interaction::Clicked`] while <kbd>Enter</kbd>, <kbd>Space</kbd> or the
+/// south gamepad button is held. While the focus is [visible](Focus::is_visible), the focused node
+/// is also treated as hovered by [`ui_focus_system`](crate::ui_focus_system).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Focus {
+    entity: Option<Entity>,
+    visible: bool,
+}
+
+impl Focus {
+    /// Returns the focused entity.
+    pub fn get(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Returns whether the focus is shown, like `:focus-visible` in CSS.
+    ///
+    /// The focus is shown after keyboard or gamepad input, and hidden after focusing a node by
+    /// clicking it, so that mouse users don't see the clicked node stuck in a hovered state.
+    pub fn is_visible(&self) -> bool {
+        self.entity.is_some() && self.visible
+    }
+
+    /// Focuses the given entity, which should be [`Focusable`], and shows the focus.
+    pub fn set(&mut self, entity: Entity) {
+        self.entity = Some(entity);
+        self.visible = true;
+    }
+
+    /// Removes the focus from the focused entity.
+    pub fn clear(&mut self) {
+        self.entity = None;
+        self.visible = false;
+    }
+}
+
+/// An event sent when an entity gains the [`Focus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusEntered {
+    /// The newly focused entity.
+    pub entity: Entity,
+}
+
+/// An event sent when an entity loses the [`Focus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusExited {
+    /// The entity that was focused before.
+    pub entity: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NavigationDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+    Next,
+    Previous,
+}
+
+/// The state of [`ui_navigation_system`].
+#[derive(Default)]
+pub struct NavigationState {
+    focused: Option<Entity>,
+    pressed: Option<Entity>,
+}
+
+/// The system that moves the [`Focus`] between [`Focusable`] nodes and sends [`FocusEntered`] and
+/// [`FocusExited`] events.
+#[allow(clippy::too_many_arguments)]
+pub fn ui_navigation_system(
+    mut state: Local<NavigationState>,
+    mut focus: ResMut<Focus>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    touches_input: Res<Touches>,
+    focusable_query: Query<
+        (Entity, &Node, &GlobalTransform, Option<&ComputedVisibility>),
+        With<Focusable>,
+    >,
+    mut interaction_query: Query<(Entity, &mut Interaction), With<Focusable>>,
+    mut focus_entered: EventWriter<FocusEntered>,
+    mut focus_exited: EventWriter<FocusExited>,
+) {
+    // Pointer input hides the focus, and clicking a focusable node focuses it
+    if mouse_button_input.just_pressed(MouseButton::Left) || touches_input.any_just_pressed() {
+        focus.visible = false;
+    }
+    for (entity, interaction) in &mut interaction_query {
+        if interaction.is_changed()
+            && *interaction == Interaction::Clicked
+            && state.pressed != Some(entity)
+        {
+            focus.entity = Some(entity);
+        }
+    }
+
+    let mut nodes = focusable_query
+        .iter()
+        .filter(|(_, node, _, computed_visibility)| {
+            if let Some(computed_visibility) = computed_visibility {
+                if !computed_visibility.is_visible() {
+                    return false;
+                }
+            }
+            node.size() != Vec2::ZERO
+        })
+        .map(|(entity, node, transform, _)| {
+            (
+                entity,
+                Rect::from_center_size(transform.translation().truncate(), node.size()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Hidden and removed nodes can't stay focused
+    if let Some(focused) = focus.get() {
+        if !nodes.iter().any(|(entity, _)| *entity == focused) {
+            focus.clear();
+        }
+    }
+
+    let just_pressed = |key: KeyCode, button: GamepadButtonType| {
+        keyboard_input.just_pressed(key)
+            || gamepads
+                .iter()
+                .any(|gamepad| gamepad_input.just_pressed(GamepadButton::new(gamepad, button)))
+    };
+    let direction = if just_pressed(KeyCode::Up, GamepadButtonType::DPadUp) {
+        Some(NavigationDirection::Up)
+    } else if just_pressed(KeyCode::Down, GamepadButtonType::DPadDown) {
+        Some(NavigationDirection::Down)
+    } else if just_pressed(KeyCode::Left, GamepadButtonType::DPadLeft) {
+        Some(NavigationDirection::Left)
+    } else if just_pressed(KeyCode::Right, GamepadButtonType::DPadRight) {
+        Some(NavigationDirection::Right)
+    } else if keyboard_input.just_pressed(KeyCode::Tab) {
+        if keyboard_input.any_pressed([KeyCode::LShift, KeyCode::RShift]) {
+            Some(NavigationDirection::Previous)
+        } else {
+            Some(NavigationDirection::Next)
+        }
+    } else {
+        None
+    };
+    if let Some(direction) = direction {
+        if let Some(entity) = navigate(focus.get(), direction, &mut nodes) {
+            focus.set(entity);
+        }
+    }
+
+    // Activating the focused node clicks it
+    let activate_keys = [KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space];
+    let activate_pressed = keyboard_input.any_just_pressed(activate_keys)
+        || gamepads.iter().any(|gamepad| {
+            gamepad_input.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::South))
+        });
+    let activate_held = keyboard_input.any_pressed(activate_keys)
+        || gamepads.iter().any(|gamepad| {
+            gamepad_input.pressed(GamepadButton::new(gamepad, GamepadButtonType::South))
+        });
+    if let Some(pressed) = state.pressed {
+        if !activate_held || focus.get() != Some(pressed) {
+            if let Ok((_, mut interaction)) = interaction_query.get_mut(pressed) {
+                if *interaction == Interaction::Clicked {
+                    *interaction = Interaction::None;
+                }
+            }
+            state.pressed = None;
+        }
+    }
+    if activate_pressed {
+        if let Some(focused) = focus.get() {
+            focus.visible = true;
+            if let Ok((_, mut interaction)) = interaction_query.get_mut(focused) {
+                interaction.set_if_neq(Interaction::Clicked);
+                state.pressed = Some(focused);
+            }
+        }
+    }
+
+    if state.focused != focus.get() {
+        if let Some(entity) = state.focused {
+            focus_exited.send(FocusExited { entity });
+        }
+        if let Some(entity) = focus.get() {
+            focus_entered.send(FocusEntered { entity });
+        }
+        state.focused = focus.get();
+    }
+}
+
+/// Finds the node to focus when navigating from the focused node in the given direction.
+fn navigate(
+    focused: Option<Entity>,
+    direction: NavigationDirection,
+    nodes: &mut [(Entity, Rect)],
+) -> Option<Entity> {
+    // Reading order, top to bottom and left to right
+    nodes.sort_by_key(|(_, rect)| (FloatOrd(rect.min.y), FloatOrd(rect.min.x)));
+    let Some(index) =
+        focused.and_then(|focused| nodes.iter().position(|(entity, _)| *entity == focused))
+    else {
+        return nodes.first().map(|(entity, _)| *entity);
+    };
+
+    let axis = match direction {
+        NavigationDirection::Next => return Some(nodes[(index + 1) % nodes.len()].0),
+        NavigationDirection::Previous => {
+            return Some(nodes[(index + nodes.len() - 1) % nodes.len()].0)
+        }
+        // The y axis of the UI points down
+        NavigationDirection::Up => Vec2::NEG_Y,
+        NavigationDirection::Down => Vec2::Y,
+        NavigationDirection::Left => Vec2::NEG_X,
+        NavigationDirection::Right => Vec2::X,
+    };
+    let origin = nodes[index].1.center();
+    nodes
+        .iter()
+        .filter_map(|(entity, rect)| {
+            let offset = rect.center() - origin;
+            let along = offset.dot(axis);
+            // Prefer nodes in line with the focused node over closer nodes off to the side
+            (along > 0.0).then(|| (*entity, along + 2.0 * offset.perp_dot(axis).abs()))
+        })
+        .min_by_key(|(_, distance)| FloatOrd(*distance))
+        .map(|(entity, _)| entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+
+    fn setup() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Focus>()
+            .init_resource::<Input<KeyCode>>()
+            .init_resource::<Gamepads>()
+            .init_resource::<Input<GamepadButton>>()
+            .init_resource::<Input<MouseButton>>()
+            .init_resource::<Touches>()
+            .add_event::<FocusEntered>()
+            .add_event::<FocusExited>()
+            .add_system(ui_navigation_system);
+        let entity = app
+            .world
+            .spawn((
+                Focusable,
+                Node {
+                    calculated_size: Vec2::splat(10.0),
+                },
+                GlobalTransform::default(),
+                Interaction::None,
+            ))
+            .id();
+        (app, entity)
+    }
+
+    #[test]
+    fn clicking_hides_the_focus() {
+        let (mut app, entity) = setup();
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .press(MouseButton::Left);
+        *app.world.get_mut::<Interaction>(entity).unwrap() = Interaction::Clicked;
+        app.update();
+
+        let focus = *app.world.resource::<Focus>();
+        assert_eq!(focus.get(), Some(entity));
+        assert!(!focus.is_visible());
+    }
+
+    #[test]
+    fn navigating_shows_the_focus() {
+        let (mut app, entity) = setup();
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Tab);
+        app.update();
+
+        let focus = *app.world.resource::<Focus>();
+        assert_eq!(focus.get(), Some(entity));
+        assert!(focus.is_visible());
+
+        // Pointer input hides the focus again, but keeps the node focused
+        let mut keyboard_input = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard_input.release(KeyCode::Tab);
+        keyboard_input.clear();
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        let focus = *app.world.resource::<Focus>();
+        assert_eq!(focus.get(), Some(entity));
+        assert!(!focus.is_visible());
+    }
+
+    #[test]
+    fn navigate_between_nodes() {
+        // A B
+        //  C
+        let [a, b, c] = [0, 1, 2].map(Entity::from_raw);
+        let mut nodes = [
+            (c, Rect::new(50.0, 100.0, 150.0, 150.0)),
+            (b, Rect::new(100.0, 0.0, 200.0, 50.0)),
+            (a, Rect::new(0.0, 0.0, 100.0, 50.0)),
+        ];
+
+        assert_eq!(
+            navigate(None, NavigationDirection::Down, &mut nodes),
+            Some(a)
+        );
+        assert_eq!(
+            navigate(Some(a), NavigationDirection::Right, &mut nodes),
+            Some(b)
+        );
+        assert_eq!(
+            navigate(Some(a), NavigationDirection::Left, &mut nodes),
+            None
+        );
+        assert_eq!(
+            navigate(Some(b), NavigationDirection::Down, &mut nodes),
+            Some(c)
+        );
+        assert_eq!(
+            navigate(Some(c), NavigationDirection::Up, &mut nodes),
+            Some(a)
+        );
+        assert_eq!(
+            navigate(Some(b), NavigationDirection::Next, &mut nodes),
+            Some(c)
+        );
+        assert_eq!(
+            navigate(Some(c), NavigationDirection::Next, &mut nodes),
+            Some(a)
+        );
+        assert_eq!(
+            navigate(Some(a), NavigationDirection::Previous, &mut nodes),
+            Some(c)
+        );
+    }
+}
@@ -12,7 +12,7 @@ use crate::{prelude::UiCameraConfig, BackgroundColor, CalculatedClip, Node, UiIm
 use bevy_app::prelude::*;
 use bevy_asset::{load_internal_asset, AssetEvent, Assets, Handle, HandleUntyped};
 use bevy_ecs::prelude::*;
-use bevy_math::{Mat4, Rect, UVec4, Vec2, Vec3, Vec4Swizzles};
+use bevy_math::{Mat4, Quat, Rect, UVec4, Vec2, Vec3, Vec4Swizzles};
 use bevy_reflect::TypeUuid;
 use bevy_render::texture::DEFAULT_IMAGE_HANDLE;
 use bevy_render::{
@@ -27,9 +27,9 @@ use bevy_render::{
     view::{ComputedVisibility, ExtractedView, ViewUniforms},
     Extract, RenderApp, RenderSet,
 };
-use bevy_sprite::SpriteAssetEvents;
 #[cfg(feature = "bevy_text")]
 use bevy_sprite::TextureAtlas;
+use bevy_sprite::{ImageScaleMode, SpriteAssetEvents};
 #[cfg(feature = "bevy_text")]
 use bevy_text::{PositionedGlyph, Text, TextLayoutInfo};
 use bevy_transform::components::GlobalTransform;
@@ -194,6 +194,7 @@ pub fn extract_uinodes(
             &GlobalTransform,
             &BackgroundColor,
             Option<&UiImage>,
+            Option<&ImageScaleMode>,
             &ComputedVisibility,
             Option<&CalculatedClip>,
         )>,
@@ -201,7 +202,7 @@ pub fn extract_uinodes(
 ) {
     extracted_uinodes.uinodes.clear();
     for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
-        if let Ok((uinode, transform, color, maybe_image, visibility, clip)) =
+        if let Ok((uinode, transform, color, maybe_image, scale_mode, visibility, clip)) =
             uinode_query.get(*entity)
         {
             // Skip invisible and completely transparent nodes
@@ -219,6 +220,47 @@ pub fn extract_uinodes(
                 (DEFAULT_IMAGE_HANDLE.typed().clone_weak(), false, false)
             };
 
+            // Draw sliced and tiled images as one quad per slice
+            let image_size = maybe_image.and_then(|image| images.get(&image.texture));
+            if let (Some(scale_mode), Some(image_size)) = (
+                scale_mode.filter(|mode| **mode != ImageScaleMode::Stretched),
+                image_size.map(|image| image.size()),
+            ) {
+                let image_rect = Rect {
+                    min: Vec2::ZERO,
+                    max: image_size,
+                };
+                let transform = transform.compute_matrix();
+                for slice in scale_mode.compute_slices(image_rect, uinode.calculated_size) {
+                    // The slice offsets point up, the y axis of the UI points down
+                    let mut offset = slice.offset * Vec2::new(1.0, -1.0);
+                    if flip_x {
+                        offset.x = -offset.x;
+                    }
+                    if flip_y {
+                        offset.y = -offset.y;
+                    }
+                    let scale = slice.draw_size / slice.texture_rect.size();
+                    extracted_uinodes.uinodes.push(ExtractedUiNode {
+                        stack_index,
+                        transform: transform
+                            * Mat4::from_scale_rotation_translation(
+                                scale.extend(1.0),
+                                Quat::IDENTITY,
+                                offset.extend(0.0),
+                            ),
+                        color: color.0,
+                        rect: slice.texture_rect,
+                        image: image.clone_weak(),
+                        atlas_size: Some(image_size),
+                        clip: clip.map(|clip| clip.clip),
+                        flip_x,
+                        flip_y,
+                    });
+                }
+                continue;
+            }
+
             extracted_uinodes.uinodes.push(ExtractedUiNode {
                 stack_index,
                 transform: transform.compute_matrix(),
@@ -493,25 +535,12 @@ pub fn prepare_uinodes(
             [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]
         } else {
             let atlas_extent = extracted_uinode.atlas_size.unwrap_or(uinode_rect.max);
-            let mut uvs = [
-                Vec2::new(
-                    uinode_rect.min.x + positions_diff[0].x,
-                    uinode_rect.min.y + positions_diff[0].y,
-                ),
-                Vec2::new(
-                    uinode_rect.max.x + positions_diff[1].x,
-                    uinode_rect.min.y + positions_diff[1].y,
-                ),
-                Vec2::new(
-                    uinode_rect.max.x + positions_diff[2].x,
-                    uinode_rect.max.y + positions_diff[2].y,
-                ),
-                Vec2::new(
-                    uinode_rect.min.x + positions_diff[3].x,
-                    uinode_rect.max.y + positions_diff[3].y,
-                ),
-            ]
-            .map(|pos| pos / atlas_extent);
+            let mut uvs = clipped_uvs(
+                uinode_rect,
+                atlas_extent,
+                &extracted_uinode.transform,
+                positions_diff,
+            );
 
             if extracted_uinode.flip_x {
                 uvs = [uvs[1], uvs[0], uvs[3], uvs[2]];
@@ -547,6 +576,42 @@ pub fn prepare_uinodes(
     ui_meta.vertices.write_buffer(&render_device, &render_queue);
 }
 
+/// Computes the texture coordinates of the corners of `rect` after moving them by the clipped
+/// distances `positions_diff`.
+///
+/// The clipped distances are in logical pixels and are scaled back to texture pixels for nodes
+/// drawn larger or smaller than their texture rect, like image slices and the glyphs of text
+/// rendered with a scale factor other than `1.0`.
+fn clipped_uvs(
+    rect: Rect,
+    atlas_extent: Vec2,
+    transform: &Mat4,
+    positions_diff: [Vec2; 4],
+) -> [Vec2; 4] {
+    let scale = Vec2::new(transform.x_axis.length(), transform.y_axis.length());
+    let scale = Vec2::select(scale.cmpeq(Vec2::ZERO), Vec2::ONE, scale);
+    let positions_diff = positions_diff.map(|diff| diff / scale);
+    [
+        Vec2::new(
+            rect.min.x + positions_diff[0].x,
+            rect.min.y + positions_diff[0].y,
+        ),
+        Vec2::new(
+            rect.max.x + positions_diff[1].x,
+            rect.min.y + positions_diff[1].y,
+        ),
+        Vec2::new(
+            rect.max.x + positions_diff[2].x,
+            rect.max.y + positions_diff[2].y,
+        ),
+        Vec2::new(
+            rect.min.x + positions_diff[3].x,
+            rect.max.y + positions_diff[3].y,
+        ),
+    ]
+    .map(|pos| pos / atlas_extent)
+}
+
 #[derive(Resource, Default)]
 pub struct UiImageBindGroups {
     pub values: HashMap<Handle<Image>, BindGroup>,
@@ -570,7 +635,9 @@ pub fn queue_uinodes(
     // If an image has changed, the GpuImage has (probably) changed
     for event in &events.images {
         match event {
-            AssetEvent::Created { .. } => None,
+            AssetEvent::Created { .. }
+            | AssetEvent::Evicted { .. }
+            | AssetEvent::DependencyModified { .. } => None,
             AssetEvent::Modified { handle } | AssetEvent::Removed { handle } => {
                 image_bind_groups.values.remove(handle)
             }
@@ -624,3 +691,50 @@ pub fn queue_uinodes(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::clipped_uvs;
+    use bevy_math::{Mat4, Rect, Vec2, Vec3};
+
+    fn clip_left(distance: f32) -> [Vec2; 4] {
+        [
+            Vec2::new(distance, 0.0),
+            Vec2::ZERO,
+            Vec2::ZERO,
+            Vec2::new(distance, 0.0),
+        ]
+    }
+
+    #[test]
+    fn clipped_uvs_of_unscaled_nodes() {
+        let rect = Rect::new(0.0, 0.0, 20.0, 20.0);
+        let uvs = clipped_uvs(rect, Vec2::splat(100.0), &Mat4::IDENTITY, clip_left(5.0));
+        assert_eq!(uvs[0], Vec2::new(0.05, 0.0));
+        assert_eq!(uvs[1], Vec2::new(0.2, 0.0));
+        assert_eq!(uvs[3], Vec2::new(0.05, 0.2));
+    }
+
+    #[test]
+    fn clipped_text_uvs_follow_the_scale_factor() {
+        // Glyphs are rasterized in physical pixels and drawn scaled by the inverse of the scale
+        // factor, so 5 clipped logical pixels cover 10 pixels of the glyph texture.
+        let rect = Rect::new(0.0, 0.0, 20.0, 20.0);
+        let transform = Mat4::from_scale(Vec3::splat(0.5));
+        let uvs = clipped_uvs(rect, Vec2::splat(100.0), &transform, clip_left(5.0));
+        assert_eq!(uvs[0], Vec2::new(0.1, 0.0));
+        assert_eq!(uvs[1], Vec2::new(0.2, 0.0));
+        assert_eq!(uvs[3], Vec2::new(0.1, 0.2));
+    }
+
+    #[test]
+    fn clipped_uvs_of_stretched_slices() {
+        // A 10 pixel wide slice drawn 40 pixels wide loses a quarter of its texture rect when
+        // 10 of its logical pixels are clipped.
+        let rect = Rect::new(10.0, 0.0, 20.0, 10.0);
+        let transform = Mat4::from_scale(Vec3::new(4.0, 1.0, 1.0));
+        let uvs = clipped_uvs(rect, Vec2::splat(100.0), &transform, clip_left(10.0));
+        assert_eq!(uvs[0], Vec2::new(0.125, 0.0));
+        assert_eq!(uvs[1], Vec2::new(0.2, 0.0));
+    }
+}
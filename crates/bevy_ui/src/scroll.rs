@@ -0,0 +1,351 @@
+use crate::{CalculatedClip, Node, Overflow, Style, UiStack};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_input::{
+    mouse::{MouseButton, MouseScrollUnit, MouseWheel},
+    touch::Touches,
+    Input,
+};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{PrimaryWindow, Window};
+
+/// The number of logical pixels scrolled per line of a [`MouseScrollUnit::Line`] mouse wheel
+/// event.
+const LINE_HEIGHT: f32 = 20.0;
+
+/// The speed below which [`ScrollInertia`] stops scrolling, in logical pixels per second.
+const MIN_INERTIA_SPEED: f32 = 1.0;
+
+/// How far the content of a node with [`Overflow::Scroll`] is scrolled, in logical pixels.
+///
+/// The children of the node are moved up and left by the offset. The offset is clamped so that
+/// the content can't be scrolled further than its end, and can be scrolled with the mouse wheel
+/// and by dragging with the mouse or touch input.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScrollPosition {
+    /// The current scroll offset.
+    pub offset: Vec2,
+    max_offset: Vec2,
+}
+
+impl ScrollPosition {
+    /// Creates a [`ScrollPosition`] scrolled to the given offset.
+    pub const fn new(offset: Vec2) -> Self {
+        Self {
+            offset,
+            max_offset: Vec2::ZERO,
+        }
+    }
+
+    /// Returns the largest offset the content can be scrolled to, as computed by the last layout.
+    pub fn max_offset(&self) -> Vec2 {
+        self.max_offset
+    }
+
+    /// Updates the largest offset and clamps the offset to it.
+    pub(crate) fn set_max_offset(&mut self, max_offset: Vec2) {
+        self.max_offset = max_offset;
+        self.offset = self.offset.clamp(Vec2::ZERO, max_offset);
+    }
+
+    fn scroll_by(&mut self, delta: Vec2) {
+        self.offset = (self.offset + delta).clamp(Vec2::ZERO, self.max_offset);
+    }
+}
+
+/// Makes a [`ScrollPosition`] keep scrolling after a drag is released, slowing down over time.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScrollInertia {
+    /// How quickly the scrolling slows down, as the fraction of the velocity lost per second on
+    /// an exponential scale. Higher values stop sooner.
+    pub damping: f32,
+    /// The current scroll velocity, in logical pixels per second.
+    pub velocity: Vec2,
+}
+
+impl Default for ScrollInertia {
+    fn default() -> Self {
+        Self {
+            damping: 5.0,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+/// The scroll node being dragged.
+#[derive(Default)]
+pub struct ScrollDrag {
+    entity: Option<Entity>,
+    last_position: Vec2,
+    velocity: Vec2,
+}
+
+/// The system that scrolls [`ScrollPosition`]s with mouse wheel, mouse drag and touch input, and
+/// applies [`ScrollInertia`].
+#[allow(clippy::too_many_arguments)]
+pub fn ui_scroll_system(
+    mut drag: Local<ScrollDrag>,
+    time: Res<Time>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    touches_input: Res<Touches>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    ui_stack: Res<UiStack>,
+    node_query: Query<(&Node, &GlobalTransform, &Style, Option<&CalculatedClip>)>,
+    mut scroll_query: Query<(&mut ScrollPosition, Option<&mut ScrollInertia>)>,
+) {
+    let cursor_position = primary_window
+        .get_single()
+        .ok()
+        .and_then(|window| {
+            window.cursor_position().map(|mut cursor_position| {
+                cursor_position.y = window.height() - cursor_position.y;
+                cursor_position
+            })
+        })
+        .or_else(|| touches_input.first_pressed_position());
+
+    // The topmost scrollable node below the cursor.
+    let hovered = cursor_position.and_then(|cursor_position| {
+        ui_stack.uinodes.iter().rev().copied().find(|&entity| {
+            let Ok((node, global_transform, style, clip)) = node_query.get(entity) else {
+                return false;
+            };
+            if style.overflow != Overflow::Scroll || !scroll_query.contains(entity) {
+                return false;
+            }
+            let center = global_transform.translation().truncate();
+            let mut min = center - node.size() / 2.0;
+            let mut max = center + node.size() / 2.0;
+            if let Some(clip) = clip {
+                min = min.max(clip.clip.min);
+                max = max.min(clip.clip.max);
+            }
+            (min.x..max.x).contains(&cursor_position.x)
+                && (min.y..max.y).contains(&cursor_position.y)
+        })
+    });
+
+    let wheel_delta = mouse_wheel_events
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y) * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+        })
+        .sum::<Vec2>();
+    if let Some(entity) = hovered.filter(|_| wheel_delta != Vec2::ZERO) {
+        let (mut scroll_position, inertia) = scroll_query.get_mut(entity).unwrap();
+        scroll_position.scroll_by(-wheel_delta);
+        if let Some(mut inertia) = inertia {
+            inertia.velocity = Vec2::ZERO;
+        }
+    }
+
+    let delta_seconds = time.delta_seconds();
+    let pressed = mouse_button_input.pressed(MouseButton::Left) || touches_input.iter().count() > 0;
+    match (drag.entity, cursor_position) {
+        (Some(entity), Some(cursor_position)) if pressed => {
+            let delta = cursor_position - drag.last_position;
+            drag.last_position = cursor_position;
+            if delta != Vec2::ZERO {
+                if let Ok((mut scroll_position, _)) = scroll_query.get_mut(entity) {
+                    scroll_position.scroll_by(-delta);
+                }
+            }
+            if delta_seconds > 0.0 {
+                drag.velocity = -delta / delta_seconds;
+            }
+        }
+        (Some(entity), _) => {
+            // The drag was released, keep scrolling at the speed of the drag.
+            if let Ok((_, Some(mut inertia))) = scroll_query.get_mut(entity) {
+                inertia.velocity = drag.velocity;
+            }
+            *drag = ScrollDrag::default();
+        }
+        (None, Some(cursor_position)) => {
+            let just_pressed = mouse_button_input.just_pressed(MouseButton::Left)
+                || touches_input.any_just_pressed();
+            if let Some(entity) = hovered.filter(|_| just_pressed) {
+                if let Ok((_, Some(mut inertia))) = scroll_query.get_mut(entity) {
+                    inertia.velocity = Vec2::ZERO;
+                }
+                *drag = ScrollDrag {
+                    entity: Some(entity),
+                    last_position: cursor_position,
+                    velocity: Vec2::ZERO,
+                };
+            }
+        }
+        (None, None) => {}
+    }
+
+    for (mut scroll_position, inertia) in &mut scroll_query {
+        let Some(mut inertia) = inertia else {
+            continue;
+        };
+        if inertia.velocity == Vec2::ZERO {
+            continue;
+        }
+        let previous_offset = scroll_position.offset;
+        scroll_position.scroll_by(inertia.velocity * delta_seconds);
+        let velocity = inertia.velocity * (-inertia.damping * delta_seconds).exp();
+        // Stop at the ends of the content and once it's too slow to notice.
+        inertia.velocity = Vec2::select(
+            (scroll_position.offset - previous_offset).cmpeq(Vec2::ZERO)
+                | velocity.abs().cmplt(Vec2::splat(MIN_INERTIA_SPEED)),
+            Vec2::ZERO,
+            velocity,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::App;
+    use bevy_utils::{default, Duration};
+
+    /// Creates an app running [`ui_scroll_system`] with a scroll node below the cursor, which
+    /// can be scrolled up to `max_offset`.
+    fn scroll_app(max_offset: Vec2, inertia: ScrollInertia) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_event::<MouseWheel>()
+            .init_resource::<Input<MouseButton>>()
+            .init_resource::<Touches>()
+            .add_system(ui_scroll_system);
+
+        let mut time = Time::default();
+        time.update();
+        app.insert_resource(time);
+
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::new(640.0, 360.0)));
+        app.world.spawn((window, PrimaryWindow));
+
+        let mut scroll_position = ScrollPosition::default();
+        scroll_position.set_max_offset(max_offset);
+        let entity = app
+            .world
+            .spawn((
+                Node {
+                    calculated_size: Vec2::splat(200.0),
+                },
+                GlobalTransform::from_xyz(640.0, 360.0, 0.0),
+                Style {
+                    overflow: Overflow::Scroll,
+                    ..default()
+                },
+                scroll_position,
+                inertia,
+            ))
+            .id();
+        app.insert_resource(UiStack {
+            uinodes: vec![entity],
+        });
+        (app, entity)
+    }
+
+    fn advance_time(app: &mut App, seconds: f32) {
+        let mut time = app.world.resource_mut::<Time>();
+        let last_update = time.last_update().unwrap();
+        time.update_with_instant(last_update + Duration::from_secs_f32(seconds));
+    }
+
+    #[test]
+    fn mouse_wheel_scrolls_the_hovered_node() {
+        let (mut app, entity) = scroll_app(Vec2::new(0.0, 100.0), default());
+
+        app.world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: -2.0,
+        });
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 2.0 * LINE_HEIGHT)
+        );
+
+        app.world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: -10.0,
+            y: -500.0,
+        });
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 100.0)
+        );
+
+        // The node isn't scrolled once the cursor leaves it.
+        app.world
+            .query::<&mut Window>()
+            .single_mut(&mut app.world)
+            .set_cursor_position(Some(Vec2::new(10.0, 10.0)));
+        app.world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 0.0,
+            y: 50.0,
+        });
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn inertia_stops_at_the_end_of_the_content() {
+        let (mut app, entity) = scroll_app(
+            Vec2::new(0.0, 150.0),
+            ScrollInertia {
+                damping: 0.0,
+                velocity: Vec2::new(0.0, 1000.0),
+            },
+        );
+
+        advance_time(&mut app, 0.1);
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 100.0)
+        );
+
+        // The offset is clamped to the end of the content...
+        advance_time(&mut app, 0.1);
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 150.0)
+        );
+
+        // ...where the inertia stops, as the content can't move any further.
+        advance_time(&mut app, 0.1);
+        app.update();
+        assert_eq!(
+            app.world.get::<ScrollPosition>(entity).unwrap().offset,
+            Vec2::new(0.0, 150.0)
+        );
+        assert_eq!(
+            app.world.get::<ScrollInertia>(entity).unwrap().velocity,
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn scroll_position_is_clamped_to_content() {
+        let mut scroll_position = ScrollPosition::new(Vec2::new(50.0, 300.0));
+        scroll_position.set_max_offset(Vec2::new(0.0, 200.0));
+        assert_eq!(scroll_position.offset, Vec2::new(0.0, 200.0));
+
+        scroll_position.scroll_by(Vec2::new(10.0, -50.0));
+        assert_eq!(scroll_position.offset, Vec2::new(0.0, 150.0));
+        scroll_position.scroll_by(Vec2::new(0.0, -500.0));
+        assert_eq!(scroll_position.offset, Vec2::ZERO);
+    }
+}
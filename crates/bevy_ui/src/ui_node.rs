@@ -222,11 +222,13 @@ impl Val {
 
 /// Describes the style of a UI node
 ///
-/// It uses the [Flexbox](https://cssreference.io/flexbox/) system.
+/// It uses the [Flexbox](https://cssreference.io/flexbox/) or the
+/// [CSS Grid](https://css-tricks.com/snippets/css/complete-guide-grid/) system, depending on
+/// [`Style::display`].
 #[derive(Component, Clone, PartialEq, Debug, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Style {
-    /// Whether to arrange this node and its children with flexbox layout
+    /// Whether to arrange the children of this node with flexbox or grid layout
     ///
     /// If this is set to [`Display::None`], this node will be collapsed.
     pub display: Display,
@@ -325,10 +327,36 @@ pub struct Style {
     pub aspect_ratio: Option<f32>,
     /// How to handle overflow
     pub overflow: Overflow,
-    /// The size of the gutters between the rows and columns of the flexbox layout
+    /// The size of the gutters between the rows and columns of the flexbox or grid layout
     ///
     /// A value of `Size::AUTO` is treated as zero.
     pub gap: Size,
+    /// How grid items without a [`Style::grid_row`] or [`Style::grid_column`] are placed
+    pub grid_auto_flow: GridAutoFlow,
+    /// The sizes of the rows of the grid, if this node uses [`Display::Grid`]
+    pub grid_template_rows: Vec<RepeatedGridTrack>,
+    /// The sizes of the columns of the grid, if this node uses [`Display::Grid`]
+    pub grid_template_columns: Vec<RepeatedGridTrack>,
+    /// The sizes of rows created implicitly, when items are placed outside of
+    /// [`Style::grid_template_rows`]
+    ///
+    /// The tracks are repeated as needed. An empty list is treated as a single `auto` track.
+    pub grid_auto_rows: Vec<GridTrack>,
+    /// The sizes of columns created implicitly, when items are placed outside of
+    /// [`Style::grid_template_columns`]
+    ///
+    /// The tracks are repeated as needed. An empty list is treated as a single `auto` track.
+    pub grid_auto_columns: Vec<GridTrack>,
+    /// The rows this node occupies in the grid of its parent
+    ///
+    /// Together with [`Style::grid_column`], this defines the grid area of the node.
+    #[doc(alias = "grid_area")]
+    pub grid_row: GridPlacement,
+    /// The columns this node occupies in the grid of its parent
+    ///
+    /// Together with [`Style::grid_row`], this defines the grid area of the node.
+    #[doc(alias = "grid_area")]
+    pub grid_column: GridPlacement,
 }
 
 impl Style {
@@ -358,6 +386,13 @@ impl Style {
         aspect_ratio: None,
         overflow: Overflow::DEFAULT,
         gap: Size::AUTO,
+        grid_auto_flow: GridAutoFlow::DEFAULT,
+        grid_template_rows: Vec::new(),
+        grid_template_columns: Vec::new(),
+        grid_auto_rows: Vec::new(),
+        grid_auto_columns: Vec::new(),
+        grid_row: GridPlacement::DEFAULT,
+        grid_column: GridPlacement::DEFAULT,
     };
 }
 
@@ -497,14 +532,16 @@ impl Default for Direction {
     }
 }
 
-/// Whether to use a Flexbox layout model.
+/// Whether to use a Flexbox or CSS Grid layout model.
 ///
 /// Part of the [`Style`] component.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(PartialEq, Serialize, Deserialize)]
 pub enum Display {
-    /// Use Flexbox layout model to determine the position of this [`Node`].
+    /// Use Flexbox layout model to determine the position of the children of this [`Node`].
     Flex,
+    /// Use CSS Grid layout model to determine the position of the children of this [`Node`].
+    Grid,
     /// Use no layout, don't render this node and its children.
     ///
     /// If you want to hide a node and its children,
@@ -522,6 +559,297 @@ impl Default for Display {
     }
 }
 
+/// How grid items without an explicit position are placed in the grid.
+///
+/// Part of the [`Style`] component.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum GridAutoFlow {
+    /// Fill each row in turn, adding new rows as necessary.
+    Row,
+    /// Fill each column in turn, adding new columns as necessary.
+    Column,
+    /// Like [`GridAutoFlow::Row`], but fills holes left by earlier items with later, smaller items.
+    RowDense,
+    /// Like [`GridAutoFlow::Column`], but fills holes left by earlier items with later, smaller items.
+    ColumnDense,
+}
+
+impl GridAutoFlow {
+    /// Fill rows first, see [`GridAutoFlow::Row`].
+    pub const DEFAULT: Self = Self::Row;
+}
+
+impl Default for GridAutoFlow {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The minimum size of a [`GridTrack`].
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum MinTrackSizingFunction {
+    /// A fixed size in logical pixels.
+    Px(f32),
+    /// A percentage of the size of the grid.
+    Percent(f32),
+    /// The smallest size that fits the content of the track.
+    MinContent,
+    /// The largest size the content of the track wants to have.
+    MaxContent,
+    /// Sized automatically, like [`MinTrackSizingFunction::MinContent`] in most cases.
+    Auto,
+}
+
+/// The maximum size of a [`GridTrack`].
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum MaxTrackSizingFunction {
+    /// A fixed size in logical pixels.
+    Px(f32),
+    /// A percentage of the size of the grid.
+    Percent(f32),
+    /// The smallest size that fits the content of the track.
+    MinContent,
+    /// The largest size the content of the track wants to have.
+    MaxContent,
+    /// Like [`MaxTrackSizingFunction::MaxContent`], but at most the given logical pixels.
+    FitContentPx(f32),
+    /// Like [`MaxTrackSizingFunction::MaxContent`], but at most the given percentage of the
+    /// size of the grid.
+    FitContentPercent(f32),
+    /// Sized automatically, like [`MaxTrackSizingFunction::MaxContent`] in most cases.
+    Auto,
+    /// A share of the space left in the grid, like the `fr` unit of CSS.
+    ///
+    /// Each track gets the fraction of its value relative to the sum of all fractions.
+    Fraction(f32),
+}
+
+/// The size of a row or column of a grid, which is between its minimum and maximum size
+/// depending on its content and the available space.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct GridTrack {
+    /// The size the track doesn't shrink below.
+    pub min_sizing_function: MinTrackSizingFunction,
+    /// The size the track doesn't grow beyond.
+    pub max_sizing_function: MaxTrackSizingFunction,
+}
+
+impl GridTrack {
+    /// A track sized automatically, see [`GridTrack::auto`].
+    pub const DEFAULT: Self = Self::auto();
+
+    /// A track sized between the given minimum and maximum, like `minmax()` in CSS.
+    pub const fn minmax(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> Self {
+        Self {
+            min_sizing_function: min,
+            max_sizing_function: max,
+        }
+    }
+
+    /// A track with a fixed size in logical pixels.
+    pub const fn px(value: f32) -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::Px(value),
+            MaxTrackSizingFunction::Px(value),
+        )
+    }
+
+    /// A track sized as a percentage of the size of the grid.
+    pub const fn percent(value: f32) -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::Percent(value),
+            MaxTrackSizingFunction::Percent(value),
+        )
+    }
+
+    /// A track taking a share of the space left in the grid, like `1fr` in CSS.
+    ///
+    /// The track doesn't shrink below the size of its content.
+    pub const fn fr(value: f32) -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::Auto,
+            MaxTrackSizingFunction::Fraction(value),
+        )
+    }
+
+    /// A track sized automatically.
+    pub const fn auto() -> Self {
+        Self::minmax(MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto)
+    }
+
+    /// A track as small as its content allows.
+    pub const fn min_content() -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::MinContent,
+            MaxTrackSizingFunction::MinContent,
+        )
+    }
+
+    /// A track as large as its content wants to be.
+    pub const fn max_content() -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::MaxContent,
+            MaxTrackSizingFunction::MaxContent,
+        )
+    }
+
+    /// A track as large as its content wants to be, but at most the given logical pixels.
+    pub const fn fit_content_px(limit: f32) -> Self {
+        Self::minmax(
+            MinTrackSizingFunction::Auto,
+            MaxTrackSizingFunction::FitContentPx(limit),
+        )
+    }
+}
+
+impl Default for GridTrack {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How often the tracks of a [`RepeatedGridTrack`] are repeated.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum GridTrackRepetition {
+    /// Repeat the tracks the given number of times.
+    Count(u16),
+    /// Repeat the tracks as often as they fit into the grid, like `auto-fill` in CSS.
+    ///
+    /// Only valid if all tracks have a fixed size.
+    AutoFill,
+    /// Like [`GridTrackRepetition::AutoFill`], but collapses empty tracks, like `auto-fit` in CSS.
+    AutoFit,
+}
+
+/// A list of [`GridTrack`]s which is repeated, like `repeat()` in CSS.
+///
+/// Part of [`Style::grid_template_rows`] and [`Style::grid_template_columns`].
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct RepeatedGridTrack {
+    /// How often the tracks are repeated.
+    pub repetition: GridTrackRepetition,
+    /// The tracks, repeated in order.
+    pub tracks: Vec<GridTrack>,
+}
+
+impl RepeatedGridTrack {
+    /// Repeats the given tracks.
+    pub fn repeat(repetition: GridTrackRepetition, tracks: impl Into<Vec<GridTrack>>) -> Self {
+        Self {
+            repetition,
+            tracks: tracks.into(),
+        }
+    }
+
+    /// `count` tracks with a fixed size in logical pixels.
+    pub fn px(count: u16, value: f32) -> Self {
+        Self::repeat(GridTrackRepetition::Count(count), [GridTrack::px(value)])
+    }
+
+    /// `count` tracks sized as a percentage of the size of the grid.
+    pub fn percent(count: u16, value: f32) -> Self {
+        Self::repeat(
+            GridTrackRepetition::Count(count),
+            [GridTrack::percent(value)],
+        )
+    }
+
+    /// `count` tracks taking a share of the space left in the grid.
+    pub fn fr(count: u16, value: f32) -> Self {
+        Self::repeat(GridTrackRepetition::Count(count), [GridTrack::fr(value)])
+    }
+
+    /// `count` tracks sized automatically.
+    pub fn auto(count: u16) -> Self {
+        Self::repeat(GridTrackRepetition::Count(count), [GridTrack::auto()])
+    }
+}
+
+impl From<GridTrack> for RepeatedGridTrack {
+    fn from(track: GridTrack) -> Self {
+        Self::repeat(GridTrackRepetition::Count(1), [track])
+    }
+}
+
+/// The lines a node occupies in one axis of the grid of its parent.
+///
+/// Grid lines are numbered from `1`, negative numbers count from the last line. Without a
+/// start or end, the node is placed automatically according to [`Style::grid_auto_flow`].
+///
+/// Part of the [`Style`] component.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct GridPlacement {
+    /// The line the node starts at.
+    pub start: Option<i16>,
+    /// The number of tracks the node spans, ignored if both `start` and `end` are set.
+    pub span: Option<u16>,
+    /// The line the node ends at.
+    pub end: Option<i16>,
+}
+
+impl GridPlacement {
+    /// Places the node automatically, see [`GridPlacement::auto`].
+    pub const DEFAULT: Self = Self::auto();
+
+    /// Places the node automatically, spanning a single track.
+    pub const fn auto() -> Self {
+        Self {
+            start: None,
+            span: None,
+            end: None,
+        }
+    }
+
+    /// Places the node automatically, spanning `span` tracks.
+    pub const fn span(span: u16) -> Self {
+        Self {
+            start: None,
+            span: Some(span),
+            end: None,
+        }
+    }
+
+    /// Places the node at the track starting at line `start`.
+    pub const fn start(start: i16) -> Self {
+        Self {
+            start: Some(start),
+            span: None,
+            end: None,
+        }
+    }
+
+    /// Places the node from line `start` to line `end`.
+    pub const fn start_end(start: i16, end: i16) -> Self {
+        Self {
+            start: Some(start),
+            span: None,
+            end: Some(end),
+        }
+    }
+
+    /// Places the node from line `start`, spanning `span` tracks.
+    pub const fn start_span(start: i16, span: u16) -> Self {
+        Self {
+            start: Some(start),
+            span: Some(span),
+            end: None,
+        }
+    }
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Defines how flexbox items are ordered within a flexbox
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(PartialEq, Serialize, Deserialize)]
@@ -586,6 +914,11 @@ pub enum Overflow {
     Visible,
     /// Hide overflowing items.
     Hidden,
+    /// Hide overflowing items and allow scrolling to them.
+    ///
+    /// The children are offset by the [`ScrollPosition`](crate::ScrollPosition) of the node, if
+    /// it has one.
+    Scroll,
 }
 
 impl Overflow {
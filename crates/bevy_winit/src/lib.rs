@@ -13,18 +13,21 @@ use system::{changed_window, create_window, despawn_window, CachedWindow};
 pub use winit_config::*;
 pub use winit_windows::*;
 
-use bevy_app::{App, AppExit, CoreSet, Plugin};
+use bevy_app::{App, AppExit, AppWaker, CoreSet, Plugin};
 use bevy_ecs::event::{Events, ManualEventReader};
 use bevy_ecs::prelude::*;
 use bevy_input::{
-    keyboard::KeyboardInput,
-    mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    keyboard::{KeyCode, KeyboardInput},
+    mouse::{
+        MouseButton, MouseButtonInput, MouseMotion, MouseMotionSamples, MouseScrollUnit, MouseWheel,
+    },
     touch::TouchInput,
+    ButtonState,
 };
 use bevy_math::{ivec2, DVec2, Vec2};
 use bevy_utils::{
     tracing::{trace, warn},
-    Instant,
+    HashMap, Instant,
 };
 use bevy_window::{
     exit_on_all_closed, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime,
@@ -37,7 +40,7 @@ use bevy_window::{
 pub use winit::platform::android::activity::AndroidApp;
 
 use winit::{
-    event::{self, DeviceEvent, Event, StartCause, WindowEvent},
+    event::{self, DeviceEvent, DeviceId, Event, StartCause, WindowEvent},
     event_loop::{ControlFlow, EventLoop, EventLoopBuilder, EventLoopWindowTarget},
 };
 
@@ -257,6 +260,8 @@ struct WinitPersistentState {
     /// Tracks if the event loop was started this frame because of a `WaitUntil` timeout.
     timeout_reached: bool,
     last_update: Instant,
+    /// Tracks the keys and mouse buttons held down on each input device.
+    held_input: HashMap<DeviceId, HeldInput>,
 }
 impl Default for WinitPersistentState {
     fn default() -> Self {
@@ -266,10 +271,71 @@ impl Default for WinitPersistentState {
             redraw_request_sent: false,
             timeout_reached: false,
             last_update: Instant::now(),
+            held_input: HashMap::default(),
         }
     }
 }
 
+/// The keys and mouse buttons held down on a single input device.
+///
+/// Used to tell OS key repeats apart from new presses, and to release everything
+/// that is still held when the device goes away, so that no input gets stuck.
+#[derive(Default)]
+struct HeldInput {
+    keys: HashMap<u32, (Option<KeyCode>, Entity)>,
+    mouse_buttons: HashMap<MouseButton, Entity>,
+}
+
+impl HeldInput {
+    /// Records the keyboard event, returning `true` if it repeats the press of a held key.
+    fn update_key(&mut self, input: &KeyboardInput) -> bool {
+        match input.state {
+            ButtonState::Pressed => self
+                .keys
+                .insert(input.scan_code, (input.key_code, input.window))
+                .is_some(),
+            ButtonState::Released => {
+                self.keys.remove(&input.scan_code);
+                false
+            }
+        }
+    }
+
+    /// Records the mouse button event.
+    fn update_mouse_button(&mut self, input: &MouseButtonInput) {
+        match input.state {
+            ButtonState::Pressed => self.mouse_buttons.insert(input.button, input.window),
+            ButtonState::Released => self.mouse_buttons.remove(&input.button),
+        };
+    }
+
+    /// Sends release events for everything that is held down.
+    fn release(
+        self,
+        keyboard_input: &mut EventWriter<KeyboardInput>,
+        mouse_button_input: &mut EventWriter<MouseButtonInput>,
+    ) {
+        keyboard_input.send_batch(
+            self.keys
+                .into_iter()
+                .map(|(scan_code, (key_code, window))| KeyboardInput {
+                    scan_code,
+                    key_code,
+                    state: ButtonState::Released,
+                    repeat: false,
+                    window,
+                }),
+        );
+        mouse_button_input.send_batch(self.mouse_buttons.into_iter().map(|(button, window)| {
+            MouseButtonInput {
+                button,
+                state: ButtonState::Released,
+                window,
+            }
+        }));
+    }
+}
+
 pub fn winit_runner(mut app: App) {
     // We remove this so that we have ownership over it.
     let mut event_loop = app
@@ -278,11 +344,23 @@ pub fn winit_runner(mut app: App) {
         .unwrap();
 
     let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
+    let mut exiting = false;
     let mut redraw_event_reader = ManualEventReader::<RequestRedraw>::default();
     let mut winit_state = WinitPersistentState::default();
     app.world
         .insert_non_send_resource(event_loop.create_proxy());
 
+    // wake the event loop when events are sent with an `AppEventSender`
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let proxy = std::sync::Mutex::new(event_loop.create_proxy());
+        app.world
+            .get_resource_or_insert_with(AppWaker::default)
+            .set(move || {
+                let _ = proxy.lock().unwrap().send_event(());
+            });
+    }
+
     let return_from_run = app.world.resource::<WinitSettings>().return_from_run;
 
     trace!("Entering winit event loop");
@@ -319,11 +397,9 @@ pub fn winit_runner(mut app: App) {
         #[cfg(feature = "trace")]
         let _span = bevy_utils::tracing::info_span!("winit event_handler").entered();
 
-        if let Some(app_exit_events) = app.world.get_resource::<Events<AppExit>>() {
-            if app_exit_event_reader.iter(app_exit_events).last().is_some() {
-                *control_flow = ControlFlow::Exit;
-                return;
-            }
+        if exiting {
+            *control_flow = ControlFlow::Exit;
+            return;
         }
 
         match event {
@@ -416,10 +492,19 @@ pub fn winit_runner(mut app: App) {
                                 window: window_entity,
                             });
                     }
-                    WindowEvent::KeyboardInput { ref input, .. } => {
-                        input_events
-                            .keyboard_input
-                            .send(converters::convert_keyboard_input(input));
+                    WindowEvent::KeyboardInput {
+                        device_id,
+                        ref input,
+                        ..
+                    } => {
+                        let mut keyboard_input =
+                            converters::convert_keyboard_input(input, window_entity);
+                        keyboard_input.repeat = winit_state
+                            .held_input
+                            .entry(device_id)
+                            .or_default()
+                            .update_key(&keyboard_input);
+                        input_events.keyboard_input.send(keyboard_input);
                     }
                     WindowEvent::CursorMoved { position, .. } => {
                         let physical_position = DVec2::new(
@@ -448,11 +533,23 @@ pub fn winit_runner(mut app: App) {
                             window: window_entity,
                         });
                     }
-                    WindowEvent::MouseInput { state, button, .. } => {
-                        input_events.mouse_button_input.send(MouseButtonInput {
+                    WindowEvent::MouseInput {
+                        device_id,
+                        state,
+                        button,
+                        ..
+                    } => {
+                        let mouse_button_input = MouseButtonInput {
                             button: converters::convert_mouse_button(button),
                             state: converters::convert_element_state(state),
-                        });
+                            window: window_entity,
+                        };
+                        winit_state
+                            .held_input
+                            .entry(device_id)
+                            .or_default()
+                            .update_mouse_button(&mouse_button_input);
+                        input_events.mouse_button_input.send(mouse_button_input);
                     }
                     WindowEvent::MouseWheel { delta, .. } => match delta {
                         event::MouseScrollDelta::LineDelta(x, y) => {
@@ -537,6 +634,16 @@ pub fn winit_runner(mut app: App) {
                         // Component
                         window.focused = focused;
 
+                        // Releases won't be delivered while the window is unfocused.
+                        if !focused {
+                            for (_, held_input) in winit_state.held_input.drain() {
+                                held_input.release(
+                                    &mut input_events.keyboard_input,
+                                    &mut input_events.mouse_button_input,
+                                );
+                            }
+                        }
+
                         window_events.window_focused.send(WindowFocused {
                             window: window_entity,
                             focused,
@@ -599,13 +706,34 @@ pub fn winit_runner(mut app: App) {
                 event: DeviceEvent::MouseMotion { delta: (x, y) },
                 ..
             } => {
-                let mut system_state: SystemState<EventWriter<MouseMotion>> =
-                    SystemState::new(&mut app.world);
-                let mut mouse_motion = system_state.get_mut(&mut app.world);
-
-                mouse_motion.send(MouseMotion {
-                    delta: Vec2::new(x as f32, y as f32),
-                });
+                let mut system_state: SystemState<(
+                    EventWriter<MouseMotion>,
+                    Option<ResMut<MouseMotionSamples>>,
+                )> = SystemState::new(&mut app.world);
+                let (mut mouse_motion, samples) = system_state.get_mut(&mut app.world);
+
+                let delta = Vec2::new(x as f32, y as f32);
+                mouse_motion.send(MouseMotion { delta });
+                if let Some(mut samples) = samples {
+                    samples
+                        .bypass_change_detection()
+                        .push(delta, Instant::now());
+                }
+            }
+            event::Event::DeviceEvent {
+                device_id,
+                event: DeviceEvent::Removed,
+            } => {
+                if let Some(held_input) = winit_state.held_input.remove(&device_id) {
+                    let mut system_state: SystemState<(
+                        EventWriter<KeyboardInput>,
+                        EventWriter<MouseButtonInput>,
+                    )> = SystemState::new(&mut app.world);
+                    let (mut keyboard_input, mut mouse_button_input) =
+                        system_state.get_mut(&mut app.world);
+
+                    held_input.release(&mut keyboard_input, &mut mouse_button_input);
+                }
             }
             event::Event::Suspended => {
                 winit_state.active = false;
@@ -620,6 +748,10 @@ pub fn winit_runner(mut app: App) {
             event::Event::Resumed => {
                 winit_state.active = true;
             }
+            event::Event::UserEvent(()) => {
+                // sent by the `AppWaker`
+                winit_state.low_power_event = true;
+            }
             event::Event::MainEventsCleared => {
                 let (winit_config, window_focused_query) = focused_window_state.get(&app.world);
 
@@ -641,6 +773,7 @@ pub fn winit_runner(mut app: App) {
                 if update {
                     winit_state.last_update = Instant::now();
                     app.update();
+                    exiting = app.poll_exit(&mut app_exit_event_reader).is_some();
                 }
             }
             Event::RedrawEventsCleared => {
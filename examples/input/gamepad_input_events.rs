@@ -45,6 +45,7 @@ fn gamepad_ordered_events(mut gamepad_events: EventReader<GamepadEvent>) {
             GamepadEvent::Connection(connection_event) => info!("{:?}", connection_event),
             GamepadEvent::Button(button_event) => info!("{:?}", button_event),
             GamepadEvent::Axis(axis_event) => info!("{:?}", axis_event),
+            GamepadEvent::Power(power_event) => info!("{:?}", power_event),
         }
     }
 }
@@ -27,54 +27,54 @@ fn setup_scene(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     commands.spawn(
         TextBundle::from_sections([
-            TextSection {
-                value: "IME Enabled: ".to_string(),
-                style: TextStyle {
+            TextSection::new(
+                "IME Enabled: ".to_string(),
+                TextStyle {
                     font: font.clone_weak(),
                     font_size: 20.0,
                     color: Color::WHITE,
                 },
-            },
-            TextSection {
-                value: "false\n".to_string(),
-                style: TextStyle {
+            ),
+            TextSection::new(
+                "false\n".to_string(),
+                TextStyle {
                     font: font.clone_weak(),
                     font_size: 30.0,
                     color: Color::WHITE,
                 },
-            },
-            TextSection {
-                value: "IME Active: ".to_string(),
-                style: TextStyle {
+            ),
+            TextSection::new(
+                "IME Active: ".to_string(),
+                TextStyle {
                     font: font.clone_weak(),
                     font_size: 20.0,
                     color: Color::WHITE,
                 },
-            },
-            TextSection {
-                value: "false\n".to_string(),
-                style: TextStyle {
+            ),
+            TextSection::new(
+                "false\n".to_string(),
+                TextStyle {
                     font: font.clone_weak(),
                     font_size: 30.0,
                     color: Color::WHITE,
                 },
-            },
-            TextSection {
-                value: "click to toggle IME, press return to start a new line\n\n".to_string(),
-                style: TextStyle {
+            ),
+            TextSection::new(
+                "click to toggle IME, press return to start a new line\n\n".to_string(),
+                TextStyle {
                     font: font.clone_weak(),
                     font_size: 18.0,
                     color: Color::WHITE,
                 },
-            },
-            TextSection {
-                value: "".to_string(),
-                style: TextStyle {
+            ),
+            TextSection::new(
+                "".to_string(),
+                TextStyle {
                     font,
                     font_size: 25.0,
                     color: Color::WHITE,
                 },
-            },
+            ),
         ])
         .with_style(Style {
             position_type: PositionType::Absolute,
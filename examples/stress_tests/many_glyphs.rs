@@ -27,14 +27,14 @@ fn main() {
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(Camera2dBundle::default());
     let mut text = Text {
-        sections: vec![TextSection {
-            value: "0123456789".repeat(10_000),
-            style: TextStyle {
+        sections: vec![TextSection::new(
+            "0123456789".repeat(10_000),
+            TextStyle {
                 font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                 font_size: 4.,
                 color: Color::WHITE,
             },
-        }],
+        )],
         alignment: TextAlignment::Left,
         linebreak_behaviour: BreakLineOn::AnyCharacter,
     };
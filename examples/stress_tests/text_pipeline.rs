@@ -29,22 +29,22 @@ fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
     let sections = (1..=50)
         .flat_map(|i| {
             [
-                TextSection {
-                    value: "text".repeat(i),
-                    style: TextStyle {
+                TextSection::new(
+                    "text".repeat(i),
+                    TextStyle {
                         font: asset_server.load("fonts/FiraMono-Medium.ttf"),
                         font_size: (4 + i % 10) as f32,
                         color: Color::BLUE,
                     },
-                },
-                TextSection {
-                    value: "pipeline".repeat(i),
-                    style: TextStyle {
+                ),
+                TextSection::new(
+                    "pipeline".repeat(i),
+                    TextStyle {
                         font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                         font_size: (4 + i % 11) as f32,
                         color: Color::YELLOW,
                     },
-                },
+                ),
             ]
         })
         .collect::<Vec<_>>();
@@ -329,18 +329,9 @@ fn setup_sticks(
                     Text2dBundle {
                         transform: Transform::from_xyz(0., STICK_BOUNDS_SIZE + 2., 4.),
                         text: Text::from_sections([
-                            TextSection {
-                                value: format!("{:.3}", 0.),
-                                style: style.clone(),
-                            },
-                            TextSection {
-                                value: ", ".to_string(),
-                                style: style.clone(),
-                            },
-                            TextSection {
-                                value: format!("{:.3}", 0.),
-                                style,
-                            },
+                            TextSection::new(format!("{:.3}", 0.), style.clone()),
+                            TextSection::new(", ", style.clone()),
+                            TextSection::new(format!("{:.3}", 0.), style),
                         ]),
                         text_anchor: Anchor::BottomCenter,
                         ..default()
@@ -436,14 +427,8 @@ fn setup_connected(mut commands: Commands, font: Res<FontHandle>) {
     };
     commands.spawn((
         TextBundle::from_sections([
-            TextSection {
-                value: "Connected Gamepads:\n".to_string(),
-                style: style.clone(),
-            },
-            TextSection {
-                value: "None".to_string(),
-                style,
-            },
+            TextSection::new("Connected Gamepads:\n", style.clone()),
+            TextSection::new("None", style),
         ]),
         ConnectedGamepadsText,
     ));
@@ -36,6 +36,7 @@ crates=(
     bevy_sprite
     bevy_text
     bevy_a11y
+    bevy_console
     bevy_ui
     bevy_winit
     bevy_internal